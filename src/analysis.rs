@@ -1115,6 +1115,21 @@ pub(crate) fn classify_mcp_request_kind(path: &str, body: Option<&[u8]>) -> Toke
     }
 }
 
+/// Classifies each JSON-RPC message of an MCP body on its own, so a batch can be checked per
+/// message instead of as a single `mcp:batch` kind. Unparseable bodies yield no kinds.
+pub fn classify_mcp_message_request_kinds(body: &[u8]) -> Vec<TokenRequestKind> {
+    match serde_json::from_slice::<Value>(body) {
+        Ok(Value::Array(items)) => items
+            .iter()
+            .filter_map(classify_mcp_request_kind_from_message)
+            .collect(),
+        Ok(message @ Value::Object(_)) => classify_mcp_request_kind_from_message(&message)
+            .into_iter()
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
 pub fn classify_token_request_kind(path: &str, body: Option<&[u8]>) -> TokenRequestKind {
     match path {
         "/api/tavily/search" => build_api_request_kind("search"),
@@ -1600,6 +1615,7 @@ fn is_client_error_failure_kind(kind: &str) -> bool {
             | FAILURE_KIND_INVALID_COUNTRY_SEARCH_DEPTH_COMBO
             | FAILURE_KIND_RESEARCH_PAYLOAD_422
             | FAILURE_KIND_QUERY_TOO_LONG
+            | FAILURE_KIND_PARAMETER_POLICY_VIOLATION
             | FAILURE_KIND_MCP_METHOD_405
             | FAILURE_KIND_MCP_PATH_404
     )
@@ -1932,6 +1948,7 @@ pub(crate) fn token_log_operational_class_case_sql(
                 '{invalid_country_search_depth_combo}',
                 '{research_payload_422}',
                 '{query_too_long}',
+                '{parameter_policy_violation}',
                 '{mcp_method_405}',
                 '{mcp_path_404}'
             ) THEN '{client_error}'
@@ -1962,6 +1979,7 @@ pub(crate) fn token_log_operational_class_case_sql(
         invalid_country_search_depth_combo = FAILURE_KIND_INVALID_COUNTRY_SEARCH_DEPTH_COMBO,
         research_payload_422 = FAILURE_KIND_RESEARCH_PAYLOAD_422,
         query_too_long = FAILURE_KIND_QUERY_TOO_LONG,
+        parameter_policy_violation = FAILURE_KIND_PARAMETER_POLICY_VIOLATION,
         mcp_method_405 = FAILURE_KIND_MCP_METHOD_405,
         mcp_path_404 = FAILURE_KIND_MCP_PATH_404,
         upstream_rate_limited_429 = FAILURE_KIND_UPSTREAM_RATE_LIMITED_429,
@@ -0,0 +1,212 @@
+use base64::Engine as _;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use rand::RngCore as _;
+use ring::aead::{Aad, CHACHA20_POLY1305, LessSafeKey, NONCE_LEN, Nonce, UnboundKey};
+
+use crate::ha::sha256_hex_bytes;
+use crate::models::ProxyError;
+
+pub const API_KEY_SECRET_MASTER_KEY_ENV: &str = "API_KEY_SECRET_MASTER_KEY";
+
+const SEALED_API_KEY_SECRET_PREFIX: &str = "hikari-enc:v1:";
+const API_KEY_SECRET_FINGERPRINT_PREFIX: &str = "sha256:";
+
+/// Envelope encryption for upstream API key secrets.
+///
+/// Every secret is sealed with its own random data key, and only that data key is wrapped with
+/// the master key, so rotating the master key rewrites the wrapped data keys without touching
+/// the secret ciphertext.
+#[derive(Clone)]
+pub struct ApiKeySecretCipher {
+    master_key: [u8; 32],
+}
+
+impl std::fmt::Debug for ApiKeySecretCipher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ApiKeySecretCipher")
+            .field("master_key", &"<redacted>")
+            .finish()
+    }
+}
+
+struct SealedApiKeySecret {
+    wrap_nonce: [u8; NONCE_LEN],
+    wrapped_data_key: Vec<u8>,
+    data_nonce: [u8; NONCE_LEN],
+    ciphertext: Vec<u8>,
+}
+
+impl SealedApiKeySecret {
+    fn parse(sealed: &str) -> Result<Self, ProxyError> {
+        let invalid = || ProxyError::Other("malformed sealed api key secret".to_string());
+        let body = sealed
+            .strip_prefix(SEALED_API_KEY_SECRET_PREFIX)
+            .ok_or_else(invalid)?;
+        let mut parts = body.split('.').map(|part| URL_SAFE_NO_PAD.decode(part));
+        let (
+            Some(Ok(wrap_nonce)),
+            Some(Ok(wrapped_data_key)),
+            Some(Ok(data_nonce)),
+            Some(Ok(ciphertext)),
+            None,
+        ) = (
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            parts.next(),
+        )
+        else {
+            return Err(invalid());
+        };
+        Ok(Self {
+            wrap_nonce: wrap_nonce.try_into().map_err(|_| invalid())?,
+            wrapped_data_key,
+            data_nonce: data_nonce.try_into().map_err(|_| invalid())?,
+            ciphertext,
+        })
+    }
+
+    fn encode(&self) -> String {
+        format!(
+            "{SEALED_API_KEY_SECRET_PREFIX}{}.{}.{}.{}",
+            URL_SAFE_NO_PAD.encode(self.wrap_nonce),
+            URL_SAFE_NO_PAD.encode(&self.wrapped_data_key),
+            URL_SAFE_NO_PAD.encode(self.data_nonce),
+            URL_SAFE_NO_PAD.encode(&self.ciphertext),
+        )
+    }
+}
+
+impl ApiKeySecretCipher {
+    pub fn new(master_key: [u8; 32]) -> Self {
+        Self { master_key }
+    }
+
+    /// Encrypt `secret` under a fresh data key wrapped by the master key.
+    pub fn seal(&self, secret: &str) -> Result<String, ProxyError> {
+        let mut data_key = [0u8; 32];
+        rand::rngs::OsRng.fill_bytes(&mut data_key);
+        let (data_nonce, ciphertext) = aead_seal(&data_key, secret.as_bytes())?;
+        let (wrap_nonce, wrapped_data_key) = aead_seal(&self.master_key, &data_key)?;
+        Ok(SealedApiKeySecret {
+            wrap_nonce,
+            wrapped_data_key,
+            data_nonce,
+            ciphertext,
+        }
+        .encode())
+    }
+
+    pub fn open(&self, sealed: &str) -> Result<String, ProxyError> {
+        let envelope = SealedApiKeySecret::parse(sealed)?;
+        let data_key = self.unwrap_data_key(&envelope)?;
+        let plaintext = aead_open(&data_key, envelope.data_nonce, envelope.ciphertext)?;
+        String::from_utf8(plaintext)
+            .map_err(|_| ProxyError::Other("sealed api key secret is not valid utf-8".to_string()))
+    }
+
+    /// Re-wrap the data key of `sealed` under `next`, leaving the secret ciphertext as is.
+    pub fn rewrap(&self, sealed: &str, next: &ApiKeySecretCipher) -> Result<String, ProxyError> {
+        let mut envelope = SealedApiKeySecret::parse(sealed)?;
+        let data_key = self.unwrap_data_key(&envelope)?;
+        let (wrap_nonce, wrapped_data_key) = aead_seal(&next.master_key, &data_key)?;
+        envelope.wrap_nonce = wrap_nonce;
+        envelope.wrapped_data_key = wrapped_data_key;
+        Ok(envelope.encode())
+    }
+
+    fn unwrap_data_key(&self, envelope: &SealedApiKeySecret) -> Result<Vec<u8>, ProxyError> {
+        let data_key = aead_open(
+            &self.master_key,
+            envelope.wrap_nonce,
+            envelope.wrapped_data_key.clone(),
+        )
+        .map_err(|_| {
+            ProxyError::Other(
+                "failed to unwrap api key data key; check API_KEY_SECRET_MASTER_KEY".to_string(),
+            )
+        })?;
+        if data_key.len() != 32 {
+            return Err(ProxyError::Other(
+                "malformed sealed api key secret".to_string(),
+            ));
+        }
+        Ok(data_key)
+    }
+}
+
+/// Whether a stored `api_keys` value is a sealed envelope rather than a plaintext secret.
+pub fn is_sealed_api_key_secret(value: &str) -> bool {
+    value.starts_with(SEALED_API_KEY_SECRET_PREFIX)
+}
+
+/// Deterministic lookup value stored in `api_keys.api_key` once secrets are encrypted.
+pub fn api_key_secret_fingerprint(secret: &str) -> String {
+    format!(
+        "{API_KEY_SECRET_FINGERPRINT_PREFIX}{}",
+        sha256_hex_bytes(secret.as_bytes())
+    )
+}
+
+/// Parse a master key given as 32 raw bytes or as base64/base64url of 32 bytes.
+pub fn parse_api_key_secret_master_key(value: Option<&str>) -> Result<Option<[u8; 32]>, String> {
+    use base64::engine::general_purpose::{STANDARD, STANDARD_NO_PAD, URL_SAFE};
+
+    let Some(raw) = value.map(str::trim).filter(|it| !it.is_empty()) else {
+        return Ok(None);
+    };
+    if raw.len() == 32 {
+        let mut key = [0u8; 32];
+        key.copy_from_slice(raw.as_bytes());
+        return Ok(Some(key));
+    }
+    for engine in [STANDARD, STANDARD_NO_PAD, URL_SAFE, URL_SAFE_NO_PAD] {
+        if let Ok(decoded) = engine.decode(raw)
+            && let Ok(key) = <[u8; 32]>::try_from(decoded.as_slice())
+        {
+            return Ok(Some(key));
+        }
+    }
+    Err(format!(
+        "{API_KEY_SECRET_MASTER_KEY_ENV} must be 32 raw bytes or decode to 32 bytes from base64/base64url"
+    ))
+}
+
+fn aead_key(key_bytes: &[u8]) -> Result<LessSafeKey, ProxyError> {
+    UnboundKey::new(&CHACHA20_POLY1305, key_bytes)
+        .map(LessSafeKey::new)
+        .map_err(|_| ProxyError::Other("invalid api key secret cipher key length".to_string()))
+}
+
+fn aead_seal(key_bytes: &[u8], plaintext: &[u8]) -> Result<([u8; NONCE_LEN], Vec<u8>), ProxyError> {
+    let key = aead_key(key_bytes)?;
+    let mut nonce = [0u8; NONCE_LEN];
+    rand::rngs::OsRng.fill_bytes(&mut nonce);
+    let mut in_out = plaintext.to_vec();
+    key.seal_in_place_append_tag(
+        Nonce::assume_unique_for_key(nonce),
+        Aad::empty(),
+        &mut in_out,
+    )
+    .map_err(|_| ProxyError::Other("failed to encrypt api key secret".to_string()))?;
+    Ok((nonce, in_out))
+}
+
+fn aead_open(
+    key_bytes: &[u8],
+    nonce: [u8; NONCE_LEN],
+    mut in_out: Vec<u8>,
+) -> Result<Vec<u8>, ProxyError> {
+    let key = aead_key(key_bytes)?;
+    let plaintext_len = key
+        .open_in_place(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::empty(),
+            &mut in_out,
+        )
+        .map_err(|_| ProxyError::Other("failed to decrypt api key secret".to_string()))?
+        .len();
+    in_out.truncate(plaintext_len);
+    Ok(in_out)
+}
@@ -0,0 +1,61 @@
+use clap::Parser;
+use dotenvy::dotenv;
+use tavily_hikari::{parse_api_key_secret_master_key, rotate_api_key_secret_master_key_once};
+
+#[derive(Debug, Parser)]
+#[command(
+    author,
+    version,
+    about = "Re-wrap encrypted upstream API key secrets under a new master key",
+    long_about = "Re-wrap encrypted upstream API key secrets under a new master key.\n\n\
+        Running servers keep the master key they started with, so restart every node with the \
+        new API_KEY_SECRET_MASTER_KEY once the rotation finishes."
+)]
+struct Cli {
+    /// SQLite database path to mutate.
+    #[arg(long, env = "PROXY_DB_PATH", default_value = "data/tavily_proxy.db")]
+    db_path: String,
+
+    /// Master key the secrets are currently encrypted with.
+    #[arg(long, env = "API_KEY_SECRET_MASTER_KEY", hide_env_values = true)]
+    current_master_key: String,
+
+    /// Master key to re-wrap the secrets with.
+    #[arg(long, env = "API_KEY_SECRET_NEXT_MASTER_KEY", hide_env_values = true)]
+    next_master_key: String,
+
+    /// Emit JSON output. Plain output is retained for interactive use.
+    #[arg(long, default_value_t = false)]
+    json: bool,
+}
+
+fn required_master_key(value: &str, flag: &str) -> Result<[u8; 32], String> {
+    parse_api_key_secret_master_key(Some(value))
+        .map_err(|err| format!("{flag}: {err}"))?
+        .ok_or_else(|| format!("{flag} must not be empty"))
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    dotenv().ok();
+    let cli = Cli::parse();
+    let current = required_master_key(&cli.current_master_key, "--current-master-key")?;
+    let next = required_master_key(&cli.next_master_key, "--next-master-key")?;
+    if current == next {
+        return Err("--next-master-key must differ from --current-master-key".into());
+    }
+    let report = rotate_api_key_secret_master_key_once(&cli.db_path, current, next).await?;
+    if cli.json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        println!(
+            "api_key_secret_rotate: rotated={} elapsed_ms={}",
+            report.rotated, report.elapsed_ms
+        );
+    }
+    eprintln!(
+        "api_key_secret_rotate: restart every node with the new API_KEY_SECRET_MASTER_KEY; \
+         running servers still hold the previous key"
+    );
+    Ok(())
+}
@@ -195,7 +195,10 @@ rule-providers:
                 std::collections::HashMap::new(),
             ),
             request_stats_coalescer: crate::store::RequestStatsCoalescer::default(),
+            process_metrics: crate::store::ProcessMetrics::default(),
             admin_heavy_read_semaphore: tokio::sync::Semaphore::new(1),
+            api_key_secret_cipher: std::sync::OnceLock::new(),
+            access_token_secret_hashing: std::sync::atomic::AtomicBool::new(false),
             #[cfg(test)]
             forced_pending_claim_miss_log_ids: tokio::sync::Mutex::new(std::collections::HashSet::new()),
             #[cfg(test)]
@@ -1528,6 +1531,10 @@ if __name__ == "__main__":
                     .expect("valid trace url"),
                 low_quota_depletion_threshold: LOW_QUOTA_DEPLETION_THRESHOLD_DEFAULT,
                 health_readiness_grace_period: Duration::from_secs(90),
+                api_key_secret_cipher: None,
+                hash_access_token_secrets: false,
+                metrics_bearer_token: None,
+                replay_upstream_bases: Vec::new(),
             },
         )
         .await
@@ -1689,6 +1696,10 @@ if __name__ == "__main__":
                     .expect("valid trace url"),
                 low_quota_depletion_threshold: LOW_QUOTA_DEPLETION_THRESHOLD_DEFAULT,
                 health_readiness_grace_period: Duration::from_secs(90),
+                api_key_secret_cipher: None,
+                hash_access_token_secrets: false,
+                metrics_bearer_token: None,
+                replay_upstream_bases: Vec::new(),
             },
         )
         .await
@@ -1736,6 +1747,10 @@ if __name__ == "__main__":
                     .expect("valid trace url"),
                 low_quota_depletion_threshold: LOW_QUOTA_DEPLETION_THRESHOLD_DEFAULT,
                 health_readiness_grace_period: Duration::from_secs(90),
+                api_key_secret_cipher: None,
+                hash_access_token_secrets: false,
+                metrics_bearer_token: None,
+                replay_upstream_bases: Vec::new(),
             },
         )
         .await
@@ -1781,6 +1796,10 @@ if __name__ == "__main__":
                     .expect("valid trace url"),
                 low_quota_depletion_threshold: LOW_QUOTA_DEPLETION_THRESHOLD_DEFAULT,
                 health_readiness_grace_period: Duration::from_secs(90),
+                api_key_secret_cipher: None,
+                hash_access_token_secrets: false,
+                metrics_bearer_token: None,
+                replay_upstream_bases: Vec::new(),
             },
         )
         .await
@@ -1878,6 +1897,10 @@ if __name__ == "__main__":
                     .expect("valid trace url"),
                 low_quota_depletion_threshold: LOW_QUOTA_DEPLETION_THRESHOLD_DEFAULT,
                 health_readiness_grace_period: Duration::from_secs(90),
+                api_key_secret_cipher: None,
+                hash_access_token_secrets: false,
+                metrics_bearer_token: None,
+                replay_upstream_bases: Vec::new(),
             },
         )
         .await
@@ -1960,6 +1983,10 @@ if __name__ == "__main__":
                     .expect("valid trace url"),
                 low_quota_depletion_threshold: LOW_QUOTA_DEPLETION_THRESHOLD_DEFAULT,
                 health_readiness_grace_period: Duration::from_secs(0),
+                api_key_secret_cipher: None,
+                hash_access_token_secrets: false,
+                metrics_bearer_token: None,
+                replay_upstream_bases: Vec::new(),
             },
         )
         .await
@@ -2003,6 +2030,10 @@ if __name__ == "__main__":
                     .expect("valid trace url"),
                 low_quota_depletion_threshold: LOW_QUOTA_DEPLETION_THRESHOLD_DEFAULT,
                 health_readiness_grace_period: Duration::from_secs(0),
+                api_key_secret_cipher: None,
+                hash_access_token_secrets: false,
+                metrics_bearer_token: None,
+                replay_upstream_bases: Vec::new(),
             },
         )
         .await
@@ -2172,6 +2203,10 @@ if __name__ == "__main__":
                     .expect("valid trace url"),
                 low_quota_depletion_threshold: LOW_QUOTA_DEPLETION_THRESHOLD_DEFAULT,
                 health_readiness_grace_period: Duration::from_secs(90),
+                api_key_secret_cipher: None,
+                hash_access_token_secrets: false,
+                metrics_bearer_token: None,
+                replay_upstream_bases: Vec::new(),
             },
         )
         .await
@@ -2245,6 +2280,10 @@ if __name__ == "__main__":
                         .expect("valid trace url"),
                     low_quota_depletion_threshold: LOW_QUOTA_DEPLETION_THRESHOLD_DEFAULT,
                 health_readiness_grace_period: Duration::from_secs(90),
+                api_key_secret_cipher: None,
+                hash_access_token_secrets: false,
+                metrics_bearer_token: None,
+                replay_upstream_bases: Vec::new(),
                 },
             )
             .await
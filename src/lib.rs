@@ -3,11 +3,13 @@ mod runtime_logging;
 mod admin_mcp_session_bindings;
 mod admin_token_filters;
 mod analysis;
+mod api_key_secret_crypto;
 mod backend_time;
 mod forward_proxy;
 mod ha;
 mod linuxdo_credit_recharge;
 mod models;
+mod request_tracing;
 mod store;
 mod tavily_proxy;
 #[cfg(test)]
@@ -20,18 +22,20 @@ pub use admin_mcp_session_bindings::*;
 pub use admin_token_filters::*;
 pub use analysis::{
     analyze_http_attempt, analyze_mcp_attempt, canonical_request_kind_key_for_filter,
-    canonicalize_request_log_request_kind, classify_token_request_kind,
-    display_result_status_for_request_kind, extract_mcp_has_error_by_id_from_bytes,
-    extract_mcp_usage_credits_by_id_from_bytes, extract_research_request_id,
-    extract_usage_credits_from_json_bytes, extract_usage_credits_total_from_json_bytes,
-    failure_kind_solution_guidance, finalize_token_request_kind, is_canonical_request_kind_key,
-    mcp_response_has_any_error, mcp_response_has_any_success, normalize_operational_class_filter,
+    canonicalize_request_log_request_kind, classify_mcp_message_request_kinds,
+    classify_token_request_kind, display_result_status_for_request_kind,
+    extract_mcp_has_error_by_id_from_bytes, extract_mcp_usage_credits_by_id_from_bytes,
+    extract_research_request_id, extract_usage_credits_from_json_bytes,
+    extract_usage_credits_total_from_json_bytes, failure_kind_solution_guidance,
+    finalize_token_request_kind, is_canonical_request_kind_key, mcp_response_has_any_error,
+    mcp_response_has_any_success, normalize_operational_class_filter,
     operational_class_for_request_kind, operational_class_for_request_log,
     operational_class_for_request_path, operational_class_for_token_log,
     should_append_solution_guidance, token_request_kind_billing_group,
     token_request_kind_billing_group_for_request, token_request_kind_billing_group_for_request_log,
     token_request_kind_billing_group_for_token_log, token_request_kind_protocol_group,
 };
+pub use api_key_secret_crypto::*;
 pub use backend_time::*;
 pub use forward_proxy::{
     ForwardProxyErrorStatsResponse, ForwardProxyHourlyBucketResponse, ForwardProxyLiveNodeResponse,
@@ -44,14 +48,16 @@ pub use forward_proxy::{
 pub use ha::*;
 pub use linuxdo_credit_recharge::*;
 pub use models::*;
+pub use request_tracing::{RequestTrace, shutdown_request_tracing};
 pub use runtime_logging::{
     LegacyStdIoLevel, RuntimeLogFormat, RuntimeMemorySnapshot, RuntimePerfScope,
     capture_runtime_memory_snapshot, emit_legacy_stdio_event, init_runtime_logging,
 };
 pub use store::{
-    DbLogStatus, HaApplyResult, HaBaselineApplyMode, HaBaselineApplySession, HaEventsApplySession,
-    HaEventsReadSession, PerfLogScope, emit_low_memory_protection_decision, emit_perf_log,
-    emit_sampled_perf_log, is_transient_sqlite_write_error,
+    ApiKeySecretRotationReport, DbLogStatus, HaApplyResult, HaBaselineApplyMode,
+    HaBaselineApplySession, HaEventsApplySession, HaEventsReadSession, PerfLogScope,
+    emit_low_memory_protection_decision, emit_perf_log, emit_sampled_perf_log,
+    export_admin_audit_log, is_transient_sqlite_write_error, rotate_api_key_secret_master_key_once,
 };
 pub use tavily_proxy::*;
 pub use upstream_privacy::*;
@@ -1106,6 +1112,8 @@ const FAILURE_KIND_INVALID_SEARCH_DEPTH: &str = "invalid_search_depth";
 const FAILURE_KIND_INVALID_COUNTRY_SEARCH_DEPTH_COMBO: &str = "invalid_country_search_depth_combo";
 const FAILURE_KIND_RESEARCH_PAYLOAD_422: &str = "research_payload_422";
 const FAILURE_KIND_QUERY_TOO_LONG: &str = "query_too_long";
+/// Recorded when a token's request parameter policy refuses a request before it goes upstream.
+pub const FAILURE_KIND_PARAMETER_POLICY_VIOLATION: &str = "parameter_policy_violation";
 const FAILURE_KIND_OTHER: &str = "other";
 const KEY_EFFECT_NONE: &str = "none";
 const KEY_EFFECT_QUARANTINED: &str = "quarantined";
@@ -1134,6 +1142,9 @@ const KEY_EFFECT_API_REBALANCE_ROUTE_REBOUND: &str = "api_rebalance_route_reboun
 const KEY_EFFECT_API_REBALANCE_COOLDOWN_AVOIDED: &str = "api_rebalance_cooldown_avoided";
 const KEY_EFFECT_API_REBALANCE_RATE_LIMIT_AVOIDED: &str = "api_rebalance_rate_limit_avoided";
 const KEY_EFFECT_API_REBALANCE_PRESSURE_AVOIDED: &str = "api_rebalance_pressure_avoided";
+const KEY_EFFECT_CROSS_KEY_RETRY: &str = "cross_key_retry";
+const KEY_EFFECT_RESPONSE_CACHE_HIT: &str = "response_cache_hit";
+const KEY_EFFECT_REQUEST_COALESCED: &str = "request_coalesced";
 const MAINTENANCE_SOURCE_SYSTEM: &str = "system";
 const MAINTENANCE_SOURCE_ADMIN: &str = "admin";
 const MAINTENANCE_OP_AUTO_QUARANTINE: &str = "auto_quarantine";
@@ -1171,6 +1182,16 @@ const MCP_SESSION_INIT_BACKOFF_MAX_SECS: i64 = 300;
 const UNKNOWN_403_TRANSIENT_BACKOFF_DEFAULT_SECS: i64 = 120;
 const MCP_SESSION_INIT_RECENT_PRESSURE_WINDOW_SECS: i64 = 60;
 const HTTP_PROJECT_AFFINITY_RECENT_PRESSURE_WINDOW_SECS: i64 = 60;
+// Credits billed to a key inside this window are subtracted from the last synced remaining
+// quota when ranking keys in quota-weighted selection mode.
+const KEY_SELECTION_QUOTA_PRESSURE_WINDOW_SECS: i64 = 15 * 60;
+const API_KEY_GROUP_NAME_MAX_LEN: usize = 64;
+const API_KEY_GROUP_PRIORITY_MIN: i64 = -1000;
+const API_KEY_GROUP_PRIORITY_MAX: i64 = 1000;
+const API_KEY_RATE_BUDGET_RPM_MIN: i64 = 1;
+const API_KEY_RATE_BUDGET_RPM_MAX: i64 = 10_000;
+/// Longest time a request queues for local per-key budget headroom before it is rejected.
+const API_KEY_RATE_BUDGET_MAX_WAIT_MS: u64 = 2_000;
 const BROKEN_KEY_SUBJECT_USER: &str = "user";
 const BROKEN_KEY_SUBJECT_TOKEN: &str = "token";
 const BROKEN_KEY_SOURCE_AUTO: &str = "auto";
@@ -1202,6 +1223,7 @@ pub(crate) fn is_selection_effect_code(code: &str) -> bool {
             | KEY_EFFECT_API_REBALANCE_COOLDOWN_AVOIDED
             | KEY_EFFECT_API_REBALANCE_RATE_LIMIT_AVOIDED
             | KEY_EFFECT_API_REBALANCE_PRESSURE_AVOIDED
+            | KEY_EFFECT_CROSS_KEY_RETRY
     )
 }
 
@@ -1236,6 +1258,9 @@ pub const REQUEST_RATE_LIMIT_MIN: i64 = 1;
 // Keep a request_id -> key affinity for Tavily research result polling.
 // This avoids switching keys between POST /research and GET /research/{request_id}.
 const RESEARCH_REQUEST_AFFINITY_TTL_SECS: i64 = 24 * 60 * 60;
+// Credits held for a streamed research request count against the quota until the stream
+// settles; a reservation left behind by a crashed instance stops counting after this long.
+const BILLING_RESERVATION_TTL_SECS: i64 = 60 * 60;
 const MCP_SESSION_RETENTION_SECS: i64 = 7 * 24 * 60 * 60;
 pub const MCP_SESSION_AFFINITY_KEY_COUNT_DEFAULT: i64 = 5;
 pub const MCP_SESSION_AFFINITY_KEY_COUNT_MIN: i64 = 1;
@@ -1248,6 +1273,30 @@ pub const API_REBALANCE_ENABLED_DEFAULT: bool = false;
 pub const API_REBALANCE_PERCENT_DEFAULT: i64 = 0;
 pub const API_REBALANCE_PERCENT_MIN: i64 = 0;
 pub const API_REBALANCE_PERCENT_MAX: i64 = 100;
+pub const CROSS_KEY_RETRY_ENABLED_DEFAULT: bool = false;
+pub const CROSS_KEY_RETRY_MAX_ATTEMPTS_DEFAULT: i64 = 3;
+pub const CROSS_KEY_RETRY_MAX_ATTEMPTS_MIN: i64 = 2;
+pub const CROSS_KEY_RETRY_MAX_ATTEMPTS_MAX: i64 = 5;
+pub const CROSS_KEY_RETRY_BUDGET_MS_DEFAULT: i64 = 8_000;
+pub const CROSS_KEY_RETRY_BUDGET_MS_MIN: i64 = 500;
+pub const CROSS_KEY_RETRY_BUDGET_MS_MAX: i64 = 60_000;
+pub const RESPONSE_CACHE_ENABLED_DEFAULT: bool = false;
+pub const RESPONSE_CACHE_TTL_SECS_DEFAULT: i64 = 10 * 60;
+pub const RESPONSE_CACHE_TTL_SECS_MIN: i64 = 10;
+pub const RESPONSE_CACHE_TTL_SECS_MAX: i64 = 7 * 24 * 60 * 60;
+pub const RESPONSE_CACHE_MAX_ENTRIES_DEFAULT: i64 = 5_000;
+pub const RESPONSE_CACHE_MAX_ENTRIES_MIN: i64 = 1;
+pub const RESPONSE_CACHE_MAX_ENTRIES_MAX: i64 = 1_000_000;
+pub const RESPONSE_CACHE_MAX_BODY_BYTES_DEFAULT: i64 = 512 * 1024;
+pub const RESPONSE_CACHE_MAX_BODY_BYTES_MIN: i64 = 1024;
+pub const RESPONSE_CACHE_MAX_BODY_BYTES_MAX: i64 = 16 * 1024 * 1024;
+pub const RESPONSE_CACHE_HIT_BILLING_PERCENT_DEFAULT: i64 = 0;
+pub const RESPONSE_CACHE_HIT_BILLING_PERCENT_MIN: i64 = 0;
+pub const RESPONSE_CACHE_HIT_BILLING_PERCENT_MAX: i64 = 100;
+pub const REQUEST_COALESCING_ENABLED_DEFAULT: bool = false;
+pub const REQUEST_COALESCING_FOLLOWER_BILLING_PERCENT_DEFAULT: i64 = 100;
+pub const REQUEST_COALESCING_FOLLOWER_BILLING_PERCENT_MIN: i64 = 0;
+pub const REQUEST_COALESCING_FOLLOWER_BILLING_PERCENT_MAX: i64 = 100;
 pub const MCP_GATEWAY_MODE_UPSTREAM: &str = "upstream_mcp";
 pub const MCP_GATEWAY_MODE_REBALANCE: &str = "rebalance_http";
 pub const MCP_EXPERIMENT_VARIANT_CONTROL: &str = "control";
@@ -1301,6 +1350,7 @@ static QUOTA_SUBJECT_LOCK_OWNER_SEQ: AtomicU64 = AtomicU64::new(1);
 const GRANULARITY_MINUTE: &str = "minute";
 const GRANULARITY_HOUR: &str = "hour";
 const GRANULARITY_DAY: &str = "day";
+const GRANULARITY_MONTH: &str = "month";
 // Per-token raw request counter (any request type), aggregated per minute.
 #[allow(dead_code)]
 const GRANULARITY_REQUEST_MINUTE: &str = "request_minute";
@@ -1352,9 +1402,14 @@ const META_KEY_ACCOUNT_USAGE_ROLLUP_QUOTA_MONTH_COVERAGE_START: &str =
     "account_usage_rollup_quota_month_coverage_start";
 const META_KEY_ACCOUNT_LIMIT_SNAPSHOT_BACKFILL_V1: &str = "account_limit_snapshot_backfill_v1";
 const META_KEY_ALLOW_REGISTRATION_V1: &str = "allow_registration_v1";
+const META_KEY_REGISTRATION_REQUIRES_APPROVAL_V1: &str = "registration_requires_approval_v1";
 const META_KEY_RECHARGE_FEATURE_ENABLED_V1: &str = "recharge_feature_enabled_v1";
 const META_KEY_RECHARGE_USER_ENABLED_V1: &str = "recharge_user_enabled_v1";
 const META_KEY_ADMIN_DEFAULT_ACTIVE_USERS_ONLY_V1: &str = "admin_default_active_users_only_v1";
+const META_KEY_KEY_SELECTION_MODE_V1: &str = "key_selection_mode_v1";
+const META_KEY_CROSS_KEY_RETRY_V1: &str = "cross_key_retry_v1";
+const META_KEY_RESPONSE_CACHE_V1: &str = "response_cache_v1";
+const META_KEY_REQUEST_COALESCING_V1: &str = "request_coalescing_v1";
 const META_KEY_ADMIN_TOTP_SECRET_CIPHERTEXT_V1: &str = "admin_totp_secret_ciphertext_v1";
 const META_KEY_ADMIN_TOTP_SECRET_NONCE_V1: &str = "admin_totp_secret_nonce_v1";
 const META_KEY_ADMIN_TOTP_ENABLED_AT_V1: &str = "admin_totp_enabled_at_v1";
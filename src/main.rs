@@ -35,9 +35,10 @@ use argon2::password_hash::PasswordHash;
 use clap::{Parser, Subcommand};
 use dotenvy::dotenv;
 use tavily_hikari::{
-    AdminPasskeyScope, DEFAULT_UPSTREAM, HaConfig, HaMode, LOW_QUOTA_DEPLETION_THRESHOLD_DEFAULT,
-    RuntimeLogFormat, TavilyProxy, TavilyProxyOptions,
-    create_admin_passkey_reset_token_for_database,
+    AdminPasskeyScope, ApiKeySecretCipher, DEFAULT_UPSTREAM, HaConfig, HaMode,
+    LOW_QUOTA_DEPLETION_THRESHOLD_DEFAULT, RuntimeLogFormat, TavilyProxy, TavilyProxyOptions,
+    create_admin_passkey_reset_token_for_database, export_admin_audit_log,
+    parse_api_key_secret_master_key,
 };
 use tracing::{info, warn};
 
@@ -56,6 +57,29 @@ struct Cli {
     )]
     keys: Vec<String>,
 
+    /// Master key for encrypting upstream API key secrets at rest (32 raw bytes or base64/base64url).
+    #[arg(long, env = "API_KEY_SECRET_MASTER_KEY", hide_env_values = true)]
+    api_key_secret_master_key: Option<String>,
+
+    /// Persist only salted hashes of access-token secrets; existing secrets are hashed on startup
+    /// and can no longer be revealed.
+    #[arg(
+        long,
+        env = "ACCESS_TOKEN_SECRET_HASHING",
+        default_value_t = false,
+        value_parser = parse_bool_flag
+    )]
+    access_token_secret_hashing: bool,
+
+    /// Bearer token Prometheus uses to scrape `/metrics`; admin sessions can always read it.
+    #[arg(long, env = "METRICS_BEARER_TOKEN", hide_env_values = true)]
+    metrics_bearer_token: Option<String>,
+
+    /// Alternate upstream bases (comma separated, e.g. a local mock_tavily) that admins may
+    /// replay logged requests against.
+    #[arg(long, value_delimiter = ',', env = "REPLAY_UPSTREAM_BASES")]
+    replay_upstream_bases: Vec<String>,
+
     /// 上游 Tavily MCP 端点
     #[arg(long, env = "TAVILY_UPSTREAM", default_value = DEFAULT_UPSTREAM)]
     upstream: String,
@@ -241,6 +265,50 @@ struct Cli {
     #[arg(long, env = "OAUTH_LOGIN_STATE_TTL_SECS", default_value_t = 600)]
     oauth_login_state_ttl_secs: i64,
 
+    /// Enable/disable login through a generic OpenID Connect provider.
+    #[arg(long, env = "OIDC_ENABLED", default_value_t = false)]
+    oidc_enabled: bool,
+
+    /// OIDC issuer URL; `/.well-known/openid-configuration` is discovered from it.
+    #[arg(long, env = "OIDC_ISSUER")]
+    oidc_issuer: Option<String>,
+
+    /// OIDC client id.
+    #[arg(long, env = "OIDC_CLIENT_ID")]
+    oidc_client_id: Option<String>,
+
+    /// OIDC client secret (omit for public clients that rely on PKCE only).
+    #[arg(long, env = "OIDC_CLIENT_SECRET", hide_env_values = true)]
+    oidc_client_secret: Option<String>,
+
+    /// OIDC callback URL for this service (the frontend `/console/oauth/oidc/callback` route).
+    #[arg(long, env = "OIDC_REDIRECT_URL")]
+    oidc_redirect_url: Option<String>,
+
+    /// OIDC requested scope.
+    #[arg(long, env = "OIDC_SCOPE", default_value = "openid profile email")]
+    oidc_scope: String,
+
+    /// Login button label shown for the OIDC provider.
+    #[arg(long, env = "OIDC_DISPLAY_NAME", default_value = "SSO")]
+    oidc_display_name: String,
+
+    /// Claim mapped to the local username (falls back to `email`).
+    #[arg(
+        long,
+        env = "OIDC_USERNAME_CLAIM",
+        default_value = "preferred_username"
+    )]
+    oidc_username_claim: String,
+
+    /// Claim mapped to the local display name.
+    #[arg(long, env = "OIDC_NAME_CLAIM", default_value = "name")]
+    oidc_name_claim: String,
+
+    /// Claim mapped to the avatar URL.
+    #[arg(long, env = "OIDC_AVATAR_CLAIM", default_value = "picture")]
+    oidc_avatar_claim: String,
+
     /// Enable LinuxDo Credit recharge payment flow.
     #[arg(long, env = "LINUXDO_CREDIT_ENABLED", default_value_t = false)]
     linuxdo_credit_enabled: bool,
@@ -377,6 +445,10 @@ struct Cli {
     /// Runtime log formatter (`json` by default, `text` for fallback grep workflows).
     #[arg(long, env = "RUNTIME_LOG_FORMAT", value_enum, default_value_t = RuntimeLogFormat::Json)]
     log_format: RuntimeLogFormat,
+
+    /// OTLP/HTTP collector URL (for example http://otel-collector:4318). Enables span export.
+    #[arg(long, env = "OTEL_EXPORTER_OTLP_ENDPOINT")]
+    otlp_endpoint: Option<String>,
 }
 
 #[derive(Debug, Subcommand)]
@@ -393,6 +465,7 @@ struct AdminCommand {
 #[derive(Debug, Subcommand)]
 enum AdminSubcommand {
     Passkey(AdminPasskeyCommand),
+    Audit(AdminAuditCommand),
 }
 
 #[derive(Debug, Parser)]
@@ -418,11 +491,36 @@ struct AdminPasskeyResetUrlCommand {
     ttl_secs: i64,
 }
 
+#[derive(Debug, Parser)]
+struct AdminAuditCommand {
+    #[command(subcommand)]
+    command: AdminAuditSubcommand,
+}
+
+#[derive(Debug, Subcommand)]
+enum AdminAuditSubcommand {
+    /// Export the admin audit log as JSON lines and verify its hash chain.
+    Export(AdminAuditExportCommand),
+}
+
+#[derive(Debug, Parser)]
+struct AdminAuditExportCommand {
+    /// Write the export to this file instead of stdout.
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     dotenv().ok();
     let cli = Cli::parse();
-    tavily_hikari::init_runtime_logging(cli.log_format);
+    tavily_hikari::init_runtime_logging(
+        cli.log_format,
+        cli.otlp_endpoint
+            .as_deref()
+            .map(str::trim)
+            .filter(|endpoint| !endpoint.is_empty()),
+    );
     reject_legacy_ha_origin_env_vars()?;
 
     // Ensure parent directory for database exists when using nested path like data/tavily_proxy.db
@@ -462,6 +560,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             "LOW_QUOTA_DEPLETION_THRESHOLD",
         ),
         health_readiness_grace_period: std::time::Duration::from_secs(90),
+        api_key_secret_cipher: parse_api_key_secret_master_key(
+            cli.api_key_secret_master_key.as_deref(),
+        )?
+        .map(ApiKeySecretCipher::new),
+        hash_access_token_secrets: cli.access_token_secret_hashing,
+        metrics_bearer_token: cli.metrics_bearer_token,
+        replay_upstream_bases: cli
+            .replay_upstream_bases
+            .iter()
+            .filter(|base| !base.trim().is_empty())
+            .map(|base| tavily_hikari::normalize_request_log_replay_base(base))
+            .collect::<Result<Vec<_>, _>>()?,
     };
     let ha_mode = HaMode::parse(&cli.ha_mode);
     let proxy = TavilyProxy::with_options_in_ha_mode(
@@ -640,6 +750,28 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         session_max_age_secs: cli.user_session_max_age_secs.max(60),
         login_state_ttl_secs: cli.oauth_login_state_ttl_secs.max(60),
     };
+    let oidc = server::OidcOptions {
+        enabled: cli.oidc_enabled,
+        issuer: trim_optional(cli.oidc_issuer),
+        client_id: trim_optional(cli.oidc_client_id),
+        client_secret: trim_optional(cli.oidc_client_secret),
+        redirect_url: trim_optional(cli.oidc_redirect_url),
+        scope: cli.oidc_scope.trim().to_string(),
+        display_name: cli.oidc_display_name.trim().to_string(),
+        username_claim: cli.oidc_username_claim.trim().to_string(),
+        name_claim: cli.oidc_name_claim.trim().to_string(),
+        avatar_claim: cli.oidc_avatar_claim.trim().to_string(),
+        session_max_age_secs: cli.user_session_max_age_secs.max(60),
+        login_state_ttl_secs: cli.oauth_login_state_ttl_secs.max(60),
+    };
+    if oidc.enabled
+        && (oidc.issuer.is_none() || oidc.client_id.is_none() || oidc.redirect_url.is_none())
+    {
+        return Err(
+            "OIDC_ISSUER, OIDC_CLIENT_ID and OIDC_REDIRECT_URL are required when OIDC_ENABLED=true"
+                .into(),
+        );
+    }
     let linuxdo_credit = server::LinuxDoCreditOptions {
         enabled: cli.linuxdo_credit_enabled,
         client_id: cli
@@ -709,7 +841,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     });
 
-    server::serve(
+    let served = server::serve(
         addr,
         proxy,
         static_dir,
@@ -721,8 +853,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         ha_config,
         linuxdo_oauth,
         linuxdo_credit,
+        oidc,
     )
-    .await?;
+    .await;
+    let _ = tokio::task::spawn_blocking(tavily_hikari::shutdown_request_tracing).await;
+    served?;
 
     Ok(())
 }
@@ -738,6 +873,9 @@ async fn handle_cli_command(
                     print_admin_passkey_reset_url(cli, command).await
                 }
             },
+            AdminSubcommand::Audit(audit) => match &audit.command {
+                AdminAuditSubcommand::Export(command) => export_admin_audit(cli, command).await,
+            },
         },
     }
 }
@@ -769,6 +907,31 @@ async fn print_admin_passkey_reset_url(
     Ok(())
 }
 
+/// Streams the audit log to stdout or `--output`, then reports the chain verification on stderr
+/// and fails when the chain is broken.
+async fn export_admin_audit(
+    cli: &Cli,
+    command: &AdminAuditExportCommand,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let verification = match &command.output {
+        Some(path) => {
+            let file = std::io::BufWriter::new(std::fs::File::create(path)?);
+            export_admin_audit_log(&cli.db_path, file).await?
+        }
+        None => export_admin_audit_log(&cli.db_path, std::io::stdout().lock()).await?,
+    };
+    std::eprintln!("{}", serde_json::to_string(&verification)?);
+    if !verification.ok {
+        return Err(format!(
+            "admin audit chain is broken at seq {}: {}",
+            verification.broken_at_seq.unwrap_or_default(),
+            verification.reason.as_deref().unwrap_or("unknown")
+        )
+        .into());
+    }
+    Ok(())
+}
+
 fn admin_passkey_scope_for_cli(cli: &Cli) -> Result<AdminPasskeyScope, Box<dyn std::error::Error>> {
     let rp_id = trim_optional(cli.admin_passkey_rp_id.clone())
         .or_else(|| infer_admin_passkey_rp_id(cli))
@@ -370,21 +370,55 @@ pub fn resolve_client_ip_info(
     }
 }
 
+mod access_token_models;
+mod admin_account_models;
+mod admin_audit_models;
 mod alert_models;
+mod alert_rule_models;
+mod alert_webhook_models;
+mod alert_workflow_models;
 #[cfg(test)]
 mod client_ip_tests;
+mod cross_key_retry_models;
 mod dashboard_month_series;
+mod key_group_models;
+mod key_rate_budget_models;
 mod monthly_quota_rebase;
+mod oauth_login_models;
 mod quota_views;
-
+mod registration_invite_models;
+mod request_coalescing_models;
+mod request_log_replay_models;
+mod request_log_search_models;
+mod request_parameter_policy_models;
+mod response_cache_models;
+mod team_models;
+
+pub use access_token_models::*;
+pub use admin_account_models::*;
+pub use admin_audit_models::*;
 pub use alert_models::*;
+pub use alert_rule_models::*;
+pub use alert_webhook_models::*;
+pub use alert_workflow_models::*;
+pub use cross_key_retry_models::*;
 
 pub use dashboard_month_series::{DashboardMonthSeries, DashboardMonthSeriesPoint};
+pub use key_group_models::*;
+pub use key_rate_budget_models::*;
 pub(crate) use monthly_quota_rebase::{
     maybe_rebase_current_month_business_quota_with_pool,
     rebase_current_month_business_quota_with_pool,
 };
+pub use oauth_login_models::*;
 pub use quota_views::*;
+pub use registration_invite_models::*;
+pub use request_coalescing_models::*;
+pub use request_log_replay_models::*;
+pub use request_log_search_models::*;
+pub use request_parameter_policy_models::*;
+pub use response_cache_models::*;
+pub use team_models::*;
 
 #[derive(Debug)]
 pub(crate) struct ApiKeyLease {
@@ -822,6 +856,7 @@ pub struct PaginatedApiKeyMetrics {
     pub page: i64,
     pub per_page: i64,
     pub facets: ApiKeyListFacets,
+    pub tiers: Vec<ApiKeyTierCapacity>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -978,6 +1013,34 @@ pub struct RequestLogRetentionSettings {
     pub debug_shared: RequestLogRetentionProfile,
 }
 
+/// How the global key pool picks the next upstream key.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum KeySelectionMode {
+    /// Least-recently-used active key first.
+    #[default]
+    Lru,
+    /// Prefer keys with the most remaining monthly credits after recent billable pressure.
+    QuotaWeighted,
+}
+
+impl KeySelectionMode {
+    pub(crate) const fn as_meta_value(self) -> &'static str {
+        match self {
+            Self::Lru => "lru",
+            Self::QuotaWeighted => "quotaWeighted",
+        }
+    }
+
+    pub(crate) fn from_meta_value(value: &str) -> Option<Self> {
+        match value {
+            "lru" => Some(Self::Lru),
+            "quotaWeighted" => Some(Self::QuotaWeighted),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct SystemSettings {
@@ -988,6 +1051,7 @@ pub struct SystemSettings {
     pub rebalance_mcp_session_percent: i64,
     pub api_rebalance_enabled: bool,
     pub api_rebalance_percent: i64,
+    pub key_selection_mode: KeySelectionMode,
     pub upstream_project_id_mode: UpstreamProjectIdMode,
     pub upstream_project_id_fixed_value: String,
     pub upstream_mcp_user_agent: String,
@@ -1000,6 +1064,9 @@ pub struct SystemSettings {
     pub trusted_proxy_cidrs: Vec<String>,
     pub trusted_client_ip_headers: Vec<String>,
     pub request_log_retention: RequestLogRetentionSettings,
+    pub cross_key_retry: CrossKeyRetrySettings,
+    pub response_cache: ResponseCacheSettings,
+    pub request_coalescing: RequestCoalescingSettings,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -1143,6 +1210,8 @@ pub struct RequestLogRecord {
     pub client_ip_source: Option<String>,
     pub client_ip_trusted: bool,
     pub ip_headers: Vec<ClientIpHeaderValue>,
+    /// W3C trace id of the proxied request; set when tracing or a `traceparent` was present.
+    pub trace_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
@@ -1338,6 +1407,9 @@ pub struct ProxySummary {
     pub last_activity: Option<i64>,
     pub total_quota_limit: i64,
     pub total_quota_remaining: i64,
+    pub key_tiers: Vec<ApiKeyTierCapacity>,
+    pub key_rate_budgets: Vec<ApiKeyRateBudgetUsage>,
+    pub response_cache: ResponseCacheStats,
 }
 
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
@@ -1715,29 +1787,6 @@ pub struct AdminPasskeySessionRecord {
     pub revoked_at: Option<i64>,
 }
 
-/// Token list record for management UI
-#[derive(Debug, Clone)]
-pub struct AuthToken {
-    pub id: String, // 4-char id code
-    pub enabled: bool,
-    pub note: Option<String>,
-    pub group_name: Option<String>,
-    pub total_requests: i64,
-    pub created_at: i64,
-    pub last_used_at: Option<i64>,
-    pub quota: Option<TokenQuotaVerdict>,
-    pub quota_hourly_reset_at: Option<i64>,
-    pub quota_daily_reset_at: Option<i64>,
-    pub quota_monthly_reset_at: Option<i64>,
-}
-
-/// Full token for copy (never store prefix-only here)
-#[derive(Debug, Clone)]
-pub struct AuthTokenSecret {
-    pub id: String,
-    pub token: String, // th-<id>-<secret>
-}
-
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct AnalysisPressureSnapshot {
@@ -2022,13 +2071,6 @@ pub enum UserTokenLookup {
     Unavailable,
 }
 
-/// Payload returned from OAuth state consume operation.
-#[derive(Debug, Clone)]
-pub struct OAuthLoginStatePayload {
-    pub redirect_to: Option<String>,
-    pub bind_token_id: Option<String>,
-}
-
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct TokenRequestKind {
     pub key: String,
@@ -2148,6 +2190,8 @@ pub enum ProxyError {
     NoAvailableKeys,
     #[error("pinned MCP session key is unavailable")]
     PinnedMcpSessionUnavailable,
+    #[error("API key {key_id} rate budget exhausted; retry after {retry_after_ms}ms")]
+    KeyRateBudgetExhausted { key_id: String, retry_after_ms: i64 },
     #[error("database error: {0}")]
     Database(#[from] sqlx::Error),
     #[error("http error: {0}")]
@@ -2161,6 +2205,8 @@ pub enum ProxyError {
     },
     #[error("cannot remove the final admin login method")]
     LastAdminLoginMethod,
+    #[error("access token {token_id} only keeps a hashed secret; rotate it to issue a new one")]
+    TokenSecretNotRecoverable { token_id: String },
     #[error("scheduled job {job_id} claim generation {claim_generation} is stale")]
     StaleClaim { job_id: i64, claim_generation: i64 },
     #[error("deferred {operation}: {reason}")]
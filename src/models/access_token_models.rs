@@ -0,0 +1,179 @@
+use super::TokenQuotaVerdict;
+use serde::{Deserialize, Serialize};
+
+/// Token list record for management UI
+#[derive(Debug, Clone)]
+pub struct AuthToken {
+    pub id: String, // 4-char id code
+    pub enabled: bool,
+    pub note: Option<String>,
+    pub group_name: Option<String>,
+    pub total_requests: i64,
+    pub created_at: i64,
+    pub last_used_at: Option<i64>,
+    pub quota: Option<TokenQuotaVerdict>,
+    pub quota_hourly_reset_at: Option<i64>,
+    pub quota_daily_reset_at: Option<i64>,
+    pub quota_monthly_reset_at: Option<i64>,
+    pub lifetime: AccessTokenLifetime,
+    /// Scopes set on the token itself; `None` falls back to its group default.
+    pub scopes: Option<AccessTokenScopes>,
+}
+
+/// Full token for copy (never store prefix-only here)
+#[derive(Debug, Clone)]
+pub struct AuthTokenSecret {
+    pub id: String,
+    pub token: String, // th-<id>-<secret>
+}
+
+/// How long before `expires_at` the expiry-notice job raises a `token_expiring` alert.
+pub const ACCESS_TOKEN_EXPIRY_NOTICE_LEAD_SECS_DEFAULT: i64 = 3 * 24 * 60 * 60;
+
+/// Optional validity window of an access token. Both bounds are unix seconds; `None` leaves that
+/// side open, so a token without either bound never expires.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessTokenLifetime {
+    pub not_before: Option<i64>,
+    pub expires_at: Option<i64>,
+}
+
+impl AccessTokenLifetime {
+    pub fn new(not_before: Option<i64>, expires_at: Option<i64>) -> Self {
+        Self {
+            not_before,
+            expires_at,
+        }
+    }
+
+    /// Rejects negative timestamps and windows that close before they open.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        if self.not_before.is_some_and(|value| value < 0)
+            || self.expires_at.is_some_and(|value| value < 0)
+        {
+            return Err("token lifetime timestamps must be non-negative unix seconds");
+        }
+        if let (Some(not_before), Some(expires_at)) = (self.not_before, self.expires_at)
+            && expires_at <= not_before
+        {
+            return Err("expiresAt must be later than notBefore");
+        }
+        Ok(())
+    }
+
+    /// Verdict for a token whose secret already matched and that is otherwise enabled.
+    pub fn check(&self, now: i64) -> AccessTokenValidation {
+        if let Some(not_before) = self.not_before
+            && now < not_before
+        {
+            return AccessTokenValidation::NotYetValid { not_before };
+        }
+        if let Some(expires_at) = self.expires_at
+            && now >= expires_at
+        {
+            return AccessTokenValidation::Expired { expires_at };
+        }
+        AccessTokenValidation::Valid
+    }
+}
+
+/// Outcome of authenticating an access token. Lifetime rejections are kept apart from unknown or
+/// disabled tokens so the auth path can answer with a distinct error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessTokenValidation {
+    Valid,
+    Invalid,
+    NotYetValid { not_before: i64 },
+    Expired { expires_at: i64 },
+}
+
+impl AccessTokenValidation {
+    pub fn is_valid(&self) -> bool {
+        matches!(self, Self::Valid)
+    }
+}
+
+/// Request kinds a token scope can grant. Every other kind (MCP handshakes, `tools/list`, usage
+/// lookups, third-party tools) stays available to any authenticated token.
+pub const ACCESS_TOKEN_SCOPE_KEYS: [&str; 10] = [
+    "api:search",
+    "api:extract",
+    "api:crawl",
+    "api:map",
+    "api:research",
+    "mcp:search",
+    "mcp:extract",
+    "mcp:crawl",
+    "mcp:map",
+    "mcp:research",
+];
+
+/// Scope key guarding a canonical request kind, or `None` when the kind is never scoped.
+/// Research results follow the scope of the research call that produced them.
+pub fn access_token_scope_for_request_kind(request_kind_key: &str) -> Option<&'static str> {
+    match request_kind_key.trim() {
+        "api:research-result" => Some("api:research"),
+        key => ACCESS_TOKEN_SCOPE_KEYS
+            .iter()
+            .copied()
+            .find(|scope| *scope == key),
+    }
+}
+
+/// Allow-list of scoped request kinds for a token or token group. An empty list still permits
+/// the unscoped control-plane kinds but no business calls.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct AccessTokenScopes(Vec<String>);
+
+impl AccessTokenScopes {
+    /// Validates canonical scope keys; duplicates collapse and the order is normalized.
+    pub fn parse<I, S>(keys: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut allowed = Vec::new();
+        for key in keys {
+            let key = key.as_ref().trim();
+            let Some(scope) = ACCESS_TOKEN_SCOPE_KEYS.iter().find(|scope| **scope == key) else {
+                return Err(format!("unsupported token scope: {key}"));
+            };
+            allowed.push(*scope);
+        }
+        Ok(Self(
+            ACCESS_TOKEN_SCOPE_KEYS
+                .iter()
+                .filter(|scope| allowed.contains(scope))
+                .map(|scope| scope.to_string())
+                .collect(),
+        ))
+    }
+
+    /// Decodes a persisted JSON list. Unknown keys are dropped so a corrupt row narrows access
+    /// instead of widening it.
+    pub fn from_stored(raw: &str) -> Self {
+        let keys = serde_json::from_str::<Vec<String>>(raw).unwrap_or_default();
+        Self(
+            ACCESS_TOKEN_SCOPE_KEYS
+                .iter()
+                .filter(|scope| keys.iter().any(|key| key == *scope))
+                .map(|scope| scope.to_string())
+                .collect(),
+        )
+    }
+
+    pub fn to_stored(&self) -> String {
+        serde_json::to_string(&self.0).unwrap_or_else(|_| "[]".to_string())
+    }
+
+    pub fn keys(&self) -> &[String] {
+        &self.0
+    }
+
+    pub fn allows(&self, request_kind_key: &str) -> bool {
+        access_token_scope_for_request_kind(request_kind_key)
+            .is_none_or(|scope| self.0.iter().any(|key| key == scope))
+    }
+}
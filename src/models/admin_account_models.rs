@@ -0,0 +1,238 @@
+use serde::{Deserialize, Serialize};
+
+const ADMIN_ACCOUNT_USERNAME_MIN_LEN: usize = 3;
+const ADMIN_ACCOUNT_USERNAME_MAX_LEN: usize = 64;
+/// Minimum password length for named admin accounts, matching the builtin admin password.
+pub const ADMIN_ACCOUNT_PASSWORD_MIN_LEN: usize = 8;
+
+/// Role of a named admin account. Roles are ordered: every role includes the permissions of
+/// the roles below it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AdminRole {
+    /// Reads dashboards, logs and settings without changing anything.
+    Viewer,
+    /// Manages keys, tokens, users, jobs, alerts and announcements.
+    Operator,
+    /// Changes settings and HA, reveals secrets, handles recharges and refunds, and manages
+    /// admin accounts.
+    Owner,
+}
+
+impl AdminRole {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Viewer => "viewer",
+            Self::Operator => "operator",
+            Self::Owner => "owner",
+        }
+    }
+
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "viewer" => Some(Self::Viewer),
+            "operator" => Some(Self::Operator),
+            "owner" => Some(Self::Owner),
+            _ => None,
+        }
+    }
+
+    /// Whether this role may perform an action that requires `required`.
+    pub fn allows(self, required: Self) -> bool {
+        self >= required
+    }
+}
+
+/// A named admin account. Sessions opened with it are attributed to the account in audit logs
+/// and maintenance records.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdminAccount {
+    pub id: String,
+    pub username: String,
+    pub display_name: Option<String>,
+    pub role: AdminRole,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub last_login_at: Option<i64>,
+    pub disabled_at: Option<i64>,
+    pub active_sessions: i64,
+}
+
+/// Account credentials loaded for a login attempt.
+#[derive(Debug, Clone)]
+pub struct AdminAccountCredential {
+    pub account: AdminAccount,
+    pub password_hash: String,
+}
+
+/// An active admin account session together with the account it belongs to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdminAccountSession {
+    pub token: String,
+    pub account_id: String,
+    pub username: String,
+    pub display_name: Option<String>,
+    pub role: AdminRole,
+    pub created_at: i64,
+    pub expires_at: i64,
+}
+
+/// Usernames are case-insensitive; lowercase letters, digits, `.`, `-` and `_` are allowed.
+pub fn normalize_admin_account_username(raw: &str) -> Result<String, String> {
+    let username = raw.trim().to_ascii_lowercase();
+    if username.len() < ADMIN_ACCOUNT_USERNAME_MIN_LEN
+        || username.len() > ADMIN_ACCOUNT_USERNAME_MAX_LEN
+    {
+        return Err(format!(
+            "username must be {ADMIN_ACCOUNT_USERNAME_MIN_LEN}-{ADMIN_ACCOUNT_USERNAME_MAX_LEN} characters"
+        ));
+    }
+    if !username
+        .chars()
+        .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '.' | '-' | '_'))
+    {
+        return Err("username may only contain letters, digits, '.', '-' and '_'".to_string());
+    }
+    Ok(username)
+}
+
+/// Prefix of admin API tokens, formatted as `tha-<id>-<secret>`. It never collides with user
+/// access tokens (`th-<id>-<secret>`).
+pub const ADMIN_API_TOKEN_PREFIX: &str = "tha-";
+/// Most IPs or CIDR ranges one admin API token may be restricted to.
+pub const ADMIN_API_TOKEN_IP_ALLOWLIST_MAX: usize = 32;
+
+/// Scopes an admin API token can be granted. `<resource>:write` includes `<resource>:read`.
+pub const ADMIN_API_TOKEN_SCOPES: [&str; 17] = [
+    "keys:read",
+    "keys:write",
+    "tokens:read",
+    "tokens:write",
+    "users:read",
+    "users:write",
+    "logs:read",
+    "logs:write",
+    "jobs:read",
+    "jobs:write",
+    "alerts:read",
+    "alerts:write",
+    "announcements:read",
+    "announcements:write",
+    "stats:read",
+    "settings:read",
+    "settings:write",
+];
+
+/// Validated scope set of an admin API token.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct AdminApiTokenScopes(Vec<String>);
+
+impl AdminApiTokenScopes {
+    /// Validates scope keys; duplicates collapse and the order is normalized.
+    pub fn parse<I, S>(keys: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut allowed = Vec::new();
+        for key in keys {
+            let key = key.as_ref().trim();
+            let Some(scope) = ADMIN_API_TOKEN_SCOPES.iter().find(|scope| **scope == key) else {
+                return Err(format!("unsupported admin API token scope: {key}"));
+            };
+            allowed.push(*scope);
+        }
+        if allowed.is_empty() {
+            return Err("admin API tokens need at least one scope".to_string());
+        }
+        Ok(Self::from_keys(&allowed))
+    }
+
+    /// Decodes a persisted JSON list. Unknown keys are dropped so a corrupt row narrows access
+    /// instead of widening it.
+    pub fn from_stored(raw: &str) -> Self {
+        let keys = serde_json::from_str::<Vec<String>>(raw).unwrap_or_default();
+        Self::from_keys(&keys.iter().map(String::as_str).collect::<Vec<_>>())
+    }
+
+    fn from_keys(keys: &[&str]) -> Self {
+        Self(
+            ADMIN_API_TOKEN_SCOPES
+                .iter()
+                .filter(|scope| keys.contains(scope))
+                .map(|scope| scope.to_string())
+                .collect(),
+        )
+    }
+
+    pub fn to_stored(&self) -> String {
+        serde_json::to_string(&self.0).unwrap_or_else(|_| "[]".to_string())
+    }
+
+    pub fn keys(&self) -> &[String] {
+        &self.0
+    }
+
+    /// Whether the set grants `required`, counting `<resource>:write` as `<resource>:read`.
+    pub fn allows(&self, required: &str) -> bool {
+        self.0.iter().any(|key| key == required)
+            || required.strip_suffix(":read").is_some_and(|resource| {
+                self.0
+                    .iter()
+                    .any(|key| key.strip_suffix(":write") == Some(resource))
+            })
+    }
+}
+
+/// Validates an IP allowlist of plain addresses or CIDR ranges. Duplicates collapse.
+pub fn normalize_admin_api_token_ip_allowlist(values: &[String]) -> Result<Vec<String>, String> {
+    let mut out: Vec<String> = Vec::new();
+    for value in values {
+        let trimmed = value.trim();
+        if super::ParsedIpCidr::parse(trimmed).is_none() {
+            return Err(format!("invalid IP or CIDR in allowlist: {trimmed}"));
+        }
+        if !out.iter().any(|existing| existing == trimmed) {
+            out.push(trimmed.to_string());
+        }
+    }
+    if out.len() > ADMIN_API_TOKEN_IP_ALLOWLIST_MAX {
+        return Err(format!(
+            "IP allowlist may hold at most {ADMIN_API_TOKEN_IP_ALLOWLIST_MAX} entries"
+        ));
+    }
+    Ok(out)
+}
+
+/// A long-lived admin credential for automation, sent as `Authorization: Bearer tha-…`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdminApiToken {
+    pub id: String,
+    pub name: String,
+    pub scopes: AdminApiTokenScopes,
+    /// Empty when the token may be used from any address.
+    pub ip_allowlist: Vec<String>,
+    pub created_by: Option<String>,
+    pub created_at: i64,
+    pub expires_at: Option<i64>,
+    pub last_used_at: Option<i64>,
+    pub last_used_ip: Option<String>,
+    pub revoked_at: Option<i64>,
+}
+
+impl AdminApiToken {
+    /// Whether a request from `client_ip` passes the allowlist. Requests without a resolvable
+    /// client IP only pass an empty allowlist.
+    pub fn allows_ip(&self, client_ip: Option<&str>) -> bool {
+        if self.ip_allowlist.is_empty() {
+            return true;
+        }
+        let Some(ip) = client_ip.and_then(|ip| ip.parse::<std::net::IpAddr>().ok()) else {
+            return false;
+        };
+        self.ip_allowlist
+            .iter()
+            .any(|entry| super::ParsedIpCidr::parse(entry).is_some_and(|cidr| cidr.contains(ip)))
+    }
+}
@@ -0,0 +1,237 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+pub const ADMIN_AUDIT_KIND_MUTATION: &str = "mutation";
+pub const ADMIN_AUDIT_KIND_SENSITIVE_READ: &str = "sensitive_read";
+
+pub const ADMIN_AUDIT_ACTOR_DEV_OPEN: &str = "dev_open";
+pub const ADMIN_AUDIT_ACTOR_FORWARD_AUTH: &str = "forward_auth";
+pub const ADMIN_AUDIT_ACTOR_BUILTIN: &str = "builtin";
+pub const ADMIN_AUDIT_ACTOR_PASSKEY: &str = "passkey";
+pub const ADMIN_AUDIT_ACTOR_ACCOUNT: &str = "account";
+pub const ADMIN_AUDIT_ACTOR_API_TOKEN: &str = "api_token";
+
+/// `prev_hash` of the first entry in the chain.
+pub const ADMIN_AUDIT_GENESIS_HASH: &str =
+    "0000000000000000000000000000000000000000000000000000000000000000";
+
+const ADMIN_AUDIT_REDACTED: &str = "[redacted]";
+
+/// An audit record as produced by the HTTP layer, before it is sequenced and chained.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdminAuditEntryInput {
+    pub occurred_at: i64,
+    pub kind: String,
+    pub actor_kind: String,
+    pub actor_id: Option<String>,
+    pub actor_name: Option<String>,
+    pub client_ip: Option<String>,
+    pub method: String,
+    pub route: String,
+    pub path: String,
+    pub status: i64,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+/// A stored audit record. `hash` covers every other field including `prev_hash`, so editing,
+/// deleting or reordering rows breaks the chain from that point on.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AdminAuditEntry {
+    pub seq: i64,
+    pub occurred_at: i64,
+    pub kind: String,
+    pub actor_kind: String,
+    pub actor_id: Option<String>,
+    pub actor_name: Option<String>,
+    pub client_ip: Option<String>,
+    pub method: String,
+    pub route: String,
+    pub path: String,
+    pub status: i64,
+    pub before_json: Option<String>,
+    pub after_json: Option<String>,
+    pub prev_hash: String,
+    pub hash: String,
+}
+
+impl AdminAuditEntry {
+    pub fn compute_hash(&self) -> String {
+        let canonical = serde_json::json!([
+            self.seq,
+            self.occurred_at,
+            self.kind,
+            self.actor_kind,
+            self.actor_id,
+            self.actor_name,
+            self.client_ip,
+            self.method,
+            self.route,
+            self.path,
+            self.status,
+            self.before_json,
+            self.after_json,
+            self.prev_hash,
+        ]);
+        crate::sha256_hex_bytes(canonical.to_string().as_bytes())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AdminAuditFilters {
+    /// Substring match against the actor id or display name.
+    pub actor: Option<String>,
+    /// Substring match against the route template.
+    pub route: Option<String>,
+    pub kind: Option<String>,
+    pub since: Option<i64>,
+    pub until: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PaginatedAdminAuditEntries {
+    pub items: Vec<AdminAuditEntry>,
+    pub total: i64,
+    pub page: i64,
+    pub per_page: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AdminAuditVerification {
+    pub ok: bool,
+    pub entries: i64,
+    pub head_hash: String,
+    pub broken_at_seq: Option<i64>,
+    pub reason: Option<String>,
+}
+
+/// Walks the chain in `seq` order and stops recording at the first broken link.
+#[derive(Debug, Clone)]
+pub struct AdminAuditChainVerifier {
+    expected_seq: i64,
+    expected_prev_hash: String,
+    entries: i64,
+    broken_at_seq: Option<i64>,
+    reason: Option<String>,
+}
+
+impl Default for AdminAuditChainVerifier {
+    fn default() -> Self {
+        Self {
+            expected_seq: 1,
+            expected_prev_hash: ADMIN_AUDIT_GENESIS_HASH.to_string(),
+            entries: 0,
+            broken_at_seq: None,
+            reason: None,
+        }
+    }
+}
+
+impl AdminAuditChainVerifier {
+    /// Checks the next entry; returns `false` once the chain is broken.
+    pub fn push(&mut self, entry: &AdminAuditEntry) -> bool {
+        if self.broken_at_seq.is_some() {
+            return false;
+        }
+        let reason = if entry.seq != self.expected_seq {
+            Some(format!(
+                "expected seq {}, found {}",
+                self.expected_seq, entry.seq
+            ))
+        } else if entry.prev_hash != self.expected_prev_hash {
+            Some("prev_hash does not match the previous entry".to_string())
+        } else if entry.compute_hash() != entry.hash {
+            Some("entry hash does not match its contents".to_string())
+        } else {
+            None
+        };
+        if let Some(reason) = reason {
+            self.broken_at_seq = Some(entry.seq);
+            self.reason = Some(reason);
+            return false;
+        }
+        self.entries += 1;
+        self.expected_seq = entry.seq + 1;
+        self.expected_prev_hash = entry.hash.clone();
+        true
+    }
+
+    pub fn finish(self) -> AdminAuditVerification {
+        AdminAuditVerification {
+            ok: self.broken_at_seq.is_none(),
+            entries: self.entries,
+            head_hash: self.expected_prev_hash,
+            broken_at_seq: self.broken_at_seq,
+            reason: self.reason,
+        }
+    }
+}
+
+fn is_sensitive_audit_field(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    ["secret", "password", "otp", "credential", "private"]
+        .iter()
+        .any(|needle| name.contains(needle))
+        || matches!(
+            name.as_str(),
+            "api_key" | "apikey" | "api_keys" | "apikeys" | "keys"
+        )
+}
+
+/// Replaces secrets in a request or settings payload before it is written to the audit log.
+pub fn redact_admin_audit_value(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (name, field) in map.iter_mut() {
+                if is_sensitive_audit_field(name) && !field.is_null() {
+                    *field = Value::String(ADMIN_AUDIT_REDACTED.to_string());
+                } else {
+                    redact_admin_audit_value(field);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(redact_admin_audit_value),
+        Value::String(text)
+            if text.starts_with("tvly-")
+                || text.starts_with("th-")
+                || text.starts_with(super::ADMIN_API_TOKEN_PREFIX) =>
+        {
+            *text = ADMIN_AUDIT_REDACTED.to_string();
+        }
+        Value::String(text) if text.contains("://") && text.contains('@') => {
+            // Proxy and subscription URLs may embed `user:password@` credentials.
+            if let Ok(mut url) = url::Url::parse(text)
+                && url.password().is_some()
+                && url.set_password(Some(ADMIN_AUDIT_REDACTED)).is_ok()
+            {
+                *text = url.to_string();
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Reduces a before/after pair of objects to the top-level fields that changed. Other value
+/// shapes are kept whole.
+pub fn admin_audit_diff(before: Value, after: Value) -> (Option<Value>, Option<Value>) {
+    match (before, after) {
+        (Value::Object(mut before), Value::Object(mut after)) => {
+            let unchanged = before
+                .iter()
+                .filter(|(name, value)| after.get(name.as_str()) == Some(value))
+                .map(|(name, _)| name.clone())
+                .collect::<Vec<_>>();
+            for name in unchanged {
+                before.remove(&name);
+                after.remove(&name);
+            }
+            if before.is_empty() && after.is_empty() {
+                (None, None)
+            } else {
+                (Some(Value::Object(before)), Some(Value::Object(after)))
+            }
+        }
+        (before, after) => (Some(before), Some(after)),
+    }
+}
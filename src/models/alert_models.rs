@@ -8,15 +8,20 @@ pub const ALERT_TYPE_USER_REQUEST_RATE_LIMITED: &str = "user_request_rate_limite
 pub const ALERT_TYPE_USER_QUOTA_EXHAUSTED: &str = "user_quota_exhausted";
 pub const ALERT_TYPE_API_KEY_EXHAUSTED: &str = "api_key_exhausted";
 pub const ALERT_TYPE_JOB_FAILED: &str = "job_failed";
+pub const ALERT_TYPE_TOKEN_EXPIRING: &str = "token_expiring";
+pub const ALERT_TYPE_THRESHOLD_RULE: &str = "threshold_rule";
 
 pub const ALERT_SOURCE_AUTH_TOKEN_LOG: &str = "auth_token_log";
 pub const ALERT_SOURCE_API_KEY_MAINTENANCE_RECORD: &str = "api_key_maintenance_record";
 pub const ALERT_SOURCE_SCHEDULED_JOB: &str = "scheduled_job";
+pub const ALERT_SOURCE_AUTH_TOKEN_EXPIRY_NOTICE: &str = "auth_token_expiry_notice";
+pub const ALERT_SOURCE_ALERT_RULE_FIRING: &str = "alert_rule_firing";
 
 pub const ALERT_SUBJECT_USER: &str = "user";
 pub const ALERT_SUBJECT_TOKEN: &str = "token";
 pub const ALERT_SUBJECT_KEY: &str = "key";
 pub const ALERT_SUBJECT_JOB: &str = "job";
+pub const ALERT_SUBJECT_RULE: &str = "rule";
 
 pub fn is_supported_alert_type(value: &str) -> bool {
     matches!(
@@ -28,6 +33,8 @@ pub fn is_supported_alert_type(value: &str) -> bool {
             | ALERT_TYPE_USER_QUOTA_EXHAUSTED
             | ALERT_TYPE_API_KEY_EXHAUSTED
             | ALERT_TYPE_JOB_FAILED
+            | ALERT_TYPE_TOKEN_EXPIRING
+            | ALERT_TYPE_THRESHOLD_RULE
     )
 }
 
@@ -40,6 +47,8 @@ pub fn default_alert_type_counts() -> Vec<AlertTypeCount> {
         ALERT_TYPE_USER_QUOTA_EXHAUSTED,
         ALERT_TYPE_API_KEY_EXHAUSTED,
         ALERT_TYPE_JOB_FAILED,
+        ALERT_TYPE_TOKEN_EXPIRING,
+        ALERT_TYPE_THRESHOLD_RULE,
     ]
     .into_iter()
     .map(|alert_type| AlertTypeCount {
@@ -177,6 +186,9 @@ pub struct AlertGroupRecord {
     pub event_count: i64,
     pub children: Vec<AlertGroupRecord>,
     pub child_events: Vec<AlertEventRecord>,
+    /// Owner workflow of the group; only populated on alert center group pages.
+    #[serde(default)]
+    pub workflow: Option<super::AlertGroupWorkflow>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -0,0 +1,164 @@
+use crate::canonical_request_kind_key_for_filter;
+
+/// Sum of the remaining upstream credits of every active, non-quarantined key.
+pub const ALERT_RULE_METRIC_POOL_CREDITS_REMAINING: &str = "pool_credits_remaining";
+/// Percentage of request log entries with an error outcome inside the rule window.
+pub const ALERT_RULE_METRIC_REQUEST_ERROR_RATE: &str = "request_error_rate";
+/// Forward-proxy nodes that are enabled, reachable and not penalized.
+pub const ALERT_RULE_METRIC_FORWARD_PROXY_HEALTHY_NODES: &str = "forward_proxy_healthy_nodes";
+/// Largest number of HA outbox events a peer has not acknowledged, across sync channels.
+pub const ALERT_RULE_METRIC_HA_OUTBOX_ACK_LAG: &str = "ha_outbox_ack_lag";
+
+pub const ALERT_RULE_COMPARATOR_ABOVE: &str = "above";
+pub const ALERT_RULE_COMPARATOR_BELOW: &str = "below";
+
+pub const ALERT_RULE_STATE_OK: &str = "ok";
+pub const ALERT_RULE_STATE_FIRING: &str = "firing";
+
+pub const ALERT_RULE_WINDOW_SECS_DEFAULT: i64 = 15 * 60;
+const ALERT_RULE_WINDOW_SECS_MIN: i64 = 60;
+const ALERT_RULE_WINDOW_SECS_MAX: i64 = 24 * 60 * 60;
+/// An error rate over fewer requests than this is not evaluated.
+pub const ALERT_RULE_ERROR_RATE_MIN_REQUESTS: i64 = 20;
+const ALERT_RULE_NAME_MAX_LEN: usize = 80;
+
+pub fn is_supported_alert_rule_metric(value: &str) -> bool {
+    matches!(
+        value,
+        ALERT_RULE_METRIC_POOL_CREDITS_REMAINING
+            | ALERT_RULE_METRIC_REQUEST_ERROR_RATE
+            | ALERT_RULE_METRIC_FORWARD_PROXY_HEALTHY_NODES
+            | ALERT_RULE_METRIC_HA_OUTBOX_ACK_LAG
+    )
+}
+
+/// An operator-defined threshold over a metric the proxy already tracks. Breaches surface as
+/// `threshold_rule` alerts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlertRule {
+    pub id: String,
+    pub name: String,
+    pub metric: String,
+    pub comparator: String,
+    pub threshold: f64,
+    /// The rule only recovers once the value is back past this level, so a value hovering
+    /// around the threshold does not flap. Defaults to the threshold itself.
+    pub clear_threshold: Option<f64>,
+    /// Look-back window for rate metrics.
+    pub window_secs: i64,
+    /// Restricts `request_error_rate` to one request kind.
+    pub request_kind: Option<String>,
+    pub enabled: bool,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub state: String,
+    pub last_value: Option<f64>,
+    pub last_evaluated_at: Option<i64>,
+    pub last_fired_at: Option<i64>,
+}
+
+impl AlertRule {
+    fn breaches(&self, value: f64, threshold: f64) -> bool {
+        if self.comparator == ALERT_RULE_COMPARATOR_BELOW {
+            value < threshold
+        } else {
+            value > threshold
+        }
+    }
+
+    /// Whether the rule should be firing after observing `value`, given whether it fires now.
+    /// A quiet rule fires once the threshold is crossed; a firing rule keeps firing until the
+    /// value also clears the clear threshold.
+    pub fn next_firing(&self, firing: bool, value: f64) -> bool {
+        if firing {
+            self.breaches(value, self.clear_threshold.unwrap_or(self.threshold))
+        } else {
+            self.breaches(value, self.threshold)
+        }
+    }
+
+    pub fn describe_breach(&self, value: f64) -> String {
+        format!(
+            "{} {} {} {}",
+            self.metric,
+            format_alert_rule_value(value),
+            self.comparator,
+            format_alert_rule_value(self.threshold)
+        )
+    }
+}
+
+fn format_alert_rule_value(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{}", value as i64)
+    } else {
+        format!("{value:.2}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlertRuleMutation {
+    pub name: String,
+    pub metric: String,
+    pub comparator: String,
+    pub threshold: f64,
+    pub clear_threshold: Option<f64>,
+    pub window_secs: Option<i64>,
+    pub request_kind: Option<String>,
+    pub enabled: bool,
+}
+
+impl AlertRuleMutation {
+    pub(crate) fn normalized(mut self) -> Result<Self, String> {
+        self.name = self.name.trim().to_string();
+        if self.name.is_empty() {
+            return Err("rule name is required".to_string());
+        }
+        if self.name.chars().count() > ALERT_RULE_NAME_MAX_LEN {
+            return Err("rule name is too long".to_string());
+        }
+        self.metric = self.metric.trim().to_ascii_lowercase();
+        if !is_supported_alert_rule_metric(&self.metric) {
+            return Err("unsupported rule metric".to_string());
+        }
+        self.comparator = self.comparator.trim().to_ascii_lowercase();
+        if !matches!(
+            self.comparator.as_str(),
+            ALERT_RULE_COMPARATOR_ABOVE | ALERT_RULE_COMPARATOR_BELOW
+        ) {
+            return Err("comparator must be above or below".to_string());
+        }
+        if !self.threshold.is_finite() {
+            return Err("threshold must be a number".to_string());
+        }
+        if let Some(clear) = self.clear_threshold {
+            let on_recovery_side = if self.comparator == ALERT_RULE_COMPARATOR_BELOW {
+                clear >= self.threshold
+            } else {
+                clear <= self.threshold
+            };
+            if !clear.is_finite() || !on_recovery_side {
+                return Err(format!(
+                    "clear threshold must not be {} the threshold",
+                    self.comparator
+                ));
+            }
+        }
+        let window_secs = self.window_secs.unwrap_or(ALERT_RULE_WINDOW_SECS_DEFAULT);
+        if !(ALERT_RULE_WINDOW_SECS_MIN..=ALERT_RULE_WINDOW_SECS_MAX).contains(&window_secs) {
+            return Err(format!(
+                "window must be between {ALERT_RULE_WINDOW_SECS_MIN} and {ALERT_RULE_WINDOW_SECS_MAX} seconds"
+            ));
+        }
+        self.window_secs = Some(window_secs);
+        self.request_kind = self
+            .request_kind
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .map(|value| canonical_request_kind_key_for_filter(&value));
+        if self.request_kind.is_some() && self.metric != ALERT_RULE_METRIC_REQUEST_ERROR_RATE {
+            return Err("request kind only applies to request_error_rate rules".to_string());
+        }
+        Ok(self)
+    }
+}
@@ -0,0 +1,258 @@
+use chrono::{TimeZone, Utc};
+use serde_json::{Value, json};
+
+use super::{AlertEventRecord, AlertSourceRef, is_supported_alert_type};
+
+pub const ALERT_WEBHOOK_TEMPLATE_GENERIC: &str = "generic";
+pub const ALERT_WEBHOOK_TEMPLATE_SLACK: &str = "slack";
+pub const ALERT_WEBHOOK_TEMPLATE_TELEGRAM: &str = "telegram";
+
+pub const ALERT_WEBHOOK_DELIVERY_PENDING: &str = "pending";
+pub const ALERT_WEBHOOK_DELIVERY_DELIVERED: &str = "delivered";
+pub const ALERT_WEBHOOK_DELIVERY_FAILED: &str = "failed";
+
+/// Alert type carried by deliveries sent from the admin test-fire button.
+pub const ALERT_WEBHOOK_TEST_EVENT_TYPE: &str = "webhook_test";
+
+/// Carries [`sign_alert_webhook_body`] so receivers can verify the sender.
+pub const ALERT_WEBHOOK_SIGNATURE_HEADER: &str = "x-hikari-signature";
+pub const ALERT_WEBHOOK_TIMESTAMP_HEADER: &str = "x-hikari-timestamp";
+pub const ALERT_WEBHOOK_EVENT_HEADER: &str = "x-hikari-event";
+pub const ALERT_WEBHOOK_DELIVERY_HEADER: &str = "x-hikari-delivery";
+
+pub const ALERT_WEBHOOK_REPEAT_INTERVAL_SECS_DEFAULT: i64 = 5 * 60;
+pub const ALERT_WEBHOOK_REPEAT_INTERVAL_SECS_MAX: i64 = 24 * 60 * 60;
+/// Deliveries are attempted this many times before they are marked failed.
+pub const ALERT_WEBHOOK_MAX_ATTEMPTS: i64 = 6;
+const ALERT_WEBHOOK_RETRY_BASE_SECS: i64 = 30;
+const ALERT_WEBHOOK_RETRY_MAX_SECS: i64 = 60 * 60;
+const ALERT_WEBHOOK_NAME_MAX_LEN: usize = 80;
+const ALERT_WEBHOOK_URL_MAX_LEN: usize = 2048;
+
+pub fn is_supported_alert_webhook_template(value: &str) -> bool {
+    matches!(
+        value,
+        ALERT_WEBHOOK_TEMPLATE_GENERIC
+            | ALERT_WEBHOOK_TEMPLATE_SLACK
+            | ALERT_WEBHOOK_TEMPLATE_TELEGRAM
+    )
+}
+
+/// A destination that receives alert events as signed JSON POSTs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlertWebhookSink {
+    pub id: String,
+    pub name: String,
+    pub url: String,
+    pub template: String,
+    /// Subscribed alert types; empty means every type.
+    pub alert_types: Vec<String>,
+    /// Destination chat for the Telegram Bot API template.
+    pub telegram_chat_id: Option<String>,
+    pub secret: String,
+    /// Further events for the same alert type and subject within this window are not sent.
+    pub repeat_interval_secs: i64,
+    pub enabled: bool,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl AlertWebhookSink {
+    pub fn subscribes_to(&self, alert_type: &str) -> bool {
+        self.alert_types.is_empty() || self.alert_types.iter().any(|value| value == alert_type)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlertWebhookSinkMutation {
+    pub name: String,
+    pub url: String,
+    pub template: String,
+    pub alert_types: Vec<String>,
+    pub telegram_chat_id: Option<String>,
+    /// `None` keeps the current secret; a new sink without one gets a generated secret.
+    pub secret: Option<String>,
+    pub repeat_interval_secs: Option<i64>,
+    pub enabled: bool,
+}
+
+impl AlertWebhookSinkMutation {
+    pub(crate) fn normalized(mut self) -> Result<Self, String> {
+        self.name = self.name.trim().to_string();
+        if self.name.is_empty() {
+            return Err("webhook name is required".to_string());
+        }
+        if self.name.chars().count() > ALERT_WEBHOOK_NAME_MAX_LEN {
+            return Err("webhook name is too long".to_string());
+        }
+        self.url = self.url.trim().to_string();
+        if self.url.len() > ALERT_WEBHOOK_URL_MAX_LEN {
+            return Err("webhook url is too long".to_string());
+        }
+        let parsed =
+            url::Url::parse(&self.url).map_err(|_| "webhook url is invalid".to_string())?;
+        if !matches!(parsed.scheme(), "http" | "https") {
+            return Err("webhook url must use http or https".to_string());
+        }
+        self.template = self.template.trim().to_ascii_lowercase();
+        if !is_supported_alert_webhook_template(&self.template) {
+            return Err("unsupported webhook template".to_string());
+        }
+        let mut alert_types = Vec::new();
+        for value in self.alert_types {
+            let value = value.trim().to_string();
+            if !is_supported_alert_type(&value) {
+                return Err(format!("unsupported alert type: {value}"));
+            }
+            if !alert_types.contains(&value) {
+                alert_types.push(value);
+            }
+        }
+        self.alert_types = alert_types;
+        self.telegram_chat_id = self
+            .telegram_chat_id
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty());
+        if self.template == ALERT_WEBHOOK_TEMPLATE_TELEGRAM && self.telegram_chat_id.is_none() {
+            return Err("telegram webhooks require a chat id".to_string());
+        }
+        self.secret = self
+            .secret
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty());
+        if let Some(interval) = self.repeat_interval_secs
+            && !(0..=ALERT_WEBHOOK_REPEAT_INTERVAL_SECS_MAX).contains(&interval)
+        {
+            return Err(format!(
+                "repeat interval must be between 0 and {ALERT_WEBHOOK_REPEAT_INTERVAL_SECS_MAX} seconds"
+            ));
+        }
+        Ok(self)
+    }
+}
+
+/// One queued or attempted POST of an alert event to a sink.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlertWebhookDelivery {
+    pub id: i64,
+    pub sink_id: String,
+    pub event_id: String,
+    pub alert_type: String,
+    pub event_occurred_at: i64,
+    pub payload_json: String,
+    pub status: String,
+    pub attempts: i64,
+    pub next_attempt_at: Option<i64>,
+    pub last_status_code: Option<i64>,
+    pub last_error: Option<String>,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub delivered_at: Option<i64>,
+}
+
+/// Delay before the next attempt once `attempts` have failed: 30s doubling up to one hour.
+pub(crate) fn alert_webhook_retry_delay_secs(attempts: i64) -> i64 {
+    let exponent = attempts.saturating_sub(1).clamp(0, 16) as u32;
+    ALERT_WEBHOOK_RETRY_BASE_SECS
+        .saturating_mul(1_i64 << exponent)
+        .min(ALERT_WEBHOOK_RETRY_MAX_SECS)
+}
+
+/// Client errors other than timeouts and rate limits will not succeed on a retry.
+pub(crate) fn alert_webhook_status_is_retryable(status: u16) -> bool {
+    !(400..500).contains(&status) || status == 408 || status == 429
+}
+
+/// Value of the signature header: `sha256=` and the hex HMAC-SHA256 of `"{timestamp}.{body}"`
+/// keyed with the sink secret.
+pub fn sign_alert_webhook_body(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let key = ring::hmac::Key::new(ring::hmac::HMAC_SHA256, secret.as_bytes());
+    let mut ctx = ring::hmac::Context::with_key(&key);
+    ctx.update(timestamp.to_string().as_bytes());
+    ctx.update(b".");
+    ctx.update(body);
+    format!(
+        "sha256={}",
+        data_encoding::HEXLOWER.encode(ctx.sign().as_ref())
+    )
+}
+
+/// Identifies repeats of the same alert so a sink's repeat interval can suppress them.
+pub(crate) fn alert_webhook_dedupe_key(event: &AlertEventRecord) -> String {
+    format!(
+        "{}:{}:{}",
+        event.alert_type, event.subject_kind, event.subject_id
+    )
+}
+
+fn alert_webhook_text(event: &AlertEventRecord) -> String {
+    let occurred_at = Utc
+        .timestamp_opt(event.occurred_at, 0)
+        .single()
+        .map(|value| value.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+        .unwrap_or_else(|| event.occurred_at.to_string());
+    let mut text = format!("[Tavily Hikari] {}\n{}", event.title, event.summary);
+    if !event.subject_label.is_empty() {
+        text.push_str(&format!(
+            "\n{}: {}",
+            event.subject_kind, event.subject_label
+        ));
+    }
+    if let Some(reason) = event
+        .reason_summary
+        .as_deref()
+        .or(event.error_message.as_deref())
+    {
+        text.push_str(&format!("\n{reason}"));
+    }
+    text.push_str(&format!("\n{occurred_at}"));
+    text
+}
+
+/// Renders the request body a sink receives for `event`.
+pub fn render_alert_webhook_payload(sink: &AlertWebhookSink, event: &AlertEventRecord) -> Value {
+    match sink.template.as_str() {
+        ALERT_WEBHOOK_TEMPLATE_SLACK => json!({ "text": alert_webhook_text(event) }),
+        ALERT_WEBHOOK_TEMPLATE_TELEGRAM => json!({
+            "chat_id": sink.telegram_chat_id,
+            "text": alert_webhook_text(event),
+            "disable_web_page_preview": true,
+        }),
+        _ => json!({
+            "event": "alert",
+            "sink_id": sink.id,
+            "alert": event,
+        }),
+    }
+}
+
+/// Synthetic alert sent by the admin test-fire button.
+pub fn alert_webhook_test_event(sink: &AlertWebhookSink, now: i64) -> AlertEventRecord {
+    AlertEventRecord {
+        id: format!("{ALERT_WEBHOOK_TEST_EVENT_TYPE}:{}:{now}", sink.id),
+        alert_type: ALERT_WEBHOOK_TEST_EVENT_TYPE.to_string(),
+        title: "Webhook test".to_string(),
+        summary: format!("Test notification for webhook \"{}\".", sink.name),
+        occurred_at: now,
+        subject_kind: "webhook".to_string(),
+        subject_id: sink.id.clone(),
+        subject_label: sink.name.clone(),
+        user: None,
+        token: None,
+        key: None,
+        job: None,
+        request: None,
+        request_kind: None,
+        failure_kind: None,
+        result_status: None,
+        error_message: None,
+        reason_code: None,
+        reason_summary: None,
+        reason_detail: None,
+        source: AlertSourceRef {
+            kind: ALERT_WEBHOOK_TEST_EVENT_TYPE.to_string(),
+            id: sink.id.clone(),
+        },
+        semantic_window: None,
+    }
+}
@@ -0,0 +1,195 @@
+use serde::{Deserialize, Serialize};
+
+use super::{
+    ALERT_SUBJECT_JOB, ALERT_SUBJECT_KEY, ALERT_SUBJECT_RULE, ALERT_SUBJECT_TOKEN,
+    ALERT_SUBJECT_USER, AlertEventRecord, is_supported_alert_type,
+};
+
+pub const ALERT_GROUP_STATUS_OPEN: &str = "open";
+pub const ALERT_GROUP_STATUS_ACKNOWLEDGED: &str = "acknowledged";
+pub const ALERT_GROUP_STATUS_RESOLVED: &str = "resolved";
+
+pub const ALERT_GROUP_ACTION_ACKNOWLEDGE: &str = "acknowledge";
+pub const ALERT_GROUP_ACTION_RESOLVE: &str = "resolve";
+pub const ALERT_GROUP_ACTION_REOPEN: &str = "reopen";
+
+const ALERT_WORKFLOW_NOTE_MAX_LEN: usize = 500;
+const ALERT_SILENCE_MAX_DURATION_SECS: i64 = 30 * 24 * 60 * 60;
+
+/// Owner workflow state of an alert group. Groups without a stored state are `open`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AlertGroupWorkflow {
+    pub status: String,
+    pub actor: Option<String>,
+    pub note: Option<String>,
+    pub updated_at: Option<i64>,
+    /// Set when a resolved group received new events after it was resolved.
+    pub reopened: bool,
+}
+
+impl AlertGroupWorkflow {
+    /// Derives the effective workflow of a group from its stored state. A resolved group
+    /// reopens as soon as an event newer than the resolution arrives.
+    pub fn effective(
+        status: &str,
+        actor: Option<String>,
+        note: Option<String>,
+        updated_at: i64,
+        group_last_seen: i64,
+    ) -> Self {
+        let reopened = status == ALERT_GROUP_STATUS_RESOLVED && group_last_seen > updated_at;
+        Self {
+            status: if reopened {
+                ALERT_GROUP_STATUS_OPEN.to_string()
+            } else {
+                status.to_string()
+            },
+            actor,
+            note,
+            updated_at: Some(updated_at),
+            reopened,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlertGroupWorkflowEvent {
+    pub id: String,
+    pub group_id: String,
+    pub action: String,
+    pub actor: String,
+    pub note: Option<String>,
+    pub created_at: i64,
+}
+
+pub fn alert_group_status_for_action(action: &str) -> Option<&'static str> {
+    match action {
+        ALERT_GROUP_ACTION_ACKNOWLEDGE => Some(ALERT_GROUP_STATUS_ACKNOWLEDGED),
+        ALERT_GROUP_ACTION_RESOLVE => Some(ALERT_GROUP_STATUS_RESOLVED),
+        ALERT_GROUP_ACTION_REOPEN => Some(ALERT_GROUP_STATUS_OPEN),
+        _ => None,
+    }
+}
+
+pub(crate) fn normalize_alert_workflow_note(
+    note: Option<String>,
+) -> Result<Option<String>, String> {
+    let note = note
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty());
+    if note
+        .as_deref()
+        .is_some_and(|value| value.chars().count() > ALERT_WORKFLOW_NOTE_MAX_LEN)
+    {
+        return Err("note is too long".to_string());
+    }
+    Ok(note)
+}
+
+/// A time-bound mute. Matching events are still recorded but stay out of Dashboard counts and
+/// webhook notifications.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlertSilence {
+    pub id: String,
+    /// `None` matches every alert type.
+    pub alert_type: Option<String>,
+    /// `key`, `token`, `user`, `job` or `rule`; `None` matches every subject.
+    pub subject_kind: Option<String>,
+    pub subject_id: Option<String>,
+    pub starts_at: i64,
+    pub ends_at: i64,
+    pub actor: String,
+    pub note: Option<String>,
+    pub created_at: i64,
+}
+
+impl AlertSilence {
+    pub fn is_active(&self, now: i64) -> bool {
+        self.starts_at <= now && now < self.ends_at
+    }
+
+    /// Whether the silence covers `event`. A subject matches when the event references that
+    /// entity, so muting a key also mutes token alerts routed through it.
+    pub fn matches(&self, event: &AlertEventRecord) -> bool {
+        if event.occurred_at < self.starts_at || event.occurred_at >= self.ends_at {
+            return false;
+        }
+        if self
+            .alert_type
+            .as_deref()
+            .is_some_and(|alert_type| alert_type != event.alert_type)
+        {
+            return false;
+        }
+        let (Some(kind), Some(id)) = (self.subject_kind.as_deref(), self.subject_id.as_deref())
+        else {
+            return true;
+        };
+        let referenced = match kind {
+            ALERT_SUBJECT_KEY => event.key.as_ref().is_some_and(|key| key.id == id),
+            ALERT_SUBJECT_TOKEN => event.token.as_ref().is_some_and(|token| token.id == id),
+            ALERT_SUBJECT_USER => event.user.as_ref().is_some_and(|user| user.user_id == id),
+            ALERT_SUBJECT_JOB => event
+                .job
+                .as_ref()
+                .is_some_and(|job| job.id.to_string() == id),
+            _ => false,
+        };
+        referenced || (event.subject_kind == kind && event.subject_id == id)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlertSilenceMutation {
+    pub alert_type: Option<String>,
+    pub subject_kind: Option<String>,
+    pub subject_id: Option<String>,
+    pub starts_at: Option<i64>,
+    pub ends_at: i64,
+    pub note: Option<String>,
+}
+
+impl AlertSilenceMutation {
+    pub(crate) fn normalized(mut self, now: i64) -> Result<Self, String> {
+        let trimmed = |value: Option<String>| {
+            value
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        self.alert_type = trimmed(self.alert_type).map(|value| value.to_ascii_lowercase());
+        if self
+            .alert_type
+            .as_deref()
+            .is_some_and(|alert_type| !is_supported_alert_type(alert_type))
+        {
+            return Err("unsupported alert type".to_string());
+        }
+        self.subject_kind = trimmed(self.subject_kind).map(|value| value.to_ascii_lowercase());
+        self.subject_id = trimmed(self.subject_id);
+        match (self.subject_kind.as_deref(), self.subject_id.as_deref()) {
+            (None, None) => {}
+            (
+                Some(
+                    ALERT_SUBJECT_KEY | ALERT_SUBJECT_TOKEN | ALERT_SUBJECT_USER
+                    | ALERT_SUBJECT_JOB | ALERT_SUBJECT_RULE,
+                ),
+                Some(_),
+            ) => {}
+            (Some(_), Some(_)) => return Err("unsupported silence subject kind".to_string()),
+            _ => return Err("subject kind and subject id must be set together".to_string()),
+        }
+        if self.alert_type.is_none() && self.subject_kind.is_none() {
+            return Err("a silence needs an alert type or a subject".to_string());
+        }
+        let starts_at = self.starts_at.unwrap_or(now);
+        if self.ends_at <= starts_at || self.ends_at <= now {
+            return Err("silence must end in the future and after it starts".to_string());
+        }
+        if self.ends_at - starts_at > ALERT_SILENCE_MAX_DURATION_SECS {
+            return Err("silence cannot last longer than 30 days".to_string());
+        }
+        self.starts_at = Some(starts_at);
+        self.note = normalize_alert_workflow_note(self.note)?;
+        Ok(self)
+    }
+}
@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+/// Endpoints that retry on a different key unless the admin narrows the list.
+pub const CROSS_KEY_RETRY_DEFAULT_ENDPOINTS: &[&str] = &["search", "extract", "map"];
+/// Idempotent endpoints that may opt into cross-key retries. `research` creates upstream tasks
+/// and is never retried.
+pub const CROSS_KEY_RETRY_SUPPORTED_ENDPOINTS: &[&str] = &["search", "extract", "crawl", "map"];
+
+/// Opt-in policy for replaying a failed HTTP API call on another upstream key.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CrossKeyRetrySettings {
+    pub enabled: bool,
+    pub endpoints: Vec<String>,
+    /// Upper bound on upstream attempts per request, including the first one.
+    pub max_attempts: i64,
+    /// No further attempt starts once this much time has passed since the first one.
+    pub budget_ms: i64,
+}
+
+impl Default for CrossKeyRetrySettings {
+    fn default() -> Self {
+        Self {
+            enabled: CROSS_KEY_RETRY_ENABLED_DEFAULT,
+            endpoints: CROSS_KEY_RETRY_DEFAULT_ENDPOINTS
+                .iter()
+                .map(|value| (*value).to_string())
+                .collect(),
+            max_attempts: CROSS_KEY_RETRY_MAX_ATTEMPTS_DEFAULT,
+            budget_ms: CROSS_KEY_RETRY_BUDGET_MS_DEFAULT,
+        }
+    }
+}
+
+impl CrossKeyRetrySettings {
+    /// Whether a request to `upstream_path` (for example `/search`) may be retried.
+    pub fn covers_upstream_path(&self, upstream_path: &str) -> bool {
+        let endpoint = upstream_path.trim_start_matches('/');
+        self.enabled
+            && CROSS_KEY_RETRY_SUPPORTED_ENDPOINTS.contains(&endpoint)
+            && self.endpoints.iter().any(|value| value == endpoint)
+    }
+}
+
+/// Upstream statuses worth replaying on another key: rate limits, exhausted plan credits and
+/// upstream server errors. Validation errors would fail the same way on every key.
+pub(crate) fn is_cross_key_retryable_status(code: i64) -> bool {
+    matches!(code, 429 | 432 | 433 | 500..=599)
+}
+
+pub fn normalize_cross_key_retry_settings(
+    settings: &CrossKeyRetrySettings,
+) -> Result<CrossKeyRetrySettings, ProxyError> {
+    if !(CROSS_KEY_RETRY_MAX_ATTEMPTS_MIN..=CROSS_KEY_RETRY_MAX_ATTEMPTS_MAX)
+        .contains(&settings.max_attempts)
+    {
+        return Err(ProxyError::Other(format!(
+            "cross_key_retry.max_attempts must be between {CROSS_KEY_RETRY_MAX_ATTEMPTS_MIN} and {CROSS_KEY_RETRY_MAX_ATTEMPTS_MAX}",
+        )));
+    }
+    if !(CROSS_KEY_RETRY_BUDGET_MS_MIN..=CROSS_KEY_RETRY_BUDGET_MS_MAX)
+        .contains(&settings.budget_ms)
+    {
+        return Err(ProxyError::Other(format!(
+            "cross_key_retry.budget_ms must be between {CROSS_KEY_RETRY_BUDGET_MS_MIN} and {CROSS_KEY_RETRY_BUDGET_MS_MAX}",
+        )));
+    }
+    let mut endpoints = Vec::with_capacity(settings.endpoints.len());
+    for value in &settings.endpoints {
+        let endpoint = value.trim().trim_start_matches('/').to_ascii_lowercase();
+        if !CROSS_KEY_RETRY_SUPPORTED_ENDPOINTS.contains(&endpoint.as_str()) {
+            return Err(ProxyError::Other(format!(
+                "cross_key_retry.endpoints does not support '{endpoint}'",
+            )));
+        }
+        if !endpoints.contains(&endpoint) {
+            endpoints.push(endpoint);
+        }
+    }
+    endpoints.sort_by_key(|endpoint| {
+        CROSS_KEY_RETRY_SUPPORTED_ENDPOINTS
+            .iter()
+            .position(|candidate| *candidate == endpoint)
+    });
+    Ok(CrossKeyRetrySettings {
+        enabled: settings.enabled,
+        endpoints,
+        max_attempts: settings.max_attempts,
+        budget_ms: settings.budget_ms,
+    })
+}
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+/// Binding subject: every token whose `auth_tokens.group_name` matches the value.
+pub const KEY_GROUP_BINDING_TOKEN_GROUP: &str = "token_group";
+/// Binding subject: every token owned by a user carrying the named user tag.
+pub const KEY_GROUP_BINDING_USER_TAG: &str = "user_tag";
+
+pub fn is_supported_key_group_binding_kind(value: &str) -> bool {
+    matches!(
+        value,
+        KEY_GROUP_BINDING_TOKEN_GROUP | KEY_GROUP_BINDING_USER_TAG
+    )
+}
+
+/// Selection priority of one API key group. Higher tiers are drained first; ungrouped keys and
+/// groups without an explicit priority sit at tier `0`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiKeyGroupTier {
+    pub group: String,
+    pub priority: i64,
+}
+
+/// Restricts the keys a token may use to the listed key groups.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiKeyGroupBinding {
+    pub kind: String,
+    pub value: String,
+    pub key_groups: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiKeyGroupRouting {
+    pub tiers: Vec<ApiKeyGroupTier>,
+    pub bindings: Vec<ApiKeyGroupBinding>,
+}
+
+/// Key pool capacity aggregated per selection tier.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ApiKeyTierCapacity {
+    pub priority: i64,
+    pub groups: Vec<String>,
+    pub total_keys: i64,
+    /// Active keys that are neither low-quota depleted, quarantined nor cooling down.
+    pub available_keys: i64,
+    pub cooling_keys: i64,
+    pub exhausted_keys: i64,
+    pub quarantined_keys: i64,
+    pub quota_limit: i64,
+    pub quota_remaining: i64,
+}
@@ -0,0 +1,15 @@
+/// Live view of one API key's local requests-per-minute budget. Counters are in-process and
+/// reset when the instance restarts.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ApiKeyRateBudgetUsage {
+    pub key_id: String,
+    pub rpm_limit: i64,
+    /// Upstream requests admitted for this key during the trailing 60 seconds.
+    pub used_last_minute: i64,
+    /// Requests currently waiting for budget headroom on this key.
+    pub queued: i64,
+    /// Requests that had to wait before they were admitted.
+    pub delayed_total: i64,
+    /// Requests that gave up after the bounded wait without being forwarded.
+    pub rejected_total: i64,
+}
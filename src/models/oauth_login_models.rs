@@ -0,0 +1,16 @@
+/// PKCE verifier and ID token nonce stored with an OIDC login state until it is consumed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OAuthLoginPkce {
+    pub code_verifier: String,
+    pub nonce: String,
+}
+
+/// Payload returned from OAuth state consume operation.
+#[derive(Debug, Clone)]
+pub struct OAuthLoginStatePayload {
+    pub redirect_to: Option<String>,
+    pub bind_token_id: Option<String>,
+    pub pkce: Option<OAuthLoginPkce>,
+    /// Registration invite code the user entered before starting the login.
+    pub invite_code: Option<String>,
+}
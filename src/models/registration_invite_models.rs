@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+
+/// Upper bound for how many accounts a single invite code may register.
+pub const REGISTRATION_INVITE_MAX_USES_LIMIT: i64 = 10_000;
+const REGISTRATION_INVITE_CODE_MIN_LEN: usize = 6;
+const REGISTRATION_INVITE_CODE_MAX_LEN: usize = 64;
+
+/// Account base quota an invite assigns to the users it registers, replacing the zero base
+/// that new accounts otherwise start from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegistrationInviteQuota {
+    pub business_calls_1h_limit: i64,
+    pub daily_credits_limit: i64,
+    pub monthly_credits_limit: i64,
+}
+
+/// What an admin submits when issuing an invite. A missing code is generated server-side.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RegistrationInviteInput {
+    pub code: Option<String>,
+    pub note: Option<String>,
+    pub max_uses: i64,
+    pub expires_at: Option<i64>,
+    pub tag_ids: Vec<String>,
+    pub quota: Option<RegistrationInviteQuota>,
+}
+
+/// An admin-issued invite code. Invites let first-time OAuth logins register while
+/// registration is paused and skip the approval queue.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegistrationInvite {
+    pub id: String,
+    pub code: String,
+    pub note: Option<String>,
+    pub max_uses: i64,
+    pub use_count: i64,
+    pub expires_at: Option<i64>,
+    pub tag_ids: Vec<String>,
+    pub quota: Option<RegistrationInviteQuota>,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub revoked_at: Option<i64>,
+}
+
+impl RegistrationInvite {
+    /// `active`, `revoked`, `expired` or `exhausted`, in that order of precedence.
+    pub fn status(&self, now: i64) -> &'static str {
+        if self.revoked_at.is_some() {
+            "revoked"
+        } else if self.expires_at.is_some_and(|expires_at| expires_at <= now) {
+            "expired"
+        } else if self.use_count >= self.max_uses {
+            "exhausted"
+        } else {
+            "active"
+        }
+    }
+}
+
+/// A user who signed in while registration required approval and still has no token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingApprovalUser {
+    pub user_id: String,
+    pub display_name: Option<String>,
+    pub username: Option<String>,
+    pub provider: Option<String>,
+    pub requested_at: i64,
+}
+
+/// Invite codes are compared case-insensitively; letters, digits, `-` and `_` are allowed.
+pub fn normalize_registration_invite_code(raw: &str) -> Result<String, String> {
+    let code = raw.trim().to_ascii_uppercase();
+    if code.len() < REGISTRATION_INVITE_CODE_MIN_LEN
+        || code.len() > REGISTRATION_INVITE_CODE_MAX_LEN
+    {
+        return Err(format!(
+            "invite code must be {REGISTRATION_INVITE_CODE_MIN_LEN}-{REGISTRATION_INVITE_CODE_MAX_LEN} characters"
+        ));
+    }
+    if !code
+        .chars()
+        .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_')
+    {
+        return Err("invite code may only contain letters, digits, '-' and '_'".to_string());
+    }
+    Ok(code)
+}
+
+pub fn validate_registration_invite_input(
+    input: &RegistrationInviteInput,
+    now: i64,
+) -> Result<(), String> {
+    if !(1..=REGISTRATION_INVITE_MAX_USES_LIMIT).contains(&input.max_uses) {
+        return Err(format!(
+            "maxUses must be between 1 and {REGISTRATION_INVITE_MAX_USES_LIMIT}"
+        ));
+    }
+    if input.expires_at.is_some_and(|expires_at| expires_at <= now) {
+        return Err("expiresAt must be in the future".to_string());
+    }
+    if let Some(quota) = input.quota
+        && (quota.business_calls_1h_limit < 0
+            || quota.daily_credits_limit < 0
+            || quota.monthly_credits_limit < 0)
+    {
+        return Err("invite quota limits must not be negative".to_string());
+    }
+    if let Some(code) = input.code.as_deref() {
+        normalize_registration_invite_code(code)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::*;
+
+/// Endpoints whose identical concurrent calls may share one upstream request. `research`
+/// creates upstream tasks and is never coalesced.
+pub const REQUEST_COALESCING_SUPPORTED_ENDPOINTS: &[&str] = &["search", "extract", "crawl", "map"];
+
+/// Opt-in single-flight policy: identical HTTP API and rebalance MCP calls that arrive while
+/// one of them is still upstream wait for it and share its response.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestCoalescingSettings {
+    pub enabled: bool,
+    pub endpoints: Vec<String>,
+    /// Share of the shared response's credits charged to each waiting request; the request
+    /// that went upstream always pays in full. 100 bills everyone as if they went alone.
+    pub follower_billing_percent: i64,
+}
+
+impl Default for RequestCoalescingSettings {
+    fn default() -> Self {
+        Self {
+            enabled: REQUEST_COALESCING_ENABLED_DEFAULT,
+            endpoints: REQUEST_COALESCING_SUPPORTED_ENDPOINTS
+                .iter()
+                .map(|value| (*value).to_string())
+                .collect(),
+            follower_billing_percent: REQUEST_COALESCING_FOLLOWER_BILLING_PERCENT_DEFAULT,
+        }
+    }
+}
+
+impl RequestCoalescingSettings {
+    /// Whether concurrent calls to `upstream_path` (for example `/search`) are coalesced.
+    pub fn covers_upstream_path(&self, upstream_path: &str) -> bool {
+        let endpoint = upstream_path.trim_start_matches('/');
+        self.enabled
+            && REQUEST_COALESCING_SUPPORTED_ENDPOINTS.contains(&endpoint)
+            && self.endpoints.iter().any(|value| value == endpoint)
+    }
+
+    /// Credits billed to a waiting request whose shared response cost `original_credits`,
+    /// rounded half up.
+    pub fn follower_credits(&self, original_credits: i64) -> i64 {
+        (original_credits.max(0) * self.follower_billing_percent + 50) / 100
+    }
+}
+
+/// In-flight key for one upstream call. It uses the response cache's canonical form, so HTTP
+/// and MCP calls with the same endpoint and arguments join the same flight.
+pub fn request_coalescing_key(upstream_path: &str, options: &Value) -> String {
+    response_cache_key(upstream_path, options)
+}
+
+pub fn normalize_request_coalescing_settings(
+    settings: &RequestCoalescingSettings,
+) -> Result<RequestCoalescingSettings, ProxyError> {
+    if !(REQUEST_COALESCING_FOLLOWER_BILLING_PERCENT_MIN
+        ..=REQUEST_COALESCING_FOLLOWER_BILLING_PERCENT_MAX)
+        .contains(&settings.follower_billing_percent)
+    {
+        return Err(ProxyError::Other(format!(
+            "request_coalescing.follower_billing_percent must be between {REQUEST_COALESCING_FOLLOWER_BILLING_PERCENT_MIN} and {REQUEST_COALESCING_FOLLOWER_BILLING_PERCENT_MAX}",
+        )));
+    }
+    let mut endpoints = Vec::with_capacity(settings.endpoints.len());
+    for value in &settings.endpoints {
+        let endpoint = value.trim().trim_start_matches('/').to_ascii_lowercase();
+        if !REQUEST_COALESCING_SUPPORTED_ENDPOINTS.contains(&endpoint.as_str()) {
+            return Err(ProxyError::Other(format!(
+                "request_coalescing.endpoints does not support '{endpoint}'",
+            )));
+        }
+        if !endpoints.contains(&endpoint) {
+            endpoints.push(endpoint);
+        }
+    }
+    endpoints.sort_by_key(|endpoint| {
+        REQUEST_COALESCING_SUPPORTED_ENDPOINTS
+            .iter()
+            .position(|candidate| *candidate == endpoint)
+    });
+    Ok(RequestCoalescingSettings {
+        enabled: settings.enabled,
+        endpoints,
+        follower_billing_percent: settings.follower_billing_percent,
+    })
+}
@@ -0,0 +1,62 @@
+use serde_json::Value;
+use url::Url;
+
+/// Logged Tavily HTTP API paths share this prefix; everything after it is the upstream path.
+const REQUEST_LOG_REPLAY_PATH_PREFIX: &str = "/api/tavily";
+
+/// What an admin asked to replay. Unset fields fall back to the logged request: its key, the
+/// primary Tavily base and the stored request body.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RequestLogReplayInput {
+    pub api_key_id: Option<String>,
+    pub upstream_base: Option<String>,
+    pub body: Option<Value>,
+}
+
+/// The logged request a replay starts from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestLogReplaySource {
+    pub log_id: i64,
+    pub api_key_id: Option<String>,
+    pub method: String,
+    pub path: String,
+    pub query: Option<String>,
+    pub request_body: Option<Vec<u8>>,
+}
+
+/// A stored replay. Replays never touch billing, quotas or `request_logs`; they only live in
+/// this diagnostics table and disappear together with their source log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestLogReplay {
+    pub id: i64,
+    pub source_log_id: i64,
+    pub api_key_id: String,
+    pub upstream_base: String,
+    pub method: String,
+    pub path: String,
+    pub status_code: Option<i64>,
+    pub duration_ms: i64,
+    pub request_body: Option<Vec<u8>>,
+    pub response_body: Option<Vec<u8>>,
+    pub error_message: Option<String>,
+    pub created_at: i64,
+}
+
+/// Upstream path for a logged Tavily HTTP API request (`/api/tavily/search` -> `/search`).
+/// MCP and other non-HTTP-API logs cannot be replayed and return `None`.
+pub fn request_log_replay_upstream_path(path: &str) -> Option<&str> {
+    let upstream = path.strip_prefix(REQUEST_LOG_REPLAY_PATH_PREFIX)?;
+    (upstream.len() > 1 && upstream.starts_with('/')).then_some(upstream)
+}
+
+/// Normalizes a configured alternate replay base, rejecting anything that is not an absolute
+/// http(s) URL.
+pub fn normalize_request_log_replay_base(raw: &str) -> Result<String, String> {
+    let trimmed = raw.trim().trim_end_matches('/');
+    let url =
+        Url::parse(trimmed).map_err(|err| format!("invalid replay upstream {raw:?}: {err}"))?;
+    if !matches!(url.scheme(), "http" | "https") || url.host_str().is_none() {
+        return Err(format!("replay upstream {raw:?} must be an http(s) URL"));
+    }
+    Ok(trimmed.to_string())
+}
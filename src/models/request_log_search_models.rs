@@ -0,0 +1,17 @@
+/// The trigram tokenizer cannot match anything shorter than three characters.
+pub const REQUEST_LOG_SEARCH_MIN_TERM_CHARS: usize = 3;
+pub const REQUEST_LOG_SEARCH_MAX_TERMS: usize = 8;
+
+/// Turns free-form admin search input into an FTS5 `MATCH` expression. Every whitespace
+/// separated term becomes a quoted phrase and all of them must match, so operators and column
+/// filters typed by the user are searched literally. Returns `None` when no term is long enough
+/// to be searchable.
+pub fn request_log_search_match_query(raw: &str) -> Option<String> {
+    let terms = raw
+        .split_whitespace()
+        .filter(|term| term.chars().count() >= REQUEST_LOG_SEARCH_MIN_TERM_CHARS)
+        .take(REQUEST_LOG_SEARCH_MAX_TERMS)
+        .map(|term| format!("\"{}\"", term.replace('"', "\"\"")))
+        .collect::<Vec<_>>();
+    (!terms.is_empty()).then(|| terms.join(" "))
+}
@@ -0,0 +1,411 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// What a parameter policy does with a request that asks for more than it allows.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RequestParameterPolicyMode {
+    /// Refuse the request with a structured `400`.
+    #[default]
+    Reject,
+    /// Lower over-limit parameters to the allowed maximum and forward the request.
+    Clamp,
+}
+
+/// Where a parameter policy is attached. A token's own policy wins over its group's, which wins
+/// over the policies of the tags on the token's owner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RequestParameterPolicySubject {
+    Token,
+    TokenGroup,
+    UserTag,
+}
+
+impl RequestParameterPolicySubject {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Token => "token",
+            Self::TokenGroup => "token_group",
+            Self::UserTag => "user_tag",
+        }
+    }
+
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.trim() {
+            "token" => Some(Self::Token),
+            "token_group" => Some(Self::TokenGroup),
+            "user_tag" => Some(Self::UserTag),
+            _ => None,
+        }
+    }
+}
+
+const SEARCH_DEPTH_ORDER: [&str; 4] = ["ultra-fast", "fast", "basic", "advanced"];
+const EXTRACT_DEPTH_ORDER: [&str; 2] = ["basic", "advanced"];
+const RESEARCH_MODELS: [&str; 3] = ["mini", "auto", "pro"];
+
+// Upstream defaults for omitted parameters; a cap below the default still applies to them.
+const DEFAULT_SEARCH_DEPTH: &str = "basic";
+const DEFAULT_EXTRACT_DEPTH: &str = "basic";
+const DEFAULT_MAX_RESULTS: i64 = 5;
+const DEFAULT_CRAWL_LIMIT: i64 = 50;
+const DEFAULT_CRAWL_DEPTH: i64 = 1;
+const DEFAULT_RESEARCH_MODEL: &str = "auto";
+
+/// Caps on the Tavily request parameters that drive credit cost. Unset fields impose no limit.
+/// Omitted request parameters are treated as their upstream default, and are filled in with the
+/// cap when that default would exceed it, whatever the mode.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RequestParameterPolicy {
+    pub mode: RequestParameterPolicyMode,
+    /// Deepest `search_depth` allowed on search (`ultra-fast` < `fast` < `basic` < `advanced`).
+    pub max_search_depth: Option<String>,
+    /// Deepest `extract_depth` allowed on extract and crawl.
+    pub max_extract_depth: Option<String>,
+    pub max_results: Option<i64>,
+    /// `Some(false)` forbids `include_raw_content` on search.
+    pub allow_raw_content: Option<bool>,
+    /// Cap on `limit` for crawl and map.
+    pub max_crawl_limit: Option<i64>,
+    /// Cap on `max_depth` for crawl and map.
+    pub max_crawl_depth: Option<i64>,
+    /// Research models that may be requested. A disallowed model is rejected even in clamp mode.
+    pub allowed_research_models: Option<Vec<String>>,
+    /// Upper bound on the expected credits of one request, checked after clamping.
+    pub max_expected_credits: Option<i64>,
+}
+
+/// The first parameter that broke a policy, reported back to the caller.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestParameterPolicyViolation {
+    pub parameter: String,
+    pub message: String,
+}
+
+impl RequestParameterPolicyViolation {
+    fn new(parameter: &str, message: String) -> Self {
+        Self {
+            parameter: parameter.to_string(),
+            message,
+        }
+    }
+}
+
+/// A stored policy together with the subject it is attached to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestParameterPolicyRecord {
+    pub subject: RequestParameterPolicySubject,
+    pub subject_id: String,
+    pub policy: RequestParameterPolicy,
+    pub updated_at: i64,
+}
+
+impl RequestParameterPolicy {
+    /// Validates the caps and canonicalizes enum values to lowercase.
+    pub fn normalized(mut self) -> Result<Self, String> {
+        self.max_search_depth =
+            normalize_rank("maxSearchDepth", self.max_search_depth, &SEARCH_DEPTH_ORDER)?;
+        self.max_extract_depth = normalize_rank(
+            "maxExtractDepth",
+            self.max_extract_depth,
+            &EXTRACT_DEPTH_ORDER,
+        )?;
+        for (field, value, min) in [
+            ("maxResults", self.max_results, 0),
+            ("maxCrawlLimit", self.max_crawl_limit, 1),
+            ("maxCrawlDepth", self.max_crawl_depth, 1),
+            ("maxExpectedCredits", self.max_expected_credits, 1),
+        ] {
+            if value.is_some_and(|value| value < min) {
+                return Err(format!("{field} must be at least {min}"));
+            }
+        }
+        if let Some(models) = self.allowed_research_models.take() {
+            let mut allowed = Vec::new();
+            for model in models {
+                let model = model.trim().to_ascii_lowercase();
+                if !RESEARCH_MODELS.contains(&model.as_str()) {
+                    return Err(format!("unsupported research model: {model}"));
+                }
+                if !allowed.contains(&model) {
+                    allowed.push(model);
+                }
+            }
+            self.allowed_research_models = Some(allowed);
+        }
+        Ok(self)
+    }
+
+    /// Applies the caps for one Tavily operation (`search`, `extract`, `crawl`, `map` or
+    /// `research`) to its JSON arguments. Returns whether the arguments were rewritten.
+    pub fn apply(
+        &self,
+        operation: &str,
+        options: &mut Value,
+    ) -> Result<bool, RequestParameterPolicyViolation> {
+        let Value::Object(map) = options else {
+            return Ok(false);
+        };
+        let mut changed = false;
+        match operation {
+            "search" => {
+                if let Some(max) = self.max_search_depth.as_deref() {
+                    changed |= self.cap_rank(
+                        map,
+                        "search_depth",
+                        &SEARCH_DEPTH_ORDER,
+                        DEFAULT_SEARCH_DEPTH,
+                        max,
+                    )?;
+                }
+                if let Some(max) = self.max_results {
+                    changed |= self.cap_number(map, "max_results", DEFAULT_MAX_RESULTS, max)?;
+                }
+                if self.allow_raw_content == Some(false)
+                    && map
+                        .get("include_raw_content")
+                        .is_some_and(raw_content_requested)
+                {
+                    if self.mode == RequestParameterPolicyMode::Reject {
+                        return Err(RequestParameterPolicyViolation::new(
+                            "include_raw_content",
+                            "include_raw_content is not allowed for this token".to_string(),
+                        ));
+                    }
+                    map.insert("include_raw_content".to_string(), Value::Bool(false));
+                    changed = true;
+                }
+            }
+            "extract" | "crawl" | "map" => {
+                if operation != "map"
+                    && let Some(max) = self.max_extract_depth.as_deref()
+                {
+                    changed |= self.cap_rank(
+                        map,
+                        "extract_depth",
+                        &EXTRACT_DEPTH_ORDER,
+                        DEFAULT_EXTRACT_DEPTH,
+                        max,
+                    )?;
+                }
+                if operation != "extract" {
+                    if let Some(max) = self.max_crawl_limit {
+                        changed |= self.cap_number(map, "limit", DEFAULT_CRAWL_LIMIT, max)?;
+                    }
+                    if let Some(max) = self.max_crawl_depth {
+                        changed |= self.cap_number(map, "max_depth", DEFAULT_CRAWL_DEPTH, max)?;
+                    }
+                }
+            }
+            "research" => {
+                if let Some(allowed) = self.allowed_research_models.as_ref() {
+                    let model = match map.get("model") {
+                        None => DEFAULT_RESEARCH_MODEL.to_string(),
+                        Some(Value::String(model)) if model.trim().is_empty() => {
+                            DEFAULT_RESEARCH_MODEL.to_string()
+                        }
+                        Some(Value::String(model)) => model.trim().to_ascii_lowercase(),
+                        // Malformed models are left to the endpoint's own validation.
+                        Some(_) => return Ok(changed),
+                    };
+                    if RESEARCH_MODELS.contains(&model.as_str()) && !allowed.contains(&model) {
+                        return Err(RequestParameterPolicyViolation::new(
+                            "model",
+                            format!(
+                                "research model `{model}` is not allowed; allowed models: {}",
+                                allowed.join(", ")
+                            ),
+                        ));
+                    }
+                }
+            }
+            _ => {}
+        }
+        Ok(changed)
+    }
+
+    /// Rejects a request whose expected credit cost is above `max_expected_credits`.
+    pub fn check_expected_credits(
+        &self,
+        expected_credits: i64,
+    ) -> Result<(), RequestParameterPolicyViolation> {
+        match self.max_expected_credits {
+            Some(max) if expected_credits > max => Err(RequestParameterPolicyViolation::new(
+                "expected_credits",
+                format!(
+                    "request is expected to cost {expected_credits} credits; this token allows at most {max}"
+                ),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Combines two policies so that every cap is at least as strict as in either of them.
+    pub fn merge_strictest(mut self, other: &Self) -> Self {
+        if other.mode == RequestParameterPolicyMode::Reject {
+            self.mode = RequestParameterPolicyMode::Reject;
+        }
+        self.max_search_depth = min_rank(
+            self.max_search_depth,
+            other.max_search_depth.as_deref(),
+            &SEARCH_DEPTH_ORDER,
+        );
+        self.max_extract_depth = min_rank(
+            self.max_extract_depth,
+            other.max_extract_depth.as_deref(),
+            &EXTRACT_DEPTH_ORDER,
+        );
+        self.max_results = min_option(self.max_results, other.max_results);
+        self.allow_raw_content = match (self.allow_raw_content, other.allow_raw_content) {
+            (Some(false), _) | (_, Some(false)) => Some(false),
+            (left, right) => left.or(right),
+        };
+        self.max_crawl_limit = min_option(self.max_crawl_limit, other.max_crawl_limit);
+        self.max_crawl_depth = min_option(self.max_crawl_depth, other.max_crawl_depth);
+        self.allowed_research_models = match (
+            self.allowed_research_models.take(),
+            other.allowed_research_models.as_ref(),
+        ) {
+            (Some(left), Some(right)) => Some(
+                left.into_iter()
+                    .filter(|model| right.contains(model))
+                    .collect(),
+            ),
+            (left, right) => left.or_else(|| right.cloned()),
+        };
+        self.max_expected_credits =
+            min_option(self.max_expected_credits, other.max_expected_credits);
+        self
+    }
+
+    fn cap_rank(
+        &self,
+        map: &mut Map<String, Value>,
+        key: &str,
+        order: &[&str],
+        default: &str,
+        max: &str,
+    ) -> Result<bool, RequestParameterPolicyViolation> {
+        let explicit = map
+            .get(key)
+            .and_then(Value::as_str)
+            .map(|value| value.trim().to_ascii_lowercase())
+            .filter(|value| !value.is_empty());
+        let requested = explicit.clone().unwrap_or_else(|| default.to_string());
+        let (Some(requested_rank), Some(max_rank)) = (rank(order, &requested), rank(order, max))
+        else {
+            // Unknown values are left to upstream validation.
+            return Ok(false);
+        };
+        if requested_rank <= max_rank {
+            return Ok(false);
+        }
+        if explicit.is_some() && self.mode == RequestParameterPolicyMode::Reject {
+            return Err(RequestParameterPolicyViolation::new(
+                key,
+                format!("{key} `{requested}` exceeds the allowed maximum `{max}`"),
+            ));
+        }
+        map.insert(key.to_string(), Value::String(max.to_string()));
+        Ok(true)
+    }
+
+    fn cap_number(
+        &self,
+        map: &mut Map<String, Value>,
+        key: &str,
+        default: i64,
+        max: i64,
+    ) -> Result<bool, RequestParameterPolicyViolation> {
+        let requested = match map.get(key) {
+            None | Some(Value::Null) => None,
+            Some(value) => match numeric_value(value) {
+                Some(value) => Some(value),
+                // Non-numeric values are left to upstream validation.
+                None => return Ok(false),
+            },
+        };
+        if requested.unwrap_or(default) <= max {
+            return Ok(false);
+        }
+        if let Some(requested) = requested
+            && self.mode == RequestParameterPolicyMode::Reject
+        {
+            return Err(RequestParameterPolicyViolation::new(
+                key,
+                format!("{key} {requested} exceeds the allowed maximum {max}"),
+            ));
+        }
+        map.insert(key.to_string(), Value::from(max));
+        Ok(true)
+    }
+}
+
+fn rank(order: &[&str], value: &str) -> Option<usize> {
+    order.iter().position(|candidate| *candidate == value)
+}
+
+fn normalize_rank(
+    field: &str,
+    value: Option<String>,
+    order: &[&str],
+) -> Result<Option<String>, String> {
+    value
+        .map(|value| {
+            let value = value.trim().to_ascii_lowercase();
+            if rank(order, &value).is_some() {
+                Ok(value)
+            } else {
+                Err(format!("{field} must be one of {}", order.join(", ")))
+            }
+        })
+        .transpose()
+}
+
+fn min_rank(left: Option<String>, right: Option<&str>, order: &[&str]) -> Option<String> {
+    match (left, right) {
+        (Some(left), Some(right)) => {
+            if rank(order, right) < rank(order, &left) {
+                Some(right.to_string())
+            } else {
+                Some(left)
+            }
+        }
+        (left, right) => left.or_else(|| right.map(str::to_string)),
+    }
+}
+
+fn min_option(left: Option<i64>, right: Option<i64>) -> Option<i64> {
+    match (left, right) {
+        (Some(left), Some(right)) => Some(left.min(right)),
+        (left, right) => left.or(right),
+    }
+}
+
+fn numeric_value(value: &Value) -> Option<i64> {
+    match value {
+        Value::Number(number) => number
+            .as_i64()
+            .or_else(|| number.as_f64().map(|value| value.ceil() as i64)),
+        Value::String(raw) => raw.trim().parse::<f64>().ok().and_then(|parsed| {
+            if parsed.is_finite() {
+                Some(parsed.ceil() as i64)
+            } else {
+                None
+            }
+        }),
+        _ => None,
+    }
+}
+
+fn raw_content_requested(value: &Value) -> bool {
+    match value {
+        Value::Bool(enabled) => *enabled,
+        Value::String(raw) => {
+            let raw = raw.trim();
+            !raw.is_empty() && !raw.eq_ignore_ascii_case("false")
+        }
+        _ => false,
+    }
+}
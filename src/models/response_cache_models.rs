@@ -0,0 +1,203 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::*;
+
+/// Endpoints cached once the admin enables the cache, unless the list is narrowed.
+pub const RESPONSE_CACHE_DEFAULT_ENDPOINTS: &[&str] = &["search", "extract", "map"];
+/// Endpoints whose response depends only on the request body. `research` creates upstream
+/// tasks and is never cached.
+pub const RESPONSE_CACHE_SUPPORTED_ENDPOINTS: &[&str] = &["search", "extract", "crawl", "map"];
+
+/// Request fields that never change the upstream answer and are left out of the cache key.
+const RESPONSE_CACHE_IGNORED_FIELDS: &[&str] = &["api_key", "include_usage"];
+
+/// Opt-in cache that answers repeated identical HTTP API calls without spending upstream credits.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseCacheSettings {
+    pub enabled: bool,
+    pub endpoints: Vec<String>,
+    /// How long a stored response is served, unless its endpoint has its own TTL.
+    pub ttl_secs: i64,
+    /// Per-endpoint TTL overrides, e.g. a longer one for `extract` than for `search`.
+    #[serde(default)]
+    pub endpoint_ttl_secs: BTreeMap<String, i64>,
+    /// The least recently stored responses are evicted beyond this many entries.
+    pub max_entries: i64,
+    /// Larger responses are passed through but never stored.
+    pub max_body_bytes: i64,
+    /// Share of the original response's credits charged for a hit: 0 serves hits for free.
+    pub hit_billing_percent: i64,
+}
+
+impl Default for ResponseCacheSettings {
+    fn default() -> Self {
+        Self {
+            enabled: RESPONSE_CACHE_ENABLED_DEFAULT,
+            endpoints: RESPONSE_CACHE_DEFAULT_ENDPOINTS
+                .iter()
+                .map(|value| (*value).to_string())
+                .collect(),
+            ttl_secs: RESPONSE_CACHE_TTL_SECS_DEFAULT,
+            endpoint_ttl_secs: BTreeMap::new(),
+            max_entries: RESPONSE_CACHE_MAX_ENTRIES_DEFAULT,
+            max_body_bytes: RESPONSE_CACHE_MAX_BODY_BYTES_DEFAULT,
+            hit_billing_percent: RESPONSE_CACHE_HIT_BILLING_PERCENT_DEFAULT,
+        }
+    }
+}
+
+impl ResponseCacheSettings {
+    /// Whether responses for `upstream_path` (for example `/search`) are served from the cache.
+    pub fn covers_upstream_path(&self, upstream_path: &str) -> bool {
+        let endpoint = upstream_path.trim_start_matches('/');
+        self.enabled
+            && RESPONSE_CACHE_SUPPORTED_ENDPOINTS.contains(&endpoint)
+            && self.endpoints.iter().any(|value| value == endpoint)
+    }
+
+    pub fn ttl_secs_for(&self, upstream_path: &str) -> i64 {
+        self.endpoint_ttl_secs
+            .get(upstream_path.trim_start_matches('/'))
+            .copied()
+            .unwrap_or(self.ttl_secs)
+    }
+
+    /// Credits billed for a hit on a response that originally cost `original_credits`,
+    /// rounded half up.
+    pub fn hit_credits(&self, original_credits: i64) -> i64 {
+        (original_credits.max(0) * self.hit_billing_percent + 50) / 100
+    }
+}
+
+/// Cache key for one HTTP API call: the endpoint plus the request body with object keys sorted,
+/// `null` fields dropped and credentials/usage flags removed. Headers never take part, so
+/// Hikari-only routing headers cannot split otherwise identical requests.
+pub fn response_cache_key(upstream_path: &str, options: &Value) -> String {
+    fn canonical(value: &Value, top_level: bool) -> Value {
+        match value {
+            Value::Object(map) => {
+                let mut entries: Vec<(&String, &Value)> = map
+                    .iter()
+                    .filter(|(key, value)| {
+                        let ignored = top_level
+                            && RESPONSE_CACHE_IGNORED_FIELDS
+                                .iter()
+                                .any(|ignored| key.eq_ignore_ascii_case(ignored));
+                        !value.is_null() && !ignored
+                    })
+                    .collect();
+                entries.sort_by(|left, right| left.0.cmp(right.0));
+                Value::Object(
+                    entries
+                        .into_iter()
+                        .map(|(key, value)| (key.clone(), canonical(value, false)))
+                        .collect(),
+                )
+            }
+            Value::Array(items) => {
+                Value::Array(items.iter().map(|item| canonical(item, false)).collect())
+            }
+            other => other.clone(),
+        }
+    }
+
+    let mut hasher = Sha256::new();
+    hasher.update(upstream_path.trim_start_matches('/').as_bytes());
+    hasher.update([0]);
+    hasher.update(canonical(options, true).to_string().as_bytes());
+    hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+pub fn normalize_response_cache_settings(
+    settings: &ResponseCacheSettings,
+) -> Result<ResponseCacheSettings, ProxyError> {
+    let ttl_range = RESPONSE_CACHE_TTL_SECS_MIN..=RESPONSE_CACHE_TTL_SECS_MAX;
+    if !ttl_range.contains(&settings.ttl_secs) {
+        return Err(ProxyError::Other(format!(
+            "response_cache.ttl_secs must be between {RESPONSE_CACHE_TTL_SECS_MIN} and {RESPONSE_CACHE_TTL_SECS_MAX}",
+        )));
+    }
+    if !(RESPONSE_CACHE_MAX_ENTRIES_MIN..=RESPONSE_CACHE_MAX_ENTRIES_MAX)
+        .contains(&settings.max_entries)
+    {
+        return Err(ProxyError::Other(format!(
+            "response_cache.max_entries must be between {RESPONSE_CACHE_MAX_ENTRIES_MIN} and {RESPONSE_CACHE_MAX_ENTRIES_MAX}",
+        )));
+    }
+    if !(RESPONSE_CACHE_MAX_BODY_BYTES_MIN..=RESPONSE_CACHE_MAX_BODY_BYTES_MAX)
+        .contains(&settings.max_body_bytes)
+    {
+        return Err(ProxyError::Other(format!(
+            "response_cache.max_body_bytes must be between {RESPONSE_CACHE_MAX_BODY_BYTES_MIN} and {RESPONSE_CACHE_MAX_BODY_BYTES_MAX}",
+        )));
+    }
+    if !(RESPONSE_CACHE_HIT_BILLING_PERCENT_MIN..=RESPONSE_CACHE_HIT_BILLING_PERCENT_MAX)
+        .contains(&settings.hit_billing_percent)
+    {
+        return Err(ProxyError::Other(format!(
+            "response_cache.hit_billing_percent must be between {RESPONSE_CACHE_HIT_BILLING_PERCENT_MIN} and {RESPONSE_CACHE_HIT_BILLING_PERCENT_MAX}",
+        )));
+    }
+    let normalize_endpoint = |value: &str| -> Result<String, ProxyError> {
+        let endpoint = value.trim().trim_start_matches('/').to_ascii_lowercase();
+        if RESPONSE_CACHE_SUPPORTED_ENDPOINTS.contains(&endpoint.as_str()) {
+            Ok(endpoint)
+        } else {
+            Err(ProxyError::Other(format!(
+                "response_cache does not support endpoint '{endpoint}'",
+            )))
+        }
+    };
+    let mut endpoints = Vec::with_capacity(settings.endpoints.len());
+    for value in &settings.endpoints {
+        let endpoint = normalize_endpoint(value)?;
+        if !endpoints.contains(&endpoint) {
+            endpoints.push(endpoint);
+        }
+    }
+    endpoints.sort_by_key(|endpoint| {
+        RESPONSE_CACHE_SUPPORTED_ENDPOINTS
+            .iter()
+            .position(|candidate| *candidate == endpoint)
+    });
+    let mut endpoint_ttl_secs = BTreeMap::new();
+    for (endpoint, ttl_secs) in &settings.endpoint_ttl_secs {
+        let endpoint = normalize_endpoint(endpoint)?;
+        if !ttl_range.contains(ttl_secs) {
+            return Err(ProxyError::Other(format!(
+                "response_cache.endpoint_ttl_secs.{endpoint} must be between {RESPONSE_CACHE_TTL_SECS_MIN} and {RESPONSE_CACHE_TTL_SECS_MAX}",
+            )));
+        }
+        endpoint_ttl_secs.insert(endpoint, *ttl_secs);
+    }
+    Ok(ResponseCacheSettings {
+        enabled: settings.enabled,
+        endpoints,
+        ttl_secs: settings.ttl_secs,
+        endpoint_ttl_secs,
+        max_entries: settings.max_entries,
+        max_body_bytes: settings.max_body_bytes,
+        hit_billing_percent: settings.hit_billing_percent,
+    })
+}
+
+/// Cache occupancy and today's savings, shown on the admin dashboard.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResponseCacheStats {
+    pub enabled: bool,
+    pub entries: i64,
+    pub stored_bytes: i64,
+    /// Hits since the start of the server-local day.
+    pub hits_today: i64,
+    /// Upstream credits the hits since the start of the server-local day did not spend.
+    pub credits_saved_today: i64,
+}
@@ -0,0 +1,176 @@
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+const TEAM_NAME_MAX_LEN: usize = 64;
+
+/// Role of a user inside a team. Owners and admins manage the team's tokens; members only draw
+/// from the shared quota pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TeamRole {
+    Member,
+    Admin,
+    Owner,
+}
+
+impl TeamRole {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Member => "member",
+            Self::Admin => "admin",
+            Self::Owner => "owner",
+        }
+    }
+
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "member" => Some(Self::Member),
+            "admin" => Some(Self::Admin),
+            "owner" => Some(Self::Owner),
+            _ => None,
+        }
+    }
+
+    /// Whether this role may create and delete team-owned tokens.
+    pub fn manages_tokens(self) -> bool {
+        self >= Self::Admin
+    }
+}
+
+/// Credit budget shared by every member and team-owned token. A limit of `0` blocks the window,
+/// matching account quota limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamQuotaLimits {
+    pub hourly_credits_limit: i64,
+    pub daily_credits_limit: i64,
+    pub monthly_credits_limit: i64,
+}
+
+/// What an admin submits when creating or updating a team.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TeamInput {
+    pub name: String,
+    pub limits: TeamQuotaLimits,
+    /// Keep enforcing each member's personal limits on top of the team pool. When `false`, the
+    /// team pool replaces personal limits for members.
+    pub enforce_member_limits: bool,
+}
+
+/// A team whose members and team-owned tokens draw from one quota pool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Team {
+    pub id: String,
+    pub name: String,
+    pub limits: TeamQuotaLimits,
+    pub enforce_member_limits: bool,
+    pub member_count: i64,
+    pub token_count: i64,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TeamMember {
+    pub user_id: String,
+    pub display_name: Option<String>,
+    pub username: Option<String>,
+    pub role: TeamRole,
+    pub joined_at: i64,
+}
+
+/// A token owned by a team rather than a user. Its usage is billed to the team pool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TeamToken {
+    pub token_id: String,
+    pub note: Option<String>,
+    pub enabled: bool,
+    pub created_by: Option<String>,
+    pub created_at: i64,
+    pub last_used_at: Option<i64>,
+}
+
+/// The team a user belongs to and the role they hold in it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TeamMembership {
+    pub team: Team,
+    pub role: TeamRole,
+}
+
+pub fn validate_team_input(input: &TeamInput) -> Result<(), String> {
+    let name = input.name.trim();
+    if name.is_empty() {
+        return Err("team name is required".to_string());
+    }
+    if name.chars().count() > TEAM_NAME_MAX_LEN {
+        return Err(format!(
+            "team name must be at most {TEAM_NAME_MAX_LEN} characters"
+        ));
+    }
+    let limits = input.limits;
+    if limits.hourly_credits_limit < 0
+        || limits.daily_credits_limit < 0
+        || limits.monthly_credits_limit < 0
+    {
+        return Err("team quota limits must not be negative".to_string());
+    }
+    Ok(())
+}
+
+impl TokenQuotaVerdict {
+    /// Verdict for the team pool alone. Unlike account verdicts the hourly window is enforced,
+    /// because team hourly limits are counted in credits.
+    pub(crate) fn for_team(
+        limits: TeamQuotaLimits,
+        hourly_used: i64,
+        daily_used: i64,
+        monthly_used: i64,
+    ) -> Self {
+        Self::new(
+            hourly_used,
+            limits.hourly_credits_limit,
+            daily_used,
+            limits.daily_credits_limit,
+            monthly_used,
+            limits.monthly_credits_limit,
+        )
+    }
+
+    /// Combines a personal verdict with the team pool verdict. Each window reports whichever
+    /// side has less headroom left, and the request is blocked when either side blocks it.
+    pub(crate) fn with_team_pool(self, team: Self) -> Self {
+        fn tighter(personal: (i64, i64), team: (i64, i64)) -> (i64, i64) {
+            if personal.1 - personal.0 < team.1 - team.0 {
+                personal
+            } else {
+                team
+            }
+        }
+        let hourly = if self.hourly_enforced {
+            tighter(
+                (self.hourly_used, self.hourly_limit),
+                (team.hourly_used, team.hourly_limit),
+            )
+        } else {
+            (team.hourly_used, team.hourly_limit)
+        };
+        let daily = tighter(
+            (self.daily_used, self.daily_limit),
+            (team.daily_used, team.daily_limit),
+        );
+        let monthly = tighter(
+            (self.monthly_used, self.monthly_limit),
+            (team.monthly_used, team.monthly_limit),
+        );
+        let mut combined = Self::new(hourly.0, hourly.1, daily.0, daily.1, monthly.0, monthly.1);
+        if !self.allowed || !team.allowed {
+            combined.allowed = false;
+            combined.exceeded_window = combined
+                .exceeded_window
+                .or(team.exceeded_window)
+                .or(self.exceeded_window);
+        }
+        combined
+    }
+}
@@ -0,0 +1,200 @@
+use std::{future::Future, sync::OnceLock};
+
+use opentelemetry::{
+    propagation::{Extractor, TextMapPropagator},
+    trace::{TraceContextExt, TracerProvider as _},
+};
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::{
+    Resource,
+    propagation::TraceContextPropagator,
+    trace::{SdkTracer, SdkTracerProvider},
+};
+use reqwest::header::HeaderMap;
+use tracing::{Instrument, Span};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+const OTLP_TRACES_PATH: &str = "/v1/traces";
+const DEFAULT_OTEL_SERVICE_NAME: &str = "tavily-hikari";
+
+static OTLP_TRACER_PROVIDER: OnceLock<SdkTracerProvider> = OnceLock::new();
+
+tokio::task_local! {
+    static REQUEST_TRACE_ID: Option<String>;
+}
+
+/// Root span of one proxied request and the trace id its request log rows carry.
+#[derive(Debug)]
+pub struct RequestTrace {
+    span: Span,
+    trace_id: Option<String>,
+}
+
+impl RequestTrace {
+    /// Opens the root span for `method path`, continuing the caller's trace when the request
+    /// carries a valid W3C `traceparent` header.
+    pub fn start(method: &str, path: &str, headers: &HeaderMap) -> Self {
+        let span = tracing::info_span!(
+            "proxy_request",
+            otel.name = %format!("{method} {path}"),
+            otel.kind = "server",
+            http.request.method = %method,
+            url.path = %path,
+        );
+        let parent = TraceContextPropagator::new().extract(&HeaderExtractor(headers));
+        let remote = parent.span().span_context().clone();
+        if remote.is_valid() {
+            let _ = span.set_parent(parent);
+        }
+        // Without an exporter the span has no OpenTelemetry context, but the caller's trace id
+        // is still worth keeping on the log rows for cross-system lookups.
+        let local = span.context().span().span_context().clone();
+        let trace_id = if local.is_valid() {
+            Some(local.trace_id().to_string())
+        } else if remote.is_valid() {
+            Some(remote.trace_id().to_string())
+        } else {
+            None
+        };
+        Self { span, trace_id }
+    }
+
+    pub fn trace_id(&self) -> Option<&str> {
+        self.trace_id.as_deref()
+    }
+
+    /// Runs `fut` inside the root span with the trace id visible to request log writes.
+    pub async fn scope<F: Future>(self, fut: F) -> F::Output {
+        REQUEST_TRACE_ID
+            .scope(self.trace_id, fut.instrument(self.span))
+            .await
+    }
+}
+
+/// Trace id of the request currently being proxied on this task, if any.
+pub(crate) fn current_request_trace_id() -> Option<String> {
+    REQUEST_TRACE_ID.try_with(Clone::clone).ok().flatten()
+}
+
+struct HeaderExtractor<'a>(&'a HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|name| name.as_str()).collect()
+    }
+}
+
+/// Accepts either a collector base URL (`http://collector:4318`) or the full traces URL.
+fn otlp_traces_url(endpoint: &str) -> Option<String> {
+    let endpoint = endpoint.trim().trim_end_matches('/');
+    if endpoint.is_empty() {
+        None
+    } else if endpoint.ends_with(OTLP_TRACES_PATH) {
+        Some(endpoint.to_string())
+    } else {
+        Some(format!("{endpoint}{OTLP_TRACES_PATH}"))
+    }
+}
+
+/// Builds the batch OTLP/HTTP span exporter. The provider is kept so that
+/// `shutdown_request_tracing` can flush it on exit.
+pub(crate) fn build_otlp_tracer(endpoint: &str) -> Result<SdkTracer, String> {
+    let url = otlp_traces_url(endpoint).ok_or_else(|| "OTLP endpoint is empty".to_string())?;
+    let exporter = opentelemetry_otlp::SpanExporter::builder()
+        .with_http()
+        .with_endpoint(url)
+        .build()
+        .map_err(|err| err.to_string())?;
+    let mut resource = Resource::builder();
+    if std::env::var_os("OTEL_SERVICE_NAME").is_none() {
+        resource = resource.with_service_name(DEFAULT_OTEL_SERVICE_NAME);
+    }
+    let provider = SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(resource.build())
+        .build();
+    let tracer = provider.tracer(DEFAULT_OTEL_SERVICE_NAME);
+    let _ = OTLP_TRACER_PROVIDER.set(provider);
+    Ok(tracer)
+}
+
+/// Flushes spans still buffered for the OTLP exporter. Blocks until the export finishes.
+pub fn shutdown_request_tracing() {
+    if let Some(provider) = OTLP_TRACER_PROVIDER.get() {
+        let _ = provider.shutdown();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    const TRACEPARENT: &str = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+
+    fn traceparent_headers() -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("traceparent", HeaderValue::from_static(TRACEPARENT));
+        headers
+    }
+
+    #[test]
+    fn otlp_traces_url_appends_signal_path_once() {
+        assert_eq!(
+            otlp_traces_url("http://collector:4318/").as_deref(),
+            Some("http://collector:4318/v1/traces")
+        );
+        assert_eq!(
+            otlp_traces_url("http://collector:4318/v1/traces").as_deref(),
+            Some("http://collector:4318/v1/traces")
+        );
+        assert_eq!(otlp_traces_url("  "), None);
+    }
+
+    #[test]
+    fn request_trace_continues_incoming_traceparent_with_an_exporter() {
+        let tracer = SdkTracerProvider::builder().build().tracer("test");
+        let subscriber =
+            tracing_subscriber::registry().with(tracing_opentelemetry::layer().with_tracer(tracer));
+        tracing::subscriber::with_default(subscriber, || {
+            let continued = RequestTrace::start("POST", "/mcp", &traceparent_headers());
+            assert_eq!(
+                continued.trace_id(),
+                Some("4bf92f3577b34da6a3ce929d0e0e4736")
+            );
+            let child = continued
+                .span
+                .in_scope(|| tracing::info_span!("key_acquire"));
+            assert_eq!(
+                child.context().span().span_context().trace_id().to_string(),
+                "4bf92f3577b34da6a3ce929d0e0e4736"
+            );
+
+            let fresh = RequestTrace::start("POST", "/mcp", &HeaderMap::new());
+            let fresh_id = fresh.trace_id().expect("new trace id");
+            assert_eq!(fresh_id.len(), 32);
+            assert_ne!(fresh_id, "4bf92f3577b34da6a3ce929d0e0e4736");
+        });
+    }
+
+    #[tokio::test]
+    async fn request_trace_keeps_caller_trace_id_without_an_exporter() {
+        let continued = RequestTrace::start("POST", "/api/tavily/search", &traceparent_headers());
+        assert_eq!(
+            continued
+                .scope(async { current_request_trace_id() })
+                .await
+                .as_deref(),
+            Some("4bf92f3577b34da6a3ce929d0e0e4736")
+        );
+
+        let untraced = RequestTrace::start("POST", "/api/tavily/search", &HeaderMap::new());
+        assert_eq!(untraced.trace_id(), None);
+        assert_eq!(current_request_trace_id(), None);
+    }
+}
@@ -6,8 +6,9 @@ use std::{
 };
 
 use clap::ValueEnum;
+use opentelemetry_sdk::trace::SdkTracer;
 use tracing::Dispatch;
-use tracing_subscriber::{EnvFilter, fmt::MakeWriter};
+use tracing_subscriber::{EnvFilter, fmt::MakeWriter, layer::SubscriberExt};
 
 const DEFAULT_RUNTIME_LOG_FILTER: &str = "warn,tavily_hikari=info,sqlx::query=warn";
 
@@ -42,12 +43,29 @@ pub enum LegacyStdIoLevel {
     Warn,
 }
 
-pub fn init_runtime_logging(format: RuntimeLogFormat) {
+/// Installs the process-wide subscriber. With `otlp_endpoint` set, spans are also exported
+/// over OTLP/HTTP.
+pub fn init_runtime_logging(format: RuntimeLogFormat, otlp_endpoint: Option<&str>) {
     RUNTIME_LOGGING_INIT.get_or_init(|| {
-        let dispatch = build_runtime_log_dispatch(format, runtime_log_env_filter(), io::stderr);
+        let (tracer, otlp_error) =
+            match otlp_endpoint.map(crate::request_tracing::build_otlp_tracer) {
+                Some(Ok(tracer)) => (Some(tracer), None),
+                Some(Err(err)) => (None, Some(err)),
+                None => (None, None),
+            };
+        let dispatch =
+            build_runtime_log_dispatch(format, runtime_log_env_filter(), io::stderr, tracer);
         if tracing::dispatcher::set_global_default(dispatch).is_ok() {
             install_log_tracer();
         }
+        if let Some(err) = otlp_error {
+            tracing::warn!(
+                component = "startup",
+                event = "otlp_exporter_init_failed",
+                err = %err,
+                "OTLP span exporter init failed; tracing stays local"
+            );
+        }
     });
 }
 
@@ -332,7 +350,12 @@ fn runtime_log_env_filter() -> EnvFilter {
         .unwrap_or_else(|_| EnvFilter::new("warn"))
 }
 
-fn build_runtime_log_dispatch<W>(format: RuntimeLogFormat, filter: EnvFilter, writer: W) -> Dispatch
+fn build_runtime_log_dispatch<W>(
+    format: RuntimeLogFormat,
+    filter: EnvFilter,
+    writer: W,
+    tracer: Option<SdkTracer>,
+) -> Dispatch
 where
     W: for<'writer> MakeWriter<'writer> + Send + Sync + 'static,
 {
@@ -346,7 +369,8 @@ where
                 .with_current_span(false)
                 .with_span_list(false)
                 .with_target(true)
-                .finish(),
+                .finish()
+                .with(tracer.map(|tracer| tracing_opentelemetry::layer().with_tracer(tracer))),
         ),
         RuntimeLogFormat::Text => Dispatch::new(
             tracing_subscriber::fmt()
@@ -355,7 +379,8 @@ where
                 .compact()
                 .with_ansi(false)
                 .with_target(true)
-                .finish(),
+                .finish()
+                .with(tracer.map(|tracer| tracing_opentelemetry::layer().with_tracer(tracer))),
         ),
     }
 }
@@ -462,7 +487,7 @@ mod tests {
         F: FnOnce(),
     {
         let (writer, buffer) = SharedWriter::new();
-        let dispatch = build_runtime_log_dispatch(format, filter, writer);
+        let dispatch = build_runtime_log_dispatch(format, filter, writer, None);
         tracing::dispatcher::with_default(&dispatch, emit);
         captured_output(&buffer)
     }
@@ -588,8 +613,12 @@ mod tests {
         let _guard = LOG_BRIDGE_TEST_LOCK.lock().expect("log bridge lock");
         install_log_tracer();
         let (writer, buffer) = SharedWriter::new();
-        let dispatch =
-            build_runtime_log_dispatch(RuntimeLogFormat::Json, EnvFilter::new("warn"), writer);
+        let dispatch = build_runtime_log_dispatch(
+            RuntimeLogFormat::Json,
+            EnvFilter::new("warn"),
+            writer,
+            None,
+        );
         tracing::dispatcher::with_default(&dispatch, || {
             log::warn!("log-bridge-ok");
         });
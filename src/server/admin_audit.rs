@@ -0,0 +1,224 @@
+const ADMIN_AUDIT_BODY_LIMIT: usize = 64 * 1024;
+
+/// Routes under `/api/` that belong to end users, upstream proxying or unauthenticated flows.
+/// Requests to them are never admin actions, even when the caller also holds an admin session.
+const ADMIN_AUDIT_EXCLUDED_PREFIXES: &[&str] = &[
+    "/api/user/",
+    "/api/tavily/",
+    "/api/public/",
+    "/api/internal/",
+    "/api/debug/",
+    "/api/linuxdo-credit/",
+    "/api/token/",
+    "/api/admin/login",
+    "/api/admin/passkey/authentication/",
+];
+
+/// Before/after snapshots a handler attaches to its response so the audit log can record what
+/// the request actually changed instead of only the submitted payload.
+#[derive(Clone, Debug)]
+struct AdminAuditChange {
+    before: Value,
+    after: Value,
+}
+
+impl AdminAuditChange {
+    fn of<B: Serialize, A: Serialize>(before: &B, after: &A) -> axum::Extension<Self> {
+        axum::Extension(Self {
+            before: serde_json::to_value(before).unwrap_or(Value::Null),
+            after: serde_json::to_value(after).unwrap_or(Value::Null),
+        })
+    }
+}
+
+struct AdminAuditActor {
+    kind: &'static str,
+    id: Option<String>,
+    name: Option<String>,
+}
+
+fn admin_audit_kind(method: &Method, route: &str) -> Option<&'static str> {
+    if !route.starts_with("/api/")
+        || ADMIN_AUDIT_EXCLUDED_PREFIXES
+            .iter()
+            .any(|prefix| route.starts_with(prefix))
+    {
+        return None;
+    }
+    match *method {
+        Method::POST | Method::PUT | Method::PATCH | Method::DELETE => {
+            Some(tavily_hikari::ADMIN_AUDIT_KIND_MUTATION)
+        }
+        Method::GET if route.ends_with("/secret") => {
+            Some(tavily_hikari::ADMIN_AUDIT_KIND_SENSITIVE_READ)
+        }
+        _ => None,
+    }
+}
+
+/// Resolves the admin identity in the same order `is_admin_request` accepts it.
+async fn admin_audit_actor(state: &AppState, headers: &HeaderMap) -> Option<AdminAuditActor> {
+    if state.dev_open_admin {
+        return Some(AdminAuditActor {
+            kind: tavily_hikari::ADMIN_AUDIT_ACTOR_DEV_OPEN,
+            id: None,
+            name: Some("dev-open-admin".to_string()),
+        });
+    }
+    if state.forward_auth_enabled && state.forward_auth.is_request_admin(headers) {
+        let id = state.forward_auth.user_value(headers).map(str::to_string);
+        return Some(AdminAuditActor {
+            kind: tavily_hikari::ADMIN_AUDIT_ACTOR_FORWARD_AUTH,
+            name: state
+                .forward_auth
+                .nickname_value(headers)
+                .or_else(|| id.clone())
+                .or_else(|| state.forward_auth.admin_override_name().map(str::to_string)),
+            id,
+        });
+    }
+    if state.builtin_admin.is_admin(headers) {
+        return Some(AdminAuditActor {
+            kind: tavily_hikari::ADMIN_AUDIT_ACTOR_BUILTIN,
+            id: None,
+            name: Some("builtin-admin".to_string()),
+        });
+    }
+    if let Some(session) = resolve_admin_passkey_session(state, headers).await {
+        return Some(AdminAuditActor {
+            kind: tavily_hikari::ADMIN_AUDIT_ACTOR_PASSKEY,
+            name: Some(
+                session
+                    .credential_id
+                    .as_deref()
+                    .map(|credential_id| {
+                        let prefix = credential_id.chars().take(16).collect::<String>();
+                        format!("admin-passkey:{prefix}")
+                    })
+                    .unwrap_or_else(|| "admin-passkey".to_string()),
+            ),
+            id: session.credential_id,
+        });
+    }
+    if let Some(session) = resolve_admin_account_session(state, headers).await {
+        return Some(AdminAuditActor {
+            kind: tavily_hikari::ADMIN_AUDIT_ACTOR_ACCOUNT,
+            id: Some(session.account_id),
+            name: Some(session.username),
+        });
+    }
+    let token = resolve_admin_api_token(state, headers).await?;
+    Some(AdminAuditActor {
+        kind: tavily_hikari::ADMIN_AUDIT_ACTOR_API_TOKEN,
+        id: Some(token.id),
+        name: Some(token.name),
+    })
+}
+
+async fn admin_audit_client_ip(
+    state: &AppState,
+    remote_addr: Option<SocketAddr>,
+    headers: &HeaderMap,
+) -> Option<String> {
+    let settings = state.proxy.get_system_settings().await.ok()?;
+    resolve_client_ip_info(
+        remote_addr,
+        headers,
+        &TrustedClientIpSettings {
+            trusted_proxy_cidrs: settings.trusted_proxy_cidrs,
+            trusted_client_ip_headers: settings.trusted_client_ip_headers,
+        },
+    )
+    .client_ip
+}
+
+/// Buffers small JSON request bodies so the submitted payload can be recorded. Larger or
+/// unsized bodies are passed through untouched and audited without a payload.
+async fn admin_audit_request_payload(req: Request<Body>) -> (Request<Body>, Option<Value>) {
+    let small_json = req
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"))
+        && req
+            .headers()
+            .get(CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<usize>().ok())
+            .is_some_and(|length| length <= ADMIN_AUDIT_BODY_LIMIT);
+    if !small_json {
+        return (req, None);
+    }
+    let (parts, body) = req.into_parts();
+    match body::to_bytes(body, ADMIN_AUDIT_BODY_LIMIT).await {
+        Ok(bytes) => {
+            let payload = serde_json::from_slice::<Value>(&bytes).ok();
+            (Request::from_parts(parts, Body::from(bytes)), payload)
+        }
+        Err(_) => (Request::from_parts(parts, Body::empty()), None),
+    }
+}
+
+/// Appends every admin mutation and secret read to the hash-chained audit log. Only requests
+/// that pass admin authentication are recorded; the handler still performs its own check.
+async fn admin_audit_http_layer(
+    State(state): State<Arc<AppState>>,
+    req: Request<Body>,
+    next: axum::middleware::Next,
+) -> Response<Body> {
+    let Some(route) = req
+        .extensions()
+        .get::<axum::extract::MatchedPath>()
+        .map(|path| path.as_str().to_string())
+    else {
+        return next.run(req).await;
+    };
+    let Some(kind) = admin_audit_kind(req.method(), &route) else {
+        return next.run(req).await;
+    };
+    let Some(actor) = admin_audit_actor(state.as_ref(), req.headers()).await else {
+        return next.run(req).await;
+    };
+    let occurred_at = state.proxy.backend_time().now_ts();
+    let method = req.method().to_string();
+    let path = req.uri().path().to_string();
+    let remote_addr = req
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|info| info.0);
+    let client_ip = admin_audit_client_ip(state.as_ref(), remote_addr, req.headers()).await;
+    let (req, payload) = if kind == tavily_hikari::ADMIN_AUDIT_KIND_MUTATION {
+        admin_audit_request_payload(req).await
+    } else {
+        (req, None)
+    };
+
+    let response = next.run(req).await;
+    let (before, after) = match response.extensions().get::<AdminAuditChange>().cloned() {
+        Some(change) => tavily_hikari::admin_audit_diff(change.before, change.after),
+        None => (None, payload),
+    };
+    let input = tavily_hikari::AdminAuditEntryInput {
+        occurred_at,
+        kind: kind.to_string(),
+        actor_kind: actor.kind.to_string(),
+        actor_id: actor.id,
+        actor_name: actor.name,
+        client_ip,
+        method,
+        route,
+        path,
+        status: i64::from(response.status().as_u16()),
+        before,
+        after,
+    };
+    if let Err(err) = state.proxy.append_admin_audit_entry(input).await {
+        tracing::warn!(
+            component = "admin_audit",
+            event = "append_failed",
+            err = %err,
+            "failed to append admin audit entry"
+        );
+    }
+    response
+}
@@ -0,0 +1,194 @@
+/// Admin routes that every role may call, such as reading its own profile or signing out.
+const ADMIN_RBAC_UNRESTRICTED_ROUTES: &[&str] =
+    &["/api/profile", "/api/version", "/api/admin/logout"];
+
+/// Admin credential management, HA and payment routes reserved for owners, including reads.
+/// HA baseline and event exports carry every key and token secret.
+const ADMIN_RBAC_OWNER_PREFIXES: &[&str] = &[
+    "/api/admin/accounts",
+    "/api/admin/api-tokens",
+    "/api/admin/audit",
+    "/api/admin/ha",
+    "/api/admin/passkeys",
+    "/api/admin/password",
+    "/api/admin/recharges",
+    "/api/admin/totp",
+];
+
+/// Minimum role a named admin account needs for a matched `/api/` route, or `None` when the
+/// route is not an admin route. Reads need `viewer`; HA routes, secret reveals and settings
+/// changes need `owner`; every other change needs `operator`.
+fn admin_route_required_role(method: &Method, route: &str) -> Option<tavily_hikari::AdminRole> {
+    use tavily_hikari::AdminRole;
+
+    if !route.starts_with("/api/")
+        || ADMIN_AUDIT_EXCLUDED_PREFIXES
+            .iter()
+            .any(|prefix| route.starts_with(prefix))
+        || ADMIN_RBAC_UNRESTRICTED_ROUTES.contains(&route)
+    {
+        return None;
+    }
+    if ADMIN_RBAC_OWNER_PREFIXES
+        .iter()
+        .any(|prefix| route.starts_with(prefix))
+    {
+        return Some(AdminRole::Owner);
+    }
+    let read = matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS);
+    if read {
+        return Some(if route.ends_with("/secret") {
+            AdminRole::Owner
+        } else {
+            AdminRole::Viewer
+        });
+    }
+    if route.starts_with("/api/settings") || route.starts_with("/api/admin/") {
+        return Some(AdminRole::Owner);
+    }
+    Some(AdminRole::Operator)
+}
+
+/// Resource each admin route family belongs to when an admin API token calls it. Routes that are
+/// not listed, such as credential management, audit, recharges and the debug endpoints, are never
+/// open to tokens.
+const ADMIN_API_TOKEN_ROUTE_RESOURCES: &[(&str, &str)] = &[
+    ("/metrics", "stats"),
+    ("/api/keys", "keys"),
+    ("/api/tokens", "tokens"),
+    ("/api/parameter-policies", "tokens"),
+    ("/api/users", "users"),
+    ("/api/user-tags", "users"),
+    ("/api/teams", "users"),
+    ("/api/admin/registration", "users"),
+    ("/api/logs", "logs"),
+    ("/api/jobs", "jobs"),
+    ("/api/alerts", "alerts"),
+    ("/api/announcements", "announcements"),
+    ("/api/summary", "stats"),
+    ("/api/dashboard", "stats"),
+    ("/api/stats", "stats"),
+    ("/api/analysis", "stats"),
+    ("/api/events", "stats"),
+    ("/api/ha/status", "stats"),
+    ("/api/settings", "settings"),
+    ("/api/admin/ha", "settings"),
+];
+
+/// HA exports that carry every key and token secret, so tokens need write scope even to read them.
+const ADMIN_API_TOKEN_SECRET_EXPORT_ROUTES: &[&str] = &[
+    "/api/admin/ha/baseline",
+    "/api/admin/ha/events",
+    "/api/admin/ha/snapshot",
+];
+
+/// Scope an admin API token needs for a matched admin route, or `None` when tokens may not call
+/// it at all. Reads need `<resource>:read`; changes, secret reveals and secret exports need
+/// `<resource>:write`.
+fn admin_api_token_required_scope(method: &Method, route: &str) -> Option<String> {
+    let (_, resource) = ADMIN_API_TOKEN_ROUTE_RESOURCES.iter().find(|(prefix, _)| {
+        route == *prefix
+            || route
+                .strip_prefix(prefix)
+                .is_some_and(|rest| rest.starts_with('/'))
+    })?;
+    let read = matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS)
+        && !route.ends_with("/secret")
+        && !ADMIN_API_TOKEN_SECRET_EXPORT_ROUTES.contains(&route);
+    Some(format!(
+        "{resource}:{}",
+        if read { "read" } else { "write" }
+    ))
+}
+
+/// Role of the admin behind a request. Forward-auth, the builtin password, passkeys and dev
+/// mode predate named accounts and keep full access.
+async fn resolve_admin_role(
+    state: &AppState,
+    headers: &HeaderMap,
+) -> Option<tavily_hikari::AdminRole> {
+    if state.dev_open_admin
+        || (state.forward_auth_enabled && state.forward_auth.is_request_admin(headers))
+        || state.builtin_admin.is_admin(headers)
+        || resolve_admin_passkey_session(state, headers)
+            .await
+            .is_some()
+    {
+        return Some(tavily_hikari::AdminRole::Owner);
+    }
+    resolve_admin_account_session(state, headers)
+        .await
+        .map(|session| session.role)
+}
+
+/// Checks an admin API token against the route scope and its IP allowlist, and records the use.
+async fn admin_api_token_permits(
+    state: &AppState,
+    token: &tavily_hikari::AdminApiToken,
+    method: &Method,
+    route: &str,
+    remote_addr: Option<SocketAddr>,
+    headers: &HeaderMap,
+) -> bool {
+    let Some(required) = admin_api_token_required_scope(method, route) else {
+        return false;
+    };
+    let client_ip = admin_audit_client_ip(state, remote_addr, headers).await;
+    if !token.allows_ip(client_ip.as_deref()) || !token.scopes.allows(&required) {
+        return false;
+    }
+    if let Err(err) = state
+        .proxy
+        .touch_admin_api_token(&token.id, client_ip.as_deref())
+        .await
+    {
+        eprintln!("record admin API token use error: {err}");
+    }
+    true
+}
+
+/// Rejects admin requests whose named account lacks the role the route needs, or whose admin API
+/// token lacks the scope or comes from outside its IP allowlist. Tokens are checked on every
+/// matched route, not only admin `/api/` routes, because handlers such as `/metrics` accept any
+/// admin through `is_admin_request`. Requests without an admin identity pass through so the
+/// handler can answer them as before.
+async fn admin_rbac_http_layer(
+    State(state): State<Arc<AppState>>,
+    req: Request<Body>,
+    next: axum::middleware::Next,
+) -> Response<Body> {
+    let Some(route) = req
+        .extensions()
+        .get::<axum::extract::MatchedPath>()
+        .map(|path| path.as_str().to_string())
+    else {
+        return next.run(req).await;
+    };
+    if let Some(required) = admin_route_required_role(req.method(), &route)
+        && let Some(role) = resolve_admin_role(state.as_ref(), req.headers()).await
+    {
+        if !role.allows(required) {
+            return (StatusCode::FORBIDDEN, "forbidden").into_response();
+        }
+        return next.run(req).await;
+    }
+    if let Some(token) = resolve_admin_api_token(state.as_ref(), req.headers()).await {
+        let remote_addr = req
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|info| info.0);
+        if !admin_api_token_permits(
+            state.as_ref(),
+            &token,
+            req.method(),
+            &route,
+            remote_addr,
+            req.headers(),
+        )
+        .await
+        {
+            return (StatusCode::FORBIDDEN, "forbidden").into_response();
+        }
+    }
+    next.run(req).await
+}
@@ -243,6 +243,7 @@ struct RequestLogView {
     client_ip_source: Option<String>,
     client_ip_trusted: bool,
     ip_headers: Vec<tavily_hikari::ClientIpHeaderValue>,
+    trace_id: Option<String>,
     #[serde(rename = "operationalClass")]
     operational_class: String,
     #[serde(rename = "requestKindProtocolGroup")]
@@ -307,6 +308,85 @@ struct SummaryView {
     last_activity: Option<i64>,
     total_quota_limit: i64,
     total_quota_remaining: i64,
+    key_tiers: Vec<ApiKeyTierCapacityView>,
+    key_rate_budgets: Vec<ApiKeyRateBudgetUsageView>,
+    response_cache: ResponseCacheStatsView,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ResponseCacheStatsView {
+    enabled: bool,
+    entries: i64,
+    stored_bytes: i64,
+    hits_today: i64,
+    credits_saved_today: i64,
+}
+
+impl From<tavily_hikari::ResponseCacheStats> for ResponseCacheStatsView {
+    fn from(value: tavily_hikari::ResponseCacheStats) -> Self {
+        Self {
+            enabled: value.enabled,
+            entries: value.entries,
+            stored_bytes: value.stored_bytes,
+            hits_today: value.hits_today,
+            credits_saved_today: value.credits_saved_today,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ApiKeyTierCapacityView {
+    priority: i64,
+    groups: Vec<String>,
+    total_keys: i64,
+    available_keys: i64,
+    cooling_keys: i64,
+    exhausted_keys: i64,
+    quarantined_keys: i64,
+    quota_limit: i64,
+    quota_remaining: i64,
+}
+
+impl From<tavily_hikari::ApiKeyTierCapacity> for ApiKeyTierCapacityView {
+    fn from(value: tavily_hikari::ApiKeyTierCapacity) -> Self {
+        Self {
+            priority: value.priority,
+            groups: value.groups,
+            total_keys: value.total_keys,
+            available_keys: value.available_keys,
+            cooling_keys: value.cooling_keys,
+            exhausted_keys: value.exhausted_keys,
+            quarantined_keys: value.quarantined_keys,
+            quota_limit: value.quota_limit,
+            quota_remaining: value.quota_remaining,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ApiKeyRateBudgetUsageView {
+    key_id: String,
+    rpm_limit: i64,
+    used_last_minute: i64,
+    queued: i64,
+    delayed_total: i64,
+    rejected_total: i64,
+}
+
+impl From<tavily_hikari::ApiKeyRateBudgetUsage> for ApiKeyRateBudgetUsageView {
+    fn from(value: tavily_hikari::ApiKeyRateBudgetUsage) -> Self {
+        Self {
+            key_id: value.key_id,
+            rpm_limit: value.rpm_limit,
+            used_last_minute: value.used_last_minute,
+            queued: value.queued,
+            delayed_total: value.delayed_total,
+            rejected_total: value.rejected_total,
+        }
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -355,6 +435,9 @@ struct AuthTokenView {
     quota_hourly_reset_at: Option<i64>,
     quota_daily_reset_at: Option<i64>,
     quota_monthly_reset_at: Option<i64>,
+    not_before: Option<i64>,
+    expires_at: Option<i64>,
+    scopes: Option<Vec<String>>,
 }
 
 impl AuthTokenView {
@@ -410,6 +493,9 @@ impl AuthTokenView {
             quota_hourly_reset_at: t.quota_hourly_reset_at,
             quota_daily_reset_at: t.quota_daily_reset_at,
             quota_monthly_reset_at: t.quota_monthly_reset_at,
+            not_before: t.lifetime.not_before,
+            expires_at: t.lifetime.expires_at,
+            scopes: t.scopes.map(|scopes| scopes.keys().to_vec()),
         }
     }
 }
@@ -425,6 +511,77 @@ struct AuthTokenSecretView {
     token: String,
 }
 
+/// Reveal endpoints answer `410 Gone` once a token only keeps the hash of its secret.
+fn token_secret_not_recoverable_response(token_id: &str) -> Response<Body> {
+    (
+        StatusCode::GONE,
+        Json(json!({
+            "error": "token_secret_not_recoverable",
+            "tokenId": token_id,
+            "message": "This token only keeps a hashed secret; rotate it to issue a new one.",
+        })),
+    )
+        .into_response()
+}
+
+/// `/mcp` and `/api/tavily/*` answer `401` for every rejected token, but name lifetime-window
+/// rejections so clients can tell an expired token from a revoked one.
+fn access_token_rejection_response(
+    validation: AccessTokenValidation,
+) -> Result<Response<Body>, StatusCode> {
+    let body = match validation {
+        AccessTokenValidation::Expired { expires_at } => json!({
+            "error": "token_expired",
+            "expiresAt": expires_at,
+            "message": "This access token has expired.",
+        }),
+        AccessTokenValidation::NotYetValid { not_before } => json!({
+            "error": "token_not_yet_valid",
+            "notBefore": not_before,
+            "message": "This access token is not valid yet.",
+        }),
+        AccessTokenValidation::Valid | AccessTokenValidation::Invalid => {
+            json!({ "error": "invalid or disabled token" })
+        }
+    };
+    Response::builder()
+        .status(StatusCode::UNAUTHORIZED)
+        .header(CONTENT_TYPE, "application/json; charset=utf-8")
+        .body(Body::from(body.to_string()))
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+fn token_scope_denied_response(
+    denied: &TokenRequestKind,
+    message: &str,
+) -> Result<Response<Body>, StatusCode> {
+    let body = json!({
+        "error": "token_scope_denied",
+        "requestKind": denied.key,
+        "message": message,
+    });
+    Response::builder()
+        .status(StatusCode::FORBIDDEN)
+        .header(CONTENT_TYPE, "application/json; charset=utf-8")
+        .body(Body::from(body.to_string()))
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+fn parameter_policy_violation_response(
+    violation: &RequestParameterPolicyViolation,
+) -> Result<Response<Body>, StatusCode> {
+    let body = json!({
+        "error": "parameter_policy_violation",
+        "parameter": violation.parameter,
+        "message": violation.message,
+    });
+    Response::builder()
+        .status(StatusCode::BAD_REQUEST)
+        .header(CONTENT_TYPE, "application/json; charset=utf-8")
+        .body(Body::from(body.to_string()))
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
+
 // ---- Token Detail views ----
 #[derive(Debug, Serialize)]
 struct TokenSummaryView {
@@ -547,6 +704,9 @@ impl From<TokenRequestKindOption> for TokenRequestKindOptionView {
 #[derive(Debug, Deserialize)]
 struct CreateTokenRequest {
     note: Option<String>,
+    not_before: Option<i64>,
+    expires_at: Option<i64>,
+    scopes: Option<Vec<String>>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -578,6 +738,7 @@ struct CursorLogsQuery {
     operational_class: Option<String>,
     since: Option<i64>,
     until: Option<String>,
+    q: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -593,6 +754,7 @@ struct TokenCursorLogsQuery {
     selection_effect: Option<String>,
     key_id: Option<String>,
     operational_class: Option<String>,
+    q: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -658,6 +820,204 @@ struct AnnouncementsResponse {
     items: Vec<AnnouncementView>,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AlertWebhookSinkMutationRequest {
+    name: String,
+    url: String,
+    template: String,
+    #[serde(default)]
+    alert_types: Vec<String>,
+    telegram_chat_id: Option<String>,
+    secret: Option<String>,
+    repeat_interval_secs: Option<i64>,
+    enabled: Option<bool>,
+}
+
+impl From<AlertWebhookSinkMutationRequest> for tavily_hikari::AlertWebhookSinkMutation {
+    fn from(value: AlertWebhookSinkMutationRequest) -> Self {
+        Self {
+            name: value.name,
+            url: value.url,
+            template: value.template,
+            alert_types: value.alert_types,
+            telegram_chat_id: value.telegram_chat_id,
+            secret: value.secret,
+            repeat_interval_secs: value.repeat_interval_secs,
+            enabled: value.enabled.unwrap_or(true),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AlertWebhookSinkView {
+    id: String,
+    name: String,
+    url: String,
+    template: String,
+    alert_types: Vec<String>,
+    telegram_chat_id: Option<String>,
+    secret: String,
+    repeat_interval_secs: i64,
+    enabled: bool,
+    created_at: i64,
+    updated_at: i64,
+}
+
+impl From<tavily_hikari::AlertWebhookSink> for AlertWebhookSinkView {
+    fn from(value: tavily_hikari::AlertWebhookSink) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            url: value.url,
+            template: value.template,
+            alert_types: value.alert_types,
+            telegram_chat_id: value.telegram_chat_id,
+            secret: value.secret,
+            repeat_interval_secs: value.repeat_interval_secs,
+            enabled: value.enabled,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AlertWebhookSinksResponse {
+    items: Vec<AlertWebhookSinkView>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AlertWebhookDeliveryView {
+    id: i64,
+    sink_id: String,
+    event_id: String,
+    alert_type: String,
+    event_occurred_at: i64,
+    payload: Value,
+    status: String,
+    attempts: i64,
+    next_attempt_at: Option<i64>,
+    last_status_code: Option<i64>,
+    last_error: Option<String>,
+    created_at: i64,
+    updated_at: i64,
+    delivered_at: Option<i64>,
+}
+
+impl From<tavily_hikari::AlertWebhookDelivery> for AlertWebhookDeliveryView {
+    fn from(value: tavily_hikari::AlertWebhookDelivery) -> Self {
+        Self {
+            id: value.id,
+            sink_id: value.sink_id,
+            event_id: value.event_id,
+            alert_type: value.alert_type,
+            event_occurred_at: value.event_occurred_at,
+            payload: serde_json::from_str(&value.payload_json).unwrap_or(Value::Null),
+            status: value.status,
+            attempts: value.attempts,
+            next_attempt_at: value.next_attempt_at,
+            last_status_code: value.last_status_code,
+            last_error: value.last_error,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+            delivered_at: value.delivered_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AlertWebhookDeliveriesResponse {
+    items: Vec<AlertWebhookDeliveryView>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AlertWebhookDeliveriesQuery {
+    sink_id: Option<String>,
+    limit: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AlertRuleMutationRequest {
+    name: String,
+    metric: String,
+    comparator: String,
+    threshold: f64,
+    clear_threshold: Option<f64>,
+    window_secs: Option<i64>,
+    request_kind: Option<String>,
+    enabled: Option<bool>,
+}
+
+impl From<AlertRuleMutationRequest> for tavily_hikari::AlertRuleMutation {
+    fn from(value: AlertRuleMutationRequest) -> Self {
+        Self {
+            name: value.name,
+            metric: value.metric,
+            comparator: value.comparator,
+            threshold: value.threshold,
+            clear_threshold: value.clear_threshold,
+            window_secs: value.window_secs,
+            request_kind: value.request_kind,
+            enabled: value.enabled.unwrap_or(true),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AlertRuleView {
+    id: String,
+    name: String,
+    metric: String,
+    comparator: String,
+    threshold: f64,
+    clear_threshold: Option<f64>,
+    window_secs: i64,
+    request_kind: Option<String>,
+    enabled: bool,
+    created_at: i64,
+    updated_at: i64,
+    state: String,
+    last_value: Option<f64>,
+    last_evaluated_at: Option<i64>,
+    last_fired_at: Option<i64>,
+}
+
+impl From<tavily_hikari::AlertRule> for AlertRuleView {
+    fn from(value: tavily_hikari::AlertRule) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            metric: value.metric,
+            comparator: value.comparator,
+            threshold: value.threshold,
+            clear_threshold: value.clear_threshold,
+            window_secs: value.window_secs,
+            request_kind: value.request_kind,
+            enabled: value.enabled,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+            state: value.state,
+            last_value: value.last_value,
+            last_evaluated_at: value.last_evaluated_at,
+            last_fired_at: value.last_fired_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AlertRulesResponse {
+    items: Vec<AlertRuleView>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct LogFacetOptionView {
@@ -1015,6 +1375,7 @@ struct AlertGroupView {
     event_count: i64,
     children: Vec<AlertGroupView>,
     child_events: Vec<AlertEventView>,
+    workflow: Option<AlertGroupWorkflowView>,
 }
 
 impl From<tavily_hikari::AlertGroupRecord> for AlertGroupView {
@@ -1048,6 +1409,7 @@ impl From<tavily_hikari::AlertGroupRecord> for AlertGroupView {
                 .into_iter()
                 .map(AlertEventView::from)
                 .collect(),
+            workflow: value.workflow.map(AlertGroupWorkflowView::from),
         }
     }
 }
@@ -1391,6 +1753,14 @@ fn normalize_optional_filter(value: Option<&str>) -> Option<&str> {
     })
 }
 
+/// Blank input means no search; input without a searchable term is rejected rather than
+/// silently listing everything.
+fn parse_log_search_filter(value: Option<&str>) -> Result<Option<String>, StatusCode> {
+    normalize_optional_filter(value)
+        .map(|raw| tavily_hikari::request_log_search_match_query(raw).ok_or(StatusCode::BAD_REQUEST))
+        .transpose()
+}
+
 fn parse_request_kind_filters(raw_query: Option<&str>) -> Vec<String> {
     raw_query
         .map(|query| {
@@ -1658,6 +2028,7 @@ async fn get_key_logs_list(
     {
         return Err(StatusCode::BAD_REQUEST);
     }
+    let search = parse_log_search_filter(q.q.as_deref())?;
 
     state
         .proxy
@@ -1671,6 +2042,7 @@ async fn get_key_logs_list(
             selection_effect_code,
             auth_token_id,
             operational_class,
+            search.as_deref(),
             cursor.as_ref(),
             direction,
             page_size,
@@ -2119,6 +2491,7 @@ async fn get_token_logs_list(
     {
         return Err(StatusCode::BAD_REQUEST);
     }
+    let search = parse_log_search_filter(q.q.as_deref())?;
 
     state
         .proxy
@@ -2134,6 +2507,7 @@ async fn get_token_logs_list(
             selection_effect_code,
             key_id,
             operational_class,
+            search.as_deref(),
             cursor.as_ref(),
             direction,
         )
@@ -2550,12 +2924,12 @@ async fn get_token_monthly_broken_keys(
     if !is_admin_request(state.as_ref(), &headers).await {
         return Err(StatusCode::FORBIDDEN);
     }
-    let exists = state
-        .proxy
-        .get_access_token_secret(&id)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-    if exists.is_none() {
+    let exists = match state.proxy.get_access_token_secret(&id).await {
+        Ok(secret) => secret.is_some(),
+        Err(ProxyError::TokenSecretNotRecoverable { .. }) => true,
+        Err(_) => return Err(StatusCode::INTERNAL_SERVER_ERROR),
+    };
+    if !exists {
         return Err(StatusCode::NOT_FOUND);
     }
 
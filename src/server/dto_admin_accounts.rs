@@ -0,0 +1,59 @@
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateAdminAccountRequest {
+    username: String,
+    display_name: Option<String>,
+    role: String,
+    password: String,
+}
+
+/// Omitted fields stay unchanged; an empty display name clears it.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UpdateAdminAccountRequest {
+    display_name: Option<String>,
+    role: Option<String>,
+    disabled: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SetAdminAccountPasswordRequest {
+    password: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AdminAccountView {
+    id: String,
+    username: String,
+    display_name: Option<String>,
+    role: tavily_hikari::AdminRole,
+    created_at: i64,
+    updated_at: i64,
+    last_login_at: Option<i64>,
+    disabled_at: Option<i64>,
+    active_sessions: i64,
+}
+
+impl From<tavily_hikari::AdminAccount> for AdminAccountView {
+    fn from(account: tavily_hikari::AdminAccount) -> Self {
+        Self {
+            id: account.id,
+            username: account.username,
+            display_name: account.display_name,
+            role: account.role,
+            created_at: account.created_at,
+            updated_at: account.updated_at,
+            last_login_at: account.last_login_at,
+            disabled_at: account.disabled_at,
+            active_sessions: account.active_sessions,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RevokeAdminAccountSessionsView {
+    revoked: u64,
+}
@@ -0,0 +1,59 @@
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateAdminApiTokenRequest {
+    name: String,
+    scopes: Vec<String>,
+    #[serde(default)]
+    ip_allowlist: Vec<String>,
+    expires_at: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AdminApiTokenView {
+    id: String,
+    name: String,
+    /// `active`, `expired` or `revoked`.
+    status: &'static str,
+    scopes: Vec<String>,
+    ip_allowlist: Vec<String>,
+    created_by: Option<String>,
+    created_at: i64,
+    expires_at: Option<i64>,
+    last_used_at: Option<i64>,
+    last_used_ip: Option<String>,
+    revoked_at: Option<i64>,
+}
+
+impl AdminApiTokenView {
+    fn new(token: tavily_hikari::AdminApiToken, now: i64) -> Self {
+        let status = if token.revoked_at.is_some() {
+            "revoked"
+        } else if token.expires_at.is_some_and(|expires_at| expires_at <= now) {
+            "expired"
+        } else {
+            "active"
+        };
+        Self {
+            id: token.id,
+            name: token.name,
+            status,
+            scopes: token.scopes.keys().to_vec(),
+            ip_allowlist: token.ip_allowlist,
+            created_by: token.created_by,
+            created_at: token.created_at,
+            expires_at: token.expires_at,
+            last_used_at: token.last_used_at,
+            last_used_ip: token.last_used_ip,
+            revoked_at: token.revoked_at,
+        }
+    }
+}
+
+/// Returned once on creation; `token` is the only copy of the full secret.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CreatedAdminApiTokenView {
+    token: String,
+    api_token: AdminApiTokenView,
+}
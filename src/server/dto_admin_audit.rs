@@ -0,0 +1,87 @@
+#[derive(Debug, Deserialize)]
+struct AdminAuditQuery {
+    page: Option<i64>,
+    per_page: Option<i64>,
+    actor: Option<String>,
+    route: Option<String>,
+    kind: Option<String>,
+    since: Option<i64>,
+    until: Option<i64>,
+}
+
+impl AdminAuditQuery {
+    fn filters(&self) -> tavily_hikari::AdminAuditFilters {
+        tavily_hikari::AdminAuditFilters {
+            actor: self.actor.clone(),
+            route: self.route.clone(),
+            kind: self.kind.clone(),
+            since: self.since,
+            until: self.until,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AdminAuditEntryView {
+    seq: i64,
+    occurred_at: i64,
+    kind: String,
+    actor_kind: String,
+    actor_id: Option<String>,
+    actor_name: Option<String>,
+    client_ip: Option<String>,
+    method: String,
+    route: String,
+    path: String,
+    status: i64,
+    before: Option<Value>,
+    after: Option<Value>,
+    prev_hash: String,
+    hash: String,
+}
+
+impl From<tavily_hikari::AdminAuditEntry> for AdminAuditEntryView {
+    fn from(value: tavily_hikari::AdminAuditEntry) -> Self {
+        let parse = |raw: Option<String>| {
+            raw.map(|raw| serde_json::from_str::<Value>(&raw).unwrap_or(Value::String(raw)))
+        };
+        Self {
+            seq: value.seq,
+            occurred_at: value.occurred_at,
+            kind: value.kind,
+            actor_kind: value.actor_kind,
+            actor_id: value.actor_id,
+            actor_name: value.actor_name,
+            client_ip: value.client_ip,
+            method: value.method,
+            route: value.route,
+            path: value.path,
+            status: value.status,
+            before: parse(value.before_json),
+            after: parse(value.after_json),
+            prev_hash: value.prev_hash,
+            hash: value.hash,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PaginatedAdminAuditEntriesView {
+    items: Vec<AdminAuditEntryView>,
+    total: i64,
+    page: i64,
+    per_page: i64,
+}
+
+impl From<tavily_hikari::PaginatedAdminAuditEntries> for PaginatedAdminAuditEntriesView {
+    fn from(value: tavily_hikari::PaginatedAdminAuditEntries) -> Self {
+        Self {
+            items: value.items.into_iter().map(AdminAuditEntryView::from).collect(),
+            total: value.total,
+            page: value.page,
+            per_page: value.per_page,
+        }
+    }
+}
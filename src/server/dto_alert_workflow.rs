@@ -0,0 +1,135 @@
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AlertGroupWorkflowView {
+    status: String,
+    actor: Option<String>,
+    note: Option<String>,
+    updated_at: Option<i64>,
+    reopened: bool,
+}
+
+impl From<tavily_hikari::AlertGroupWorkflow> for AlertGroupWorkflowView {
+    fn from(value: tavily_hikari::AlertGroupWorkflow) -> Self {
+        Self {
+            status: value.status,
+            actor: value.actor,
+            note: value.note,
+            updated_at: value.updated_at,
+            reopened: value.reopened,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AlertGroupActionRequest {
+    group_id: String,
+    action: String,
+    note: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AlertGroupHistoryQuery {
+    group_id: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AlertGroupWorkflowEventView {
+    id: String,
+    group_id: String,
+    action: String,
+    actor: String,
+    note: Option<String>,
+    created_at: i64,
+}
+
+impl From<tavily_hikari::AlertGroupWorkflowEvent> for AlertGroupWorkflowEventView {
+    fn from(value: tavily_hikari::AlertGroupWorkflowEvent) -> Self {
+        Self {
+            id: value.id,
+            group_id: value.group_id,
+            action: value.action,
+            actor: value.actor,
+            note: value.note,
+            created_at: value.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AlertGroupHistoryResponse {
+    items: Vec<AlertGroupWorkflowEventView>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AlertSilencesQuery {
+    include_expired: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AlertSilenceCreateRequest {
+    #[serde(rename = "type")]
+    alert_type: Option<String>,
+    subject_kind: Option<String>,
+    subject_id: Option<String>,
+    starts_at: Option<i64>,
+    ends_at: i64,
+    note: Option<String>,
+}
+
+impl From<AlertSilenceCreateRequest> for tavily_hikari::AlertSilenceMutation {
+    fn from(value: AlertSilenceCreateRequest) -> Self {
+        Self {
+            alert_type: value.alert_type,
+            subject_kind: value.subject_kind,
+            subject_id: value.subject_id,
+            starts_at: value.starts_at,
+            ends_at: value.ends_at,
+            note: value.note,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AlertSilenceView {
+    id: String,
+    #[serde(rename = "type")]
+    alert_type: Option<String>,
+    subject_kind: Option<String>,
+    subject_id: Option<String>,
+    starts_at: i64,
+    ends_at: i64,
+    actor: String,
+    note: Option<String>,
+    created_at: i64,
+    active: bool,
+}
+
+impl AlertSilenceView {
+    fn new(value: tavily_hikari::AlertSilence, now: i64) -> Self {
+        Self {
+            active: value.is_active(now),
+            id: value.id,
+            alert_type: value.alert_type,
+            subject_kind: value.subject_kind,
+            subject_id: value.subject_id,
+            starts_at: value.starts_at,
+            ends_at: value.ends_at,
+            actor: value.actor,
+            note: value.note,
+            created_at: value.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AlertSilencesResponse {
+    items: Vec<AlertSilenceView>,
+}
@@ -0,0 +1,88 @@
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateRegistrationInviteRequest {
+    code: Option<String>,
+    note: Option<String>,
+    max_uses: Option<i64>,
+    expires_at: Option<i64>,
+    #[serde(default)]
+    tag_ids: Vec<String>,
+    quota: Option<tavily_hikari::RegistrationInviteQuota>,
+}
+
+impl From<CreateRegistrationInviteRequest> for tavily_hikari::RegistrationInviteInput {
+    fn from(value: CreateRegistrationInviteRequest) -> Self {
+        let mut tag_ids = value
+            .tag_ids
+            .into_iter()
+            .map(|tag_id| tag_id.trim().to_string())
+            .filter(|tag_id| !tag_id.is_empty())
+            .collect::<Vec<_>>();
+        tag_ids.sort();
+        tag_ids.dedup();
+        Self {
+            code: normalize_optional_text(value.code),
+            note: normalize_optional_text(value.note),
+            max_uses: value.max_uses.unwrap_or(1),
+            expires_at: value.expires_at,
+            tag_ids,
+            quota: value.quota,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RegistrationInviteView {
+    id: String,
+    code: String,
+    note: Option<String>,
+    status: &'static str,
+    max_uses: i64,
+    use_count: i64,
+    expires_at: Option<i64>,
+    tag_ids: Vec<String>,
+    quota: Option<tavily_hikari::RegistrationInviteQuota>,
+    created_at: i64,
+    revoked_at: Option<i64>,
+}
+
+impl RegistrationInviteView {
+    fn from_invite(invite: tavily_hikari::RegistrationInvite, now: i64) -> Self {
+        Self {
+            status: invite.status(now),
+            id: invite.id,
+            code: invite.code,
+            note: invite.note,
+            max_uses: invite.max_uses,
+            use_count: invite.use_count,
+            expires_at: invite.expires_at,
+            tag_ids: invite.tag_ids,
+            quota: invite.quota,
+            created_at: invite.created_at,
+            revoked_at: invite.revoked_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PendingApprovalUserView {
+    user_id: String,
+    display_name: Option<String>,
+    username: Option<String>,
+    provider: Option<String>,
+    requested_at: i64,
+}
+
+impl From<tavily_hikari::PendingApprovalUser> for PendingApprovalUserView {
+    fn from(value: tavily_hikari::PendingApprovalUser) -> Self {
+        Self {
+            user_id: value.user_id,
+            display_name: value.display_name,
+            username: value.username,
+            provider: value.provider,
+            requested_at: value.requested_at,
+        }
+    }
+}
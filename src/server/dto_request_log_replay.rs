@@ -0,0 +1,60 @@
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RequestLogReplayRequest {
+    key_id: Option<String>,
+    upstream: Option<String>,
+    body: Option<Value>,
+}
+
+impl From<RequestLogReplayRequest> for tavily_hikari::RequestLogReplayInput {
+    fn from(value: RequestLogReplayRequest) -> Self {
+        Self {
+            api_key_id: normalize_optional_text(value.key_id),
+            upstream_base: normalize_optional_text(value.upstream),
+            body: value.body,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RequestLogReplayUpstreamsView {
+    primary: String,
+    alternates: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RequestLogReplayView {
+    id: i64,
+    source_log_id: i64,
+    key_id: String,
+    upstream: String,
+    method: String,
+    path: String,
+    status_code: Option<i64>,
+    duration_ms: i64,
+    request_body: Option<String>,
+    response_body: Option<String>,
+    error_message: Option<String>,
+    created_at: i64,
+}
+
+impl From<tavily_hikari::RequestLogReplay> for RequestLogReplayView {
+    fn from(value: tavily_hikari::RequestLogReplay) -> Self {
+        Self {
+            id: value.id,
+            source_log_id: value.source_log_id,
+            key_id: value.api_key_id,
+            upstream: value.upstream_base,
+            method: value.method,
+            path: value.path,
+            status_code: value.status_code,
+            duration_ms: value.duration_ms,
+            request_body: value.request_body.as_deref().and_then(decode_body),
+            response_body: value.response_body.as_deref().and_then(decode_body),
+            error_message: value.error_message,
+            created_at: value.created_at,
+        }
+    }
+}
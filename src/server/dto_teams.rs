@@ -0,0 +1,191 @@
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TeamRequest {
+    name: String,
+    limits: tavily_hikari::TeamQuotaLimits,
+    enforce_member_limits: Option<bool>,
+}
+
+impl From<TeamRequest> for tavily_hikari::TeamInput {
+    fn from(value: TeamRequest) -> Self {
+        Self {
+            name: value.name.trim().to_string(),
+            limits: value.limits,
+            enforce_member_limits: value.enforce_member_limits.unwrap_or(true),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TeamMemberRequest {
+    role: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TeamTokenRequest {
+    note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TeamView {
+    id: String,
+    name: String,
+    limits: tavily_hikari::TeamQuotaLimits,
+    enforce_member_limits: bool,
+    member_count: i64,
+    token_count: i64,
+    created_at: i64,
+    updated_at: i64,
+}
+
+impl From<tavily_hikari::Team> for TeamView {
+    fn from(value: tavily_hikari::Team) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            limits: value.limits,
+            enforce_member_limits: value.enforce_member_limits,
+            member_count: value.member_count,
+            token_count: value.token_count,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+/// Team pool usage against its limits. `window` names the exhausted window, if any.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TeamQuotaView {
+    hourly_used: i64,
+    hourly_limit: i64,
+    daily_used: i64,
+    daily_limit: i64,
+    monthly_used: i64,
+    monthly_limit: i64,
+    window: Option<&'static str>,
+}
+
+impl From<tavily_hikari::TokenQuotaVerdict> for TeamQuotaView {
+    fn from(value: tavily_hikari::TokenQuotaVerdict) -> Self {
+        Self {
+            window: value.window_name(),
+            hourly_used: value.hourly_used,
+            hourly_limit: value.hourly_limit,
+            daily_used: value.daily_used,
+            daily_limit: value.daily_limit,
+            monthly_used: value.monthly_used,
+            monthly_limit: value.monthly_limit,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TeamMemberView {
+    user_id: String,
+    display_name: Option<String>,
+    username: Option<String>,
+    role: tavily_hikari::TeamRole,
+    joined_at: i64,
+}
+
+impl From<tavily_hikari::TeamMember> for TeamMemberView {
+    fn from(value: tavily_hikari::TeamMember) -> Self {
+        Self {
+            user_id: value.user_id,
+            display_name: value.display_name,
+            username: value.username,
+            role: value.role,
+            joined_at: value.joined_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TeamTokenView {
+    token_id: String,
+    note: Option<String>,
+    enabled: bool,
+    created_by: Option<String>,
+    created_at: i64,
+    last_used_at: Option<i64>,
+}
+
+impl From<tavily_hikari::TeamToken> for TeamTokenView {
+    fn from(value: tavily_hikari::TeamToken) -> Self {
+        Self {
+            token_id: value.token_id,
+            note: value.note,
+            enabled: value.enabled,
+            created_by: value.created_by,
+            created_at: value.created_at,
+            last_used_at: value.last_used_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TeamDetailView {
+    team: TeamView,
+    quota: TeamQuotaView,
+    members: Vec<TeamMemberView>,
+    tokens: Vec<TeamTokenView>,
+}
+
+/// The signed-in user's team, or `team: null` when they are not in one.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UserTeamView {
+    team: Option<TeamView>,
+    role: Option<tavily_hikari::TeamRole>,
+    quota: Option<TeamQuotaView>,
+    members: Vec<TeamMemberView>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CreatedTeamTokenView {
+    /// Full token value; team token secrets are shown once, like other new tokens.
+    token: String,
+    token_id: String,
+}
+
+fn team_usage_series_view(usage: tavily_hikari::AdminUserUsageSeries) -> AdminUserUsageSeriesView {
+    AdminUserUsageSeriesView::QuotaLike {
+        limit: usage.limit,
+        points: usage
+            .points
+            .into_iter()
+            .map(|point| AdminUserUsageSeriesQuotaPointView {
+                bucket_start: point.bucket_start,
+                display_bucket_start: point.display_bucket_start,
+                value: point.value,
+                limit_value: point.limit_value,
+            })
+            .collect(),
+    }
+}
+
+/// Team usage series only cover daily and monthly credits.
+fn parse_team_usage_series(
+    raw: Option<&str>,
+) -> Result<tavily_hikari::AdminUserUsageSeriesKind, (StatusCode, String)> {
+    use tavily_hikari::AdminUserUsageSeriesKind;
+
+    let Some(raw) = raw else {
+        return Err((StatusCode::BAD_REQUEST, "series is required".to_string()));
+    };
+    match AdminUserUsageSeriesKind::parse(raw) {
+        Some(
+            series @ (AdminUserUsageSeriesKind::DailyCredits
+            | AdminUserUsageSeriesKind::MonthlyCredits),
+        ) => Ok(series),
+        _ => Err((StatusCode::BAD_REQUEST, "invalid series".to_string())),
+    }
+}
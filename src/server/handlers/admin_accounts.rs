@@ -0,0 +1,260 @@
+fn admin_account_session_set_cookie(token: &str, secure: bool) -> Result<HeaderValue, StatusCode> {
+    let secure = if secure { "; Secure" } else { "" };
+    let cookie = format!(
+        "{name}={token}; Path=/; HttpOnly; SameSite=Lax; Max-Age={max_age}{secure}",
+        name = ADMIN_ACCOUNT_COOKIE_NAME,
+        max_age = BUILTIN_ADMIN_SESSION_MAX_AGE_SECS,
+        secure = secure
+    );
+    HeaderValue::from_str(&cookie).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+fn admin_account_session_clear_cookie(secure: bool) -> Result<HeaderValue, StatusCode> {
+    let secure = if secure { "; Secure" } else { "" };
+    let cookie = format!(
+        "{name}=; Path=/; HttpOnly; SameSite=Lax; Max-Age=0{secure}",
+        name = ADMIN_ACCOUNT_COOKIE_NAME,
+        secure = secure
+    );
+    HeaderValue::from_str(&cookie).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+fn hash_admin_account_password(password: &str) -> Result<String, (StatusCode, String)> {
+    if password.trim().len() < tavily_hikari::ADMIN_ACCOUNT_PASSWORD_MIN_LEN {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!(
+                "password must be at least {} characters",
+                tavily_hikari::ADMIN_ACCOUNT_PASSWORD_MIN_LEN
+            ),
+        ));
+    }
+    let salt = SaltString::generate(&mut OsRng);
+    Argon2::default()
+        .hash_password(password.trim().as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|err| {
+            eprintln!("hash admin account password error: {err}");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "failed to hash password".to_string(),
+            )
+        })
+}
+
+fn parse_admin_role(raw: &str) -> Result<tavily_hikari::AdminRole, (StatusCode, String)> {
+    tavily_hikari::AdminRole::parse(raw).ok_or_else(|| {
+        (
+            StatusCode::BAD_REQUEST,
+            "role must be viewer, operator, or owner".to_string(),
+        )
+    })
+}
+
+/// Signs in a named admin account. Called by `POST /api/admin/login` when a username is given.
+async fn admin_account_login(
+    state: &AppState,
+    headers: &HeaderMap,
+    username: &str,
+    password: &str,
+) -> Result<Response<Body>, StatusCode> {
+    let credential = state
+        .proxy
+        .admin_account_credential(username)
+        .await
+        .map_err(|err| {
+            eprintln!("load admin account credential error: {err}");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+        .ok_or(StatusCode::UNAUTHORIZED)?;
+    let parsed =
+        PasswordHash::new(&credential.password_hash).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    if Argon2::default()
+        .verify_password(password.as_bytes(), &parsed)
+        .is_err()
+    {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+    let token = state
+        .proxy
+        .create_admin_account_session(
+            &credential.account.id,
+            BUILTIN_ADMIN_SESSION_MAX_AGE_SECS as i64,
+        )
+        .await
+        .map_err(|err| {
+            eprintln!("create admin account session error: {err}");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+    let cookie = admin_account_session_set_cookie(&token, wants_secure_cookie(headers))?;
+    Ok((
+        StatusCode::OK,
+        [(SET_COOKIE, cookie)],
+        Json(AdminLoginResponse { ok: true }),
+    )
+        .into_response())
+}
+
+async fn list_admin_accounts(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<AdminAccountView>>, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    state
+        .proxy
+        .list_admin_accounts()
+        .await
+        .map(|accounts| Json(accounts.into_iter().map(AdminAccountView::from).collect()))
+        .map_err(|err| admin_proxy_error_response("list admin accounts error", err))
+}
+
+async fn create_admin_account(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(payload): Json<CreateAdminAccountRequest>,
+) -> Result<(StatusCode, Json<AdminAccountView>), (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    require_full_master_write(state.as_ref()).await?;
+
+    let username = tavily_hikari::normalize_admin_account_username(&payload.username)
+        .map_err(|detail| (StatusCode::BAD_REQUEST, detail))?;
+    let role = parse_admin_role(&payload.role)?;
+    let password_hash = hash_admin_account_password(&payload.password)?;
+    match state
+        .proxy
+        .create_admin_account(
+            &username,
+            payload.display_name.as_deref(),
+            role,
+            &password_hash,
+        )
+        .await
+    {
+        Ok(Some(account)) => Ok((StatusCode::CREATED, Json(AdminAccountView::from(account)))),
+        Ok(None) => Err((StatusCode::CONFLICT, "username already exists".to_string())),
+        Err(err) => Err(admin_proxy_error_response("create admin account error", err)),
+    }
+}
+
+/// Rejects changes that would lock the calling account out of account management.
+async fn reject_admin_account_self_lockout(
+    state: &AppState,
+    headers: &HeaderMap,
+    account_id: &str,
+) -> Result<(), (StatusCode, String)> {
+    if resolve_admin_account_session(state, headers)
+        .await
+        .is_some_and(|session| session.account_id == account_id)
+    {
+        return Err((
+            StatusCode::CONFLICT,
+            "admins cannot demote, disable, or delete their own account".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+async fn update_admin_account(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(id): Path<String>,
+    Json(payload): Json<UpdateAdminAccountRequest>,
+) -> Result<Json<AdminAccountView>, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    require_full_master_write(state.as_ref()).await?;
+
+    let role = payload.role.as_deref().map(parse_admin_role).transpose()?;
+    if role.is_some_and(|role| role != tavily_hikari::AdminRole::Owner)
+        || payload.disabled == Some(true)
+    {
+        reject_admin_account_self_lockout(state.as_ref(), &headers, &id).await?;
+    }
+    let display_name = payload.display_name.as_deref().map(|name| {
+        let trimmed = name.trim();
+        (!trimmed.is_empty()).then_some(trimmed)
+    });
+    state
+        .proxy
+        .update_admin_account(&id, display_name, role, payload.disabled)
+        .await
+        .map_err(|err| admin_proxy_error_response("update admin account error", err))?
+        .map(|account| Json(AdminAccountView::from(account)))
+        .ok_or((StatusCode::NOT_FOUND, "admin account not found".to_string()))
+}
+
+async fn put_admin_account_password(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(id): Path<String>,
+    Json(payload): Json<SetAdminAccountPasswordRequest>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    require_full_master_write(state.as_ref()).await?;
+
+    let password_hash = hash_admin_account_password(&payload.password)?;
+    match state
+        .proxy
+        .set_admin_account_password_hash(&id, &password_hash)
+        .await
+    {
+        Ok(true) => Ok(StatusCode::NO_CONTENT),
+        Ok(false) => Err((StatusCode::NOT_FOUND, "admin account not found".to_string())),
+        Err(err) => Err(admin_proxy_error_response(
+            "set admin account password error",
+            err,
+        )),
+    }
+}
+
+async fn delete_admin_account(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(id): Path<String>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    require_full_master_write(state.as_ref()).await?;
+    reject_admin_account_self_lockout(state.as_ref(), &headers, &id).await?;
+
+    match state.proxy.delete_admin_account(&id).await {
+        Ok(true) => Ok(StatusCode::NO_CONTENT),
+        Ok(false) => Err((StatusCode::NOT_FOUND, "admin account not found".to_string())),
+        Err(err) => Err(admin_proxy_error_response("delete admin account error", err)),
+    }
+}
+
+async fn delete_admin_account_sessions(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(id): Path<String>,
+) -> Result<Json<RevokeAdminAccountSessionsView>, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    require_full_master_write(state.as_ref()).await?;
+
+    if state
+        .proxy
+        .fetch_admin_account(&id)
+        .await
+        .map_err(|err| admin_proxy_error_response("load admin account error", err))?
+        .is_none()
+    {
+        return Err((StatusCode::NOT_FOUND, "admin account not found".to_string()));
+    }
+    let revoked = state
+        .proxy
+        .revoke_admin_account_sessions(&id)
+        .await
+        .map_err(|err| admin_proxy_error_response("revoke admin account sessions error", err))?;
+    Ok(Json(RevokeAdminAccountSessionsView { revoked }))
+}
@@ -0,0 +1,95 @@
+const ADMIN_API_TOKEN_NAME_MAX_LEN: usize = 64;
+
+async fn list_admin_api_tokens(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<AdminApiTokenView>>, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    let now = state.proxy.backend_time().now_ts();
+    state
+        .proxy
+        .list_admin_api_tokens()
+        .await
+        .map(|tokens| {
+            Json(
+                tokens
+                    .into_iter()
+                    .map(|token| AdminApiTokenView::new(token, now))
+                    .collect(),
+            )
+        })
+        .map_err(|err| admin_proxy_error_response("list admin API tokens error", err))
+}
+
+async fn create_admin_api_token(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(payload): Json<CreateAdminApiTokenRequest>,
+) -> Result<(StatusCode, Json<CreatedAdminApiTokenView>), (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    require_full_master_write(state.as_ref()).await?;
+
+    let name = payload.name.trim();
+    if name.is_empty() || name.chars().count() > ADMIN_API_TOKEN_NAME_MAX_LEN {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("name must be 1-{ADMIN_API_TOKEN_NAME_MAX_LEN} characters"),
+        ));
+    }
+    let scopes = tavily_hikari::AdminApiTokenScopes::parse(&payload.scopes)
+        .map_err(|detail| (StatusCode::BAD_REQUEST, detail))?;
+    let ip_allowlist = tavily_hikari::normalize_admin_api_token_ip_allowlist(&payload.ip_allowlist)
+        .map_err(|detail| (StatusCode::BAD_REQUEST, detail))?;
+    let now = state.proxy.backend_time().now_ts();
+    if payload.expires_at.is_some_and(|expires_at| expires_at <= now) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "expiresAt must be in the future".to_string(),
+        ));
+    }
+    let created_by = admin_audit_actor(state.as_ref(), &headers)
+        .await
+        .and_then(|actor| actor.name);
+    let (api_token, token) = state
+        .proxy
+        .create_admin_api_token(
+            name,
+            &scopes,
+            &ip_allowlist,
+            payload.expires_at,
+            created_by.as_deref(),
+        )
+        .await
+        .map_err(|err| admin_proxy_error_response("create admin API token error", err))?;
+    Ok((
+        StatusCode::CREATED,
+        Json(CreatedAdminApiTokenView {
+            token,
+            api_token: AdminApiTokenView::new(api_token, now),
+        }),
+    ))
+}
+
+async fn revoke_admin_api_token(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(id): Path<String>,
+) -> Result<Json<AdminApiTokenView>, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    require_full_master_write(state.as_ref()).await?;
+
+    let now = state.proxy.backend_time().now_ts();
+    state
+        .proxy
+        .revoke_admin_api_token(&id)
+        .await
+        .map_err(|err| admin_proxy_error_response("revoke admin API token error", err))?
+        .map(|token| Json(AdminApiTokenView::new(token, now)))
+        .ok_or((StatusCode::NOT_FOUND, "admin API token not found".to_string()))
+}
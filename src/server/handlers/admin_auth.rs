@@ -72,6 +72,7 @@ fn manual_trigger_supported(job_type: &str) -> bool {
             | "linuxdo_user_tag_binding_refresh"
             | "forward_proxy_geo_refresh"
             | "db_compaction"
+            | "token_expiry_notice"
     )
 }
 
@@ -526,9 +527,12 @@ struct ProfileView {
     forward_auth_enabled: bool,
     builtin_auth_enabled: bool,
     passkey_auth_enabled: bool,
+    admin_accounts_enabled: bool,
     admin_login_totp_required: bool,
     allow_registration: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
+    admin_role: Option<tavily_hikari::AdminRole>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     user_logged_in: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     user_provider: Option<String>,
@@ -536,6 +540,37 @@ struct ProfileView {
     user_display_name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     user_avatar_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user_pending_approval: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user_login_providers: Option<Vec<UserLoginProviderView>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UserLoginProviderView {
+    id: &'static str,
+    label: String,
+    start_path: &'static str,
+}
+
+fn user_login_providers(state: &AppState) -> Vec<UserLoginProviderView> {
+    let mut providers = Vec::new();
+    if state.linuxdo_oauth.is_enabled_and_configured() {
+        providers.push(UserLoginProviderView {
+            id: "linuxdo",
+            label: "LinuxDo".to_string(),
+            start_path: "/auth/linuxdo",
+        });
+    }
+    if state.oidc.is_enabled_and_configured() {
+        providers.push(UserLoginProviderView {
+            id: OIDC_PROVIDER,
+            label: state.oidc.display_name.clone(),
+            start_path: "/auth/oidc",
+        });
+    }
+    providers
 }
 
 fn resolve_linuxdo_avatar_url(
@@ -1271,12 +1306,15 @@ struct ForwardAuthDebugView {
 #[serde(rename_all = "camelCase")]
 struct AdminRegistrationSettingsView {
     allow_registration: bool,
+    require_approval: bool,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct UpdateAdminRegistrationSettingsRequest {
     allow_registration: bool,
+    /// Omitted by older clients that only toggle registration on and off.
+    require_approval: Option<bool>,
 }
 
 async fn get_forward_auth_debug(
@@ -1336,6 +1374,14 @@ async fn get_profile(
             }),
         _ => false,
     };
+    let admin_accounts_enabled = state
+        .proxy
+        .has_active_admin_accounts()
+        .await
+        .unwrap_or_else(|err| {
+            eprintln!("get admin accounts enabled error: {err}");
+            false
+        });
 
     if state.dev_open_admin {
         return Ok(Json(ProfileView {
@@ -1344,12 +1390,16 @@ async fn get_profile(
             forward_auth_enabled,
             builtin_auth_enabled,
             passkey_auth_enabled,
+            admin_accounts_enabled,
             admin_login_totp_required,
             allow_registration,
+            admin_role: Some(tavily_hikari::AdminRole::Owner),
             user_logged_in: None,
             user_provider: None,
             user_display_name: None,
             user_avatar_url: None,
+            user_pending_approval: None,
+            user_login_providers: None,
         }));
     }
 
@@ -1367,14 +1417,24 @@ async fn get_profile(
         None
     };
 
-    let is_admin = is_admin_request(state.as_ref(), &headers).await;
+    let admin_role = resolve_admin_role(state.as_ref(), &headers).await;
+    let is_admin = admin_role.is_some();
+    let admin_account = if is_admin {
+        resolve_admin_account_session(state.as_ref(), &headers).await
+    } else {
+        None
+    };
 
     let display_name = forward_nickname
+        .or_else(|| {
+            admin_account
+                .map(|session| session.display_name.unwrap_or(session.username))
+        })
         .or_else(|| config.admin_override_name().map(str::to_string))
         .or_else(|| is_admin.then(|| "admin".to_string()));
 
     let user_session = resolve_user_session(state.as_ref(), &headers).await;
-    let user_logged_in = if state.linuxdo_oauth.is_enabled_and_configured() {
+    let user_logged_in = if state.user_login_enabled() {
         Some(user_session.is_some())
     } else {
         None
@@ -1390,12 +1450,32 @@ async fn get_profile(
             .or_else(|| session.user.username.clone())
     });
     let user_avatar_url = user_session.as_ref().and_then(|session| {
-        if session.user.provider == "linuxdo" {
-            resolve_linuxdo_avatar_url(&state.linuxdo_oauth, session.user.avatar_template.as_deref())
-        } else {
-            None
+        match session.user.provider.as_str() {
+            "linuxdo" => resolve_linuxdo_avatar_url(
+                &state.linuxdo_oauth,
+                session.user.avatar_template.as_deref(),
+            ),
+            // OIDC avatars are stored as absolute http(s) URLs when the account is synced.
+            OIDC_PROVIDER => session.user.avatar_template.clone(),
+            _ => None,
         }
     });
+    let user_pending_approval = match user_session.as_ref() {
+        Some(session) => Some(
+            state
+                .proxy
+                .user_pending_approval(&session.user.user_id)
+                .await
+                .map_err(|err| {
+                    eprintln!("read user approval state error: {err}");
+                    StatusCode::INTERNAL_SERVER_ERROR
+                })?,
+        ),
+        None => None,
+    };
+    let user_login_providers = state
+        .user_login_enabled()
+        .then(|| user_login_providers(state.as_ref()));
 
     Ok(Json(ProfileView {
         display_name,
@@ -1403,12 +1483,16 @@ async fn get_profile(
         forward_auth_enabled,
         builtin_auth_enabled,
         passkey_auth_enabled,
+        admin_accounts_enabled,
         admin_login_totp_required,
         allow_registration,
+        admin_role,
         user_logged_in,
         user_provider,
         user_display_name,
         user_avatar_url,
+        user_pending_approval,
+        user_login_providers,
     }))
 }
 
@@ -1423,7 +1507,18 @@ async fn get_admin_registration_settings(
         eprintln!("get admin registration settings error: {err}");
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
-    Ok(Json(AdminRegistrationSettingsView { allow_registration }))
+    let require_approval = state
+        .proxy
+        .registration_requires_approval()
+        .await
+        .map_err(|err| {
+            eprintln!("get admin registration approval setting error: {err}");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+    Ok(Json(AdminRegistrationSettingsView {
+        allow_registration,
+        require_approval,
+    }))
 }
 
 async fn patch_admin_registration_settings(
@@ -1449,12 +1544,25 @@ async fn patch_admin_registration_settings(
             eprintln!("patch admin registration settings error: {err}");
             StatusCode::INTERNAL_SERVER_ERROR
         })?;
-    Ok(Json(AdminRegistrationSettingsView { allow_registration }))
+    let require_approval = match payload.require_approval {
+        Some(required) => state.proxy.set_registration_requires_approval(required).await,
+        None => state.proxy.registration_requires_approval().await,
+    }
+    .map_err(|err| {
+        eprintln!("patch admin registration approval setting error: {err}");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    Ok(Json(AdminRegistrationSettingsView {
+        allow_registration,
+        require_approval,
+    }))
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct AdminLoginRequest {
+    /// Named admin account to sign in as; the builtin admin password is used when omitted.
+    username: Option<String>,
     password: String,
     totp_code: Option<String>,
 }
@@ -2342,6 +2450,15 @@ async fn post_admin_login(
     headers: HeaderMap,
     Json(payload): Json<AdminLoginRequest>,
 ) -> Result<Response<Body>, StatusCode> {
+    if let Some(username) = payload
+        .username
+        .as_deref()
+        .map(str::trim)
+        .filter(|username| !username.is_empty())
+    {
+        return admin_account_login(state.as_ref(), &headers, username, payload.password.trim())
+            .await;
+    }
     if !state.builtin_admin.is_enabled() {
         return Err(StatusCode::NOT_FOUND);
     }
@@ -2369,10 +2486,24 @@ async fn post_admin_logout(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
 ) -> Result<Response<Body>, StatusCode> {
-    if !state.builtin_admin.is_enabled() && !state.admin_passkey.is_configured() {
+    let account_token = cookie_value(&headers, ADMIN_ACCOUNT_COOKIE_NAME);
+    if !state.builtin_admin.is_enabled()
+        && !state.admin_passkey.is_configured()
+        && account_token.is_none()
+    {
         return Err(StatusCode::NOT_FOUND);
     }
     state.builtin_admin.forget_session(&headers);
+    if let Some(token) = account_token.as_deref() {
+        state
+            .proxy
+            .revoke_admin_account_session(token)
+            .await
+            .map_err(|err| {
+                eprintln!("revoke admin account session error: {err}");
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+    }
     if let Some(token) = cookie_value(&headers, ADMIN_PASSKEY_COOKIE_NAME)
         && let Some(scope) = state.admin_passkey.scope.as_ref()
     {
@@ -2388,9 +2519,14 @@ async fn post_admin_logout(
     let secure = wants_secure_cookie(&headers);
     let builtin_cookie = session_clear_cookie(secure)?;
     let passkey_cookie = passkey_session_clear_cookie(secure)?;
+    let account_cookie = admin_account_session_clear_cookie(secure)?;
     Ok((
         StatusCode::NO_CONTENT,
-        AppendHeaders([(SET_COOKIE, builtin_cookie), (SET_COOKIE, passkey_cookie)]),
+        AppendHeaders([
+            (SET_COOKIE, builtin_cookie),
+            (SET_COOKIE, passkey_cookie),
+            (SET_COOKIE, account_cookie),
+        ]),
     )
         .into_response())
 }
@@ -2453,6 +2589,7 @@ mod admin_auth_last_login_method_tests {
                 login_state_ttl_secs: 600,
             },
             linuxdo_credit: LinuxDoCreditOptions::disabled(),
+            oidc: OidcOptions::disabled(),
             ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
             dev_open_admin: false,
             usage_base: "http://127.0.0.1:58088".to_string(),
@@ -2488,6 +2625,7 @@ mod admin_auth_last_login_method_tests {
             State(state.clone()),
             HeaderMap::new(),
             Json(AdminLoginRequest {
+                username: None,
                 password: "pw-123456".to_string(),
                 totp_code: None,
             }),
@@ -2624,6 +2762,7 @@ mod admin_auth_last_login_method_tests {
             State(state.clone()),
             HeaderMap::new(),
             Json(AdminLoginRequest {
+                username: None,
                 password: "pw-123456".to_string(),
                 totp_code: None,
             }),
@@ -5,6 +5,14 @@ include!("admin_resources/admin_user_views_and_sort.rs");
 include!("admin_resources/user_tag_and_token_handlers.rs");
 include!("admin_resources/announcements.rs");
 include!("admin_resources/alerts.rs");
+include!("admin_resources/alert_webhooks.rs");
+include!("admin_resources/alert_rules.rs");
+include!("admin_resources/alert_workflow.rs");
+include!("admin_resources/admin_audit.rs");
+include!("admin_resources/request_log_replay.rs");
+include!("admin_resources/registration_invites.rs");
+include!("admin_resources/teams.rs");
 include!("admin_resources/recharges_and_totp.rs");
 include!("admin_resources/ha.rs");
+include!("admin_resources/metrics.rs");
 include!("admin_resources/tests.rs");
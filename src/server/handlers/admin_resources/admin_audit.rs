@@ -0,0 +1,34 @@
+async fn get_admin_audit_entries(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Query(q): Query<AdminAuditQuery>,
+) -> Result<Json<PaginatedAdminAuditEntriesView>, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    state
+        .proxy
+        .admin_audit_entries_page(
+            &q.filters(),
+            q.page.unwrap_or(1),
+            q.per_page.unwrap_or(50),
+        )
+        .await
+        .map(|page| Json(PaginatedAdminAuditEntriesView::from(page)))
+        .map_err(|err| admin_proxy_error_response("admin audit list error", err))
+}
+
+async fn get_admin_audit_verification(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<tavily_hikari::AdminAuditVerification>, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    state
+        .proxy
+        .verify_admin_audit_chain()
+        .await
+        .map(Json)
+        .map_err(|err| admin_proxy_error_response("admin audit verify error", err))
+}
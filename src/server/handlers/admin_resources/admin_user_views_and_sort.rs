@@ -126,6 +126,7 @@ struct AdminUserSummaryView {
     display_name: Option<String>,
     username: Option<String>,
     active: bool,
+    pending_approval: bool,
     last_login_at: Option<i64>,
     token_count: i64,
     api_key_count: i64,
@@ -249,6 +250,7 @@ struct AdminUserDetailView {
     display_name: Option<String>,
     username: Option<String>,
     active: bool,
+    pending_approval: bool,
     last_login_at: Option<i64>,
     token_count: i64,
     api_key_count: i64,
@@ -687,6 +689,7 @@ fn default_request_rate_view(scope: tavily_hikari::RequestRateScope) -> tavily_h
 }
 
 struct AdminUserSummaryViewInput {
+    pending_approval: bool,
     api_key_count: i64,
     monthly_broken_count: i64,
     monthly_broken_limit: i64,
@@ -710,6 +713,7 @@ fn build_admin_user_summary_view(
         display_name: user.display_name.clone(),
         username: user.username.clone(),
         active: user.active,
+        pending_approval: input.pending_approval,
         last_login_at: user.last_login_at,
         token_count: user.token_count,
         api_key_count: input.api_key_count,
@@ -0,0 +1,76 @@
+fn alert_rule_not_found() -> (StatusCode, String) {
+    (StatusCode::NOT_FOUND, "alert rule not found".to_string())
+}
+
+async fn get_alert_rules(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<AlertRulesResponse>, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    let items = state
+        .proxy
+        .list_alert_rules()
+        .await
+        .map_err(|err| admin_proxy_error_response("list alert rules error", err))?
+        .into_iter()
+        .map(AlertRuleView::from)
+        .collect();
+    Ok(Json(AlertRulesResponse { items }))
+}
+
+async fn create_alert_rule(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(payload): Json<AlertRuleMutationRequest>,
+) -> Result<Json<AlertRuleView>, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    state
+        .proxy
+        .create_alert_rule(payload.into())
+        .await
+        .map(|rule| Json(AlertRuleView::from(rule)))
+        .map_err(|err| admin_proxy_error_response("create alert rule error", err))
+}
+
+async fn update_alert_rule(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(id): Path<String>,
+    Json(payload): Json<AlertRuleMutationRequest>,
+) -> Result<Json<AlertRuleView>, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    let Some(rule) = state
+        .proxy
+        .update_alert_rule(&id, payload.into())
+        .await
+        .map_err(|err| admin_proxy_error_response("update alert rule error", err))?
+    else {
+        return Err(alert_rule_not_found());
+    };
+    Ok(Json(AlertRuleView::from(rule)))
+}
+
+async fn delete_alert_rule(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(id): Path<String>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    let deleted = state
+        .proxy
+        .delete_alert_rule(&id)
+        .await
+        .map_err(|err| admin_proxy_error_response("delete alert rule error", err))?;
+    if !deleted {
+        return Err(alert_rule_not_found());
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
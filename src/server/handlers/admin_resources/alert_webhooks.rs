@@ -0,0 +1,119 @@
+const ALERT_WEBHOOK_DELIVERIES_DEFAULT_LIMIT: i64 = 50;
+
+fn alert_webhook_not_found() -> (StatusCode, String) {
+    (StatusCode::NOT_FOUND, "webhook not found".to_string())
+}
+
+async fn get_alert_webhooks(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<AlertWebhookSinksResponse>, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    let items = state
+        .proxy
+        .list_alert_webhook_sinks()
+        .await
+        .map_err(|err| admin_proxy_error_response("list alert webhooks error", err))?
+        .into_iter()
+        .map(AlertWebhookSinkView::from)
+        .collect();
+    Ok(Json(AlertWebhookSinksResponse { items }))
+}
+
+async fn create_alert_webhook(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(payload): Json<AlertWebhookSinkMutationRequest>,
+) -> Result<Json<AlertWebhookSinkView>, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    state
+        .proxy
+        .create_alert_webhook_sink(payload.into())
+        .await
+        .map(|sink| Json(AlertWebhookSinkView::from(sink)))
+        .map_err(|err| admin_proxy_error_response("create alert webhook error", err))
+}
+
+async fn update_alert_webhook(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(id): Path<String>,
+    Json(payload): Json<AlertWebhookSinkMutationRequest>,
+) -> Result<Json<AlertWebhookSinkView>, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    let Some(sink) = state
+        .proxy
+        .update_alert_webhook_sink(&id, payload.into())
+        .await
+        .map_err(|err| admin_proxy_error_response("update alert webhook error", err))?
+    else {
+        return Err(alert_webhook_not_found());
+    };
+    Ok(Json(AlertWebhookSinkView::from(sink)))
+}
+
+async fn delete_alert_webhook(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(id): Path<String>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    let deleted = state
+        .proxy
+        .delete_alert_webhook_sink(&id)
+        .await
+        .map_err(|err| admin_proxy_error_response("delete alert webhook error", err))?;
+    if !deleted {
+        return Err(alert_webhook_not_found());
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn test_alert_webhook(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(id): Path<String>,
+) -> Result<Json<AlertWebhookDeliveryView>, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    let Some(delivery) = state
+        .proxy
+        .test_fire_alert_webhook(&id)
+        .await
+        .map_err(|err| admin_proxy_error_response("test alert webhook error", err))?
+    else {
+        return Err(alert_webhook_not_found());
+    };
+    Ok(Json(AlertWebhookDeliveryView::from(delivery)))
+}
+
+async fn get_alert_webhook_deliveries(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Query(query): Query<AlertWebhookDeliveriesQuery>,
+) -> Result<Json<AlertWebhookDeliveriesResponse>, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    let items = state
+        .proxy
+        .alert_webhook_deliveries(
+            normalize_optional_filter(query.sink_id.as_deref()),
+            query.limit.unwrap_or(ALERT_WEBHOOK_DELIVERIES_DEFAULT_LIMIT),
+        )
+        .await
+        .map_err(|err| admin_proxy_error_response("list alert webhook deliveries error", err))?
+        .into_iter()
+        .map(AlertWebhookDeliveryView::from)
+        .collect();
+    Ok(Json(AlertWebhookDeliveriesResponse { items }))
+}
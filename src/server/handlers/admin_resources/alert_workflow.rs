@@ -0,0 +1,105 @@
+fn alert_silence_not_found() -> (StatusCode, String) {
+    (StatusCode::NOT_FOUND, "alert silence not found".to_string())
+}
+
+/// Name recorded as the actor of workflow actions and silences.
+async fn alert_workflow_actor(state: &AppState, headers: &HeaderMap) -> String {
+    let actor = admin_maintenance_actor(state, headers, None).await;
+    actor
+        .actor_display_name
+        .or(actor.actor_user_id)
+        .unwrap_or_else(|| "admin".to_string())
+}
+
+async fn post_alert_group_action(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(payload): Json<AlertGroupActionRequest>,
+) -> Result<Json<AlertGroupWorkflowView>, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    let actor = alert_workflow_actor(state.as_ref(), &headers).await;
+    state
+        .proxy
+        .apply_alert_group_action(&payload.group_id, &payload.action, &actor, payload.note)
+        .await
+        .map(|workflow| Json(AlertGroupWorkflowView::from(workflow)))
+        .map_err(|err| admin_proxy_error_response("alert group action error", err))
+}
+
+async fn get_alert_group_history(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Query(q): Query<AlertGroupHistoryQuery>,
+) -> Result<Json<AlertGroupHistoryResponse>, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    let items = state
+        .proxy
+        .alert_group_workflow_history(q.group_id.trim())
+        .await
+        .map_err(|err| admin_proxy_error_response("alert group history error", err))?
+        .into_iter()
+        .map(AlertGroupWorkflowEventView::from)
+        .collect();
+    Ok(Json(AlertGroupHistoryResponse { items }))
+}
+
+async fn get_alert_silences(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Query(q): Query<AlertSilencesQuery>,
+) -> Result<Json<AlertSilencesResponse>, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    let now = state.proxy.backend_time().now_ts();
+    let items = state
+        .proxy
+        .list_alert_silences(q.include_expired.unwrap_or(false))
+        .await
+        .map_err(|err| admin_proxy_error_response("list alert silences error", err))?
+        .into_iter()
+        .map(|silence| AlertSilenceView::new(silence, now))
+        .collect();
+    Ok(Json(AlertSilencesResponse { items }))
+}
+
+async fn create_alert_silence(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(payload): Json<AlertSilenceCreateRequest>,
+) -> Result<Json<AlertSilenceView>, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    let actor = alert_workflow_actor(state.as_ref(), &headers).await;
+    let now = state.proxy.backend_time().now_ts();
+    state
+        .proxy
+        .create_alert_silence(payload.into(), &actor)
+        .await
+        .map(|silence| Json(AlertSilenceView::new(silence, now)))
+        .map_err(|err| admin_proxy_error_response("create alert silence error", err))
+}
+
+async fn delete_alert_silence(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(id): Path<String>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    let expired = state
+        .proxy
+        .expire_alert_silence(&id)
+        .await
+        .map_err(|err| admin_proxy_error_response("expire alert silence error", err))?;
+    if expired.is_none() {
+        return Err(alert_silence_not_found());
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
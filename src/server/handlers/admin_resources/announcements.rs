@@ -102,7 +102,7 @@ async fn get_user_announcements(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
 ) -> Result<Json<AnnouncementsResponse>, (StatusCode, String)> {
-    if !state.linuxdo_oauth.is_enabled_and_configured() {
+    if !state.user_login_enabled() {
         return Err((StatusCode::NOT_FOUND, "not found".to_string()));
     }
     if resolve_user_session(state.as_ref(), &headers).await.is_none() {
@@ -129,7 +129,7 @@ async fn get_user_announcement_history(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
 ) -> Result<Json<AnnouncementsResponse>, (StatusCode, String)> {
-    if !state.linuxdo_oauth.is_enabled_and_configured() {
+    if !state.user_login_enabled() {
         return Err((StatusCode::NOT_FOUND, "not found".to_string()));
     }
     if resolve_user_session(state.as_ref(), &headers).await.is_none() {
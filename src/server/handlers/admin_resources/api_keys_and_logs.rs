@@ -760,6 +760,66 @@ async fn get_api_key_secret(
     }
 }
 
+async fn get_api_key_group_routing(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<tavily_hikari::ApiKeyGroupRouting>, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    state
+        .proxy
+        .get_api_key_group_routing()
+        .await
+        .map(Json)
+        .map_err(|err| admin_proxy_error_response("get key group routing error", err))
+}
+
+async fn put_api_key_group_routing(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(payload): Json<tavily_hikari::ApiKeyGroupRouting>,
+) -> Result<Json<tavily_hikari::ApiKeyGroupRouting>, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    state
+        .proxy
+        .replace_api_key_group_routing(payload)
+        .await
+        .map(Json)
+        .map_err(|err| admin_proxy_error_response("update key group routing error", err))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UpdateKeyRateBudget {
+    rpm_limit: Option<i64>,
+}
+
+async fn put_api_key_rate_budget(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    headers: HeaderMap,
+    Json(payload): Json<UpdateKeyRateBudget>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    match state
+        .proxy
+        .set_api_key_rate_budget(&id, payload.rpm_limit)
+        .await
+    {
+        Ok(true) => Ok(StatusCode::NO_CONTENT),
+        Ok(false) => Err((StatusCode::NOT_FOUND, "api key not found".to_string())),
+        Err(err) => Err(admin_proxy_error_response(
+            "update api key rate budget error",
+            err,
+        )),
+    }
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct PaginatedLogsView {
@@ -928,6 +988,7 @@ async fn list_logs_cursor(
     {
         return Err(StatusCode::BAD_REQUEST);
     }
+    let search = parse_log_search_filter(params.q.as_deref())?;
 
     state
         .proxy
@@ -942,6 +1003,7 @@ async fn list_logs_cursor(
             auth_token_id,
             key_id,
             operational_class,
+            search.as_deref(),
             until,
             cursor.as_ref(),
             direction,
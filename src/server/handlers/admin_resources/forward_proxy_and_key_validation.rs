@@ -105,7 +105,13 @@ async fn put_system_settings(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
     Json(payload): Json<SystemSettingsUpdatePayload>,
-) -> Result<Json<tavily_hikari::SystemSettings>, (StatusCode, String)> {
+) -> Result<
+    (
+        axum::Extension<AdminAuditChange>,
+        Json<tavily_hikari::SystemSettings>,
+    ),
+    (StatusCode, String),
+> {
     if !is_admin_request(state.as_ref(), &headers).await {
         return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
     }
@@ -115,6 +121,7 @@ async fn put_system_settings(
         eprintln!("get current system settings error: {err}");
         (StatusCode::INTERNAL_SERVER_ERROR, err.to_string())
     })?;
+    let settings_before = serde_json::to_value(&current_settings).unwrap_or(Value::Null);
     if state.dev_open_admin
         && payload
             .recharge_feature_enabled
@@ -144,6 +151,9 @@ async fn put_system_settings(
             api_rebalance_percent: payload
                 .api_rebalance_percent
                 .unwrap_or(current_settings.api_rebalance_percent),
+            key_selection_mode: payload
+                .key_selection_mode
+                .unwrap_or(current_settings.key_selection_mode),
             upstream_project_id_mode: payload
                 .upstream_project_id_mode
                 .unwrap_or(current_settings.upstream_project_id_mode),
@@ -180,6 +190,15 @@ async fn put_system_settings(
             request_log_retention: payload
                 .request_log_retention
                 .unwrap_or(current_settings.request_log_retention),
+            cross_key_retry: payload
+                .cross_key_retry
+                .unwrap_or(current_settings.cross_key_retry),
+            response_cache: payload
+                .response_cache
+                .unwrap_or(current_settings.response_cache),
+            request_coalescing: payload
+                .request_coalescing
+                .unwrap_or(current_settings.request_coalescing),
         })
         .await
         .map_err(|err| {
@@ -195,6 +214,9 @@ async fn put_system_settings(
                 || message.contains("user_blocked_key_base_limit must be")
                 || message.contains("global_ip_limit must be")
                 || message.contains("request_log_retention")
+                || message.contains("cross_key_retry")
+                || message.contains("response_cache")
+                || message.contains("request_coalescing")
                 || message.contains("max_log_retention_days")
                 || message.contains("business_body_days")
                 || message.contains("non_business_body_days")
@@ -209,7 +231,7 @@ async fn put_system_settings(
             }
         })?;
     maintenance_worker_wake_for_state(state.as_ref()).notify_one();
-    Ok(Json(updated))
+    Ok((AdminAuditChange::of(&settings_before, &updated), Json(updated)))
 }
 
 async fn get_upstream_privacy_status(
@@ -0,0 +1,23 @@
+/// Prometheus scrape endpoint. Readable by admins and by scrapers that present the
+/// configured `METRICS_BEARER_TOKEN`.
+async fn get_prometheus_metrics(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Response<Body>, (StatusCode, String)> {
+    let bearer = extract_bearer_token(&headers);
+    if !state.proxy.metrics_bearer_token_matches(bearer.as_deref())
+        && !is_admin_request(state.as_ref(), &headers).await
+    {
+        return Err((StatusCode::UNAUTHORIZED, "unauthorized".to_string()));
+    }
+    let body = state
+        .proxy
+        .render_prometheus_metrics(&state.ha)
+        .await
+        .map_err(|err| (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()))?;
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(CONTENT_TYPE, "text/plain; version=0.0.4; charset=utf-8")
+        .body(Body::from(body))
+        .map_err(|err| (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()))
+}
@@ -0,0 +1,152 @@
+async fn list_registration_invites(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<RegistrationInviteView>>, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    let now = state.proxy.backend_time().now_ts();
+    state
+        .proxy
+        .list_registration_invites()
+        .await
+        .map(|invites| {
+            Json(
+                invites
+                    .into_iter()
+                    .map(|invite| RegistrationInviteView::from_invite(invite, now))
+                    .collect(),
+            )
+        })
+        .map_err(|err| admin_proxy_error_response("list registration invites error", err))
+}
+
+async fn create_registration_invite(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(payload): Json<CreateRegistrationInviteRequest>,
+) -> Result<(StatusCode, Json<RegistrationInviteView>), (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    require_full_master_write(state.as_ref()).await?;
+
+    let input = tavily_hikari::RegistrationInviteInput::from(payload);
+    let now = state.proxy.backend_time().now_ts();
+    tavily_hikari::validate_registration_invite_input(&input, now)
+        .map_err(|detail| (StatusCode::BAD_REQUEST, detail))?;
+    if !input.tag_ids.is_empty() {
+        let tags = state
+            .proxy
+            .list_user_tags()
+            .await
+            .map_err(|err| admin_proxy_error_response("list user tags error", err))?;
+        for tag_id in &input.tag_ids {
+            match tags.iter().find(|tag| &tag.id == tag_id) {
+                Some(tag) if tag.system_key.is_none() => {}
+                Some(_) => {
+                    return Err((
+                        StatusCode::BAD_REQUEST,
+                        format!("system tag {tag_id} cannot be assigned by an invite"),
+                    ));
+                }
+                None => {
+                    return Err((StatusCode::BAD_REQUEST, format!("unknown tag {tag_id}")));
+                }
+            }
+        }
+    }
+
+    match state.proxy.create_registration_invite(&input).await {
+        Ok(Some(invite)) => Ok((
+            StatusCode::CREATED,
+            Json(RegistrationInviteView::from_invite(invite, now)),
+        )),
+        Ok(None) => Err((
+            StatusCode::CONFLICT,
+            "invite code already exists".to_string(),
+        )),
+        Err(err) => Err(admin_proxy_error_response(
+            "create registration invite error",
+            err,
+        )),
+    }
+}
+
+async fn revoke_registration_invite(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(id): Path<String>,
+) -> Result<Json<RegistrationInviteView>, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    require_full_master_write(state.as_ref()).await?;
+
+    let now = state.proxy.backend_time().now_ts();
+    state
+        .proxy
+        .revoke_registration_invite(&id)
+        .await
+        .map_err(|err| admin_proxy_error_response("revoke registration invite error", err))?
+        .map(|invite| Json(RegistrationInviteView::from_invite(invite, now)))
+        .ok_or_else(|| (StatusCode::NOT_FOUND, "invite not found".to_string()))
+}
+
+async fn list_pending_registration_approvals(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<PendingApprovalUserView>>, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    state
+        .proxy
+        .list_pending_approval_users()
+        .await
+        .map(|users| Json(users.into_iter().map(PendingApprovalUserView::from).collect()))
+        .map_err(|err| admin_proxy_error_response("list pending approvals error", err))
+}
+
+/// Lets a pending account in and binds the token its login skipped.
+async fn approve_user_registration(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(id): Path<String>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    require_full_master_write(state.as_ref()).await?;
+
+    let pending = state
+        .proxy
+        .list_pending_approval_users()
+        .await
+        .map_err(|err| admin_proxy_error_response("list pending approvals error", err))?
+        .into_iter()
+        .find(|user| user.user_id == id)
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                "user is not pending approval".to_string(),
+            )
+        })?;
+    state
+        .proxy
+        .approve_user_registration(&id)
+        .await
+        .map_err(|err| admin_proxy_error_response("approve user registration error", err))?;
+    let note = format!(
+        "{}:{}",
+        pending.provider.as_deref().unwrap_or("user"),
+        pending.username.as_deref().unwrap_or(&pending.user_id)
+    );
+    match state.proxy.ensure_user_token_binding(&id, Some(&note)).await {
+        Ok(_) | Err(ProxyError::TokenSecretNotRecoverable { .. }) => Ok(StatusCode::NO_CONTENT),
+        Err(err) => Err(admin_proxy_error_response(
+            "bind approved user token error",
+            err,
+        )),
+    }
+}
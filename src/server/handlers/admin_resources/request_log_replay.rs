@@ -0,0 +1,46 @@
+async fn get_request_log_replay_upstreams(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<RequestLogReplayUpstreamsView>, StatusCode> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    Ok(Json(RequestLogReplayUpstreamsView {
+        primary: state.usage_base.trim_end_matches('/').to_string(),
+        alternates: state.proxy.request_log_replay_upstream_bases().to_vec(),
+    }))
+}
+
+async fn post_request_log_replay(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(log_id): Path<i64>,
+    Json(payload): Json<RequestLogReplayRequest>,
+) -> Result<Json<RequestLogReplayView>, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    state
+        .proxy
+        .replay_request_log(log_id, payload.into(), &state.usage_base)
+        .await
+        .map_err(|err| admin_proxy_error_response("request log replay error", err))?
+        .map(|replay| Json(RequestLogReplayView::from(replay)))
+        .ok_or_else(|| (StatusCode::NOT_FOUND, "request log not found".to_string()))
+}
+
+async fn get_request_log_replays(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(log_id): Path<i64>,
+) -> Result<Json<Vec<RequestLogReplayView>>, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    state
+        .proxy
+        .request_log_replays(log_id)
+        .await
+        .map(|replays| Json(replays.into_iter().map(RequestLogReplayView::from).collect()))
+        .map_err(|err| admin_proxy_error_response("request log replays error", err))
+}
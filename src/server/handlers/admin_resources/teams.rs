@@ -0,0 +1,241 @@
+async fn load_admin_team(
+    state: &AppState,
+    team_id: &str,
+) -> Result<tavily_hikari::Team, (StatusCode, String)> {
+    state
+        .proxy
+        .get_team(team_id)
+        .await
+        .map_err(|err| admin_proxy_error_response("get team error", err))?
+        .ok_or_else(|| (StatusCode::NOT_FOUND, "team not found".to_string()))
+}
+
+async fn list_teams(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<TeamView>>, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    state
+        .proxy
+        .list_teams()
+        .await
+        .map(|teams| Json(teams.into_iter().map(TeamView::from).collect()))
+        .map_err(|err| admin_proxy_error_response("list teams error", err))
+}
+
+async fn create_team(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(payload): Json<TeamRequest>,
+) -> Result<(StatusCode, Json<TeamView>), (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    require_full_master_write(state.as_ref()).await?;
+
+    let input = tavily_hikari::TeamInput::from(payload);
+    tavily_hikari::validate_team_input(&input)
+        .map_err(|detail| (StatusCode::BAD_REQUEST, detail))?;
+    state
+        .proxy
+        .create_team(&input)
+        .await
+        .map(|team| (StatusCode::CREATED, Json(TeamView::from(team))))
+        .map_err(|err| admin_proxy_error_response("create team error", err))
+}
+
+async fn get_team_detail(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(id): Path<String>,
+) -> Result<Json<TeamDetailView>, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    let team = load_admin_team(state.as_ref(), &id).await?;
+    let quota = state
+        .proxy
+        .team_quota_snapshot(&team)
+        .await
+        .map_err(|err| admin_proxy_error_response("get team quota error", err))?;
+    let members = state
+        .proxy
+        .list_team_members(&id)
+        .await
+        .map_err(|err| admin_proxy_error_response("list team members error", err))?;
+    let tokens = state
+        .proxy
+        .list_team_tokens(&id)
+        .await
+        .map_err(|err| admin_proxy_error_response("list team tokens error", err))?;
+    Ok(Json(TeamDetailView {
+        team: TeamView::from(team),
+        quota: TeamQuotaView::from(quota),
+        members: members.into_iter().map(TeamMemberView::from).collect(),
+        tokens: tokens.into_iter().map(TeamTokenView::from).collect(),
+    }))
+}
+
+async fn update_team(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(id): Path<String>,
+    Json(payload): Json<TeamRequest>,
+) -> Result<Json<TeamView>, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    require_full_master_write(state.as_ref()).await?;
+
+    let input = tavily_hikari::TeamInput::from(payload);
+    tavily_hikari::validate_team_input(&input)
+        .map_err(|detail| (StatusCode::BAD_REQUEST, detail))?;
+    state
+        .proxy
+        .update_team(&id, &input)
+        .await
+        .map_err(|err| admin_proxy_error_response("update team error", err))?
+        .map(|team| Json(TeamView::from(team)))
+        .ok_or_else(|| (StatusCode::NOT_FOUND, "team not found".to_string()))
+}
+
+async fn delete_team(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(id): Path<String>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    require_full_master_write(state.as_ref()).await?;
+
+    match state.proxy.delete_team(&id).await {
+        Ok(true) => Ok(StatusCode::NO_CONTENT),
+        Ok(false) => Err((StatusCode::NOT_FOUND, "team not found".to_string())),
+        Err(err) => Err(admin_proxy_error_response("delete team error", err)),
+    }
+}
+
+async fn put_team_member(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path((id, user_id)): Path<(String, String)>,
+    Json(payload): Json<TeamMemberRequest>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    require_full_master_write(state.as_ref()).await?;
+
+    let role = tavily_hikari::TeamRole::parse(&payload.role).ok_or_else(|| {
+        (
+            StatusCode::BAD_REQUEST,
+            "role must be owner, admin or member".to_string(),
+        )
+    })?;
+    load_admin_team(state.as_ref(), &id).await?;
+    if state
+        .proxy
+        .get_admin_user_identity(&user_id)
+        .await
+        .map_err(|err| admin_proxy_error_response("get admin user identity error", err))?
+        .is_none()
+    {
+        return Err((StatusCode::NOT_FOUND, "user not found".to_string()));
+    }
+    match state.proxy.upsert_team_member(&id, &user_id, role).await {
+        Ok(true) => Ok(StatusCode::NO_CONTENT),
+        Ok(false) => Err((
+            StatusCode::CONFLICT,
+            "user already belongs to another team".to_string(),
+        )),
+        Err(err) => Err(admin_proxy_error_response("update team member error", err)),
+    }
+}
+
+async fn delete_team_member(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path((id, user_id)): Path<(String, String)>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    require_full_master_write(state.as_ref()).await?;
+
+    match state.proxy.remove_team_member(&id, &user_id).await {
+        Ok(true) => Ok(StatusCode::NO_CONTENT),
+        Ok(false) => Err((StatusCode::NOT_FOUND, "team member not found".to_string())),
+        Err(err) => Err(admin_proxy_error_response("remove team member error", err)),
+    }
+}
+
+async fn create_team_token(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(id): Path<String>,
+    Json(payload): Json<TeamTokenRequest>,
+) -> Result<(StatusCode, Json<CreatedTeamTokenView>), (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    require_full_master_write(state.as_ref()).await?;
+
+    let team = load_admin_team(state.as_ref(), &id).await?;
+    let note = normalize_optional_text(payload.note).unwrap_or_else(|| format!("team:{}", team.name));
+    let created_by = admin_audit_actor(state.as_ref(), &headers)
+        .await
+        .and_then(|actor| actor.name);
+    state
+        .proxy
+        .create_team_token(&id, Some(&note), created_by.as_deref())
+        .await
+        .map(|secret| {
+            (
+                StatusCode::CREATED,
+                Json(CreatedTeamTokenView {
+                    token: secret.token,
+                    token_id: secret.id,
+                }),
+            )
+        })
+        .map_err(|err| admin_proxy_error_response("create team token error", err))
+}
+
+async fn delete_team_token(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path((id, token_id)): Path<(String, String)>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    require_full_master_write(state.as_ref()).await?;
+
+    match state.proxy.delete_team_token(&id, &token_id).await {
+        Ok(true) => Ok(StatusCode::NO_CONTENT),
+        Ok(false) => Err((StatusCode::NOT_FOUND, "team token not found".to_string())),
+        Err(err) => Err(admin_proxy_error_response("delete team token error", err)),
+    }
+}
+
+async fn get_team_usage_series(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(id): Path<String>,
+    Query(q): Query<AdminUserUsageSeriesQuery>,
+) -> Result<Json<AdminUserUsageSeriesView>, (StatusCode, String)> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    let series = parse_team_usage_series(q.series.as_deref())?;
+    let team = load_admin_team(state.as_ref(), &id).await?;
+    state
+        .proxy
+        .team_usage_series(&team, series)
+        .await
+        .map(|usage| Json(team_usage_series_view(usage)))
+        .map_err(|err| admin_proxy_error_response("get team usage series error", err))
+}
@@ -138,6 +138,7 @@ mod admin_resources_tests {
                 login_state_ttl_secs: 600,
             },
             linuxdo_credit: LinuxDoCreditOptions::disabled(),
+            oidc: OidcOptions::disabled(),
             ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
             dev_open_admin: false,
             usage_base: "http://127.0.0.1:58088".to_string(),
@@ -263,6 +264,7 @@ mod admin_resources_tests {
             State(state.clone()),
             HeaderMap::new(),
             Json(AdminLoginRequest {
+                username: None,
                 password: "pw-123".to_string(),
                 totp_code: None,
             }),
@@ -279,6 +281,7 @@ mod admin_resources_tests {
             State(state),
             HeaderMap::new(),
             Json(AdminLoginRequest {
+                username: None,
                 password: "pw-123".to_string(),
                 totp_code: Some(login_code),
             }),
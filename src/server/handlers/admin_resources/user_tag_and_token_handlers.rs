@@ -483,6 +483,14 @@ async fn list_users(
                 StatusCode::INTERNAL_SERVER_ERROR
             })?
     };
+    let pending_approval_ids = state
+        .proxy
+        .pending_approval_user_ids(&page_user_ids)
+        .await
+        .map_err(|err| {
+            eprintln!("list admin user pending approvals error: {err}");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
     for row in paged_rows {
         let tags = user_tags.remove(&row.user.user_id).unwrap_or_default();
         let api_key_count = api_key_counts
@@ -568,6 +576,7 @@ async fn list_users(
             &row.user,
             &row.summary,
             AdminUserSummaryViewInput {
+                pending_approval: pending_approval_ids.contains(&row.user.user_id),
                 api_key_count,
                 monthly_broken_count: row.monthly_broken_count,
                 monthly_broken_limit: row.monthly_broken_limit,
@@ -879,12 +888,21 @@ async fn get_user_detail(
             eprintln!("get admin user entitlements error: {err}");
             StatusCode::INTERNAL_SERVER_ERROR
         })?;
+    let pending_approval = state
+        .proxy
+        .user_pending_approval(&user.user_id)
+        .await
+        .map_err(|err| {
+            eprintln!("get admin user approval state error: {err}");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
 
     Ok(Json(AdminUserDetailView {
         user_id: user.user_id,
         display_name: user.display_name,
         username: user.username,
         active: user.active,
+        pending_approval,
         last_login_at: user.last_login_at,
         token_count: user.token_count,
         api_key_count,
@@ -1394,6 +1412,7 @@ struct TokenGroupView {
     name: String,
     token_count: i64,
     latest_created_at: i64,
+    default_scopes: Option<Vec<String>>,
 }
 
 async fn build_auth_token_views(
@@ -1472,16 +1491,27 @@ async fn list_token_groups(
         return Err(StatusCode::FORBIDDEN);
     }
 
+    let mut default_scopes = state
+        .proxy
+        .list_token_group_default_scopes()
+        .await
+        .map_err(|err| {
+            eprintln!("list token group scopes error: {err}");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
     match state.proxy.list_access_tokens().await {
         Ok(tokens) => {
             let mut groups: HashMap<String, TokenGroupView> = HashMap::new();
             for t in tokens {
                 let raw = t.group_name.as_deref().map(str::trim).unwrap_or("");
                 let key = raw.to_owned();
-                let entry = groups.entry(key.clone()).or_insert(TokenGroupView {
+                let entry = groups.entry(key.clone()).or_insert_with(|| TokenGroupView {
                     name: key.clone(),
                     token_count: 0,
                     latest_created_at: t.created_at,
+                    default_scopes: default_scopes
+                        .remove(&key)
+                        .map(|scopes| scopes.keys().to_vec()),
                 });
                 entry.token_count += 1;
                 if t.created_at > entry.latest_created_at {
@@ -1515,22 +1545,44 @@ async fn create_token(
     if require_full_master_write(state.as_ref()).await.is_err() {
         return Err(StatusCode::SERVICE_UNAVAILABLE);
     }
-    state
+    let lifetime = AccessTokenLifetime::new(payload.not_before, payload.expires_at);
+    if lifetime.validate().is_err() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    let scopes = parse_token_scopes(payload.scopes)?;
+    let secret = state
         .proxy
-        .create_access_token(payload.note.as_deref())
+        .create_access_token_with_lifetime(payload.note.as_deref(), lifetime)
         .await
-        .map(|secret| {
-            (
-                StatusCode::CREATED,
-                Json(AuthTokenSecretView {
-                    token: secret.token,
-                }),
-            )
-        })
         .map_err(|err| {
             eprintln!("create token error: {err}");
             StatusCode::INTERNAL_SERVER_ERROR
-        })
+        })?;
+    if let Some(scopes) = scopes.as_ref() {
+        state
+            .proxy
+            .set_access_tokens_scopes(std::slice::from_ref(&secret.id), Some(scopes))
+            .await
+            .map_err(|err| {
+                eprintln!("set token scopes error: {err}");
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+    }
+    Ok((
+        StatusCode::CREATED,
+        Json(AuthTokenSecretView {
+            token: secret.token,
+        }),
+    ))
+}
+
+/// `None` leaves a token on its group default; an invalid scope key is a bad request.
+fn parse_token_scopes(
+    scopes: Option<Vec<String>>,
+) -> Result<Option<AccessTokenScopes>, StatusCode> {
+    scopes
+        .map(|keys| AccessTokenScopes::parse(keys).map_err(|_| StatusCode::BAD_REQUEST))
+        .transpose()
 }
 
 async fn delete_token(
@@ -1641,6 +1693,224 @@ async fn delete_tokens_batch(
         })
 }
 
+#[derive(Debug, Deserialize)]
+struct UpdateTokenLifetime {
+    not_before: Option<i64>,
+    expires_at: Option<i64>,
+}
+
+async fn update_token_lifetime(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    headers: HeaderMap,
+    Json(payload): Json<UpdateTokenLifetime>,
+) -> Result<StatusCode, StatusCode> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    if require_full_master_write(state.as_ref()).await.is_err() {
+        return Err(StatusCode::SERVICE_UNAVAILABLE);
+    }
+    let lifetime = AccessTokenLifetime::new(payload.not_before, payload.expires_at);
+    if lifetime.validate().is_err() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    match state.proxy.set_access_token_lifetime(&id, lifetime).await {
+        Ok(true) => Ok(StatusCode::NO_CONTENT),
+        Ok(false) => Err(StatusCode::NOT_FOUND),
+        Err(err) => {
+            eprintln!("update token lifetime error: {err}");
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct UpdateTokenScopes {
+    scopes: Option<Vec<String>>,
+}
+
+async fn update_token_scopes(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    headers: HeaderMap,
+    Json(payload): Json<UpdateTokenScopes>,
+) -> Result<StatusCode, StatusCode> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    if require_full_master_write(state.as_ref()).await.is_err() {
+        return Err(StatusCode::SERVICE_UNAVAILABLE);
+    }
+    let scopes = parse_token_scopes(payload.scopes)?;
+    match state
+        .proxy
+        .set_access_tokens_scopes(std::slice::from_ref(&id), scopes.as_ref())
+        .await
+    {
+        Ok(0) => Err(StatusCode::NOT_FOUND),
+        Ok(_) => Ok(StatusCode::NO_CONTENT),
+        Err(err) => {
+            eprintln!("update token scopes error: {err}");
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+async fn update_token_group_scopes(
+    State(state): State<Arc<AppState>>,
+    Path(group): Path<String>,
+    headers: HeaderMap,
+    Json(payload): Json<UpdateTokenScopes>,
+) -> Result<StatusCode, StatusCode> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    if require_full_master_write(state.as_ref()).await.is_err() {
+        return Err(StatusCode::SERVICE_UNAVAILABLE);
+    }
+    if group.trim().is_empty() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    let scopes = parse_token_scopes(payload.scopes)?;
+    state
+        .proxy
+        .set_token_group_default_scopes(&group, scopes.as_ref())
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(|err| {
+            eprintln!("update token group scopes error: {err}");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })
+}
+
+#[derive(Debug, Deserialize)]
+struct UpdateRequestParameterPolicy {
+    policy: Option<RequestParameterPolicy>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RequestParameterPolicyView {
+    subject: &'static str,
+    subject_id: String,
+    policy: RequestParameterPolicy,
+    updated_at: i64,
+}
+
+async fn list_request_parameter_policies(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<RequestParameterPolicyView>>, StatusCode> {
+    if !is_admin_request(state.as_ref(), &headers).await {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    state
+        .proxy
+        .list_request_parameter_policies()
+        .await
+        .map(|records| {
+            Json(
+                records
+                    .into_iter()
+                    .map(|record| RequestParameterPolicyView {
+                        subject: record.subject.as_str(),
+                        subject_id: record.subject_id,
+                        policy: record.policy,
+                        updated_at: record.updated_at,
+                    })
+                    .collect(),
+            )
+        })
+        .map_err(|err| {
+            eprintln!("list request parameter policies error: {err}");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })
+}
+
+async fn update_request_parameter_policy(
+    state: &AppState,
+    headers: &HeaderMap,
+    subject: RequestParameterPolicySubject,
+    subject_id: &str,
+    payload: UpdateRequestParameterPolicy,
+) -> Result<StatusCode, StatusCode> {
+    if !is_admin_request(state, headers).await {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    if require_full_master_write(state).await.is_err() {
+        return Err(StatusCode::SERVICE_UNAVAILABLE);
+    }
+    if subject_id.trim().is_empty() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    let policy = payload
+        .policy
+        .map(RequestParameterPolicy::normalized)
+        .transpose()
+        .map_err(|_| StatusCode::BAD_REQUEST)?;
+    match state
+        .proxy
+        .set_request_parameter_policy(subject, subject_id, policy.as_ref())
+        .await
+    {
+        Ok(true) => Ok(StatusCode::NO_CONTENT),
+        Ok(false) => Err(StatusCode::NOT_FOUND),
+        Err(err) => {
+            eprintln!("update {} parameter policy error: {err}", subject.as_str());
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+async fn update_token_parameter_policy(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    headers: HeaderMap,
+    Json(payload): Json<UpdateRequestParameterPolicy>,
+) -> Result<StatusCode, StatusCode> {
+    update_request_parameter_policy(
+        state.as_ref(),
+        &headers,
+        RequestParameterPolicySubject::Token,
+        &id,
+        payload,
+    )
+    .await
+}
+
+async fn update_token_group_parameter_policy(
+    State(state): State<Arc<AppState>>,
+    Path(group): Path<String>,
+    headers: HeaderMap,
+    Json(payload): Json<UpdateRequestParameterPolicy>,
+) -> Result<StatusCode, StatusCode> {
+    update_request_parameter_policy(
+        state.as_ref(),
+        &headers,
+        RequestParameterPolicySubject::TokenGroup,
+        &group,
+        payload,
+    )
+    .await
+}
+
+async fn update_user_tag_parameter_policy(
+    State(state): State<Arc<AppState>>,
+    Path(tag_id): Path<String>,
+    headers: HeaderMap,
+    Json(payload): Json<UpdateRequestParameterPolicy>,
+) -> Result<StatusCode, StatusCode> {
+    update_request_parameter_policy(
+        state.as_ref(),
+        &headers,
+        RequestParameterPolicySubject::UserTag,
+        &tag_id,
+        payload,
+    )
+    .await
+}
+
 #[derive(Debug, Deserialize)]
 struct UpdateTokenNote {
     note: String,
@@ -1673,15 +1943,19 @@ async fn get_token_secret(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
     headers: HeaderMap,
-) -> Result<Json<AuthTokenSecretView>, StatusCode> {
+) -> Result<Response<Body>, StatusCode> {
     if !is_admin_request(state.as_ref(), &headers).await {
         return Err(StatusCode::FORBIDDEN);
     }
     match state.proxy.get_access_token_secret(&id).await {
         Ok(Some(secret)) => Ok(Json(AuthTokenSecretView {
             token: secret.token,
-        })),
+        })
+        .into_response()),
         Ok(None) => Err(StatusCode::NOT_FOUND),
+        Err(ProxyError::TokenSecretNotRecoverable { token_id }) => {
+            Ok(token_secret_not_recoverable_response(&token_id))
+        }
         Err(err) => {
             eprintln!("get token secret error: {err}");
             Err(StatusCode::INTERNAL_SERVER_ERROR)
@@ -1721,6 +1995,9 @@ struct BatchCreateTokenRequest {
     group: String,
     count: usize,
     note: Option<String>,
+    not_before: Option<i64>,
+    expires_at: Option<i64>,
+    scopes: Option<Vec<String>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -1745,17 +2022,31 @@ async fn create_tokens_batch(
         return Err(StatusCode::BAD_REQUEST);
     }
     let count = payload.count.clamp(1, 1000);
-    state
+    let lifetime = AccessTokenLifetime::new(payload.not_before, payload.expires_at);
+    if lifetime.validate().is_err() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    let scopes = parse_token_scopes(payload.scopes)?;
+    let secrets = state
         .proxy
-        .create_access_tokens_batch(group, count, payload.note.as_deref())
+        .create_access_tokens_batch_with_lifetime(group, count, payload.note.as_deref(), lifetime)
         .await
-        .map(|secrets| {
-            Json(BatchCreateTokenResponse {
-                tokens: secrets.into_iter().map(|s| s.token).collect(),
-            })
-        })
         .map_err(|err| {
             eprintln!("batch create tokens error: {err}");
             StatusCode::INTERNAL_SERVER_ERROR
-        })
+        })?;
+    if let Some(scopes) = scopes.as_ref() {
+        let ids: Vec<String> = secrets.iter().map(|secret| secret.id.clone()).collect();
+        state
+            .proxy
+            .set_access_tokens_scopes(&ids, Some(scopes))
+            .await
+            .map_err(|err| {
+                eprintln!("set batch token scopes error: {err}");
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+    }
+    Ok(Json(BatchCreateTokenResponse {
+        tokens: secrets.into_iter().map(|s| s.token).collect(),
+    }))
 }
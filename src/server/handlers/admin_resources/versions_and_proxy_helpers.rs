@@ -182,6 +182,11 @@ async fn list_keys(
                         })
                         .collect(),
                 },
+                tiers: result
+                    .tiers
+                    .into_iter()
+                    .map(ApiKeyTierCapacityView::from)
+                    .collect(),
             })
         })
         .map_err(|err| {
@@ -259,6 +264,7 @@ struct PaginatedApiKeysView {
     page: i64,
     per_page: i64,
     facets: ApiKeyFacetsView,
+    tiers: Vec<ApiKeyTierCapacityView>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -755,6 +761,7 @@ struct SystemSettingsUpdatePayload {
     rebalance_mcp_session_percent: i64,
     api_rebalance_enabled: Option<bool>,
     api_rebalance_percent: Option<i64>,
+    key_selection_mode: Option<tavily_hikari::KeySelectionMode>,
     upstream_project_id_mode: Option<tavily_hikari::UpstreamProjectIdMode>,
     upstream_project_id_fixed_value: Option<String>,
     upstream_mcp_user_agent: Option<String>,
@@ -767,6 +774,9 @@ struct SystemSettingsUpdatePayload {
     trusted_proxy_cidrs: Option<Vec<String>>,
     trusted_client_ip_headers: Option<Vec<String>>,
     request_log_retention: Option<tavily_hikari::RequestLogRetentionSettings>,
+    cross_key_retry: Option<tavily_hikari::CrossKeyRetrySettings>,
+    response_cache: Option<tavily_hikari::ResponseCacheSettings>,
+    request_coalescing: Option<tavily_hikari::RequestCoalescingSettings>,
 }
 
 #[derive(Debug, Deserialize)]
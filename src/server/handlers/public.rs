@@ -12,6 +12,9 @@ async fn fetch_summary(
                 summary.active_keys += summary.temporary_isolated_keys;
                 summary.quarantined_keys = 0;
                 summary.temporary_isolated_keys = 0;
+                summary.key_tiers.clear();
+                summary.key_rate_budgets.clear();
+                summary.response_cache = Default::default();
             }
             Json(summary.into())
         })
@@ -155,6 +155,179 @@ async fn record_rebalance_period_usage(
     }
 }
 
+/// Charge and log a finished `/research` attempt through the pending-billing flow.
+///
+/// `credits` is the settled research cost: the final usage event for streamed research, or the
+/// model-based estimate otherwise. When the billing lock could not be taken or was lost
+/// (`billing_lock_error`), the attempt stays pending so the pending-billing retry charges it
+/// under the lock instead.
+#[allow(clippy::too_many_arguments)]
+async fn record_tavily_http_research_billing(
+    state: &Arc<AppState>,
+    method: &Method,
+    path: &str,
+    token_id_for_logs: Option<&str>,
+    billing_subject: Option<&str>,
+    token_billing_guard: Option<&tavily_hikari::TokenBillingGuard>,
+    billing_lock_error: Option<&str>,
+    resp: &ProxyResponse,
+    analysis: &tavily_hikari::AttemptAnalysis,
+    credits: i64,
+) {
+    let mut billing_error: Option<String> = None;
+    let mut attempt_logged = false;
+
+    if resp.status.is_success()
+        && analysis.status == "success"
+        && let Some(tid) = token_id_for_logs
+        && credits > 0
+    {
+        match if let Some(subject) = billing_subject {
+            state
+                .proxy
+                .record_pending_billing_attempt_for_subject_request_log_metadata(
+                    tid,
+                    method,
+                    path,
+                    None,
+                    Some(resp.status.as_u16() as i64),
+                    analysis.tavily_status_code,
+                    true,
+                    analysis.status,
+                    None,
+                    credits,
+                    subject,
+                    analysis.api_key_id.as_deref(),
+                    analysis.failure_kind.as_deref(),
+                    Some(resp.key_effect_code.as_str()),
+                    resp.key_effect_summary.as_deref(),
+                    Some(resp.binding_effect_code.as_str()),
+                    resp.binding_effect_summary.as_deref(),
+                    Some(resp.selection_effect_code.as_str()),
+                    resp.selection_effect_summary.as_deref(),
+                    resp.request_log_id,
+                )
+                .await
+        } else {
+            state
+                .proxy
+                .record_pending_billing_attempt_request_log_metadata(
+                    tid,
+                    method,
+                    path,
+                    None,
+                    Some(resp.status.as_u16() as i64),
+                    analysis.tavily_status_code,
+                    true,
+                    analysis.status,
+                    None,
+                    credits,
+                    analysis.api_key_id.as_deref(),
+                    analysis.failure_kind.as_deref(),
+                    Some(resp.key_effect_code.as_str()),
+                    resp.key_effect_summary.as_deref(),
+                    Some(resp.binding_effect_code.as_str()),
+                    resp.binding_effect_summary.as_deref(),
+                    Some(resp.selection_effect_code.as_str()),
+                    resp.selection_effect_summary.as_deref(),
+                    resp.request_log_id,
+                )
+                .await
+        }
+        {
+            Ok(log_id) => {
+                attempt_logged = true;
+                if let Some(msg) = billing_error.as_deref() {
+                    let _ = state
+                        .proxy
+                        .annotate_pending_billing_attempt(log_id, msg)
+                        .await;
+                }
+                let lock_lost_msg = billing_lock_error
+                    .map(str::to_string)
+                    .or_else(|| {
+                        token_billing_guard
+                            .and_then(|guard| guard.ensure_live().err())
+                            .map(|err| err.to_string())
+                    })
+                    .map(|err| {
+                        format!(
+                            "charge_token_quota deferred for {path}: {err}; pending billing will retry"
+                        )
+                    });
+                if let Some(msg) = lock_lost_msg {
+                    eprintln!("{msg}");
+                    let _ = state
+                        .proxy
+                        .annotate_pending_billing_attempt(log_id, &msg)
+                        .await;
+                    billing_error = Some(msg);
+                } else {
+                    match state.proxy.settle_pending_billing_attempt(log_id).await {
+                        Ok(PendingBillingSettleOutcome::Charged)
+                        | Ok(PendingBillingSettleOutcome::AlreadySettled) => {}
+                        Ok(PendingBillingSettleOutcome::RetryLater) => {
+                            let msg = format!(
+                                "charge_token_quota delayed for {path}: pending billing claim miss; will retry"
+                            );
+                            eprintln!("{msg}");
+                            let _ = state
+                                .proxy
+                                .annotate_pending_billing_attempt(log_id, &msg)
+                                .await;
+                            billing_error = Some(msg);
+                        }
+                        Err(err) => {
+                            let msg = format!("charge_token_quota failed for {path}: {err}");
+                            eprintln!("{msg}");
+                            let _ = state
+                                .proxy
+                                .annotate_pending_billing_attempt(log_id, &msg)
+                                .await;
+                            billing_error = Some(msg);
+                        }
+                    }
+                }
+            }
+            Err(err) => {
+                let msg = format!(
+                    "record_pending_billing_attempt failed for {path}: {err}"
+                );
+                eprintln!("{msg}");
+                billing_error = Some(msg);
+            }
+        }
+    }
+
+    if !attempt_logged
+        && let Some(tid) = token_id_for_logs
+    {
+        let http_code = resp.status.as_u16() as i64;
+        let _ = state
+            .proxy
+            .record_token_attempt_request_log_metadata(
+                tid,
+                method,
+                path,
+                None,
+                Some(http_code),
+                analysis.tavily_status_code,
+                true,
+                analysis.status,
+                billing_error.as_deref(),
+                analysis.failure_kind.as_deref(),
+                Some(resp.key_effect_code.as_str()),
+                resp.key_effect_summary.as_deref(),
+                Some(resp.binding_effect_code.as_str()),
+                resp.binding_effect_summary.as_deref(),
+                Some(resp.selection_effect_code.as_str()),
+                resp.selection_effect_summary.as_deref(),
+                resp.request_log_id,
+            )
+            .await;
+    }
+}
+
 fn chunked_credits(items: usize, chunk_size: usize, credits_per_chunk: i64) -> i64 {
     if items == 0 || credits_per_chunk <= 0 {
         return 0;
@@ -262,15 +435,6 @@ fn tavily_http_free_account_validation_message(
         return Some("safe_search is an Enterprise-only Tavily parameter and is not supported");
     }
 
-    if upstream_path == "/research"
-        && options
-            .get("stream")
-            .and_then(Value::as_bool)
-            .unwrap_or(false)
-    {
-        return Some("stream=true is not supported by this proxy; use non-streaming research");
-    }
-
     None
 }
 
@@ -418,22 +582,29 @@ async fn tavily_http_research_result(
     let auth_token_id = token_resolution.auth_token_id;
     let using_dev_open_admin_fallback = token_resolution.using_dev_open_admin_fallback;
 
-    let valid = if using_dev_open_admin_fallback {
-        true
+    let validation = if using_dev_open_admin_fallback {
+        AccessTokenValidation::Valid
     } else {
         state
             .proxy
-            .validate_access_token(&token)
+            .check_access_token(&token)
             .await
             .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
     };
-    if !valid {
-        let resp = Response::builder()
-            .status(StatusCode::UNAUTHORIZED)
-            .header(CONTENT_TYPE, "application/json; charset=utf-8")
-            .body(Body::from("{\"error\":\"invalid or disabled token\"}"))
-            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-        return Ok(resp);
+    if !validation.is_valid() {
+        return access_token_rejection_response(validation);
+    }
+    if !using_dev_open_admin_fallback
+        && let Some(response) = token_scope_rejection(
+            &state,
+            auth_token_id.as_deref(),
+            &method,
+            &path,
+            &[classify_token_request_kind(&path, None)],
+        )
+        .await?
+    {
+        return Ok(response);
     }
 
     if let Some(ref tid) = auth_token_id
@@ -673,9 +844,11 @@ async fn tavily_http_research_result(
                 ProxyError::Http(_)
                 | ProxyError::NoAvailableKeys
                 | ProxyError::PinnedMcpSessionUnavailable => StatusCode::BAD_GATEWAY,
+                ProxyError::KeyRateBudgetExhausted { .. } => StatusCode::TOO_MANY_REQUESTS,
                 ProxyError::Database(_)
                 | ProxyError::InvalidEndpoint { .. }
                 | ProxyError::LastAdminLoginMethod
+                | ProxyError::TokenSecretNotRecoverable { .. }
                 | ProxyError::QuotaDataMissing { .. }
                 | ProxyError::UsageHttp { .. }
                 | ProxyError::StaleClaim { .. }
@@ -702,6 +875,23 @@ async fn proxy_tavily_http_endpoint(
     req: Request<Body>,
     config: TavilyEndpointConfig,
     remote_addr: Option<SocketAddr>,
+) -> Result<Response<Body>, StatusCode> {
+    let trace = RequestTrace::start(req.method().as_str(), req.uri().path(), req.headers());
+    trace
+        .scope(proxy_tavily_http_endpoint_traced(
+            state,
+            req,
+            config,
+            remote_addr,
+        ))
+        .await
+}
+
+async fn proxy_tavily_http_endpoint_traced(
+    state: Arc<AppState>,
+    req: Request<Body>,
+    config: TavilyEndpointConfig,
+    remote_addr: Option<SocketAddr>,
 ) -> Result<Response<Body>, StatusCode> {
     let (parts, body) = req.into_parts();
     let method = parts.method.clone();
@@ -759,22 +949,29 @@ async fn proxy_tavily_http_endpoint(
     let auth_token_id = token_resolution.auth_token_id;
     let using_dev_open_admin_fallback = token_resolution.using_dev_open_admin_fallback;
 
-    let valid = if using_dev_open_admin_fallback {
-        true
+    let validation = if using_dev_open_admin_fallback {
+        AccessTokenValidation::Valid
     } else {
         state
             .proxy
-            .validate_access_token(&token)
+            .check_access_token(&token)
             .await
             .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
     };
-    if !valid {
-        let resp = Response::builder()
-            .status(StatusCode::UNAUTHORIZED)
-            .header(CONTENT_TYPE, "application/json; charset=utf-8")
-            .body(Body::from("{\"error\":\"invalid or disabled token\"}"))
-            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-        return Ok(resp);
+    if !validation.is_valid() {
+        return access_token_rejection_response(validation);
+    }
+    if !using_dev_open_admin_fallback
+        && let Some(response) = token_scope_rejection(
+            &state,
+            auth_token_id.as_deref(),
+            &method,
+            &path,
+            &[classify_token_request_kind(&path, None)],
+        )
+        .await?
+    {
+        return Ok(response);
     }
 
     if let Value::Object(ref mut map) = options {
@@ -797,6 +994,30 @@ async fn proxy_tavily_http_endpoint(
         return invalid_tavily_http_request_response(message);
     }
 
+    if !using_dev_open_admin_fallback
+        && let Some(tid) = auth_token_id.as_deref()
+        && let Some(policy) = state
+            .proxy
+            .effective_request_parameter_policy(tid)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        && let Err(violation) = enforce_request_parameter_policy(
+            &policy,
+            config.upstream_path.trim_start_matches('/'),
+            &mut options,
+        )
+    {
+        return parameter_policy_violation_rejection(
+            &state,
+            tid,
+            &method,
+            &path,
+            &classify_token_request_kind(&path, None),
+            &violation,
+        )
+        .await;
+    }
+
     let token_id_for_logs = auth_token_id.clone();
     let hikari_routing_key = if using_dev_open_admin_fallback {
         None
@@ -1060,6 +1281,67 @@ async fn proxy_tavily_http_endpoint(
     headers.remove(axum::http::header::AUTHORIZATION);
     headers.remove(HIKARI_ROUTING_KEY_HEADER);
 
+    if config.upstream_path == "/research" && tavily_research_stream_requested(&options) {
+        // Streamed research settles billing after the stream closes; the subject lock is
+        // re-acquired there instead of being held for the lifetime of the stream. Reserve the
+        // estimated credits first so concurrent prechecks can not all spend the same headroom.
+        let mut quota_reservation_id = None;
+        if let (Some(tid), Some(subject)) = (auth_token_id.as_deref(), billing_subject.as_deref())
+            && reserved_credits > 0
+        {
+            match state
+                .proxy
+                .reserve_quota_credits_for_subject(tid, subject, reserved_credits)
+                .await
+            {
+                Ok(reservation_id) => quota_reservation_id = Some(reservation_id),
+                Err(err) => {
+                    eprintln!("quota reservation failed for {path}: {err}");
+                    state
+                        .proxy
+                        .release_business_calls_1h_reservation(business_calls_reservation)
+                        .await;
+                    let msg = err.to_string();
+                    let _ = state
+                        .proxy
+                        .record_token_attempt(
+                            tid,
+                            &method,
+                            &path,
+                            None,
+                            Some(StatusCode::INTERNAL_SERVER_ERROR.as_u16() as i64),
+                            None,
+                            false,
+                            "error",
+                            Some(msg.as_str()),
+                        )
+                        .await;
+                    return Err(StatusCode::INTERNAL_SERVER_ERROR);
+                }
+            }
+        }
+        drop(token_billing_guard);
+        return proxy_tavily_http_research_stream(
+            state,
+            TavilyResearchStreamRequest {
+                method,
+                path,
+                auth_token_id,
+                use_api_rebalance,
+                api_routing_key,
+                http_project_id,
+                options,
+                headers,
+                client_ip,
+                billing_subject,
+                reserved_credits,
+                quota_reservation_id,
+                business_calls_reservation,
+            },
+        )
+        .await;
+    }
+
     if config.upstream_path == "/research" {
         let result = state
             .proxy
@@ -1080,156 +1362,19 @@ async fn proxy_tavily_http_endpoint(
 
         match result {
             Ok((resp, analysis, usage_delta)) => {
-                let mut billing_error: Option<String> = None;
-                let mut attempt_logged = false;
-
-                if resp.status.is_success()
-                    && analysis.status == "success"
-                    && let Some(tid) = token_id_for_logs.as_deref()
-                {
-                    let credits = usage_delta.unwrap_or(reserved_credits);
-                    if credits > 0 {
-                        match if let Some(subject) = billing_subject.as_deref() {
-                            state
-                                .proxy
-                                .record_pending_billing_attempt_for_subject_request_log_metadata(
-                                    tid,
-                                    &method,
-                                    &path,
-                                    None,
-                                    Some(resp.status.as_u16() as i64),
-                                    analysis.tavily_status_code,
-                                    true,
-                                    analysis.status,
-                                    None,
-                                    credits,
-                                    subject,
-                                    analysis.api_key_id.as_deref(),
-                                    analysis.failure_kind.as_deref(),
-                                    Some(resp.key_effect_code.as_str()),
-                                    resp.key_effect_summary.as_deref(),
-                                    Some(resp.binding_effect_code.as_str()),
-                                    resp.binding_effect_summary.as_deref(),
-                                    Some(resp.selection_effect_code.as_str()),
-                                    resp.selection_effect_summary.as_deref(),
-                                    resp.request_log_id,
-                                )
-                                .await
-                        } else {
-                            state
-                                .proxy
-                                .record_pending_billing_attempt_request_log_metadata(
-                                    tid,
-                                    &method,
-                                    &path,
-                                    None,
-                                    Some(resp.status.as_u16() as i64),
-                                    analysis.tavily_status_code,
-                                    true,
-                                    analysis.status,
-                                    None,
-                                    credits,
-                                    analysis.api_key_id.as_deref(),
-                                    analysis.failure_kind.as_deref(),
-                                    Some(resp.key_effect_code.as_str()),
-                                    resp.key_effect_summary.as_deref(),
-                                    Some(resp.binding_effect_code.as_str()),
-                                    resp.binding_effect_summary.as_deref(),
-                                    Some(resp.selection_effect_code.as_str()),
-                                    resp.selection_effect_summary.as_deref(),
-                                    resp.request_log_id,
-                                )
-                                .await
-                        }
-                        {
-                            Ok(log_id) => {
-                                attempt_logged = true;
-                                if let Some(msg) = billing_error.as_deref() {
-                                    let _ = state
-                                        .proxy
-                                        .annotate_pending_billing_attempt(log_id, msg)
-                                        .await;
-                                }
-                                let lock_lost_msg = token_billing_guard
-                                    .as_ref()
-                                    .and_then(|guard| guard.ensure_live().err())
-                                    .map(|err| {
-                                        format!(
-                                            "charge_token_quota deferred for {path}: {err}; pending billing will retry"
-                                        )
-                                    });
-                                if let Some(msg) = lock_lost_msg {
-                                    eprintln!("{msg}");
-                                    let _ = state
-                                        .proxy
-                                        .annotate_pending_billing_attempt(log_id, &msg)
-                                        .await;
-                                    billing_error = Some(msg);
-                                } else {
-                                    match state.proxy.settle_pending_billing_attempt(log_id).await {
-                                    Ok(PendingBillingSettleOutcome::Charged)
-                                    | Ok(PendingBillingSettleOutcome::AlreadySettled) => {}
-                                    Ok(PendingBillingSettleOutcome::RetryLater) => {
-                                        let msg = format!(
-                                            "charge_token_quota delayed for {path}: pending billing claim miss; will retry"
-                                        );
-                                        eprintln!("{msg}");
-                                        let _ = state
-                                            .proxy
-                                            .annotate_pending_billing_attempt(log_id, &msg)
-                                            .await;
-                                        billing_error = Some(msg);
-                                    }
-                                    Err(err) => {
-                                        let msg = format!("charge_token_quota failed for {path}: {err}");
-                                        eprintln!("{msg}");
-                                        let _ = state
-                                            .proxy
-                                            .annotate_pending_billing_attempt(log_id, &msg)
-                                            .await;
-                                        billing_error = Some(msg);
-                                    }
-                                }
-                                }
-                            }
-                            Err(err) => {
-                                let msg = format!(
-                                    "record_pending_billing_attempt failed for {path}: {err}"
-                                );
-                                eprintln!("{msg}");
-                                billing_error = Some(msg);
-                            }
-                        }
-                    }
-                }
-
-                if !attempt_logged
-                    && let Some(tid) = token_id_for_logs.as_deref()
-                {
-                    let http_code = resp.status.as_u16() as i64;
-                    let _ = state
-                        .proxy
-                        .record_token_attempt_request_log_metadata(
-                            tid,
-                            &method,
-                            &path,
-                            None,
-                            Some(http_code),
-                            analysis.tavily_status_code,
-                            true,
-                            analysis.status,
-                            billing_error.as_deref(),
-                            analysis.failure_kind.as_deref(),
-                            Some(resp.key_effect_code.as_str()),
-                            resp.key_effect_summary.as_deref(),
-                            Some(resp.binding_effect_code.as_str()),
-                            resp.binding_effect_summary.as_deref(),
-                            Some(resp.selection_effect_code.as_str()),
-                            resp.selection_effect_summary.as_deref(),
-                            resp.request_log_id,
-                        )
-                        .await;
-                }
+                record_tavily_http_research_billing(
+                    &state,
+                    &method,
+                    &path,
+                    token_id_for_logs.as_deref(),
+                    billing_subject.as_deref(),
+                    token_billing_guard.as_ref(),
+                    None,
+                    &resp,
+                    &analysis,
+                    usage_delta.unwrap_or(reserved_credits),
+                )
+                .await;
                 state
                     .proxy
                     .finalize_business_calls_1h_reservation_from_status(
@@ -1281,9 +1426,11 @@ async fn proxy_tavily_http_endpoint(
                     | ProxyError::PinnedMcpSessionUnavailable
                     | ProxyError::QuotaDataMissing { .. }
                     | ProxyError::UsageHttp { .. } => StatusCode::BAD_GATEWAY,
+                    ProxyError::KeyRateBudgetExhausted { .. } => StatusCode::TOO_MANY_REQUESTS,
                     ProxyError::Database(_)
                     | ProxyError::InvalidEndpoint { .. }
                     | ProxyError::LastAdminLoginMethod
+                    | ProxyError::TokenSecretNotRecoverable { .. }
                     | ProxyError::StaleClaim { .. }
                     | ProxyError::Deferred { .. }
                     | ProxyError::Other(_) => StatusCode::INTERNAL_SERVER_ERROR,
@@ -1303,7 +1450,85 @@ async fn proxy_tavily_http_endpoint(
         }
     }
 
-    let result = match config.mode {
+    // Identical calls within the TTL are answered locally and never reach key selection.
+    let response_cache = match state.proxy.response_cache_settings().await {
+        Ok(settings) if settings.covers_upstream_path(config.upstream_path) => {
+            let cache_key = response_cache_key(config.upstream_path, &options);
+            Some((settings, cache_key))
+        }
+        Ok(_) => None,
+        Err(err) => {
+            eprintln!("response cache settings unavailable for {path}: {err}");
+            None
+        }
+    };
+    let cached = match response_cache.as_ref() {
+        Some((settings, cache_key)) => state
+            .proxy
+            .serve_cached_http_response(
+                settings,
+                cache_key,
+                auth_token_id.as_deref(),
+                &method,
+                &path,
+                &options,
+                Some(&client_ip),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("response cache lookup failed for {path}: {err}");
+                None
+            }),
+        None => None,
+    };
+    // Identical calls already upstream are joined instead of spending another key.
+    let mut flight_leader = None;
+    let coalesced = match cached {
+        Some(hit) => Some(hit),
+        None => match state.proxy.request_coalescing_settings().await {
+            Ok(settings) if settings.covers_upstream_path(config.upstream_path) => {
+                match state
+                    .proxy
+                    .join_request_flight(&request_coalescing_key(config.upstream_path, &options))
+                {
+                    RequestFlight::Leader(leader) => {
+                        flight_leader = Some(leader);
+                        None
+                    }
+                    RequestFlight::Follower(follower) => match follower.wait().await {
+                        Some(shared) => state
+                            .proxy
+                            .serve_coalesced_http_response(
+                                &settings,
+                                shared,
+                                auth_token_id.as_deref(),
+                                &method,
+                                &path,
+                                &options,
+                                Some(&client_ip),
+                            )
+                            .await
+                            .map_err(|err| {
+                                eprintln!("coalesced response failed for {path}: {err}");
+                            })
+                            .ok(),
+                        None => None,
+                    },
+                }
+            }
+            Ok(_) => None,
+            Err(err) => {
+                eprintln!("request coalescing settings unavailable for {path}: {err}");
+                None
+            }
+        },
+    };
+    // Credits billed for an answer served without going upstream.
+    let local_credits = coalesced.as_ref().map(|(_, _, credits)| *credits);
+
+    let result = match coalesced {
+        Some((resp, analysis, _)) => Ok((resp, analysis)),
+        None => match config.mode {
         TavilyUpstreamMode::Search => {
             state
                 .proxy
@@ -1340,17 +1565,38 @@ async fn proxy_tavily_http_endpoint(
                 )
                 .await
         }
+        },
     };
 
     match result {
         Ok((resp, analysis)) => {
+            let upstream_credits = extract_usage_credits_from_json_bytes(&resp.body)
+                .or(expected_search_credits)
+                .unwrap_or(0);
+            if let Some(leader) = flight_leader.take()
+                && resp.status.is_success()
+                && analysis.status == "success"
+            {
+                leader.publish(SharedUpstreamResponse {
+                    status: resp.status,
+                    content_type: resp
+                        .headers
+                        .get(CONTENT_TYPE)
+                        .and_then(|value| value.to_str().ok())
+                        .map(str::to_string),
+                    body: resp.body.clone(),
+                    credits: upstream_credits,
+                });
+            }
             let mut billing_error: Option<String> = None;
             let mut attempt_logged = false;
             if resp.status.is_success()
                 && analysis.status == "success"
                 && let Some(tid) = token_id_for_logs.as_deref()
             {
-                let credits = if config.upstream_path == "/search" {
+                let credits = if let Some(credits) = local_credits {
+                    credits
+                } else if config.upstream_path == "/search" {
                     extract_usage_credits_from_json_bytes(&resp.body)
                         .unwrap_or_else(|| expected_search_credits.unwrap_or(1))
                 } else {
@@ -1501,6 +1747,22 @@ async fn proxy_tavily_http_endpoint(
                     )
                     .await;
             }
+            if local_credits.is_none()
+                && analysis.status == "success"
+                && let Some((settings, cache_key)) = response_cache.as_ref()
+                && let Err(err) = state
+                    .proxy
+                    .store_cached_http_response(
+                        settings,
+                        config.upstream_path,
+                        cache_key,
+                        &resp,
+                        upstream_credits,
+                    )
+                    .await
+            {
+                eprintln!("response cache store failed for {path}: {err}");
+            }
             state
                 .proxy
                 .finalize_business_calls_1h_reservation_from_status(
@@ -1551,9 +1813,11 @@ async fn proxy_tavily_http_endpoint(
                 ProxyError::Http(_)
                 | ProxyError::NoAvailableKeys
                 | ProxyError::PinnedMcpSessionUnavailable => StatusCode::BAD_GATEWAY,
+                ProxyError::KeyRateBudgetExhausted { .. } => StatusCode::TOO_MANY_REQUESTS,
                 ProxyError::Database(_)
                 | ProxyError::InvalidEndpoint { .. }
                 | ProxyError::LastAdminLoginMethod
+                | ProxyError::TokenSecretNotRecoverable { .. }
                 | ProxyError::QuotaDataMissing { .. }
                 | ProxyError::UsageHttp { .. }
                 | ProxyError::StaleClaim { .. }
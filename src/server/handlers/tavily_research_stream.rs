@@ -0,0 +1,293 @@
+/// Buffered upstream chunks between the research stream driver and the client body.
+const RESEARCH_STREAM_CHANNEL_CAPACITY: usize = 16;
+
+fn tavily_research_stream_requested(options: &Value) -> bool {
+    options
+        .get("stream")
+        .and_then(Value::as_bool)
+        .unwrap_or(false)
+}
+
+/// Request facts carried from the `/api/tavily/research` prechecks into the streaming path.
+struct TavilyResearchStreamRequest {
+    method: Method,
+    path: String,
+    auth_token_id: Option<String>,
+    use_api_rebalance: bool,
+    api_routing_key: Option<String>,
+    http_project_id: Option<String>,
+    options: Value,
+    headers: HeaderMap,
+    client_ip: ClientIpInfo,
+    billing_subject: Option<String>,
+    reserved_credits: i64,
+    /// Credits held against the billing subject while the stream is open, so concurrent
+    /// prechecks see them before the final charge lands.
+    quota_reservation_id: Option<i64>,
+    business_calls_reservation: Option<tavily_hikari::UserBusinessCallReservation>,
+}
+
+async fn release_research_stream_quota_reservation(
+    state: &AppState,
+    request: &mut TavilyResearchStreamRequest,
+) {
+    if let Some(reservation_id) = request.quota_reservation_id.take()
+        && let Err(err) = state.proxy.release_quota_reservation(reservation_id).await
+    {
+        eprintln!(
+            "quota reservation release failed for {}: {err}",
+            request.path
+        );
+    }
+}
+
+/// Settlement facts for one streamed (or stream-requested but buffered) research attempt.
+struct TavilyResearchStreamSettlement {
+    response: ProxyResponse,
+    analysis: tavily_hikari::AttemptAnalysis,
+    usage_credits: Option<i64>,
+    research_request_id: Option<String>,
+    completed: bool,
+}
+
+/// Proxy `POST /api/tavily/research` with `stream=true`.
+///
+/// The upstream SSE body is forwarded chunk by chunk. A detached driver keeps reading upstream
+/// even if the client disconnects, so the request log, research affinity, and billing always
+/// settle from the final usage event instead of depending on the client staying connected.
+async fn proxy_tavily_http_research_stream(
+    state: Arc<AppState>,
+    mut request: TavilyResearchStreamRequest,
+) -> Result<Response<Body>, StatusCode> {
+    let options = std::mem::take(&mut request.options);
+    let start = state
+        .proxy
+        .proxy_http_research_stream(
+            &state.usage_base,
+            request.auth_token_id.as_deref(),
+            request.use_api_rebalance,
+            request.api_routing_key.as_deref(),
+            request.http_project_id.as_deref(),
+            &request.method,
+            &request.path,
+            options,
+            &request.headers,
+            true,
+            Some(&request.client_ip),
+        )
+        .await;
+
+    match start {
+        Ok(tavily_hikari::HttpResearchStreamStart::Buffered(resp, analysis)) => {
+            let research_request_id = resp
+                .status
+                .is_success()
+                .then(|| extract_research_request_id(&resp.body))
+                .flatten();
+            let settlement = TavilyResearchStreamSettlement {
+                response: *resp,
+                analysis,
+                usage_credits: None,
+                research_request_id,
+                completed: false,
+            };
+            settle_tavily_http_research_stream(&state, &mut request, &settlement).await;
+            Ok(build_response(settlement.response))
+        }
+        Ok(tavily_hikari::HttpResearchStreamStart::Streaming(mut upstream)) => {
+            let mut builder = Response::builder().status(upstream.status());
+            if let Some(headers) = builder.headers_mut() {
+                for (name, value) in upstream.headers().iter() {
+                    if name == TRANSFER_ENCODING || name == CONNECTION || name == CONTENT_LENGTH {
+                        continue;
+                    }
+                    headers.append(name.clone(), value.clone());
+                }
+            }
+
+            let (tx, mut rx) = tokio::sync::mpsc::channel::<Bytes>(RESEARCH_STREAM_CHANNEL_CAPACITY);
+            let driver_state = state.clone();
+            tokio::spawn(async move {
+                let mut client_connected = true;
+                while let Some(chunk) = upstream.next_chunk(&driver_state.proxy).await {
+                    if client_connected && tx.send(chunk).await.is_err() {
+                        client_connected = false;
+                    }
+                }
+                drop(tx);
+                match upstream.finish(&driver_state.proxy).await {
+                    Ok(outcome) => {
+                        let settlement = TavilyResearchStreamSettlement {
+                            response: outcome.response,
+                            analysis: outcome.analysis,
+                            usage_credits: outcome.usage_credits,
+                            research_request_id: outcome.research_request_id,
+                            completed: outcome.completed,
+                        };
+                        settle_tavily_http_research_stream(&driver_state, &mut request, &settlement)
+                            .await;
+                    }
+                    Err(err) => {
+                        eprintln!("tavily http research stream finish failed: {err}");
+                        release_research_stream_quota_reservation(&driver_state, &mut request)
+                            .await;
+                        driver_state
+                            .proxy
+                            .release_business_calls_1h_reservation(
+                                request.business_calls_reservation.take(),
+                            )
+                            .await;
+                        if let Some(tid) = request.auth_token_id.as_deref() {
+                            let msg = err.to_string();
+                            let _ = driver_state
+                                .proxy
+                                .record_token_attempt(
+                                    tid,
+                                    &request.method,
+                                    &request.path,
+                                    None,
+                                    None,
+                                    None,
+                                    false,
+                                    "error",
+                                    Some(msg.as_str()),
+                                )
+                                .await;
+                        }
+                    }
+                }
+            });
+
+            let body = stream! {
+                while let Some(chunk) = rx.recv().await {
+                    yield Ok::<Bytes, std::convert::Infallible>(chunk);
+                }
+            };
+            builder
+                .body(Body::from_stream(body))
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+        }
+        Err(err) => {
+            release_research_stream_quota_reservation(&state, &mut request).await;
+            state
+                .proxy
+                .release_business_calls_1h_reservation(request.business_calls_reservation.take())
+                .await;
+            eprintln!("tavily http /research stream proxy error: {err}");
+            if let Some(tid) = request.auth_token_id.as_deref() {
+                let msg = err.to_string();
+                let _ = state
+                    .proxy
+                    .record_token_attempt(
+                        tid,
+                        &request.method,
+                        &request.path,
+                        None,
+                        None,
+                        None,
+                        false,
+                        "error",
+                        Some(msg.as_str()),
+                    )
+                    .await;
+            }
+
+            let status = match err {
+                ProxyError::Http(_)
+                | ProxyError::NoAvailableKeys
+                | ProxyError::PinnedMcpSessionUnavailable
+                | ProxyError::QuotaDataMissing { .. }
+                | ProxyError::UsageHttp { .. } => StatusCode::BAD_GATEWAY,
+                ProxyError::KeyRateBudgetExhausted { .. } => StatusCode::TOO_MANY_REQUESTS,
+                ProxyError::Database(_)
+                | ProxyError::InvalidEndpoint { .. }
+                | ProxyError::LastAdminLoginMethod
+                | ProxyError::TokenSecretNotRecoverable { .. }
+                | ProxyError::StaleClaim { .. }
+                | ProxyError::Deferred { .. }
+                | ProxyError::Other(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            };
+            let payload = json!({
+                "error": "proxy_error",
+                "message": "upstream unavailable",
+            });
+            Response::builder()
+                .status(status)
+                .header(CONTENT_TYPE, "application/json; charset=utf-8")
+                .body(Body::from(payload.to_string()))
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+/// Settle billing and reconciliation records for a research stream.
+///
+/// The per-subject billing lock is not held while the stream is open (research can run for
+/// minutes); it is re-acquired here so the charge still serializes with other billable calls,
+/// and the credits reserved at precheck are swapped for the final usage under that lock. If the
+/// lock cannot be taken, the charge is left pending for the pending-billing retry.
+async fn settle_tavily_http_research_stream(
+    state: &Arc<AppState>,
+    request: &mut TavilyResearchStreamRequest,
+    settlement: &TavilyResearchStreamSettlement,
+) {
+    let mut billing_lock_error = None;
+    let token_billing_guard = match (
+        request.billing_subject.as_deref(),
+        request.auth_token_id.as_deref(),
+    ) {
+        (Some(_), Some(tid)) => match state.proxy.lock_token_billing(tid).await {
+            Ok(guard) => Some(guard),
+            Err(err) => {
+                billing_lock_error = Some(format!("token billing lock failed: {err}"));
+                None
+            }
+        },
+        _ => None,
+    };
+    let billing_subject = token_billing_guard
+        .as_ref()
+        .map(|guard| guard.billing_subject().to_string())
+        .or_else(|| request.billing_subject.clone());
+
+    record_tavily_http_research_billing(
+        state,
+        &request.method,
+        &request.path,
+        request.auth_token_id.as_deref(),
+        billing_subject.as_deref(),
+        token_billing_guard.as_ref(),
+        billing_lock_error.as_deref(),
+        &settlement.response,
+        &settlement.analysis,
+        settlement.usage_credits.unwrap_or(request.reserved_credits),
+    )
+    .await;
+    release_research_stream_quota_reservation(state, request).await;
+    state
+        .proxy
+        .finalize_business_calls_1h_reservation_from_status(
+            request.business_calls_reservation.take(),
+            settlement.analysis.status,
+            settlement.response.request_log_id,
+        )
+        .await;
+    record_rebalance_period_usage(
+        state,
+        request.use_api_rebalance,
+        request.auth_token_id.as_deref(),
+        settlement.analysis.api_key_id.as_deref(),
+        billing_subject.as_deref(),
+        &settlement.response.body,
+        settlement.research_request_id.as_deref(),
+    )
+    .await;
+    if settlement.completed
+        && let Some(request_id) = settlement.research_request_id.as_deref()
+    {
+        let _ = state
+            .proxy
+            .mark_upstream_reconciliation_research_terminal(request_id)
+            .await;
+    }
+}
@@ -59,6 +59,8 @@ struct LinuxDoTokenResponse {
 #[derive(Debug, Deserialize)]
 struct LinuxDoAuthForm {
     token: Option<String>,
+    /// Registration invite code, consumed only if this login registers a new account.
+    invite_code: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -74,6 +76,7 @@ enum LinuxDoFinalizeOutcome {
     Success,
     InvalidState,
     RegistrationPaused,
+    InvalidInvite,
     InactiveUser,
     UpstreamFailure,
     ServerError,
@@ -89,55 +92,64 @@ struct LinuxDoFinalizeResponse {
 }
 
 impl LinuxDoFinalizeResponse {
-    fn success() -> Self {
+    fn success(provider: &'static str) -> Self {
         Self {
             outcome: LinuxDoFinalizeOutcome::Success,
-            provider: "linuxdo",
+            provider,
             redirect_to: Some("/console"),
             detail: None,
         }
     }
 
-    fn invalid_state(detail: impl Into<String>) -> Self {
+    fn invalid_state(provider: &'static str, detail: impl Into<String>) -> Self {
         Self {
             outcome: LinuxDoFinalizeOutcome::InvalidState,
-            provider: "linuxdo",
+            provider,
             redirect_to: None,
             detail: Some(detail.into()),
         }
     }
 
-    fn registration_paused() -> Self {
+    fn registration_paused(provider: &'static str) -> Self {
         Self {
             outcome: LinuxDoFinalizeOutcome::RegistrationPaused,
-            provider: "linuxdo",
+            provider,
             redirect_to: Some("/registration-paused"),
             detail: None,
         }
     }
 
-    fn inactive_user() -> Self {
+    fn invalid_invite(provider: &'static str) -> Self {
+        Self {
+            outcome: LinuxDoFinalizeOutcome::InvalidInvite,
+            provider,
+            redirect_to: None,
+            detail: Some("invite code is invalid, expired, or used up".to_string()),
+        }
+    }
+
+    fn inactive_user(provider: &'static str) -> Self {
         Self {
             outcome: LinuxDoFinalizeOutcome::InactiveUser,
-            provider: "linuxdo",
+            provider,
             redirect_to: None,
-            detail: Some("linuxdo account is inactive".to_string()),
+            detail: Some(format!("{provider} account is inactive")),
         }
     }
 
-    fn upstream_failure(detail: impl Into<String>) -> Self {
+    fn upstream_failure(provider: &'static str, detail: impl Into<String>) -> Self {
         Self {
             outcome: LinuxDoFinalizeOutcome::UpstreamFailure,
-            provider: "linuxdo",
+            provider,
             redirect_to: None,
             detail: Some(detail.into()),
         }
     }
 
-    fn server_error(detail: impl Into<String>) -> Self {
+    fn server_error(provider: &'static str, detail: impl Into<String>) -> Self {
         Self {
             outcome: LinuxDoFinalizeOutcome::ServerError,
-            provider: "linuxdo",
+            provider,
             redirect_to: None,
             detail: Some(detail.into()),
         }
@@ -149,6 +161,7 @@ enum LinuxDoFinalizeResult {
     Success { session_token: String },
     InvalidState { detail: String },
     RegistrationPaused,
+    InvalidInvite,
     InactiveUser,
     UpstreamFailure { detail: String },
     ServerError { detail: String },
@@ -500,8 +513,10 @@ fn decrypt_linuxdo_refresh_token(
         .map_err(|err| LinuxDoSyncError::Crypto(format!("refresh token is not valid UTF-8: {err}")))
 }
 
-async fn persist_linuxdo_refresh_token_best_effort(
+/// Refresh tokens from every login provider are sealed with the linux.do refresh-token key.
+async fn persist_oauth_refresh_token_best_effort(
     state: &AppState,
+    provider: &str,
     provider_user_id: &str,
     refresh_token: Option<&str>,
 ) -> Result<bool, LinuxDoSyncError> {
@@ -516,53 +531,81 @@ async fn persist_linuxdo_refresh_token_best_effort(
 
     state
         .proxy
-        .set_oauth_account_refresh_token("linuxdo", provider_user_id, &ciphertext, &nonce)
+        .set_oauth_account_refresh_token(provider, provider_user_id, &ciphertext, &nonce)
         .await
         .map_err(|err| LinuxDoSyncError::Storage(err.to_string()))?;
     Ok(true)
 }
 
+/// Resolves the access token a visitor asked to keep (posted from the public page) to its id,
+/// so the login can bind it instead of minting a new one.
+async fn resolve_preferred_bind_token_id(
+    state: &AppState,
+    token: Option<&str>,
+) -> Result<Option<String>, StatusCode> {
+    let Some(raw_token) = token.map(str::trim).filter(|value| !value.is_empty()) else {
+        return Ok(None);
+    };
+    let valid = state
+        .proxy
+        .validate_access_token(raw_token)
+        .await
+        .map_err(|err| {
+            eprintln!("validate preferred token error: {err}");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+    Ok(if valid {
+        parse_full_token_id(raw_token)
+    } else {
+        None
+    })
+}
+
+fn oauth_login_redirect_response(
+    headers: &HeaderMap,
+    binding_nonce: &str,
+    login_state_ttl_secs: i64,
+    authorize_url: &reqwest::Url,
+) -> Result<Response<Body>, StatusCode> {
+    let binding_cookie = oauth_login_binding_set_cookie(
+        binding_nonce,
+        login_state_ttl_secs,
+        wants_secure_cookie(headers),
+    )?;
+    Ok((
+        [(SET_COOKIE, binding_cookie)],
+        // Use 303 to force the subsequent request to be a GET.
+        //
+        // This avoids browsers preserving the original POST body when following the redirect,
+        // which can break OAuth authorize endpoints (GET-only) and risk leaking form fields.
+        Redirect::to(authorize_url.as_ref()),
+    )
+        .into_response())
+}
+
 async fn start_linuxdo_auth(
     state: Arc<AppState>,
     headers: HeaderMap,
     token: Option<String>,
+    invite_code: Option<String>,
 ) -> Result<Response<Body>, StatusCode> {
     let cfg = &state.linuxdo_oauth;
     if !cfg.is_enabled_and_configured() {
         return Err(StatusCode::NOT_FOUND);
     }
 
-    let bind_token_id = if let Some(raw_token) = token
-        .as_deref()
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-    {
-        if state
-            .proxy
-            .validate_access_token(raw_token)
-            .await
-            .map_err(|err| {
-                eprintln!("validate preferred token error: {err}");
-                StatusCode::INTERNAL_SERVER_ERROR
-            })? {
-            parse_full_token_id(raw_token)
-        } else {
-            None
-        }
-    } else {
-        None
-    };
-
+    let bind_token_id = resolve_preferred_bind_token_id(state.as_ref(), token.as_deref()).await?;
     let binding_nonce = new_cookie_nonce();
     let binding_hash = hash_oauth_binding(&binding_nonce);
     let state_token = state
         .proxy
-        .create_oauth_login_state_with_binding_and_token(
+        .create_user_login_state(
             "linuxdo",
-            None,
             cfg.login_state_ttl_secs,
-            Some(&binding_hash),
+            &binding_hash,
             bind_token_id.as_deref(),
+            None,
+            invite_code.as_deref(),
         )
         .await
         .map_err(|err| {
@@ -583,53 +626,45 @@ async fn start_linuxdo_auth(
         pairs.append_pair("scope", &cfg.scope);
         pairs.append_pair("state", &state_token);
     }
-
-    let binding_cookie = oauth_login_binding_set_cookie(
-        &binding_nonce,
-        cfg.login_state_ttl_secs,
-        wants_secure_cookie(&headers),
-    )?;
-    Ok((
-        [(SET_COOKIE, binding_cookie)],
-        // Use 303 to force the subsequent request to be a GET.
-        //
-        // This avoids browsers preserving the original POST body when following the redirect,
-        // which can break OAuth authorize endpoints (GET-only) and risk leaking form fields.
-        Redirect::to(url.as_ref()),
-    )
-        .into_response())
+    oauth_login_redirect_response(&headers, &binding_nonce, cfg.login_state_ttl_secs, &url)
 }
 
-async fn finalize_linuxdo_login(
+async fn consume_user_oauth_login_state(
     state: &AppState,
-    code: &str,
+    provider: &str,
     oauth_state: &str,
     binding_hash: &str,
-) -> LinuxDoFinalizeResult {
-    let cfg = &state.linuxdo_oauth;
-    let state_payload = match state
+) -> Result<OAuthLoginStatePayload, LinuxDoFinalizeResult> {
+    match state
         .proxy
-        .consume_oauth_login_state_with_binding_and_token(
-            "linuxdo",
-            oauth_state,
-            Some(binding_hash),
-        )
+        .consume_oauth_login_state_with_binding_and_token(provider, oauth_state, Some(binding_hash))
         .await
     {
-        Ok(Some(payload)) => payload,
-        Ok(None) => {
-            return LinuxDoFinalizeResult::InvalidState {
-                detail: "oauth state is missing, expired, or already used".to_string(),
-            };
-        }
+        Ok(Some(payload)) => Ok(payload),
+        Ok(None) => Err(LinuxDoFinalizeResult::InvalidState {
+            detail: "oauth state is missing, expired, or already used".to_string(),
+        }),
         Err(err) => {
-            eprintln!("consume linuxdo oauth state error: {err}");
-            return LinuxDoFinalizeResult::ServerError {
+            eprintln!("consume {provider} oauth state error: {err}");
+            Err(LinuxDoFinalizeResult::ServerError {
                 detail: "failed to consume oauth login state".to_string(),
-            };
+            })
         }
-    };
-    let preferred_token_id = state_payload.bind_token_id;
+    }
+}
+
+async fn finalize_linuxdo_login(
+    state: &AppState,
+    code: &str,
+    oauth_state: &str,
+    binding_hash: &str,
+) -> LinuxDoFinalizeResult {
+    let cfg = &state.linuxdo_oauth;
+    let state_payload =
+        match consume_user_oauth_login_state(state, "linuxdo", oauth_state, binding_hash).await {
+            Ok(payload) => payload,
+            Err(result) => return result,
+        };
 
     let client = reqwest::Client::new();
     let (profile, token_payload) =
@@ -642,112 +677,15 @@ async fn finalize_linuxdo_login(
                 };
             }
         };
-    let provider_user_id = profile.provider_user_id.clone();
-    let allow_registration = match state.proxy.allow_registration().await {
-        Ok(value) => value,
-        Err(err) => {
-            eprintln!("read allow registration during linuxdo finalize error: {err}");
-            return LinuxDoFinalizeResult::ServerError {
-                detail: "failed to read registration policy".to_string(),
-            };
-        }
-    };
-    if !allow_registration {
-        let existing_account = match state
-            .proxy
-            .oauth_account_exists("linuxdo", &provider_user_id)
-            .await
-        {
-            Ok(value) => value,
-            Err(err) => {
-                eprintln!("query linuxdo oauth account existence error: {err}");
-                return LinuxDoFinalizeResult::ServerError {
-                    detail: "failed to read existing linuxdo account binding".to_string(),
-                };
-            }
-        };
-        if !existing_account {
-            return LinuxDoFinalizeResult::RegistrationPaused;
-        }
-    }
-    let username = profile.username.clone();
-
-    let user = match state.proxy.upsert_oauth_account(&profile).await {
-        Ok(user) => user,
-        Err(err) => {
-            eprintln!("upsert linuxdo oauth account error: {err}");
-            return LinuxDoFinalizeResult::ServerError {
-                detail: "failed to persist linuxdo account".to_string(),
-            };
-        }
-    };
-    let sync_attempted_at = state.proxy.backend_time().now_ts();
-    if let Err(err) = persist_linuxdo_refresh_token_best_effort(
+    complete_user_oauth_login(
         state,
-        &provider_user_id,
+        &profile,
         token_payload.refresh_token.as_deref(),
+        state_payload.bind_token_id.as_deref(),
+        state_payload.invite_code.as_deref(),
+        cfg.session_max_age_secs,
     )
     .await
-    {
-        eprintln!("persist linuxdo refresh token error: {err}");
-        if let Err(mark_err) = state
-            .proxy
-            .record_oauth_account_profile_sync_failure(
-                "linuxdo",
-                &provider_user_id,
-                sync_attempted_at,
-                &err.to_string(),
-            )
-            .await
-        {
-            eprintln!("record linuxdo finalize sync failure error: {mark_err}");
-        }
-    } else if let Err(err) = state
-        .proxy
-        .record_oauth_account_profile_sync_success("linuxdo", &provider_user_id, sync_attempted_at)
-        .await
-    {
-        eprintln!("record linuxdo finalize sync success error: {err}");
-    }
-    if !profile.active {
-        return LinuxDoFinalizeResult::InactiveUser;
-    }
-
-    let note = format!(
-        "linuxdo:{}",
-        username.clone().unwrap_or_else(|| provider_user_id.clone())
-    );
-    if let Err(err) = state
-        .proxy
-        .ensure_user_token_binding_with_preferred(
-            &user.user_id,
-            Some(&note),
-            preferred_token_id.as_deref(),
-        )
-        .await
-    {
-        eprintln!("ensure user token binding error: {err}");
-        return LinuxDoFinalizeResult::ServerError {
-            detail: "failed to ensure user token binding".to_string(),
-        };
-    }
-
-    let session = match state
-        .proxy
-        .create_user_session(&user, cfg.session_max_age_secs)
-        .await
-    {
-        Ok(session) => session,
-        Err(err) => {
-            eprintln!("create user session error: {err}");
-            return LinuxDoFinalizeResult::ServerError {
-                detail: "failed to create user session".to_string(),
-            };
-        }
-    };
-    LinuxDoFinalizeResult::Success {
-        session_token: session.token,
-    }
 }
 
 fn linuxdo_finalize_json_response(
@@ -893,7 +831,7 @@ async fn get_linuxdo_auth(
     if require_full_master_write(state.as_ref()).await.is_err() {
         return Err(StatusCode::SERVICE_UNAVAILABLE);
     }
-    start_linuxdo_auth(state, headers, None).await
+    start_linuxdo_auth(state, headers, None, None).await
 }
 
 async fn post_linuxdo_auth(
@@ -904,7 +842,7 @@ async fn post_linuxdo_auth(
     if require_full_master_write(state.as_ref()).await.is_err() {
         return Err(StatusCode::SERVICE_UNAVAILABLE);
     }
-    start_linuxdo_auth(state, headers, payload.token).await
+    start_linuxdo_auth(state, headers, payload.token, payload.invite_code).await
 }
 
 async fn get_linuxdo_callback(
@@ -918,6 +856,59 @@ async fn get_linuxdo_callback(
     render_linuxdo_callback_diagnostic(cfg, &query).await
 }
 
+/// Reads the browser binding cookie set when the login started; `Err` carries the
+/// `invalid_state` detail.
+fn oauth_finalize_binding_hash(
+    headers: &HeaderMap,
+    payload: &LinuxDoFinalizeRequest,
+) -> Result<String, &'static str> {
+    if payload.code.trim().is_empty() || payload.state.trim().is_empty() {
+        return Err("missing code or state");
+    }
+    cookie_value(headers, OAUTH_LOGIN_BINDING_COOKIE_NAME)
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .map(|binding_nonce| hash_oauth_binding(&binding_nonce))
+        .ok_or("missing oauth binding cookie")
+}
+
+fn user_oauth_finalize_response(
+    provider: &'static str,
+    result: LinuxDoFinalizeResult,
+    session_max_age_secs: i64,
+    use_secure_cookie: bool,
+) -> Result<Response<Body>, StatusCode> {
+    let (payload, session_cookie) = match result {
+        LinuxDoFinalizeResult::Success { session_token } => (
+            LinuxDoFinalizeResponse::success(provider),
+            Some(user_session_set_cookie(
+                &session_token,
+                session_max_age_secs,
+                use_secure_cookie,
+            )?),
+        ),
+        LinuxDoFinalizeResult::InvalidState { detail } => {
+            (LinuxDoFinalizeResponse::invalid_state(provider, detail), None)
+        }
+        LinuxDoFinalizeResult::RegistrationPaused => {
+            (LinuxDoFinalizeResponse::registration_paused(provider), None)
+        }
+        LinuxDoFinalizeResult::InvalidInvite => {
+            (LinuxDoFinalizeResponse::invalid_invite(provider), None)
+        }
+        LinuxDoFinalizeResult::InactiveUser => {
+            (LinuxDoFinalizeResponse::inactive_user(provider), None)
+        }
+        LinuxDoFinalizeResult::UpstreamFailure { detail } => {
+            (LinuxDoFinalizeResponse::upstream_failure(provider, detail), None)
+        }
+        LinuxDoFinalizeResult::ServerError { detail } => {
+            (LinuxDoFinalizeResponse::server_error(provider, detail), None)
+        }
+    };
+    linuxdo_finalize_json_response(payload, use_secure_cookie, session_cookie)
+}
+
 async fn post_linuxdo_finalize(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
@@ -929,71 +920,36 @@ async fn post_linuxdo_finalize(
     }
 
     let use_secure_cookie = wants_secure_cookie(&headers);
-    let code = payload.code.trim();
-    let oauth_state = payload.state.trim();
-    if code.is_empty() || oauth_state.is_empty() {
-        return linuxdo_finalize_json_response(
-            LinuxDoFinalizeResponse::invalid_state("missing code or state"),
-            use_secure_cookie,
-            None,
-        );
-    }
-    let Some(binding_nonce) = cookie_value(&headers, OAUTH_LOGIN_BINDING_COOKIE_NAME)
-        .map(|value| value.trim().to_string())
-        .filter(|value| !value.is_empty())
-    else {
-        return linuxdo_finalize_json_response(
-            LinuxDoFinalizeResponse::invalid_state("missing oauth binding cookie"),
-            use_secure_cookie,
-            None,
-        );
-    };
-
-    let binding_hash = hash_oauth_binding(&binding_nonce);
-    let result = finalize_linuxdo_login(state.as_ref(), code, oauth_state, &binding_hash).await;
-    match result {
-        LinuxDoFinalizeResult::Success { session_token } => {
-            let session_cookie =
-                user_session_set_cookie(&session_token, cfg.session_max_age_secs, use_secure_cookie)?;
-            linuxdo_finalize_json_response(
-                LinuxDoFinalizeResponse::success(),
+    let binding_hash = match oauth_finalize_binding_hash(&headers, &payload) {
+        Ok(hash) => hash,
+        Err(detail) => {
+            return linuxdo_finalize_json_response(
+                LinuxDoFinalizeResponse::invalid_state("linuxdo", detail),
                 use_secure_cookie,
-                Some(session_cookie),
-            )
+                None,
+            );
         }
-        LinuxDoFinalizeResult::InvalidState { detail } => linuxdo_finalize_json_response(
-            LinuxDoFinalizeResponse::invalid_state(detail),
-            use_secure_cookie,
-            None,
-        ),
-        LinuxDoFinalizeResult::RegistrationPaused => linuxdo_finalize_json_response(
-            LinuxDoFinalizeResponse::registration_paused(),
-            use_secure_cookie,
-            None,
-        ),
-        LinuxDoFinalizeResult::InactiveUser => linuxdo_finalize_json_response(
-            LinuxDoFinalizeResponse::inactive_user(),
-            use_secure_cookie,
-            None,
-        ),
-        LinuxDoFinalizeResult::UpstreamFailure { detail } => linuxdo_finalize_json_response(
-            LinuxDoFinalizeResponse::upstream_failure(detail),
-            use_secure_cookie,
-            None,
-        ),
-        LinuxDoFinalizeResult::ServerError { detail } => linuxdo_finalize_json_response(
-            LinuxDoFinalizeResponse::server_error(detail),
-            use_secure_cookie,
-            None,
-        ),
-    }
+    };
+    let result = finalize_linuxdo_login(
+        state.as_ref(),
+        payload.code.trim(),
+        payload.state.trim(),
+        &binding_hash,
+    )
+    .await;
+    user_oauth_finalize_response(
+        "linuxdo",
+        result,
+        cfg.session_max_age_secs,
+        use_secure_cookie,
+    )
 }
 
 async fn post_user_logout(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
 ) -> Result<Response<Body>, StatusCode> {
-    if !state.linuxdo_oauth.is_enabled_and_configured() {
+    if !state.user_login_enabled() {
         return Err(StatusCode::NOT_FOUND);
     }
     if let Some(token) = cookie_value(&headers, USER_SESSION_COOKIE_NAME) {
@@ -1006,8 +962,8 @@ async fn post_user_logout(
 async fn get_user_token(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
-) -> Result<Json<UserTokenView>, StatusCode> {
-    if !state.linuxdo_oauth.is_enabled_and_configured() {
+) -> Result<Response<Body>, StatusCode> {
+    if !state.user_login_enabled() {
         return Err(StatusCode::NOT_FOUND);
     }
     let Some(user_session) = resolve_user_session(state.as_ref(), &headers).await else {
@@ -1017,9 +973,13 @@ async fn get_user_token(
     match state.proxy.get_user_token(&user_session.user.user_id).await {
         Ok(UserTokenLookup::Found(secret)) => Ok(Json(UserTokenView {
             token: secret.token,
-        })),
+        })
+        .into_response()),
         Ok(UserTokenLookup::MissingBinding) => Err(StatusCode::NOT_FOUND),
         Ok(UserTokenLookup::Unavailable) => Err(StatusCode::CONFLICT),
+        Err(ProxyError::TokenSecretNotRecoverable { token_id }) => {
+            Ok(token_secret_not_recoverable_response(&token_id))
+        }
         Err(err) => {
             eprintln!("get user token error: {err}");
             Err(StatusCode::INTERNAL_SERVER_ERROR)
@@ -1508,7 +1468,7 @@ async fn get_user_dashboard(
     headers: HeaderMap,
     Query(query): Query<UserTodayWindowQuery>,
 ) -> Result<Json<UserDashboardView>, (StatusCode, String)> {
-    if !state.linuxdo_oauth.is_enabled_and_configured() {
+    if !state.user_login_enabled() {
         return Err((StatusCode::NOT_FOUND, "not found".to_string()));
     }
     let Some(user_session) = resolve_user_session(state.as_ref(), &headers).await else {
@@ -1531,7 +1491,7 @@ async fn get_user_dashboard_overview(
     headers: HeaderMap,
     Query(query): Query<UserTodayWindowQuery>,
 ) -> Result<Json<UserDashboardOverviewView>, (StatusCode, String)> {
-    if !state.linuxdo_oauth.is_enabled_and_configured() {
+    if !state.user_login_enabled() {
         return Err((StatusCode::NOT_FOUND, "not found".to_string()));
     }
     let Some(user_session) = resolve_user_session(state.as_ref(), &headers).await else {
@@ -1558,7 +1518,7 @@ async fn put_user_debug_info_sharing(
     Json(payload): Json<UserDebugInfoSharingPayload>,
 ) -> Result<Json<UserDebugInfoSharingView>, (StatusCode, String)> {
     require_full_master_write(state.as_ref()).await?;
-    if !state.linuxdo_oauth.is_enabled_and_configured() {
+    if !state.user_login_enabled() {
         return Err((StatusCode::NOT_FOUND, "not found".to_string()));
     }
     let Some(user_session) = resolve_user_session(state.as_ref(), &headers).await else {
@@ -1813,7 +1773,7 @@ async fn get_user_recharge_config(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
 ) -> Result<Json<RechargeConfigView>, (StatusCode, String)> {
-    if !state.linuxdo_oauth.is_enabled_and_configured() {
+    if !state.user_login_enabled() {
         return Err((StatusCode::NOT_FOUND, "not found".to_string()));
     }
     let Some(user_session) = resolve_user_session(state.as_ref(), &headers).await else {
@@ -1857,7 +1817,7 @@ async fn get_user_billing_summary(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
 ) -> Result<Json<UserBillingSummaryView>, (StatusCode, String)> {
-    if !state.linuxdo_oauth.is_enabled_and_configured() {
+    if !state.user_login_enabled() {
         return Err((StatusCode::NOT_FOUND, "not found".to_string()));
     }
     let Some(user_session) = resolve_user_session(state.as_ref(), &headers).await else {
@@ -2081,7 +2041,7 @@ async fn post_user_recharge_quote(
     headers: HeaderMap,
     Json(payload): Json<CreateRechargeQuoteRequest>,
 ) -> Result<Json<RechargeQuoteView>, (StatusCode, String)> {
-    if !state.linuxdo_oauth.is_enabled_and_configured() {
+    if !state.user_login_enabled() {
         return Err((StatusCode::NOT_FOUND, "not found".to_string()));
     }
     let Some(user_session) = resolve_user_session(state.as_ref(), &headers).await else {
@@ -2111,7 +2071,7 @@ async fn get_user_recharge_orders(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
 ) -> Result<Json<RechargeOrdersView>, (StatusCode, String)> {
-    if !state.linuxdo_oauth.is_enabled_and_configured() {
+    if !state.user_login_enabled() {
         return Err((StatusCode::NOT_FOUND, "not found".to_string()));
     }
     let Some(user_session) = resolve_user_session(state.as_ref(), &headers).await else {
@@ -2133,7 +2093,7 @@ async fn get_user_recharge_order(
     headers: HeaderMap,
     Path(out_trade_no): Path<String>,
 ) -> Result<Json<RechargeOrderView>, (StatusCode, String)> {
-    if !state.linuxdo_oauth.is_enabled_and_configured() {
+    if !state.user_login_enabled() {
         return Err((StatusCode::NOT_FOUND, "not found".to_string()));
     }
     let Some(user_session) = resolve_user_session(state.as_ref(), &headers).await else {
@@ -2159,7 +2119,7 @@ async fn post_user_recharge_order(
     Json(raw_payload): Json<serde_json::Value>,
 ) -> Result<Json<CreateRechargeOrderResponse>, (StatusCode, String)> {
     require_full_master_write(state.as_ref()).await?;
-    if !state.linuxdo_oauth.is_enabled_and_configured() {
+    if !state.user_login_enabled() {
         return Err((StatusCode::NOT_FOUND, "not found".to_string()));
     }
     let Some(user_session) = resolve_user_session(state.as_ref(), &headers).await else {
@@ -2547,7 +2507,7 @@ async fn get_user_tokens(
     headers: HeaderMap,
     Query(query): Query<UserTodayWindowQuery>,
 ) -> Result<Json<Vec<UserTokenSummaryView>>, (StatusCode, String)> {
-    if !state.linuxdo_oauth.is_enabled_and_configured() {
+    if !state.user_login_enabled() {
         return Err((StatusCode::NOT_FOUND, "not found".to_string()));
     }
     let Some(user_session) = resolve_user_session(state.as_ref(), &headers).await else {
@@ -2632,7 +2592,7 @@ async fn get_user_token_detail(
     Path(id): Path<String>,
     Query(query): Query<UserTodayWindowQuery>,
 ) -> Result<Json<UserTokenSummaryView>, (StatusCode, String)> {
-    if !state.linuxdo_oauth.is_enabled_and_configured() {
+    if !state.user_login_enabled() {
         return Err((StatusCode::NOT_FOUND, "not found".to_string()));
     }
     let Some(user_session) = resolve_user_session(state.as_ref(), &headers).await else {
@@ -2662,8 +2622,8 @@ async fn get_user_token_secret(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
     Path(id): Path<String>,
-) -> Result<Json<UserTokenView>, StatusCode> {
-    if !state.linuxdo_oauth.is_enabled_and_configured() {
+) -> Result<Response<Body>, StatusCode> {
+    if !state.user_login_enabled() {
         return Err(StatusCode::NOT_FOUND);
     }
     let Some(user_session) = resolve_user_session(state.as_ref(), &headers).await else {
@@ -2674,8 +2634,11 @@ async fn get_user_token_secret(
         .get_user_token_secret(&user_session.user.user_id, &id)
         .await
     {
-        Ok(Some(token)) => Ok(Json(UserTokenView { token: token.token })),
+        Ok(Some(token)) => Ok(Json(UserTokenView { token: token.token }).into_response()),
         Ok(None) => Err(StatusCode::NOT_FOUND),
+        Err(ProxyError::TokenSecretNotRecoverable { token_id }) => {
+            Ok(token_secret_not_recoverable_response(&token_id))
+        }
         Err(err) => {
             eprintln!("get user token secret error: {err}");
             Err(StatusCode::INTERNAL_SERVER_ERROR)
@@ -2688,21 +2651,25 @@ async fn rotate_user_token_secret(
     headers: HeaderMap,
     Path(id): Path<String>,
 ) -> Result<Json<UserTokenView>, StatusCode> {
-    if !state.linuxdo_oauth.is_enabled_and_configured() {
+    if !state.user_login_enabled() {
         return Err(StatusCode::NOT_FOUND);
     }
     let Some(user_session) = resolve_user_session(state.as_ref(), &headers).await else {
         return Err(StatusCode::UNAUTHORIZED);
     };
-    let visible_token = state
+    let visible = match state
         .proxy
         .get_user_token_secret(&user_session.user.user_id, &id)
         .await
-        .map_err(|err| {
+    {
+        Ok(token) => token.is_some(),
+        Err(ProxyError::TokenSecretNotRecoverable { .. }) => true,
+        Err(err) => {
             eprintln!("rotate user token secret visibility error: {err}");
-            StatusCode::INTERNAL_SERVER_ERROR
-        })?;
-    if visible_token.is_none() {
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+    if !visible {
         return Err(StatusCode::NOT_FOUND);
     }
     match state.proxy.rotate_access_token_secret(&id).await {
@@ -2723,7 +2690,7 @@ async fn get_user_token_logs(
     Path(id): Path<String>,
     Query(q): Query<UserTokenLogsQuery>,
 ) -> Result<Json<Vec<UserTokenLogView>>, StatusCode> {
-    if !state.linuxdo_oauth.is_enabled_and_configured() {
+    if !state.user_login_enabled() {
         return Err(StatusCode::NOT_FOUND);
     }
     let Some(user_session) = resolve_user_session(state.as_ref(), &headers).await else {
@@ -2755,7 +2722,7 @@ async fn sse_user_dashboard(
     headers: HeaderMap,
     Query(query): Query<UserTodayWindowQuery>,
 ) -> Result<Sse<impl futures_util::Stream<Item = Result<Event, axum::http::Error>>>, StatusCode> {
-    if !state.linuxdo_oauth.is_enabled_and_configured() {
+    if !state.user_login_enabled() {
         return Err(StatusCode::NOT_FOUND);
     }
     let Some(user_session) = resolve_user_session(state.as_ref(), &headers).await else {
@@ -2798,7 +2765,7 @@ async fn sse_user_token(
     Path(id): Path<String>,
     Query(query): Query<UserTodayWindowQuery>,
 ) -> Result<Sse<impl futures_util::Stream<Item = Result<Event, axum::http::Error>>>, StatusCode> {
-    if !state.linuxdo_oauth.is_enabled_and_configured() {
+    if !state.user_login_enabled() {
         return Err(StatusCode::NOT_FOUND);
     }
     let Some(user_session) = resolve_user_session(state.as_ref(), &headers).await else {
@@ -0,0 +1,163 @@
+/// Provider-independent tail of a login: registration policy and invites, account upsert,
+/// refresh-token bookkeeping, token auto-bind and the user session.
+async fn complete_user_oauth_login(
+    state: &AppState,
+    profile: &OAuthAccountProfile,
+    refresh_token: Option<&str>,
+    preferred_token_id: Option<&str>,
+    invite_code: Option<&str>,
+    session_max_age_secs: i64,
+) -> LinuxDoFinalizeResult {
+    let provider = profile.provider.as_str();
+    let provider_user_id = profile.provider_user_id.as_str();
+    let existing_account = match state
+        .proxy
+        .oauth_account_exists(provider, provider_user_id)
+        .await
+    {
+        Ok(value) => value,
+        Err(err) => {
+            eprintln!("query {provider} oauth account existence error: {err}");
+            return LinuxDoFinalizeResult::ServerError {
+                detail: format!("failed to read existing {provider} account binding"),
+            };
+        }
+    };
+    // Only a first login registers: it either redeems its invite, which bypasses the pause and
+    // the approval queue, or follows the registration policy.
+    let mut invite = None;
+    let mut needs_approval = false;
+    if !existing_account {
+        if let Some(code) = invite_code {
+            match state.proxy.claim_registration_invite(code).await {
+                Ok(Some(claimed)) => invite = Some(claimed),
+                Ok(None) => return LinuxDoFinalizeResult::InvalidInvite,
+                Err(err) => {
+                    eprintln!("claim registration invite during {provider} finalize error: {err}");
+                    return LinuxDoFinalizeResult::ServerError {
+                        detail: "failed to redeem registration invite".to_string(),
+                    };
+                }
+            }
+        } else {
+            let policy = match state.proxy.allow_registration().await {
+                Ok(false) => return LinuxDoFinalizeResult::RegistrationPaused,
+                Ok(true) => state.proxy.registration_requires_approval().await,
+                Err(err) => Err(err),
+            };
+            needs_approval = match policy {
+                Ok(value) => value,
+                Err(err) => {
+                    eprintln!("read registration policy during {provider} finalize error: {err}");
+                    return LinuxDoFinalizeResult::ServerError {
+                        detail: "failed to read registration policy".to_string(),
+                    };
+                }
+            };
+        }
+    }
+
+    let user = match state.proxy.upsert_oauth_account(profile).await {
+        Ok(user) => user,
+        Err(err) => {
+            eprintln!("upsert {provider} oauth account error: {err}");
+            return LinuxDoFinalizeResult::ServerError {
+                detail: format!("failed to persist {provider} account"),
+            };
+        }
+    };
+    let registered = if let Some(invite) = invite.as_ref() {
+        state
+            .proxy
+            .apply_registration_invite(invite, &user.user_id)
+            .await
+    } else if needs_approval {
+        state.proxy.mark_user_pending_approval(&user.user_id).await
+    } else {
+        Ok(())
+    };
+    if let Err(err) = registered {
+        eprintln!("apply {provider} registration policy error: {err}");
+        return LinuxDoFinalizeResult::ServerError {
+            detail: "failed to apply registration policy".to_string(),
+        };
+    }
+    let sync_attempted_at = state.proxy.backend_time().now_ts();
+    if let Err(err) =
+        persist_oauth_refresh_token_best_effort(state, provider, provider_user_id, refresh_token)
+            .await
+    {
+        eprintln!("persist {provider} refresh token error: {err}");
+        if let Err(mark_err) = state
+            .proxy
+            .record_oauth_account_profile_sync_failure(
+                provider,
+                provider_user_id,
+                sync_attempted_at,
+                &err.to_string(),
+            )
+            .await
+        {
+            eprintln!("record {provider} finalize sync failure error: {mark_err}");
+        }
+    } else if let Err(err) = state
+        .proxy
+        .record_oauth_account_profile_sync_success(provider, provider_user_id, sync_attempted_at)
+        .await
+    {
+        eprintln!("record {provider} finalize sync success error: {err}");
+    }
+    if !profile.active {
+        return LinuxDoFinalizeResult::InactiveUser;
+    }
+
+    let pending_approval = match state.proxy.user_pending_approval(&user.user_id).await {
+        Ok(value) => value,
+        Err(err) => {
+            eprintln!("read {provider} user approval state error: {err}");
+            return LinuxDoFinalizeResult::ServerError {
+                detail: "failed to read registration approval state".to_string(),
+            };
+        }
+    };
+    let note = format!(
+        "{provider}:{}",
+        profile.username.as_deref().unwrap_or(provider_user_id)
+    );
+    // Pending users still get a session so the console can explain the wait, but no token until
+    // an admin approves them. A bound token that only keeps a hashed secret still satisfies the
+    // binding; the user rotates it from the console to see a new secret.
+    if !pending_approval
+        && let Err(err) = state
+            .proxy
+            .ensure_user_token_binding_with_preferred(
+                &user.user_id,
+                Some(&note),
+                preferred_token_id,
+            )
+            .await
+        && !matches!(err, ProxyError::TokenSecretNotRecoverable { .. })
+    {
+        eprintln!("ensure user token binding error: {err}");
+        return LinuxDoFinalizeResult::ServerError {
+            detail: "failed to ensure user token binding".to_string(),
+        };
+    }
+
+    let session = match state
+        .proxy
+        .create_user_session(&user, session_max_age_secs)
+        .await
+    {
+        Ok(session) => session,
+        Err(err) => {
+            eprintln!("create user session error: {err}");
+            return LinuxDoFinalizeResult::ServerError {
+                detail: "failed to create user session".to_string(),
+            };
+        }
+    };
+    LinuxDoFinalizeResult::Success {
+        session_token: session.token,
+    }
+}
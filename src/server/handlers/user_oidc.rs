@@ -0,0 +1,198 @@
+async fn start_oidc_auth(
+    state: Arc<AppState>,
+    headers: HeaderMap,
+    token: Option<String>,
+    invite_code: Option<String>,
+) -> Result<Response<Body>, StatusCode> {
+    let cfg = &state.oidc;
+    if !cfg.is_enabled_and_configured() {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    let provider = load_oidc_provider(&reqwest::Client::new(), cfg, false)
+        .await
+        .map_err(|err| {
+            eprintln!("load oidc provider metadata error: {err}");
+            StatusCode::BAD_GATEWAY
+        })?;
+    let bind_token_id = resolve_preferred_bind_token_id(state.as_ref(), token.as_deref()).await?;
+    let binding_nonce = new_cookie_nonce();
+    let binding_hash = hash_oauth_binding(&binding_nonce);
+    let pkce = OAuthLoginPkce {
+        code_verifier: new_oidc_random_token(),
+        nonce: new_oidc_random_token(),
+    };
+    let state_token = state
+        .proxy
+        .create_user_login_state(
+            OIDC_PROVIDER,
+            cfg.login_state_ttl_secs,
+            &binding_hash,
+            bind_token_id.as_deref(),
+            Some(&pkce),
+            invite_code.as_deref(),
+        )
+        .await
+        .map_err(|err| {
+            eprintln!("create oidc login state error: {err}");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    let url = oidc_authorize_url(cfg, &provider, &state_token, &pkce.code_verifier, &pkce.nonce)
+        .map_err(|err| {
+            eprintln!("build oidc authorize url error: {err}");
+            StatusCode::BAD_GATEWAY
+        })?;
+    oauth_login_redirect_response(&headers, &binding_nonce, cfg.login_state_ttl_secs, &url)
+}
+
+async fn finalize_oidc_login(
+    state: &AppState,
+    code: &str,
+    oauth_state: &str,
+    binding_hash: &str,
+) -> LinuxDoFinalizeResult {
+    let cfg = &state.oidc;
+    let state_payload =
+        match consume_user_oauth_login_state(state, OIDC_PROVIDER, oauth_state, binding_hash)
+            .await
+        {
+            Ok(payload) => payload,
+            Err(result) => return result,
+        };
+    let Some(pkce) = state_payload.pkce else {
+        return LinuxDoFinalizeResult::InvalidState {
+            detail: "oauth state is missing its PKCE verifier".to_string(),
+        };
+    };
+
+    let client = reqwest::Client::new();
+    let (profile, token_payload) = match fetch_oidc_profile_from_authorization_code(
+        &client,
+        cfg,
+        code,
+        &pkce.code_verifier,
+        &pkce.nonce,
+    )
+    .await
+    {
+        Ok(result) => result,
+        Err(err) => {
+            eprintln!("oidc finalize flow error: {err}");
+            return LinuxDoFinalizeResult::UpstreamFailure {
+                detail: err.to_string(),
+            };
+        }
+    };
+    complete_user_oauth_login(
+        state,
+        &profile,
+        token_payload.refresh_token.as_deref(),
+        state_payload.bind_token_id.as_deref(),
+        state_payload.invite_code.as_deref(),
+        cfg.session_max_age_secs,
+    )
+    .await
+}
+
+async fn get_oidc_auth(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Response<Body>, StatusCode> {
+    if require_full_master_write(state.as_ref()).await.is_err() {
+        return Err(StatusCode::SERVICE_UNAVAILABLE);
+    }
+    start_oidc_auth(state, headers, None, None).await
+}
+
+async fn post_oidc_auth(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Form(payload): Form<LinuxDoAuthForm>,
+) -> Result<Response<Body>, StatusCode> {
+    if require_full_master_write(state.as_ref()).await.is_err() {
+        return Err(StatusCode::SERVICE_UNAVAILABLE);
+    }
+    start_oidc_auth(state, headers, payload.token, payload.invite_code).await
+}
+
+async fn post_oidc_finalize(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(payload): Json<LinuxDoFinalizeRequest>,
+) -> Result<Response<Body>, StatusCode> {
+    let cfg = &state.oidc;
+    if !cfg.is_enabled_and_configured() {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    let use_secure_cookie = wants_secure_cookie(&headers);
+    let binding_hash = match oauth_finalize_binding_hash(&headers, &payload) {
+        Ok(hash) => hash,
+        Err(detail) => {
+            return linuxdo_finalize_json_response(
+                LinuxDoFinalizeResponse::invalid_state(OIDC_PROVIDER, detail),
+                use_secure_cookie,
+                None,
+            );
+        }
+    };
+    let result = finalize_oidc_login(
+        state.as_ref(),
+        payload.code.trim(),
+        payload.state.trim(),
+        &binding_hash,
+    )
+    .await;
+    user_oauth_finalize_response(
+        OIDC_PROVIDER,
+        result,
+        cfg.session_max_age_secs,
+        use_secure_cookie,
+    )
+}
+
+/// Accounts the daily user sync refreshes: every configured provider's accounts that kept a
+/// refresh token.
+async fn list_user_sync_oauth_accounts(
+    state: &AppState,
+) -> Result<Vec<tavily_hikari::OAuthAccountRefreshTokenRecord>, ProxyError> {
+    let mut records = Vec::new();
+    if state.linuxdo_oauth.is_enabled_and_configured() {
+        records.extend(
+            state
+                .proxy
+                .list_oauth_accounts_with_refresh_token("linuxdo")
+                .await?,
+        );
+    }
+    if state.oidc.is_enabled_and_configured() {
+        records.extend(
+            state
+                .proxy
+                .list_oauth_accounts_with_refresh_token(OIDC_PROVIDER)
+                .await?,
+        );
+    }
+    Ok(records)
+}
+
+/// Refreshes one account's profile from its provider, returning any rotated refresh token.
+async fn refresh_user_oauth_profile(
+    client: &reqwest::Client,
+    state: &AppState,
+    provider: &str,
+    refresh_token: &str,
+) -> Result<(OAuthAccountProfile, Option<String>), String> {
+    if provider == OIDC_PROVIDER {
+        fetch_oidc_profile_from_refresh_token(client, &state.oidc, refresh_token)
+            .await
+            .map(|(profile, token)| (profile, token.refresh_token))
+            .map_err(|err| err.to_string())
+    } else {
+        fetch_linuxdo_profile_from_refresh_token(client, &state.linuxdo_oauth, refresh_token)
+            .await
+            .map(|(profile, token)| (profile, token.refresh_token))
+            .map_err(|err| err.to_string())
+    }
+}
@@ -0,0 +1,155 @@
+/// Signed-in user's team membership, or `None` when they are not in a team.
+async fn resolve_user_team(
+    state: &AppState,
+    headers: &HeaderMap,
+) -> Result<(String, Option<tavily_hikari::TeamMembership>), (StatusCode, String)> {
+    if !state.user_login_enabled() {
+        return Err((StatusCode::NOT_FOUND, "not found".to_string()));
+    }
+    let Some(user_session) = resolve_user_session(state, headers).await else {
+        return Err((StatusCode::UNAUTHORIZED, "unauthorized".to_string()));
+    };
+    let user_id = user_session.user.user_id;
+    let membership = state
+        .proxy
+        .user_team_membership(&user_id)
+        .await
+        .map_err(|err| {
+            eprintln!("load user team error: {err}");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "failed to load team".to_string(),
+            )
+        })?;
+    Ok((user_id, membership))
+}
+
+/// Membership of a team owner or admin; plain members may not manage team tokens.
+async fn resolve_user_team_manager(
+    state: &AppState,
+    headers: &HeaderMap,
+) -> Result<(String, tavily_hikari::TeamMembership), (StatusCode, String)> {
+    let (user_id, membership) = resolve_user_team(state, headers).await?;
+    let Some(membership) = membership else {
+        return Err((StatusCode::NOT_FOUND, "not in a team".to_string()));
+    };
+    if !membership.role.manages_tokens() {
+        return Err((StatusCode::FORBIDDEN, "forbidden".to_string()));
+    }
+    Ok((user_id, membership))
+}
+
+fn user_team_error(context: &str, err: ProxyError) -> (StatusCode, String) {
+    eprintln!("{context}: {err}");
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "failed to load team".to_string(),
+    )
+}
+
+async fn get_user_team(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<UserTeamView>, (StatusCode, String)> {
+    let (_, membership) = resolve_user_team(state.as_ref(), &headers).await?;
+    let Some(membership) = membership else {
+        return Ok(Json(UserTeamView {
+            team: None,
+            role: None,
+            quota: None,
+            members: Vec::new(),
+        }));
+    };
+    let quota = state
+        .proxy
+        .team_quota_snapshot(&membership.team)
+        .await
+        .map_err(|err| user_team_error("load user team quota error", err))?;
+    let members = state
+        .proxy
+        .list_team_members(&membership.team.id)
+        .await
+        .map_err(|err| user_team_error("list user team members error", err))?;
+    Ok(Json(UserTeamView {
+        team: Some(TeamView::from(membership.team)),
+        role: Some(membership.role),
+        quota: Some(TeamQuotaView::from(quota)),
+        members: members.into_iter().map(TeamMemberView::from).collect(),
+    }))
+}
+
+async fn get_user_team_usage_series(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Query(q): Query<AdminUserUsageSeriesQuery>,
+) -> Result<Json<AdminUserUsageSeriesView>, (StatusCode, String)> {
+    let (_, membership) = resolve_user_team(state.as_ref(), &headers).await?;
+    let Some(membership) = membership else {
+        return Err((StatusCode::NOT_FOUND, "not in a team".to_string()));
+    };
+    let series = parse_team_usage_series(q.series.as_deref())?;
+    state
+        .proxy
+        .team_usage_series(&membership.team, series)
+        .await
+        .map(|usage| Json(team_usage_series_view(usage)))
+        .map_err(|err| user_team_error("load user team usage series error", err))
+}
+
+async fn get_user_team_tokens(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<TeamTokenView>>, (StatusCode, String)> {
+    let (_, membership) = resolve_user_team_manager(state.as_ref(), &headers).await?;
+    state
+        .proxy
+        .list_team_tokens(&membership.team.id)
+        .await
+        .map(|tokens| Json(tokens.into_iter().map(TeamTokenView::from).collect()))
+        .map_err(|err| user_team_error("list user team tokens error", err))
+}
+
+async fn post_user_team_token(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(payload): Json<TeamTokenRequest>,
+) -> Result<(StatusCode, Json<CreatedTeamTokenView>), (StatusCode, String)> {
+    let (user_id, membership) = resolve_user_team_manager(state.as_ref(), &headers).await?;
+    let note = normalize_optional_text(payload.note)
+        .unwrap_or_else(|| format!("team:{}", membership.team.name));
+    state
+        .proxy
+        .create_team_token(
+            &membership.team.id,
+            Some(&note),
+            Some(&format!("user:{user_id}")),
+        )
+        .await
+        .map(|secret| {
+            (
+                StatusCode::CREATED,
+                Json(CreatedTeamTokenView {
+                    token: secret.token,
+                    token_id: secret.id,
+                }),
+            )
+        })
+        .map_err(|err| user_team_error("create user team token error", err))
+}
+
+async fn delete_user_team_token(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(token_id): Path<String>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    let (_, membership) = resolve_user_team_manager(state.as_ref(), &headers).await?;
+    match state
+        .proxy
+        .delete_team_token(&membership.team.id, &token_id)
+        .await
+    {
+        Ok(true) => Ok(StatusCode::NO_CONTENT),
+        Ok(false) => Err((StatusCode::NOT_FOUND, "team token not found".to_string())),
+        Err(err) => Err(user_team_error("delete user team token error", err)),
+    }
+}
@@ -53,31 +53,36 @@ struct SummarySig {
 }
 use std::time::Duration;
 use tavily_hikari::{
-    AdminTokenEnabledFilter, AdminTokenListFilters, AdminTokenOwnerFilter, AdminUserIdentity,
-    AdminUserSortedPageRequest, AdminUserUsageSeriesKind, AlertCatalog, AnalysisPressureSnapshot,
-    ApiKeyMetrics, ApiKeyStickyNode, ApiKeyStickyUser, ApiKeyUserUsageBucket, AuthToken,
+    AccessTokenLifetime, AccessTokenScopes, AccessTokenValidation, AdminTokenEnabledFilter,
+    AdminTokenListFilters, AdminTokenOwnerFilter, AdminUserIdentity, AdminUserSortedPageRequest,
+    AdminUserUsageSeriesKind, AlertCatalog, AnalysisPressureSnapshot, ApiKeyMetrics,
+    ApiKeyStickyNode, ApiKeyStickyUser, ApiKeyUserUsageBucket, AuthToken,
     BusinessCalls1hLimitVerdict, ClientIpInfo, DB_COMPACTION_COOLDOWN_SECS,
     DB_COMPACTION_MIN_RECLAIMABLE_BYTES, DB_COMPACTION_MIN_RECLAIMABLE_RATIO,
     ForwardProxyHourlyBucketResponse, ForwardProxyStatsResponse,
     ForwardProxyWeightHourlyBucketResponse, JobLog, LogFacetOption, OAuthAccountProfile,
-    PaginatedAlertEvents, PaginatedAlertGroups, PendingBillingSettleOutcome, ProxyError,
-    ProxyRequest, ProxyResponse, ProxySummary, QUOTA_SYNC_JOB_TIMEOUT_SECS, RequestLogBodiesRecord,
-    RequestLogRecord, RequestLogsCatalog, RequestLogsCursor, RequestLogsCursorDirection,
-    RequestLogsCursorPage, RequestLogsGcOptions, StickyCreditsWindow, TavilyProxy,
-    TokenHourlyBucket, TokenHourlyRequestVerdict, TokenLogBillingFilter, TokenLogRecord,
-    TokenLogsCursorPage, TokenQuotaVerdict, TokenRequestKindOption, TokenSummary, TokenUsageBucket,
-    TrustedClientIpSettings, UNBOUND_TOKEN_MONTHLY_BROKEN_LIMIT_DEFAULT,
-    USER_MONTHLY_BROKEN_LIMIT_DEFAULT, UserTokenLookup, analyze_mcp_attempt,
-    canonical_request_kind_key_for_filter, classify_token_request_kind,
-    display_result_status_for_request_kind, effective_request_logs_gc_at,
-    effective_token_daily_limit, effective_token_hourly_limit, effective_token_monthly_limit,
-    extract_mcp_has_error_by_id_from_bytes, extract_mcp_usage_credits_by_id_from_bytes,
-    extract_research_request_id, extract_usage_credits_from_json_bytes,
-    extract_usage_credits_total_from_json_bytes, format_request_logs_gc_report_message,
-    mcp_response_has_any_error, mcp_response_has_any_success, normalize_operational_class_filter,
-    operational_class_for_token_log, request_rate_limit, request_rate_limit_window_minutes,
-    research_response_is_terminal, resolve_client_ip_info, run_db_compaction_once,
-    token_request_kind_billing_group_for_token_log, token_request_kind_protocol_group,
+    OAuthLoginPkce, OAuthLoginStatePayload, PaginatedAlertEvents, PaginatedAlertGroups,
+    PendingBillingSettleOutcome, ProxyError, ProxyRequest, ProxyResponse, ProxySummary,
+    QUOTA_SYNC_JOB_TIMEOUT_SECS, RequestFlight, RequestLogBodiesRecord, RequestLogRecord,
+    RequestLogsCatalog, RequestLogsCursor, RequestLogsCursorDirection, RequestLogsCursorPage,
+    RequestLogsGcOptions, RequestParameterPolicy, RequestParameterPolicySubject,
+    RequestParameterPolicyViolation, RequestTrace, SharedUpstreamResponse, StickyCreditsWindow,
+    TavilyProxy, TokenHourlyBucket, TokenHourlyRequestVerdict, TokenLogBillingFilter,
+    TokenLogRecord, TokenLogsCursorPage, TokenQuotaVerdict, TokenRequestKind,
+    TokenRequestKindOption, TokenSummary, TokenUsageBucket, TrustedClientIpSettings,
+    UNBOUND_TOKEN_MONTHLY_BROKEN_LIMIT_DEFAULT, USER_MONTHLY_BROKEN_LIMIT_DEFAULT, UserTokenLookup,
+    analyze_mcp_attempt, canonical_request_kind_key_for_filter, classify_mcp_message_request_kinds,
+    classify_token_request_kind, display_result_status_for_request_kind,
+    effective_request_logs_gc_at, effective_token_daily_limit, effective_token_hourly_limit,
+    effective_token_monthly_limit, extract_mcp_has_error_by_id_from_bytes,
+    extract_mcp_usage_credits_by_id_from_bytes, extract_research_request_id,
+    extract_usage_credits_from_json_bytes, extract_usage_credits_total_from_json_bytes,
+    format_request_logs_gc_report_message, mcp_response_has_any_error,
+    mcp_response_has_any_success, normalize_operational_class_filter,
+    operational_class_for_token_log, request_coalescing_key, request_rate_limit,
+    request_rate_limit_window_minutes, research_response_is_terminal, resolve_client_ip_info,
+    response_cache_key, run_db_compaction_once, token_request_kind_billing_group_for_token_log,
+    token_request_kind_protocol_group,
 };
 use tokio::signal;
 #[cfg(unix)]
@@ -85,15 +90,31 @@ use tokio::signal::unix::{SignalKind, signal as unix_signal};
 use tokio::sync::{Mutex, OwnedMutexGuard, RwLock, Semaphore};
 use tokio_util::io::{ReaderStream, StreamReader};
 include!("state.rs");
+include!("oidc.rs");
+include!("admin_audit.rs");
+include!("admin_rbac.rs");
 include!("schedulers.rs");
 include!("spa.rs");
 include!("handlers/tavily.rs");
+include!("handlers/tavily_research_stream.rs");
 include!("handlers/public.rs");
 include!("handlers/admin_auth.rs");
+include!("handlers/admin_accounts.rs");
+include!("handlers/admin_api_tokens.rs");
 include!("handlers/user.rs");
+include!("handlers/user_oauth_login.rs");
+include!("handlers/user_oidc.rs");
+include!("handlers/user_teams.rs");
 include!("handlers/admin_resources.rs");
 include!("serve.rs");
 include!("ha_peer_lookup.rs");
 include!("dto.rs");
+include!("dto_alert_workflow.rs");
+include!("dto_admin_audit.rs");
+include!("dto_request_log_replay.rs");
+include!("dto_registration_invites.rs");
+include!("dto_admin_accounts.rs");
+include!("dto_admin_api_tokens.rs");
+include!("dto_teams.rs");
 include!("proxy.rs");
 include!("tests.rs");
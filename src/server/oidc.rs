@@ -0,0 +1,586 @@
+/// Provider id stored in `oauth_accounts.provider` for accounts from the configured OIDC issuer.
+const OIDC_PROVIDER: &str = "oidc";
+/// Discovery documents and signing keys are refetched after this long.
+const OIDC_PROVIDER_METADATA_TTL: Duration = Duration::from_secs(60 * 60);
+/// Clock skew tolerated when checking ID token `exp` / `iat`.
+const OIDC_ID_TOKEN_LEEWAY_SECS: i64 = 60;
+
+#[derive(Clone, Debug)]
+pub struct OidcOptions {
+    pub enabled: bool,
+    pub issuer: Option<String>,
+    pub client_id: Option<String>,
+    pub client_secret: Option<String>,
+    pub redirect_url: Option<String>,
+    pub scope: String,
+    pub display_name: String,
+    pub username_claim: String,
+    pub name_claim: String,
+    pub avatar_claim: String,
+    pub session_max_age_secs: i64,
+    pub login_state_ttl_secs: i64,
+}
+
+impl OidcOptions {
+    #[cfg(test)]
+    fn disabled() -> Self {
+        Self {
+            enabled: false,
+            issuer: None,
+            client_id: None,
+            client_secret: None,
+            redirect_url: None,
+            scope: "openid profile email".to_string(),
+            display_name: "SSO".to_string(),
+            username_claim: "preferred_username".to_string(),
+            name_claim: "name".to_string(),
+            avatar_claim: "picture".to_string(),
+            session_max_age_secs: 60 * 60 * 24 * 14,
+            login_state_ttl_secs: 600,
+        }
+    }
+
+    fn is_enabled_and_configured(&self) -> bool {
+        let present = |value: Option<&str>| value.map(str::trim).is_some_and(|v| !v.is_empty());
+        self.enabled
+            && present(self.issuer.as_deref())
+            && present(self.client_id.as_deref())
+            && present(self.redirect_url.as_deref())
+    }
+
+    fn issuer(&self) -> &str {
+        self.issuer
+            .as_deref()
+            .unwrap_or_default()
+            .trim()
+            .trim_end_matches('/')
+    }
+
+    fn client_id(&self) -> &str {
+        self.client_id.as_deref().unwrap_or_default()
+    }
+}
+
+#[derive(Debug)]
+enum OidcError {
+    Transport {
+        stage: &'static str,
+        source: reqwest::Error,
+    },
+    UpstreamStatus {
+        stage: &'static str,
+        status: reqwest::StatusCode,
+        body: String,
+    },
+    Parse {
+        stage: &'static str,
+        detail: String,
+    },
+    InvalidMetadata(String),
+    UnknownSigningKey(String),
+    InvalidIdToken(String),
+    InvalidPayload(&'static str),
+}
+
+impl std::fmt::Display for OidcError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Transport { stage, source } => write!(f, "oidc {stage} transport error: {source}"),
+            Self::UpstreamStatus {
+                stage,
+                status,
+                body,
+            } => write!(f, "oidc {stage} upstream status {status}: {body}"),
+            Self::Parse { stage, detail } => write!(f, "oidc {stage} parse error: {detail}"),
+            Self::InvalidMetadata(detail) => write!(f, "invalid oidc provider metadata: {detail}"),
+            Self::UnknownSigningKey(kid) => write!(f, "oidc signing key {kid:?} not found"),
+            Self::InvalidIdToken(detail) => write!(f, "invalid oidc id_token: {detail}"),
+            Self::InvalidPayload(detail) => write!(f, "invalid oidc payload: {detail}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct OidcProviderMetadata {
+    issuer: String,
+    authorization_endpoint: String,
+    token_endpoint: String,
+    #[serde(default)]
+    userinfo_endpoint: Option<String>,
+    jwks_uri: String,
+    #[serde(default)]
+    token_endpoint_auth_methods_supported: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct OidcJwk {
+    kty: String,
+    #[serde(default)]
+    kid: Option<String>,
+    #[serde(default, rename = "use")]
+    key_use: Option<String>,
+    #[serde(default)]
+    n: Option<String>,
+    #[serde(default)]
+    e: Option<String>,
+    #[serde(default)]
+    crv: Option<String>,
+    #[serde(default)]
+    x: Option<String>,
+    #[serde(default)]
+    y: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct OidcJwks {
+    keys: Vec<OidcJwk>,
+}
+
+#[derive(Debug, Clone)]
+struct OidcProvider {
+    metadata: OidcProviderMetadata,
+    jwks: OidcJwks,
+    fetched_at: std::time::Instant,
+}
+
+#[derive(Debug, Deserialize)]
+struct OidcTokenResponse {
+    access_token: String,
+    #[serde(default)]
+    id_token: Option<String>,
+    #[serde(default)]
+    refresh_token: Option<String>,
+}
+
+type OidcClaims = serde_json::Map<String, Value>;
+
+fn oidc_provider_cache() -> &'static std::sync::Mutex<HashMap<String, OidcProvider>> {
+    static CACHE: OnceLock<std::sync::Mutex<HashMap<String, OidcProvider>>> = OnceLock::new();
+    CACHE.get_or_init(|| std::sync::Mutex::new(HashMap::new()))
+}
+
+async fn fetch_oidc_json<T: serde::de::DeserializeOwned>(
+    request: reqwest::RequestBuilder,
+    stage: &'static str,
+) -> Result<T, OidcError> {
+    let response = request
+        .header(reqwest::header::ACCEPT, "application/json")
+        .send()
+        .await
+        .map_err(|source| OidcError::Transport { stage, source })?;
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        return Err(OidcError::UpstreamStatus {
+            stage,
+            status,
+            body,
+        });
+    }
+    response.json().await.map_err(|err| OidcError::Parse {
+        stage,
+        detail: err.to_string(),
+    })
+}
+
+/// Loads the issuer's discovery document and JWKS, served from a per-issuer cache unless
+/// `force_refresh` is set (used when an ID token names a key we have not seen yet).
+async fn load_oidc_provider(
+    client: &reqwest::Client,
+    cfg: &OidcOptions,
+    force_refresh: bool,
+) -> Result<OidcProvider, OidcError> {
+    let issuer = cfg.issuer();
+    if !force_refresh
+        && let Some(cached) = oidc_provider_cache()
+            .lock()
+            .expect("oidc provider cache poisoned")
+            .get(issuer)
+            .filter(|cached| cached.fetched_at.elapsed() < OIDC_PROVIDER_METADATA_TTL)
+            .cloned()
+    {
+        return Ok(cached);
+    }
+
+    let metadata: OidcProviderMetadata = fetch_oidc_json(
+        client.get(format!("{issuer}/.well-known/openid-configuration")),
+        "discovery",
+    )
+    .await?;
+    if metadata.issuer.trim_end_matches('/') != issuer {
+        return Err(OidcError::InvalidMetadata(format!(
+            "issuer mismatch: expected {issuer}, got {}",
+            metadata.issuer
+        )));
+    }
+    let jwks: OidcJwks = fetch_oidc_json(client.get(&metadata.jwks_uri), "jwks").await?;
+    let provider = OidcProvider {
+        metadata,
+        jwks,
+        fetched_at: std::time::Instant::now(),
+    };
+    oidc_provider_cache()
+        .lock()
+        .expect("oidc provider cache poisoned")
+        .insert(issuer.to_string(), provider.clone());
+    Ok(provider)
+}
+
+/// Random URL-safe value used for PKCE verifiers and ID token nonces.
+fn new_oidc_random_token() -> String {
+    let mut bytes = [0u8; 32];
+    OsRng.fill(&mut bytes);
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(bytes)
+}
+
+fn oidc_pkce_challenge(code_verifier: &str) -> String {
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()))
+}
+
+fn oidc_authorize_url(
+    cfg: &OidcOptions,
+    provider: &OidcProvider,
+    state_token: &str,
+    code_verifier: &str,
+    nonce: &str,
+) -> Result<reqwest::Url, OidcError> {
+    let mut url = reqwest::Url::parse(&provider.metadata.authorization_endpoint)
+        .map_err(|err| OidcError::InvalidMetadata(format!("authorization_endpoint: {err}")))?;
+    url.query_pairs_mut()
+        .append_pair("client_id", cfg.client_id())
+        .append_pair(
+            "redirect_uri",
+            cfg.redirect_url.as_deref().unwrap_or_default(),
+        )
+        .append_pair("response_type", "code")
+        .append_pair("scope", &cfg.scope)
+        .append_pair("state", state_token)
+        .append_pair("nonce", nonce)
+        .append_pair("code_challenge", &oidc_pkce_challenge(code_verifier))
+        .append_pair("code_challenge_method", "S256");
+    Ok(url)
+}
+
+async fn request_oidc_token(
+    client: &reqwest::Client,
+    cfg: &OidcOptions,
+    provider: &OidcProvider,
+    mut form: Vec<(&str, &str)>,
+) -> Result<OidcTokenResponse, OidcError> {
+    let mut request = client.post(&provider.metadata.token_endpoint);
+    let methods = &provider.metadata.token_endpoint_auth_methods_supported;
+    match cfg.client_secret.as_deref().filter(|secret| !secret.is_empty()) {
+        // `client_secret_basic` is the spec default when the issuer does not advertise methods.
+        Some(secret) if methods.is_empty() || methods.iter().any(|m| m == "client_secret_basic") => {
+            request = request.basic_auth(cfg.client_id(), Some(secret));
+        }
+        Some(secret) => {
+            form.push(("client_id", cfg.client_id()));
+            form.push(("client_secret", secret));
+        }
+        None => form.push(("client_id", cfg.client_id())),
+    }
+    let token: OidcTokenResponse = fetch_oidc_json(request.form(&form), "token").await?;
+    if token.access_token.trim().is_empty() {
+        return Err(OidcError::InvalidPayload(
+            "token response missing access_token",
+        ));
+    }
+    Ok(OidcTokenResponse {
+        access_token: token.access_token.trim().to_string(),
+        id_token: trim_to_option(token.id_token.as_deref()),
+        refresh_token: trim_to_option(token.refresh_token.as_deref()),
+    })
+}
+
+fn decode_oidc_segment(segment: &str, what: &str) -> Result<Vec<u8>, OidcError> {
+    base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .decode(segment.trim_end_matches('='))
+        .map_err(|err| OidcError::InvalidIdToken(format!("{what} is not base64url: {err}")))
+}
+
+fn decode_oidc_key_part(value: Option<&str>, what: &str) -> Result<Vec<u8>, OidcError> {
+    let value =
+        value.ok_or_else(|| OidcError::InvalidMetadata(format!("jwk is missing `{what}`")))?;
+    base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .decode(value.trim_end_matches('='))
+        .map_err(|err| OidcError::InvalidMetadata(format!("jwk `{what}` is not base64url: {err}")))
+}
+
+fn verify_oidc_signature(
+    jwk: &OidcJwk,
+    alg: &str,
+    message: &[u8],
+    signature: &[u8],
+) -> Result<(), OidcError> {
+    use ring::signature;
+
+    match alg {
+        "RS256" | "RS384" | "RS512" | "PS256" | "PS384" | "PS512" => {
+            let params: &signature::RsaParameters = match alg {
+                "RS256" => &signature::RSA_PKCS1_2048_8192_SHA256,
+                "RS384" => &signature::RSA_PKCS1_2048_8192_SHA384,
+                "RS512" => &signature::RSA_PKCS1_2048_8192_SHA512,
+                "PS256" => &signature::RSA_PSS_2048_8192_SHA256,
+                "PS384" => &signature::RSA_PSS_2048_8192_SHA384,
+                _ => &signature::RSA_PSS_2048_8192_SHA512,
+            };
+            let n = decode_oidc_key_part(jwk.n.as_deref(), "n")?;
+            let e = decode_oidc_key_part(jwk.e.as_deref(), "e")?;
+            let leading_zeros = n.iter().take_while(|byte| **byte == 0).count();
+            signature::RsaPublicKeyComponents {
+                n: &n[leading_zeros..],
+                e: &e[..],
+            }
+            .verify(params, message, signature)
+            .map_err(|_| OidcError::InvalidIdToken("signature verification failed".to_string()))
+        }
+        "ES256" | "ES384" => {
+            let (params, curve): (&signature::EcdsaVerificationAlgorithm, _) = if alg == "ES256" {
+                (&signature::ECDSA_P256_SHA256_FIXED, "P-256")
+            } else {
+                (&signature::ECDSA_P384_SHA384_FIXED, "P-384")
+            };
+            if jwk.crv.as_deref() != Some(curve) {
+                return Err(OidcError::InvalidIdToken(format!(
+                    "{alg} requires a {curve} key"
+                )));
+            }
+            let mut point = vec![0x04];
+            point.extend(decode_oidc_key_part(jwk.x.as_deref(), "x")?);
+            point.extend(decode_oidc_key_part(jwk.y.as_deref(), "y")?);
+            signature::UnparsedPublicKey::new(params, point)
+                .verify(message, signature)
+                .map_err(|_| OidcError::InvalidIdToken("signature verification failed".to_string()))
+        }
+        other => Err(OidcError::InvalidIdToken(format!(
+            "unsupported signing algorithm {other}"
+        ))),
+    }
+}
+
+fn oidc_audience_contains(claims: &OidcClaims, client_id: &str) -> bool {
+    match claims.get("aud") {
+        Some(Value::String(aud)) => aud == client_id,
+        Some(Value::Array(values)) => values.iter().any(|v| v.as_str() == Some(client_id)),
+        _ => false,
+    }
+}
+
+/// Verifies an ID token's signature against the issuer's JWKS and checks `iss`, `aud`/`azp`,
+/// `exp`/`iat` and (at login) the `nonce` bound to the login state.
+fn verify_oidc_id_token(
+    cfg: &OidcOptions,
+    jwks: &OidcJwks,
+    id_token: &str,
+    expected_nonce: Option<&str>,
+    now: i64,
+) -> Result<OidcClaims, OidcError> {
+    let mut parts = id_token.split('.');
+    let (Some(header_b64), Some(payload_b64), Some(signature_b64), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(OidcError::InvalidIdToken("expected a three-part JWS".to_string()));
+    };
+    let header: Value = serde_json::from_slice(&decode_oidc_segment(header_b64, "header")?)
+        .map_err(|err| OidcError::InvalidIdToken(format!("header is not JSON: {err}")))?;
+    let alg = header.get("alg").and_then(Value::as_str).unwrap_or_default();
+    let kid = header.get("kid").and_then(Value::as_str);
+    let kty = if alg.starts_with("ES") { "EC" } else { "RSA" };
+    let candidates = jwks
+        .keys
+        .iter()
+        .filter(|jwk| jwk.kty == kty && jwk.key_use.as_deref().is_none_or(|u| u == "sig"))
+        .filter(|jwk| kid.is_none() || jwk.kid.as_deref() == kid)
+        .collect::<Vec<_>>();
+    if candidates.is_empty() {
+        return Err(OidcError::UnknownSigningKey(kid.unwrap_or_default().to_string()));
+    }
+    let message = format!("{header_b64}.{payload_b64}");
+    let signature = decode_oidc_segment(signature_b64, "signature")?;
+    let mut verified = Err(OidcError::InvalidIdToken("no signing key".to_string()));
+    for jwk in candidates {
+        verified = verify_oidc_signature(jwk, alg, message.as_bytes(), &signature);
+        if verified.is_ok() {
+            break;
+        }
+    }
+    verified?;
+
+    let claims: OidcClaims = serde_json::from_slice(&decode_oidc_segment(payload_b64, "payload")?)
+        .map_err(|err| OidcError::InvalidIdToken(format!("payload is not a JSON object: {err}")))?;
+    let issuer = claims.get("iss").and_then(Value::as_str).unwrap_or_default();
+    if issuer.trim_end_matches('/') != cfg.issuer() {
+        return Err(OidcError::InvalidIdToken(format!("unexpected iss {issuer}")));
+    }
+    if !oidc_audience_contains(&claims, cfg.client_id()) {
+        return Err(OidcError::InvalidIdToken("aud does not include client_id".to_string()));
+    }
+    if let Some(azp) = claims.get("azp").and_then(Value::as_str)
+        && azp != cfg.client_id()
+    {
+        return Err(OidcError::InvalidIdToken(format!("unexpected azp {azp}")));
+    }
+    let exp = claims.get("exp").and_then(Value::as_i64).unwrap_or_default();
+    if exp + OIDC_ID_TOKEN_LEEWAY_SECS < now {
+        return Err(OidcError::InvalidIdToken("token has expired".to_string()));
+    }
+    if claims
+        .get("iat")
+        .and_then(Value::as_i64)
+        .is_some_and(|iat| iat > now + OIDC_ID_TOKEN_LEEWAY_SECS)
+    {
+        return Err(OidcError::InvalidIdToken("token issued in the future".to_string()));
+    }
+    if let Some(expected) = expected_nonce
+        && claims.get("nonce").and_then(Value::as_str) != Some(expected)
+    {
+        return Err(OidcError::InvalidIdToken("nonce mismatch".to_string()));
+    }
+    if claims
+        .get("sub")
+        .and_then(Value::as_str)
+        .is_none_or(|sub| sub.trim().is_empty())
+    {
+        return Err(OidcError::InvalidIdToken("missing sub".to_string()));
+    }
+    Ok(claims)
+}
+
+fn oidc_claim_string(claims: &OidcClaims, name: &str) -> Option<String> {
+    claims
+        .get(name)
+        .and_then(json_value_to_string)
+        .and_then(|value| trim_to_option(Some(&value)))
+}
+
+/// Maps verified ID token claims (overlaid with userinfo, when fetched) onto a local profile
+/// using the configured username / name / avatar claims.
+fn oidc_profile_from_claims(
+    cfg: &OidcOptions,
+    mut claims: OidcClaims,
+    userinfo: Option<OidcClaims>,
+) -> Result<OAuthAccountProfile, OidcError> {
+    if let Some(userinfo) = userinfo {
+        let subject = claims.get("sub").cloned();
+        if subject.is_some() && userinfo.get("sub") != subject.as_ref() {
+            return Err(OidcError::InvalidPayload(
+                "userinfo sub does not match id_token sub",
+            ));
+        }
+        claims.extend(userinfo);
+    }
+    let provider_user_id = oidc_claim_string(&claims, "sub")
+        .ok_or(OidcError::InvalidPayload("claims missing sub"))?;
+    let username = oidc_claim_string(&claims, &cfg.username_claim)
+        .or_else(|| oidc_claim_string(&claims, "email"));
+    let name = oidc_claim_string(&claims, &cfg.name_claim);
+    let avatar_template = oidc_claim_string(&claims, &cfg.avatar_claim)
+        .filter(|url| url.starts_with("https://") || url.starts_with("http://"));
+    Ok(OAuthAccountProfile {
+        provider: OIDC_PROVIDER.to_string(),
+        provider_user_id,
+        username,
+        name,
+        avatar_template,
+        active: true,
+        trust_level: None,
+        raw_payload_json: serde_json::to_string(&claims).ok(),
+    })
+}
+
+async fn verify_oidc_id_token_with_key_refresh(
+    client: &reqwest::Client,
+    cfg: &OidcOptions,
+    provider: &OidcProvider,
+    id_token: &str,
+    expected_nonce: Option<&str>,
+) -> Result<OidcClaims, OidcError> {
+    let now = Utc::now().timestamp();
+    match verify_oidc_id_token(cfg, &provider.jwks, id_token, expected_nonce, now) {
+        Err(OidcError::UnknownSigningKey(_)) => {
+            // The issuer may have rotated keys since the JWKS was cached.
+            let refreshed = load_oidc_provider(client, cfg, true).await?;
+            verify_oidc_id_token(cfg, &refreshed.jwks, id_token, expected_nonce, now)
+        }
+        result => result,
+    }
+}
+
+async fn fetch_oidc_userinfo(
+    client: &reqwest::Client,
+    provider: &OidcProvider,
+    access_token: &str,
+) -> Result<Option<OidcClaims>, OidcError> {
+    let Some(endpoint) = provider.metadata.userinfo_endpoint.as_deref() else {
+        return Ok(None);
+    };
+    fetch_oidc_json(client.get(endpoint).bearer_auth(access_token), "userinfo")
+        .await
+        .map(Some)
+}
+
+async fn fetch_oidc_profile_from_authorization_code(
+    client: &reqwest::Client,
+    cfg: &OidcOptions,
+    code: &str,
+    code_verifier: &str,
+    nonce: &str,
+) -> Result<(OAuthAccountProfile, OidcTokenResponse), OidcError> {
+    let provider = load_oidc_provider(client, cfg, false).await?;
+    let token = request_oidc_token(
+        client,
+        cfg,
+        &provider,
+        vec![
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            (
+                "redirect_uri",
+                cfg.redirect_url.as_deref().unwrap_or_default(),
+            ),
+            ("code_verifier", code_verifier),
+        ],
+    )
+    .await?;
+    let id_token = token
+        .id_token
+        .as_deref()
+        .ok_or(OidcError::InvalidPayload("token response missing id_token"))?;
+    let claims =
+        verify_oidc_id_token_with_key_refresh(client, cfg, &provider, id_token, Some(nonce))
+            .await?;
+    let userinfo = fetch_oidc_userinfo(client, &provider, &token.access_token).await?;
+    Ok((oidc_profile_from_claims(cfg, claims, userinfo)?, token))
+}
+
+async fn fetch_oidc_profile_from_refresh_token(
+    client: &reqwest::Client,
+    cfg: &OidcOptions,
+    refresh_token: &str,
+) -> Result<(OAuthAccountProfile, OidcTokenResponse), OidcError> {
+    let provider = load_oidc_provider(client, cfg, false).await?;
+    let token = request_oidc_token(
+        client,
+        cfg,
+        &provider,
+        vec![
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+        ],
+    )
+    .await?;
+    let claims = match token.id_token.as_deref() {
+        Some(id_token) => {
+            verify_oidc_id_token_with_key_refresh(client, cfg, &provider, id_token, None).await?
+        }
+        None => OidcClaims::new(),
+    };
+    let userinfo = fetch_oidc_userinfo(client, &provider, &token.access_token).await?;
+    if claims.is_empty() && userinfo.is_none() {
+        return Err(OidcError::InvalidPayload(
+            "refresh returned neither id_token nor userinfo",
+        ));
+    }
+    Ok((oidc_profile_from_claims(cfg, claims, userinfo)?, token))
+}
@@ -344,14 +344,6 @@ fn missing_token_response() -> Result<Response<Body>, StatusCode> {
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
 }
 
-fn invalid_token_response() -> Result<Response<Body>, StatusCode> {
-    Response::builder()
-        .status(StatusCode::UNAUTHORIZED)
-        .header(CONTENT_TYPE, "application/json; charset=utf-8")
-        .body(Body::from("{\"error\":\"invalid or disabled token\"}"))
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
-}
-
 async fn authenticate_request_token(
     state: &Arc<AppState>,
     headers: &HeaderMap,
@@ -369,12 +361,12 @@ async fn authenticate_request_token(
         }));
     };
 
-    let valid = if token_resolution.using_dev_open_admin_fallback {
-        true
+    let validation = if token_resolution.using_dev_open_admin_fallback {
+        AccessTokenValidation::Valid
     } else {
         state
             .proxy
-            .validate_access_token(&token_resolution.token)
+            .check_access_token(&token_resolution.token)
             .await
             .map_err(|_| {
                 Response::builder()
@@ -384,8 +376,8 @@ async fn authenticate_request_token(
             })?
     };
 
-    if !valid {
-        return Err(invalid_token_response().unwrap_or_else(|status| {
+    if !validation.is_valid() {
+        return Err(access_token_rejection_response(validation).unwrap_or_else(|status| {
             Response::builder()
                 .status(status)
                 .body(Body::empty())
@@ -399,6 +391,164 @@ async fn authenticate_request_token(
     })
 }
 
+/// Rejects a request whose kinds fall outside the token's effective scopes. The denied attempt is
+/// recorded on the token log like the other pre-upstream rejections.
+async fn token_scope_rejection(
+    state: &Arc<AppState>,
+    token_id: Option<&str>,
+    method: &Method,
+    path: &str,
+    kinds: &[TokenRequestKind],
+) -> Result<Option<Response<Body>>, StatusCode> {
+    let Some(token_id) = token_id else {
+        return Ok(None);
+    };
+    let Some(scopes) = state
+        .proxy
+        .effective_access_token_scopes(token_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+    else {
+        return Ok(None);
+    };
+    let Some(denied) = kinds.iter().find(|kind| !scopes.allows(&kind.key)) else {
+        return Ok(None);
+    };
+    let message = format!("This access token is not allowed to call {}.", denied.label);
+    let _ = state
+        .proxy
+        .record_token_attempt(
+            token_id,
+            method,
+            path,
+            None,
+            Some(StatusCode::FORBIDDEN.as_u16() as i64),
+            None,
+            false,
+            "error",
+            Some(&message),
+        )
+        .await;
+    token_scope_denied_response(denied, &message).map(Some)
+}
+
+/// Caps one Tavily operation's arguments with a parameter policy, then checks the expected
+/// credits of what is left. Returns whether the arguments were rewritten.
+fn enforce_request_parameter_policy(
+    policy: &RequestParameterPolicy,
+    operation: &str,
+    arguments: &mut Value,
+) -> Result<bool, RequestParameterPolicyViolation> {
+    let changed = policy.apply(operation, arguments)?;
+    policy.check_expected_credits(tavily_http_reserved_credits(
+        &format!("/{operation}"),
+        arguments,
+    ))?;
+    Ok(changed)
+}
+
+/// Records a parameter policy violation on the token log and builds the structured `400`.
+async fn parameter_policy_violation_rejection(
+    state: &Arc<AppState>,
+    token_id: &str,
+    method: &Method,
+    path: &str,
+    request_kind: &TokenRequestKind,
+    violation: &RequestParameterPolicyViolation,
+) -> Result<Response<Body>, StatusCode> {
+    let _ = state
+        .proxy
+        .record_token_attempt_with_kind_metadata(
+            token_id,
+            method,
+            path,
+            None,
+            Some(StatusCode::BAD_REQUEST.as_u16() as i64),
+            None,
+            false,
+            "error",
+            Some(&violation.message),
+            request_kind,
+            Some(tavily_hikari::FAILURE_KIND_PARAMETER_POLICY_VIOLATION),
+            None,
+            None,
+        )
+        .await;
+    parameter_policy_violation_response(violation)
+}
+
+/// Applies the token's parameter policy to every Tavily `tools/call` in an MCP body. Clamped
+/// arguments are written back into `body_bytes`; a violation rejects the whole body.
+async fn mcp_request_parameter_policy_rejection(
+    state: &Arc<AppState>,
+    token_id: Option<&str>,
+    method: &Method,
+    path: &str,
+    body_bytes: &mut Bytes,
+) -> Result<Option<Response<Body>>, StatusCode> {
+    let Some(token_id) = token_id else {
+        return Ok(None);
+    };
+    let Some(policy) = state
+        .proxy
+        .effective_request_parameter_policy(token_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+    else {
+        return Ok(None);
+    };
+    let Ok(mut payload) = serde_json::from_slice::<Value>(body_bytes) else {
+        return Ok(None);
+    };
+    let messages: Vec<&mut Value> = match &mut payload {
+        Value::Array(items) => items.iter_mut().collect(),
+        message @ Value::Object(_) => vec![message],
+        _ => return Ok(None),
+    };
+    let mut changed = false;
+    for message in messages {
+        if message.get("method").and_then(Value::as_str) != Some("tools/call") {
+            continue;
+        }
+        let Some(params) = message.get_mut("params") else {
+            continue;
+        };
+        let Some(operation) = params
+            .get("name")
+            .and_then(Value::as_str)
+            .map(|name| name.trim().to_ascii_lowercase().replace('_', "-"))
+            .and_then(|name| name.strip_prefix("tavily-").map(str::to_string))
+        else {
+            continue;
+        };
+        let Some(arguments) = params.get_mut("arguments") else {
+            continue;
+        };
+        match enforce_request_parameter_policy(&policy, &operation, arguments) {
+            Ok(rewritten) => changed |= rewritten,
+            Err(violation) => {
+                let request_kind = classify_token_request_kind(path, Some(body_bytes));
+                return parameter_policy_violation_rejection(
+                    state,
+                    token_id,
+                    method,
+                    path,
+                    &request_kind,
+                    &violation,
+                )
+                .await
+                .map(Some);
+            }
+        }
+    }
+    if changed {
+        *body_bytes = Bytes::from(
+            serde_json::to_vec(&payload).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?,
+        );
+    }
+    Ok(None)
+}
+
 fn header_string(headers: &ReqHeaderMap, name: &'static str) -> Option<String> {
     headers
         .get(name)
@@ -1186,9 +1336,12 @@ fn build_rebalance_mcp_initialize_body(
     )
 }
 
-fn rebalance_mcp_tools_descriptor() -> Vec<Value> {
+fn rebalance_mcp_tools_descriptor(scopes: Option<&AccessTokenScopes>) -> Vec<Value> {
     REBALANCE_MCP_TOOL_DEFINITIONS
         .iter()
+        .filter(|tool| {
+            scopes.is_none_or(|scopes| scopes.allows(&format!("mcp:{}", tool.upstream_tool)))
+        })
         .map(|tool| {
             json!({
                 "name": tool.advertised_name,
@@ -1199,11 +1352,15 @@ fn rebalance_mcp_tools_descriptor() -> Vec<Value> {
         .collect()
 }
 
-fn build_rebalance_mcp_tools_list_body(response_id: Option<&Value>) -> Vec<u8> {
+/// Advertises only the tools the calling token's scopes allow.
+fn build_rebalance_mcp_tools_list_body(
+    response_id: Option<&Value>,
+    scopes: Option<&AccessTokenScopes>,
+) -> Vec<u8> {
     build_rebalance_mcp_success_body(
         response_id,
         json!({
-            "tools": rebalance_mcp_tools_descriptor(),
+            "tools": rebalance_mcp_tools_descriptor(scopes),
         }),
     )
 }
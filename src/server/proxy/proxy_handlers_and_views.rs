@@ -1,6 +1,14 @@
 async fn proxy_handler(
     State(state): State<Arc<AppState>>,
     req: Request<Body>,
+) -> Result<Response<Body>, StatusCode> {
+    let trace = RequestTrace::start(req.method().as_str(), req.uri().path(), req.headers());
+    trace.scope(proxy_handler_traced(state, req)).await
+}
+
+async fn proxy_handler_traced(
+    state: Arc<AppState>,
+    req: Request<Body>,
 ) -> Result<Response<Body>, StatusCode> {
     let (parts, body) = req.into_parts();
     let method = parts.method.clone();
@@ -34,7 +42,7 @@ async fn proxy_handler(
     let mut headers = clone_headers(&parts.headers);
     // prevent leaking our Authorization to upstream
     headers.remove(axum::http::header::AUTHORIZATION);
-    let body_bytes = body::to_bytes(body, BODY_LIMIT)
+    let mut body_bytes = body::to_bytes(body, BODY_LIMIT)
         .await
         .map_err(|_| StatusCode::BAD_REQUEST)?;
     let system_settings = state
@@ -59,6 +67,32 @@ async fn proxy_handler(
             "MCP requests must provide an explicit token when --dev-open-admin is enabled.",
         );
     }
+    if is_mcp_request
+        && !is_mcp_delete_root_request
+        && let Some(response) = token_scope_rejection(
+            &state,
+            token_id.as_deref(),
+            &method,
+            &path,
+            &classify_mcp_message_request_kinds(&body_bytes),
+        )
+        .await?
+    {
+        return Ok(response);
+    }
+    if is_mcp_request
+        && !is_mcp_delete_root_request
+        && let Some(response) = mcp_request_parameter_policy_rejection(
+            &state,
+            token_id.as_deref(),
+            &method,
+            &path,
+            &mut body_bytes,
+        )
+        .await?
+    {
+        return Ok(response);
+    }
     let mcp_body_summary = if is_mcp_request && !is_mcp_delete_root_request {
         Some(summarize_mcp_jsonrpc_body(&body_bytes))
     } else {
@@ -2088,6 +2122,7 @@ impl RequestLogView {
             client_ip_source: record.client_ip_source,
             client_ip_trusted: record.client_ip_trusted,
             ip_headers: record.ip_headers,
+            trace_id: record.trace_id,
             operational_class: record.operational_class,
             request_kind_protocol_group: record.request_kind_protocol_group,
             request_kind_billing_group: record.request_kind_billing_group,
@@ -2156,6 +2191,7 @@ impl RequestLogView {
             client_ip_source: None,
             client_ip_trusted: false,
             ip_headers: Vec::new(),
+            trace_id: None,
             operational_class,
             request_kind_protocol_group,
             request_kind_billing_group,
@@ -2177,6 +2213,17 @@ impl From<ProxySummary> for SummaryView {
             last_activity: summary.last_activity,
             total_quota_limit: summary.total_quota_limit,
             total_quota_remaining: summary.total_quota_remaining,
+            key_tiers: summary
+                .key_tiers
+                .into_iter()
+                .map(ApiKeyTierCapacityView::from)
+                .collect(),
+            key_rate_budgets: summary
+                .key_rate_budgets
+                .into_iter()
+                .map(ApiKeyRateBudgetUsageView::from)
+                .collect(),
+            response_cache: summary.response_cache.into(),
         }
     }
 }
@@ -108,7 +108,15 @@ async fn handle_rebalance_mcp_single_message(
             ))
         }
         "tools/list" => {
-            let body = build_rebalance_mcp_tools_list_body(response_id);
+            let scopes = match token_id {
+                Some(token_id) => state
+                    .proxy
+                    .effective_access_token_scopes(token_id)
+                    .await
+                    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?,
+                None => None,
+            };
+            let body = build_rebalance_mcp_tools_list_body(response_id, scopes.as_ref());
             let request_log_id = log_rebalance_local_control_plane_response(
                 state,
                 token_id,
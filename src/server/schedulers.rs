@@ -527,6 +527,10 @@ fn spawn_dashboard_rollup_integrity_scheduler(state: Arc<AppState>) {
     });
 }
 include!("schedulers_dashboard_alert_projection.rs");
+include!("schedulers_token_expiry_notices.rs");
+include!("schedulers_alert_webhooks.rs");
+include!("schedulers_alert_rules.rs");
+include!("schedulers_request_log_search.rs");
 async fn finish_dashboard_rollup_integrity_and_enqueue(
     state: &AppState,
     job_id: i64,
@@ -1510,29 +1514,28 @@ async fn run_ha_outbox_gc_claimed_job(
     true
 }
 
-async fn record_linuxdo_user_sync_failure(
+async fn record_oauth_user_sync_failure(
     state: &AppState,
-    provider_user_id: &str,
+    record: &tavily_hikari::OAuthAccountRefreshTokenRecord,
     attempted_at: i64,
     error: &str,
 ) {
     let _job_execution_gate = acquire_db_job_execution_gate_for_state(state).await;
     let _maintenance = acquire_db_maintenance_read_gate().await;
-    record_linuxdo_user_sync_failure_in_db_window(state, provider_user_id, attempted_at, error)
-        .await;
+    record_oauth_user_sync_failure_in_db_window(state, record, attempted_at, error).await;
 }
 
-async fn record_linuxdo_user_sync_failure_in_db_window(
+async fn record_oauth_user_sync_failure_in_db_window(
     state: &AppState,
-    provider_user_id: &str,
+    record: &tavily_hikari::OAuthAccountRefreshTokenRecord,
     attempted_at: i64,
     error: &str,
 ) {
     if let Err(mark_err) = state
         .proxy
         .record_oauth_account_profile_sync_failure(
-            "linuxdo",
-            provider_user_id,
+            &record.provider,
+            &record.provider_user_id,
             attempted_at,
             error,
         )
@@ -1541,7 +1544,8 @@ async fn record_linuxdo_user_sync_failure_in_db_window(
         tracing::warn!(
             component = "linuxdo_user_sync",
             event = "record_failure_metadata_failed",
-            provider_user_id,
+            provider = %record.provider,
+            provider_user_id = %record.provider_user_id,
             err = %mark_err,
         );
     }
@@ -1608,7 +1612,7 @@ async fn run_linuxdo_user_status_sync_claimed_job(
             .take()
             .expect("claimed linuxdo job has execution gate");
         let _maintenance = acquire_db_maintenance_read_gate().await;
-        if !cfg.is_enabled_and_configured() {
+        if !state.user_login_enabled() {
             let _ = state
                 .proxy
                 .scheduled_job_finish_claimed(
@@ -1633,11 +1637,7 @@ async fn run_linuxdo_user_status_sync_claimed_job(
             return true;
         }
 
-        let records = match state
-            .proxy
-            .list_oauth_accounts_with_refresh_token("linuxdo")
-            .await
-        {
+        let records = match list_user_sync_oauth_accounts(state.as_ref()).await {
             Ok(records) => records,
             Err(err) => {
                 let _ = state
@@ -1695,9 +1695,9 @@ async fn run_linuxdo_user_status_sync_claimed_job(
                 failure += 1;
                 first_failure
                     .get_or_insert_with(|| format!("{record_label}: {message}"));
-                record_linuxdo_user_sync_failure(
+                record_oauth_user_sync_failure(
                     state.as_ref(),
-                    &record.provider_user_id,
+                    &record,
                     attempted_at,
                     &message,
                 )
@@ -1705,17 +1705,17 @@ async fn run_linuxdo_user_status_sync_claimed_job(
                 continue;
             }
         };
-        let (profile, token_payload) =
-            match fetch_linuxdo_profile_from_refresh_token(&client, cfg, &refresh_token).await {
+        let (profile, rotated_refresh_token) =
+            match refresh_user_oauth_profile(&client, &state, &record.provider, &refresh_token).await {
                 Ok(result) => result,
                 Err(err) => {
                     let message = err.to_string();
                     failure += 1;
                     first_failure
                         .get_or_insert_with(|| format!("{record_label}: {message}"));
-                    record_linuxdo_user_sync_failure(
+                    record_oauth_user_sync_failure(
                         state.as_ref(),
-                        &record.provider_user_id,
+                        &record,
                         attempted_at,
                         &message,
                     )
@@ -1732,9 +1732,9 @@ async fn run_linuxdo_user_status_sync_claimed_job(
             .to_string();
             failure += 1;
             first_failure.get_or_insert_with(|| format!("{record_label}: {message}"));
-            record_linuxdo_user_sync_failure(
+            record_oauth_user_sync_failure(
                 state.as_ref(),
-                &record.provider_user_id,
+                &record,
                 attempted_at,
                 &message,
             )
@@ -1746,8 +1746,7 @@ async fn run_linuxdo_user_status_sync_claimed_job(
         {
             let _job_execution_gate = acquire_db_job_execution_gate_for_state(state.as_ref()).await;
             let _maintenance = acquire_db_maintenance_read_gate().await;
-            let upsert_result = if let Some(rotated_refresh_token) = token_payload
-                .refresh_token
+            let upsert_result = if let Some(rotated_refresh_token) = rotated_refresh_token
                 .as_deref()
                 .map(str::trim)
                 .filter(|value| !value.is_empty())
@@ -1768,9 +1767,9 @@ async fn run_linuxdo_user_status_sync_claimed_job(
                         let message = format!("encrypt rotated refresh token error: {err}");
                         failure += 1;
                         first_failure.get_or_insert_with(|| format!("{record_label}: {message}"));
-                        record_linuxdo_user_sync_failure_in_db_window(
+                        record_oauth_user_sync_failure_in_db_window(
                             state.as_ref(),
-                            &record.provider_user_id,
+                            &record,
                             attempted_at,
                             &message,
                         )
@@ -1794,9 +1793,9 @@ async fn run_linuxdo_user_status_sync_claimed_job(
                         "; deactivate local user error: {deactivate_err}"
                     ));
                 }
-                record_linuxdo_user_sync_failure_in_db_window(
+                record_oauth_user_sync_failure_in_db_window(
                     state.as_ref(),
-                    &record.provider_user_id,
+                    &record,
                     attempted_at,
                     &message,
                 )
@@ -1817,7 +1816,7 @@ async fn run_linuxdo_user_status_sync_claimed_job(
             if let Err(err) = state
                 .proxy
                 .record_oauth_account_profile_sync_success(
-                    "linuxdo",
+                    &record.provider,
                     &record.provider_user_id,
                     attempted_at,
                 )
@@ -2690,6 +2689,12 @@ async fn run_manual_claimed_job(
                 Err(err) => finish(state, "error", err.to_string()).await,
             }
         },
+        ACCESS_TOKEN_EXPIRY_NOTICE_JOB_TYPE => {
+            match state.proxy.record_access_token_expiry_notices().await {
+                Ok(noticed) => finish(state, "success", format!("notices={noticed}")).await,
+                Err(err) => finish(state, "error", err.to_string()).await,
+            }
+        }
         "request_logs_gc" => unreachable!("request_logs_gc handled above"),
         "linuxdo_user_status_sync" => unreachable!("linuxdo_user_status_sync handled above"),
         "linuxdo_user_tag_binding_refresh" => {
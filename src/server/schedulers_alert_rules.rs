@@ -0,0 +1,33 @@
+const ALERT_RULE_EVALUATION_INTERVAL_SECS: u64 = 60;
+
+/// Evaluates the operator-defined threshold rules. Breaches become alerts through the alert
+/// projection like every other alert source.
+fn spawn_alert_rule_evaluation_scheduler(state: Arc<AppState>) {
+    tokio::spawn(async move {
+        let mut last_error = None::<String>;
+        loop {
+            match state.proxy.evaluate_alert_rules(&state.ha).await {
+                Ok(_) => {
+                    last_error = None;
+                }
+                Err(err) => {
+                    let error = err.to_string();
+                    if last_error.as_deref() != Some(error.as_str()) {
+                        tracing::warn!(
+                            component = "alert_rules",
+                            event = "evaluation_failed",
+                            err = %error,
+                            "alert rule evaluation failed"
+                        );
+                    }
+                    last_error = Some(error);
+                }
+            }
+            state
+                .proxy
+                .backend_time()
+                .sleep(Duration::from_secs(ALERT_RULE_EVALUATION_INTERVAL_SECS))
+                .await;
+        }
+    });
+}
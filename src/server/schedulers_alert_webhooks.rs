@@ -0,0 +1,32 @@
+const ALERT_WEBHOOK_DISPATCH_INTERVAL_SECS: u64 = 5;
+
+/// Drains the alert webhook delivery queue that the alert projection fills.
+fn spawn_alert_webhook_dispatch_scheduler(state: Arc<AppState>) {
+    tokio::spawn(async move {
+        let mut last_error = None::<String>;
+        loop {
+            match state.proxy.dispatch_alert_webhook_deliveries().await {
+                Ok(_) => {
+                    last_error = None;
+                }
+                Err(err) => {
+                    let error = err.to_string();
+                    if last_error.as_deref() != Some(error.as_str()) {
+                        tracing::warn!(
+                            component = "alert_webhooks",
+                            event = "dispatch_failed",
+                            err = %error,
+                            "alert webhook dispatch failed"
+                        );
+                    }
+                    last_error = Some(error);
+                }
+            }
+            state
+                .proxy
+                .backend_time()
+                .sleep(Duration::from_secs(ALERT_WEBHOOK_DISPATCH_INTERVAL_SECS))
+                .await;
+        }
+    });
+}
@@ -0,0 +1,38 @@
+const REQUEST_LOG_SEARCH_INDEX_INTERVAL_SECS: u64 = 30;
+const REQUEST_LOG_SEARCH_INDEX_BACKLOG_SECS: u64 = 2;
+
+/// Keeps the request-log full-text index caught up. Each slice is admitted as maintenance-bulk
+/// work, so a busy database simply leaves the backlog for a later wake.
+fn spawn_request_log_search_index_scheduler(state: Arc<AppState>) {
+    tokio::spawn(async move {
+        let mut last_error = None::<String>;
+        loop {
+            let mut next_delay_secs = REQUEST_LOG_SEARCH_INDEX_INTERVAL_SECS;
+            match state.proxy.advance_request_log_search_index().await {
+                Ok(backlog) => {
+                    last_error = None;
+                    if backlog {
+                        next_delay_secs = REQUEST_LOG_SEARCH_INDEX_BACKLOG_SECS;
+                    }
+                }
+                Err(err) => {
+                    let error = err.to_string();
+                    if last_error.as_deref() != Some(error.as_str()) {
+                        tracing::warn!(
+                            component = "request_log_search",
+                            event = "index_failed",
+                            err = %error,
+                            "request log search indexing failed"
+                        );
+                    }
+                    last_error = Some(error);
+                }
+            }
+            state
+                .proxy
+                .backend_time()
+                .sleep(Duration::from_secs(next_delay_secs))
+                .await;
+        }
+    });
+}
@@ -0,0 +1,24 @@
+const ACCESS_TOKEN_EXPIRY_NOTICE_JOB_TYPE: &str = "token_expiry_notice";
+const ACCESS_TOKEN_EXPIRY_NOTICE_INTERVAL_SECS: u64 = 15 * 60;
+
+/// Periodically records `token_expiring` notices for tokens that are about to run out, so the
+/// alert shows up while there is still time to extend or replace them.
+fn spawn_access_token_expiry_notice_scheduler(state: Arc<AppState>) {
+    tokio::spawn(async move {
+        loop {
+            let _ = enqueue_scheduled_job_logged(
+                state.as_ref(),
+                ACCESS_TOKEN_EXPIRY_NOTICE_JOB_TYPE,
+                None,
+                TRIGGER_SOURCE_SCHEDULER,
+                "token-expiry-notice",
+            )
+            .await;
+            state
+                .proxy
+                .backend_time()
+                .sleep(Duration::from_secs(ACCESS_TOKEN_EXPIRY_NOTICE_INTERVAL_SECS))
+                .await;
+        }
+    });
+}
@@ -11,6 +11,7 @@ pub async fn serve(
     ha_config: tavily_hikari::HaConfig,
     linuxdo_oauth: LinuxDoOAuthOptions,
     linuxdo_credit: LinuxDoCreditOptions,
+    oidc: OidcOptions,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let AdminAuthOptions {
         forward_auth_enabled,
@@ -75,6 +76,7 @@ pub async fn serve(
         admin_passkey,
         linuxdo_oauth,
         linuxdo_credit,
+        oidc,
         ha,
         dev_open_admin,
         usage_base: usage_base.clone(),
@@ -150,13 +152,21 @@ pub async fn serve(
     tracing::info!(
         component = "startup",
         event = "linuxdo_user_sync_configuration",
-        scheduler_enabled = state.linuxdo_oauth.is_user_sync_scheduler_enabled(),
+        scheduler_enabled = state.user_sync_scheduler_enabled(),
         oauth_ready = state.linuxdo_oauth.is_enabled_and_configured(),
         refresh_token_key = state.linuxdo_oauth.has_refresh_token_crypt_key(),
         sync_hour = linuxdo_user_sync_hour,
         sync_minute = linuxdo_user_sync_minute,
         "linuxdo user sync configuration loaded"
     );
+    tracing::info!(
+        component = "startup",
+        event = "oidc_configuration",
+        enabled = state.oidc.enabled,
+        configured = state.oidc.is_enabled_and_configured(),
+        issuer = state.oidc.issuer.as_deref().unwrap_or_default(),
+        "oidc login configuration loaded"
+    );
     tracing::info!(
         component = "startup",
         event = "linuxdo_credit_configuration",
@@ -179,6 +189,7 @@ pub async fn serve(
 
     let mut router = Router::new()
         .route("/health", get(health_check))
+        .route("/metrics", get(get_prometheus_metrics))
         .route("/api/debug/headers", get(debug_headers))
         .route("/api/debug/is-admin", get(debug_is_admin))
         .route("/api/debug/forward-auth", get(get_forward_auth_debug))
@@ -204,6 +215,8 @@ pub async fn serve(
         .route("/auth/linuxdo", get(get_linuxdo_auth).post(post_linuxdo_auth))
         .route("/auth/linuxdo/callback", get(get_linuxdo_callback))
         .route("/auth/linuxdo/finalize", post(post_linuxdo_finalize))
+        .route("/auth/oidc", get(get_oidc_auth).post(post_oidc_auth))
+        .route("/auth/oidc/finalize", post(post_oidc_finalize))
         .route("/api/user/logout", post(post_user_logout))
         .route("/api/user/token", get(get_user_token))
         .route("/api/user/dashboard", get(get_user_dashboard))
@@ -239,11 +252,40 @@ pub async fn serve(
         )
         .route("/api/user/tokens/:id/logs", get(get_user_token_logs))
         .route("/api/user/tokens/:id/events", get(sse_user_token))
+        .route("/api/user/team", get(get_user_team))
+        .route(
+            "/api/user/team/usage-series",
+            get(get_user_team_usage_series),
+        )
+        .route(
+            "/api/user/team/tokens",
+            get(get_user_team_tokens).post(post_user_team_token),
+        )
+        .route(
+            "/api/user/team/tokens/:token_id",
+            delete(delete_user_team_token),
+        )
         .route("/api/admin/registration", get(get_admin_registration_settings))
         .route(
             "/api/admin/registration",
             patch(patch_admin_registration_settings),
         )
+        .route(
+            "/api/admin/registration/invites",
+            get(list_registration_invites),
+        )
+        .route(
+            "/api/admin/registration/invites",
+            post(create_registration_invite),
+        )
+        .route(
+            "/api/admin/registration/invites/:id",
+            delete(revoke_registration_invite),
+        )
+        .route(
+            "/api/admin/registration/approvals",
+            get(list_pending_registration_approvals),
+        )
         .route("/api/admin/login", post(post_admin_login))
         .route("/api/admin/logout", post(post_admin_logout))
         .route(
@@ -253,6 +295,30 @@ pub async fn serve(
                 .patch(patch_admin_password)
                 .delete(delete_admin_password),
         )
+        .route(
+            "/api/admin/accounts",
+            get(list_admin_accounts).post(create_admin_account),
+        )
+        .route(
+            "/api/admin/accounts/:id",
+            patch(update_admin_account).delete(delete_admin_account),
+        )
+        .route(
+            "/api/admin/accounts/:id/password",
+            put(put_admin_account_password),
+        )
+        .route(
+            "/api/admin/accounts/:id/sessions",
+            delete(delete_admin_account_sessions),
+        )
+        .route(
+            "/api/admin/api-tokens",
+            get(list_admin_api_tokens).post(create_admin_api_token),
+        )
+        .route(
+            "/api/admin/api-tokens/:id",
+            delete(revoke_admin_api_token),
+        )
         .route(
             "/api/admin/passkey/authentication/start",
             post(post_admin_passkey_authentication_start),
@@ -392,18 +458,29 @@ pub async fn serve(
         .route("/api/keys/validate", post(post_validate_api_keys))
         .route("/api/keys/batch", post(create_api_keys_batch))
         .route("/api/keys/bulk-actions", post(post_api_key_bulk_actions))
+        .route(
+            "/api/keys/group-routing",
+            get(get_api_key_group_routing).put(put_api_key_group_routing),
+        )
         .route("/api/keys/:id", get(get_api_key_detail))
         .route("/api/keys/:id/quarantine", delete(delete_api_key_quarantine))
         .route("/api/keys/:id/sync-usage", post(post_sync_key_usage))
         .route("/api/keys/:id/secret", get(get_api_key_secret))
         .route("/api/keys/:id", delete(delete_api_key))
         .route("/api/keys/:id/status", patch(update_api_key_status))
+        .route("/api/keys/:id/rate-budget", put(put_api_key_rate_budget))
         .route("/api/jobs", get(list_jobs))
         .route("/api/jobs/trigger", post(post_trigger_job))
         .route("/api/logs", get(list_logs))
         .route("/api/logs/list", get(list_logs_cursor))
         .route("/api/logs/catalog", get(get_logs_catalog))
         .route("/api/logs/:log_id/details", get(get_log_details))
+        .route(
+            "/api/logs/replay/upstreams",
+            get(get_request_log_replay_upstreams),
+        )
+        .route("/api/logs/:log_id/replay", post(post_request_log_replay))
+        .route("/api/logs/:log_id/replays", get(get_request_log_replays))
         .route("/api/announcements", get(get_announcements))
         .route("/api/announcements", post(create_announcement))
         .route("/api/announcements/:id", patch(update_announcement))
@@ -418,10 +495,34 @@ pub async fn serve(
         .route("/api/alerts/catalog", get(get_alert_catalog))
         .route("/api/alerts/events", get(get_alert_events))
         .route("/api/alerts/groups", get(get_alert_groups))
+        .route("/api/alerts/webhooks", get(get_alert_webhooks))
+        .route("/api/alerts/webhooks", post(create_alert_webhook))
+        .route(
+            "/api/alerts/webhooks/deliveries",
+            get(get_alert_webhook_deliveries),
+        )
+        .route("/api/alerts/webhooks/:id", patch(update_alert_webhook))
+        .route("/api/alerts/webhooks/:id", delete(delete_alert_webhook))
+        .route("/api/alerts/webhooks/:id/test", post(test_alert_webhook))
+        .route("/api/alerts/rules", get(get_alert_rules))
+        .route("/api/alerts/rules", post(create_alert_rule))
+        .route("/api/alerts/rules/:id", patch(update_alert_rule))
+        .route("/api/alerts/rules/:id", delete(delete_alert_rule))
+        .route("/api/alerts/groups/actions", post(post_alert_group_action))
+        .route("/api/alerts/groups/history", get(get_alert_group_history))
+        .route("/api/alerts/silences", get(get_alert_silences))
+        .route("/api/alerts/silences", post(create_alert_silence))
+        .route("/api/alerts/silences/:id", delete(delete_alert_silence))
+        .route("/api/admin/audit", get(get_admin_audit_entries))
+        .route("/api/admin/audit/verify", get(get_admin_audit_verification))
         .route("/api/user-tags", get(list_user_tags))
         .route("/api/user-tags", post(create_user_tag))
         .route("/api/user-tags/:tag_id", patch(update_user_tag))
         .route("/api/user-tags/:tag_id", delete(delete_user_tag))
+        .route(
+            "/api/user-tags/:tag_id/parameter-policy",
+            patch(update_user_tag_parameter_policy),
+        )
         .route("/api/users", get(list_users))
         .route("/api/users/:id", get(get_user_detail))
         .route(
@@ -429,6 +530,7 @@ pub async fn serve(
             get(list_user_entitlements).post(create_user_entitlement),
         )
         .route("/api/users/:id/usage-series", get(get_user_usage_series))
+        .route("/api/users/:id/approve", post(approve_user_registration))
         .route("/api/users/:id/tokens", post(create_user_token))
         .route("/api/users/:id/tokens/:token_id", delete(delete_user_token))
         .route(
@@ -438,6 +540,21 @@ pub async fn serve(
         .route("/api/users/:id/broken-keys", get(get_user_monthly_broken_keys))
         .route("/api/users/:id/tags", post(bind_user_tag))
         .route("/api/users/:id/tags/:tag_id", delete(unbind_user_tag))
+        .route("/api/teams", get(list_teams).post(create_team))
+        .route(
+            "/api/teams/:id",
+            get(get_team_detail).patch(update_team).delete(delete_team),
+        )
+        .route(
+            "/api/teams/:id/members/:user_id",
+            put(put_team_member).delete(delete_team_member),
+        )
+        .route("/api/teams/:id/tokens", post(create_team_token))
+        .route(
+            "/api/teams/:id/tokens/:token_id",
+            delete(delete_team_token),
+        )
+        .route("/api/teams/:id/usage-series", get(get_team_usage_series))
         // Key details
         .route("/api/keys/:id/metrics", get(get_key_metrics))
         .route("/api/keys/:id/logs", get(get_key_logs))
@@ -477,6 +594,24 @@ pub async fn serve(
         .route("/api/tokens/:id", delete(delete_token))
         .route("/api/tokens/:id/status", patch(update_token_status))
         .route("/api/tokens/:id/note", patch(update_token_note))
+        .route("/api/tokens/:id/lifetime", patch(update_token_lifetime))
+        .route("/api/tokens/:id/scopes", patch(update_token_scopes))
+        .route(
+            "/api/tokens/groups/:group/scopes",
+            patch(update_token_group_scopes),
+        )
+        .route(
+            "/api/tokens/:id/parameter-policy",
+            patch(update_token_parameter_policy),
+        )
+        .route(
+            "/api/tokens/groups/:group/parameter-policy",
+            patch(update_token_group_parameter_policy),
+        )
+        .route(
+            "/api/parameter-policies",
+            get(list_request_parameter_policies),
+        )
         .route("/api/tokens/:id/secret", get(get_token_secret))
         .route("/api/tokens/:id/secret/rotate", post(rotate_token_secret))
         .route("/", get(serve_index))
@@ -589,6 +724,14 @@ pub async fn serve(
     axum::serve(
         listener,
         router
+            .layer(axum::middleware::from_fn_with_state(
+                state.clone(),
+                admin_rbac_http_layer,
+            ))
+            .layer(axum::middleware::from_fn_with_state(
+                state.clone(),
+                admin_audit_http_layer,
+            ))
             .layer(axum::middleware::from_fn_with_state(
                 state.clone(),
                 db_maintenance_http_gate,
@@ -1575,10 +1718,14 @@ fn spawn_business_background_tasks(state: Arc<AppState>) {
     spawn_mcp_sessions_gc_scheduler(state.clone());
     spawn_mcp_session_init_backoffs_gc_scheduler(state.clone());
     spawn_request_logs_gc_scheduler(state.clone());
+    spawn_request_log_search_index_scheduler(state.clone());
     spawn_dashboard_rollup_integrity_scheduler(state.clone());
     spawn_dashboard_alert_projection_scheduler(state.clone());
     spawn_auth_token_logs_alert_index_ensure_scheduler(state.clone());
-    if state.linuxdo_oauth.is_user_sync_scheduler_enabled() {
+    spawn_access_token_expiry_notice_scheduler(state.clone());
+    spawn_alert_webhook_dispatch_scheduler(state.clone());
+    spawn_alert_rule_evaluation_scheduler(state.clone());
+    if state.user_sync_scheduler_enabled() {
         spawn_linuxdo_user_status_sync_scheduler(state.clone());
     }
     spawn_linuxdo_user_tag_binding_refresh_scheduler(state.clone());
@@ -2054,6 +2201,7 @@ mod serve_tests {
             },
             linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
             linuxdo_credit: LinuxDoCreditOptions::disabled(),
+            oidc: OidcOptions::disabled(),
             ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
             dev_open_admin: false,
             usage_base: "http://127.0.0.1:58088".to_string(),
@@ -2171,6 +2319,7 @@ mod serve_tests {
             },
             linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
             linuxdo_credit: LinuxDoCreditOptions::disabled(),
+            oidc: OidcOptions::disabled(),
             ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
             dev_open_admin: false,
             usage_base: "http://127.0.0.1:58088".to_string(),
@@ -2227,6 +2376,7 @@ mod serve_tests {
             },
             linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
             linuxdo_credit: LinuxDoCreditOptions::disabled(),
+            oidc: OidcOptions::disabled(),
             ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig {
                 mode: tavily_hikari::HaMode::ActiveStandby,
                 node_id: "node-passkey-standby".to_string(),
@@ -138,7 +138,7 @@ async fn serve_index(
         return Ok(Redirect::temporary("/admin").into_response());
     }
 
-    if state.linuxdo_oauth.is_enabled_and_configured()
+    if state.user_login_enabled()
         && resolve_user_session(state.as_ref(), &headers)
             .await
             .is_some()
@@ -235,7 +235,7 @@ async fn serve_console_index(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
 ) -> Result<Response<Body>, StatusCode> {
-    if !state.linuxdo_oauth.is_enabled_and_configured() {
+    if !state.user_login_enabled() {
         return load_spa_response(state.as_ref(), "console.html").await;
     }
     if resolve_user_session(state.as_ref(), &headers)
@@ -8,6 +8,7 @@ pub(crate) struct AppState {
     admin_passkey: AdminPasskeyOptions,
     linuxdo_oauth: LinuxDoOAuthOptions,
     linuxdo_credit: LinuxDoCreditOptions,
+    oidc: OidcOptions,
     ha: tavily_hikari::HaRuntime,
     dev_open_admin: bool,
     usage_base: String,
@@ -15,6 +16,19 @@ pub(crate) struct AppState {
     dashboard_overview_cache: Arc<Mutex<DashboardOverviewCacheState>>,
 }
 
+impl AppState {
+    /// User accounts, sessions and the console exist once any login provider is configured.
+    fn user_login_enabled(&self) -> bool {
+        self.linuxdo_oauth.is_enabled_and_configured() || self.oidc.is_enabled_and_configured()
+    }
+
+    /// The daily account sync covers OIDC accounts too; it shares the linux.do schedule.
+    fn user_sync_scheduler_enabled(&self) -> bool {
+        self.linuxdo_oauth.is_user_sync_scheduler_enabled()
+            || (self.linuxdo_oauth.user_sync_enabled && self.oidc.is_enabled_and_configured())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct DashboardOverviewFreshness {
     summary: [i64; 10],
@@ -665,6 +679,7 @@ const BUILTIN_ADMIN_COOKIE_NAME: &str = "hikari_admin_session";
 const BUILTIN_ADMIN_SESSION_MAX_AGE_SECS: u64 = 60 * 60 * 24 * 14;
 const BUILTIN_ADMIN_SESSION_MAX_COUNT: usize = 1024;
 const ADMIN_PASSKEY_COOKIE_NAME: &str = "hikari_admin_passkey_session";
+const ADMIN_ACCOUNT_COOKIE_NAME: &str = "hikari_admin_account_session";
 const USER_SESSION_COOKIE_NAME: &str = "hikari_user_session";
 const OAUTH_LOGIN_BINDING_COOKIE_NAME: &str = "hikari_oauth_login_binding";
 const DEV_OPEN_ADMIN_REQUEST_TOKEN: &str = "th-dev-override";
@@ -1131,7 +1146,13 @@ async fn is_admin_request(state: &AppState, headers: &HeaderMap) -> bool {
     if state.builtin_admin.is_admin(headers) {
         return true;
     }
-    resolve_admin_passkey_session(state, headers).await.is_some()
+    if resolve_admin_passkey_session(state, headers).await.is_some() {
+        return true;
+    }
+    if resolve_admin_account_session(state, headers).await.is_some() {
+        return true;
+    }
+    resolve_admin_api_token(state, headers).await.is_some()
 }
 
 async fn require_full_master_write(state: &AppState) -> Result<(), (StatusCode, String)> {
@@ -1145,7 +1166,7 @@ async fn resolve_user_session(
     state: &AppState,
     headers: &HeaderMap,
 ) -> Option<tavily_hikari::UserSession> {
-    if !state.linuxdo_oauth.is_enabled_and_configured() {
+    if !state.user_login_enabled() {
         return None;
     }
     let cookie = cookie_value(headers, USER_SESSION_COOKIE_NAME)?;
@@ -1170,6 +1191,36 @@ async fn resolve_admin_passkey_session(
     }
 }
 
+async fn resolve_admin_account_session(
+    state: &AppState,
+    headers: &HeaderMap,
+) -> Option<tavily_hikari::AdminAccountSession> {
+    let token = cookie_value(headers, ADMIN_ACCOUNT_COOKIE_NAME)?;
+    match state.proxy.get_active_admin_account_session(&token).await {
+        Ok(Some(session)) => Some(session),
+        _ => None,
+    }
+}
+
+/// Admin API token sent as `Authorization: Bearer tha-…`. Scopes and the IP allowlist are
+/// enforced per route by `admin_rbac_http_layer`, which also rejects tokens on routes that
+/// have no token scope.
+async fn resolve_admin_api_token(
+    state: &AppState,
+    headers: &HeaderMap,
+) -> Option<tavily_hikari::AdminApiToken> {
+    let token = headers
+        .get(axum::http::header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|raw| raw.trim().strip_prefix("Bearer "))
+        .map(str::trim)
+        .filter(|token| token.starts_with(tavily_hikari::ADMIN_API_TOKEN_PREFIX))?;
+    match state.proxy.authenticate_admin_api_token(token).await {
+        Ok(Some(token)) => Some(token),
+        _ => None,
+    }
+}
+
 async fn admin_maintenance_actor(
     state: &AppState,
     headers: &HeaderMap,
@@ -1221,6 +1272,16 @@ async fn admin_maintenance_actor(
                 })
                 .unwrap_or_else(|| "admin-passkey".to_string()),
         );
+        return actor;
+    }
+
+    if let Some(session) = resolve_admin_account_session(state, headers).await {
+        actor.actor_display_name = Some(format!("admin:{}", session.username));
+        return actor;
+    }
+
+    if let Some(token) = resolve_admin_api_token(state, headers).await {
+        actor.actor_display_name = Some(format!("api-token:{}", token.name));
     }
 
     actor
@@ -6,7 +6,7 @@ mod tests {
     use axum::extract::{DefaultBodyLimit, Form, Json, Query, State};
     use axum::http::{HeaderMap, Method, Uri};
     use axum::response::{IntoResponse, Response};
-    use axum::routing::{any, delete, get, patch, post};
+    use axum::routing::{any, delete, get, patch, post, put};
     use bytes::Bytes;
     use nanoid::nanoid;
     use reqwest::Client;
@@ -28,8 +28,14 @@ mod tests {
     use tokio::net::TcpListener;
     use tokio::sync::Notify;
 
+    mod access_token_lifetime;
+    mod access_token_scopes;
+    mod access_token_secret_hashing;
+    mod admin_accounts;
+    mod admin_api_tokens;
     mod admin_logs_and_summary;
     mod admin_analysis_pressure;
+    mod admin_audit;
     mod admin_token_owner_summary;
     mod admin_token_filters_and_maintenance;
     mod admin_users_and_tokens;
@@ -47,16 +53,29 @@ mod tests {
     mod branded_assets_contract;
     mod core_support_and_parsing;
     mod dashboard_overview_snapshot;
+    mod key_group_routing;
+    mod key_rate_budget;
     mod linuxdo_oauth_and_admin_keys;
     mod log_catalog_and_dashboard_sse;
     mod mcp_billing_and_sessions;
     mod mcp_rebalance_and_follow_up;
+    mod mcp_rebalance_local_facade;
     mod observability_audit_support;
+    mod oidc_login;
+    mod prometheus_metrics;
+    mod registration_invites;
+    mod request_coalescing;
+    mod request_log_replay;
+    mod request_parameter_policies;
+    mod request_tracing;
     mod research_result_and_mcp_subpath;
+    mod response_cache;
     mod system_settings_and_forward_proxy;
     mod system_settings_reconciliation_status;
     mod tavily_http_free_account_boundary;
+    mod tavily_http_research_stream;
     mod tavily_http_search;
+    mod teams;
     mod token_log_details;
     mod upstream_support_and_manual_jobs;
 }
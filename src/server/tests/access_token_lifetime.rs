@@ -0,0 +1,118 @@
+use super::*;
+use super::core_support_and_parsing::temp_db_path;
+use super::upstream_support_and_manual_jobs::{spawn_admin_tokens_server, spawn_proxy_server_with_dev};
+
+#[tokio::test]
+async fn expired_tokens_are_rejected_with_a_distinct_error_on_http_and_mcp() {
+    let db_path = temp_db_path("access-token-lifetime-auth");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(
+        vec!["tvly-lifetime-key".to_string()],
+        DEFAULT_UPSTREAM,
+        &db_str,
+    )
+    .await
+    .expect("proxy created");
+    let addr = spawn_proxy_server_with_dev(proxy.clone(), "http://127.0.0.1:58088".to_string(), true)
+        .await;
+    let client = Client::new();
+
+    let now = chrono::Utc::now().timestamp();
+    let created = client
+        .post(format!("http://{addr}/api/tokens"))
+        .json(&serde_json::json!({ "note": "ci", "expires_at": now - 60 }))
+        .send()
+        .await
+        .expect("create expired token");
+    assert_eq!(created.status(), reqwest::StatusCode::CREATED);
+    let token = created.json::<serde_json::Value>().await.expect("token body")["token"]
+        .as_str()
+        .expect("token secret")
+        .to_string();
+
+    let search = client
+        .post(format!("http://{addr}/api/tavily/search"))
+        .bearer_auth(&token)
+        .json(&serde_json::json!({ "query": "expired" }))
+        .send()
+        .await
+        .expect("search with expired token");
+    assert_eq!(search.status(), reqwest::StatusCode::UNAUTHORIZED);
+    let body: serde_json::Value = search.json().await.expect("search body");
+    assert_eq!(body["error"], "token_expired");
+    assert_eq!(body["expiresAt"], now - 60);
+
+    let mcp = client
+        .post(format!("http://{addr}/mcp"))
+        .bearer_auth(&token)
+        .json(&serde_json::json!({ "jsonrpc": "2.0", "id": 1, "method": "tools/list" }))
+        .send()
+        .await
+        .expect("mcp with expired token");
+    assert_eq!(mcp.status(), reqwest::StatusCode::UNAUTHORIZED);
+    let body: serde_json::Value = mcp.json().await.expect("mcp body");
+    assert_eq!(body["error"], "token_expired");
+
+    let inverted = client
+        .post(format!("http://{addr}/api/tokens"))
+        .json(&serde_json::json!({ "not_before": now + 60, "expires_at": now }))
+        .send()
+        .await
+        .expect("create inverted window");
+    assert_eq!(inverted.status(), reqwest::StatusCode::BAD_REQUEST);
+
+    let _ = std::fs::remove_file(db_path);
+}
+
+#[tokio::test]
+async fn admin_can_replace_a_token_lifetime() {
+    let db_path = temp_db_path("access-token-lifetime-admin");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+        .await
+        .expect("proxy created");
+    let issued = proxy
+        .create_access_token(Some("contractor"))
+        .await
+        .expect("create token");
+    let addr = spawn_admin_tokens_server(proxy.clone(), true).await;
+    let client = Client::new();
+
+    let now = chrono::Utc::now().timestamp();
+    let updated = client
+        .patch(format!("http://{addr}/api/tokens/{}/lifetime", issued.id))
+        .json(&serde_json::json!({ "not_before": now - 60, "expires_at": now + 3_600 }))
+        .send()
+        .await
+        .expect("update lifetime");
+    assert_eq!(updated.status(), reqwest::StatusCode::NO_CONTENT);
+
+    let detail: serde_json::Value = client
+        .get(format!("http://{addr}/api/tokens/{}", issued.id))
+        .send()
+        .await
+        .expect("token detail")
+        .json()
+        .await
+        .expect("detail body");
+    assert_eq!(detail["not_before"], now - 60);
+    assert_eq!(detail["expires_at"], now + 3_600);
+
+    let invalid = client
+        .patch(format!("http://{addr}/api/tokens/{}/lifetime", issued.id))
+        .json(&serde_json::json!({ "expires_at": -1 }))
+        .send()
+        .await
+        .expect("invalid lifetime");
+    assert_eq!(invalid.status(), reqwest::StatusCode::BAD_REQUEST);
+
+    let missing = client
+        .patch(format!("http://{addr}/api/tokens/zzzz/lifetime"))
+        .json(&serde_json::json!({}))
+        .send()
+        .await
+        .expect("missing token");
+    assert_eq!(missing.status(), reqwest::StatusCode::NOT_FOUND);
+
+    let _ = std::fs::remove_file(db_path);
+}
@@ -0,0 +1,202 @@
+use super::*;
+use super::core_support_and_parsing::{decode_sse_json_response, temp_db_path};
+use super::upstream_support_and_manual_jobs::{
+    RecordedRebalanceGatewayCalls, spawn_admin_tokens_server,
+    spawn_http_search_mock_asserting_api_key, spawn_proxy_server, spawn_rebalance_gateway_mock,
+};
+
+async fn search_only_token(proxy: &TavilyProxy, note: &str) -> tavily_hikari::AuthTokenSecret {
+    let issued = proxy
+        .create_access_token(Some(note))
+        .await
+        .expect("create token");
+    let scopes = AccessTokenScopes::parse(["api:search", "mcp:search"]).expect("parse scopes");
+    proxy
+        .set_access_tokens_scopes(std::slice::from_ref(&issued.id), Some(&scopes))
+        .await
+        .expect("set token scopes");
+    issued
+}
+
+#[tokio::test]
+async fn scoped_token_is_denied_out_of_scope_http_and_mcp_calls() {
+    let db_path = temp_db_path("access-token-scopes-enforced");
+    let db_str = db_path.to_string_lossy().to_string();
+    let expected_api_key = "tvly-scopes-key";
+    let upstream_addr = spawn_http_search_mock_asserting_api_key(expected_api_key.to_string()).await;
+    let upstream = format!("http://{upstream_addr}");
+    let proxy = TavilyProxy::with_endpoint(vec![expected_api_key.to_string()], &upstream, &db_str)
+        .await
+        .expect("proxy created");
+    let token = search_only_token(&proxy, "cheap-search").await;
+    let addr = spawn_proxy_server(proxy.clone(), upstream.clone()).await;
+    let client = Client::new();
+
+    let search = client
+        .post(format!("http://{addr}/api/tavily/search"))
+        .bearer_auth(&token.token)
+        .json(&serde_json::json!({ "query": "scoped" }))
+        .send()
+        .await
+        .expect("search in scope");
+    assert_eq!(search.status(), reqwest::StatusCode::OK);
+
+    let research = client
+        .post(format!("http://{addr}/api/tavily/research"))
+        .bearer_auth(&token.token)
+        .json(&serde_json::json!({ "input": "expensive" }))
+        .send()
+        .await
+        .expect("research out of scope");
+    assert_eq!(research.status(), reqwest::StatusCode::FORBIDDEN);
+    let body: serde_json::Value = research.json().await.expect("research body");
+    assert_eq!(body["error"], "token_scope_denied");
+    assert_eq!(body["requestKind"], "api:research");
+
+    let mcp = client
+        .post(format!("http://{addr}/mcp"))
+        .bearer_auth(&token.token)
+        .header("accept", "application/json, text/event-stream")
+        .json(&serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "tools/call",
+            "params": { "name": "tavily_research", "arguments": { "input": "expensive" } }
+        }))
+        .send()
+        .await
+        .expect("mcp research out of scope");
+    assert_eq!(mcp.status(), reqwest::StatusCode::FORBIDDEN);
+    let body: serde_json::Value = mcp.json().await.expect("mcp body");
+    assert_eq!(body["error"], "token_scope_denied");
+    assert_eq!(body["requestKind"], "mcp:research");
+
+    let _ = std::fs::remove_file(db_path);
+}
+
+#[tokio::test]
+async fn rebalance_tools_list_hides_out_of_scope_tools() {
+    let db_path = temp_db_path("access-token-scopes-rebalance-tools");
+    let db_str = db_path.to_string_lossy().to_string();
+    let expected_api_key = "tvly-scopes-rebalance";
+    let seen: RecordedRebalanceGatewayCalls = Arc::new(Mutex::new(Vec::new()));
+    let upstream_addr = spawn_rebalance_gateway_mock(expected_api_key.to_string(), seen).await;
+    let upstream = format!("http://{upstream_addr}");
+    let proxy = TavilyProxy::with_endpoint(vec![expected_api_key.to_string()], &upstream, &db_str)
+        .await
+        .expect("proxy created");
+    let mut settings = proxy.get_system_settings().await.expect("read settings");
+    settings.rebalance_mcp_enabled = true;
+    settings.rebalance_mcp_session_percent = 100;
+    proxy
+        .set_system_settings(&settings)
+        .await
+        .expect("enable rebalance mcp");
+    let token = search_only_token(&proxy, "cheap-mcp").await;
+    let addr = spawn_proxy_server(proxy.clone(), upstream.clone()).await;
+    let client = Client::new();
+
+    let tools_list = client
+        .post(format!("http://{addr}/mcp?tavilyApiKey={}", token.token))
+        .header("accept", "application/json, text/event-stream")
+        .header("content-type", "application/json")
+        .header("mcp-protocol-version", "2025-03-26")
+        .json(&json!({ "jsonrpc": "2.0", "id": "scoped-tools", "method": "tools/list" }))
+        .send()
+        .await
+        .expect("tools/list request");
+    assert_eq!(tools_list.status(), StatusCode::OK);
+    let body = decode_sse_json_response(tools_list).await;
+    let names: Vec<&str> = body["result"]["tools"]
+        .as_array()
+        .expect("tools array")
+        .iter()
+        .filter_map(|tool| tool["name"].as_str())
+        .collect();
+    assert_eq!(names, vec!["tavily_search"]);
+
+    let _ = std::fs::remove_file(db_path);
+}
+
+#[tokio::test]
+async fn admin_can_set_token_and_group_scopes() {
+    let db_path = temp_db_path("access-token-scopes-admin");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+        .await
+        .expect("proxy created");
+    let issued = proxy
+        .create_access_tokens_batch("cheap", 1, None)
+        .await
+        .expect("create token");
+    let token_id = issued[0].id.clone();
+    let addr = spawn_admin_tokens_server(proxy.clone(), true).await;
+    let client = Client::new();
+
+    let updated = client
+        .patch(format!("http://{addr}/api/tokens/{token_id}/scopes"))
+        .json(&serde_json::json!({ "scopes": ["mcp:map", "api:map"] }))
+        .send()
+        .await
+        .expect("update token scopes");
+    assert_eq!(updated.status(), reqwest::StatusCode::NO_CONTENT);
+    let detail: serde_json::Value = client
+        .get(format!("http://{addr}/api/tokens/{token_id}"))
+        .send()
+        .await
+        .expect("token detail")
+        .json()
+        .await
+        .expect("detail body");
+    assert_eq!(detail["scopes"], serde_json::json!(["api:map", "mcp:map"]));
+
+    let invalid = client
+        .patch(format!("http://{addr}/api/tokens/{token_id}/scopes"))
+        .json(&serde_json::json!({ "scopes": ["api:usage"] }))
+        .send()
+        .await
+        .expect("invalid scopes");
+    assert_eq!(invalid.status(), reqwest::StatusCode::BAD_REQUEST);
+    let missing = client
+        .patch(format!("http://{addr}/api/tokens/zzzz/scopes"))
+        .json(&serde_json::json!({ "scopes": null }))
+        .send()
+        .await
+        .expect("missing token");
+    assert_eq!(missing.status(), reqwest::StatusCode::NOT_FOUND);
+
+    let group = client
+        .patch(format!("http://{addr}/api/tokens/groups/cheap/scopes"))
+        .json(&serde_json::json!({ "scopes": ["api:search"] }))
+        .send()
+        .await
+        .expect("update group scopes");
+    assert_eq!(group.status(), reqwest::StatusCode::NO_CONTENT);
+    let groups: serde_json::Value = client
+        .get(format!("http://{addr}/api/tokens/groups"))
+        .send()
+        .await
+        .expect("list groups")
+        .json()
+        .await
+        .expect("groups body");
+    let cheap = groups
+        .as_array()
+        .expect("groups array")
+        .iter()
+        .find(|group| group["name"] == "cheap")
+        .expect("cheap group listed");
+    assert_eq!(cheap["defaultScopes"], serde_json::json!(["api:search"]));
+
+    // The token's own scopes still take precedence over the new group default.
+    let effective = proxy
+        .effective_access_token_scopes(&token_id)
+        .await
+        .expect("effective scopes");
+    assert_eq!(
+        effective.as_ref().map(|scopes| scopes.keys().to_vec()),
+        Some(vec!["api:map".to_string(), "mcp:map".to_string()])
+    );
+
+    let _ = std::fs::remove_file(db_path);
+}
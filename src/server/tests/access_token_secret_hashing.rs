@@ -0,0 +1,60 @@
+use super::*;
+use super::core_support_and_parsing::temp_db_path;
+use super::upstream_support_and_manual_jobs::spawn_admin_tokens_server;
+
+#[tokio::test]
+async fn admin_token_secret_reveal_reports_hashed_tokens_as_not_recoverable() {
+    let db_path = temp_db_path("admin-token-secret-hashed");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_options(
+        Vec::<String>::new(),
+        DEFAULT_UPSTREAM,
+        &db_str,
+        tavily_hikari::TavilyProxyOptions {
+            hash_access_token_secrets: true,
+            ..tavily_hikari::TavilyProxyOptions::from_database_path(&db_str)
+        },
+    )
+    .await
+    .expect("proxy created");
+    let issued = proxy
+        .create_access_token(Some("hashed"))
+        .await
+        .expect("create token");
+    let addr = spawn_admin_tokens_server(proxy.clone(), true).await;
+    let client = Client::new();
+
+    let reveal = client
+        .get(format!("http://{addr}/api/tokens/{}/secret", issued.id))
+        .send()
+        .await
+        .expect("reveal request");
+    assert_eq!(reveal.status(), reqwest::StatusCode::GONE);
+    let body: serde_json::Value = reveal.json().await.expect("reveal body");
+    assert_eq!(body["error"], "token_secret_not_recoverable");
+    assert_eq!(body["tokenId"], issued.id);
+
+    let rotated = client
+        .post(format!("http://{addr}/api/tokens/{}/secret/rotate", issued.id))
+        .send()
+        .await
+        .expect("rotate request");
+    assert_eq!(rotated.status(), reqwest::StatusCode::OK);
+    let body: serde_json::Value = rotated.json().await.expect("rotate body");
+    let token = body["token"].as_str().expect("rotated token shown once");
+    assert!(
+        proxy
+            .validate_access_token(token)
+            .await
+            .expect("validate rotated token")
+    );
+
+    let missing = client
+        .get(format!("http://{addr}/api/tokens/none/secret"))
+        .send()
+        .await
+        .expect("missing token request");
+    assert_eq!(missing.status(), reqwest::StatusCode::NOT_FOUND);
+
+    let _ = std::fs::remove_file(db_path);
+}
@@ -0,0 +1,423 @@
+use super::*;
+use super::core_support_and_parsing::*;
+use super::linuxdo_oauth_and_admin_keys::find_cookie_pair;
+
+const OWNER_PASSWORD: &str = "builtin-owner-password";
+
+async fn spawn_admin_accounts_server(proxy: TavilyProxy) -> SocketAddr {
+    let state = Arc::new(AppState {
+        proxy,
+        static_dir: None,
+        forward_auth: ForwardAuthConfig::new(None, None, None, None),
+        forward_auth_enabled: false,
+        builtin_admin: BuiltinAdminAuth::new(true, Some(OWNER_PASSWORD.to_string()), None),
+        admin_passkey: AdminPasskeyOptions::disabled(),
+        linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
+        linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
+        ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
+        dev_open_admin: false,
+        usage_base: "http://127.0.0.1:58088".to_string(),
+        api_key_ip_geo_origin: "https://api.country.is".to_string(),
+        dashboard_overview_cache: new_dashboard_overview_cache(),
+    });
+
+    let app = Router::new()
+        .route("/api/profile", get(get_profile))
+        .route("/api/admin/login", post(post_admin_login))
+        .route("/api/admin/logout", post(post_admin_logout))
+        .route(
+            "/api/admin/accounts",
+            get(list_admin_accounts).post(create_admin_account),
+        )
+        .route(
+            "/api/admin/accounts/:id",
+            patch(update_admin_account).delete(delete_admin_account),
+        )
+        .route(
+            "/api/admin/accounts/:id/password",
+            put(put_admin_account_password),
+        )
+        .route(
+            "/api/admin/accounts/:id/sessions",
+            delete(delete_admin_account_sessions),
+        )
+        .route("/api/settings", get(get_settings))
+        .route("/api/settings/system", put(put_system_settings))
+        .route("/api/keys/:id/secret", get(get_api_key_secret))
+        .route("/api/user-tags", get(list_user_tags).post(create_user_tag))
+        .route("/api/admin/audit", get(get_admin_audit_entries))
+        .route("/api/admin/ha/baseline", get(get_admin_ha_baseline))
+        .route("/api/admin/ha/events", get(get_admin_ha_events))
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            admin_rbac_http_layer,
+        ))
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            admin_audit_http_layer,
+        ))
+        .with_state(state);
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .await
+        .unwrap();
+    });
+    addr
+}
+
+async fn admin_login(
+    client: &Client,
+    addr: SocketAddr,
+    body: serde_json::Value,
+    cookie_name: &str,
+) -> String {
+    let resp = client
+        .post(format!("http://{addr}/api/admin/login"))
+        .json(&body)
+        .send()
+        .await
+        .expect("admin login");
+    assert_eq!(resp.status(), StatusCode::OK);
+    find_cookie_pair(resp.headers(), cookie_name).expect("admin session cookie")
+}
+
+async fn create_account(
+    client: &Client,
+    addr: SocketAddr,
+    owner_cookie: &str,
+    username: &str,
+    role: &str,
+) -> serde_json::Value {
+    let resp = client
+        .post(format!("http://{addr}/api/admin/accounts"))
+        .header(reqwest::header::COOKIE, owner_cookie)
+        .json(&serde_json::json!({
+            "username": username,
+            "role": role,
+            "password": format!("{username}-password"),
+        }))
+        .send()
+        .await
+        .expect("create admin account");
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    resp.json().await.expect("decode admin account")
+}
+
+fn tag_body(name: &str) -> serde_json::Value {
+    serde_json::json!({
+        "name": name,
+        "displayName": name,
+        "effectKind": "quota_delta",
+        "businessCalls1hDelta": 0,
+        "dailyCreditsDelta": 0,
+        "monthlyCreditsDelta": 0,
+    })
+}
+
+#[tokio::test]
+async fn admin_account_roles_gate_reads_changes_and_owner_routes() {
+    let db_path = temp_db_path("admin-accounts-rbac");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+        .await
+        .expect("create proxy");
+    let addr = spawn_admin_accounts_server(proxy).await;
+    let client = Client::new();
+
+    let owner = admin_login(
+        &client,
+        addr,
+        serde_json::json!({ "password": OWNER_PASSWORD }),
+        BUILTIN_ADMIN_COOKIE_NAME,
+    )
+    .await;
+    create_account(&client, addr, &owner, "ops-viewer", "viewer").await;
+    create_account(&client, addr, &owner, "ops-operator", "operator").await;
+    let duplicate = client
+        .post(format!("http://{addr}/api/admin/accounts"))
+        .header(reqwest::header::COOKIE, &owner)
+        .json(&serde_json::json!({
+            "username": "OPS-VIEWER",
+            "role": "viewer",
+            "password": "another-password",
+        }))
+        .send()
+        .await
+        .expect("create duplicate account");
+    assert_eq!(duplicate.status(), StatusCode::CONFLICT);
+
+    let wrong_password = client
+        .post(format!("http://{addr}/api/admin/login"))
+        .json(&serde_json::json!({ "username": "ops-viewer", "password": "nope-nope" }))
+        .send()
+        .await
+        .expect("login with wrong password");
+    assert_eq!(wrong_password.status(), StatusCode::UNAUTHORIZED);
+    let viewer = admin_login(
+        &client,
+        addr,
+        serde_json::json!({ "username": "ops-viewer", "password": "ops-viewer-password" }),
+        ADMIN_ACCOUNT_COOKIE_NAME,
+    )
+    .await;
+    let operator = admin_login(
+        &client,
+        addr,
+        serde_json::json!({ "username": "ops-operator", "password": "ops-operator-password" }),
+        ADMIN_ACCOUNT_COOKIE_NAME,
+    )
+    .await;
+
+    let profile: serde_json::Value = client
+        .get(format!("http://{addr}/api/profile"))
+        .header(reqwest::header::COOKIE, &viewer)
+        .send()
+        .await
+        .expect("viewer profile")
+        .json()
+        .await
+        .expect("decode profile");
+    assert_eq!(profile["isAdmin"], true);
+    assert_eq!(profile["adminRole"], "viewer");
+    assert_eq!(profile["displayName"], "ops-viewer");
+    assert_eq!(profile["adminAccountsEnabled"], true);
+
+    let status = |cookie: &str, method: reqwest::Method, path: &str, body: Option<serde_json::Value>| {
+        let mut request = client
+            .request(method, format!("http://{addr}{path}"))
+            .header(reqwest::header::COOKIE, cookie.to_string());
+        if let Some(body) = body {
+            request = request.json(&body);
+        }
+        async move { request.send().await.expect("send request").status() }
+    };
+
+    assert_eq!(status(&viewer, reqwest::Method::GET, "/api/settings", None).await, StatusCode::OK);
+    assert_eq!(status(&viewer, reqwest::Method::GET, "/api/user-tags", None).await, StatusCode::OK);
+    assert_eq!(
+        status(&viewer, reqwest::Method::POST, "/api/user-tags", Some(tag_body("viewer_tag"))).await,
+        StatusCode::FORBIDDEN
+    );
+    assert_eq!(
+        status(&operator, reqwest::Method::POST, "/api/user-tags", Some(tag_body("operator_tag"))).await,
+        StatusCode::OK
+    );
+    assert_eq!(
+        status(&operator, reqwest::Method::GET, "/api/keys/missing/secret", None).await,
+        StatusCode::FORBIDDEN
+    );
+    assert_eq!(
+        status(&owner, reqwest::Method::GET, "/api/keys/missing/secret", None).await,
+        StatusCode::NOT_FOUND
+    );
+    assert_eq!(
+        status(
+            &operator,
+            reqwest::Method::PUT,
+            "/api/settings/system",
+            Some(serde_json::json!({ "requestRateLimit": 10 })),
+        )
+        .await,
+        StatusCode::FORBIDDEN
+    );
+    assert_eq!(
+        status(&operator, reqwest::Method::GET, "/api/admin/accounts", None).await,
+        StatusCode::FORBIDDEN
+    );
+    assert_eq!(
+        status(&viewer, reqwest::Method::GET, "/api/admin/audit", None).await,
+        StatusCode::FORBIDDEN
+    );
+    for path in ["/api/admin/ha/baseline", "/api/admin/ha/events"] {
+        assert_eq!(
+            status(&viewer, reqwest::Method::GET, path, None).await,
+            StatusCode::FORBIDDEN,
+            "viewers must not export HA secrets from {path}"
+        );
+        assert_eq!(
+            status(&operator, reqwest::Method::GET, path, None).await,
+            StatusCode::FORBIDDEN
+        );
+        assert_ne!(
+            status(&owner, reqwest::Method::GET, path, None).await,
+            StatusCode::FORBIDDEN
+        );
+    }
+
+    let audit: serde_json::Value = client
+        .get(format!("http://{addr}/api/admin/audit?kind=mutation&route=user-tags"))
+        .header(reqwest::header::COOKIE, &owner)
+        .send()
+        .await
+        .expect("list audit entries")
+        .json()
+        .await
+        .expect("decode audit entries");
+    let actors = audit["items"]
+        .as_array()
+        .expect("audit items")
+        .iter()
+        .map(|entry| {
+            (
+                entry["actorKind"].as_str().unwrap_or_default().to_string(),
+                entry["actorName"].as_str().unwrap_or_default().to_string(),
+                entry["status"].as_i64().unwrap_or_default(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert!(actors.contains(&("account".to_string(), "ops-operator".to_string(), 200)), "{audit}");
+    assert!(actors.contains(&("account".to_string(), "ops-viewer".to_string(), 403)), "{audit}");
+
+    let _ = std::fs::remove_file(db_path);
+}
+
+#[tokio::test]
+async fn admin_account_changes_take_effect_on_existing_sessions() {
+    let db_path = temp_db_path("admin-accounts-sessions");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+        .await
+        .expect("create proxy");
+    let addr = spawn_admin_accounts_server(proxy).await;
+    let client = Client::new();
+
+    let builtin = admin_login(
+        &client,
+        addr,
+        serde_json::json!({ "password": OWNER_PASSWORD }),
+        BUILTIN_ADMIN_COOKIE_NAME,
+    )
+    .await;
+    let lead = create_account(&client, addr, &builtin, "ops-lead", "owner").await;
+    let lead_id = lead["id"].as_str().expect("lead id").to_string();
+    let member = create_account(&client, addr, &builtin, "ops-member", "viewer").await;
+    let member_id = member["id"].as_str().expect("member id").to_string();
+    let lead_cookie = admin_login(
+        &client,
+        addr,
+        serde_json::json!({ "username": "ops-lead", "password": "ops-lead-password" }),
+        ADMIN_ACCOUNT_COOKIE_NAME,
+    )
+    .await;
+    let member_cookie = admin_login(
+        &client,
+        addr,
+        serde_json::json!({ "username": "ops-member", "password": "ops-member-password" }),
+        ADMIN_ACCOUNT_COOKIE_NAME,
+    )
+    .await;
+
+    let self_demote = client
+        .patch(format!("http://{addr}/api/admin/accounts/{lead_id}"))
+        .header(reqwest::header::COOKIE, &lead_cookie)
+        .json(&serde_json::json!({ "role": "viewer" }))
+        .send()
+        .await
+        .expect("self demote");
+    assert_eq!(self_demote.status(), StatusCode::CONFLICT);
+
+    let promoted = client
+        .patch(format!("http://{addr}/api/admin/accounts/{member_id}"))
+        .header(reqwest::header::COOKIE, &lead_cookie)
+        .json(&serde_json::json!({ "role": "operator", "displayName": "Ops Member" }))
+        .send()
+        .await
+        .expect("promote member");
+    assert_eq!(promoted.status(), StatusCode::OK);
+    let promoted: serde_json::Value = promoted.json().await.expect("decode promoted");
+    assert_eq!(promoted["role"], "operator");
+    assert_eq!(promoted["activeSessions"].as_i64(), Some(1));
+    let tag = client
+        .post(format!("http://{addr}/api/user-tags"))
+        .header(reqwest::header::COOKIE, &member_cookie)
+        .json(&tag_body("promoted_tag"))
+        .send()
+        .await
+        .expect("create tag after promotion");
+    assert_eq!(tag.status(), StatusCode::OK);
+
+    let disabled = client
+        .patch(format!("http://{addr}/api/admin/accounts/{member_id}"))
+        .header(reqwest::header::COOKIE, &lead_cookie)
+        .json(&serde_json::json!({ "disabled": true }))
+        .send()
+        .await
+        .expect("disable member");
+    assert_eq!(disabled.status(), StatusCode::OK);
+    let after_disable = client
+        .get(format!("http://{addr}/api/settings"))
+        .header(reqwest::header::COOKIE, &member_cookie)
+        .send()
+        .await
+        .expect("read settings after disable");
+    assert_eq!(after_disable.status(), StatusCode::FORBIDDEN);
+    let disabled_login = client
+        .post(format!("http://{addr}/api/admin/login"))
+        .json(&serde_json::json!({ "username": "ops-member", "password": "ops-member-password" }))
+        .send()
+        .await
+        .expect("login disabled account");
+    assert_eq!(disabled_login.status(), StatusCode::UNAUTHORIZED);
+
+    let reset = client
+        .put(format!("http://{addr}/api/admin/accounts/{lead_id}/password"))
+        .header(reqwest::header::COOKIE, &builtin)
+        .json(&serde_json::json!({ "password": "rotated-lead-password" }))
+        .send()
+        .await
+        .expect("reset lead password");
+    assert_eq!(reset.status(), StatusCode::NO_CONTENT);
+    let after_reset = client
+        .get(format!("http://{addr}/api/admin/accounts"))
+        .header(reqwest::header::COOKIE, &lead_cookie)
+        .send()
+        .await
+        .expect("list accounts after reset");
+    assert_eq!(after_reset.status(), StatusCode::FORBIDDEN);
+
+    let relogin = admin_login(
+        &client,
+        addr,
+        serde_json::json!({ "username": "ops-lead", "password": "rotated-lead-password" }),
+        ADMIN_ACCOUNT_COOKIE_NAME,
+    )
+    .await;
+    let logout = client
+        .post(format!("http://{addr}/api/admin/logout"))
+        .header(reqwest::header::COOKIE, &relogin)
+        .send()
+        .await
+        .expect("logout");
+    assert_eq!(logout.status(), StatusCode::NO_CONTENT);
+    let after_logout = client
+        .get(format!("http://{addr}/api/settings"))
+        .header(reqwest::header::COOKIE, &relogin)
+        .send()
+        .await
+        .expect("read settings after logout");
+    assert_eq!(after_logout.status(), StatusCode::FORBIDDEN);
+
+    let deleted = client
+        .delete(format!("http://{addr}/api/admin/accounts/{member_id}"))
+        .header(reqwest::header::COOKIE, &builtin)
+        .send()
+        .await
+        .expect("delete member");
+    assert_eq!(deleted.status(), StatusCode::NO_CONTENT);
+    let missing = client
+        .delete(format!("http://{addr}/api/admin/accounts/{member_id}/sessions"))
+        .header(reqwest::header::COOKIE, &builtin)
+        .send()
+        .await
+        .expect("revoke deleted member sessions");
+    assert_eq!(missing.status(), StatusCode::NOT_FOUND);
+
+    let _ = std::fs::remove_file(db_path);
+}
@@ -0,0 +1,300 @@
+use super::*;
+use super::core_support_and_parsing::*;
+use super::linuxdo_oauth_and_admin_keys::find_cookie_pair;
+
+const OWNER_PASSWORD: &str = "builtin-owner-password";
+
+async fn spawn_admin_api_tokens_server(proxy: TavilyProxy) -> SocketAddr {
+    let state = Arc::new(AppState {
+        proxy,
+        static_dir: None,
+        forward_auth: ForwardAuthConfig::new(None, None, None, None),
+        forward_auth_enabled: false,
+        builtin_admin: BuiltinAdminAuth::new(true, Some(OWNER_PASSWORD.to_string()), None),
+        admin_passkey: AdminPasskeyOptions::disabled(),
+        linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
+        linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
+        ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
+        dev_open_admin: false,
+        usage_base: "http://127.0.0.1:58088".to_string(),
+        api_key_ip_geo_origin: "https://api.country.is".to_string(),
+        dashboard_overview_cache: new_dashboard_overview_cache(),
+    });
+
+    let app = Router::new()
+        .route("/api/admin/login", post(post_admin_login))
+        .route(
+            "/api/admin/api-tokens",
+            get(list_admin_api_tokens).post(create_admin_api_token),
+        )
+        .route("/api/admin/api-tokens/:id", delete(revoke_admin_api_token))
+        .route("/api/settings", get(get_settings))
+        .route("/api/settings/system", put(put_system_settings))
+        .route("/api/user-tags", get(list_user_tags).post(create_user_tag))
+        .route("/api/admin/audit", get(get_admin_audit_entries))
+        .route("/metrics", get(get_prometheus_metrics))
+        .route("/api/debug/admin", get(get_admin_debug))
+        .route("/api/admin/ha/baseline", get(get_admin_ha_baseline))
+        .route("/api/admin/ha/events", get(get_admin_ha_events))
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            admin_rbac_http_layer,
+        ))
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            admin_audit_http_layer,
+        ))
+        .with_state(state);
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .await
+        .unwrap();
+    });
+    addr
+}
+
+async fn create_token(
+    client: &Client,
+    addr: SocketAddr,
+    owner_cookie: &str,
+    body: serde_json::Value,
+) -> reqwest::Response {
+    client
+        .post(format!("http://{addr}/api/admin/api-tokens"))
+        .header(reqwest::header::COOKIE, owner_cookie)
+        .json(&body)
+        .send()
+        .await
+        .expect("create admin API token")
+}
+
+#[tokio::test]
+async fn admin_api_tokens_enforce_scopes_ip_allowlist_and_revocation() {
+    let db_path = temp_db_path("admin-api-tokens");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+        .await
+        .expect("create proxy");
+    let addr = spawn_admin_api_tokens_server(proxy).await;
+    let client = Client::new();
+
+    let login = client
+        .post(format!("http://{addr}/api/admin/login"))
+        .json(&serde_json::json!({ "password": OWNER_PASSWORD }))
+        .send()
+        .await
+        .expect("owner login");
+    assert_eq!(login.status(), StatusCode::OK);
+    let owner = find_cookie_pair(login.headers(), BUILTIN_ADMIN_COOKIE_NAME).expect("owner cookie");
+
+    for invalid in [
+        serde_json::json!({ "name": "ci", "scopes": ["keys:admin"] }),
+        serde_json::json!({ "name": "ci", "scopes": [] }),
+        serde_json::json!({ "name": " ", "scopes": ["keys:read"] }),
+        serde_json::json!({ "name": "ci", "scopes": ["keys:read"], "ipAllowlist": ["10.0.0.0/99"] }),
+        serde_json::json!({ "name": "ci", "scopes": ["keys:read"], "expiresAt": 1 }),
+    ] {
+        let resp = create_token(&client, addr, &owner, invalid.clone()).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST, "{invalid}");
+    }
+
+    let created = create_token(
+        &client,
+        addr,
+        &owner,
+        serde_json::json!({
+            "name": "provisioning",
+            "scopes": ["users:write"],
+            "ipAllowlist": ["10.1.0.0/16"],
+        }),
+    )
+    .await;
+    assert_eq!(created.status(), StatusCode::CREATED);
+    let created: serde_json::Value = created.json().await.expect("decode created token");
+    let token = created["token"].as_str().expect("token value").to_string();
+    let token_id = created["apiToken"]["id"].as_str().expect("token id").to_string();
+    assert!(token.starts_with("tha-"));
+    assert_eq!(created["apiToken"]["scopes"], serde_json::json!(["users:write"]));
+    assert_eq!(created["apiToken"]["createdBy"], "builtin-admin");
+    assert_eq!(created["apiToken"]["status"], "active");
+
+    let status = |method: reqwest::Method, path: &str, ip: &str, body: Option<serde_json::Value>| {
+        let mut request = client
+            .request(method, format!("http://{addr}{path}"))
+            .bearer_auth(&token)
+            .header("x-real-ip", ip.to_string());
+        if let Some(body) = body {
+            request = request.json(&body);
+        }
+        async move { request.send().await.expect("send request").status() }
+    };
+    let tag = serde_json::json!({
+        "name": "token_tag",
+        "displayName": "token_tag",
+        "effectKind": "quota_delta",
+        "businessCalls1hDelta": 0,
+        "dailyCreditsDelta": 0,
+        "monthlyCreditsDelta": 0,
+    });
+
+    assert_eq!(
+        status(reqwest::Method::GET, "/api/user-tags", "10.1.2.3", None).await,
+        StatusCode::OK
+    );
+    assert_eq!(
+        status(reqwest::Method::POST, "/api/user-tags", "10.1.2.3", Some(tag.clone())).await,
+        StatusCode::OK
+    );
+    assert_eq!(
+        status(reqwest::Method::GET, "/api/user-tags", "10.2.0.1", None).await,
+        StatusCode::FORBIDDEN
+    );
+    assert_eq!(
+        status(reqwest::Method::GET, "/api/settings", "10.1.2.3", None).await,
+        StatusCode::FORBIDDEN
+    );
+    assert_eq!(
+        status(reqwest::Method::GET, "/api/admin/api-tokens", "10.1.2.3", None).await,
+        StatusCode::FORBIDDEN
+    );
+    assert_eq!(
+        status(reqwest::Method::GET, "/api/admin/audit", "10.1.2.3", None).await,
+        StatusCode::FORBIDDEN
+    );
+
+    let tokens: serde_json::Value = client
+        .get(format!("http://{addr}/api/admin/api-tokens"))
+        .header(reqwest::header::COOKIE, &owner)
+        .send()
+        .await
+        .expect("list tokens")
+        .json()
+        .await
+        .expect("decode tokens");
+    let listed = &tokens[0];
+    assert_eq!(listed["id"], token_id.as_str());
+    assert_eq!(listed["lastUsedIp"], "10.1.2.3");
+    assert!(listed["lastUsedAt"].as_i64().is_some());
+    assert!(listed.get("token").is_none());
+
+    let audit: serde_json::Value = client
+        .get(format!("http://{addr}/api/admin/audit?kind=mutation&route=user-tags"))
+        .header(reqwest::header::COOKIE, &owner)
+        .send()
+        .await
+        .expect("list audit entries")
+        .json()
+        .await
+        .expect("decode audit entries");
+    assert!(
+        audit["items"].as_array().expect("audit items").iter().any(|entry| {
+            entry["actorKind"] == "api_token"
+                && entry["actorId"] == token_id.as_str()
+                && entry["actorName"] == "provisioning"
+        }),
+        "{audit}"
+    );
+
+    let revoked = client
+        .delete(format!("http://{addr}/api/admin/api-tokens/{token_id}"))
+        .header(reqwest::header::COOKIE, &owner)
+        .send()
+        .await
+        .expect("revoke token");
+    assert_eq!(revoked.status(), StatusCode::OK);
+    let revoked: serde_json::Value = revoked.json().await.expect("decode revoked token");
+    assert_eq!(revoked["status"], "revoked");
+    assert_eq!(
+        status(reqwest::Method::GET, "/api/user-tags", "10.1.2.3", None).await,
+        StatusCode::FORBIDDEN
+    );
+
+    let reader = create_token(
+        &client,
+        addr,
+        &owner,
+        serde_json::json!({ "name": "settings-reader", "scopes": ["settings:read"] }),
+    )
+    .await;
+    assert_eq!(reader.status(), StatusCode::CREATED);
+    let reader: serde_json::Value = reader.json().await.expect("decode reader token");
+    let reader = reader["token"].as_str().expect("reader token").to_string();
+    let settings = client
+        .get(format!("http://{addr}/api/settings"))
+        .bearer_auth(&reader)
+        .send()
+        .await
+        .expect("read settings");
+    assert_eq!(settings.status(), StatusCode::OK);
+    let write = client
+        .put(format!("http://{addr}/api/settings/system"))
+        .bearer_auth(&reader)
+        .json(&serde_json::json!({ "requestRateLimit": 10 }))
+        .send()
+        .await
+        .expect("write settings");
+    assert_eq!(write.status(), StatusCode::FORBIDDEN);
+    for path in [
+        "/metrics",
+        "/api/debug/admin",
+        "/api/admin/ha/baseline",
+        "/api/admin/ha/events",
+    ] {
+        let resp = client
+            .get(format!("http://{addr}{path}"))
+            .bearer_auth(&reader)
+            .send()
+            .await
+            .expect("call route outside token scopes");
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN, "{path}");
+    }
+
+    let stats = create_token(
+        &client,
+        addr,
+        &owner,
+        serde_json::json!({ "name": "metrics-scraper", "scopes": ["stats:read"] }),
+    )
+    .await;
+    let stats: serde_json::Value = stats.json().await.expect("decode stats token");
+    let stats = stats["token"].as_str().expect("stats token").to_string();
+    let metrics = client
+        .get(format!("http://{addr}/metrics"))
+        .bearer_auth(&stats)
+        .send()
+        .await
+        .expect("scrape metrics");
+    assert_eq!(metrics.status(), StatusCode::OK);
+
+    let settings_writer = create_token(
+        &client,
+        addr,
+        &owner,
+        serde_json::json!({ "name": "ha-exporter", "scopes": ["settings:write"] }),
+    )
+    .await;
+    let settings_writer: serde_json::Value = settings_writer
+        .json()
+        .await
+        .expect("decode settings writer token");
+    let settings_writer = settings_writer["token"]
+        .as_str()
+        .expect("settings writer token")
+        .to_string();
+    let baseline = client
+        .get(format!("http://{addr}/api/admin/ha/baseline"))
+        .bearer_auth(&settings_writer)
+        .send()
+        .await
+        .expect("export HA baseline");
+    assert_ne!(baseline.status(), StatusCode::FORBIDDEN);
+
+    let _ = std::fs::remove_file(db_path);
+}
@@ -0,0 +1,136 @@
+use super::*;
+use super::core_support_and_parsing::*;
+
+    async fn spawn_admin_audit_server(proxy: TavilyProxy) -> SocketAddr {
+        let state = Arc::new(AppState {
+            proxy,
+            static_dir: None,
+            forward_auth: ForwardAuthConfig::new(None, None, None, None),
+            forward_auth_enabled: false,
+            builtin_admin: BuiltinAdminAuth::new(false, None, None),
+            admin_passkey: AdminPasskeyOptions::disabled(),
+            linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
+            linuxdo_credit: LinuxDoCreditOptions::disabled(),
+            oidc: OidcOptions::disabled(),
+            ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
+            dev_open_admin: true,
+            usage_base: "http://127.0.0.1:58088".to_string(),
+            api_key_ip_geo_origin: "https://api.country.is".to_string(),
+            dashboard_overview_cache: new_dashboard_overview_cache(),
+        });
+
+        let app = Router::new()
+            .route("/api/settings", get(get_settings))
+            .route("/api/settings/system", put(put_system_settings))
+            .route("/api/keys/:id/secret", get(get_api_key_secret))
+            .route("/api/admin/audit", get(get_admin_audit_entries))
+            .route("/api/admin/audit/verify", get(get_admin_audit_verification))
+            .layer(axum::middleware::from_fn_with_state(
+                state.clone(),
+                admin_audit_http_layer,
+            ))
+            .with_state(state);
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(
+                listener,
+                app.into_make_service_with_connect_info::<SocketAddr>(),
+            )
+            .await
+            .unwrap();
+        });
+        addr
+    }
+
+    #[tokio::test]
+    async fn admin_audit_layer_records_mutations_and_secret_reads() {
+        let db_path = temp_db_path("admin-audit-layer");
+        let db_str = db_path.to_string_lossy().to_string();
+        let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+            .await
+            .expect("create proxy");
+        let before_limit = proxy
+            .get_system_settings()
+            .await
+            .expect("read settings")
+            .request_rate_limit;
+        let addr = spawn_admin_audit_server(proxy).await;
+        let client = Client::new();
+
+        let updated = client
+            .put(format!("http://{addr}/api/settings/system"))
+            .json(&serde_json::json!({
+                "requestRateLimit": before_limit + 5,
+                "mcpSessionAffinityKeyCount": 5,
+                "rebalanceMcpEnabled": false,
+                "rebalanceMcpSessionPercent": 100,
+            }))
+            .send()
+            .await
+            .expect("update system settings");
+        assert_eq!(updated.status(), StatusCode::OK);
+        let secret = client
+            .get(format!("http://{addr}/api/keys/missing/secret"))
+            .send()
+            .await
+            .expect("read key secret");
+        assert_eq!(secret.status(), StatusCode::NOT_FOUND);
+        let settings = client
+            .get(format!("http://{addr}/api/settings"))
+            .send()
+            .await
+            .expect("read settings");
+        assert_eq!(settings.status(), StatusCode::OK);
+
+        let page = client
+            .get(format!("http://{addr}/api/admin/audit"))
+            .send()
+            .await
+            .expect("list audit entries")
+            .json::<serde_json::Value>()
+            .await
+            .expect("decode audit entries");
+        assert_eq!(page["total"].as_i64(), Some(2), "{page}");
+        let read = &page["items"][0];
+        assert_eq!(read["kind"], "sensitive_read");
+        assert_eq!(read["route"], "/api/keys/:id/secret");
+        assert_eq!(read["path"], "/api/keys/missing/secret");
+        assert_eq!(read["status"].as_i64(), Some(404));
+        let mutation = &page["items"][1];
+        assert_eq!(mutation["kind"], "mutation");
+        assert_eq!(mutation["actorKind"], "dev_open");
+        assert_eq!(mutation["clientIp"], "127.0.0.1");
+        assert_eq!(
+            mutation["before"],
+            serde_json::json!({ "requestRateLimit": before_limit })
+        );
+        assert_eq!(
+            mutation["after"],
+            serde_json::json!({ "requestRateLimit": before_limit + 5 })
+        );
+
+        let filtered = client
+            .get(format!("http://{addr}/api/admin/audit?kind=mutation&route=settings"))
+            .send()
+            .await
+            .expect("filter audit entries")
+            .json::<serde_json::Value>()
+            .await
+            .expect("decode filtered entries");
+        assert_eq!(filtered["total"].as_i64(), Some(1));
+
+        let verification = client
+            .get(format!("http://{addr}/api/admin/audit/verify"))
+            .send()
+            .await
+            .expect("verify audit chain")
+            .json::<serde_json::Value>()
+            .await
+            .expect("decode verification");
+        assert_eq!(verification["ok"], true);
+        assert_eq!(verification["entries"].as_i64(), Some(2));
+
+        let _ = std::fs::remove_file(db_path);
+    }
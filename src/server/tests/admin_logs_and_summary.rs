@@ -90,6 +90,7 @@ use super::upstream_support_and_manual_jobs::*;
                 client_ip_source: None,
                 client_ip_trusted: false,
                 ip_headers: Vec::new(),
+                trace_id: None,
             },
             false,
         );
@@ -2246,6 +2247,7 @@ use super::upstream_support_and_manual_jobs::*;
                 None,
                 None,
                 None,
+                None,
                 RequestLogsCursorDirection::Older,
                 20,
             )
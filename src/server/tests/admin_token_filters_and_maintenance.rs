@@ -204,6 +204,7 @@ use super::upstream_support_and_manual_jobs::*;
             admin_passkey: AdminPasskeyOptions::disabled(),
             linuxdo_oauth: linuxdo_oauth_options_for_test(),
             linuxdo_credit: LinuxDoCreditOptions::disabled(),
+            oidc: OidcOptions::disabled(),
             ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
             dev_open_admin: false,
             usage_base: "http://127.0.0.1:58088".to_string(),
@@ -274,6 +275,7 @@ use super::upstream_support_and_manual_jobs::*;
             admin_passkey: AdminPasskeyOptions::disabled(),
             linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
             linuxdo_credit: LinuxDoCreditOptions::disabled(),
+            oidc: OidcOptions::disabled(),
             ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
             dev_open_admin: true,
             usage_base: format!("http://{upstream_addr}"),
@@ -382,6 +384,7 @@ use super::upstream_support_and_manual_jobs::*;
             admin_passkey: AdminPasskeyOptions::disabled(),
             linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
             linuxdo_credit: LinuxDoCreditOptions::disabled(),
+            oidc: OidcOptions::disabled(),
             ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
             dev_open_admin: true,
             usage_base: format!("http://{upstream_addr}"),
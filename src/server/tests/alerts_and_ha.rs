@@ -1202,6 +1202,7 @@ async fn ha_standby_sync_does_not_repeat_zero_watermark_baseline() {
             admin_passkey: AdminPasskeyOptions::disabled(),
         linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
         linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
         ha,
         dev_open_admin: true,
         usage_base: "http://127.0.0.1:58088".to_string(),
@@ -1368,6 +1369,7 @@ async fn ha_standby_sync_recovers_after_invalid_baseline_stream() {
             admin_passkey: AdminPasskeyOptions::disabled(),
         linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
         linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
         ha,
         dev_open_admin: true,
         usage_base: "http://127.0.0.1:58088".to_string(),
@@ -1637,6 +1639,7 @@ async fn ha_standby_sync_recovers_after_invalid_events_stream() {
             admin_passkey: AdminPasskeyOptions::disabled(),
         linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
         linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
         ha,
         dev_open_admin: true,
         usage_base: "http://127.0.0.1:58088".to_string(),
@@ -76,6 +76,7 @@ async fn reconciliation_low_pressure_recovery_runs_shadow_fixture_despite_prior_
         admin_passkey: AdminPasskeyOptions::disabled(),
         linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
         linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
         ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
         dev_open_admin: false,
         usage_base: format!("http://{address}"),
@@ -335,6 +336,7 @@ async fn ha_gc_real_worker_wakes_an_eligible_channel_before_a_legacy_defer() {
         admin_passkey: AdminPasskeyOptions::disabled(),
         linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
         linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
         ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
         dev_open_admin: false,
         usage_base: "http://127.0.0.1:58088".to_string(),
@@ -524,6 +526,7 @@ async fn ha_gc_productive_continuation_lock_defers_to_stale_reaper() {
         admin_passkey: AdminPasskeyOptions::disabled(),
         linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
         linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
         ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
         dev_open_admin: false,
         usage_base: "http://127.0.0.1:58088".to_string(),
@@ -659,6 +662,7 @@ async fn request_logs_gc_handoff_preserves_error_and_defers_to_stale_reaper() {
         admin_passkey: AdminPasskeyOptions::disabled(),
         linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
         linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
         ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
         dev_open_admin: false,
         usage_base: "http://127.0.0.1:58088".to_string(),
@@ -1271,6 +1275,7 @@ async fn compute_signatures_tracks_recent_alert_summary_changes() {
             admin_passkey: AdminPasskeyOptions::disabled(),
         linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
         linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
         ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
         dev_open_admin: false,
         usage_base: "http://127.0.0.1:58088".to_string(),
@@ -1375,6 +1380,8 @@ async fn compute_signatures_tracks_recent_alert_summary_changes() {
                 0
             ),
             (tavily_hikari::ALERT_TYPE_JOB_FAILED.to_string(), 0),
+            (tavily_hikari::ALERT_TYPE_TOKEN_EXPIRING.to_string(), 0),
+            (tavily_hikari::ALERT_TYPE_THRESHOLD_RULE.to_string(), 0),
         ]
     );
     assert_ne!(before_sig, after_sig);
@@ -21,6 +21,7 @@ async fn standby_server_startup_does_not_spawn_business_scheduled_jobs() {
             admin_passkey: AdminPasskeyOptions::disabled(),
         linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
         linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
         ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig {
             mode: tavily_hikari::HaMode::ActiveStandby,
             node_id: "node-standby-startup".to_string(),
@@ -346,6 +347,7 @@ async fn persist_ha_status_snapshot_skips_unneeded_pressure_rebuild_for_serving_
             admin_passkey: AdminPasskeyOptions::disabled(),
         linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
         linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
         ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig {
             mode: tavily_hikari::HaMode::ActiveStandby,
             node_id: "node-post-ready-rebuild".to_string(),
@@ -489,6 +491,7 @@ async fn post_ready_serving_tasks_run_once_per_writable_tenure() {
         admin_passkey: AdminPasskeyOptions::disabled(),
         linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
         linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
         ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig {
             mode: tavily_hikari::HaMode::ActiveStandby,
             node_id: "node-post-ready-writable-tenure".to_string(),
@@ -40,6 +40,7 @@ async fn dual_active_peer_sync_does_not_mark_success_when_no_peer_is_reached() {
             admin_passkey: AdminPasskeyOptions::disabled(),
         linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
         linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
         ha,
         dev_open_admin: true,
         usage_base: "http://127.0.0.1:58088".to_string(),
@@ -332,6 +333,7 @@ async fn ha_standby_sync_resets_runtime_baseline_after_foreign_key_gap() {
             admin_passkey: AdminPasskeyOptions::disabled(),
         linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
         linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
         ha,
         dev_open_admin: true,
         usage_base: "http://127.0.0.1:58088".to_string(),
@@ -527,6 +529,7 @@ async fn dual_active_peer_runtime_baseline_does_not_delete_local_rows() {
             admin_passkey: AdminPasskeyOptions::disabled(),
         linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
         linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
         ha,
         dev_open_admin: true,
         usage_base: "http://127.0.0.1:58088".to_string(),
@@ -776,6 +779,7 @@ async fn dual_active_peer_billing_sync_namespaces_peer_log_ids() {
             admin_passkey: AdminPasskeyOptions::disabled(),
         linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
         linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
         ha,
         dev_open_admin: true,
         usage_base: "http://127.0.0.1:58088".to_string(),
@@ -1058,6 +1062,7 @@ async fn dual_active_peer_runtime_sync_merges_mutable_quota_counter_deltas() {
             admin_passkey: AdminPasskeyOptions::disabled(),
         linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
         linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
         ha,
         dev_open_admin: true,
         usage_base: "http://127.0.0.1:58088".to_string(),
@@ -375,6 +375,7 @@ colo=LAX
             admin_passkey: AdminPasskeyOptions::disabled(),
             linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
             linuxdo_credit: LinuxDoCreditOptions::disabled(),
+            oidc: OidcOptions::disabled(),
             ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
             dev_open_admin: false,
             usage_base: "http://127.0.0.1:58088".to_string(),
@@ -479,6 +480,7 @@ colo=LAX
             admin_passkey: AdminPasskeyOptions::disabled(),
             linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
             linuxdo_credit: LinuxDoCreditOptions::disabled(),
+            oidc: OidcOptions::disabled(),
             ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
             dev_open_admin: false,
             usage_base: "http://127.0.0.1:58088".to_string(),
@@ -578,6 +580,7 @@ colo=LAX
             admin_passkey: AdminPasskeyOptions::disabled(),
             linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
             linuxdo_credit: LinuxDoCreditOptions::disabled(),
+            oidc: OidcOptions::disabled(),
             ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
             dev_open_admin: false,
             usage_base: "http://127.0.0.1:58088".to_string(),
@@ -670,6 +673,7 @@ colo=LAX
             admin_passkey: AdminPasskeyOptions::disabled(),
             linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
             linuxdo_credit: LinuxDoCreditOptions::disabled(),
+            oidc: OidcOptions::disabled(),
             ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
             dev_open_admin: false,
             usage_base: "http://127.0.0.1:58088".to_string(),
@@ -2655,6 +2659,7 @@ colo=LAX
             admin_passkey: AdminPasskeyOptions::disabled(),
             linuxdo_oauth: linuxdo_oauth_options_for_test(),
             linuxdo_credit: LinuxDoCreditOptions::disabled(),
+            oidc: OidcOptions::disabled(),
             ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
             dev_open_admin: false,
             usage_base: "http://127.0.0.1:58088".to_string(),
@@ -3135,6 +3140,7 @@ colo=LAX
             admin_passkey: AdminPasskeyOptions::disabled(),
             linuxdo_oauth: oauth_options,
             linuxdo_credit: LinuxDoCreditOptions::disabled(),
+            oidc: OidcOptions::disabled(),
             ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
             dev_open_admin: false,
             usage_base: "http://127.0.0.1:58088".to_string(),
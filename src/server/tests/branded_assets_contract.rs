@@ -96,6 +96,7 @@ async fn branded_assets_are_served_from_assets_contract_and_favicon_remains_avai
             admin_passkey: AdminPasskeyOptions::disabled(),
         linuxdo_oauth: linuxdo_oauth_options_for_test(),
         linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
         ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
         dev_open_admin: false,
         usage_base: "http://127.0.0.1:58088".to_string(),
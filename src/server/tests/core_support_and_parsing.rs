@@ -2589,3 +2589,44 @@
         });
         (addr, hits)
     }
+
+    #[test]
+    fn extract_token_from_query_none_or_empty() {
+        let (q, t) = extract_token_from_query(None);
+        assert_eq!(q, None);
+        assert_eq!(t, None);
+
+        let (q, t) = extract_token_from_query(Some(""));
+        assert_eq!(q, None);
+        assert_eq!(t, None);
+    }
+
+    #[test]
+    fn extract_token_from_query_single_param_case_insensitive() {
+        let (q, t) = extract_token_from_query(Some("TavilyApiKey=th-abc-xyz"));
+        assert_eq!(q, None, "no other params → query should be None");
+        assert_eq!(t.as_deref(), Some("th-abc-xyz"));
+    }
+
+    #[test]
+    fn extract_token_from_query_strips_param_and_preserves_others() {
+        let (q, t) = extract_token_from_query(Some("foo=1&tavilyApiKey=th-abc-xyz&bar=2"));
+        assert_eq!(t.as_deref(), Some("th-abc-xyz"));
+        // Order should be preserved for non-auth params.
+        assert_eq!(q.as_deref(), Some("foo=1&bar=2"));
+    }
+
+    #[test]
+    fn extract_token_from_query_uses_first_non_empty_token() {
+        let (q, t) =
+            extract_token_from_query(Some("tavilyApiKey=&tavilyApiKey=th-abc-xyz&foo=bar"));
+        assert_eq!(t.as_deref(), Some("th-abc-xyz"));
+        assert_eq!(q.as_deref(), Some("foo=bar"));
+    }
+
+    #[test]
+    fn extract_token_from_query_ignores_additional_token_params() {
+        let (q, t) = extract_token_from_query(Some("tavilyApiKey=th-1&tavilyApiKey=th-2&foo=bar"));
+        assert_eq!(t.as_deref(), Some("th-1"));
+        assert_eq!(q.as_deref(), Some("foo=bar"));
+    }
@@ -0,0 +1,81 @@
+use super::*;
+use super::core_support_and_parsing::*;
+use super::upstream_support_and_manual_jobs::*;
+
+    #[tokio::test]
+    async fn admin_key_group_routing_round_trips_and_rejects_invalid_payloads() {
+        let db_path = temp_db_path("admin-key-group-routing");
+        let db_str = db_path.to_string_lossy().to_string();
+        let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+            .await
+            .expect("proxy created");
+        let forward_auth = ForwardAuthConfig::new(
+            Some(HeaderName::from_static("x-forward-user")),
+            Some("admin".to_string()),
+            None,
+            None,
+        );
+        let addr = spawn_keys_admin_server(proxy, forward_auth, false).await;
+        let client = Client::new();
+        let url = format!("http://{addr}/api/keys/group-routing");
+
+        let forbidden = client
+            .get(&url)
+            .send()
+            .await
+            .expect("anonymous routing request");
+        assert_eq!(forbidden.status(), reqwest::StatusCode::FORBIDDEN);
+
+        let saved = client
+            .put(&url)
+            .header("x-forward-user", "admin")
+            .json(&serde_json::json!({
+                "tiers": [
+                    { "group": "overflow", "priority": -10 },
+                    { "group": "primary", "priority": 20 },
+                ],
+                "bindings": [
+                    { "kind": "user_tag", "value": "vip", "keyGroups": ["primary", "primary"] },
+                ],
+            }))
+            .send()
+            .await
+            .expect("save routing");
+        assert_eq!(saved.status(), reqwest::StatusCode::OK);
+
+        let loaded: serde_json::Value = client
+            .get(&url)
+            .header("x-forward-user", "admin")
+            .send()
+            .await
+            .expect("load routing")
+            .json()
+            .await
+            .expect("routing json");
+        assert_eq!(
+            loaded,
+            serde_json::json!({
+                "tiers": [
+                    { "group": "primary", "priority": 20 },
+                    { "group": "overflow", "priority": -10 },
+                ],
+                "bindings": [
+                    { "kind": "user_tag", "value": "vip", "keyGroups": ["primary"] },
+                ],
+            })
+        );
+
+        let rejected = client
+            .put(&url)
+            .header("x-forward-user", "admin")
+            .json(&serde_json::json!({
+                "tiers": [{ "group": "primary", "priority": 5000 }],
+                "bindings": [],
+            }))
+            .send()
+            .await
+            .expect("save invalid routing");
+        assert_eq!(rejected.status(), reqwest::StatusCode::BAD_REQUEST);
+
+        let _ = std::fs::remove_file(db_path);
+    }
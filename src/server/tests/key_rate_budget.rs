@@ -0,0 +1,111 @@
+use super::*;
+use super::core_support_and_parsing::*;
+use super::upstream_support_and_manual_jobs::*;
+
+    #[tokio::test]
+    async fn admin_key_rate_budget_updates_and_surfaces_in_key_metrics() {
+        let db_path = temp_db_path("admin-key-rate-budget");
+        let db_str = db_path.to_string_lossy().to_string();
+        let proxy = TavilyProxy::with_endpoint(
+            vec!["tvly-rate-budget".to_string()],
+            DEFAULT_UPSTREAM,
+            &db_str,
+        )
+        .await
+        .expect("proxy created");
+        let key_id = proxy
+            .list_api_key_metrics()
+            .await
+            .expect("list api key metrics")
+            .into_iter()
+            .next()
+            .expect("seeded key")
+            .id;
+        let forward_auth = ForwardAuthConfig::new(
+            Some(HeaderName::from_static("x-forward-user")),
+            Some("admin".to_string()),
+            None,
+            None,
+        );
+        let addr = spawn_keys_admin_server(proxy, forward_auth, false).await;
+        let client = Client::new();
+        let url = format!("http://{addr}/api/keys/{key_id}/rate-budget");
+
+        let forbidden = client
+            .put(&url)
+            .json(&serde_json::json!({ "rpmLimit": 30 }))
+            .send()
+            .await
+            .expect("anonymous budget request");
+        assert_eq!(forbidden.status(), reqwest::StatusCode::FORBIDDEN);
+
+        let invalid = client
+            .put(&url)
+            .header("x-forward-user", "admin")
+            .json(&serde_json::json!({ "rpmLimit": 0 }))
+            .send()
+            .await
+            .expect("invalid budget request");
+        assert_eq!(invalid.status(), reqwest::StatusCode::BAD_REQUEST);
+
+        let missing = client
+            .put(format!("http://{addr}/api/keys/missing-key/rate-budget"))
+            .header("x-forward-user", "admin")
+            .json(&serde_json::json!({ "rpmLimit": 30 }))
+            .send()
+            .await
+            .expect("missing key budget request");
+        assert_eq!(missing.status(), reqwest::StatusCode::NOT_FOUND);
+
+        let saved = client
+            .put(&url)
+            .header("x-forward-user", "admin")
+            .json(&serde_json::json!({ "rpmLimit": 30 }))
+            .send()
+            .await
+            .expect("save budget");
+        assert_eq!(saved.status(), reqwest::StatusCode::NO_CONTENT);
+
+        let metrics_url = format!("http://{addr}/api/keys/{key_id}/metrics?period=day");
+        let metrics: serde_json::Value = client
+            .get(&metrics_url)
+            .header("x-forward-user", "admin")
+            .send()
+            .await
+            .expect("key metrics")
+            .json()
+            .await
+            .expect("key metrics json");
+        assert_eq!(
+            metrics["key_rate_budgets"],
+            serde_json::json!([{
+                "keyId": key_id,
+                "rpmLimit": 30,
+                "usedLastMinute": 0,
+                "queued": 0,
+                "delayedTotal": 0,
+                "rejectedTotal": 0,
+            }])
+        );
+
+        let cleared = client
+            .put(&url)
+            .header("x-forward-user", "admin")
+            .json(&serde_json::json!({ "rpmLimit": null }))
+            .send()
+            .await
+            .expect("clear budget");
+        assert_eq!(cleared.status(), reqwest::StatusCode::NO_CONTENT);
+        let metrics: serde_json::Value = client
+            .get(&metrics_url)
+            .header("x-forward-user", "admin")
+            .send()
+            .await
+            .expect("key metrics after clear")
+            .json()
+            .await
+            .expect("key metrics json after clear");
+        assert_eq!(metrics["key_rate_budgets"], serde_json::json!([]));
+
+        let _ = std::fs::remove_file(db_path);
+    }
@@ -433,6 +433,7 @@ use tavily_hikari::UpstreamProjectIdMode;
             admin_passkey: AdminPasskeyOptions::disabled(),
             linuxdo_oauth: oauth_options,
             linuxdo_credit: LinuxDoCreditOptions::disabled(),
+            oidc: OidcOptions::disabled(),
             ha,
             dev_open_admin: false,
             usage_base: "http://127.0.0.1:58088".to_string(),
@@ -703,6 +704,7 @@ use tavily_hikari::UpstreamProjectIdMode;
             admin_passkey: AdminPasskeyOptions::disabled(),
             linuxdo_oauth: oauth_options,
             linuxdo_credit: LinuxDoCreditOptions::disabled(),
+            oidc: OidcOptions::disabled(),
             ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
             dev_open_admin: false,
             usage_base: "http://127.0.0.1:58088".to_string(),
@@ -825,6 +827,7 @@ use tavily_hikari::UpstreamProjectIdMode;
             admin_passkey: AdminPasskeyOptions::disabled(),
             linuxdo_oauth: oauth_options,
             linuxdo_credit: LinuxDoCreditOptions::disabled(),
+            oidc: OidcOptions::disabled(),
             ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
             dev_open_admin: false,
             usage_base: "http://127.0.0.1:58088".to_string(),
@@ -930,6 +933,7 @@ use tavily_hikari::UpstreamProjectIdMode;
             admin_passkey: AdminPasskeyOptions::disabled(),
             linuxdo_oauth: oauth_options,
             linuxdo_credit: LinuxDoCreditOptions::disabled(),
+            oidc: OidcOptions::disabled(),
             ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
             dev_open_admin: false,
             usage_base: "http://127.0.0.1:58088".to_string(),
@@ -1051,6 +1055,7 @@ use tavily_hikari::UpstreamProjectIdMode;
             admin_passkey: AdminPasskeyOptions::disabled(),
             linuxdo_oauth: oauth_options,
             linuxdo_credit: LinuxDoCreditOptions::disabled(),
+            oidc: OidcOptions::disabled(),
             ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
             dev_open_admin: false,
             usage_base: "http://127.0.0.1:58088".to_string(),
@@ -1121,6 +1126,7 @@ use tavily_hikari::UpstreamProjectIdMode;
             admin_passkey: AdminPasskeyOptions::disabled(),
             linuxdo_oauth: oauth_options,
             linuxdo_credit: LinuxDoCreditOptions::disabled(),
+            oidc: OidcOptions::disabled(),
             ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
             dev_open_admin: false,
             usage_base: "http://127.0.0.1:58088".to_string(),
@@ -2399,6 +2399,7 @@ async fn create_legacy_request_logs_source(pool: &sqlx::SqlitePool) {
             admin_passkey: AdminPasskeyOptions::disabled(),
             linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
         linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
             ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
             dev_open_admin: false,
             usage_base: "http://127.0.0.1:58088".to_string(),
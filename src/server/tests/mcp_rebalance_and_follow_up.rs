@@ -1130,1416 +1130,6 @@ use super::upstream_support_and_manual_jobs::*;
         let _ = std::fs::remove_file(db_path);
     }
 
-    #[tokio::test]
-    async fn mcp_initialize_rebalance_enabled_uses_local_facade() {
-        let db_path = temp_db_path("mcp-rebalance-local-init");
-        let db_str = db_path.to_string_lossy().to_string();
-        let expected_api_key = "tvly-rebalance-local-init";
-        let seen: RecordedRebalanceGatewayCalls = Arc::new(Mutex::new(Vec::new()));
-        let upstream_addr =
-            spawn_rebalance_gateway_mock(expected_api_key.to_string(), seen.clone()).await;
-        let upstream = format!("http://{}", upstream_addr);
-
-        let proxy =
-            TavilyProxy::with_endpoint(vec![expected_api_key.to_string()], &upstream, &db_str)
-                .await
-                .expect("proxy created");
-        proxy
-            .set_system_settings(&tavily_hikari::SystemSettings {
-                request_rate_limit: request_rate_limit(),
-                auth_token_log_retention_days: tavily_hikari::AUTH_TOKEN_LOG_RETENTION_DAYS_DEFAULT,
-                mcp_session_affinity_key_count: 5,
-                rebalance_mcp_enabled: true,
-                rebalance_mcp_session_percent: 100,
-                api_rebalance_enabled: tavily_hikari::API_REBALANCE_ENABLED_DEFAULT,
-                api_rebalance_percent: tavily_hikari::API_REBALANCE_PERCENT_DEFAULT,
-                upstream_project_id_mode: tavily_hikari::UpstreamProjectIdMode::AccessToken,
-                upstream_project_id_fixed_value: String::new(),
-                upstream_mcp_user_agent: String::new(),
-                upstream_precise_reconciliation_enabled: true,
-                recharge_feature_enabled: true,
-                recharge_user_enabled: true,
-                admin_default_active_users_only: false,
-                user_blocked_key_base_limit: 7,
-                global_ip_limit: tavily_hikari::GLOBAL_IP_LIMIT_DEFAULT,
-                trusted_proxy_cidrs: tavily_hikari::TrustedClientIpSettings::default().trusted_proxy_cidrs,
-                trusted_client_ip_headers: tavily_hikari::TrustedClientIpSettings::default().trusted_client_ip_headers,
-                request_log_retention: tavily_hikari::default_request_log_retention_settings(),
-            })
-            .await
-            .expect("enable rebalance mcp");
-        let access_token = proxy
-            .create_access_token(Some("mcp-rebalance-local-init"))
-            .await
-            .expect("create access token");
-
-        let proxy_addr = spawn_proxy_server(proxy.clone(), upstream.clone()).await;
-        let url = format!(
-            "http://{}/mcp?tavilyApiKey={}",
-            proxy_addr, access_token.token
-        );
-        let client = Client::new();
-
-        let initialize = client
-            .post(&url)
-            .header("accept", "application/json, text/event-stream")
-            .header("content-type", "application/json")
-            .header("mcp-protocol-version", "2025-03-26")
-            .json(&json!({
-                "jsonrpc": "2.0",
-                "id": "rebalance-init",
-                "method": "initialize",
-                "params": {
-                    "protocolVersion": "2025-03-26",
-                    "capabilities": {},
-                    "clientInfo": { "name": "browser-probe", "version": "0.1.0" }
-                }
-            }))
-            .send()
-            .await
-            .expect("initialize request");
-        assert_eq!(initialize.status(), StatusCode::OK);
-        let proxy_session_id = initialize
-            .headers()
-            .get("mcp-session-id")
-            .and_then(|value| value.to_str().ok())
-            .expect("initialize response should expose mcp-session-id")
-            .to_string();
-        assert_eq!(
-            initialize
-                .headers()
-                .get("content-type")
-                .and_then(|value| value.to_str().ok())
-                .map(|value| value.starts_with("text/event-stream")),
-            Some(true),
-            "rebalance initialize should use official-style SSE transport"
-        );
-        let initialize_body = decode_sse_json_response(initialize).await;
-        assert_eq!(
-            initialize_body["result"]["serverInfo"],
-            json!({
-                "name": "tavily-mcp",
-                "version": env!("CARGO_PKG_VERSION")
-            }),
-            "rebalance initialize should use Hikari's own server version"
-        );
-        assert_eq!(
-            initialize_body["result"]["capabilities"]["prompts"]["listChanged"].as_bool(),
-            Some(false),
-            "rebalance initialize should advertise prompts/list parity"
-        );
-        assert!(
-            initialize_body["result"]["capabilities"]
-                .get("resources")
-                .is_some(),
-            "rebalance initialize should advertise resources parity"
-        );
-
-        let tools_list = client
-            .post(&url)
-            .header("accept", "application/json, text/event-stream")
-            .header("content-type", "application/json")
-            .header("mcp-protocol-version", "2025-03-26")
-            .json(&json!({
-                "jsonrpc": "2.0",
-                "id": "rebalance-tools-list",
-                "method": "tools/list"
-            }))
-            .send()
-            .await
-            .expect("tools/list request");
-        assert_eq!(tools_list.status(), StatusCode::OK);
-        assert_eq!(
-            tools_list
-                .headers()
-                .get("content-type")
-                .and_then(|value| value.to_str().ok())
-                .map(|value| value.starts_with("text/event-stream")),
-            Some(true),
-            "rebalance tools/list should use official-style SSE transport"
-        );
-        let body = decode_sse_json_response(tools_list).await;
-        assert_eq!(
-            body["result"]["tools"].as_array().map(Vec::len),
-            Some(5),
-            "rebalance tools/list should be served locally with five Tavily tools"
-        );
-        let tools = body["result"]["tools"]
-            .as_array()
-            .expect("rebalance tools/list should include a tools array");
-        let tool_by_name = tools
-            .iter()
-            .filter_map(|tool| {
-                let name = tool.get("name").and_then(Value::as_str)?;
-                Some((name, tool))
-            })
-            .collect::<std::collections::HashMap<_, _>>();
-        let prop_keys = |name: &str| {
-            let mut keys = tool_by_name[name]["inputSchema"]["properties"]
-                .as_object()
-                .expect("schema properties object")
-                .keys()
-                .cloned()
-                .collect::<Vec<_>>();
-            keys.sort();
-            keys
-        };
-        assert_eq!(
-            prop_keys("tavily_search"),
-            vec![
-                "auto_parameters",
-                "chunks_per_source",
-                "country",
-                "end_date",
-                "exact_match",
-                "exclude_domains",
-                "include_answer",
-                "include_domains",
-                "include_favicon",
-                "include_image_descriptions",
-                "include_images",
-                "include_raw_content",
-                "max_results",
-                "query",
-                "search_depth",
-                "start_date",
-                "time_range",
-                "topic",
-            ],
-            "rebalance search schema should match the official field set"
-        );
-        assert_eq!(
-            prop_keys("tavily_extract"),
-            vec![
-                "chunks_per_source",
-                "extract_depth",
-                "format",
-                "include_favicon",
-                "include_images",
-                "query",
-                "timeout",
-                "urls",
-            ],
-            "rebalance extract schema should match the official field set"
-        );
-        assert_eq!(
-            prop_keys("tavily_crawl"),
-            vec![
-                "allow_external",
-                "chunks_per_source",
-                "exclude_domains",
-                "exclude_paths",
-                "extract_depth",
-                "format",
-                "include_favicon",
-                "include_images",
-                "instructions",
-                "limit",
-                "max_breadth",
-                "max_depth",
-                "select_domains",
-                "select_paths",
-                "timeout",
-                "url",
-            ],
-            "rebalance crawl schema should match the official field set"
-        );
-        assert_eq!(
-            prop_keys("tavily_map"),
-            vec![
-                "allow_external",
-                "exclude_domains",
-                "exclude_paths",
-                "instructions",
-                "limit",
-                "max_breadth",
-                "max_depth",
-                "select_domains",
-                "select_paths",
-                "timeout",
-                "url",
-            ],
-            "rebalance map schema should match the official field set"
-        );
-        assert_eq!(
-            prop_keys("tavily_research"),
-            vec![
-                "citation_format",
-                "exclude_domains",
-                "files",
-                "include_domains",
-                "input",
-                "model",
-                "output_length",
-                "output_schema",
-            ],
-            "rebalance research schema should match the official field set"
-        );
-        let search_props = &tool_by_name["tavily_search"]["inputSchema"]["properties"];
-        assert_eq!(
-            search_props["topic"]["enum"],
-            json!(["general", "news", "finance"]),
-            "search topic should expose free-account REST topics"
-        );
-        assert_eq!(
-            search_props["include_favicon"]["default"].as_bool(),
-            Some(false),
-            "search include_favicon should match the official default"
-        );
-        assert!(
-            search_props.get("safe_search").is_none(),
-            "Enterprise-only safe_search must not be advertised to free-account downstream clients"
-        );
-        assert!(
-            search_props.get("include_usage").is_none(),
-            "include_usage is controlled by Hikari billing and must not be advertised"
-        );
-
-        for name in ["tavily_crawl", "tavily_map"] {
-            assert_eq!(
-                tool_by_name[name]["inputSchema"]["properties"]["allow_external"]["default"]
-                    .as_bool(),
-                Some(true),
-                "{name} allow_external should match the official default"
-            );
-        }
-        assert_eq!(
-            tool_by_name["tavily_extract"]["inputSchema"]["properties"]["include_favicon"]
-                ["default"]
-                .as_bool(),
-            Some(false),
-            "extract include_favicon should match the official default"
-        );
-        assert!(
-            tool_by_name["tavily_research"]["inputSchema"]["properties"]
-                .get("stream")
-                .is_none(),
-            "streaming research is not supported by this proxy and must not be advertised"
-        );
-        for (name, required) in [
-            ("tavily_search", "query"),
-            ("tavily_extract", "urls"),
-            ("tavily_crawl", "url"),
-            ("tavily_map", "url"),
-            ("tavily_research", "input"),
-        ] {
-            assert_eq!(
-                tool_by_name[name]["inputSchema"]["required"],
-                json!([required]),
-                "{name} should keep the official required field"
-            );
-        }
-
-        let ping = client
-            .post(&url)
-            .header("accept", "application/json, text/event-stream")
-            .header("content-type", "application/json")
-            .header("mcp-protocol-version", "2025-03-26")
-            .json(&json!({
-                "jsonrpc": "2.0",
-                "id": "rebalance-ping-no-session",
-                "method": "ping"
-            }))
-            .send()
-            .await
-            .expect("ping request");
-        assert_eq!(
-            ping.status(),
-            StatusCode::OK,
-            "rebalance ping follow-up should not require mcp-session-id"
-        );
-        let ping_body = decode_sse_json_response(ping).await;
-        assert_eq!(ping_body["result"], json!({}));
-
-        let recorded = seen
-            .lock()
-            .expect("rebalance gateway calls lock poisoned")
-            .clone();
-        assert!(
-            recorded.is_empty(),
-            "initialize + tools/list should stay local under rebalance mode"
-        );
-
-        let pool = connect_sqlite_test_pool(&db_str).await;
-        let row = sqlx::query(
-            r#"
-            SELECT gateway_mode, experiment_variant, upstream_session_id, upstream_key_id
-            FROM mcp_sessions
-            WHERE proxy_session_id = ?
-            LIMIT 1
-            "#,
-        )
-        .bind(&proxy_session_id)
-        .fetch_one(&pool)
-        .await
-        .expect("fetch rebalance mcp session");
-        assert_eq!(
-            row.try_get::<String, _>("gateway_mode").unwrap(),
-            tavily_hikari::MCP_GATEWAY_MODE_REBALANCE
-        );
-        assert_eq!(
-            row.try_get::<String, _>("experiment_variant").unwrap(),
-            tavily_hikari::MCP_EXPERIMENT_VARIANT_REBALANCE
-        );
-        assert_eq!(
-            row.try_get::<Option<String>, _>("upstream_session_id")
-                .unwrap(),
-            None
-        );
-        assert_eq!(
-            row.try_get::<Option<String>, _>("upstream_key_id").unwrap(),
-            None
-        );
-
-        let _ = std::fs::remove_file(db_path);
-    }
-
-    #[tokio::test]
-    async fn mcp_rebalance_no_session_follow_up_does_not_bypass_active_control_session() {
-        let db_path = temp_db_path("mcp-rebalance-no-session-mixed-control");
-        let db_str = db_path.to_string_lossy().to_string();
-        let expected_api_key = "tvly-rebalance-no-session-mixed-control";
-        let (upstream_addr, calls) =
-            spawn_mock_mcp_upstream_for_session_headers(vec![expected_api_key.to_string()]).await;
-        let upstream = format!("http://{}", upstream_addr);
-
-        let proxy =
-            TavilyProxy::with_endpoint(vec![expected_api_key.to_string()], &upstream, &db_str)
-                .await
-                .expect("proxy created");
-        proxy
-            .set_system_settings(&tavily_hikari::SystemSettings {
-                request_rate_limit: request_rate_limit(),
-                auth_token_log_retention_days: tavily_hikari::AUTH_TOKEN_LOG_RETENTION_DAYS_DEFAULT,
-                mcp_session_affinity_key_count: 5,
-                rebalance_mcp_enabled: false,
-                rebalance_mcp_session_percent: 0,
-                api_rebalance_enabled: tavily_hikari::API_REBALANCE_ENABLED_DEFAULT,
-                api_rebalance_percent: tavily_hikari::API_REBALANCE_PERCENT_DEFAULT,
-                upstream_project_id_mode: tavily_hikari::UpstreamProjectIdMode::AccessToken,
-                upstream_project_id_fixed_value: String::new(),
-                upstream_mcp_user_agent: String::new(),
-                upstream_precise_reconciliation_enabled: true,
-                recharge_feature_enabled: true,
-                recharge_user_enabled: true,
-                admin_default_active_users_only: false,
-                user_blocked_key_base_limit: 7,
-                global_ip_limit: tavily_hikari::GLOBAL_IP_LIMIT_DEFAULT,
-                trusted_proxy_cidrs: tavily_hikari::TrustedClientIpSettings::default().trusted_proxy_cidrs,
-                trusted_client_ip_headers: tavily_hikari::TrustedClientIpSettings::default().trusted_client_ip_headers,
-                request_log_retention: tavily_hikari::default_request_log_retention_settings(),
-            })
-            .await
-            .expect("disable rebalance mcp");
-        let access_token = proxy
-            .create_access_token(Some("mcp-rebalance-no-session-mixed-control"))
-            .await
-            .expect("create access token");
-
-        let proxy_addr = spawn_proxy_server(proxy.clone(), upstream.clone()).await;
-        let url = format!(
-            "http://{}/mcp?tavilyApiKey={}",
-            proxy_addr, access_token.token
-        );
-        let client = Client::new();
-
-        let control_initialize = client
-            .post(&url)
-            .header("accept", "application/json, text/event-stream")
-            .header("content-type", "application/json")
-            .header("mcp-protocol-version", "2025-03-26")
-            .json(&json!({
-                "jsonrpc": "2.0",
-                "id": "control-init",
-                "method": "initialize",
-                "params": {
-                    "protocolVersion": "2025-03-26",
-                    "capabilities": {}
-                }
-            }))
-            .send()
-            .await
-            .expect("control initialize request");
-        assert_eq!(control_initialize.status(), StatusCode::OK);
-        assert!(
-            control_initialize.headers().get("mcp-session-id").is_some(),
-            "control initialize should create an upstream-backed proxy session"
-        );
-
-        proxy
-            .set_system_settings(&tavily_hikari::SystemSettings {
-                request_rate_limit: request_rate_limit(),
-                auth_token_log_retention_days: tavily_hikari::AUTH_TOKEN_LOG_RETENTION_DAYS_DEFAULT,
-                mcp_session_affinity_key_count: 5,
-                rebalance_mcp_enabled: true,
-                rebalance_mcp_session_percent: 100,
-                api_rebalance_enabled: tavily_hikari::API_REBALANCE_ENABLED_DEFAULT,
-                api_rebalance_percent: tavily_hikari::API_REBALANCE_PERCENT_DEFAULT,
-                upstream_project_id_mode: tavily_hikari::UpstreamProjectIdMode::AccessToken,
-                upstream_project_id_fixed_value: String::new(),
-                upstream_mcp_user_agent: String::new(),
-                upstream_precise_reconciliation_enabled: true,
-                recharge_feature_enabled: true,
-                recharge_user_enabled: true,
-                admin_default_active_users_only: false,
-                user_blocked_key_base_limit: 7,
-                global_ip_limit: tavily_hikari::GLOBAL_IP_LIMIT_DEFAULT,
-                trusted_proxy_cidrs: tavily_hikari::TrustedClientIpSettings::default().trusted_proxy_cidrs,
-                trusted_client_ip_headers: tavily_hikari::TrustedClientIpSettings::default().trusted_client_ip_headers,
-                request_log_retention: tavily_hikari::default_request_log_retention_settings(),
-            })
-            .await
-            .expect("enable rebalance mcp");
-        let rebalance_initialize = client
-            .post(&url)
-            .header("accept", "application/json, text/event-stream")
-            .header("content-type", "application/json")
-            .header("mcp-protocol-version", "2025-03-26")
-            .json(&json!({
-                "jsonrpc": "2.0",
-                "id": "rebalance-init-after-control",
-                "method": "initialize",
-                "params": {
-                    "protocolVersion": "2025-03-26",
-                    "capabilities": {}
-                }
-            }))
-            .send()
-            .await
-            .expect("rebalance initialize request");
-        assert_eq!(rebalance_initialize.status(), StatusCode::OK);
-        assert!(
-            rebalance_initialize.headers().get("mcp-session-id").is_some(),
-            "rebalance initialize should create a local proxy session"
-        );
-
-        let missing_session = client
-            .post(&url)
-            .header("accept", "application/json, text/event-stream")
-            .header("content-type", "application/json")
-            .header("mcp-protocol-version", "2025-03-26")
-            .json(&json!({
-                "jsonrpc": "2.0",
-                "id": "ambiguous-tools-list",
-                "method": "tools/list"
-            }))
-            .send()
-            .await
-            .expect("headerless tools/list request");
-        assert_eq!(
-            missing_session.status(),
-            StatusCode::BAD_REQUEST,
-            "headerless follow-up should not bypass an active control session on the same token"
-        );
-        let body = decode_sse_json_response(missing_session).await;
-        assert_eq!(
-            body.get("error").and_then(Value::as_str),
-            Some("session_required")
-        );
-
-        let recorded = calls
-            .lock()
-            .expect("session header calls lock poisoned")
-            .clone();
-        assert_eq!(
-            recorded.iter().filter(|call| call.method == "initialize").count(),
-            1,
-            "only the control initialize should hit upstream /mcp"
-        );
-
-        let _ = std::fs::remove_file(db_path);
-    }
-
-    #[tokio::test]
-    async fn mcp_rebalance_control_plane_lists_stay_local_and_return_empty_results() {
-        let db_path = temp_db_path("mcp-rebalance-control-plane-parity");
-        let db_str = db_path.to_string_lossy().to_string();
-        let expected_api_key = "tvly-rebalance-control-plane-parity";
-        let seen: RecordedRebalanceGatewayCalls = Arc::new(Mutex::new(Vec::new()));
-        let upstream_addr =
-            spawn_rebalance_gateway_mock(expected_api_key.to_string(), seen.clone()).await;
-        let upstream = format!("http://{}", upstream_addr);
-
-        let proxy =
-            TavilyProxy::with_endpoint(vec![expected_api_key.to_string()], &upstream, &db_str)
-                .await
-                .expect("proxy created");
-        proxy
-            .set_system_settings(&tavily_hikari::SystemSettings {
-                request_rate_limit: request_rate_limit(),
-                auth_token_log_retention_days: tavily_hikari::AUTH_TOKEN_LOG_RETENTION_DAYS_DEFAULT,
-                mcp_session_affinity_key_count: 5,
-                rebalance_mcp_enabled: true,
-                rebalance_mcp_session_percent: 100,
-                api_rebalance_enabled: tavily_hikari::API_REBALANCE_ENABLED_DEFAULT,
-                api_rebalance_percent: tavily_hikari::API_REBALANCE_PERCENT_DEFAULT,
-                upstream_project_id_mode: tavily_hikari::UpstreamProjectIdMode::AccessToken,
-                upstream_project_id_fixed_value: String::new(),
-                upstream_mcp_user_agent: String::new(),
-                upstream_precise_reconciliation_enabled: true,
-                recharge_feature_enabled: true,
-                recharge_user_enabled: true,
-                admin_default_active_users_only: false,
-                user_blocked_key_base_limit: 7,
-                global_ip_limit: tavily_hikari::GLOBAL_IP_LIMIT_DEFAULT,
-                trusted_proxy_cidrs: tavily_hikari::TrustedClientIpSettings::default().trusted_proxy_cidrs,
-                trusted_client_ip_headers: tavily_hikari::TrustedClientIpSettings::default().trusted_client_ip_headers,
-                request_log_retention: tavily_hikari::default_request_log_retention_settings(),
-            })
-            .await
-            .expect("enable rebalance mcp");
-        let access_token = proxy
-            .create_access_token(Some("mcp-rebalance-control-plane-parity"))
-            .await
-            .expect("create access token");
-
-        let proxy_addr = spawn_proxy_server(proxy.clone(), upstream.clone()).await;
-        let url = format!(
-            "http://{}/mcp?tavilyApiKey={}",
-            proxy_addr, access_token.token
-        );
-        let client = Client::new();
-
-        let initialize = client
-            .post(&url)
-            .header("accept", "application/json, text/event-stream")
-            .header("content-type", "application/json")
-            .header("mcp-protocol-version", "2025-03-26")
-            .json(&json!({
-                "jsonrpc": "2.0",
-                "id": "rebalance-parity-init",
-                "method": "initialize",
-                "params": {
-                    "protocolVersion": "2025-03-26",
-                    "capabilities": {},
-                    "clientInfo": { "name": "browser-probe", "version": "0.1.0" }
-                }
-            }))
-            .send()
-            .await
-            .expect("initialize request");
-        assert_eq!(initialize.status(), StatusCode::OK);
-        let proxy_session_id = initialize
-            .headers()
-            .get("mcp-session-id")
-            .and_then(|value| value.to_str().ok())
-            .expect("initialize response should expose mcp-session-id")
-            .to_string();
-
-        for (method_name, response_id, expected_field) in [
-            ("prompts/list", "rebalance-prompts-list", "prompts"),
-            ("resources/list", "rebalance-resources-list", "resources"),
-            (
-                "resources/templates/list",
-                "rebalance-resource-templates-list",
-                "resourceTemplates",
-            ),
-        ] {
-            let response = client
-                .post(&url)
-                .header("accept", "application/json, text/event-stream")
-                .header("content-type", "application/json")
-                .header("mcp-protocol-version", "2025-03-26")
-                .header("mcp-session-id", proxy_session_id.as_str())
-                .json(&json!({
-                    "jsonrpc": "2.0",
-                    "id": response_id,
-                    "method": method_name,
-                }))
-                .send()
-                .await
-                .unwrap_or_else(|err| panic!("{method_name} request failed: {err}"));
-            assert_eq!(
-                response.status(),
-                StatusCode::OK,
-                "{method_name} should succeed under rebalance parity"
-            );
-            let body = decode_sse_json_response(response).await;
-            assert_eq!(
-                body["result"][expected_field].as_array().map(Vec::len),
-                Some(0),
-                "{method_name} should return an empty {expected_field} list"
-            );
-        }
-
-        let recorded = seen
-            .lock()
-            .expect("rebalance gateway calls lock poisoned")
-            .clone();
-        assert!(
-            recorded.is_empty(),
-            "initialize + prompts/resources parity methods should stay local under rebalance mode"
-        );
-
-        let pool = connect_sqlite_test_pool(&db_str).await;
-        let rows = tokio::time::timeout(Duration::from_secs(2), async {
-            loop {
-                let rows = sqlx::query(
-                    r#"
-                    SELECT proxy_session_id
-                    FROM observability.request_logs
-                    WHERE path = '/mcp'
-                    ORDER BY id ASC
-                    "#,
-                )
-                .fetch_all(&pool)
-                .await
-                .expect("fetch rebalance control-plane audit records");
-                if rows.len() == 4 {
-                    return rows;
-                }
-                tokio::time::sleep(Duration::from_millis(10)).await;
-            }
-        })
-        .await
-        .expect("rebalance control-plane audits should flush promptly");
-        assert_eq!(rows.len(), 4, "initialize plus three list calls should be logged");
-        for row in rows {
-            assert_eq!(
-                row.try_get::<Option<String>, _>("proxy_session_id")
-                    .unwrap()
-                    .as_deref(),
-                Some(proxy_session_id.as_str()),
-                "stateful rebalance requests must preserve proxy session attribution"
-            );
-        }
-
-        let _ = std::fs::remove_file(db_path);
-    }
-
-    #[tokio::test]
-    async fn observed_client_ip_requests_skip_empty_rebalance_control_plane_logs() {
-        let db_path = temp_db_path("observed-client-ip-skip-rebalance-control");
-        let db_str = db_path.to_string_lossy().to_string();
-        let proxy = TavilyProxy::with_endpoint(
-            vec!["tvly-observed-client-ip-filter".to_string()],
-            "http://127.0.0.1:1",
-            &db_str,
-        )
-        .await
-        .expect("proxy created");
-        let pool = connect_sqlite_test_pool(&db_str).await;
-
-        sqlx::query(
-            r#"
-            INSERT INTO request_logs (
-                method,
-                path,
-                status_code,
-                tavily_status_code,
-                result_status,
-                request_kind_key,
-                gateway_mode,
-                upstream_operation,
-                remote_addr,
-                client_ip,
-                client_ip_source,
-                client_ip_trusted,
-                ip_headers,
-                created_at
-            ) VALUES
-                (
-                    'POST', '/mcp', 200, 200, 'success', 'mcp:tools/list',
-                    ?, 'mcp', NULL, NULL, NULL, 0, NULL, 30
-                ),
-                (
-                    'POST', '/api/tavily/search', 200, 200, 'success', 'api:search',
-                    NULL, NULL, NULL, NULL, NULL, 0, NULL, 25
-                ),
-                (
-                    'POST', '/api/tavily/search', 200, 200, 'success', 'api:search',
-                    NULL, NULL, '172.24.0.176:51000', '203.0.113.10',
-                    'eo-connecting-ip', 1,
-                    '[{"name":"eo-connecting-ip","value":"203.0.113.10"}]', 20
-                ),
-                (
-                    'POST', '/mcp', 200, 200, 'success', 'mcp:search',
-                    ?, 'http_search', '172.24.0.176:51001', '172.24.0.176',
-                    'remote_addr', 0, '[]', 10
-                )
-            "#,
-        )
-        .bind(tavily_hikari::MCP_GATEWAY_MODE_REBALANCE)
-        .bind(tavily_hikari::MCP_GATEWAY_MODE_REBALANCE)
-        .execute(&pool)
-        .await
-        .expect("insert request logs");
-
-        let control_log_id: i64 = sqlx::query_scalar(
-            "SELECT id FROM request_logs WHERE upstream_operation = 'mcp'",
-        )
-        .fetch_one(&pool)
-        .await
-        .expect("fetch control log id");
-
-        let observed = proxy
-            .recent_client_ip_requests(50)
-            .await
-            .expect("fetch observed client ip requests");
-        let observed_ids: Vec<i64> = observed.iter().map(|item| item.id).collect();
-
-        assert_eq!(observed.len(), 3);
-        assert!(
-            !observed_ids.contains(&control_log_id),
-            "empty rebalance control-plane logs should not crowd out IP diagnostics"
-        );
-        assert!(
-            observed.iter().any(|item| item.client_ip.is_none()),
-            "legacy rows without gateway metadata should not be removed by the rebalance filter"
-        );
-        let edge_one = observed
-            .iter()
-            .find(|item| item.client_ip.as_deref() == Some("203.0.113.10"))
-            .expect("EdgeOne observed row should remain");
-        assert_eq!(edge_one.client_ip_source.as_deref(), Some("eo-connecting-ip"));
-        assert_eq!(edge_one.ip_headers.len(), 1);
-        assert!(
-            observed
-                .iter()
-                .any(|item| item.client_ip_source.as_deref() == Some("remote_addr")),
-            "MCP tool rows with remote_addr should remain"
-        );
-
-        let _ = std::fs::remove_file(db_path);
-    }
-
-    #[tokio::test]
-    async fn mcp_rebalance_tools_call_search_uses_http_upstream_and_strict_headers() {
-        let db_path = temp_db_path("mcp-rebalance-search-http");
-        let db_str = db_path.to_string_lossy().to_string();
-        let expected_api_key = "tvly-rebalance-search-http";
-        let seen: RecordedRebalanceGatewayCalls = Arc::new(Mutex::new(Vec::new()));
-        let upstream_addr =
-            spawn_rebalance_gateway_mock(expected_api_key.to_string(), seen.clone()).await;
-        let upstream = format!("http://{}", upstream_addr);
-
-        let proxy =
-            TavilyProxy::with_endpoint(vec![expected_api_key.to_string()], &upstream, &db_str)
-                .await
-                .expect("proxy created");
-        proxy
-            .set_system_settings(&tavily_hikari::SystemSettings {
-                request_rate_limit: request_rate_limit(),
-                auth_token_log_retention_days: tavily_hikari::AUTH_TOKEN_LOG_RETENTION_DAYS_DEFAULT,
-                mcp_session_affinity_key_count: 5,
-                rebalance_mcp_enabled: true,
-                rebalance_mcp_session_percent: 100,
-                api_rebalance_enabled: tavily_hikari::API_REBALANCE_ENABLED_DEFAULT,
-                api_rebalance_percent: tavily_hikari::API_REBALANCE_PERCENT_DEFAULT,
-                upstream_project_id_mode: tavily_hikari::UpstreamProjectIdMode::AccessToken,
-                upstream_project_id_fixed_value: String::new(),
-                upstream_mcp_user_agent: String::new(),
-                upstream_precise_reconciliation_enabled: true,
-                recharge_feature_enabled: true,
-                recharge_user_enabled: true,
-                admin_default_active_users_only: false,
-                user_blocked_key_base_limit: 7,
-                global_ip_limit: tavily_hikari::GLOBAL_IP_LIMIT_DEFAULT,
-                trusted_proxy_cidrs: tavily_hikari::TrustedClientIpSettings::default().trusted_proxy_cidrs,
-                trusted_client_ip_headers: tavily_hikari::TrustedClientIpSettings::default().trusted_client_ip_headers,
-                request_log_retention: tavily_hikari::default_request_log_retention_settings(),
-            })
-            .await
-            .expect("enable rebalance mcp");
-        let access_token = proxy
-            .create_access_token(Some("mcp-rebalance-search-http"))
-            .await
-            .expect("create access token");
-
-        let proxy_addr = spawn_proxy_server(proxy.clone(), upstream.clone()).await;
-        let url = format!(
-            "http://{}/mcp?tavilyApiKey={}",
-            proxy_addr, access_token.token
-        );
-        let client = Client::new();
-
-        let initialize = client
-            .post(&url)
-            .header("accept", "application/json, text/event-stream")
-            .header("content-type", "application/json")
-            .header("mcp-protocol-version", "2025-03-26")
-            .json(&json!({
-                "jsonrpc": "2.0",
-                "id": "rebalance-search-init",
-                "method": "initialize",
-                "params": {
-                    "protocolVersion": "2025-03-26",
-                    "capabilities": {},
-                    "clientInfo": { "name": "browser-probe", "version": "0.1.0" }
-                }
-            }))
-            .send()
-            .await
-            .expect("initialize request");
-        let proxy_session_id = initialize
-            .headers()
-            .get("mcp-session-id")
-            .and_then(|value| value.to_str().ok())
-            .expect("initialize response should expose mcp-session-id")
-            .to_string();
-
-        let search = client
-            .post(&url)
-            .header("accept", "application/json, text/event-stream")
-            .header("content-type", "application/json")
-            .header("mcp-protocol-version", "2025-03-26")
-            .header("x-forwarded-for", "198.51.100.1")
-            .header("cookie", "session=should-not-leak")
-            .header("sec-fetch-mode", "cors")
-            .json(&json!({
-                "jsonrpc": "2.0",
-                "id": "rebalance-search-call",
-                "method": "tools/call",
-                "params": {
-                    "name": "tavily_search",
-                    "arguments": {
-                        "query": "rebalance strict header test",
-                        "search_depth": "basic"
-                    }
-                }
-            }))
-            .send()
-            .await
-            .expect("rebalance search request");
-        assert_eq!(search.status(), StatusCode::OK);
-        assert_eq!(
-            search
-                .headers()
-                .get("content-type")
-                .and_then(|value| value.to_str().ok())
-                .map(|value| value.starts_with("text/event-stream")),
-            Some(true),
-            "rebalance tools/call should use official-style SSE transport"
-        );
-        let body = decode_sse_json_response(search).await;
-        assert_eq!(
-            body["result"]["structuredContent"]["usage"]["credits"].as_i64(),
-            Some(1)
-        );
-
-        let recorded = seen
-            .lock()
-            .expect("rebalance gateway calls lock poisoned")
-            .clone();
-        assert_eq!(
-            recorded.iter().filter(|call| call.path == "/mcp").count(),
-            0,
-            "rebalance search should not hit upstream /mcp"
-        );
-        let search_calls = recorded
-            .iter()
-            .filter(|call| call.path == "/search")
-            .cloned()
-            .collect::<Vec<_>>();
-        assert_eq!(search_calls.len(), 1, "expected one upstream /search call");
-        let search_call = &search_calls[0];
-        assert!(
-            !search_call.headers.contains_key("mcp-session-id"),
-            "rebalance HTTP call must not forward mcp-session-id"
-        );
-        assert!(
-            !search_call.headers.contains_key("x-forwarded-for"),
-            "rebalance HTTP call must not leak x-forwarded-for"
-        );
-        assert!(
-            !search_call.headers.contains_key("cookie"),
-            "rebalance HTTP call must not leak cookies"
-        );
-        assert!(
-            !search_call.headers.contains_key("sec-fetch-mode"),
-            "rebalance HTTP call must not leak browser sec-* headers"
-        );
-        assert!(
-            search_call.headers.contains_key("authorization"),
-            "rebalance HTTP call must send Authorization"
-        );
-        assert!(
-            search_call.headers.contains_key("accept"),
-            "rebalance HTTP call must keep Accept"
-        );
-        assert!(
-            search_call.headers.contains_key("content-type"),
-            "rebalance HTTP call must keep Content-Type"
-        );
-        assert!(
-            !search_call.headers.contains_key("user-agent"),
-            "rebalance HTTP call must omit User-Agent"
-        );
-        assert_eq!(
-            search_call.body.get("query").and_then(Value::as_str),
-            Some("rebalance strict header test"),
-            "rebalance HTTP call should forward the Tavily tool payload as JSON"
-        );
-
-        let pool = connect_sqlite_test_pool(&db_str).await;
-        let request_row = sqlx::query(
-            r#"
-            SELECT gateway_mode, experiment_variant, proxy_session_id, upstream_operation
-            FROM observability.request_logs
-            WHERE path = '/mcp'
-              AND proxy_session_id = ?
-              AND upstream_operation = 'http_search'
-            ORDER BY id DESC
-            LIMIT 1
-            "#,
-        )
-        .bind(&proxy_session_id)
-        .fetch_one(&pool)
-        .await
-        .expect("fetch latest request log");
-        assert_eq!(
-            request_row.try_get::<String, _>("gateway_mode").unwrap(),
-            tavily_hikari::MCP_GATEWAY_MODE_REBALANCE
-        );
-        assert_eq!(
-            request_row
-                .try_get::<String, _>("experiment_variant")
-                .unwrap(),
-            tavily_hikari::MCP_EXPERIMENT_VARIANT_REBALANCE
-        );
-        assert_eq!(
-            request_row
-                .try_get::<String, _>("proxy_session_id")
-                .unwrap(),
-            proxy_session_id
-        );
-        assert_eq!(
-            request_row
-                .try_get::<String, _>("upstream_operation")
-                .unwrap(),
-            "http_search"
-        );
-
-        let token_row = sqlx::query(
-            r#"
-            SELECT gateway_mode, experiment_variant, proxy_session_id, upstream_operation
-            FROM auth_token_logs
-            WHERE proxy_session_id = ?
-              AND upstream_operation = 'http_search'
-            ORDER BY id DESC
-            LIMIT 1
-            "#,
-        )
-        .bind(&proxy_session_id)
-        .fetch_one(&pool)
-        .await
-        .expect("fetch latest token log");
-        assert_eq!(
-            token_row.try_get::<String, _>("gateway_mode").unwrap(),
-            tavily_hikari::MCP_GATEWAY_MODE_REBALANCE
-        );
-        assert_eq!(
-            token_row
-                .try_get::<String, _>("experiment_variant")
-                .unwrap(),
-            tavily_hikari::MCP_EXPERIMENT_VARIANT_REBALANCE
-        );
-        assert_eq!(
-            token_row.try_get::<String, _>("proxy_session_id").unwrap(),
-            proxy_session_id
-        );
-        assert_eq!(
-            token_row
-                .try_get::<String, _>("upstream_operation")
-                .unwrap(),
-            "http_search"
-        );
-
-        let _ = std::fs::remove_file(db_path);
-    }
-
-    #[tokio::test]
-    async fn mcp_rebalance_tool_errors_use_top_level_is_error_and_content_array() {
-        let db_path = temp_db_path("mcp-rebalance-search-http-error");
-        let db_str = db_path.to_string_lossy().to_string();
-        let expected_api_key = "tvly-rebalance-search-http-error";
-        let seen: RecordedRebalanceGatewayCalls = Arc::new(Mutex::new(Vec::new()));
-        let upstream_addr = spawn_rebalance_gateway_http_error_mock(
-            expected_api_key.to_string(),
-            seen,
-            StatusCode::BAD_REQUEST,
-            json!({
-                "status": 400,
-                "detail": "bad query"
-            }),
-        )
-        .await;
-        let upstream = format!("http://{}", upstream_addr);
-
-        let proxy =
-            TavilyProxy::with_endpoint(vec![expected_api_key.to_string()], &upstream, &db_str)
-                .await
-                .expect("proxy created");
-        proxy
-            .set_system_settings(&tavily_hikari::SystemSettings {
-                request_rate_limit: request_rate_limit(),
-                auth_token_log_retention_days: tavily_hikari::AUTH_TOKEN_LOG_RETENTION_DAYS_DEFAULT,
-                mcp_session_affinity_key_count: 5,
-                rebalance_mcp_enabled: true,
-                rebalance_mcp_session_percent: 100,
-                api_rebalance_enabled: tavily_hikari::API_REBALANCE_ENABLED_DEFAULT,
-                api_rebalance_percent: tavily_hikari::API_REBALANCE_PERCENT_DEFAULT,
-                upstream_project_id_mode: tavily_hikari::UpstreamProjectIdMode::AccessToken,
-                upstream_project_id_fixed_value: String::new(),
-                upstream_mcp_user_agent: String::new(),
-                upstream_precise_reconciliation_enabled: true,
-                recharge_feature_enabled: true,
-                recharge_user_enabled: true,
-                admin_default_active_users_only: false,
-                user_blocked_key_base_limit: 7,
-                global_ip_limit: tavily_hikari::GLOBAL_IP_LIMIT_DEFAULT,
-                trusted_proxy_cidrs: tavily_hikari::TrustedClientIpSettings::default().trusted_proxy_cidrs,
-                trusted_client_ip_headers: tavily_hikari::TrustedClientIpSettings::default().trusted_client_ip_headers,
-                request_log_retention: tavily_hikari::default_request_log_retention_settings(),
-            })
-            .await
-            .expect("enable rebalance mcp");
-        let access_token = proxy
-            .create_access_token(Some("mcp-rebalance-search-http-error"))
-            .await
-            .expect("create access token");
-
-        let proxy_addr = spawn_proxy_server(proxy.clone(), upstream.clone()).await;
-        let url = format!(
-            "http://{}/mcp?tavilyApiKey={}",
-            proxy_addr, access_token.token
-        );
-        let client = Client::new();
-
-        let initialize = client
-            .post(&url)
-            .header("content-type", "application/json")
-            .header("mcp-protocol-version", "2025-03-26")
-            .json(&json!({
-                "jsonrpc": "2.0",
-                "id": "rebalance-error-init",
-                "method": "initialize",
-                "params": {
-                    "protocolVersion": "2025-03-26",
-                    "capabilities": {}
-                }
-            }))
-            .send()
-            .await
-            .expect("initialize request");
-        assert_eq!(initialize.status(), StatusCode::OK);
-        let proxy_session_id = initialize
-            .headers()
-            .get("mcp-session-id")
-            .and_then(|value| value.to_str().ok())
-            .expect("initialize response should expose mcp-session-id")
-            .to_string();
-
-        let search = client
-            .post(&url)
-            .header("content-type", "application/json")
-            .header("mcp-protocol-version", "2025-03-26")
-            .header("mcp-session-id", proxy_session_id.as_str())
-            .json(&json!({
-                "jsonrpc": "2.0",
-                "id": "rebalance-search-error",
-                "method": "tools/call",
-                "params": {
-                    "name": "tavily_search",
-                    "arguments": {
-                        "query": "bad input"
-                    }
-                }
-            }))
-            .send()
-            .await
-            .expect("rebalance search request");
-        assert_eq!(search.status(), StatusCode::OK);
-        let body = decode_sse_json_response(search).await;
-        assert_eq!(body["result"]["isError"].as_bool(), Some(true));
-        assert!(
-            body["result"]["content"].is_array(),
-            "rebalance error responses must keep a top-level content array"
-        );
-        assert_eq!(
-            body["result"]["structuredContent"]["isError"].as_bool(),
-            None,
-            "isError must not be nested inside structuredContent"
-        );
-        assert_eq!(
-            body["result"]["structuredContent"]["status"].as_i64(),
-            Some(400)
-        );
-
-        let _ = std::fs::remove_file(db_path);
-    }
-
-    #[tokio::test]
-    async fn mcp_rebalance_parse_error_returns_jsonrpc_parse_error() {
-        let db_path = temp_db_path("mcp-rebalance-parse-error");
-        let db_str = db_path.to_string_lossy().to_string();
-        let expected_api_key = "tvly-rebalance-parse-error";
-        let seen: RecordedRebalanceGatewayCalls = Arc::new(Mutex::new(Vec::new()));
-        let upstream_addr =
-            spawn_rebalance_gateway_mock(expected_api_key.to_string(), seen.clone()).await;
-        let upstream = format!("http://{}", upstream_addr);
-
-        let proxy =
-            TavilyProxy::with_endpoint(vec![expected_api_key.to_string()], &upstream, &db_str)
-                .await
-                .expect("proxy created");
-        proxy
-            .set_system_settings(&tavily_hikari::SystemSettings {
-                request_rate_limit: request_rate_limit(),
-                auth_token_log_retention_days: tavily_hikari::AUTH_TOKEN_LOG_RETENTION_DAYS_DEFAULT,
-                mcp_session_affinity_key_count: 5,
-                rebalance_mcp_enabled: true,
-                rebalance_mcp_session_percent: 100,
-                api_rebalance_enabled: tavily_hikari::API_REBALANCE_ENABLED_DEFAULT,
-                api_rebalance_percent: tavily_hikari::API_REBALANCE_PERCENT_DEFAULT,
-                upstream_project_id_mode: tavily_hikari::UpstreamProjectIdMode::AccessToken,
-                upstream_project_id_fixed_value: String::new(),
-                upstream_mcp_user_agent: String::new(),
-                upstream_precise_reconciliation_enabled: true,
-                recharge_feature_enabled: true,
-                recharge_user_enabled: true,
-                admin_default_active_users_only: false,
-                user_blocked_key_base_limit: 7,
-                global_ip_limit: tavily_hikari::GLOBAL_IP_LIMIT_DEFAULT,
-                trusted_proxy_cidrs: tavily_hikari::TrustedClientIpSettings::default().trusted_proxy_cidrs,
-                trusted_client_ip_headers: tavily_hikari::TrustedClientIpSettings::default().trusted_client_ip_headers,
-                request_log_retention: tavily_hikari::default_request_log_retention_settings(),
-            })
-            .await
-            .expect("enable rebalance mcp");
-        let access_token = proxy
-            .create_access_token(Some("mcp-rebalance-parse-error"))
-            .await
-            .expect("create access token");
-
-        let proxy_addr = spawn_proxy_server(proxy.clone(), upstream.clone()).await;
-        let url = format!(
-            "http://{}/mcp?tavilyApiKey={}",
-            proxy_addr, access_token.token
-        );
-        let client = Client::new();
-
-        let response = client
-            .post(&url)
-            .header("content-type", "application/json")
-            .body("{")
-            .send()
-            .await
-            .expect("parse-error request");
-        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
-        assert_eq!(
-            response
-                .headers()
-                .get("content-type")
-                .and_then(|value| value.to_str().ok())
-                .map(|value| value.starts_with("text/event-stream")),
-            Some(true),
-            "rebalance parse errors should use official-style SSE transport"
-        );
-        let body = decode_sse_json_response(response).await;
-        assert_eq!(body["error"]["code"].as_i64(), Some(-32700));
-        assert_eq!(body["error"]["message"].as_str(), Some("Parse error"));
-
-        let recorded = seen
-            .lock()
-            .expect("rebalance gateway calls lock poisoned")
-            .clone();
-        assert!(
-            recorded.is_empty(),
-            "parse errors must be rejected locally before any upstream hit"
-        );
-
-        let _ = std::fs::remove_file(db_path);
-    }
-
-    #[tokio::test]
-    async fn mcp_rebalance_empty_batch_returns_invalid_request() {
-        let db_path = temp_db_path("mcp-rebalance-empty-batch");
-        let db_str = db_path.to_string_lossy().to_string();
-        let expected_api_key = "tvly-rebalance-empty-batch";
-        let seen: RecordedRebalanceGatewayCalls = Arc::new(Mutex::new(Vec::new()));
-        let upstream_addr =
-            spawn_rebalance_gateway_mock(expected_api_key.to_string(), seen.clone()).await;
-        let upstream = format!("http://{}", upstream_addr);
-
-        let proxy =
-            TavilyProxy::with_endpoint(vec![expected_api_key.to_string()], &upstream, &db_str)
-                .await
-                .expect("proxy created");
-        proxy
-            .set_system_settings(&tavily_hikari::SystemSettings {
-                request_rate_limit: request_rate_limit(),
-                auth_token_log_retention_days: tavily_hikari::AUTH_TOKEN_LOG_RETENTION_DAYS_DEFAULT,
-                mcp_session_affinity_key_count: 5,
-                rebalance_mcp_enabled: true,
-                rebalance_mcp_session_percent: 100,
-                api_rebalance_enabled: tavily_hikari::API_REBALANCE_ENABLED_DEFAULT,
-                api_rebalance_percent: tavily_hikari::API_REBALANCE_PERCENT_DEFAULT,
-                upstream_project_id_mode: tavily_hikari::UpstreamProjectIdMode::AccessToken,
-                upstream_project_id_fixed_value: String::new(),
-                upstream_mcp_user_agent: String::new(),
-                upstream_precise_reconciliation_enabled: true,
-                recharge_feature_enabled: true,
-                recharge_user_enabled: true,
-                admin_default_active_users_only: false,
-                user_blocked_key_base_limit: 7,
-                global_ip_limit: tavily_hikari::GLOBAL_IP_LIMIT_DEFAULT,
-                trusted_proxy_cidrs: tavily_hikari::TrustedClientIpSettings::default().trusted_proxy_cidrs,
-                trusted_client_ip_headers: tavily_hikari::TrustedClientIpSettings::default().trusted_client_ip_headers,
-                request_log_retention: tavily_hikari::default_request_log_retention_settings(),
-            })
-            .await
-            .expect("enable rebalance mcp");
-        let access_token = proxy
-            .create_access_token(Some("mcp-rebalance-empty-batch"))
-            .await
-            .expect("create access token");
-
-        let proxy_addr = spawn_proxy_server(proxy.clone(), upstream.clone()).await;
-        let url = format!(
-            "http://{}/mcp?tavilyApiKey={}",
-            proxy_addr, access_token.token
-        );
-        let client = Client::new();
-
-        let response = client
-            .post(&url)
-            .header("content-type", "application/json")
-            .json(&json!([]))
-            .send()
-            .await
-            .expect("empty-batch request");
-        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
-        assert_eq!(
-            response
-                .headers()
-                .get("content-type")
-                .and_then(|value| value.to_str().ok())
-                .map(|value| value.starts_with("text/event-stream")),
-            Some(true),
-            "rebalance empty batch errors should use official-style SSE transport"
-        );
-        let body = decode_sse_json_response(response).await;
-        assert_eq!(body["error"]["code"].as_i64(), Some(-32600));
-        assert_eq!(body["error"]["message"].as_str(), Some("Invalid Request"));
-
-        let _ = std::fs::remove_file(db_path);
-    }
-
-    #[tokio::test]
-    async fn mcp_rebalance_response_only_batch_is_rejected_locally() {
-        let db_path = temp_db_path("mcp-rebalance-response-only-batch");
-        let db_str = db_path.to_string_lossy().to_string();
-        let expected_api_key = "tvly-rebalance-response-only-batch";
-        let seen: RecordedRebalanceGatewayCalls = Arc::new(Mutex::new(Vec::new()));
-        let upstream_addr =
-            spawn_rebalance_gateway_mock(expected_api_key.to_string(), seen.clone()).await;
-        let upstream = format!("http://{}", upstream_addr);
-
-        let proxy =
-            TavilyProxy::with_endpoint(vec![expected_api_key.to_string()], &upstream, &db_str)
-                .await
-                .expect("proxy created");
-        proxy
-            .set_system_settings(&tavily_hikari::SystemSettings {
-                request_rate_limit: request_rate_limit(),
-                auth_token_log_retention_days: tavily_hikari::AUTH_TOKEN_LOG_RETENTION_DAYS_DEFAULT,
-                mcp_session_affinity_key_count: 5,
-                rebalance_mcp_enabled: true,
-                rebalance_mcp_session_percent: 100,
-                api_rebalance_enabled: tavily_hikari::API_REBALANCE_ENABLED_DEFAULT,
-                api_rebalance_percent: tavily_hikari::API_REBALANCE_PERCENT_DEFAULT,
-                upstream_project_id_mode: tavily_hikari::UpstreamProjectIdMode::AccessToken,
-                upstream_project_id_fixed_value: String::new(),
-                upstream_mcp_user_agent: String::new(),
-                upstream_precise_reconciliation_enabled: true,
-                recharge_feature_enabled: true,
-                recharge_user_enabled: true,
-                admin_default_active_users_only: false,
-                user_blocked_key_base_limit: 7,
-                global_ip_limit: tavily_hikari::GLOBAL_IP_LIMIT_DEFAULT,
-                trusted_proxy_cidrs: tavily_hikari::TrustedClientIpSettings::default().trusted_proxy_cidrs,
-                trusted_client_ip_headers: tavily_hikari::TrustedClientIpSettings::default().trusted_client_ip_headers,
-                request_log_retention: tavily_hikari::default_request_log_retention_settings(),
-            })
-            .await
-            .expect("enable rebalance mcp");
-        let access_token = proxy
-            .create_access_token(Some("mcp-rebalance-response-only-batch"))
-            .await
-            .expect("create access token");
-
-        let proxy_addr = spawn_proxy_server(proxy.clone(), upstream.clone()).await;
-        let url = format!(
-            "http://{}/mcp?tavilyApiKey={}",
-            proxy_addr, access_token.token
-        );
-        let client = Client::new();
-
-        let initialize = client
-            .post(&url)
-            .header("content-type", "application/json")
-            .header("mcp-protocol-version", "2025-03-26")
-            .json(&json!({
-                "jsonrpc": "2.0",
-                "id": "rebalance-response-only-init",
-                "method": "initialize",
-                "params": {
-                    "protocolVersion": "2025-03-26",
-                    "capabilities": {}
-                }
-            }))
-            .send()
-            .await
-            .expect("initialize request");
-        assert_eq!(initialize.status(), StatusCode::OK);
-        let proxy_session_id = initialize
-            .headers()
-            .get("mcp-session-id")
-            .and_then(|value| value.to_str().ok())
-            .expect("initialize response should expose mcp-session-id")
-            .to_string();
-
-        let response = client
-            .post(&url)
-            .header("content-type", "application/json")
-            .header("mcp-protocol-version", "2025-03-26")
-            .header("mcp-session-id", proxy_session_id.as_str())
-            .json(&json!([
-                {
-                    "jsonrpc": "2.0",
-                    "id": "server-request-1",
-                    "result": { "ok": true }
-                }
-            ]))
-            .send()
-            .await
-            .expect("response-only batch request");
-        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
-        assert_eq!(
-            response
-                .headers()
-                .get("content-type")
-                .and_then(|value| value.to_str().ok())
-                .map(|value| value.starts_with("text/event-stream")),
-            Some(true),
-            "rebalance invalid JSON-RPC shape errors should use official-style SSE transport"
-        );
-        let body = decode_sse_json_response(response).await;
-        assert_eq!(body["error"]["code"].as_i64(), Some(-32600));
-
-        let recorded = seen
-            .lock()
-            .expect("rebalance gateway calls lock poisoned")
-            .clone();
-        assert!(
-            recorded.is_empty(),
-            "response-only batches must be rejected locally"
-        );
-
-        let _ = std::fs::remove_file(db_path);
-    }
-
     #[tokio::test]
     async fn mcp_tools_call_follow_up_without_session_header_is_rejected_locally() {
         let db_path = temp_db_path("mcp-tools-call-follow-up-missing-session-id");
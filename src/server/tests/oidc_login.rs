@@ -0,0 +1,368 @@
+use super::*;
+use super::core_support_and_parsing::*;
+use super::linuxdo_oauth_and_admin_keys::find_cookie_pair;
+use ring::signature::{ECDSA_P256_SHA256_FIXED_SIGNING, EcdsaKeyPair, KeyPair};
+
+    #[derive(Default)]
+    struct MockOidcIdp {
+        issuer: String,
+        nonce: Option<String>,
+        code_challenge: Option<String>,
+        wrong_nonce: bool,
+        token_requests: Vec<(Option<String>, HashMap<String, String>)>,
+    }
+
+    fn b64url(bytes: &[u8]) -> String {
+        base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(bytes)
+    }
+
+    fn sign_es256_jwt(key: &EcdsaKeyPair, claims: &Value) -> String {
+        let header = b64url(br#"{"alg":"ES256","kid":"mock-key","typ":"JWT"}"#);
+        let payload = b64url(claims.to_string().as_bytes());
+        let message = format!("{header}.{payload}");
+        let signature = key
+            .sign(&ring::rand::SystemRandom::new(), message.as_bytes())
+            .expect("sign id token");
+        format!("{message}.{}", b64url(signature.as_ref()))
+    }
+
+    async fn spawn_mock_oidc_idp() -> (String, Arc<Mutex<MockOidcIdp>>) {
+        let rng = ring::rand::SystemRandom::new();
+        let pkcs8 = EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_FIXED_SIGNING, &rng)
+            .expect("generate key");
+        let key = Arc::new(
+            EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_FIXED_SIGNING, pkcs8.as_ref(), &rng)
+                .expect("load key"),
+        );
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let issuer = format!("http://{}", listener.local_addr().unwrap());
+        let idp = Arc::new(Mutex::new(MockOidcIdp {
+            issuer: issuer.clone(),
+            ..MockOidcIdp::default()
+        }));
+        let point = key.public_key().as_ref().to_vec();
+        let jwks = json!({
+            "keys": [{
+                "kty": "EC",
+                "crv": "P-256",
+                "kid": "mock-key",
+                "use": "sig",
+                "x": b64url(&point[1..33]),
+                "y": b64url(&point[33..65]),
+            }]
+        });
+        let discovery = json!({
+            "issuer": issuer,
+            "authorization_endpoint": format!("{issuer}/authorize"),
+            "token_endpoint": format!("{issuer}/token"),
+            "userinfo_endpoint": format!("{issuer}/userinfo"),
+            "jwks_uri": format!("{issuer}/jwks"),
+            "token_endpoint_auth_methods_supported": ["client_secret_basic"],
+        });
+
+        let app = Router::new()
+            .route(
+                "/.well-known/openid-configuration",
+                get(move || async move { Json(discovery) }),
+            )
+            .route("/jwks", get(move || async move { Json(jwks) }))
+            .route(
+                "/token",
+                post({
+                    let idp = idp.clone();
+                    move |headers: HeaderMap, Form(form): Form<HashMap<String, String>>| {
+                        let idp = idp.clone();
+                        let key = key.clone();
+                        async move {
+                            let mut idp = idp.lock().unwrap();
+                            let authorization = headers
+                                .get("authorization")
+                                .and_then(|value| value.to_str().ok())
+                                .map(str::to_string);
+                            idp.token_requests.push((authorization, form.clone()));
+                            let verifier = form.get("code_verifier").cloned().unwrap_or_default();
+                            let challenge = b64url(&Sha256::digest(verifier.as_bytes()));
+                            if idp.code_challenge.as_deref() != Some(challenge.as_str()) {
+                                return (
+                                    StatusCode::BAD_REQUEST,
+                                    Json(json!({"error": "invalid_grant"})),
+                                );
+                            }
+                            let nonce = if idp.wrong_nonce {
+                                "forged-nonce".to_string()
+                            } else {
+                                idp.nonce.clone().unwrap_or_default()
+                            };
+                            let now = Utc::now().timestamp();
+                            let id_token = sign_es256_jwt(
+                                &key,
+                                &json!({
+                                    "iss": idp.issuer,
+                                    "aud": "oidc-test-client",
+                                    "sub": "oidc-subject-1",
+                                    "nonce": nonce,
+                                    "iat": now,
+                                    "exp": now + 300,
+                                }),
+                            );
+                            (
+                                StatusCode::OK,
+                                Json(json!({
+                                    "access_token": "oidc-access-token",
+                                    "token_type": "Bearer",
+                                    "id_token": id_token,
+                                    "refresh_token": "oidc-refresh-token",
+                                })),
+                            )
+                        }
+                    }
+                }),
+            )
+            .route(
+                "/userinfo",
+                get(|| async {
+                    Json(json!({
+                        "sub": "oidc-subject-1",
+                        "preferred_username": "oidc_user",
+                        "name": "OIDC User",
+                        "picture": "https://idp.example/avatar.png",
+                    }))
+                }),
+            );
+        tokio::spawn(async move {
+            axum::serve(listener, app.into_make_service()).await.unwrap();
+        });
+        (issuer, idp)
+    }
+
+    fn oidc_options_for_test(issuer: &str) -> OidcOptions {
+        OidcOptions {
+            enabled: true,
+            issuer: Some(issuer.to_string()),
+            client_id: Some("oidc-test-client".to_string()),
+            client_secret: Some("oidc-test-secret".to_string()),
+            redirect_url: Some("http://127.0.0.1/console/oauth/oidc/callback".to_string()),
+            ..OidcOptions::disabled()
+        }
+    }
+
+    async fn spawn_oidc_login_server(proxy: TavilyProxy, oidc: OidcOptions) -> SocketAddr {
+        let mut linuxdo_oauth = LinuxDoOAuthOptions::disabled();
+        linuxdo_oauth.refresh_token_crypt_key = Some(*b"0123456789abcdef0123456789abcdef");
+        let state = Arc::new(AppState {
+            proxy,
+            static_dir: None,
+            forward_auth: ForwardAuthConfig::new(None, None, None, None),
+            forward_auth_enabled: false,
+            builtin_admin: BuiltinAdminAuth::new(false, None, None),
+            admin_passkey: AdminPasskeyOptions::disabled(),
+            linuxdo_oauth,
+            linuxdo_credit: LinuxDoCreditOptions::disabled(),
+            oidc,
+            ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
+            dev_open_admin: false,
+            usage_base: "http://127.0.0.1:58088".to_string(),
+            api_key_ip_geo_origin: "https://api.country.is".to_string(),
+            dashboard_overview_cache: new_dashboard_overview_cache(),
+        });
+
+        let app = Router::new()
+            .route("/auth/oidc", get(get_oidc_auth).post(post_oidc_auth))
+            .route("/auth/oidc/finalize", post(post_oidc_finalize))
+            .route("/api/profile", get(get_profile))
+            .route("/api/user/token", get(get_user_token))
+            .with_state(state);
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app.into_make_service()).await.unwrap();
+        });
+        addr
+    }
+
+    /// Starts the login, records the PKCE challenge and nonce the IdP would have received, and
+    /// returns the state plus binding cookie the callback page posts back.
+    async fn start_oidc_login(
+        client: &Client,
+        addr: SocketAddr,
+        idp: &Mutex<MockOidcIdp>,
+        token: Option<&str>,
+    ) -> (String, String) {
+        let request = client.post(format!("http://{addr}/auth/oidc"));
+        let request = match token {
+            Some(token) => request.form(&[("token", token)]),
+            None => request.form(&Vec::<(String, String)>::new()),
+        };
+        let resp = request.send().await.expect("start oidc auth");
+        assert_eq!(resp.status(), reqwest::StatusCode::SEE_OTHER);
+        let location = reqwest::Url::parse(
+            resp.headers()
+                .get(reqwest::header::LOCATION)
+                .and_then(|value| value.to_str().ok())
+                .expect("authorize location"),
+        )
+        .expect("parse authorize url");
+        let query: HashMap<String, String> = location.query_pairs().into_owned().collect();
+        assert_eq!(location.path(), "/authorize");
+        assert_eq!(query.get("code_challenge_method").map(String::as_str), Some("S256"));
+        assert_eq!(query.get("client_id").map(String::as_str), Some("oidc-test-client"));
+        {
+            let mut idp = idp.lock().unwrap();
+            idp.nonce = query.get("nonce").cloned();
+            idp.code_challenge = query.get("code_challenge").cloned();
+        }
+        let binding_cookie = find_cookie_pair(resp.headers(), OAUTH_LOGIN_BINDING_COOKIE_NAME)
+            .expect("oauth binding cookie");
+        (query["state"].clone(), binding_cookie)
+    }
+
+    #[tokio::test]
+    async fn oidc_login_verifies_id_token_and_binds_the_preferred_token() {
+        let db_path = temp_db_path("oidc-login-success");
+        let db_str = db_path.to_string_lossy().to_string();
+        let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+            .await
+            .expect("proxy created");
+        let preferred = proxy
+            .create_access_token(Some("kept from the public page"))
+            .await
+            .expect("create preferred token");
+        let (issuer, idp) = spawn_mock_oidc_idp().await;
+        let addr = spawn_oidc_login_server(proxy, oidc_options_for_test(&issuer)).await;
+        let client = Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .expect("build no-redirect client");
+
+        let profile: Value = client
+            .get(format!("http://{addr}/api/profile"))
+            .send()
+            .await
+            .expect("profile")
+            .json()
+            .await
+            .expect("profile json");
+        assert_eq!(profile["userLoggedIn"], false);
+        assert_eq!(
+            profile["userLoginProviders"],
+            json!([{"id": "oidc", "label": "SSO", "startPath": "/auth/oidc"}])
+        );
+
+        let (state, binding_cookie) =
+            start_oidc_login(&client, addr, &idp, Some(&preferred.token)).await;
+        let resp = client
+            .post(format!("http://{addr}/auth/oidc/finalize"))
+            .header(reqwest::header::COOKIE, binding_cookie)
+            .json(&json!({ "code": "oidc-code", "state": state }))
+            .send()
+            .await
+            .expect("oidc finalize");
+        assert_eq!(resp.status(), reqwest::StatusCode::OK);
+        let session_cookie =
+            find_cookie_pair(resp.headers(), USER_SESSION_COOKIE_NAME).expect("session cookie");
+        let body: Value = resp.json().await.expect("finalize body");
+        assert_eq!(body["outcome"], "success");
+        assert_eq!(body["provider"], "oidc");
+
+        {
+            let idp = idp.lock().unwrap();
+            let (authorization, form) = &idp.token_requests[0];
+            assert!(authorization.as_deref().is_some_and(|value| value.starts_with("Basic ")));
+            assert_eq!(form.get("grant_type").map(String::as_str), Some("authorization_code"));
+            assert!(!form.contains_key("client_secret"));
+        }
+
+        let profile: Value = client
+            .get(format!("http://{addr}/api/profile"))
+            .header(reqwest::header::COOKIE, &session_cookie)
+            .send()
+            .await
+            .expect("profile after login")
+            .json()
+            .await
+            .expect("profile json");
+        assert_eq!(profile["userLoggedIn"], true);
+        assert_eq!(profile["userProvider"], "oidc");
+        assert_eq!(profile["userDisplayName"], "OIDC User");
+        assert_eq!(profile["userAvatarUrl"], "https://idp.example/avatar.png");
+
+        let token: Value = client
+            .get(format!("http://{addr}/api/user/token"))
+            .header(reqwest::header::COOKIE, &session_cookie)
+            .send()
+            .await
+            .expect("user token")
+            .json()
+            .await
+            .expect("user token json");
+        assert_eq!(token["token"], preferred.token);
+
+        let pool = connect_sqlite_test_pool(&db_str).await;
+        let (username, ciphertext): (Option<String>, Option<String>) = sqlx::query_as(
+            "SELECT username, refresh_token_ciphertext FROM oauth_accounts WHERE provider = 'oidc' AND provider_user_id = 'oidc-subject-1'",
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("oidc account");
+        assert_eq!(username.as_deref(), Some("oidc_user"));
+        assert!(ciphertext.is_some(), "refresh token should be persisted for user sync");
+
+        let _ = std::fs::remove_file(db_path);
+    }
+
+    #[tokio::test]
+    async fn oidc_login_rejects_id_tokens_with_a_foreign_nonce() {
+        let db_path = temp_db_path("oidc-login-bad-nonce");
+        let db_str = db_path.to_string_lossy().to_string();
+        let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+            .await
+            .expect("proxy created");
+        let (issuer, idp) = spawn_mock_oidc_idp().await;
+        idp.lock().unwrap().wrong_nonce = true;
+        let addr = spawn_oidc_login_server(proxy, oidc_options_for_test(&issuer)).await;
+        let client = Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .expect("build no-redirect client");
+
+        let (state, binding_cookie) = start_oidc_login(&client, addr, &idp, None).await;
+        let resp = client
+            .post(format!("http://{addr}/auth/oidc/finalize"))
+            .header(reqwest::header::COOKIE, binding_cookie.clone())
+            .json(&json!({ "code": "oidc-code", "state": state }))
+            .send()
+            .await
+            .expect("oidc finalize");
+        assert!(find_cookie_pair(resp.headers(), USER_SESSION_COOKIE_NAME).is_none());
+        let body: Value = resp.json().await.expect("finalize body");
+        assert_eq!(body["outcome"], "upstream_failure");
+        assert!(
+            body["detail"]
+                .as_str()
+                .is_some_and(|detail| detail.contains("nonce mismatch"))
+        );
+
+        // The state is single use even when the upstream exchange fails.
+        let replay: Value = client
+            .post(format!("http://{addr}/auth/oidc/finalize"))
+            .header(reqwest::header::COOKIE, binding_cookie)
+            .json(&json!({ "code": "oidc-code", "state": state }))
+            .send()
+            .await
+            .expect("oidc finalize replay")
+            .json()
+            .await
+            .expect("replay body");
+        assert_eq!(replay["outcome"], "invalid_state");
+
+        let pool = connect_sqlite_test_pool(&db_str).await;
+        let accounts: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM oauth_accounts WHERE provider = 'oidc'")
+                .fetch_one(&pool)
+                .await
+                .expect("count oidc accounts");
+        assert_eq!(accounts, 0);
+
+        let _ = std::fs::remove_file(db_path);
+    }
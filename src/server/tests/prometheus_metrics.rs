@@ -0,0 +1,97 @@
+use super::*;
+use super::core_support_and_parsing::temp_db_path;
+use super::upstream_support_and_manual_jobs::{spawn_proxy_server, spawn_proxy_server_with_dev};
+
+async fn spawn_search_upstream() -> SocketAddr {
+    let app = Router::new().route(
+        "/search",
+        post(|| async { Json(json!({ "query": "metrics", "results": [], "usage": { "credits": 1 } })) }),
+    );
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app.into_make_service())
+            .await
+            .unwrap();
+    });
+    addr
+}
+
+#[tokio::test]
+async fn prometheus_metrics_require_bearer_token_or_admin_and_count_requests() {
+    let db_path = temp_db_path("prometheus-metrics-endpoint");
+    let db_str = db_path.to_string_lossy().to_string();
+    let upstream = format!("http://{}", spawn_search_upstream().await);
+    let proxy = TavilyProxy::with_options(
+        vec!["tvly-metrics".to_string()],
+        &upstream,
+        &db_str,
+        tavily_hikari::TavilyProxyOptions {
+            metrics_bearer_token: Some("scrape-secret".to_string()),
+            ..tavily_hikari::TavilyProxyOptions::from_database_path(&db_str)
+        },
+    )
+    .await
+    .expect("proxy created");
+    let token = proxy
+        .create_access_token(Some("metrics"))
+        .await
+        .expect("create token");
+    let addr = spawn_proxy_server(proxy.clone(), upstream.clone()).await;
+    let client = Client::new();
+
+    let search = client
+        .post(format!("http://{addr}/api/tavily/search"))
+        .bearer_auth(&token.token)
+        .json(&json!({ "query": "metrics" }))
+        .send()
+        .await
+        .expect("search");
+    assert_eq!(search.status(), reqwest::StatusCode::OK);
+
+    let metrics_url = format!("http://{addr}/metrics");
+    let anonymous = client.get(&metrics_url).send().await.expect("anonymous");
+    assert_eq!(anonymous.status(), reqwest::StatusCode::UNAUTHORIZED);
+    let wrong = client
+        .get(&metrics_url)
+        .bearer_auth("not-the-secret")
+        .send()
+        .await
+        .expect("wrong token");
+    assert_eq!(wrong.status(), reqwest::StatusCode::UNAUTHORIZED);
+
+    let scrape = client
+        .get(&metrics_url)
+        .bearer_auth("scrape-secret")
+        .send()
+        .await
+        .expect("scrape");
+    assert_eq!(scrape.status(), reqwest::StatusCode::OK);
+    assert!(
+        scrape
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.starts_with("text/plain; version=0.0.4"))
+    );
+    let body = scrape.text().await.expect("metrics body");
+    for expected in [
+        r#"tavily_hikari_requests_total{request_kind="api:search",outcome="success"} 1"#,
+        r#"tavily_hikari_upstream_request_duration_seconds_count{operation="search",result="ok"} 1"#,
+        r#"status="active"} 1"#,
+        r#"role="full_master"} 1"#,
+    ] {
+        assert!(body.contains(expected), "missing {expected:?} in:\n{body}");
+    }
+
+    // Admin sessions can read the metrics without the scrape token.
+    let admin_addr = spawn_proxy_server_with_dev(proxy.clone(), upstream, true).await;
+    let admin = client
+        .get(format!("http://{admin_addr}/metrics"))
+        .send()
+        .await
+        .expect("admin scrape");
+    assert_eq!(admin.status(), reqwest::StatusCode::OK);
+
+    let _ = std::fs::remove_file(db_path);
+}
@@ -0,0 +1,364 @@
+use super::*;
+use super::core_support_and_parsing::*;
+use super::linuxdo_oauth_and_admin_keys::*;
+use super::upstream_support_and_manual_jobs::*;
+
+    async fn spawn_registration_admin_server(proxy: TavilyProxy) -> SocketAddr {
+        let state = Arc::new(AppState {
+            proxy,
+            static_dir: None,
+            forward_auth: ForwardAuthConfig::new(None, None, None, None),
+            forward_auth_enabled: false,
+            builtin_admin: BuiltinAdminAuth::new(false, None, None),
+            admin_passkey: AdminPasskeyOptions::disabled(),
+            linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
+            linuxdo_credit: LinuxDoCreditOptions::disabled(),
+            oidc: OidcOptions::disabled(),
+            ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
+            dev_open_admin: true,
+            usage_base: "http://127.0.0.1:58088".to_string(),
+            api_key_ip_geo_origin: "https://api.country.is".to_string(),
+            dashboard_overview_cache: new_dashboard_overview_cache(),
+        });
+
+        let app = Router::new()
+            .route(
+                "/api/admin/registration",
+                get(get_admin_registration_settings).patch(patch_admin_registration_settings),
+            )
+            .route(
+                "/api/admin/registration/invites",
+                get(list_registration_invites).post(create_registration_invite),
+            )
+            .route(
+                "/api/admin/registration/invites/:id",
+                delete(revoke_registration_invite),
+            )
+            .route(
+                "/api/admin/registration/approvals",
+                get(list_pending_registration_approvals),
+            )
+            .route("/api/users", get(list_users))
+            .route("/api/users/:id/approve", post(approve_user_registration))
+            .with_state(state);
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app.into_make_service()).await.unwrap();
+        });
+        addr
+    }
+
+    async fn spawn_registration_user_server(
+        proxy: TavilyProxy,
+        provider_user_id: &str,
+        username: &str,
+    ) -> SocketAddr {
+        let oauth_upstream =
+            spawn_linuxdo_oauth_mock_server(provider_user_id, username, username).await;
+        let mut oauth_options = linuxdo_oauth_options_for_test();
+        oauth_options.authorize_url = format!("http://{oauth_upstream}/oauth2/authorize");
+        oauth_options.token_url = format!("http://{oauth_upstream}/oauth2/token");
+        oauth_options.userinfo_url = format!("http://{oauth_upstream}/api/user");
+        spawn_user_oauth_server_with_options(proxy, oauth_options).await
+    }
+
+    /// Runs a LinuxDo login through start and finalize, returning the finalize body and the
+    /// session cookie when one was issued.
+    async fn linuxdo_login_with_invite(
+        addr: SocketAddr,
+        invite_code: Option<&str>,
+    ) -> (Value, Option<String>) {
+        let client = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .expect("build no-redirect client");
+        let mut form = Vec::new();
+        if let Some(code) = invite_code {
+            form.push(("invite_code", code));
+        }
+        let auth_resp = client
+            .post(format!("http://{addr}/auth/linuxdo"))
+            .form(&form)
+            .send()
+            .await
+            .expect("start linuxdo auth");
+        assert_eq!(auth_resp.status(), reqwest::StatusCode::SEE_OTHER);
+        let location = auth_resp
+            .headers()
+            .get(reqwest::header::LOCATION)
+            .and_then(|value| value.to_str().ok())
+            .expect("auth redirect location");
+        let state = reqwest::Url::parse(location)
+            .expect("parse redirect url")
+            .query_pairs()
+            .find_map(|(k, v)| (k == "state").then(|| v.into_owned()))
+            .expect("oauth state");
+        let binding_cookie = find_cookie_pair(auth_resp.headers(), OAUTH_LOGIN_BINDING_COOKIE_NAME)
+            .expect("oauth binding cookie");
+
+        let finalize_resp = client
+            .post(format!("http://{addr}/auth/linuxdo/finalize"))
+            .header(reqwest::header::COOKIE, binding_cookie)
+            .json(&json!({ "code": "invite-code", "state": state }))
+            .send()
+            .await
+            .expect("oauth finalize");
+        assert_eq!(finalize_resp.status(), reqwest::StatusCode::OK);
+        let session_cookie = find_cookie_pair(finalize_resp.headers(), USER_SESSION_COOKIE_NAME);
+        let body = finalize_resp.json().await.expect("oauth finalize body");
+        (body, session_cookie)
+    }
+
+    #[tokio::test]
+    async fn invite_code_registers_while_paused_and_applies_tags_until_used_up() {
+        let db_path = temp_db_path("registration-invite-paused-login");
+        let db_str = db_path.to_string_lossy().to_string();
+        let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+            .await
+            .expect("proxy created");
+        proxy
+            .set_allow_registration(false)
+            .await
+            .expect("disable registration");
+        let tag = proxy
+            .create_user_tag("invitees", "Invitees", None, "quota_delta", 0, 0, 0)
+            .await
+            .expect("create tag");
+        let admin_addr = spawn_registration_admin_server(proxy.clone()).await;
+        let client = reqwest::Client::new();
+        let created = client
+            .post(format!("http://{admin_addr}/api/admin/registration/invites"))
+            .json(&json!({ "code": "friends-2026", "maxUses": 1, "tagIds": [tag.id] }))
+            .send()
+            .await
+            .expect("create invite");
+        assert_eq!(created.status(), reqwest::StatusCode::CREATED);
+        let created: Value = created.json().await.expect("invite body");
+        assert_eq!(created["code"], "FRIENDS-2026");
+        assert_eq!(created["status"], "active");
+
+        let first_addr =
+            spawn_registration_user_server(proxy.clone(), "invitee-1", "invitee_one").await;
+        let (body, session_cookie) =
+            linuxdo_login_with_invite(first_addr, Some("friends-2026")).await;
+        assert_eq!(body["outcome"], "success");
+        assert!(session_cookie.is_some());
+        let invitee = proxy
+            .upsert_oauth_account(&OAuthAccountProfile {
+                provider: "linuxdo".to_string(),
+                provider_user_id: "invitee-1".to_string(),
+                username: Some("invitee_one".to_string()),
+                name: Some("invitee_one".to_string()),
+                avatar_template: None,
+                active: true,
+                trust_level: Some(3),
+                raw_payload_json: None,
+            })
+            .await
+            .expect("lookup invitee");
+        let tags = proxy
+            .list_user_tag_bindings_for_users(std::slice::from_ref(&invitee.user_id))
+            .await
+            .expect("list tags");
+        assert!(
+            tags.get(&invitee.user_id)
+                .is_some_and(|bindings| bindings.iter().any(|binding| binding.tag_id == tag.id))
+        );
+
+        let second_addr =
+            spawn_registration_user_server(proxy.clone(), "invitee-2", "invitee_two").await;
+        let (body, session_cookie) =
+            linuxdo_login_with_invite(second_addr, Some("FRIENDS-2026")).await;
+        assert_eq!(body["outcome"], "invalid_invite");
+        assert!(session_cookie.is_none());
+        let (body, _) = linuxdo_login_with_invite(second_addr, None).await;
+        assert_eq!(body["outcome"], "registration_paused");
+
+        let listed: Value = client
+            .get(format!("http://{admin_addr}/api/admin/registration/invites"))
+            .send()
+            .await
+            .expect("list invites")
+            .json()
+            .await
+            .expect("list body");
+        assert_eq!(listed[0]["useCount"], 1);
+        assert_eq!(listed[0]["status"], "exhausted");
+
+        let _ = std::fs::remove_file(db_path);
+    }
+
+    #[tokio::test]
+    async fn approval_required_logins_wait_for_admin_before_getting_a_token() {
+        let db_path = temp_db_path("registration-approval-login");
+        let db_str = db_path.to_string_lossy().to_string();
+        let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+            .await
+            .expect("proxy created");
+        let admin_addr = spawn_registration_admin_server(proxy.clone()).await;
+        let client = reqwest::Client::new();
+        let settings: Value = client
+            .patch(format!("http://{admin_addr}/api/admin/registration"))
+            .json(&json!({ "allowRegistration": true, "requireApproval": true }))
+            .send()
+            .await
+            .expect("patch registration")
+            .json()
+            .await
+            .expect("registration body");
+        assert_eq!(settings["requireApproval"], true);
+
+        let user_addr =
+            spawn_registration_user_server(proxy.clone(), "waiting-user", "waiting_user").await;
+        let (body, session_cookie) = linuxdo_login_with_invite(user_addr, None).await;
+        assert_eq!(body["outcome"], "success");
+        let session_cookie = session_cookie.expect("pending users still get a session");
+
+        let profile: Value = client
+            .get(format!("http://{user_addr}/api/profile"))
+            .header(reqwest::header::COOKIE, &session_cookie)
+            .send()
+            .await
+            .expect("profile")
+            .json()
+            .await
+            .expect("profile body");
+        assert_eq!(profile["userPendingApproval"], true);
+        let token_resp = client
+            .get(format!("http://{user_addr}/api/user/token"))
+            .header(reqwest::header::COOKIE, &session_cookie)
+            .send()
+            .await
+            .expect("user token");
+        assert_eq!(token_resp.status(), reqwest::StatusCode::NOT_FOUND);
+
+        let queue: Value = client
+            .get(format!("http://{admin_addr}/api/admin/registration/approvals"))
+            .send()
+            .await
+            .expect("list approvals")
+            .json()
+            .await
+            .expect("approvals body");
+        assert_eq!(queue.as_array().map(Vec::len), Some(1));
+        assert_eq!(queue[0]["provider"], "linuxdo");
+        let user_id = queue[0]["userId"].as_str().expect("pending user id").to_string();
+        let users: Value = client
+            .get(format!("http://{admin_addr}/api/users"))
+            .send()
+            .await
+            .expect("list users")
+            .json()
+            .await
+            .expect("users body");
+        assert_eq!(users["items"][0]["pendingApproval"], true);
+
+        let approve = client
+            .post(format!("http://{admin_addr}/api/users/{user_id}/approve"))
+            .send()
+            .await
+            .expect("approve user");
+        assert_eq!(approve.status(), reqwest::StatusCode::NO_CONTENT);
+        let approve_again = client
+            .post(format!("http://{admin_addr}/api/users/{user_id}/approve"))
+            .send()
+            .await
+            .expect("approve user again");
+        assert_eq!(approve_again.status(), reqwest::StatusCode::NOT_FOUND);
+
+        let token_resp = client
+            .get(format!("http://{user_addr}/api/user/token"))
+            .header(reqwest::header::COOKIE, &session_cookie)
+            .send()
+            .await
+            .expect("user token after approval");
+        assert_eq!(token_resp.status(), reqwest::StatusCode::OK);
+        let profile: Value = client
+            .get(format!("http://{user_addr}/api/profile"))
+            .header(reqwest::header::COOKIE, &session_cookie)
+            .send()
+            .await
+            .expect("profile after approval")
+            .json()
+            .await
+            .expect("profile body");
+        assert_eq!(profile["userPendingApproval"], false);
+
+        let _ = std::fs::remove_file(db_path);
+    }
+
+    #[tokio::test]
+    async fn admin_invite_endpoints_validate_input_and_revoke() {
+        let db_path = temp_db_path("registration-invite-admin-api");
+        let db_str = db_path.to_string_lossy().to_string();
+        let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+            .await
+            .expect("proxy created");
+        let admin_addr = spawn_registration_admin_server(proxy).await;
+        let client = reqwest::Client::new();
+        let invites_url = format!("http://{admin_addr}/api/admin/registration/invites");
+
+        for payload in [
+            json!({ "maxUses": 0 }),
+            json!({ "code": "a b c d e f" }),
+            json!({ "tagIds": ["missing-tag"] }),
+            json!({ "expiresAt": 1 }),
+        ] {
+            let resp = client
+                .post(&invites_url)
+                .json(&payload)
+                .send()
+                .await
+                .expect("create invalid invite");
+            assert_eq!(resp.status(), reqwest::StatusCode::BAD_REQUEST, "{payload}");
+        }
+
+        let created: Value = client
+            .post(&invites_url)
+            .json(&json!({
+                "code": "LAUNCH",
+                "note": "launch week",
+                "maxUses": 25,
+                "quota": {
+                    "businessCalls1hLimit": 10,
+                    "dailyCreditsLimit": 100,
+                    "monthlyCreditsLimit": 1000
+                }
+            }))
+            .send()
+            .await
+            .expect("create invite")
+            .json()
+            .await
+            .expect("invite body");
+        assert_eq!(created["maxUses"], 25);
+        assert_eq!(created["quota"]["dailyCreditsLimit"], 100);
+        let duplicate = client
+            .post(&invites_url)
+            .json(&json!({ "code": "launch" }))
+            .send()
+            .await
+            .expect("create duplicate invite");
+        assert_eq!(duplicate.status(), reqwest::StatusCode::CONFLICT);
+
+        let invite_id = created["id"].as_str().expect("invite id");
+        let revoked: Value = client
+            .delete(format!("{invites_url}/{invite_id}"))
+            .send()
+            .await
+            .expect("revoke invite")
+            .json()
+            .await
+            .expect("revoke body");
+        assert_eq!(revoked["status"], "revoked");
+        let missing = client
+            .delete(format!("{invites_url}/missing"))
+            .send()
+            .await
+            .expect("revoke missing invite");
+        assert_eq!(missing.status(), reqwest::StatusCode::NOT_FOUND);
+
+        let _ = std::fs::remove_file(db_path);
+    }
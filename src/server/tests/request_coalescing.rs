@@ -0,0 +1,168 @@
+use super::*;
+use super::core_support_and_parsing::{decode_sse_json_response, temp_db_path};
+use super::upstream_support_and_manual_jobs::spawn_proxy_server;
+
+/// Upstream `/search` that answers slowly enough for identical calls to overlap.
+async fn spawn_slow_search_upstream(calls: Arc<AtomicUsize>) -> SocketAddr {
+    let app = Router::new().route(
+        "/search",
+        post(move |Json(_body): Json<Value>| {
+            let calls = calls.clone();
+            async move {
+                calls.fetch_add(1, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(400)).await;
+                Json(json!({ "query": "shared", "results": [], "usage": { "credits": 2 } }))
+            }
+        }),
+    );
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app.into_make_service())
+            .await
+            .unwrap();
+    });
+    addr
+}
+
+async fn initialize_rebalance_mcp_session(client: &Client, url: &str) -> String {
+    let initialize = client
+        .post(url)
+        .header("accept", "application/json, text/event-stream")
+        .header("mcp-protocol-version", "2025-03-26")
+        .json(&json!({
+            "jsonrpc": "2.0",
+            "id": "coalescing-init",
+            "method": "initialize",
+            "params": {
+                "protocolVersion": "2025-03-26",
+                "capabilities": {},
+                "clientInfo": { "name": "coalescing-probe", "version": "0.1.0" }
+            }
+        }))
+        .send()
+        .await
+        .expect("initialize request");
+    initialize
+        .headers()
+        .get("mcp-session-id")
+        .and_then(|value| value.to_str().ok())
+        .expect("initialize response should expose mcp-session-id")
+        .to_string()
+}
+
+#[tokio::test]
+async fn request_coalescing_shares_one_upstream_call_across_http_and_mcp_waiters() {
+    let db_path = temp_db_path("request-coalescing");
+    let db_str = db_path.to_string_lossy().to_string();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let upstream = format!("http://{}", spawn_slow_search_upstream(calls.clone()).await);
+    let proxy = TavilyProxy::with_endpoint(vec!["tvly-coalescing".to_string()], &upstream, &db_str)
+        .await
+        .expect("proxy created");
+    let mut settings = proxy.get_system_settings().await.expect("read settings");
+    settings.rebalance_mcp_enabled = true;
+    settings.rebalance_mcp_session_percent = 100;
+    settings.request_coalescing = tavily_hikari::RequestCoalescingSettings {
+        enabled: true,
+        follower_billing_percent: 50,
+        ..tavily_hikari::RequestCoalescingSettings::default()
+    };
+    proxy
+        .set_system_settings(&settings)
+        .await
+        .expect("enable request coalescing");
+    let leader_token = proxy
+        .create_access_token(Some("coalescing-leader"))
+        .await
+        .expect("create leader token");
+    let http_waiter = proxy
+        .create_access_token(Some("coalescing-http"))
+        .await
+        .expect("create http waiter token");
+    let mcp_waiter = proxy
+        .create_access_token(Some("coalescing-mcp"))
+        .await
+        .expect("create mcp waiter token");
+    let addr = spawn_proxy_server(proxy.clone(), upstream.clone()).await;
+    let client = Client::new();
+    let mcp_url = format!("http://{addr}/mcp?tavilyApiKey={}", mcp_waiter.token);
+    let proxy_session_id = initialize_rebalance_mcp_session(&client, &mcp_url).await;
+
+    let search = |token: String, body: Value| {
+        let client = client.clone();
+        let url = format!("http://{addr}/api/tavily/search");
+        async move {
+            client
+                .post(url)
+                .bearer_auth(token)
+                .json(&body)
+                .send()
+                .await
+                .expect("http search")
+        }
+    };
+    let leader = tokio::spawn(search(
+        leader_token.token.clone(),
+        json!({ "query": "shared", "max_results": 2 }),
+    ));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    let http_follower = search(
+        http_waiter.token.clone(),
+        json!({ "max_results": 2, "query": "shared" }),
+    );
+    let mcp_follower = client
+        .post(&mcp_url)
+        .header("accept", "application/json, text/event-stream")
+        .header("mcp-protocol-version", "2025-03-26")
+        .header("mcp-session-id", &proxy_session_id)
+        .json(&json!({
+            "jsonrpc": "2.0",
+            "id": "coalesced-call",
+            "method": "tools/call",
+            "params": {
+                "name": "tavily_search",
+                "arguments": { "query": "shared", "max_results": 2 }
+            }
+        }))
+        .send();
+    let (http_follower, mcp_follower) = tokio::join!(http_follower, mcp_follower);
+    let leader = leader.await.expect("leader task");
+
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    let leader_body: Value = leader.json().await.expect("leader body");
+    let http_follower_body: Value = http_follower.json().await.expect("http follower body");
+    assert_eq!(leader_body, http_follower_body);
+    let mcp_body = decode_sse_json_response(mcp_follower.expect("mcp follower")).await;
+    assert_eq!(
+        mcp_body["result"]["structuredContent"]["query"].as_str(),
+        Some("shared")
+    );
+    assert_eq!(
+        mcp_body["result"]["structuredContent"]["usage"]["credits"].as_i64(),
+        Some(1)
+    );
+
+    let leader_logs = proxy
+        .token_recent_logs(&leader_token.id, 1, None)
+        .await
+        .expect("leader logs");
+    assert_ne!(leader_logs[0].key_effect_code, "request_coalesced");
+    assert_eq!(leader_logs[0].business_credits, Some(2));
+    for waiter in [&http_waiter, &mcp_waiter] {
+        let logs = proxy
+            .token_recent_logs(&waiter.id, 1, None)
+            .await
+            .expect("waiter logs");
+        assert_eq!(logs[0].key_effect_code, "request_coalesced");
+        assert_eq!(logs[0].key_id, None);
+        assert_eq!(logs[0].business_credits, Some(1));
+    }
+
+    // Finished flights are not reused: the next identical call goes upstream again.
+    let again = search(leader_token.token.clone(), json!({ "query": "shared", "max_results": 2 })).await;
+    assert_eq!(again.status(), reqwest::StatusCode::OK);
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    let _ = std::fs::remove_file(db_path);
+}
@@ -0,0 +1,153 @@
+use super::*;
+use super::core_support_and_parsing::*;
+
+    async fn spawn_mock_search_upstream() -> String {
+        let app = Router::new().route(
+            "/search",
+            post(|headers: HeaderMap, Json(body): Json<Value>| async move {
+                let bearer = headers
+                    .get("authorization")
+                    .and_then(|value| value.to_str().ok())
+                    .unwrap_or_default()
+                    .to_string();
+                Json(serde_json::json!({
+                    "echo": body["query"],
+                    "bearer": bearer,
+                }))
+            }),
+        );
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app.into_make_service()).await.unwrap();
+        });
+        format!("http://{addr}")
+    }
+
+    async fn spawn_request_log_replay_server(proxy: TavilyProxy, usage_base: String) -> SocketAddr {
+        let state = Arc::new(AppState {
+            proxy,
+            static_dir: None,
+            forward_auth: ForwardAuthConfig::new(None, None, None, None),
+            forward_auth_enabled: false,
+            builtin_admin: BuiltinAdminAuth::new(false, None, None),
+            admin_passkey: AdminPasskeyOptions::disabled(),
+            linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
+            linuxdo_credit: LinuxDoCreditOptions::disabled(),
+            oidc: OidcOptions::disabled(),
+            ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
+            dev_open_admin: true,
+            usage_base,
+            api_key_ip_geo_origin: "https://api.country.is".to_string(),
+            dashboard_overview_cache: new_dashboard_overview_cache(),
+        });
+
+        let app = Router::new()
+            .route(
+                "/api/logs/replay/upstreams",
+                get(get_request_log_replay_upstreams),
+            )
+            .route("/api/logs/:log_id/replay", post(post_request_log_replay))
+            .route("/api/logs/:log_id/replays", get(get_request_log_replays))
+            .with_state(state);
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app.into_make_service()).await.unwrap();
+        });
+        addr
+    }
+
+    #[tokio::test]
+    async fn request_log_replay_endpoints_replay_against_the_primary_upstream() {
+        let db_path = temp_db_path("request-log-replay-endpoints");
+        let db_str = db_path.to_string_lossy().to_string();
+        let proxy = TavilyProxy::with_endpoint(
+            vec!["tvly-replay-endpoint".to_string()],
+            DEFAULT_UPSTREAM,
+            &db_str,
+        )
+        .await
+        .expect("create proxy");
+        let pool = connect_sqlite_test_pool(&db_str).await;
+        let key_id: String = sqlx::query_scalar("SELECT id FROM api_keys LIMIT 1")
+            .fetch_one(&pool)
+            .await
+            .expect("key id");
+        let mut log_ids = Vec::new();
+        for path in ["/api/tavily/search", "/mcp"] {
+            let id = sqlx::query(
+                r#"INSERT INTO observability.request_logs (
+                     api_key_id, method, path, result_status, request_body, created_at
+                   ) VALUES (?, 'POST', ?, 'success', ?, ?)"#,
+            )
+            .bind(&key_id)
+            .bind(path)
+            .bind(br#"{"query":"replayed"}"#.to_vec())
+            .bind(Utc::now().timestamp())
+            .execute(&pool)
+            .await
+            .expect("insert request log")
+            .last_insert_rowid();
+            log_ids.push(id);
+        }
+        let usage_base = spawn_mock_search_upstream().await;
+        let addr = spawn_request_log_replay_server(proxy, format!("{usage_base}/")).await;
+        let client = Client::new();
+
+        let upstreams: Value = client
+            .get(format!("http://{addr}/api/logs/replay/upstreams"))
+            .send()
+            .await
+            .expect("list upstreams")
+            .json()
+            .await
+            .expect("upstreams json");
+        assert_eq!(upstreams["primary"], usage_base);
+        assert_eq!(upstreams["alternates"], serde_json::json!([]));
+
+        let replay = client
+            .post(format!("http://{addr}/api/logs/{}/replay", log_ids[0]))
+            .json(&serde_json::json!({}))
+            .send()
+            .await
+            .expect("replay log");
+        assert_eq!(replay.status(), StatusCode::OK);
+        let replay: Value = replay.json().await.expect("replay json");
+        assert_eq!(replay["statusCode"], 200);
+        assert_eq!(replay["keyId"], key_id);
+        let response: Value =
+            serde_json::from_str(replay["responseBody"].as_str().expect("response body"))
+                .expect("response json");
+        assert_eq!(response["echo"], "replayed");
+        assert_eq!(response["bearer"], "Bearer tvly-replay-endpoint");
+
+        let history: Value = client
+            .get(format!("http://{addr}/api/logs/{}/replays", log_ids[0]))
+            .send()
+            .await
+            .expect("list replays")
+            .json()
+            .await
+            .expect("replays json");
+        assert_eq!(history.as_array().map(Vec::len), Some(1));
+        assert_eq!(history[0]["id"], replay["id"]);
+
+        let mcp = client
+            .post(format!("http://{addr}/api/logs/{}/replay", log_ids[1]))
+            .json(&serde_json::json!({}))
+            .send()
+            .await
+            .expect("replay mcp log");
+        assert_eq!(mcp.status(), StatusCode::BAD_REQUEST);
+        let missing = client
+            .post(format!("http://{addr}/api/logs/999999/replay"))
+            .json(&serde_json::json!({}))
+            .send()
+            .await
+            .expect("replay missing log");
+        assert_eq!(missing.status(), StatusCode::NOT_FOUND);
+
+        let _ = std::fs::remove_file(db_path);
+    }
@@ -0,0 +1,248 @@
+use super::*;
+use super::core_support_and_parsing::temp_db_path;
+use super::upstream_support_and_manual_jobs::{
+    RecordedRebalanceGatewayCalls, spawn_admin_tokens_server,
+    spawn_http_search_mock_asserting_api_key, spawn_proxy_server, spawn_rebalance_gateway_mock,
+};
+
+async fn token_with_policy(
+    proxy: &TavilyProxy,
+    note: &str,
+    policy: RequestParameterPolicy,
+) -> tavily_hikari::AuthTokenSecret {
+    let issued = proxy
+        .create_access_token(Some(note))
+        .await
+        .expect("create token");
+    proxy
+        .set_request_parameter_policy(
+            RequestParameterPolicySubject::Token,
+            &issued.id,
+            Some(&policy),
+        )
+        .await
+        .expect("set token policy");
+    issued
+}
+
+#[tokio::test]
+async fn parameter_policy_rejects_http_and_mcp_requests_with_structured_400() {
+    let db_path = temp_db_path("parameter-policy-reject");
+    let db_str = db_path.to_string_lossy().to_string();
+    let expected_api_key = "tvly-parameter-policy-reject";
+    let upstream_addr = spawn_http_search_mock_asserting_api_key(expected_api_key.to_string()).await;
+    let upstream = format!("http://{upstream_addr}");
+    let proxy = TavilyProxy::with_endpoint(vec![expected_api_key.to_string()], &upstream, &db_str)
+        .await
+        .expect("proxy created");
+    let token = token_with_policy(
+        &proxy,
+        "capped",
+        RequestParameterPolicy {
+            max_search_depth: Some("basic".to_string()),
+            max_crawl_limit: Some(5),
+            ..RequestParameterPolicy::default()
+        },
+    )
+    .await;
+    let addr = spawn_proxy_server(proxy.clone(), upstream.clone()).await;
+    let client = Client::new();
+
+    let advanced = client
+        .post(format!("http://{addr}/api/tavily/search"))
+        .bearer_auth(&token.token)
+        .json(&serde_json::json!({ "query": "costly", "search_depth": "advanced" }))
+        .send()
+        .await
+        .expect("advanced search");
+    assert_eq!(advanced.status(), reqwest::StatusCode::BAD_REQUEST);
+    let body: serde_json::Value = advanced.json().await.expect("violation body");
+    assert_eq!(body["error"], "parameter_policy_violation");
+    assert_eq!(body["parameter"], "search_depth");
+
+    let logs = proxy
+        .token_recent_logs(&token.id, 1, None)
+        .await
+        .expect("token logs");
+    assert_eq!(logs[0].request_kind_key, "api:search");
+    assert_eq!(logs[0].http_status, Some(400));
+    assert_eq!(logs[0].result_status, "error");
+    assert_eq!(
+        logs[0].failure_kind.as_deref(),
+        Some(tavily_hikari::FAILURE_KIND_PARAMETER_POLICY_VIOLATION)
+    );
+    assert!(!logs[0].counts_business_quota);
+
+    let basic = client
+        .post(format!("http://{addr}/api/tavily/search"))
+        .bearer_auth(&token.token)
+        .json(&serde_json::json!({ "query": "cheap", "search_depth": "basic" }))
+        .send()
+        .await
+        .expect("basic search");
+    assert_eq!(basic.status(), reqwest::StatusCode::OK);
+
+    let crawl = client
+        .post(format!("http://{addr}/mcp"))
+        .bearer_auth(&token.token)
+        .header("accept", "application/json, text/event-stream")
+        .json(&serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "tools/call",
+            "params": {
+                "name": "tavily_crawl",
+                "arguments": { "url": "https://example.com", "limit": 100 }
+            }
+        }))
+        .send()
+        .await
+        .expect("mcp crawl");
+    assert_eq!(crawl.status(), reqwest::StatusCode::BAD_REQUEST);
+    let body: serde_json::Value = crawl.json().await.expect("mcp violation body");
+    assert_eq!(body["error"], "parameter_policy_violation");
+    assert_eq!(body["parameter"], "limit");
+    let logs = proxy
+        .token_recent_logs(&token.id, 1, None)
+        .await
+        .expect("token logs after mcp");
+    assert_eq!(logs[0].request_kind_key, "mcp:crawl");
+    assert_eq!(
+        logs[0].failure_kind.as_deref(),
+        Some(tavily_hikari::FAILURE_KIND_PARAMETER_POLICY_VIOLATION)
+    );
+
+    let _ = std::fs::remove_file(db_path);
+}
+
+#[tokio::test]
+async fn parameter_policy_clamp_mode_rewrites_forwarded_http_body() {
+    let db_path = temp_db_path("parameter-policy-clamp");
+    let db_str = db_path.to_string_lossy().to_string();
+    let expected_api_key = "tvly-parameter-policy-clamp";
+    let seen: RecordedRebalanceGatewayCalls = Arc::new(Mutex::new(Vec::new()));
+    let upstream_addr =
+        spawn_rebalance_gateway_mock(expected_api_key.to_string(), seen.clone()).await;
+    let upstream = format!("http://{upstream_addr}");
+    let proxy = TavilyProxy::with_endpoint(vec![expected_api_key.to_string()], &upstream, &db_str)
+        .await
+        .expect("proxy created");
+    let token = token_with_policy(
+        &proxy,
+        "clamped",
+        RequestParameterPolicy {
+            mode: tavily_hikari::RequestParameterPolicyMode::Clamp,
+            max_search_depth: Some("basic".to_string()),
+            max_results: Some(3),
+            max_expected_credits: Some(1),
+            ..RequestParameterPolicy::default()
+        },
+    )
+    .await;
+    let addr = spawn_proxy_server(proxy.clone(), upstream.clone()).await;
+
+    let response = Client::new()
+        .post(format!("http://{addr}/api/tavily/search"))
+        .bearer_auth(&token.token)
+        .json(&serde_json::json!({
+            "query": "clamped",
+            "search_depth": "advanced",
+            "max_results": 20
+        }))
+        .send()
+        .await
+        .expect("clamped search");
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    let calls = seen.lock().expect("recorded calls");
+    let forwarded = calls
+        .iter()
+        .find(|call| call.path == "/search")
+        .expect("search forwarded");
+    assert_eq!(forwarded.body["search_depth"], "basic");
+    assert_eq!(forwarded.body["max_results"], 3);
+    drop(calls);
+
+    let _ = std::fs::remove_file(db_path);
+}
+
+#[tokio::test]
+async fn admin_can_set_and_list_parameter_policies() {
+    let db_path = temp_db_path("parameter-policy-admin");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+        .await
+        .expect("proxy created");
+    let issued = proxy
+        .create_access_tokens_batch("metered", 1, None)
+        .await
+        .expect("create token");
+    let token_id = issued[0].id.clone();
+    let addr = spawn_admin_tokens_server(proxy.clone(), true).await;
+    let client = Client::new();
+
+    let updated = client
+        .patch(format!("http://{addr}/api/tokens/{token_id}/parameter-policy"))
+        .json(&serde_json::json!({
+            "policy": { "mode": "clamp", "maxResults": 5, "allowedResearchModels": ["MINI"] }
+        }))
+        .send()
+        .await
+        .expect("update token policy");
+    assert_eq!(updated.status(), reqwest::StatusCode::NO_CONTENT);
+    let invalid = client
+        .patch(format!("http://{addr}/api/tokens/{token_id}/parameter-policy"))
+        .json(&serde_json::json!({ "policy": { "maxSearchDepth": "deepest" } }))
+        .send()
+        .await
+        .expect("invalid policy");
+    assert_eq!(invalid.status(), reqwest::StatusCode::BAD_REQUEST);
+    let missing = client
+        .patch(format!("http://{addr}/api/tokens/zzzz/parameter-policy"))
+        .json(&serde_json::json!({ "policy": null }))
+        .send()
+        .await
+        .expect("missing token");
+    assert_eq!(missing.status(), reqwest::StatusCode::NOT_FOUND);
+    let missing_tag = client
+        .patch(format!("http://{addr}/api/user-tags/missing/parameter-policy"))
+        .json(&serde_json::json!({ "policy": { "maxResults": 1 } }))
+        .send()
+        .await
+        .expect("missing tag");
+    assert_eq!(missing_tag.status(), reqwest::StatusCode::NOT_FOUND);
+    let group = client
+        .patch(format!("http://{addr}/api/tokens/groups/metered/parameter-policy"))
+        .json(&serde_json::json!({ "policy": { "maxExpectedCredits": 2 } }))
+        .send()
+        .await
+        .expect("update group policy");
+    assert_eq!(group.status(), reqwest::StatusCode::NO_CONTENT);
+
+    let listed: serde_json::Value = client
+        .get(format!("http://{addr}/api/parameter-policies"))
+        .send()
+        .await
+        .expect("list policies")
+        .json()
+        .await
+        .expect("policies body");
+    let listed = listed.as_array().expect("policies array");
+    assert_eq!(listed.len(), 2);
+    let token_entry = listed
+        .iter()
+        .find(|entry| entry["subject"] == "token")
+        .expect("token policy listed");
+    assert_eq!(token_entry["subjectId"], token_id.as_str());
+    assert_eq!(token_entry["policy"]["mode"], "clamp");
+    assert_eq!(
+        token_entry["policy"]["allowedResearchModels"],
+        serde_json::json!(["mini"])
+    );
+    let group_entry = listed
+        .iter()
+        .find(|entry| entry["subject"] == "token_group")
+        .expect("group policy listed");
+    assert_eq!(group_entry["subjectId"], "metered");
+
+    let _ = std::fs::remove_file(db_path);
+}
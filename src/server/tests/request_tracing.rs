@@ -0,0 +1,71 @@
+use super::*;
+use super::core_support_and_parsing::temp_db_path;
+use super::linuxdo_oauth_and_admin_keys::login_builtin_admin_cookie;
+use super::upstream_support_and_manual_jobs::{
+    spawn_builtin_keys_admin_server, spawn_http_search_mock_with_usage, spawn_proxy_server,
+};
+
+const TRACEPARENT: &str = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+
+#[tokio::test]
+async fn http_search_stores_incoming_traceparent_trace_id_on_request_logs() {
+    let db_path = temp_db_path("request-tracing-traceparent");
+    let db_str = db_path.to_string_lossy().to_string();
+    let expected_api_key = "tvly-request-tracing";
+    let (upstream_addr, _hits) =
+        spawn_http_search_mock_with_usage(expected_api_key.to_string()).await;
+    let upstream = format!("http://{upstream_addr}");
+    let proxy = TavilyProxy::with_endpoint(vec![expected_api_key.to_string()], &upstream, &db_str)
+        .await
+        .expect("proxy created");
+    let token = proxy
+        .create_access_token(Some("tracing"))
+        .await
+        .expect("create token");
+    let proxy_addr = spawn_proxy_server(proxy.clone(), upstream.clone()).await;
+    let admin_password = "request-tracing-password";
+    let admin_addr = spawn_builtin_keys_admin_server(proxy, admin_password).await;
+    let (client, admin_cookie) = login_builtin_admin_cookie(admin_addr, admin_password).await;
+
+    let traced = client
+        .post(format!("http://{proxy_addr}/api/tavily/search"))
+        .bearer_auth(&token.token)
+        .header("traceparent", TRACEPARENT)
+        .json(&json!({ "query": "traced" }))
+        .send()
+        .await
+        .expect("traced search");
+    assert_eq!(traced.status(), reqwest::StatusCode::OK);
+    let untraced = client
+        .post(format!("http://{proxy_addr}/api/tavily/search"))
+        .bearer_auth(&token.token)
+        .header("traceparent", "00-not-a-valid-trace-header")
+        .json(&json!({ "query": "untraced" }))
+        .send()
+        .await
+        .expect("untraced search");
+    assert_eq!(untraced.status(), reqwest::StatusCode::OK);
+
+    let logs: Value = client
+        .get(format!("http://{admin_addr}/api/logs?page=1&per_page=20"))
+        .header(reqwest::header::COOKIE, admin_cookie)
+        .send()
+        .await
+        .expect("admin logs")
+        .json()
+        .await
+        .expect("admin logs json");
+    let items = logs
+        .get("items")
+        .and_then(|value| value.as_array())
+        .expect("admin log items");
+    assert_eq!(items.len(), 2);
+    // Newest first: the request with a malformed header carries no trace id.
+    assert!(items[0].get("trace_id").is_some_and(Value::is_null));
+    assert_eq!(
+        items[1].get("trace_id").and_then(|value| value.as_str()),
+        Some("4bf92f3577b34da6a3ce929d0e0e4736")
+    );
+
+    let _ = std::fs::remove_file(db_path);
+}
@@ -0,0 +1,130 @@
+use super::*;
+use super::core_support_and_parsing::temp_db_path;
+use super::upstream_support_and_manual_jobs::{
+    RecordedRebalanceGatewayCalls, spawn_proxy_server, spawn_rebalance_gateway_mock,
+};
+
+async fn enable_response_cache(proxy: &TavilyProxy, hit_billing_percent: i64) {
+    let mut settings = proxy.get_system_settings().await.expect("read settings");
+    settings.response_cache = tavily_hikari::ResponseCacheSettings {
+        enabled: true,
+        hit_billing_percent,
+        ..tavily_hikari::ResponseCacheSettings::default()
+    };
+    proxy
+        .set_system_settings(&settings)
+        .await
+        .expect("enable response cache");
+}
+
+#[tokio::test]
+async fn response_cache_serves_identical_search_without_upstream_call() {
+    let db_path = temp_db_path("response-cache-hit");
+    let db_str = db_path.to_string_lossy().to_string();
+    let expected_api_key = "tvly-response-cache";
+    let seen: RecordedRebalanceGatewayCalls = Arc::new(Mutex::new(Vec::new()));
+    let upstream_addr =
+        spawn_rebalance_gateway_mock(expected_api_key.to_string(), seen.clone()).await;
+    let upstream = format!("http://{upstream_addr}");
+    let proxy = TavilyProxy::with_endpoint(vec![expected_api_key.to_string()], &upstream, &db_str)
+        .await
+        .expect("proxy created");
+    enable_response_cache(&proxy, 0).await;
+    let token = proxy
+        .create_access_token(Some("cached"))
+        .await
+        .expect("create token");
+    let addr = spawn_proxy_server(proxy.clone(), upstream.clone()).await;
+    let client = Client::new();
+
+    let first: serde_json::Value = client
+        .post(format!("http://{addr}/api/tavily/search"))
+        .bearer_auth(&token.token)
+        .json(&serde_json::json!({ "query": "cached", "max_results": 3 }))
+        .send()
+        .await
+        .expect("first search")
+        .json()
+        .await
+        .expect("first body");
+    // Same request with reordered fields, an explicit null and a Hikari routing header.
+    let second = client
+        .post(format!("http://{addr}/api/tavily/search"))
+        .bearer_auth(&token.token)
+        .header("X-Hikari-Routing-Key", "agent-b")
+        .json(&serde_json::json!({ "max_results": 3, "topic": null, "query": "cached" }))
+        .send()
+        .await
+        .expect("second search");
+    assert_eq!(second.status(), reqwest::StatusCode::OK);
+    let second: serde_json::Value = second.json().await.expect("second body");
+    assert_eq!(first, second);
+    let upstream_searches = seen
+        .lock()
+        .expect("recorded calls")
+        .iter()
+        .filter(|call| call.path == "/search")
+        .count();
+    assert_eq!(upstream_searches, 1);
+
+    let logs = proxy
+        .token_recent_logs(&token.id, 2, None)
+        .await
+        .expect("token logs");
+    assert_eq!(logs[0].key_effect_code, "response_cache_hit");
+    assert_eq!(logs[0].key_id, None);
+    assert_eq!(logs[0].result_status, "success");
+    assert_eq!(logs[0].business_credits.unwrap_or(0), 0);
+    assert_eq!(logs[1].business_credits, Some(1));
+
+    let summary = proxy.summary().await.expect("summary");
+    assert!(summary.response_cache.enabled);
+    assert_eq!(summary.response_cache.entries, 1);
+    assert_eq!(summary.response_cache.hits_today, 1);
+    assert_eq!(summary.response_cache.credits_saved_today, 1);
+
+    let _ = std::fs::remove_file(db_path);
+}
+
+#[tokio::test]
+async fn response_cache_bills_hits_at_configured_percentage() {
+    let db_path = temp_db_path("response-cache-billing");
+    let db_str = db_path.to_string_lossy().to_string();
+    let expected_api_key = "tvly-response-cache-billing";
+    let seen: RecordedRebalanceGatewayCalls = Arc::new(Mutex::new(Vec::new()));
+    let upstream_addr =
+        spawn_rebalance_gateway_mock(expected_api_key.to_string(), seen.clone()).await;
+    let upstream = format!("http://{upstream_addr}");
+    let proxy = TavilyProxy::with_endpoint(vec![expected_api_key.to_string()], &upstream, &db_str)
+        .await
+        .expect("proxy created");
+    enable_response_cache(&proxy, 100).await;
+    let token = proxy
+        .create_access_token(Some("full-price"))
+        .await
+        .expect("create token");
+    let addr = spawn_proxy_server(proxy.clone(), upstream.clone()).await;
+    let client = Client::new();
+
+    for _ in 0..2 {
+        let response = client
+            .post(format!("http://{addr}/api/tavily/search"))
+            .bearer_auth(&token.token)
+            .json(&serde_json::json!({ "query": "full price" }))
+            .send()
+            .await
+            .expect("search");
+        assert_eq!(response.status(), reqwest::StatusCode::OK);
+    }
+    assert_eq!(seen.lock().expect("recorded calls").len(), 1);
+    let logs = proxy
+        .token_recent_logs(&token.id, 2, None)
+        .await
+        .expect("token logs");
+    assert_eq!(logs[0].key_effect_code, "response_cache_hit");
+    assert_eq!(logs[0].business_credits, Some(1));
+    let summary = proxy.summary().await.expect("summary");
+    assert_eq!(summary.response_cache.credits_saved_today, 0);
+
+    let _ = std::fs::remove_file(db_path);
+}
@@ -22,6 +22,7 @@ use super::upstream_support_and_manual_jobs::*;
                 rebalance_mcp_session_percent: 100,
                 api_rebalance_enabled: tavily_hikari::API_REBALANCE_ENABLED_DEFAULT,
                 api_rebalance_percent: tavily_hikari::API_REBALANCE_PERCENT_DEFAULT,
+                key_selection_mode: tavily_hikari::KeySelectionMode::Lru,
                 upstream_project_id_mode: tavily_hikari::UpstreamProjectIdMode::AccessToken,
                 upstream_project_id_fixed_value: String::new(),
                 upstream_mcp_user_agent: String::new(),
@@ -34,6 +35,9 @@ use super::upstream_support_and_manual_jobs::*;
                 trusted_proxy_cidrs: tavily_hikari::TrustedClientIpSettings::default().trusted_proxy_cidrs,
                 trusted_client_ip_headers: tavily_hikari::TrustedClientIpSettings::default().trusted_client_ip_headers,
                 request_log_retention: tavily_hikari::default_request_log_retention_settings(),
+                cross_key_retry: tavily_hikari::CrossKeyRetrySettings::default(),
+                response_cache: tavily_hikari::ResponseCacheSettings::default(),
+                request_coalescing: tavily_hikari::RequestCoalescingSettings::default(),
             })
             .await
             .expect("seed system settings");
@@ -2062,47 +2066,6 @@ use super::upstream_support_and_manual_jobs::*;
         let _ = std::fs::remove_file(db_path);
     }
 
-    #[test]
-    fn extract_token_from_query_none_or_empty() {
-        let (q, t) = extract_token_from_query(None);
-        assert_eq!(q, None);
-        assert_eq!(t, None);
-
-        let (q, t) = extract_token_from_query(Some(""));
-        assert_eq!(q, None);
-        assert_eq!(t, None);
-    }
-
-    #[test]
-    fn extract_token_from_query_single_param_case_insensitive() {
-        let (q, t) = extract_token_from_query(Some("TavilyApiKey=th-abc-xyz"));
-        assert_eq!(q, None, "no other params → query should be None");
-        assert_eq!(t.as_deref(), Some("th-abc-xyz"));
-    }
-
-    #[test]
-    fn extract_token_from_query_strips_param_and_preserves_others() {
-        let (q, t) = extract_token_from_query(Some("foo=1&tavilyApiKey=th-abc-xyz&bar=2"));
-        assert_eq!(t.as_deref(), Some("th-abc-xyz"));
-        // Order should be preserved for non-auth params.
-        assert_eq!(q.as_deref(), Some("foo=1&bar=2"));
-    }
-
-    #[test]
-    fn extract_token_from_query_uses_first_non_empty_token() {
-        let (q, t) =
-            extract_token_from_query(Some("tavilyApiKey=&tavilyApiKey=th-abc-xyz&foo=bar"));
-        assert_eq!(t.as_deref(), Some("th-abc-xyz"));
-        assert_eq!(q.as_deref(), Some("foo=bar"));
-    }
-
-    #[test]
-    fn extract_token_from_query_ignores_additional_token_params() {
-        let (q, t) = extract_token_from_query(Some("tavilyApiKey=th-1&tavilyApiKey=th-2&foo=bar"));
-        assert_eq!(t.as_deref(), Some("th-1"));
-        assert_eq!(q.as_deref(), Some("foo=bar"));
-    }
-
 
     #[tokio::test]
     async fn mcp_rebalance_tools_call_rejects_invalid_arguments_locally() {
@@ -2127,6 +2090,7 @@ use super::upstream_support_and_manual_jobs::*;
                 rebalance_mcp_session_percent: 100,
                 api_rebalance_enabled: tavily_hikari::API_REBALANCE_ENABLED_DEFAULT,
                 api_rebalance_percent: tavily_hikari::API_REBALANCE_PERCENT_DEFAULT,
+                key_selection_mode: tavily_hikari::KeySelectionMode::Lru,
                 upstream_project_id_mode: tavily_hikari::UpstreamProjectIdMode::AccessToken,
                 upstream_project_id_fixed_value: String::new(),
                 upstream_mcp_user_agent: String::new(),
@@ -2139,6 +2103,9 @@ use super::upstream_support_and_manual_jobs::*;
                 trusted_proxy_cidrs: tavily_hikari::TrustedClientIpSettings::default().trusted_proxy_cidrs,
                 trusted_client_ip_headers: tavily_hikari::TrustedClientIpSettings::default().trusted_client_ip_headers,
                 request_log_retention: tavily_hikari::default_request_log_retention_settings(),
+                cross_key_retry: tavily_hikari::CrossKeyRetrySettings::default(),
+                response_cache: tavily_hikari::ResponseCacheSettings::default(),
+                request_coalescing: tavily_hikari::RequestCoalescingSettings::default(),
             })
             .await
             .expect("enable rebalance mcp");
@@ -2446,6 +2413,7 @@ use super::upstream_support_and_manual_jobs::*;
                 rebalance_mcp_session_percent: 100,
                 api_rebalance_enabled: tavily_hikari::API_REBALANCE_ENABLED_DEFAULT,
                 api_rebalance_percent: tavily_hikari::API_REBALANCE_PERCENT_DEFAULT,
+                key_selection_mode: tavily_hikari::KeySelectionMode::Lru,
                 upstream_project_id_mode: tavily_hikari::UpstreamProjectIdMode::AccessToken,
                 upstream_project_id_fixed_value: String::new(),
                 upstream_mcp_user_agent: String::new(),
@@ -2458,6 +2426,9 @@ use super::upstream_support_and_manual_jobs::*;
                 trusted_proxy_cidrs: tavily_hikari::TrustedClientIpSettings::default().trusted_proxy_cidrs,
                 trusted_client_ip_headers: tavily_hikari::TrustedClientIpSettings::default().trusted_client_ip_headers,
                 request_log_retention: tavily_hikari::default_request_log_retention_settings(),
+                cross_key_retry: tavily_hikari::CrossKeyRetrySettings::default(),
+                response_cache: tavily_hikari::ResponseCacheSettings::default(),
+                request_coalescing: tavily_hikari::RequestCoalescingSettings::default(),
             })
             .await
             .expect("enable rebalance mcp");
@@ -2585,6 +2556,7 @@ use super::upstream_support_and_manual_jobs::*;
                 rebalance_mcp_session_percent: 100,
                 api_rebalance_enabled: tavily_hikari::API_REBALANCE_ENABLED_DEFAULT,
                 api_rebalance_percent: tavily_hikari::API_REBALANCE_PERCENT_DEFAULT,
+                key_selection_mode: tavily_hikari::KeySelectionMode::Lru,
                 upstream_project_id_mode: tavily_hikari::UpstreamProjectIdMode::AccessToken,
                 upstream_project_id_fixed_value: String::new(),
                 upstream_mcp_user_agent: String::new(),
@@ -2597,6 +2569,9 @@ use super::upstream_support_and_manual_jobs::*;
                 trusted_proxy_cidrs: tavily_hikari::TrustedClientIpSettings::default().trusted_proxy_cidrs,
                 trusted_client_ip_headers: tavily_hikari::TrustedClientIpSettings::default().trusted_client_ip_headers,
                 request_log_retention: tavily_hikari::default_request_log_retention_settings(),
+                cross_key_retry: tavily_hikari::CrossKeyRetrySettings::default(),
+                response_cache: tavily_hikari::ResponseCacheSettings::default(),
+                request_coalescing: tavily_hikari::RequestCoalescingSettings::default(),
             })
             .await
             .expect("enable rebalance mcp");
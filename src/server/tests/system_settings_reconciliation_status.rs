@@ -202,3 +202,66 @@ async fn admin_system_status_reports_reconciliation_diagnostic_timestamps() {
 
     let _ = std::fs::remove_file(db_path);
 }
+
+#[tokio::test]
+async fn admin_system_settings_persists_key_selection_mode() {
+    let db_path = temp_db_path("admin-system-settings-key-selection-mode");
+    let db_str = db_path.to_string_lossy().to_string();
+    let upstream_addr = spawn_forward_proxy_probe_upstream().await;
+    let upstream = format!("http://{}/mcp", upstream_addr);
+    let usage_base = format!("http://{}", upstream_addr);
+    let proxy =
+        TavilyProxy::with_endpoint::<Vec<String>, String>(Vec::new(), &upstream, &db_str)
+            .await
+            .expect("create proxy");
+    let addr = spawn_admin_forward_proxy_server(proxy, usage_base, true).await;
+    let client = Client::new();
+
+    let response = client
+        .put(format!("http://{addr}/api/settings/system"))
+        .json(&serde_json::json!({
+            "mcpSessionAffinityKeyCount": 5,
+            "keySelectionMode": "quotaWeighted",
+        }))
+        .send()
+        .await
+        .expect("enable quota-weighted selection");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response
+        .json::<serde_json::Value>()
+        .await
+        .expect("decode updated body");
+    assert_eq!(body["keySelectionMode"].as_str(), Some("quotaWeighted"));
+
+    let response = client
+        .put(format!("http://{addr}/api/settings/system"))
+        .json(&serde_json::json!({
+            "mcpSessionAffinityKeyCount": 6,
+        }))
+        .send()
+        .await
+        .expect("update unrelated setting");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response
+        .json::<serde_json::Value>()
+        .await
+        .expect("decode preserved body");
+    assert_eq!(
+        body["keySelectionMode"].as_str(),
+        Some("quotaWeighted"),
+        "omitting keySelectionMode keeps the stored mode"
+    );
+
+    let response = client
+        .put(format!("http://{addr}/api/settings/system"))
+        .json(&serde_json::json!({
+            "mcpSessionAffinityKeyCount": 5,
+            "keySelectionMode": "roundRobin",
+        }))
+        .send()
+        .await
+        .expect("reject unknown mode");
+    assert!(response.status().is_client_error());
+
+    let _ = std::fs::remove_file(db_path);
+}
@@ -83,26 +83,6 @@ async fn tavily_http_free_account_boundary_rejects_unsupported_public_api_surfac
         "safe_search rejection should name the unsupported parameter"
     );
 
-    let stream_resp = client
-        .post(format!("http://{proxy_addr}/api/tavily/research"))
-        .json(&serde_json::json!({
-            "api_key": access_token.token,
-            "input": "streaming should be rejected",
-            "stream": true
-        }))
-        .send()
-        .await
-        .expect("stream research request");
-    assert_eq!(stream_resp.status(), reqwest::StatusCode::BAD_REQUEST);
-    let stream_body: serde_json::Value = stream_resp.json().await.expect("stream error body");
-    assert_eq!(stream_body["error"].as_str(), Some("invalid_request"));
-    assert!(
-        stream_body["message"]
-            .as_str()
-            .is_some_and(|message| message.contains("stream=true")),
-        "stream rejection should name stream=true"
-    );
-
     let org_usage_resp = client
         .post(format!("http://{proxy_addr}/api/tavily/org-usage"))
         .json(&serde_json::json!({
@@ -0,0 +1,316 @@
+use super::*;
+use super::core_support_and_parsing::*;
+use super::upstream_support_and_manual_jobs::*;
+
+const RESEARCH_STREAM_BODY: &str = concat!(
+    "data: {\"id\":\"mock-stream-research\",\"object\":\"chat.completion.chunk\",\"choices\":[{\"delta\":{\"content\":\"hello\"}}]}\n\n",
+    "data: {\"id\":\"mock-stream-research\",\"object\":\"chat.completion.chunk\",\"choices\":[{\"delta\":{\"content\":\" world\"}}],\"usage\":{\"credits\":23}}\n\n",
+    "event: done\ndata: {}\n\n",
+);
+
+async fn spawn_http_research_stream_mock(expected_api_key: String) -> SocketAddr {
+    let app = Router::new()
+        .route(
+            "/research",
+            post(move |headers: HeaderMap, Json(body): Json<Value>| {
+                let expected_api_key = expected_api_key.clone();
+                async move {
+                    assert_upstream_json_auth(&headers, &body, &expected_api_key, "/research");
+                    assert_eq!(body.get("stream").and_then(Value::as_bool), Some(true));
+                    let chunks = RESEARCH_STREAM_BODY
+                        .split_inclusive("\n\n")
+                        .map(|chunk| Ok::<Bytes, Infallible>(Bytes::from(chunk.to_string())))
+                        .collect::<Vec<_>>();
+                    Response::builder()
+                        .status(StatusCode::OK)
+                        .header(CONTENT_TYPE, "text/event-stream")
+                        .body(Body::from_stream(futures_util::stream::iter(chunks)))
+                        .unwrap()
+                }
+            }),
+        )
+        .route(
+            "/research/:request_id",
+            get(|Path(request_id): Path<String>| async move {
+                Json(serde_json::json!({ "request_id": request_id, "status": "completed" }))
+            }),
+        );
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app.into_make_service())
+            .await
+            .unwrap();
+    });
+    addr
+}
+
+/// Streams the first chunk, then holds the rest of the body until `release` is notified.
+async fn spawn_held_http_research_stream_mock(
+    expected_api_key: String,
+    release: Arc<Notify>,
+) -> SocketAddr {
+    let app = Router::new().route(
+        "/research",
+        post(move |headers: HeaderMap, Json(body): Json<Value>| {
+            let expected_api_key = expected_api_key.clone();
+            let release = release.clone();
+            async move {
+                assert_upstream_json_auth(&headers, &body, &expected_api_key, "/research");
+                let body = stream! {
+                    let mut chunks = RESEARCH_STREAM_BODY.split_inclusive("\n\n");
+                    if let Some(first) = chunks.next() {
+                        yield Ok::<Bytes, Infallible>(Bytes::from(first.to_string()));
+                    }
+                    release.notified().await;
+                    for chunk in chunks {
+                        yield Ok::<Bytes, Infallible>(Bytes::from(chunk.to_string()));
+                    }
+                };
+                Response::builder()
+                    .status(StatusCode::OK)
+                    .header(CONTENT_TYPE, "text/event-stream")
+                    .body(Body::from_stream(body))
+                    .unwrap()
+            }
+        }),
+    );
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app.into_make_service())
+            .await
+            .unwrap();
+    });
+    addr
+}
+
+#[tokio::test]
+async fn tavily_http_research_stream_forwards_sse_and_bills_final_usage_event() {
+    let db_path = temp_db_path("http-research-stream");
+    let db_str = db_path.to_string_lossy().to_string();
+
+    // Avoid cross-test env var interference.
+    let _hourly_business_guard = EnvVarGuard::set("TOKEN_HOURLY_LIMIT", "1000");
+
+    let expected_api_key = "tvly-http-research-stream-key";
+    let upstream_addr = spawn_http_research_stream_mock(expected_api_key.to_string()).await;
+    let proxy = TavilyProxy::with_endpoint(
+        vec![expected_api_key.to_string()],
+        DEFAULT_UPSTREAM,
+        &db_str,
+    )
+    .await
+    .expect("proxy created");
+    let access_token = proxy
+        .create_access_token(Some("http-research-stream"))
+        .await
+        .expect("create token");
+    let proxy_addr = spawn_proxy_server(proxy.clone(), format!("http://{upstream_addr}")).await;
+    let client = Client::new();
+
+    let resp = client
+        .post(format!("http://{proxy_addr}/api/tavily/research"))
+        .json(&serde_json::json!({
+            "api_key": access_token.token,
+            "input": "stream me",
+            "model": "mini",
+            "stream": true
+        }))
+        .send()
+        .await
+        .expect("stream research request");
+    assert_eq!(resp.status(), reqwest::StatusCode::OK);
+    assert_eq!(
+        resp.headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok()),
+        Some("text/event-stream")
+    );
+    let body = resp.text().await.expect("stream body");
+    assert_eq!(body, RESEARCH_STREAM_BODY);
+
+    let mut hourly_used = 0;
+    for _ in 0..50 {
+        hourly_used = proxy
+            .peek_token_quota(&access_token.id)
+            .await
+            .expect("peek quota")
+            .hourly_used;
+        if hourly_used == 23 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert_eq!(
+        hourly_used, 23,
+        "streamed research should bill the final usage event instead of the mini estimate"
+    );
+
+    let pool = connect_sqlite_test_pool(&db_str).await;
+    let (log_id, stored_body): (i64, Vec<u8>) = sqlx::query_as(
+        "SELECT id, response_body FROM request_logs WHERE path = '/api/tavily/research' ORDER BY id DESC LIMIT 1",
+    )
+    .fetch_one(&pool)
+    .await
+    .expect("stream request log");
+    assert!(
+        stored_body.starts_with(&[0x28, 0xB5, 0x2F, 0xFD]),
+        "stream transcript should be stored zstd-compressed"
+    );
+    let bodies = proxy
+        .request_log_bodies(log_id)
+        .await
+        .expect("request log bodies")
+        .expect("request log bodies exist");
+    assert_eq!(
+        bodies.response_body.as_deref(),
+        Some(RESEARCH_STREAM_BODY.as_bytes())
+    );
+
+    let result_resp = client
+        .get(format!(
+            "http://{proxy_addr}/api/tavily/research/mock-stream-research"
+        ))
+        .header("Authorization", format!("Bearer {}", access_token.token))
+        .send()
+        .await
+        .expect("research result request");
+    assert_eq!(
+        result_resp.status(),
+        reqwest::StatusCode::OK,
+        "streamed research request id should be pinned to the streaming token"
+    );
+
+    let _ = std::fs::remove_file(db_path);
+}
+
+#[tokio::test]
+async fn tavily_http_research_stream_falls_back_to_estimate_for_buffered_upstream_response() {
+    let db_path = temp_db_path("http-research-stream-buffered");
+    let db_str = db_path.to_string_lossy().to_string();
+
+    // Avoid cross-test env var interference.
+    let _hourly_business_guard = EnvVarGuard::set("TOKEN_HOURLY_LIMIT", "1000");
+
+    let expected_api_key = "tvly-http-research-stream-buffered-key";
+    let (upstream_addr, _usage_calls, research_calls) =
+        spawn_http_research_mock_with_usage_diff(expected_api_key.to_string(), 10, 0).await;
+    let proxy = TavilyProxy::with_endpoint(
+        vec![expected_api_key.to_string()],
+        DEFAULT_UPSTREAM,
+        &db_str,
+    )
+    .await
+    .expect("proxy created");
+    let access_token = proxy
+        .create_access_token(Some("http-research-stream-buffered"))
+        .await
+        .expect("create token");
+    let proxy_addr = spawn_proxy_server(proxy.clone(), format!("http://{upstream_addr}")).await;
+
+    let resp = Client::new()
+        .post(format!("http://{proxy_addr}/api/tavily/research"))
+        .json(&serde_json::json!({
+            "api_key": access_token.token,
+            "input": "stream requested, json returned",
+            "model": "mini",
+            "stream": true
+        }))
+        .send()
+        .await
+        .expect("stream research request");
+    assert_eq!(resp.status(), reqwest::StatusCode::OK);
+    let body: Value = resp.json().await.expect("buffered research body");
+    assert_eq!(
+        body.get("request_id").and_then(Value::as_str),
+        Some("mock-research-request")
+    );
+    assert_eq!(research_calls.load(Ordering::SeqCst), 1);
+
+    let verdict = proxy
+        .peek_token_quota(&access_token.id)
+        .await
+        .expect("peek quota");
+    assert_eq!(verdict.hourly_used, 40);
+
+    let _ = std::fs::remove_file(db_path);
+}
+
+#[tokio::test]
+async fn tavily_http_research_stream_holds_estimate_against_concurrent_prechecks() {
+    let db_path = temp_db_path("http-research-stream-concurrent");
+    let db_str = db_path.to_string_lossy().to_string();
+
+    // Room for one mini research estimate (40 credits) but not two.
+    let _hourly_business_guard = EnvVarGuard::set("TOKEN_HOURLY_LIMIT", "60");
+
+    let expected_api_key = "tvly-http-research-stream-concurrent-key";
+    let release = Arc::new(Notify::new());
+    let upstream_addr =
+        spawn_held_http_research_stream_mock(expected_api_key.to_string(), release.clone()).await;
+    let proxy = TavilyProxy::with_endpoint(
+        vec![expected_api_key.to_string()],
+        DEFAULT_UPSTREAM,
+        &db_str,
+    )
+    .await
+    .expect("proxy created");
+    let access_token = proxy
+        .create_access_token(Some("http-research-stream-concurrent"))
+        .await
+        .expect("create token");
+    let proxy_addr = spawn_proxy_server(proxy.clone(), format!("http://{upstream_addr}")).await;
+    let client = Client::new();
+    let request_body = serde_json::json!({
+        "api_key": access_token.token,
+        "input": "stream me",
+        "model": "mini",
+        "stream": true
+    });
+
+    let first = client
+        .post(format!("http://{proxy_addr}/api/tavily/research"))
+        .json(&request_body)
+        .send()
+        .await
+        .expect("first stream research request");
+    assert_eq!(first.status(), reqwest::StatusCode::OK);
+
+    let second = client
+        .post(format!("http://{proxy_addr}/api/tavily/research"))
+        .json(&request_body)
+        .send()
+        .await
+        .expect("second stream research request");
+    assert_eq!(
+        second.status(),
+        reqwest::StatusCode::TOO_MANY_REQUESTS,
+        "the open stream's estimate should count against the second precheck"
+    );
+
+    release.notify_one();
+    let body = first.text().await.expect("first stream body");
+    assert_eq!(body, RESEARCH_STREAM_BODY);
+
+    let mut hourly_used = 0;
+    for _ in 0..50 {
+        hourly_used = proxy
+            .peek_token_quota(&access_token.id)
+            .await
+            .expect("peek quota")
+            .hourly_used;
+        if hourly_used == 23 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert_eq!(
+        hourly_used, 23,
+        "settlement should swap the reserved estimate for the final usage event"
+    );
+
+    let _ = std::fs::remove_file(db_path);
+}
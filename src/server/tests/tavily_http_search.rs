@@ -990,6 +990,7 @@ use tower::ServiceExt;
             admin_passkey: AdminPasskeyOptions::disabled(),
             linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
             linuxdo_credit: LinuxDoCreditOptions::disabled(),
+            oidc: OidcOptions::disabled(),
             ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
             dev_open_admin: false,
             usage_base,
@@ -1099,6 +1100,7 @@ use tower::ServiceExt;
                     tavily_hikari::REBALANCE_MCP_SESSION_PERCENT_DEFAULT,
                 api_rebalance_enabled: tavily_hikari::API_REBALANCE_ENABLED_DEFAULT,
                 api_rebalance_percent: tavily_hikari::API_REBALANCE_PERCENT_DEFAULT,
+                key_selection_mode: tavily_hikari::KeySelectionMode::Lru,
                 upstream_project_id_mode: tavily_hikari::UpstreamProjectIdMode::AccessToken,
                 upstream_project_id_fixed_value: String::new(),
                 upstream_mcp_user_agent: String::new(),
@@ -1111,6 +1113,9 @@ use tower::ServiceExt;
                 trusted_proxy_cidrs: tavily_hikari::TrustedClientIpSettings::default().trusted_proxy_cidrs,
                 trusted_client_ip_headers: tavily_hikari::TrustedClientIpSettings::default().trusted_client_ip_headers,
                 request_log_retention: tavily_hikari::default_request_log_retention_settings(),
+                cross_key_retry: tavily_hikari::CrossKeyRetrySettings::default(),
+                response_cache: tavily_hikari::ResponseCacheSettings::default(),
+                request_coalescing: tavily_hikari::RequestCoalescingSettings::default(),
             })
             .await
             .expect("set request-rate limit");
@@ -1326,6 +1331,7 @@ use tower::ServiceExt;
                     tavily_hikari::REBALANCE_MCP_SESSION_PERCENT_DEFAULT,
                 api_rebalance_enabled: tavily_hikari::API_REBALANCE_ENABLED_DEFAULT,
                 api_rebalance_percent: tavily_hikari::API_REBALANCE_PERCENT_DEFAULT,
+                key_selection_mode: tavily_hikari::KeySelectionMode::Lru,
                 upstream_project_id_mode: tavily_hikari::UpstreamProjectIdMode::AccessToken,
                 upstream_project_id_fixed_value: String::new(),
                 upstream_mcp_user_agent: String::new(),
@@ -1338,6 +1344,9 @@ use tower::ServiceExt;
                 trusted_proxy_cidrs: tavily_hikari::TrustedClientIpSettings::default().trusted_proxy_cidrs,
                 trusted_client_ip_headers: tavily_hikari::TrustedClientIpSettings::default().trusted_client_ip_headers,
                 request_log_retention: tavily_hikari::default_request_log_retention_settings(),
+                cross_key_retry: tavily_hikari::CrossKeyRetrySettings::default(),
+                response_cache: tavily_hikari::ResponseCacheSettings::default(),
+                request_coalescing: tavily_hikari::RequestCoalescingSettings::default(),
             })
             .await
             .expect("set request-rate limit");
@@ -1427,6 +1436,7 @@ use tower::ServiceExt;
                     tavily_hikari::REBALANCE_MCP_SESSION_PERCENT_DEFAULT,
                 api_rebalance_enabled: tavily_hikari::API_REBALANCE_ENABLED_DEFAULT,
                 api_rebalance_percent: tavily_hikari::API_REBALANCE_PERCENT_DEFAULT,
+                key_selection_mode: tavily_hikari::KeySelectionMode::Lru,
                 upstream_project_id_mode: tavily_hikari::UpstreamProjectIdMode::AccessToken,
                 upstream_project_id_fixed_value: String::new(),
                 upstream_mcp_user_agent: String::new(),
@@ -1439,6 +1449,9 @@ use tower::ServiceExt;
                 trusted_proxy_cidrs: tavily_hikari::TrustedClientIpSettings::default().trusted_proxy_cidrs,
                 trusted_client_ip_headers: tavily_hikari::TrustedClientIpSettings::default().trusted_client_ip_headers,
                 request_log_retention: tavily_hikari::default_request_log_retention_settings(),
+                cross_key_retry: tavily_hikari::CrossKeyRetrySettings::default(),
+                response_cache: tavily_hikari::ResponseCacheSettings::default(),
+                request_coalescing: tavily_hikari::RequestCoalescingSettings::default(),
             })
             .await
             .expect("lower request-rate limit");
@@ -2887,6 +2900,7 @@ use tower::ServiceExt;
                     tavily_hikari::REBALANCE_MCP_SESSION_PERCENT_DEFAULT,
                 api_rebalance_enabled: tavily_hikari::API_REBALANCE_ENABLED_DEFAULT,
                 api_rebalance_percent: tavily_hikari::API_REBALANCE_PERCENT_DEFAULT,
+                key_selection_mode: tavily_hikari::KeySelectionMode::Lru,
                 upstream_project_id_mode: tavily_hikari::UpstreamProjectIdMode::AccessToken,
                 upstream_project_id_fixed_value: String::new(),
                 upstream_mcp_user_agent: String::new(),
@@ -2899,6 +2913,9 @@ use tower::ServiceExt;
                 trusted_proxy_cidrs: tavily_hikari::TrustedClientIpSettings::default().trusted_proxy_cidrs,
                 trusted_client_ip_headers: tavily_hikari::TrustedClientIpSettings::default().trusted_client_ip_headers,
                 request_log_retention: tavily_hikari::default_request_log_retention_settings(),
+                cross_key_retry: tavily_hikari::CrossKeyRetrySettings::default(),
+                response_cache: tavily_hikari::ResponseCacheSettings::default(),
+                request_coalescing: tavily_hikari::RequestCoalescingSettings::default(),
             })
             .await
             .expect("set request-rate limit");
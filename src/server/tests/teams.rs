@@ -0,0 +1,299 @@
+use super::*;
+use super::core_support_and_parsing::*;
+use super::linuxdo_oauth_and_admin_keys::find_cookie_pair;
+use super::upstream_support_and_manual_jobs::linuxdo_oauth_options_for_test;
+
+const OWNER_PASSWORD: &str = "builtin-owner-password";
+
+async fn spawn_teams_server(proxy: TavilyProxy) -> SocketAddr {
+    let state = Arc::new(AppState {
+        proxy,
+        static_dir: None,
+        forward_auth: ForwardAuthConfig::new(None, None, None, None),
+        forward_auth_enabled: false,
+        builtin_admin: BuiltinAdminAuth::new(true, Some(OWNER_PASSWORD.to_string()), None),
+        admin_passkey: AdminPasskeyOptions::disabled(),
+        linuxdo_oauth: linuxdo_oauth_options_for_test(),
+        linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
+        ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
+        dev_open_admin: false,
+        usage_base: "http://127.0.0.1:58088".to_string(),
+        api_key_ip_geo_origin: "https://api.country.is".to_string(),
+        dashboard_overview_cache: new_dashboard_overview_cache(),
+    });
+
+    let app = Router::new()
+        .route("/api/admin/login", post(post_admin_login))
+        .route("/api/teams", get(list_teams).post(create_team))
+        .route(
+            "/api/teams/:id",
+            get(get_team_detail).patch(update_team).delete(delete_team),
+        )
+        .route(
+            "/api/teams/:id/members/:user_id",
+            put(put_team_member).delete(delete_team_member),
+        )
+        .route("/api/teams/:id/tokens", post(create_team_token))
+        .route("/api/teams/:id/usage-series", get(get_team_usage_series))
+        .route("/api/user/team", get(get_user_team))
+        .route(
+            "/api/user/team/tokens",
+            get(get_user_team_tokens).post(post_user_team_token),
+        )
+        .route(
+            "/api/user/team/tokens/:token_id",
+            delete(delete_user_team_token),
+        )
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            admin_rbac_http_layer,
+        ))
+        .with_state(state);
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .await
+        .unwrap();
+    });
+    addr
+}
+
+async fn team_user_cookie(proxy: &TavilyProxy, provider_user_id: &str) -> (String, String) {
+    let user = proxy
+        .upsert_oauth_account(&OAuthAccountProfile {
+            provider: "linuxdo".to_string(),
+            provider_user_id: provider_user_id.to_string(),
+            username: Some(provider_user_id.to_string()),
+            name: None,
+            avatar_template: None,
+            active: true,
+            trust_level: Some(2),
+            raw_payload_json: None,
+        })
+        .await
+        .expect("upsert oauth user");
+    let session = proxy
+        .create_user_session(&user, 3600)
+        .await
+        .expect("create user session");
+    (
+        user.user_id,
+        format!("{USER_SESSION_COOKIE_NAME}={}", session.token),
+    )
+}
+
+#[tokio::test]
+async fn admin_manages_teams_and_members_pick_up_team_console_views() {
+    let db_path = temp_db_path("teams-http");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+        .await
+        .expect("create proxy");
+    let (owner_id, owner_cookie) = team_user_cookie(&proxy, "team-http-owner").await;
+    let (member_id, member_cookie) = team_user_cookie(&proxy, "team-http-member").await;
+    let addr = spawn_teams_server(proxy).await;
+    let base = format!("http://{addr}");
+    let client = Client::new();
+
+    let login = client
+        .post(format!("{base}/api/admin/login"))
+        .json(&serde_json::json!({ "password": OWNER_PASSWORD }))
+        .send()
+        .await
+        .expect("admin login");
+    assert_eq!(login.status(), StatusCode::OK);
+    let admin = find_cookie_pair(login.headers(), BUILTIN_ADMIN_COOKIE_NAME).expect("admin cookie");
+
+    let anonymous = client
+        .get(format!("{base}/api/teams"))
+        .send()
+        .await
+        .expect("anonymous list teams");
+    assert_eq!(anonymous.status(), StatusCode::FORBIDDEN);
+
+    let limits = serde_json::json!({
+        "hourlyCreditsLimit": 50,
+        "dailyCreditsLimit": 500,
+        "monthlyCreditsLimit": 5000,
+    });
+    let invalid = client
+        .post(format!("{base}/api/teams"))
+        .header(reqwest::header::COOKIE, &admin)
+        .json(&serde_json::json!({ "name": " ", "limits": limits }))
+        .send()
+        .await
+        .expect("create invalid team");
+    assert_eq!(invalid.status(), StatusCode::BAD_REQUEST);
+
+    let created = client
+        .post(format!("{base}/api/teams"))
+        .header(reqwest::header::COOKIE, &admin)
+        .json(&serde_json::json!({ "name": "Research", "limits": limits }))
+        .send()
+        .await
+        .expect("create team");
+    assert_eq!(created.status(), StatusCode::CREATED);
+    let created: serde_json::Value = created.json().await.expect("decode team");
+    let team_id = created["id"].as_str().expect("team id").to_string();
+    assert_eq!(created["enforceMemberLimits"], true);
+
+    let other: serde_json::Value = client
+        .post(format!("{base}/api/teams"))
+        .header(reqwest::header::COOKIE, &admin)
+        .json(&serde_json::json!({ "name": "Ops", "limits": limits }))
+        .send()
+        .await
+        .expect("create other team")
+        .json()
+        .await
+        .expect("decode other team");
+    let other_id = other["id"].as_str().expect("other team id");
+
+    let no_team: serde_json::Value = client
+        .get(format!("{base}/api/user/team"))
+        .header(reqwest::header::COOKIE, &member_cookie)
+        .send()
+        .await
+        .expect("user team before joining")
+        .json()
+        .await
+        .expect("decode empty user team");
+    assert!(no_team["team"].is_null());
+
+    for (user_id, role, expected) in [
+        (owner_id.as_str(), "owner", StatusCode::NO_CONTENT),
+        (member_id.as_str(), "member", StatusCode::NO_CONTENT),
+        (member_id.as_str(), "captain", StatusCode::BAD_REQUEST),
+        ("missing-user", "member", StatusCode::NOT_FOUND),
+    ] {
+        let resp = client
+            .put(format!("{base}/api/teams/{team_id}/members/{user_id}"))
+            .header(reqwest::header::COOKIE, &admin)
+            .json(&serde_json::json!({ "role": role }))
+            .send()
+            .await
+            .expect("put team member");
+        assert_eq!(resp.status(), expected, "{user_id} as {role}");
+    }
+    let conflict = client
+        .put(format!("{base}/api/teams/{other_id}/members/{member_id}"))
+        .header(reqwest::header::COOKIE, &admin)
+        .json(&serde_json::json!({ "role": "member" }))
+        .send()
+        .await
+        .expect("put member into second team");
+    assert_eq!(conflict.status(), StatusCode::CONFLICT);
+
+    let detail: serde_json::Value = client
+        .get(format!("{base}/api/teams/{team_id}"))
+        .header(reqwest::header::COOKIE, &admin)
+        .send()
+        .await
+        .expect("team detail")
+        .json()
+        .await
+        .expect("decode team detail");
+    assert_eq!(detail["members"].as_array().map(Vec::len), Some(2));
+    assert_eq!(detail["quota"]["dailyLimit"], 500);
+
+    let member_view: serde_json::Value = client
+        .get(format!("{base}/api/user/team"))
+        .header(reqwest::header::COOKIE, &member_cookie)
+        .send()
+        .await
+        .expect("member team view")
+        .json()
+        .await
+        .expect("decode member team view");
+    assert_eq!(member_view["team"]["name"], "Research");
+    assert_eq!(member_view["role"], "member");
+    assert_eq!(member_view["quota"]["monthlyLimit"], 5000);
+
+    let member_tokens = client
+        .post(format!("{base}/api/user/team/tokens"))
+        .header(reqwest::header::COOKIE, &member_cookie)
+        .json(&serde_json::json!({}))
+        .send()
+        .await
+        .expect("member creates team token");
+    assert_eq!(member_tokens.status(), StatusCode::FORBIDDEN);
+
+    let owner_token = client
+        .post(format!("{base}/api/user/team/tokens"))
+        .header(reqwest::header::COOKIE, &owner_cookie)
+        .json(&serde_json::json!({ "note": "ci runner" }))
+        .send()
+        .await
+        .expect("owner creates team token");
+    assert_eq!(owner_token.status(), StatusCode::CREATED);
+    let owner_token: serde_json::Value = owner_token.json().await.expect("decode team token");
+    let token_id = owner_token["tokenId"].as_str().expect("token id").to_string();
+    assert!(owner_token["token"].as_str().is_some_and(|token| token.starts_with("th-")));
+
+    let tokens: serde_json::Value = client
+        .get(format!("{base}/api/user/team/tokens"))
+        .header(reqwest::header::COOKIE, &owner_cookie)
+        .send()
+        .await
+        .expect("owner lists team tokens")
+        .json()
+        .await
+        .expect("decode team tokens");
+    assert_eq!(tokens[0]["note"], "ci runner");
+    assert_eq!(tokens[0]["createdBy"], format!("user:{owner_id}"));
+
+    let series = client
+        .get(format!("{base}/api/teams/{team_id}/usage-series?series=rate5m"))
+        .header(reqwest::header::COOKIE, &admin)
+        .send()
+        .await
+        .expect("invalid team series");
+    assert_eq!(series.status(), StatusCode::BAD_REQUEST);
+    let series: serde_json::Value = client
+        .get(format!(
+            "{base}/api/teams/{team_id}/usage-series?series=monthlyCredits"
+        ))
+        .header(reqwest::header::COOKIE, &admin)
+        .send()
+        .await
+        .expect("team monthly series")
+        .json()
+        .await
+        .expect("decode team monthly series");
+    assert_eq!(series["limit"], 5000);
+    assert_eq!(series["points"].as_array().map(Vec::len), Some(12));
+
+    let removed = client
+        .delete(format!("{base}/api/user/team/tokens/{token_id}"))
+        .header(reqwest::header::COOKIE, &owner_cookie)
+        .send()
+        .await
+        .expect("owner deletes team token");
+    assert_eq!(removed.status(), StatusCode::NO_CONTENT);
+
+    let deleted = client
+        .delete(format!("{base}/api/teams/{team_id}"))
+        .header(reqwest::header::COOKIE, &admin)
+        .send()
+        .await
+        .expect("delete team");
+    assert_eq!(deleted.status(), StatusCode::NO_CONTENT);
+    let gone: serde_json::Value = client
+        .get(format!("{base}/api/user/team"))
+        .header(reqwest::header::COOKIE, &member_cookie)
+        .send()
+        .await
+        .expect("user team after delete")
+        .json()
+        .await
+        .expect("decode user team after delete");
+    assert!(gone["team"].is_null());
+
+    let _ = std::fs::remove_file(db_path);
+}
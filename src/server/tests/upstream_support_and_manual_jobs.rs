@@ -1457,6 +1457,7 @@ pub(super) async fn spawn_proxy_server_with_dev_and_ha(
             admin_passkey: AdminPasskeyOptions::disabled(),
         linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
         linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
         ha,
         dev_open_admin,
         usage_base,
@@ -1465,6 +1466,7 @@ pub(super) async fn spawn_proxy_server_with_dev_and_ha(
     });
     let app = Router::new()
         .route("/health", get(health_check))
+        .route("/metrics", get(get_prometheus_metrics))
         .route("/mcp", any(proxy_handler))
         .route("/mcp/*path", any(mcp_subpath_reject_handler))
         .route("/api/tavily/search", post(tavily_http_search))
@@ -1504,6 +1506,7 @@ pub(super) async fn spawn_keys_admin_server(
             admin_passkey: AdminPasskeyOptions::disabled(),
         linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
         linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
         ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
         dev_open_admin,
         usage_base: "http://127.0.0.1:58088".to_string(),
@@ -1515,7 +1518,13 @@ pub(super) async fn spawn_keys_admin_server(
     let app = Router::new()
         .route("/api/keys/batch", post(create_api_keys_batch))
         .route("/api/keys/bulk-actions", post(post_api_key_bulk_actions))
+        .route(
+            "/api/keys/group-routing",
+            get(get_api_key_group_routing).put(put_api_key_group_routing),
+        )
         .route("/api/keys/:id/sync-usage", post(post_sync_key_usage))
+        .route("/api/keys/:id/rate-budget", put(put_api_key_rate_budget))
+        .route("/api/keys/:id/metrics", get(get_key_metrics))
         .route("/api/jobs", get(list_jobs))
         .route("/api/jobs/trigger", post(post_trigger_job))
         .route(
@@ -1559,6 +1568,7 @@ pub(super) async fn spawn_keys_admin_server_with_usage_base(
             admin_passkey: AdminPasskeyOptions::disabled(),
         linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
         linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
         ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
         dev_open_admin,
         usage_base,
@@ -1602,6 +1612,7 @@ pub(super) async fn spawn_keys_admin_server_with_geo_origin(
             admin_passkey: AdminPasskeyOptions::disabled(),
         linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
         linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
         ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
         dev_open_admin,
         usage_base: "http://127.0.0.1:58088".to_string(),
@@ -1645,6 +1656,7 @@ pub(super) async fn spawn_keys_admin_server_with_usage_and_geo(
             admin_passkey: AdminPasskeyOptions::disabled(),
         linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
         linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
         ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
         dev_open_admin,
         usage_base,
@@ -1687,6 +1699,7 @@ pub(super) async fn spawn_ha_admin_server(
             admin_passkey: AdminPasskeyOptions::disabled(),
         linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
         linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
         ha,
         dev_open_admin,
         usage_base: "http://127.0.0.1:58088".to_string(),
@@ -2352,6 +2365,7 @@ pub(super) async fn spawn_builtin_keys_admin_server_with_state(
             admin_passkey: AdminPasskeyOptions::disabled(),
         linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
         linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
         ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
         dev_open_admin: false,
         usage_base: "http://127.0.0.1:58088".to_string(),
@@ -2450,6 +2464,7 @@ pub(super) async fn spawn_user_oauth_server_with_options(
             admin_passkey: AdminPasskeyOptions::disabled(),
         linuxdo_oauth,
         linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
         ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
         dev_open_admin: false,
         usage_base: "http://127.0.0.1:58088".to_string(),
@@ -2515,6 +2530,7 @@ pub(super) async fn spawn_user_oauth_recharge_server(
             admin_passkey: AdminPasskeyOptions::disabled(),
         linuxdo_oauth: linuxdo_oauth_options_for_test(),
         linuxdo_credit,
+        oidc: OidcOptions::disabled(),
         ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
         dev_open_admin,
         usage_base: "http://127.0.0.1:58088".to_string(),
@@ -2573,6 +2589,7 @@ pub(super) async fn spawn_admin_users_server(proxy: TavilyProxy, dev_open_admin:
             admin_passkey: AdminPasskeyOptions::disabled(),
         linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
         linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
         ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
         dev_open_admin,
         usage_base: "http://127.0.0.1:58088".to_string(),
@@ -2626,6 +2643,7 @@ pub(super) async fn spawn_admin_tokens_server(proxy: TavilyProxy, dev_open_admin
             admin_passkey: AdminPasskeyOptions::disabled(),
         linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
         linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
         ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
         dev_open_admin,
         usage_base: "http://127.0.0.1:58088".to_string(),
@@ -2636,9 +2654,31 @@ pub(super) async fn spawn_admin_tokens_server(proxy: TavilyProxy, dev_open_admin
     let app = Router::new()
         .route("/api/tokens", get(list_tokens))
         .route("/api/tokens/unbound-usage", get(list_unbound_token_usage))
+        .route("/api/tokens/groups", get(list_token_groups))
+        .route(
+            "/api/tokens/groups/:group/scopes",
+            patch(update_token_group_scopes),
+        )
         .route("/api/tokens/batch/status", patch(update_tokens_status_batch))
         .route("/api/tokens/batch", delete(delete_tokens_batch))
         .route("/api/tokens/:id", get(get_token_detail))
+        .route("/api/tokens/:id/secret", get(get_token_secret))
+        .route("/api/tokens/:id/secret/rotate", post(rotate_token_secret))
+        .route("/api/tokens/:id/lifetime", patch(update_token_lifetime))
+        .route("/api/tokens/:id/scopes", patch(update_token_scopes))
+        .route(
+            "/api/tokens/:id/parameter-policy",
+            patch(update_token_parameter_policy),
+        )
+        .route(
+            "/api/tokens/groups/:group/parameter-policy",
+            patch(update_token_group_parameter_policy),
+        )
+        .route(
+            "/api/user-tags/:tag_id/parameter-policy",
+            patch(update_user_tag_parameter_policy),
+        )
+        .route("/api/parameter-policies", get(list_request_parameter_policies))
         .route("/api/tokens/:id/logs", get(get_token_logs))
         .route("/api/tokens/:id/logs/page", get(get_token_logs_page))
         .route(
@@ -2688,6 +2728,7 @@ pub(super) async fn spawn_admin_forward_proxy_server_with_geo_origin(
             admin_passkey: AdminPasskeyOptions::disabled(),
         linuxdo_oauth: LinuxDoOAuthOptions::disabled(),
         linuxdo_credit: LinuxDoCreditOptions::disabled(),
+        oidc: OidcOptions::disabled(),
             ha: tavily_hikari::HaRuntime::new(tavily_hikari::HaConfig::default()),
         dev_open_admin,
         usage_base,
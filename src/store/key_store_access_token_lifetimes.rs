@@ -0,0 +1,77 @@
+impl KeyStore {
+    pub(crate) async fn ensure_access_token_lifetime_schema(&self) -> Result<(), ProxyError> {
+        for column in ["not_before", "expires_at"] {
+            if !self.auth_tokens_column_exists(column).await? {
+                sqlx::query(&format!("ALTER TABLE auth_tokens ADD COLUMN {column} INTEGER"))
+                    .execute(&self.pool)
+                    .await?;
+            }
+        }
+        // One row per (token, expiry) pair that already raised a `token_expiring` alert, so
+        // extending a token's lifetime arms a fresh notice while repeated scans stay quiet.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS auth_token_expiry_notices (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                token_id TEXT NOT NULL,
+                expires_at INTEGER NOT NULL,
+                created_at INTEGER NOT NULL,
+                UNIQUE(token_id, expires_at)
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+        sqlx::query(
+            r#"CREATE INDEX IF NOT EXISTS idx_auth_token_expiry_notices_created
+               ON auth_token_expiry_notices(created_at, id)"#,
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Replace a token's lifetime window. Returns `false` when the token does not exist.
+    pub(crate) async fn set_access_token_lifetime(
+        &self,
+        id: &str,
+        lifetime: AccessTokenLifetime,
+    ) -> Result<bool, ProxyError> {
+        let result = sqlx::query(
+            "UPDATE auth_tokens SET not_before = ?, expires_at = ? WHERE id = ? AND deleted_at IS NULL",
+        )
+        .bind(lifetime.not_before)
+        .bind(lifetime.expires_at)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Record an expiry notice for every enabled token that expires within `lead_secs`.
+    /// Returns how many tokens were newly noticed; each notice surfaces as a
+    /// `token_expiring` alert.
+    pub(crate) async fn record_access_token_expiry_notices(
+        &self,
+        lead_secs: i64,
+    ) -> Result<u64, ProxyError> {
+        let now = self.backend_time.now_ts();
+        let result = sqlx::query(
+            r#"INSERT OR IGNORE INTO auth_token_expiry_notices (token_id, expires_at, created_at)
+               SELECT id, expires_at, ?
+               FROM auth_tokens
+               WHERE deleted_at IS NULL
+                 AND enabled = 1
+                 AND expires_at IS NOT NULL
+                 AND expires_at > ?
+                 AND expires_at <= ?
+               ORDER BY expires_at ASC, id ASC"#,
+        )
+        .bind(now)
+        .bind(now)
+        .bind(now.saturating_add(lead_secs.max(0)))
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
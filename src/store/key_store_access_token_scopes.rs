@@ -0,0 +1,114 @@
+impl KeyStore {
+    pub(crate) async fn ensure_access_token_scopes_schema(&self) -> Result<(), ProxyError> {
+        if !self.auth_tokens_column_exists("scopes").await? {
+            sqlx::query("ALTER TABLE auth_tokens ADD COLUMN scopes TEXT")
+                .execute(&self.pool)
+                .await?;
+        }
+        // Token groups only exist as `auth_tokens.group_name` values, so their default scopes
+        // live beside them keyed by the trimmed group name.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS token_group_scopes (
+                group_name TEXT PRIMARY KEY,
+                scopes TEXT NOT NULL,
+                updated_at INTEGER NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Scopes that apply to a token: its own list when set, otherwise its group default.
+    /// `None` means the token is unrestricted.
+    pub(crate) async fn effective_access_token_scopes(
+        &self,
+        token_id: &str,
+    ) -> Result<Option<AccessTokenScopes>, ProxyError> {
+        let row = sqlx::query_as::<_, (Option<String>, Option<String>)>(
+            r#"SELECT t.scopes, g.scopes
+               FROM auth_tokens t
+               LEFT JOIN token_group_scopes g ON g.group_name = TRIM(t.group_name)
+               WHERE t.id = ? AND t.deleted_at IS NULL
+               LIMIT 1"#,
+        )
+        .bind(token_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row
+            .and_then(|(token_scopes, group_scopes)| token_scopes.or(group_scopes))
+            .map(|raw| AccessTokenScopes::from_stored(&raw)))
+    }
+
+    /// Replace the scopes set on each token; `None` clears them back to the group default.
+    /// Returns how many tokens were updated.
+    pub(crate) async fn set_access_tokens_scopes(
+        &self,
+        ids: &[String],
+        scopes: Option<&AccessTokenScopes>,
+    ) -> Result<u64, ProxyError> {
+        let stored = scopes.map(AccessTokenScopes::to_stored);
+        let mut tx = self.pool.begin().await?;
+        let mut updated = 0;
+        for id in ids {
+            updated += sqlx::query(
+                "UPDATE auth_tokens SET scopes = ? WHERE id = ? AND deleted_at IS NULL",
+            )
+            .bind(stored.as_deref())
+            .bind(id)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+        }
+        tx.commit().await?;
+        Ok(updated)
+    }
+
+    /// Set or clear (`None`) the default scopes for tokens in `group_name`.
+    pub(crate) async fn set_token_group_default_scopes(
+        &self,
+        group_name: &str,
+        scopes: Option<&AccessTokenScopes>,
+    ) -> Result<(), ProxyError> {
+        let group_name = group_name.trim();
+        match scopes {
+            Some(scopes) => {
+                sqlx::query(
+                    r#"INSERT INTO token_group_scopes (group_name, scopes, updated_at)
+                       VALUES (?, ?, ?)
+                       ON CONFLICT(group_name) DO UPDATE SET
+                           scopes = excluded.scopes,
+                           updated_at = excluded.updated_at"#,
+                )
+                .bind(group_name)
+                .bind(scopes.to_stored())
+                .bind(self.backend_time.now_ts())
+                .execute(&self.pool)
+                .await?;
+            }
+            None => {
+                sqlx::query("DELETE FROM token_group_scopes WHERE group_name = ?")
+                    .bind(group_name)
+                    .execute(&self.pool)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn list_token_group_default_scopes(
+        &self,
+    ) -> Result<HashMap<String, AccessTokenScopes>, ProxyError> {
+        let rows = sqlx::query_as::<_, (String, String)>(
+            "SELECT group_name, scopes FROM token_group_scopes",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|(group_name, raw)| (group_name, AccessTokenScopes::from_stored(&raw)))
+            .collect())
+    }
+}
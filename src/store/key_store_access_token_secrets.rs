@@ -0,0 +1,180 @@
+/// Value left in `auth_tokens.secret` once only the salted hash is persisted. Issued secrets are
+/// never empty, so this can not collide with a real secret.
+const HASHED_ACCESS_TOKEN_SECRET_PLACEHOLDER: &str = "";
+const ACCESS_TOKEN_SECRET_HASH_PREFIX: &str = "sha256:";
+const ACCESS_TOKEN_SECRET_SALT_LEN: usize = 16;
+
+fn salted_access_token_secret_digest(salt: &str, secret: &str) -> String {
+    sha256_hex_bytes(format!("{salt}:{secret}").as_bytes())
+}
+
+/// Salted hash persisted in `auth_tokens.secret_hash`, formatted as `sha256:<salt>:<digest>`.
+fn hash_access_token_secret(secret: &str) -> String {
+    const ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+    let salt = random_string(ALPHABET, ACCESS_TOKEN_SECRET_SALT_LEN);
+    let digest = salted_access_token_secret_digest(&salt, secret);
+    format!("{ACCESS_TOKEN_SECRET_HASH_PREFIX}{salt}:{digest}")
+}
+
+fn verify_access_token_secret_hash(stored_hash: &str, secret: &str) -> bool {
+    let Some((salt, digest)) = stored_hash
+        .strip_prefix(ACCESS_TOKEN_SECRET_HASH_PREFIX)
+        .and_then(|rest| rest.split_once(':'))
+    else {
+        return false;
+    };
+    salted_access_token_secret_digest(salt, secret) == digest
+}
+
+/// Secret material of one `auth_tokens` row as persisted: the plaintext secret, or only its
+/// salted hash once the row has been hashed.
+#[derive(Debug, Clone)]
+pub(crate) struct StoredAccessTokenSecret {
+    pub(crate) id: String,
+    pub(crate) secret: String,
+    pub(crate) secret_hash: Option<String>,
+}
+
+impl StoredAccessTokenSecret {
+    pub(crate) fn new(id: String, secret: String, secret_hash: Option<String>) -> Self {
+        Self {
+            id,
+            secret,
+            secret_hash,
+        }
+    }
+
+    pub(crate) fn matches(&self, candidate: &str) -> bool {
+        match self.secret_hash.as_deref() {
+            Some(stored_hash) => verify_access_token_secret_hash(stored_hash, candidate),
+            None => self.secret == candidate,
+        }
+    }
+
+    /// Full `th-<id>-<secret>` token, or `TokenSecretNotRecoverable` for hashed rows.
+    pub(crate) fn reveal(self) -> Result<AuthTokenSecret, ProxyError> {
+        if self.secret_hash.is_some() {
+            return Err(ProxyError::TokenSecretNotRecoverable { token_id: self.id });
+        }
+        Ok(AuthTokenSecret {
+            token: KeyStore::compose_full_token(&self.id, &self.secret),
+            id: self.id,
+        })
+    }
+}
+
+impl KeyStore {
+    pub(crate) async fn ensure_access_token_secret_hash_column(&self) -> Result<(), ProxyError> {
+        if !self.auth_tokens_column_exists("secret_hash").await? {
+            sqlx::query("ALTER TABLE auth_tokens ADD COLUMN secret_hash TEXT")
+                .execute(&self.pool)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Switch newly issued access-token secrets to hash-only storage and hash every token that
+    /// still keeps its plaintext secret. Hashed rows stay hashed when the mode is turned off
+    /// again; they keep validating but can no longer be revealed.
+    pub(crate) async fn configure_access_token_secret_hashing(
+        &self,
+        enabled: bool,
+    ) -> Result<(), ProxyError> {
+        self.access_token_secret_hashing
+            .store(enabled, std::sync::atomic::Ordering::Relaxed);
+        if !enabled {
+            return Ok(());
+        }
+        self.hash_plaintext_access_token_secrets().await
+    }
+
+    pub(crate) fn access_token_secret_hashing_enabled(&self) -> bool {
+        self.access_token_secret_hashing
+            .load(std::sync::atomic::Ordering::Relaxed)
+    }
+
+    async fn hash_plaintext_access_token_secrets(&self) -> Result<(), ProxyError> {
+        let mut tx = self.pool.begin().await?;
+        let rows = sqlx::query_as::<_, (String, String)>(
+            "SELECT id, secret FROM auth_tokens WHERE secret_hash IS NULL",
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+        if rows.is_empty() {
+            return Ok(());
+        }
+        let mut hashes_by_id = HashMap::with_capacity(rows.len());
+        for (id, secret) in rows {
+            let secret_hash = hash_access_token_secret(&secret);
+            sqlx::query("UPDATE auth_tokens SET secret = ?, secret_hash = ? WHERE id = ?")
+                .bind(HASHED_ACCESS_TOKEN_SECRET_PLACEHOLDER)
+                .bind(&secret_hash)
+                .bind(&id)
+                .execute(&mut *tx)
+                .await?;
+            hashes_by_id.insert(id, secret_hash);
+        }
+
+        // Control events recorded before hashing still carry plaintext secrets; rewrite them so
+        // peers and baseline consumers only ever see the hash.
+        let events = sqlx::query_as::<_, (i64, String, Option<String>)>(
+            "SELECT seq, payload_json, checksum FROM ha_outbox WHERE resource = 'auth_tokens'",
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+        for (seq, payload_json, checksum) in events {
+            let Ok(mut payload) = serde_json::from_str::<Value>(&payload_json) else {
+                continue;
+            };
+            let Some(object) = payload.as_object_mut() else {
+                continue;
+            };
+            if object.get("secret_hash").is_some_and(|value| !value.is_null()) {
+                continue;
+            }
+            let Some(secret_hash) = object
+                .get("id")
+                .and_then(Value::as_str)
+                .and_then(|id| hashes_by_id.get(id))
+            else {
+                continue;
+            };
+            if !object.contains_key("secret") {
+                continue;
+            }
+            object.insert(
+                "secret".to_string(),
+                Value::String(HASHED_ACCESS_TOKEN_SECRET_PLACEHOLDER.to_string()),
+            );
+            object.insert("secret_hash".to_string(), Value::String(secret_hash.clone()));
+            let payload_json = payload.to_string();
+            let checksum = checksum.map(|_| sha256_hex_bytes(payload_json.as_bytes()));
+            sqlx::query("UPDATE ha_outbox SET payload_json = ?, checksum = ? WHERE seq = ?")
+                .bind(&payload_json)
+                .bind(checksum)
+                .bind(seq)
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await?;
+        tracing::info!(
+            component = "access_token_secrets",
+            event = "plaintext_secrets_hashed",
+            token_count = hashes_by_id.len() as u64,
+            "replaced plaintext access token secrets with salted hashes"
+        );
+        Ok(())
+    }
+
+    /// `(secret, secret_hash)` column values for a newly issued secret.
+    pub(crate) fn access_token_secret_columns(&self, secret: &str) -> (String, Option<String>) {
+        if self.access_token_secret_hashing_enabled() {
+            (
+                HASHED_ACCESS_TOKEN_SECRET_PLACEHOLDER.to_string(),
+                Some(hash_access_token_secret(secret)),
+            )
+        } else {
+            (secret.to_string(), None)
+        }
+    }
+}
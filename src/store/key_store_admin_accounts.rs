@@ -0,0 +1,380 @@
+const ADMIN_ACCOUNT_COLUMNS: &str = "a.id, a.username, a.display_name, a.role, a.created_at, \
+     a.updated_at, a.last_login_at, a.disabled_at, \
+     (SELECT COUNT(*) FROM admin_account_sessions s \
+      WHERE s.account_id = a.id AND s.revoked_at IS NULL AND s.expires_at >= ?) AS active_sessions";
+
+fn admin_account_from_row(row: &sqlx::sqlite::SqliteRow) -> Result<AdminAccount, sqlx::Error> {
+    let role: String = row.try_get("role")?;
+    Ok(AdminAccount {
+        id: row.try_get("id")?,
+        username: row.try_get("username")?,
+        display_name: row.try_get("display_name")?,
+        // Unknown roles written by a newer build degrade to the least privileged one.
+        role: AdminRole::parse(&role).unwrap_or(AdminRole::Viewer),
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+        last_login_at: row.try_get("last_login_at")?,
+        disabled_at: row.try_get("disabled_at")?,
+        active_sessions: row.try_get("active_sessions")?,
+    })
+}
+
+impl KeyStore {
+    pub(crate) async fn ensure_admin_accounts_schema(&self) -> Result<(), ProxyError> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS admin_accounts (
+                id TEXT PRIMARY KEY,
+                username TEXT NOT NULL UNIQUE,
+                display_name TEXT,
+                role TEXT NOT NULL,
+                password_hash TEXT NOT NULL,
+                created_at INTEGER NOT NULL,
+                updated_at INTEGER NOT NULL,
+                last_login_at INTEGER,
+                disabled_at INTEGER
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS admin_account_sessions (
+                token TEXT PRIMARY KEY,
+                account_id TEXT NOT NULL,
+                created_at INTEGER NOT NULL,
+                expires_at INTEGER NOT NULL,
+                revoked_at INTEGER
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+        sqlx::query(
+            r#"CREATE INDEX IF NOT EXISTS idx_admin_account_sessions_account_active
+               ON admin_account_sessions(account_id, revoked_at, expires_at)"#,
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub(crate) async fn list_admin_accounts(&self) -> Result<Vec<AdminAccount>, ProxyError> {
+        let now = self.backend_time.now_ts();
+        let rows = sqlx::query(&format!(
+            "SELECT {ADMIN_ACCOUNT_COLUMNS} FROM admin_accounts a ORDER BY a.username"
+        ))
+        .bind(now)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .iter()
+            .map(admin_account_from_row)
+            .collect::<Result<_, _>>()?)
+    }
+
+    pub(crate) async fn fetch_admin_account(
+        &self,
+        account_id: &str,
+    ) -> Result<Option<AdminAccount>, ProxyError> {
+        let now = self.backend_time.now_ts();
+        let row = sqlx::query(&format!(
+            "SELECT {ADMIN_ACCOUNT_COLUMNS} FROM admin_accounts a WHERE a.id = ?"
+        ))
+        .bind(now)
+        .bind(account_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row.as_ref().map(admin_account_from_row).transpose()?)
+    }
+
+    pub(crate) async fn has_active_admin_accounts(&self) -> Result<bool, ProxyError> {
+        let count: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM admin_accounts WHERE disabled_at IS NULL")
+                .fetch_one(&self.pool)
+                .await?;
+        Ok(count > 0)
+    }
+
+    /// Returns `None` when the username is already taken.
+    pub(crate) async fn create_admin_account(
+        &self,
+        username: &str,
+        display_name: Option<&str>,
+        role: AdminRole,
+        password_hash: &str,
+    ) -> Result<Option<AdminAccount>, ProxyError> {
+        const ID_ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+        let username = normalize_admin_account_username(username).map_err(ProxyError::Other)?;
+        let now = self.backend_time.now_ts();
+        let id = random_string(ID_ALPHABET, 16);
+        let inserted = sqlx::query(
+            r#"INSERT INTO admin_accounts
+               (id, username, display_name, role, password_hash, created_at, updated_at,
+                last_login_at, disabled_at)
+               VALUES (?, ?, ?, ?, ?, ?, ?, NULL, NULL)"#,
+        )
+        .bind(&id)
+        .bind(&username)
+        .bind(display_name.map(str::trim).filter(|name| !name.is_empty()))
+        .bind(role.as_str())
+        .bind(password_hash)
+        .bind(now)
+        .bind(now)
+        .execute(&self.pool)
+        .await;
+        match inserted {
+            Ok(_) => self.fetch_admin_account(&id).await,
+            Err(sqlx::Error::Database(db_err)) if db_err.is_unique_violation() => Ok(None),
+            Err(err) => Err(ProxyError::Database(err)),
+        }
+    }
+
+    /// Applies the given changes; disabling an account also ends all of its sessions.
+    pub(crate) async fn update_admin_account(
+        &self,
+        account_id: &str,
+        display_name: Option<Option<&str>>,
+        role: Option<AdminRole>,
+        disabled: Option<bool>,
+    ) -> Result<Option<AdminAccount>, ProxyError> {
+        let now = self.backend_time.now_ts();
+        let mut tx = self.pool.begin().await?;
+        let exists: Option<i64> = sqlx::query_scalar("SELECT 1 FROM admin_accounts WHERE id = ?")
+            .bind(account_id)
+            .fetch_optional(&mut *tx)
+            .await?;
+        if exists.is_none() {
+            return Ok(None);
+        }
+        if let Some(display_name) = display_name {
+            sqlx::query("UPDATE admin_accounts SET display_name = ?, updated_at = ? WHERE id = ?")
+                .bind(display_name.map(str::trim).filter(|name| !name.is_empty()))
+                .bind(now)
+                .bind(account_id)
+                .execute(&mut *tx)
+                .await?;
+        }
+        if let Some(role) = role {
+            sqlx::query("UPDATE admin_accounts SET role = ?, updated_at = ? WHERE id = ?")
+                .bind(role.as_str())
+                .bind(now)
+                .bind(account_id)
+                .execute(&mut *tx)
+                .await?;
+        }
+        match disabled {
+            Some(true) => {
+                sqlx::query(
+                    r#"UPDATE admin_accounts
+                       SET disabled_at = COALESCE(disabled_at, ?), updated_at = ?
+                       WHERE id = ?"#,
+                )
+                .bind(now)
+                .bind(now)
+                .bind(account_id)
+                .execute(&mut *tx)
+                .await?;
+                sqlx::query(
+                    "UPDATE admin_account_sessions SET revoked_at = ? WHERE account_id = ? AND revoked_at IS NULL",
+                )
+                .bind(now)
+                .bind(account_id)
+                .execute(&mut *tx)
+                .await?;
+            }
+            Some(false) => {
+                sqlx::query(
+                    "UPDATE admin_accounts SET disabled_at = NULL, updated_at = ? WHERE id = ?",
+                )
+                .bind(now)
+                .bind(account_id)
+                .execute(&mut *tx)
+                .await?;
+            }
+            None => {}
+        }
+        tx.commit().await?;
+        self.fetch_admin_account(account_id).await
+    }
+
+    /// Replaces the password and ends every session opened with the old one.
+    pub(crate) async fn set_admin_account_password_hash(
+        &self,
+        account_id: &str,
+        password_hash: &str,
+    ) -> Result<bool, ProxyError> {
+        let now = self.backend_time.now_ts();
+        let mut tx = self.pool.begin().await?;
+        let updated = sqlx::query(
+            "UPDATE admin_accounts SET password_hash = ?, updated_at = ? WHERE id = ?",
+        )
+        .bind(password_hash)
+        .bind(now)
+        .bind(account_id)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+        sqlx::query(
+            "UPDATE admin_account_sessions SET revoked_at = ? WHERE account_id = ? AND revoked_at IS NULL",
+        )
+        .bind(now)
+        .bind(account_id)
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(updated > 0)
+    }
+
+    pub(crate) async fn delete_admin_account(&self, account_id: &str) -> Result<bool, ProxyError> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("DELETE FROM admin_account_sessions WHERE account_id = ?")
+            .bind(account_id)
+            .execute(&mut *tx)
+            .await?;
+        let deleted = sqlx::query("DELETE FROM admin_accounts WHERE id = ?")
+            .bind(account_id)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+        tx.commit().await?;
+        Ok(deleted > 0)
+    }
+
+    /// Loads the password hash of an enabled account for a login attempt.
+    pub(crate) async fn admin_account_credential(
+        &self,
+        username: &str,
+    ) -> Result<Option<AdminAccountCredential>, ProxyError> {
+        let Ok(username) = normalize_admin_account_username(username) else {
+            return Ok(None);
+        };
+        let now = self.backend_time.now_ts();
+        let row = sqlx::query(&format!(
+            r#"SELECT {ADMIN_ACCOUNT_COLUMNS}, a.password_hash
+               FROM admin_accounts a
+               WHERE a.username = ? AND a.disabled_at IS NULL"#
+        ))
+        .bind(now)
+        .bind(&username)
+        .fetch_optional(&self.pool)
+        .await?;
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        Ok(Some(AdminAccountCredential {
+            account: admin_account_from_row(&row)?,
+            password_hash: row.try_get("password_hash")?,
+        }))
+    }
+
+    pub(crate) async fn create_admin_account_session(
+        &self,
+        account_id: &str,
+        ttl_secs: i64,
+    ) -> Result<String, ProxyError> {
+        const ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+        let now = self.backend_time.now_ts();
+        let expires_at = now + ttl_secs.max(60);
+
+        sqlx::query(
+            "DELETE FROM admin_account_sessions WHERE expires_at < ? OR revoked_at IS NOT NULL",
+        )
+        .bind(now)
+        .execute(&self.pool)
+        .await?;
+        sqlx::query("UPDATE admin_accounts SET last_login_at = ? WHERE id = ?")
+            .bind(now)
+            .bind(account_id)
+            .execute(&self.pool)
+            .await?;
+
+        loop {
+            let token = random_string(ALPHABET, 48);
+            let res = sqlx::query(
+                r#"INSERT INTO admin_account_sessions
+                   (token, account_id, created_at, expires_at, revoked_at)
+                   VALUES (?, ?, ?, ?, NULL)"#,
+            )
+            .bind(&token)
+            .bind(account_id)
+            .bind(now)
+            .bind(expires_at)
+            .execute(&self.pool)
+            .await;
+            match res {
+                Ok(_) => return Ok(token),
+                Err(sqlx::Error::Database(db_err)) if db_err.is_unique_violation() => continue,
+                Err(err) => return Err(ProxyError::Database(err)),
+            }
+        }
+    }
+
+    /// Resolves a session cookie; sessions of disabled or deleted accounts never match.
+    pub(crate) async fn get_active_admin_account_session(
+        &self,
+        token: &str,
+    ) -> Result<Option<AdminAccountSession>, ProxyError> {
+        let now = self.backend_time.now_ts();
+        let row = sqlx::query_as::<_, (String, String, String, Option<String>, String, i64, i64)>(
+            r#"SELECT s.token, a.id, a.username, a.display_name, a.role, s.created_at, s.expires_at
+               FROM admin_account_sessions s
+               JOIN admin_accounts a ON a.id = s.account_id
+               WHERE s.token = ?
+                 AND s.revoked_at IS NULL
+                 AND s.expires_at >= ?
+                 AND a.disabled_at IS NULL
+               LIMIT 1"#,
+        )
+        .bind(token)
+        .bind(now)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row.map(
+            |(token, account_id, username, display_name, role, created_at, expires_at)| {
+                AdminAccountSession {
+                    token,
+                    account_id,
+                    username,
+                    display_name,
+                    role: AdminRole::parse(&role).unwrap_or(AdminRole::Viewer),
+                    created_at,
+                    expires_at,
+                }
+            },
+        ))
+    }
+
+    pub(crate) async fn revoke_admin_account_session(&self, token: &str) -> Result<(), ProxyError> {
+        let now = self.backend_time.now_ts();
+        sqlx::query(
+            "UPDATE admin_account_sessions SET revoked_at = ? WHERE token = ? AND revoked_at IS NULL",
+        )
+        .bind(now)
+        .bind(token)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Ends every session of one account and returns how many were still active.
+    pub(crate) async fn revoke_admin_account_sessions(
+        &self,
+        account_id: &str,
+    ) -> Result<u64, ProxyError> {
+        let now = self.backend_time.now_ts();
+        let revoked = sqlx::query(
+            r#"UPDATE admin_account_sessions
+               SET revoked_at = ?
+               WHERE account_id = ? AND revoked_at IS NULL AND expires_at >= ?"#,
+        )
+        .bind(now)
+        .bind(account_id)
+        .bind(now)
+        .execute(&self.pool)
+        .await?
+        .rows_affected();
+        Ok(revoked)
+    }
+}
@@ -0,0 +1,188 @@
+const ADMIN_API_TOKEN_COLUMNS: &str = "id, name, scopes, ip_allowlist, created_by, created_at, \
+     expires_at, last_used_at, last_used_ip, revoked_at";
+const ADMIN_API_TOKEN_ID_LEN: usize = 8;
+const ADMIN_API_TOKEN_SECRET_LEN: usize = 32;
+
+fn admin_api_token_from_row(row: &sqlx::sqlite::SqliteRow) -> Result<AdminApiToken, sqlx::Error> {
+    let scopes: String = row.try_get("scopes")?;
+    let ip_allowlist: String = row.try_get("ip_allowlist")?;
+    Ok(AdminApiToken {
+        id: row.try_get("id")?,
+        name: row.try_get("name")?,
+        scopes: AdminApiTokenScopes::from_stored(&scopes),
+        ip_allowlist: serde_json::from_str(&ip_allowlist).unwrap_or_default(),
+        created_by: row.try_get("created_by")?,
+        created_at: row.try_get("created_at")?,
+        expires_at: row.try_get("expires_at")?,
+        last_used_at: row.try_get("last_used_at")?,
+        last_used_ip: row.try_get("last_used_ip")?,
+        revoked_at: row.try_get("revoked_at")?,
+    })
+}
+
+impl KeyStore {
+    pub(crate) async fn ensure_admin_api_tokens_schema(&self) -> Result<(), ProxyError> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS admin_api_tokens (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL,
+                secret_hash TEXT NOT NULL,
+                scopes TEXT NOT NULL,
+                ip_allowlist TEXT NOT NULL DEFAULT '[]',
+                created_by TEXT,
+                created_at INTEGER NOT NULL,
+                expires_at INTEGER,
+                last_used_at INTEGER,
+                last_used_ip TEXT,
+                revoked_at INTEGER
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub(crate) async fn list_admin_api_tokens(&self) -> Result<Vec<AdminApiToken>, ProxyError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {ADMIN_API_TOKEN_COLUMNS} FROM admin_api_tokens ORDER BY created_at DESC, id"
+        ))
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .iter()
+            .map(admin_api_token_from_row)
+            .collect::<Result<_, _>>()?)
+    }
+
+    pub(crate) async fn fetch_admin_api_token(
+        &self,
+        token_id: &str,
+    ) -> Result<Option<AdminApiToken>, ProxyError> {
+        let row = sqlx::query(&format!(
+            "SELECT {ADMIN_API_TOKEN_COLUMNS} FROM admin_api_tokens WHERE id = ?"
+        ))
+        .bind(token_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row.as_ref().map(admin_api_token_from_row).transpose()?)
+    }
+
+    /// Issues a token and returns it with its full `tha-<id>-<secret>` value. Only a salted hash
+    /// of the secret is stored, so the value can not be shown again.
+    pub(crate) async fn create_admin_api_token(
+        &self,
+        name: &str,
+        scopes: &AdminApiTokenScopes,
+        ip_allowlist: &[String],
+        expires_at: Option<i64>,
+        created_by: Option<&str>,
+    ) -> Result<(AdminApiToken, String), ProxyError> {
+        const ID_ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+        const SECRET_ALPHABET: &[u8] =
+            b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+        let now = self.backend_time.now_ts();
+        let ip_allowlist =
+            serde_json::to_string(ip_allowlist).unwrap_or_else(|_| "[]".to_string());
+        loop {
+            let id = random_string(ID_ALPHABET, ADMIN_API_TOKEN_ID_LEN);
+            let secret = random_string(SECRET_ALPHABET, ADMIN_API_TOKEN_SECRET_LEN);
+            let res = sqlx::query(
+                r#"INSERT INTO admin_api_tokens
+                   (id, name, secret_hash, scopes, ip_allowlist, created_by, created_at,
+                    expires_at, last_used_at, last_used_ip, revoked_at)
+                   VALUES (?, ?, ?, ?, ?, ?, ?, ?, NULL, NULL, NULL)"#,
+            )
+            .bind(&id)
+            .bind(name.trim())
+            .bind(hash_access_token_secret(&secret))
+            .bind(scopes.to_stored())
+            .bind(&ip_allowlist)
+            .bind(created_by)
+            .bind(now)
+            .bind(expires_at)
+            .execute(&self.pool)
+            .await;
+            match res {
+                Ok(_) => {
+                    let token = self.fetch_admin_api_token(&id).await?.ok_or_else(|| {
+                        ProxyError::Other("admin API token vanished after insert".to_string())
+                    })?;
+                    return Ok((token, format!("{ADMIN_API_TOKEN_PREFIX}{id}-{secret}")));
+                }
+                Err(sqlx::Error::Database(db_err)) if db_err.is_unique_violation() => continue,
+                Err(err) => return Err(ProxyError::Database(err)),
+            }
+        }
+    }
+
+    /// Revokes a token; revoking it again keeps the original revocation time.
+    pub(crate) async fn revoke_admin_api_token(
+        &self,
+        token_id: &str,
+    ) -> Result<Option<AdminApiToken>, ProxyError> {
+        let now = self.backend_time.now_ts();
+        sqlx::query(
+            "UPDATE admin_api_tokens SET revoked_at = COALESCE(revoked_at, ?) WHERE id = ?",
+        )
+        .bind(now)
+        .bind(token_id)
+        .execute(&self.pool)
+        .await?;
+        self.fetch_admin_api_token(token_id).await
+    }
+
+    /// Resolves a presented `tha-<id>-<secret>` value. Revoked, expired, unknown or malformed
+    /// tokens never match.
+    pub(crate) async fn authenticate_admin_api_token(
+        &self,
+        token: &str,
+    ) -> Result<Option<AdminApiToken>, ProxyError> {
+        let Some((id, secret)) = token
+            .trim()
+            .strip_prefix(ADMIN_API_TOKEN_PREFIX)
+            .and_then(|rest| rest.split_once('-'))
+        else {
+            return Ok(None);
+        };
+        if id.len() != ADMIN_API_TOKEN_ID_LEN || secret.len() != ADMIN_API_TOKEN_SECRET_LEN {
+            return Ok(None);
+        }
+        let now = self.backend_time.now_ts();
+        let row = sqlx::query(&format!(
+            r#"SELECT {ADMIN_API_TOKEN_COLUMNS}, secret_hash
+               FROM admin_api_tokens
+               WHERE id = ?
+                 AND revoked_at IS NULL
+                 AND (expires_at IS NULL OR expires_at > ?)"#
+        ))
+        .bind(id)
+        .bind(now)
+        .fetch_optional(&self.pool)
+        .await?;
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let secret_hash: String = row.try_get("secret_hash")?;
+        if !verify_access_token_secret_hash(&secret_hash, secret) {
+            return Ok(None);
+        }
+        Ok(Some(admin_api_token_from_row(&row)?))
+    }
+
+    pub(crate) async fn touch_admin_api_token(
+        &self,
+        token_id: &str,
+        client_ip: Option<&str>,
+    ) -> Result<(), ProxyError> {
+        let now = self.backend_time.now_ts();
+        sqlx::query("UPDATE admin_api_tokens SET last_used_at = ?, last_used_ip = ? WHERE id = ?")
+            .bind(now)
+            .bind(client_ip)
+            .bind(token_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,278 @@
+const ADMIN_AUDIT_COLUMNS: &str = "seq, occurred_at, kind, actor_kind, actor_id, actor_name, \
+     client_ip, method, route, path, status, before_json, after_json, prev_hash, hash";
+const ADMIN_AUDIT_EXPORT_BATCH: i64 = 500;
+
+fn admin_audit_entry_from_row(row: sqlx::sqlite::SqliteRow) -> Result<AdminAuditEntry, sqlx::Error> {
+    Ok(AdminAuditEntry {
+        seq: row.try_get("seq")?,
+        occurred_at: row.try_get("occurred_at")?,
+        kind: row.try_get("kind")?,
+        actor_kind: row.try_get("actor_kind")?,
+        actor_id: row.try_get("actor_id")?,
+        actor_name: row.try_get("actor_name")?,
+        client_ip: row.try_get("client_ip")?,
+        method: row.try_get("method")?,
+        route: row.try_get("route")?,
+        path: row.try_get("path")?,
+        status: row.try_get("status")?,
+        before_json: row.try_get("before_json")?,
+        after_json: row.try_get("after_json")?,
+        prev_hash: row.try_get("prev_hash")?,
+        hash: row.try_get("hash")?,
+    })
+}
+
+fn push_admin_audit_filters(
+    builder: &mut QueryBuilder<'_, Sqlite>,
+    filters: &AdminAuditFilters,
+) {
+    builder.push(" WHERE 1 = 1");
+    if let Some(actor) = filters.actor.as_deref().map(str::trim).filter(|v| !v.is_empty()) {
+        let pattern = format!("%{actor}%");
+        builder
+            .push(" AND (actor_id LIKE ")
+            .push_bind(pattern.clone())
+            .push(" OR actor_name LIKE ")
+            .push_bind(pattern)
+            .push(")");
+    }
+    if let Some(route) = filters.route.as_deref().map(str::trim).filter(|v| !v.is_empty()) {
+        builder.push(" AND route LIKE ").push_bind(format!("%{route}%"));
+    }
+    if let Some(kind) = filters.kind.as_deref().map(str::trim).filter(|v| !v.is_empty()) {
+        builder.push(" AND kind = ").push_bind(kind.to_string());
+    }
+    if let Some(since) = filters.since {
+        builder.push(" AND occurred_at >= ").push_bind(since);
+    }
+    if let Some(until) = filters.until {
+        builder.push(" AND occurred_at < ").push_bind(until);
+    }
+}
+
+impl KeyStore {
+    pub(crate) async fn ensure_admin_audit_schema(&self) -> Result<(), ProxyError> {
+        // The audit log is node-local and append-only: triggers reject every UPDATE and
+        // DELETE, and each row chains the hash of its predecessor.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS admin_audit_log (
+                seq INTEGER PRIMARY KEY,
+                occurred_at INTEGER NOT NULL,
+                kind TEXT NOT NULL,
+                actor_kind TEXT NOT NULL,
+                actor_id TEXT,
+                actor_name TEXT,
+                client_ip TEXT,
+                method TEXT NOT NULL,
+                route TEXT NOT NULL,
+                path TEXT NOT NULL,
+                status INTEGER NOT NULL,
+                before_json TEXT,
+                after_json TEXT,
+                prev_hash TEXT NOT NULL,
+                hash TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+        sqlx::query(
+            r#"CREATE INDEX IF NOT EXISTS idx_admin_audit_log_occurred
+               ON admin_audit_log(occurred_at DESC)"#,
+        )
+        .execute(&self.pool)
+        .await?;
+        sqlx::query(
+            r#"CREATE TRIGGER IF NOT EXISTS trg_admin_audit_log_no_update
+               BEFORE UPDATE ON admin_audit_log
+               BEGIN
+                   SELECT RAISE(ABORT, 'admin_audit_log is append-only');
+               END"#,
+        )
+        .execute(&self.pool)
+        .await?;
+        sqlx::query(
+            r#"CREATE TRIGGER IF NOT EXISTS trg_admin_audit_log_no_delete
+               BEFORE DELETE ON admin_audit_log
+               BEGIN
+                   SELECT RAISE(ABORT, 'admin_audit_log is append-only');
+               END"#,
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub(crate) async fn append_admin_audit_entry(
+        &self,
+        input: AdminAuditEntryInput,
+    ) -> Result<AdminAuditEntry, ProxyError> {
+        let encode = |value: Option<serde_json::Value>| {
+            value.map(|mut value| {
+                redact_admin_audit_value(&mut value);
+                value.to_string()
+            })
+        };
+        let before_json = encode(input.before);
+        let after_json = encode(input.after);
+        let mut conn = ImmediateSqliteTransaction::begin(self.pool.acquire().await?).await?;
+        let result = async {
+            let head = sqlx::query_as::<_, (i64, String)>(
+                "SELECT seq, hash FROM admin_audit_log ORDER BY seq DESC LIMIT 1",
+            )
+            .fetch_optional(&mut *conn)
+            .await?;
+            let (seq, prev_hash) = match head {
+                Some((seq, hash)) => (seq + 1, hash),
+                None => (1, ADMIN_AUDIT_GENESIS_HASH.to_string()),
+            };
+            let mut entry = AdminAuditEntry {
+                seq,
+                occurred_at: input.occurred_at,
+                kind: input.kind,
+                actor_kind: input.actor_kind,
+                actor_id: input.actor_id,
+                actor_name: input.actor_name,
+                client_ip: input.client_ip,
+                method: input.method,
+                route: input.route,
+                path: input.path,
+                status: input.status,
+                before_json,
+                after_json,
+                prev_hash,
+                hash: String::new(),
+            };
+            entry.hash = entry.compute_hash();
+            sqlx::query(&format!(
+                "INSERT INTO admin_audit_log ({ADMIN_AUDIT_COLUMNS}) \
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+            ))
+            .bind(entry.seq)
+            .bind(entry.occurred_at)
+            .bind(&entry.kind)
+            .bind(&entry.actor_kind)
+            .bind(&entry.actor_id)
+            .bind(&entry.actor_name)
+            .bind(&entry.client_ip)
+            .bind(&entry.method)
+            .bind(&entry.route)
+            .bind(&entry.path)
+            .bind(entry.status)
+            .bind(&entry.before_json)
+            .bind(&entry.after_json)
+            .bind(&entry.prev_hash)
+            .bind(&entry.hash)
+            .execute(&mut *conn)
+            .await?;
+            Ok::<_, ProxyError>(entry)
+        }
+        .await;
+        match result {
+            Ok(entry) => {
+                conn.commit().await?;
+                Ok(entry)
+            }
+            Err(err) => {
+                let _ = conn.rollback().await;
+                Err(err)
+            }
+        }
+    }
+
+    pub(crate) async fn admin_audit_entries_page(
+        &self,
+        filters: &AdminAuditFilters,
+        page: i64,
+        per_page: i64,
+    ) -> Result<PaginatedAdminAuditEntries, ProxyError> {
+        let page = page.max(1);
+        let per_page = per_page.clamp(1, 200);
+        let mut count = QueryBuilder::<Sqlite>::new("SELECT COUNT(*) FROM admin_audit_log");
+        push_admin_audit_filters(&mut count, filters);
+        let total: i64 = count.build_query_scalar().fetch_one(&self.pool).await?;
+
+        let mut select = QueryBuilder::<Sqlite>::new(format!(
+            "SELECT {ADMIN_AUDIT_COLUMNS} FROM admin_audit_log"
+        ));
+        push_admin_audit_filters(&mut select, filters);
+        select
+            .push(" ORDER BY seq DESC LIMIT ")
+            .push_bind(per_page)
+            .push(" OFFSET ")
+            .push_bind((page - 1) * per_page);
+        let items = select
+            .build()
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .map(admin_audit_entry_from_row)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(PaginatedAdminAuditEntries {
+            items,
+            total,
+            page,
+            per_page,
+        })
+    }
+
+    pub(crate) async fn verify_admin_audit_chain(
+        &self,
+    ) -> Result<AdminAuditVerification, ProxyError> {
+        scan_admin_audit_chain_on(&self.pool, |_| Ok(())).await
+    }
+}
+
+/// Streams the whole chain in `seq` order, handing each entry to `sink` and verifying the links
+/// as it goes. The sink sees every entry, including those after a broken link.
+async fn scan_admin_audit_chain_on<F>(
+    pool: &SqlitePool,
+    mut sink: F,
+) -> Result<AdminAuditVerification, ProxyError>
+where
+    F: FnMut(&AdminAuditEntry) -> Result<(), ProxyError>,
+{
+    let mut verifier = AdminAuditChainVerifier::default();
+    let mut after_seq = 0_i64;
+    loop {
+        let rows = sqlx::query(&format!(
+            "SELECT {ADMIN_AUDIT_COLUMNS} FROM admin_audit_log \
+             WHERE seq > ? ORDER BY seq ASC LIMIT ?"
+        ))
+        .bind(after_seq)
+        .bind(ADMIN_AUDIT_EXPORT_BATCH)
+        .fetch_all(pool)
+        .await?;
+        if rows.is_empty() {
+            break;
+        }
+        for row in rows {
+            let entry = admin_audit_entry_from_row(row)?;
+            verifier.push(&entry);
+            sink(&entry)?;
+            after_seq = entry.seq;
+        }
+    }
+    Ok(verifier.finish())
+}
+
+/// Writes the audit log of `database_path` as JSON lines and verifies its hash chain.
+pub async fn export_admin_audit_log<W: std::io::Write>(
+    database_path: &str,
+    mut writer: W,
+) -> Result<AdminAuditVerification, ProxyError> {
+    let pool = open_sqlite_pool(database_path, false, true).await?;
+    let verification = scan_admin_audit_chain_on(&pool, |entry| {
+        serde_json::to_writer(&mut writer, entry)
+            .map_err(|err| ProxyError::Other(format!("encode audit entry: {err}")))?;
+        writer
+            .write_all(b"\n")
+            .map_err(|err| ProxyError::Other(format!("write audit entry: {err}")))
+    })
+    .await?;
+    writer
+        .flush()
+        .map_err(|err| ProxyError::Other(format!("flush audit export: {err}")))?;
+    Ok(verification)
+}
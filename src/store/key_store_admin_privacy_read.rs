@@ -60,6 +60,24 @@ async fn admin_privacy_alert_source_fence(
         .fetch_optional(&mut **snapshot)
         .await?
         .map(|(occurred_at, id)| (occurred_at, format!("job:{id:020}"))),
+        ALERT_SOURCE_AUTH_TOKEN_EXPIRY_NOTICE => sqlx::query_as::<_, (i64, i64)>(
+            r#"SELECT created_at, id
+                 FROM auth_token_expiry_notices
+                ORDER BY created_at DESC, id DESC
+                LIMIT 1"#,
+        )
+        .fetch_optional(&mut **snapshot)
+        .await?
+        .map(|(occurred_at, id)| (occurred_at, format!("tokexp:{id:020}"))),
+        ALERT_SOURCE_ALERT_RULE_FIRING => sqlx::query_as::<_, (i64, i64)>(
+            r#"SELECT created_at, id
+                 FROM alert_rule_firings
+                ORDER BY created_at DESC, id DESC
+                LIMIT 1"#,
+        )
+        .fetch_optional(&mut **snapshot)
+        .await?
+        .map(|(occurred_at, id)| (occurred_at, format!("rulefire:{id:020}"))),
         other => {
             return Err(ProxyError::Other(format!(
                 "unknown alert projection source: {other}"
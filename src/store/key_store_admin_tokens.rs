@@ -1,3 +1,17 @@
+/// `auth_tokens` columns read into an [`AuthToken`] by the list queries, in select order.
+type AuthTokenListRow = (
+    String,
+    i64,
+    Option<String>,
+    Option<String>,
+    i64,
+    i64,
+    Option<i64>,
+    Option<i64>,
+    Option<i64>,
+    Option<String>,
+);
+
 impl KeyStore {
     fn push_admin_token_filters<'a>(
         builder: &mut QueryBuilder<'a, Sqlite>,
@@ -52,16 +66,19 @@ impl KeyStore {
         }
     }
 
-    fn auth_token_from_row(
-        (id, enabled, note, group_name, total_requests, created_at, last_used_at): (
-            String,
-            i64,
-            Option<String>,
-            Option<String>,
-            i64,
-            i64,
-            Option<i64>,
-        ),
+    pub(crate) fn auth_token_from_row(
+        (
+            id,
+            enabled,
+            note,
+            group_name,
+            total_requests,
+            created_at,
+            last_used_at,
+            not_before,
+            expires_at,
+            scopes,
+        ): AuthTokenListRow,
     ) -> AuthToken {
         AuthToken {
             id,
@@ -75,6 +92,8 @@ impl KeyStore {
             quota_hourly_reset_at: None,
             quota_daily_reset_at: None,
             quota_monthly_reset_at: None,
+            lifetime: AccessTokenLifetime::new(not_before, expires_at),
+            scopes: scopes.as_deref().map(AccessTokenScopes::from_stored),
         }
     }
 
@@ -101,7 +120,8 @@ impl KeyStore {
         let total: i64 = count_builder.build_query_scalar().fetch_one(&self.pool).await?;
 
         let mut rows_builder = QueryBuilder::<Sqlite>::new(
-            r#"SELECT id, enabled, note, group_name, total_requests, created_at, last_used_at
+            r#"SELECT id, enabled, note, group_name, total_requests, created_at, last_used_at,
+                      not_before, expires_at, scopes
                FROM auth_tokens
                WHERE "#,
         );
@@ -112,15 +132,7 @@ impl KeyStore {
         rows_builder.push_bind(offset);
 
         let rows = rows_builder
-            .build_query_as::<(
-                String,
-                i64,
-                Option<String>,
-                Option<String>,
-                i64,
-                i64,
-                Option<i64>,
-            )>()
+            .build_query_as::<AuthTokenListRow>()
             .fetch_all(&self.pool)
             .await?;
         Ok((rows.into_iter().map(Self::auth_token_from_row).collect(), total))
@@ -137,22 +149,15 @@ impl KeyStore {
             .expect("admin heavy read semaphore is never closed");
         let search_like = filters.search.as_ref().map(|value| format!("%{value}%"));
         let mut builder = QueryBuilder::<Sqlite>::new(
-            r#"SELECT id, enabled, note, group_name, total_requests, created_at, last_used_at
+            r#"SELECT id, enabled, note, group_name, total_requests, created_at, last_used_at,
+                      not_before, expires_at, scopes
                FROM auth_tokens
                WHERE "#,
         );
         Self::push_admin_token_filters(&mut builder, filters, search_like.as_deref());
         builder.push(" ORDER BY created_at DESC, id DESC");
         let rows = builder
-            .build_query_as::<(
-                String,
-                i64,
-                Option<String>,
-                Option<String>,
-                i64,
-                i64,
-                Option<i64>,
-            )>()
+            .build_query_as::<AuthTokenListRow>()
             .fetch_all(&self.pool)
             .await?;
         Ok(rows.into_iter().map(Self::auth_token_from_row).collect())
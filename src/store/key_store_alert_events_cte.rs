@@ -26,6 +26,8 @@ impl KeyStore {
         format!(
             "CASE \
                 WHEN {alias}.alert_type = 'job_failed' AND {alias}.job_id IS NOT NULL THEN 'job' \
+                WHEN {alias}.alert_type = 'token_expiring' AND {alias}.token_id IS NOT NULL THEN 'token' \
+                WHEN {alias}.alert_type = 'threshold_rule' AND {alias}.reason_code IS NOT NULL THEN 'rule' \
                 WHEN {alias}.alert_type IN ('upstream_rate_limited_429', 'upstream_usage_limit_432', 'upstream_key_blocked', 'api_key_exhausted') AND {alias}.key_id IS NOT NULL THEN 'key' \
                 WHEN {alias}.user_id IS NOT NULL THEN 'user' \
                 WHEN {alias}.token_id IS NOT NULL THEN 'token' \
@@ -39,6 +41,8 @@ impl KeyStore {
         format!(
             "CASE \
                 WHEN {alias}.alert_type = 'job_failed' AND {alias}.job_id IS NOT NULL THEN CAST({alias}.job_id AS TEXT) \
+                WHEN {alias}.alert_type = 'token_expiring' AND {alias}.token_id IS NOT NULL THEN {alias}.token_id \
+                WHEN {alias}.alert_type = 'threshold_rule' AND {alias}.reason_code IS NOT NULL THEN {alias}.reason_code \
                 WHEN {alias}.alert_type IN ('upstream_rate_limited_429', 'upstream_usage_limit_432', 'upstream_key_blocked', 'api_key_exhausted') AND {alias}.key_id IS NOT NULL THEN {alias}.key_id \
                 WHEN {alias}.user_id IS NOT NULL THEN {alias}.user_id \
                 WHEN {alias}.token_id IS NOT NULL THEN {alias}.token_id \
@@ -172,6 +176,52 @@ impl KeyStore {
         }
     }
 
+    fn push_token_expiry_alert_filters<'a>(
+        query: &mut QueryBuilder<'a, Sqlite>,
+        filters: AlertEventFilters<'a>,
+    ) {
+        if let Some(alert_type) = filters.alert_type
+            && alert_type != ALERT_TYPE_TOKEN_EXPIRING
+        {
+            query.push(" AND 1 = 0");
+        }
+        if let Some(since) = filters.since {
+            query.push(" AND n.created_at >= ").push_bind(since);
+        }
+        if let Some(until) = filters.until {
+            query.push(" AND n.created_at <= ").push_bind(until);
+        }
+        if let Some(user_id) = filters.user_id {
+            query.push(" AND u.id = ").push_bind(user_id);
+        }
+        if let Some(token_id) = filters.token_id {
+            query.push(" AND n.token_id = ").push_bind(token_id);
+        }
+        if filters.key_id.is_some() {
+            query.push(" AND 1 = 0");
+        }
+    }
+
+    fn push_alert_rule_firing_filters<'a>(
+        query: &mut QueryBuilder<'a, Sqlite>,
+        filters: AlertEventFilters<'a>,
+    ) {
+        if let Some(alert_type) = filters.alert_type
+            && alert_type != ALERT_TYPE_THRESHOLD_RULE
+        {
+            query.push(" AND 1 = 0");
+        }
+        if let Some(since) = filters.since {
+            query.push(" AND f.created_at >= ").push_bind(since);
+        }
+        if let Some(until) = filters.until {
+            query.push(" AND f.created_at <= ").push_bind(until);
+        }
+        if filters.user_id.is_some() || filters.token_id.is_some() || filters.key_id.is_some() {
+            query.push(" AND 1 = 0");
+        }
+    }
+
     fn push_alert_events_cte<'a>(
         query: &mut QueryBuilder<'a, Sqlite>,
         filters: AlertEventFilters<'a>,
@@ -483,6 +533,113 @@ impl KeyStore {
             ALERT_SOURCE_SCHEDULED_JOB,
             "CAST(j.id AS TEXT)",
         );
+        query.push(
+            r#"
+            UNION ALL
+            SELECT
+            "#,
+        );
+        query.push_bind(ALERT_SOURCE_AUTH_TOKEN_EXPIRY_NOTICE);
+        query.push(
+            r#" AS source_kind,
+                CAST(n.id AS TEXT) AS source_id,
+                printf('tokexp:%020lld', n.id) AS row_sort_id,
+                'token_expiring' AS alert_type,
+                n.created_at AS occurred_at,
+                n.token_id AS token_id,
+                NULL AS key_id,
+                NULL AS request_log_id,
+                NULL AS method,
+                NULL AS path,
+                NULL AS query,
+                NULL AS request_kind_key,
+                NULL AS request_kind_label,
+                NULL AS request_kind_detail,
+                NULL AS result_status,
+                NULL AS failure_kind,
+                NULL AS error_message,
+                NULL AS counts_business_quota,
+                u.id AS user_id,
+                u.display_name AS user_display_name,
+                u.username AS user_username,
+                'token_expiring' AS reason_code,
+                'expires at ' || strftime('%Y-%m-%dT%H:%M:%SZ', n.expires_at, 'unixepoch') AS reason_summary,
+                NULL AS reason_detail,
+                NULL AS job_id,
+                NULL AS job_type,
+                NULL AS job_trigger_source,
+                NULL AS job_status,
+                NULL AS job_attempt,
+                NULL AS job_message,
+                NULL AS job_queued_at,
+                NULL AS job_started_at,
+                NULL AS job_finished_at
+            FROM auth_token_expiry_notices n
+            LEFT JOIN user_token_bindings b ON b.token_id = n.token_id
+            LEFT JOIN users u ON u.id = b.user_id
+            WHERE 1 = 1
+            "#,
+        );
+        Self::push_token_expiry_alert_filters(query, filters);
+        Self::push_alert_projection_source_selection(
+            query,
+            selected_source,
+            ALERT_SOURCE_AUTH_TOKEN_EXPIRY_NOTICE,
+            "CAST(n.id AS TEXT)",
+        );
+        // Rule firings carry the rule id as reason code so their alerts group per rule.
+        query.push(
+            r#"
+            UNION ALL
+            SELECT
+            "#,
+        );
+        query.push_bind(ALERT_SOURCE_ALERT_RULE_FIRING);
+        query.push(
+            r#" AS source_kind,
+                CAST(f.id AS TEXT) AS source_id,
+                printf('rulefire:%020lld', f.id) AS row_sort_id,
+                'threshold_rule' AS alert_type,
+                f.created_at AS occurred_at,
+                NULL AS token_id,
+                NULL AS key_id,
+                NULL AS request_log_id,
+                NULL AS method,
+                NULL AS path,
+                NULL AS query,
+                NULL AS request_kind_key,
+                NULL AS request_kind_label,
+                NULL AS request_kind_detail,
+                NULL AS result_status,
+                NULL AS failure_kind,
+                NULL AS error_message,
+                NULL AS counts_business_quota,
+                NULL AS user_id,
+                NULL AS user_display_name,
+                NULL AS user_username,
+                f.rule_id AS reason_code,
+                f.summary AS reason_summary,
+                f.rule_name AS reason_detail,
+                NULL AS job_id,
+                NULL AS job_type,
+                NULL AS job_trigger_source,
+                NULL AS job_status,
+                NULL AS job_attempt,
+                NULL AS job_message,
+                NULL AS job_queued_at,
+                NULL AS job_started_at,
+                NULL AS job_finished_at
+            FROM alert_rule_firings f
+            WHERE 1 = 1
+            "#,
+        );
+        Self::push_alert_rule_firing_filters(query, filters);
+        Self::push_alert_projection_source_selection(
+            query,
+            selected_source,
+            ALERT_SOURCE_ALERT_RULE_FIRING,
+            "CAST(f.id AS TEXT)",
+        );
         query.push(")");
     }
 
@@ -558,6 +715,9 @@ impl KeyStore {
                 .push(" AND json_extract(payload_json, '$.key_id') = ")
                 .push_bind(key_id);
         }
+        if filters.exclude_silenced {
+            query.push(" AND silence_id IS NULL");
+        }
         query.push(")");
     }
 }
@@ -101,6 +101,7 @@ impl KeyStore {
                     event_count: group.count,
                     children: Vec::new(),
                     child_events: Vec::new(),
+                    workflow: None,
                 })
             })
             .collect()
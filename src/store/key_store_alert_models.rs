@@ -7,6 +7,8 @@ struct AlertEventFilters<'a> {
     token_id: Option<&'a str>,
     key_id: Option<&'a str>,
     request_kinds: &'a [String],
+    /// Drops silenced events. Only the projected read model knows which events were silenced.
+    exclude_silenced: bool,
 }
 
 #[derive(Clone, Copy)]
@@ -6,10 +6,12 @@ const ALERT_PROJECTION_BATCH_ROWS: i64 = 25;
 const ALERT_PROJECTION_STALE_SECS: i64 = 90;
 const ALERT_PROJECTION_SUMMARY_REFRESH_SECS: i64 = 60;
 const ALERT_PROJECTION_DASHBOARD_WINDOW_HOURS: i64 = 24;
-const ALERT_PROJECTION_SOURCES: [&str; 3] = [
+const ALERT_PROJECTION_SOURCES: [&str; 5] = [
     ALERT_SOURCE_AUTH_TOKEN_LOG,
     ALERT_SOURCE_API_KEY_MAINTENANCE_RECORD,
     ALERT_SOURCE_SCHEDULED_JOB,
+    ALERT_SOURCE_AUTH_TOKEN_EXPIRY_NOTICE,
+    ALERT_SOURCE_ALERT_RULE_FIRING,
 ];
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -240,6 +242,24 @@ impl KeyStore {
             .fetch_optional(&mut *conn)
             .await
             .map(|row| row.map(|(occurred_at, id)| (occurred_at, format!("job:{id:020}")))),
+            ALERT_SOURCE_AUTH_TOKEN_EXPIRY_NOTICE => sqlx::query_as::<_, (i64, i64)>(
+                r#"SELECT created_at, id
+                     FROM auth_token_expiry_notices
+                    ORDER BY created_at DESC, id DESC
+                    LIMIT 1"#,
+            )
+            .fetch_optional(&mut *conn)
+            .await
+            .map(|row| row.map(|(occurred_at, id)| (occurred_at, format!("tokexp:{id:020}")))),
+            ALERT_SOURCE_ALERT_RULE_FIRING => sqlx::query_as::<_, (i64, i64)>(
+                r#"SELECT created_at, id
+                     FROM alert_rule_firings
+                    ORDER BY created_at DESC, id DESC
+                    LIMIT 1"#,
+            )
+            .fetch_optional(&mut *conn)
+            .await
+            .map(|row| row.map(|(occurred_at, id)| (occurred_at, format!("rulefire:{id:020}")))),
             other => Err(sqlx::Error::Protocol(format!(
                 "unknown alert projection source: {other}"
             ))),
@@ -263,6 +283,16 @@ impl KeyStore {
                 .unwrap_or_default()
                 .trim_start_matches('0')
                 .to_string(),
+            ALERT_SOURCE_AUTH_TOKEN_EXPIRY_NOTICE => row_sort_id
+                .strip_prefix("tokexp:")
+                .unwrap_or_default()
+                .trim_start_matches('0')
+                .to_string(),
+            ALERT_SOURCE_ALERT_RULE_FIRING => row_sort_id
+                .strip_prefix("rulefire:")
+                .unwrap_or_default()
+                .trim_start_matches('0')
+                .to_string(),
             _ => String::new(),
         }
     }
@@ -373,6 +403,58 @@ impl KeyStore {
                     })
                     .collect()
             }),
+            ALERT_SOURCE_AUTH_TOKEN_EXPIRY_NOTICE => sqlx::query_as::<_, (i64, i64)>(
+                r#"SELECT created_at, id
+                     FROM auth_token_expiry_notices
+                    WHERE (created_at > ? OR (created_at = ? AND id > ?))
+                      AND (created_at < ? OR (created_at = ? AND id <= ?))
+                    ORDER BY created_at ASC, id ASC
+                    LIMIT ?"#,
+            )
+            .bind(cursor.0)
+            .bind(cursor.0)
+            .bind(cursor_id.parse::<i64>().unwrap_or_default())
+            .bind(fence.0)
+            .bind(fence.0)
+            .bind(fence_id.parse::<i64>().unwrap_or_default())
+            .bind(ALERT_PROJECTION_BATCH_ROWS)
+            .fetch_all(&mut *conn)
+            .await
+            .map(|rows| {
+                rows.into_iter()
+                    .map(|(occurred_at, id)| AlertProjectionSourceKey {
+                        source_id: id.to_string(),
+                        occurred_at,
+                        row_sort_id: format!("tokexp:{id:020}"),
+                    })
+                    .collect()
+            }),
+            ALERT_SOURCE_ALERT_RULE_FIRING => sqlx::query_as::<_, (i64, i64)>(
+                r#"SELECT created_at, id
+                     FROM alert_rule_firings
+                    WHERE (created_at > ? OR (created_at = ? AND id > ?))
+                      AND (created_at < ? OR (created_at = ? AND id <= ?))
+                    ORDER BY created_at ASC, id ASC
+                    LIMIT ?"#,
+            )
+            .bind(cursor.0)
+            .bind(cursor.0)
+            .bind(cursor_id.parse::<i64>().unwrap_or_default())
+            .bind(fence.0)
+            .bind(fence.0)
+            .bind(fence_id.parse::<i64>().unwrap_or_default())
+            .bind(ALERT_PROJECTION_BATCH_ROWS)
+            .fetch_all(&mut *conn)
+            .await
+            .map(|rows| {
+                rows.into_iter()
+                    .map(|(occurred_at, id)| AlertProjectionSourceKey {
+                        source_id: id.to_string(),
+                        occurred_at,
+                        row_sort_id: format!("rulefire:{id:020}"),
+                    })
+                    .collect()
+            }),
             other => Err(sqlx::Error::Protocol(format!(
                 "unknown alert projection source: {other}"
             ))),
@@ -396,6 +478,7 @@ impl KeyStore {
             token_id: None,
             key_id: None,
             request_kinds: &[],
+            exclude_silenced: false,
         };
         let mut query = QueryBuilder::new("");
         let source_ids = source_keys
@@ -522,7 +605,7 @@ impl KeyStore {
                 > (recent.cursor_occurred_at, recent.cursor_row_sort_id.as_str())
         });
         // A catching-up source must not hide a newly arrived alert from an
-        // otherwise idle source. Probe the other two bounded watermarks every
+        // otherwise idle source. Probe the other bounded watermarks every
         // slice and let newly eligible tail work preempt historical backlog.
         // This advances a cursor only when there is actual source work.
         for source_kind in ALERT_PROJECTION_SOURCES {
@@ -662,19 +745,22 @@ impl KeyStore {
             .begin_immediate(SqliteOperation::AlertProjection)
             .await?;
         let result = async {
-            for row in rows {
+            let silence_ids = Self::alert_projection_silence_ids_on(&mut tx, rows).await?;
+            for (row, silence_id) in rows.iter().zip(&silence_ids) {
                 let payload_json = serde_json::to_string(row).map_err(|err| {
                     ProxyError::Other(format!("serialize alert projection event: {err}"))
                 })?;
                 sqlx::query(
                     r#"INSERT INTO observability.dashboard_alert_projection_events
-                        (source_kind, source_id, occurred_at, row_sort_id, payload_json, projected_at)
-                       VALUES (?, ?, ?, ?, ?, ?)
+                        (source_kind, source_id, occurred_at, row_sort_id, payload_json, projected_at,
+                         silence_id)
+                       VALUES (?, ?, ?, ?, ?, ?, ?)
                        ON CONFLICT(source_kind, source_id) DO UPDATE SET
                          occurred_at = excluded.occurred_at,
                          row_sort_id = excluded.row_sort_id,
                          payload_json = excluded.payload_json,
-                         projected_at = excluded.projected_at"#,
+                         projected_at = excluded.projected_at,
+                         silence_id = COALESCE(excluded.silence_id, silence_id)"#,
                 )
                 .bind(&row.source_kind)
                 .bind(&row.source_id)
@@ -682,9 +768,18 @@ impl KeyStore {
                 .bind(&row.row_sort_id)
                 .bind(payload_json)
                 .bind(observed_at)
+                .bind(silence_id)
                 .execute(&mut *tx)
                 .await?;
             }
+            // Silenced events are recorded but never notified.
+            let notify_rows = rows
+                .iter()
+                .zip(&silence_ids)
+                .filter(|(_, silence_id)| silence_id.is_none())
+                .map(|(row, _)| row.clone())
+                .collect::<Vec<_>>();
+            Self::enqueue_alert_webhook_deliveries_on(&mut tx, &notify_rows, observed_at).await?;
             let (fence_occurred_at, fence_row_sort_id, phase) = if complete {
                 (None, None, "idle")
             } else {
@@ -785,7 +880,7 @@ impl KeyStore {
         // A newly created sidecar has no observation timestamps yet. It is
         // nevertheless safe for Dashboard to serve an empty recent-alert
         // view when every unfinished tail source has an empty direct
-        // watermark. This uses three bounded source seeks, not the raw alert
+        // watermark. This uses one bounded seek per source, not the raw alert
         // CTE, and avoids turning a cold no-alert database into a 5xx.
         if recent_coverage == "projecting" && stale_reason.is_none() {
             let mut conn = self
@@ -1053,6 +1148,7 @@ impl KeyStore {
             token_id: None,
             key_id: None,
             request_kinds: &[],
+            exclude_silenced: true,
         };
         let (top_groups, grouped_count) = self
             .fetch_projected_alert_group_page(filters, 1, 10)
@@ -1068,6 +1164,7 @@ impl KeyStore {
                 token_id: None,
                 key_id: None,
                 request_kinds: &[],
+                exclude_silenced: true,
             };
             let grouped_count = if window_hours == clamped_window_hours {
                 grouped_count
@@ -1081,7 +1178,8 @@ impl KeyStore {
         }
         let mut count_query = QueryBuilder::new(
             "SELECT json_extract(payload_json, '$.alert_type') AS alert_type, COUNT(*) AS count \
-             FROM observability.dashboard_alert_projection_events WHERE occurred_at >= ",
+             FROM observability.dashboard_alert_projection_events \
+             WHERE silence_id IS NULL AND occurred_at >= ",
         );
         count_query.push_bind(since);
         count_query.push(" GROUP BY json_extract(payload_json, '$.alert_type')");
@@ -1097,7 +1195,8 @@ impl KeyStore {
             .acquire_operation_connection(SqliteOperation::AlertProjection)
             .await?;
         let total_result = sqlx::query_scalar::<_, i64>(
-            "SELECT COUNT(*) FROM observability.dashboard_alert_projection_events WHERE occurred_at >= ?",
+            "SELECT COUNT(*) FROM observability.dashboard_alert_projection_events \
+             WHERE silence_id IS NULL AND occurred_at >= ?",
         )
         .bind(since)
         .fetch_one(&mut *total_conn)
@@ -0,0 +1,308 @@
+const ALERT_RULE_ID_ALPHABET: &[u8] = b"23456789abcdefghjkmnpqrstuvwxyz";
+
+const ALERT_RULE_COLUMNS: &str = "r.id, r.name, r.metric, r.comparator, r.threshold, \
+     r.clear_threshold, r.window_secs, r.request_kind, r.enabled, r.created_at, r.updated_at, \
+     COALESCE(s.state, 'ok') AS state, s.last_value, s.last_evaluated_at, s.last_fired_at";
+
+fn alert_rule_from_row(row: sqlx::sqlite::SqliteRow) -> Result<AlertRule, sqlx::Error> {
+    Ok(AlertRule {
+        id: row.try_get("id")?,
+        name: row.try_get("name")?,
+        metric: row.try_get("metric")?,
+        comparator: row.try_get("comparator")?,
+        threshold: row.try_get("threshold")?,
+        clear_threshold: row.try_get("clear_threshold")?,
+        window_secs: row.try_get("window_secs")?,
+        request_kind: row.try_get("request_kind")?,
+        enabled: row.try_get::<i64, _>("enabled")? != 0,
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+        state: row.try_get("state")?,
+        last_value: row.try_get("last_value")?,
+        last_evaluated_at: row.try_get("last_evaluated_at")?,
+        last_fired_at: row.try_get("last_fired_at")?,
+    })
+}
+
+impl KeyStore {
+    pub(crate) async fn ensure_alert_rules_schema(&self) -> Result<(), ProxyError> {
+        // Rule definitions replicate over HA. Evaluation state and firings belong to the node
+        // that runs the background tasks, like the other alert sources.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS alert_rules (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL,
+                metric TEXT NOT NULL,
+                comparator TEXT NOT NULL,
+                threshold REAL NOT NULL,
+                clear_threshold REAL,
+                window_secs INTEGER NOT NULL DEFAULT 900,
+                request_kind TEXT,
+                enabled INTEGER NOT NULL DEFAULT 1,
+                created_at INTEGER NOT NULL,
+                updated_at INTEGER NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS alert_rule_states (
+                rule_id TEXT PRIMARY KEY,
+                state TEXT NOT NULL,
+                last_value REAL,
+                last_evaluated_at INTEGER,
+                last_fired_at INTEGER
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+        // One row per ok -> firing transition; each surfaces as a `threshold_rule` alert.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS alert_rule_firings (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                rule_id TEXT NOT NULL,
+                rule_name TEXT NOT NULL,
+                metric TEXT NOT NULL,
+                value REAL NOT NULL,
+                threshold REAL NOT NULL,
+                summary TEXT NOT NULL,
+                created_at INTEGER NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+        sqlx::query(
+            r#"CREATE INDEX IF NOT EXISTS idx_alert_rule_firings_created
+               ON alert_rule_firings(created_at, id)"#,
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub(crate) async fn list_alert_rules(&self) -> Result<Vec<AlertRule>, ProxyError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {ALERT_RULE_COLUMNS} FROM alert_rules r \
+             LEFT JOIN alert_rule_states s ON s.rule_id = r.id \
+             ORDER BY r.created_at, r.id"
+        ))
+        .try_map(alert_rule_from_row)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    pub(crate) async fn get_alert_rule(&self, id: &str) -> Result<Option<AlertRule>, ProxyError> {
+        let row = sqlx::query(&format!(
+            "SELECT {ALERT_RULE_COLUMNS} FROM alert_rules r \
+             LEFT JOIN alert_rule_states s ON s.rule_id = r.id \
+             WHERE r.id = ?"
+        ))
+        .bind(id)
+        .try_map(alert_rule_from_row)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row)
+    }
+
+    pub(crate) async fn create_alert_rule(
+        &self,
+        input: AlertRuleMutation,
+    ) -> Result<AlertRule, ProxyError> {
+        let input = input.normalized().map_err(ProxyError::Other)?;
+        let id = random_string(ALERT_RULE_ID_ALPHABET, 10);
+        let now = self.backend_time.now_ts();
+        sqlx::query(
+            r#"INSERT INTO alert_rules
+                   (id, name, metric, comparator, threshold, clear_threshold, window_secs,
+                    request_kind, enabled, created_at, updated_at)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+        )
+        .bind(&id)
+        .bind(&input.name)
+        .bind(&input.metric)
+        .bind(&input.comparator)
+        .bind(input.threshold)
+        .bind(input.clear_threshold)
+        .bind(input.window_secs.unwrap_or(ALERT_RULE_WINDOW_SECS_DEFAULT))
+        .bind(&input.request_kind)
+        .bind(input.enabled)
+        .bind(now)
+        .bind(now)
+        .execute(&self.pool)
+        .await?;
+        self.get_alert_rule(&id)
+            .await?
+            .ok_or_else(|| ProxyError::Other("alert rule disappeared after insert".to_string()))
+    }
+
+    /// Replaces a rule definition. A rule that changes metric or direction starts over from
+    /// `ok`, since its previous state described a different condition.
+    pub(crate) async fn update_alert_rule(
+        &self,
+        id: &str,
+        input: AlertRuleMutation,
+    ) -> Result<Option<AlertRule>, ProxyError> {
+        let input = input.normalized().map_err(ProxyError::Other)?;
+        let Some(existing) = self.get_alert_rule(id).await? else {
+            return Ok(None);
+        };
+        let mut tx = self.pool.begin().await?;
+        sqlx::query(
+            r#"UPDATE alert_rules
+                  SET name = ?, metric = ?, comparator = ?, threshold = ?, clear_threshold = ?,
+                      window_secs = ?, request_kind = ?, enabled = ?, updated_at = ?
+                WHERE id = ?"#,
+        )
+        .bind(&input.name)
+        .bind(&input.metric)
+        .bind(&input.comparator)
+        .bind(input.threshold)
+        .bind(input.clear_threshold)
+        .bind(input.window_secs.unwrap_or(ALERT_RULE_WINDOW_SECS_DEFAULT))
+        .bind(&input.request_kind)
+        .bind(input.enabled)
+        .bind(self.backend_time.now_ts())
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+        if existing.metric != input.metric
+            || existing.comparator != input.comparator
+            || existing.request_kind != input.request_kind
+            || !input.enabled
+        {
+            sqlx::query("DELETE FROM alert_rule_states WHERE rule_id = ?")
+                .bind(id)
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await?;
+        self.get_alert_rule(id).await
+    }
+
+    /// Deletes a rule. Alerts it already raised stay in the alert history.
+    pub(crate) async fn delete_alert_rule(&self, id: &str) -> Result<bool, ProxyError> {
+        let mut tx = self.pool.begin().await?;
+        let deleted = sqlx::query("DELETE FROM alert_rules WHERE id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected()
+            > 0;
+        sqlx::query("DELETE FROM alert_rule_states WHERE rule_id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(deleted)
+    }
+
+    pub(crate) async fn alert_rule_pool_credits_remaining(&self) -> Result<f64, ProxyError> {
+        let remaining: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COALESCE(SUM(ak.quota_remaining), 0)
+            FROM api_keys ak
+            LEFT JOIN api_key_quarantines aq
+              ON aq.key_id = ak.id AND aq.cleared_at IS NULL
+            WHERE ak.deleted_at IS NULL
+              AND ak.status = ?
+              AND aq.key_id IS NULL
+            "#,
+        )
+        .bind(STATUS_ACTIVE)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(remaining as f64)
+    }
+
+    /// Error percentage of requests logged since `since`, or `None` while there are too few
+    /// requests for the rate to mean anything.
+    pub(crate) async fn alert_rule_request_error_rate(
+        &self,
+        since: i64,
+        request_kind: Option<&str>,
+    ) -> Result<Option<f64>, ProxyError> {
+        let mut query = QueryBuilder::<Sqlite>::new(
+            "SELECT COUNT(*) AS total, \
+                    COALESCE(SUM(CASE WHEN result_status = ",
+        );
+        query
+            .push_bind(OUTCOME_ERROR)
+            .push(" THEN 1 ELSE 0 END), 0) AS errors FROM request_logs WHERE created_at >= ")
+            .push_bind(since);
+        if let Some(request_kind) = request_kind {
+            query.push(" AND request_kind_key = ").push_bind(request_kind);
+        }
+        let row = query.build().fetch_one(&self.pool).await?;
+        let total: i64 = row.try_get("total")?;
+        let errors: i64 = row.try_get("errors")?;
+        if total < ALERT_RULE_ERROR_RATE_MIN_REQUESTS {
+            return Ok(None);
+        }
+        Ok(Some(errors as f64 * 100.0 / total as f64))
+    }
+
+    /// Stores the outcome of evaluating `rule` and records a firing when it crosses into
+    /// breach. A `None` value leaves the state untouched. Returns whether the rule fired.
+    pub(crate) async fn record_alert_rule_evaluation(
+        &self,
+        rule: &AlertRule,
+        value: Option<f64>,
+    ) -> Result<bool, ProxyError> {
+        let now = self.backend_time.now_ts();
+        let mut tx = self.pool.begin().await?;
+        let current: Option<String> =
+            sqlx::query_scalar("SELECT state FROM alert_rule_states WHERE rule_id = ?")
+                .bind(&rule.id)
+                .fetch_optional(&mut *tx)
+                .await?;
+        let was_firing = current.as_deref() == Some(ALERT_RULE_STATE_FIRING);
+        let firing = value.map_or(was_firing, |value| rule.next_firing(was_firing, value));
+        let fired = firing && !was_firing;
+        let state = if firing {
+            ALERT_RULE_STATE_FIRING
+        } else {
+            ALERT_RULE_STATE_OK
+        };
+        sqlx::query(
+            r#"INSERT INTO alert_rule_states (rule_id, state, last_value, last_evaluated_at, last_fired_at)
+               VALUES (?, ?, ?, ?, ?)
+               ON CONFLICT(rule_id) DO UPDATE SET
+                   state = excluded.state,
+                   last_value = excluded.last_value,
+                   last_evaluated_at = excluded.last_evaluated_at,
+                   last_fired_at = COALESCE(excluded.last_fired_at, alert_rule_states.last_fired_at)"#,
+        )
+        .bind(&rule.id)
+        .bind(state)
+        .bind(value)
+        .bind(now)
+        .bind(fired.then_some(now))
+        .execute(&mut *tx)
+        .await?;
+        if let Some(value) = value.filter(|_| fired) {
+            sqlx::query(
+                r#"INSERT INTO alert_rule_firings
+                       (rule_id, rule_name, metric, value, threshold, summary, created_at)
+                   VALUES (?, ?, ?, ?, ?, ?, ?)"#,
+            )
+            .bind(&rule.id)
+            .bind(&rule.name)
+            .bind(&rule.metric)
+            .bind(value)
+            .bind(rule.threshold)
+            .bind(rule.describe_breach(value))
+            .bind(now)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(fired)
+    }
+}
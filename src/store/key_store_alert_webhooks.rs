@@ -0,0 +1,480 @@
+const ALERT_WEBHOOK_ID_ALPHABET: &[u8] = b"23456789abcdefghjkmnpqrstuvwxyz";
+const ALERT_WEBHOOK_SECRET_ALPHABET: &[u8] = b"0123456789abcdef";
+/// Alerts projected later than this after they happened (catch-up after downtime, history
+/// backfill) are not sent: a page about a problem from yesterday is noise.
+const ALERT_WEBHOOK_EVENT_MAX_AGE_SECS: i64 = 60 * 60;
+/// Delivered and failed deliveries stay in the admin history this long.
+const ALERT_WEBHOOK_DELIVERY_RETENTION_SECS: i64 = 14 * 24 * 60 * 60;
+const ALERT_WEBHOOK_LAST_ERROR_MAX_CHARS: usize = 500;
+
+const ALERT_WEBHOOK_SINK_COLUMNS: &str = "id, name, url, template, alert_types, telegram_chat_id, \
+     secret, repeat_interval_secs, enabled, created_at, updated_at";
+const ALERT_WEBHOOK_DELIVERY_COLUMNS: &str = "id, sink_id, event_id, alert_type, \
+     event_occurred_at, payload_json, status, attempts, next_attempt_at, last_status_code, \
+     last_error, created_at, updated_at, delivered_at";
+
+fn alert_webhook_sink_from_row(row: sqlx::sqlite::SqliteRow) -> Result<AlertWebhookSink, sqlx::Error> {
+    let alert_types: String = row.try_get("alert_types")?;
+    Ok(AlertWebhookSink {
+        id: row.try_get("id")?,
+        name: row.try_get("name")?,
+        url: row.try_get("url")?,
+        template: row.try_get("template")?,
+        alert_types: serde_json::from_str(&alert_types).unwrap_or_default(),
+        telegram_chat_id: row.try_get("telegram_chat_id")?,
+        secret: row.try_get("secret")?,
+        repeat_interval_secs: row.try_get("repeat_interval_secs")?,
+        enabled: row.try_get::<i64, _>("enabled")? != 0,
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
+
+fn alert_webhook_delivery_from_row(
+    row: sqlx::sqlite::SqliteRow,
+) -> Result<AlertWebhookDelivery, sqlx::Error> {
+    Ok(AlertWebhookDelivery {
+        id: row.try_get("id")?,
+        sink_id: row.try_get("sink_id")?,
+        event_id: row.try_get("event_id")?,
+        alert_type: row.try_get("alert_type")?,
+        event_occurred_at: row.try_get("event_occurred_at")?,
+        payload_json: row.try_get("payload_json")?,
+        status: row.try_get("status")?,
+        attempts: row.try_get("attempts")?,
+        next_attempt_at: row.try_get("next_attempt_at")?,
+        last_status_code: row.try_get("last_status_code")?,
+        last_error: row.try_get("last_error")?,
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+        delivered_at: row.try_get("delivered_at")?,
+    })
+}
+
+fn generate_alert_webhook_secret() -> String {
+    format!("whsec_{}", random_string(ALERT_WEBHOOK_SECRET_ALPHABET, 48))
+}
+
+/// Result of one POST attempt, recorded against the delivery.
+#[derive(Debug, Clone)]
+pub(crate) struct AlertWebhookAttemptOutcome {
+    pub(crate) status_code: Option<u16>,
+    pub(crate) error: Option<String>,
+}
+
+impl AlertWebhookAttemptOutcome {
+    fn delivered(&self) -> bool {
+        self.error.is_none() && self.status_code.is_some_and(|status| (200..300).contains(&status))
+    }
+
+    fn retryable(&self) -> bool {
+        self.status_code
+            .is_none_or(alert_webhook_status_is_retryable)
+    }
+}
+
+impl KeyStore {
+    pub(crate) async fn ensure_alert_webhooks_schema(&self) -> Result<(), ProxyError> {
+        // Sinks are control-plane configuration and replicate over HA; the delivery queue is
+        // owned by whichever node runs the background tasks.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS alert_webhook_sinks (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL,
+                url TEXT NOT NULL,
+                template TEXT NOT NULL,
+                alert_types TEXT NOT NULL DEFAULT '[]',
+                telegram_chat_id TEXT,
+                secret TEXT NOT NULL,
+                repeat_interval_secs INTEGER NOT NULL DEFAULT 300,
+                enabled INTEGER NOT NULL DEFAULT 1,
+                created_at INTEGER NOT NULL,
+                updated_at INTEGER NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS alert_webhook_deliveries (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                sink_id TEXT NOT NULL,
+                event_id TEXT NOT NULL,
+                alert_type TEXT NOT NULL,
+                dedupe_key TEXT NOT NULL,
+                event_occurred_at INTEGER NOT NULL,
+                payload_json TEXT NOT NULL,
+                status TEXT NOT NULL,
+                attempts INTEGER NOT NULL DEFAULT 0,
+                next_attempt_at INTEGER,
+                last_status_code INTEGER,
+                last_error TEXT,
+                created_at INTEGER NOT NULL,
+                updated_at INTEGER NOT NULL,
+                delivered_at INTEGER
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+        sqlx::query(
+            r#"CREATE UNIQUE INDEX IF NOT EXISTS idx_alert_webhook_deliveries_sink_event
+               ON alert_webhook_deliveries(sink_id, event_id)"#,
+        )
+        .execute(&self.pool)
+        .await?;
+        sqlx::query(
+            r#"CREATE INDEX IF NOT EXISTS idx_alert_webhook_deliveries_due
+               ON alert_webhook_deliveries(status, next_attempt_at)"#,
+        )
+        .execute(&self.pool)
+        .await?;
+        sqlx::query(
+            r#"CREATE INDEX IF NOT EXISTS idx_alert_webhook_deliveries_dedupe
+               ON alert_webhook_deliveries(sink_id, dedupe_key, event_occurred_at)"#,
+        )
+        .execute(&self.pool)
+        .await?;
+        sqlx::query(
+            r#"CREATE INDEX IF NOT EXISTS idx_alert_webhook_deliveries_created
+               ON alert_webhook_deliveries(created_at)"#,
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub(crate) async fn list_alert_webhook_sinks(&self) -> Result<Vec<AlertWebhookSink>, ProxyError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {ALERT_WEBHOOK_SINK_COLUMNS} FROM alert_webhook_sinks ORDER BY created_at, id"
+        ))
+        .try_map(alert_webhook_sink_from_row)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    pub(crate) async fn get_alert_webhook_sink(
+        &self,
+        id: &str,
+    ) -> Result<Option<AlertWebhookSink>, ProxyError> {
+        let row = sqlx::query(&format!(
+            "SELECT {ALERT_WEBHOOK_SINK_COLUMNS} FROM alert_webhook_sinks WHERE id = ?"
+        ))
+        .bind(id)
+        .try_map(alert_webhook_sink_from_row)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row)
+    }
+
+    pub(crate) async fn create_alert_webhook_sink(
+        &self,
+        input: AlertWebhookSinkMutation,
+    ) -> Result<AlertWebhookSink, ProxyError> {
+        let input = input.normalized().map_err(ProxyError::Other)?;
+        let now = self.backend_time.now_ts();
+        let sink = AlertWebhookSink {
+            id: random_string(ALERT_WEBHOOK_ID_ALPHABET, 10),
+            name: input.name,
+            url: input.url,
+            template: input.template,
+            alert_types: input.alert_types,
+            telegram_chat_id: input.telegram_chat_id,
+            secret: input.secret.unwrap_or_else(generate_alert_webhook_secret),
+            repeat_interval_secs: input
+                .repeat_interval_secs
+                .unwrap_or(ALERT_WEBHOOK_REPEAT_INTERVAL_SECS_DEFAULT),
+            enabled: input.enabled,
+            created_at: now,
+            updated_at: now,
+        };
+        sqlx::query(
+            r#"INSERT INTO alert_webhook_sinks
+                   (id, name, url, template, alert_types, telegram_chat_id, secret,
+                    repeat_interval_secs, enabled, created_at, updated_at)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+        )
+        .bind(&sink.id)
+        .bind(&sink.name)
+        .bind(&sink.url)
+        .bind(&sink.template)
+        .bind(serde_json::to_string(&sink.alert_types).unwrap_or_else(|_| "[]".to_string()))
+        .bind(&sink.telegram_chat_id)
+        .bind(&sink.secret)
+        .bind(sink.repeat_interval_secs)
+        .bind(i64::from(sink.enabled))
+        .bind(sink.created_at)
+        .bind(sink.updated_at)
+        .execute(&self.pool)
+        .await?;
+        Ok(sink)
+    }
+
+    pub(crate) async fn update_alert_webhook_sink(
+        &self,
+        id: &str,
+        input: AlertWebhookSinkMutation,
+    ) -> Result<Option<AlertWebhookSink>, ProxyError> {
+        let input = input.normalized().map_err(ProxyError::Other)?;
+        let result = sqlx::query(
+            r#"UPDATE alert_webhook_sinks
+               SET name = ?, url = ?, template = ?, alert_types = ?, telegram_chat_id = ?,
+                   secret = COALESCE(?, secret),
+                   repeat_interval_secs = COALESCE(?, repeat_interval_secs),
+                   enabled = ?, updated_at = ?
+               WHERE id = ?"#,
+        )
+        .bind(&input.name)
+        .bind(&input.url)
+        .bind(&input.template)
+        .bind(serde_json::to_string(&input.alert_types).unwrap_or_else(|_| "[]".to_string()))
+        .bind(&input.telegram_chat_id)
+        .bind(&input.secret)
+        .bind(input.repeat_interval_secs)
+        .bind(i64::from(input.enabled))
+        .bind(self.backend_time.now_ts())
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+        if result.rows_affected() == 0 {
+            return Ok(None);
+        }
+        self.get_alert_webhook_sink(id).await
+    }
+
+    /// Deletes the sink together with its queued and historical deliveries.
+    pub(crate) async fn delete_alert_webhook_sink(&self, id: &str) -> Result<bool, ProxyError> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("DELETE FROM alert_webhook_deliveries WHERE sink_id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+        let deleted = sqlx::query("DELETE FROM alert_webhook_sinks WHERE id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+        tx.commit().await?;
+        Ok(deleted > 0)
+    }
+
+    /// Queues a delivery of each freshly projected alert for every enabled sink subscribed to
+    /// its type. Runs inside the projection transaction, so an alert is queued exactly when it
+    /// becomes visible in the admin alert center.
+    async fn enqueue_alert_webhook_deliveries_on(
+        conn: &mut sqlx::SqliteConnection,
+        rows: &[AlertEventProjectionRow],
+        now: i64,
+    ) -> Result<(), ProxyError> {
+        let oldest = now.saturating_sub(ALERT_WEBHOOK_EVENT_MAX_AGE_SECS);
+        if rows.iter().all(|row| row.occurred_at < oldest) {
+            return Ok(());
+        }
+        let sinks = sqlx::query(&format!(
+            "SELECT {ALERT_WEBHOOK_SINK_COLUMNS} FROM alert_webhook_sinks WHERE enabled = 1"
+        ))
+        .try_map(alert_webhook_sink_from_row)
+        .fetch_all(&mut *conn)
+        .await?;
+        if sinks.is_empty() {
+            return Ok(());
+        }
+        for row in rows.iter().filter(|row| row.occurred_at >= oldest) {
+            let Some(event) = Self::build_alert_event_from_projection(row.clone()) else {
+                continue;
+            };
+            let dedupe_key = alert_webhook_dedupe_key(&event);
+            for sink in &sinks {
+                if event.occurred_at < sink.created_at || !sink.subscribes_to(&event.alert_type) {
+                    continue;
+                }
+                if sink.repeat_interval_secs > 0 {
+                    let repeated = sqlx::query_scalar::<_, i64>(
+                        r#"SELECT EXISTS(
+                               SELECT 1 FROM alert_webhook_deliveries
+                               WHERE sink_id = ? AND dedupe_key = ?
+                                 AND event_occurred_at > ? AND event_occurred_at <= ?
+                                 AND event_id <> ?
+                           )"#,
+                    )
+                    .bind(&sink.id)
+                    .bind(&dedupe_key)
+                    .bind(event.occurred_at - sink.repeat_interval_secs)
+                    .bind(event.occurred_at)
+                    .bind(&event.id)
+                    .fetch_one(&mut *conn)
+                    .await?
+                        != 0;
+                    if repeated {
+                        continue;
+                    }
+                }
+                let payload = render_alert_webhook_payload(sink, &event);
+                sqlx::query(
+                    r#"INSERT OR IGNORE INTO alert_webhook_deliveries
+                           (sink_id, event_id, alert_type, dedupe_key, event_occurred_at,
+                            payload_json, status, attempts, next_attempt_at, created_at, updated_at)
+                       VALUES (?, ?, ?, ?, ?, ?, ?, 0, ?, ?, ?)"#,
+                )
+                .bind(&sink.id)
+                .bind(&event.id)
+                .bind(&event.alert_type)
+                .bind(&dedupe_key)
+                .bind(event.occurred_at)
+                .bind(payload.to_string())
+                .bind(ALERT_WEBHOOK_DELIVERY_PENDING)
+                .bind(now)
+                .bind(now)
+                .bind(now)
+                .execute(&mut *conn)
+                .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Queues the admin test-fire event for `sink`, due immediately.
+    pub(crate) async fn insert_alert_webhook_test_delivery(
+        &self,
+        sink: &AlertWebhookSink,
+    ) -> Result<AlertWebhookDelivery, ProxyError> {
+        let now = self.backend_time.now_ts();
+        let event = alert_webhook_test_event(sink, now);
+        let payload = render_alert_webhook_payload(sink, &event);
+        let row = sqlx::query(&format!(
+            r#"INSERT INTO alert_webhook_deliveries
+                   (sink_id, event_id, alert_type, dedupe_key, event_occurred_at, payload_json,
+                    status, attempts, next_attempt_at, created_at, updated_at)
+               VALUES (?, ?, ?, ?, ?, ?, ?, 0, ?, ?, ?)
+               ON CONFLICT(sink_id, event_id) DO UPDATE SET updated_at = excluded.updated_at
+               RETURNING {ALERT_WEBHOOK_DELIVERY_COLUMNS}"#
+        ))
+        .bind(&sink.id)
+        .bind(&event.id)
+        .bind(&event.alert_type)
+        .bind(alert_webhook_dedupe_key(&event))
+        .bind(event.occurred_at)
+        .bind(payload.to_string())
+        .bind(ALERT_WEBHOOK_DELIVERY_PENDING)
+        .bind(now)
+        .bind(now)
+        .bind(now)
+        .try_map(alert_webhook_delivery_from_row)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(row)
+    }
+
+    /// Pending deliveries whose next attempt is due, oldest first, with their sinks.
+    pub(crate) async fn due_alert_webhook_deliveries(
+        &self,
+        now: i64,
+        limit: i64,
+    ) -> Result<Vec<(AlertWebhookDelivery, AlertWebhookSink)>, ProxyError> {
+        let deliveries = sqlx::query(&format!(
+            r#"SELECT {ALERT_WEBHOOK_DELIVERY_COLUMNS} FROM alert_webhook_deliveries
+               WHERE status = ? AND next_attempt_at <= ?
+               ORDER BY next_attempt_at, id
+               LIMIT ?"#
+        ))
+        .bind(ALERT_WEBHOOK_DELIVERY_PENDING)
+        .bind(now)
+        .bind(limit.max(1))
+        .try_map(alert_webhook_delivery_from_row)
+        .fetch_all(&self.pool)
+        .await?;
+        let mut due = Vec::with_capacity(deliveries.len());
+        for delivery in deliveries {
+            match self.get_alert_webhook_sink(&delivery.sink_id).await? {
+                Some(sink) => due.push((delivery, sink)),
+                None => {
+                    sqlx::query("DELETE FROM alert_webhook_deliveries WHERE id = ?")
+                        .bind(delivery.id)
+                        .execute(&self.pool)
+                        .await?;
+                }
+            }
+        }
+        Ok(due)
+    }
+
+    /// Records one attempt and either completes the delivery or schedules the next try.
+    pub(crate) async fn record_alert_webhook_attempt(
+        &self,
+        delivery: &AlertWebhookDelivery,
+        outcome: &AlertWebhookAttemptOutcome,
+        max_attempts: i64,
+    ) -> Result<AlertWebhookDelivery, ProxyError> {
+        let now = self.backend_time.now_ts();
+        let attempts = delivery.attempts + 1;
+        let (status, next_attempt_at, delivered_at) = if outcome.delivered() {
+            (ALERT_WEBHOOK_DELIVERY_DELIVERED, None, Some(now))
+        } else if outcome.retryable() && attempts < max_attempts {
+            (
+                ALERT_WEBHOOK_DELIVERY_PENDING,
+                Some(now + alert_webhook_retry_delay_secs(attempts)),
+                None,
+            )
+        } else {
+            (ALERT_WEBHOOK_DELIVERY_FAILED, None, None)
+        };
+        let last_error = outcome
+            .error
+            .as_deref()
+            .map(|error| error.chars().take(ALERT_WEBHOOK_LAST_ERROR_MAX_CHARS).collect::<String>());
+        let row = sqlx::query(&format!(
+            r#"UPDATE alert_webhook_deliveries
+               SET status = ?, attempts = ?, next_attempt_at = ?, last_status_code = ?,
+                   last_error = ?, updated_at = ?, delivered_at = ?
+               WHERE id = ?
+               RETURNING {ALERT_WEBHOOK_DELIVERY_COLUMNS}"#
+        ))
+        .bind(status)
+        .bind(attempts)
+        .bind(next_attempt_at)
+        .bind(outcome.status_code.map(i64::from))
+        .bind(last_error)
+        .bind(now)
+        .bind(delivered_at)
+        .bind(delivery.id)
+        .try_map(alert_webhook_delivery_from_row)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(row)
+    }
+
+    /// Latest deliveries, newest first, optionally for one sink.
+    pub(crate) async fn list_alert_webhook_deliveries(
+        &self,
+        sink_id: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<AlertWebhookDelivery>, ProxyError> {
+        let rows = sqlx::query(&format!(
+            r#"SELECT {ALERT_WEBHOOK_DELIVERY_COLUMNS} FROM alert_webhook_deliveries
+               WHERE (? IS NULL OR sink_id = ?)
+               ORDER BY id DESC
+               LIMIT ?"#
+        ))
+        .bind(sink_id)
+        .bind(sink_id)
+        .bind(limit.clamp(1, 200))
+        .try_map(alert_webhook_delivery_from_row)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    pub(crate) async fn prune_alert_webhook_deliveries(&self, now: i64) -> Result<u64, ProxyError> {
+        let result = sqlx::query(
+            "DELETE FROM alert_webhook_deliveries WHERE status <> ? AND created_at < ?",
+        )
+        .bind(ALERT_WEBHOOK_DELIVERY_PENDING)
+        .bind(now.saturating_sub(ALERT_WEBHOOK_DELIVERY_RETENTION_SECS))
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
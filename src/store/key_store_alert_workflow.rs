@@ -0,0 +1,395 @@
+const ALERT_WORKFLOW_ID_ALPHABET: &[u8] = b"23456789abcdefghjkmnpqrstuvwxyz";
+const ALERT_GROUP_WORKFLOW_HISTORY_LIMIT: i64 = 50;
+
+const ALERT_SILENCE_COLUMNS: &str = "id, alert_type, subject_kind, subject_id, starts_at, ends_at, \
+     actor, note, created_at";
+
+fn alert_silence_from_row(row: sqlx::sqlite::SqliteRow) -> Result<AlertSilence, sqlx::Error> {
+    Ok(AlertSilence {
+        id: row.try_get("id")?,
+        alert_type: row.try_get("alert_type")?,
+        subject_kind: row.try_get("subject_kind")?,
+        subject_id: row.try_get("subject_id")?,
+        starts_at: row.try_get("starts_at")?,
+        ends_at: row.try_get("ends_at")?,
+        actor: row.try_get("actor")?,
+        note: row.try_get("note")?,
+        created_at: row.try_get("created_at")?,
+    })
+}
+
+impl KeyStore {
+    pub(crate) async fn ensure_alert_workflow_schema(&self) -> Result<(), ProxyError> {
+        // Workflow state is keyed by the alert group id, which is derived from the alert type
+        // and subject, so it survives projection rebuilds. All three tables replicate over HA.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS alert_group_workflows (
+                group_id TEXT PRIMARY KEY,
+                status TEXT NOT NULL,
+                actor TEXT NOT NULL,
+                note TEXT,
+                updated_at INTEGER NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS alert_group_workflow_events (
+                id TEXT PRIMARY KEY,
+                group_id TEXT NOT NULL,
+                action TEXT NOT NULL,
+                actor TEXT NOT NULL,
+                note TEXT,
+                created_at INTEGER NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+        sqlx::query(
+            r#"CREATE INDEX IF NOT EXISTS idx_alert_group_workflow_events_group
+               ON alert_group_workflow_events(group_id, created_at DESC)"#,
+        )
+        .execute(&self.pool)
+        .await?;
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS alert_silences (
+                id TEXT PRIMARY KEY,
+                alert_type TEXT,
+                subject_kind TEXT,
+                subject_id TEXT,
+                starts_at INTEGER NOT NULL,
+                ends_at INTEGER NOT NULL,
+                actor TEXT NOT NULL,
+                note TEXT,
+                created_at INTEGER NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+        sqlx::query(
+            r#"CREATE INDEX IF NOT EXISTS idx_alert_silences_window
+               ON alert_silences(ends_at, starts_at)"#,
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Projected events remember the silence that covered them when they were projected.
+    pub(crate) async fn ensure_alert_projection_silence_column(&self) -> Result<(), ProxyError> {
+        let exists = sqlx::query_scalar::<_, i64>(
+            "SELECT 1 FROM observability.pragma_table_info('dashboard_alert_projection_events') \
+             WHERE name = 'silence_id' LIMIT 1",
+        )
+        .fetch_optional(&self.pool)
+        .await?
+        .is_some();
+        if !exists {
+            sqlx::query(
+                "ALTER TABLE observability.dashboard_alert_projection_events ADD COLUMN silence_id TEXT",
+            )
+            .execute(&self.pool)
+            .await?;
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn apply_alert_group_action(
+        &self,
+        group_id: &str,
+        action: &str,
+        actor: &str,
+        note: Option<String>,
+    ) -> Result<AlertGroupWorkflow, ProxyError> {
+        let group_id = group_id.trim();
+        if group_id.is_empty() {
+            return Err(ProxyError::Other("alert group id is required".to_string()));
+        }
+        let status = alert_group_status_for_action(action)
+            .ok_or_else(|| ProxyError::Other(format!("unsupported alert group action: {action}")))?;
+        let note = normalize_alert_workflow_note(note).map_err(ProxyError::Other)?;
+        let now = self.backend_time.now_ts();
+        let mut tx = self.pool.begin().await?;
+        sqlx::query(
+            r#"INSERT INTO alert_group_workflows (group_id, status, actor, note, updated_at)
+               VALUES (?, ?, ?, ?, ?)
+               ON CONFLICT(group_id) DO UPDATE SET
+                   status = excluded.status,
+                   actor = excluded.actor,
+                   note = excluded.note,
+                   updated_at = excluded.updated_at"#,
+        )
+        .bind(group_id)
+        .bind(status)
+        .bind(actor)
+        .bind(&note)
+        .bind(now)
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query(
+            r#"INSERT INTO alert_group_workflow_events (id, group_id, action, actor, note, created_at)
+               VALUES (?, ?, ?, ?, ?, ?)"#,
+        )
+        .bind(random_string(ALERT_WORKFLOW_ID_ALPHABET, 12))
+        .bind(group_id)
+        .bind(action)
+        .bind(actor)
+        .bind(&note)
+        .bind(now)
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(AlertGroupWorkflow::effective(
+            status,
+            Some(actor.to_string()),
+            note,
+            now,
+            now,
+        ))
+    }
+
+    pub(crate) async fn alert_group_workflow_history(
+        &self,
+        group_id: &str,
+    ) -> Result<Vec<AlertGroupWorkflowEvent>, ProxyError> {
+        let rows = sqlx::query_as::<_, (String, String, String, String, Option<String>, i64)>(
+            r#"SELECT id, group_id, action, actor, note, created_at
+                 FROM alert_group_workflow_events
+                WHERE group_id = ?
+                ORDER BY created_at DESC, id DESC
+                LIMIT ?"#,
+        )
+        .bind(group_id)
+        .bind(ALERT_GROUP_WORKFLOW_HISTORY_LIMIT)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(
+                |(id, group_id, action, actor, note, created_at)| AlertGroupWorkflowEvent {
+                    id,
+                    group_id,
+                    action,
+                    actor,
+                    note,
+                    created_at,
+                },
+            )
+            .collect())
+    }
+
+    /// Fills the workflow of each group that has one. Groups without stored state stay open.
+    pub(crate) async fn attach_alert_group_workflows(
+        &self,
+        groups: &mut [AlertGroupRecord],
+    ) -> Result<(), ProxyError> {
+        if groups.is_empty() {
+            return Ok(());
+        }
+        let mut query = QueryBuilder::<Sqlite>::new(
+            "SELECT group_id, status, actor, note, updated_at FROM alert_group_workflows \
+             WHERE group_id IN (",
+        );
+        {
+            let mut separated = query.separated(", ");
+            for group in groups.iter() {
+                separated.push_bind(group.id.clone());
+            }
+        }
+        query.push(")");
+        let rows = query.build().fetch_all(&self.pool).await?;
+        let mut by_group = HashMap::with_capacity(rows.len());
+        for row in rows {
+            let group_id: String = row.try_get("group_id")?;
+            by_group.insert(
+                group_id,
+                (
+                    row.try_get::<String, _>("status")?,
+                    row.try_get::<String, _>("actor")?,
+                    row.try_get::<Option<String>, _>("note")?,
+                    row.try_get::<i64, _>("updated_at")?,
+                ),
+            );
+        }
+        for group in groups.iter_mut() {
+            if let Some((status, actor, note, updated_at)) = by_group.remove(&group.id) {
+                group.workflow = Some(AlertGroupWorkflow::effective(
+                    &status,
+                    Some(actor),
+                    note,
+                    updated_at,
+                    group.last_seen,
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Lists silences that have not ended yet, or every silence when `include_expired` is set.
+    pub(crate) async fn list_alert_silences(
+        &self,
+        include_expired: bool,
+    ) -> Result<Vec<AlertSilence>, ProxyError> {
+        let now = self.backend_time.now_ts();
+        let rows = sqlx::query(&format!(
+            "SELECT {ALERT_SILENCE_COLUMNS} FROM alert_silences \
+             WHERE ? OR ends_at > ? ORDER BY ends_at DESC, id LIMIT 200"
+        ))
+        .bind(include_expired)
+        .bind(now)
+        .try_map(alert_silence_from_row)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    pub(crate) async fn create_alert_silence(
+        &self,
+        input: AlertSilenceMutation,
+        actor: &str,
+    ) -> Result<AlertSilence, ProxyError> {
+        let now = self.backend_time.now_ts();
+        let input = input.normalized(now).map_err(ProxyError::Other)?;
+        let silence = AlertSilence {
+            id: random_string(ALERT_WORKFLOW_ID_ALPHABET, 10),
+            alert_type: input.alert_type,
+            subject_kind: input.subject_kind,
+            subject_id: input.subject_id,
+            starts_at: input.starts_at.unwrap_or(now),
+            ends_at: input.ends_at,
+            actor: actor.to_string(),
+            note: input.note,
+            created_at: now,
+        };
+        sqlx::query(
+            r#"INSERT INTO alert_silences
+                   (id, alert_type, subject_kind, subject_id, starts_at, ends_at, actor, note, created_at)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+        )
+        .bind(&silence.id)
+        .bind(&silence.alert_type)
+        .bind(&silence.subject_kind)
+        .bind(&silence.subject_id)
+        .bind(silence.starts_at)
+        .bind(silence.ends_at)
+        .bind(&silence.actor)
+        .bind(&silence.note)
+        .bind(silence.created_at)
+        .execute(&self.pool)
+        .await?;
+        self.apply_alert_silence_to_projection(&silence).await?;
+        Ok(silence)
+    }
+
+    /// Ends a silence now. Events it already covered stay silenced.
+    pub(crate) async fn expire_alert_silence(
+        &self,
+        id: &str,
+    ) -> Result<Option<AlertSilence>, ProxyError> {
+        let now = self.backend_time.now_ts();
+        sqlx::query(
+            "UPDATE alert_silences SET ends_at = MAX(starts_at, MIN(ends_at, ?)) WHERE id = ?",
+        )
+        .bind(now)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+        let silence = sqlx::query(&format!(
+            "SELECT {ALERT_SILENCE_COLUMNS} FROM alert_silences WHERE id = ?"
+        ))
+        .bind(id)
+        .try_map(alert_silence_from_row)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(silence)
+    }
+
+    async fn alert_silences_covering_on(
+        conn: &mut sqlx::SqliteConnection,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<AlertSilence>, ProxyError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {ALERT_SILENCE_COLUMNS} FROM alert_silences \
+             WHERE ends_at > ? AND starts_at <= ? ORDER BY created_at, id"
+        ))
+        .bind(from)
+        .bind(to)
+        .try_map(alert_silence_from_row)
+        .fetch_all(&mut *conn)
+        .await?;
+        Ok(rows)
+    }
+
+    /// Resolves the silence covering each projected row, in row order.
+    async fn alert_projection_silence_ids_on(
+        conn: &mut sqlx::SqliteConnection,
+        rows: &[AlertEventProjectionRow],
+    ) -> Result<Vec<Option<String>>, ProxyError> {
+        let (Some(from), Some(to)) = (
+            rows.iter().map(|row| row.occurred_at).min(),
+            rows.iter().map(|row| row.occurred_at).max(),
+        ) else {
+            return Ok(Vec::new());
+        };
+        let silences = Self::alert_silences_covering_on(conn, from, to).await?;
+        Ok(rows
+            .iter()
+            .map(|row| {
+                if silences.is_empty() {
+                    return None;
+                }
+                let event = Self::build_alert_event_from_projection(row.clone())?;
+                silences
+                    .iter()
+                    .find(|silence| silence.matches(&event))
+                    .map(|silence| silence.id.clone())
+            })
+            .collect())
+    }
+
+    /// Marks already projected events inside the silence window, so a silence created for an
+    /// ongoing incident also covers the events that arrived before the projection saw it.
+    async fn apply_alert_silence_to_projection(
+        &self,
+        silence: &AlertSilence,
+    ) -> Result<(), ProxyError> {
+        let rows = sqlx::query_as::<_, (String, String, String)>(
+            r#"SELECT source_kind, source_id, payload_json
+                 FROM observability.dashboard_alert_projection_events
+                WHERE occurred_at >= ? AND occurred_at < ? AND silence_id IS NULL"#,
+        )
+        .bind(silence.starts_at)
+        .bind(silence.ends_at)
+        .fetch_all(&self.pool)
+        .await?;
+        for (source_kind, source_id, payload_json) in rows {
+            let Ok(row) = serde_json::from_str::<AlertEventProjectionRow>(&payload_json) else {
+                continue;
+            };
+            let Some(event) = Self::build_alert_event_from_projection(row) else {
+                continue;
+            };
+            if !silence.matches(&event) {
+                continue;
+            }
+            sqlx::query(
+                r#"UPDATE observability.dashboard_alert_projection_events
+                      SET silence_id = ?
+                    WHERE source_kind = ? AND source_id = ?"#,
+            )
+            .bind(&silence.id)
+            .bind(&source_kind)
+            .bind(&source_id)
+            .execute(&self.pool)
+            .await?;
+        }
+        Ok(())
+    }
+}
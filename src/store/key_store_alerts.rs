@@ -137,6 +137,7 @@ fn build_compat_group_record(events: &[AlertEventRecord]) -> Option<AlertGroupRe
         event_count: events.len() as i64,
         children: Vec::new(),
         child_events: Vec::new(),
+        workflow: None,
     })
 }
 
@@ -186,6 +187,7 @@ fn build_child_group_record(id: String, events: Vec<AlertEventRecord>) -> Option
         event_count: events.len() as i64,
         children: Vec::new(),
         child_events: events,
+        workflow: None,
     })
 }
 
@@ -405,6 +407,7 @@ fn build_semantic_mother_groups(children: Vec<AlertGroupRecord>) -> Vec<AlertGro
                 event_count,
                 children: chain,
                 child_events: Vec::new(),
+                workflow: None,
             })
         })
         .collect()
@@ -611,6 +614,7 @@ fn alert_subject_tuple(
             .or_else(key_subject)
             .or_else(user_subject)
             .or_else(token_subject),
+        ALERT_TYPE_TOKEN_EXPIRING => token_subject().or_else(user_subject),
         _ => user_subject()
             .or_else(token_subject)
             .or_else(key_subject)
@@ -699,6 +703,17 @@ fn build_alert_title_and_summary(
             format!("{subject_label} failed"),
             format!("{job_type} finished with status {job_status} on attempt {job_attempt}.{job_message_suffix}"),
         ),
+        ALERT_TYPE_TOKEN_EXPIRING => (
+            format!("Token {token_label} is about to expire"),
+            format!(
+                "Access token {token_label} {}.",
+                reason_summary.unwrap_or("expires soon")
+            ),
+        ),
+        ALERT_TYPE_THRESHOLD_RULE => (
+            format!("Rule {subject_label} breached its threshold"),
+            format!("{}.", reason_summary.unwrap_or("threshold crossed")),
+        ),
         ALERT_TYPE_USER_REQUEST_RATE_LIMITED => (
             format!("{subject_label} hit the local request-rate limit"),
             format!(
@@ -1785,13 +1800,23 @@ impl KeyStore {
             path: path.clone().unwrap_or_else(|| "/unknown".to_string()),
             query: query.clone(),
         });
-        let (subject_kind, subject_id, subject_label) = alert_subject_tuple(
-            resolved_alert_type.as_str(),
-            user.as_ref(),
-            token.as_ref(),
-            key.as_ref(),
-            job.as_ref(),
-        );
+        let rule_subject = reason_code
+            .as_deref()
+            .filter(|_| resolved_alert_type == ALERT_TYPE_THRESHOLD_RULE);
+        let (subject_kind, subject_id, subject_label) = match rule_subject {
+            Some(rule_id) => (
+                ALERT_SUBJECT_RULE.to_string(),
+                rule_id.to_string(),
+                reason_detail.clone().unwrap_or_else(|| rule_id.to_string()),
+            ),
+            None => alert_subject_tuple(
+                resolved_alert_type.as_str(),
+                user.as_ref(),
+                token.as_ref(),
+                key.as_ref(),
+                job.as_ref(),
+            ),
+        };
         let (title, summary) = build_alert_title_and_summary(
             resolved_alert_type.as_str(),
             AlertTitleSummaryContext {
@@ -1857,6 +1882,7 @@ impl KeyStore {
             token_id,
             key_id,
             request_kinds,
+            exclude_silenced: false,
         };
         if self.alert_projection_is_complete().await? {
             return self
@@ -1889,6 +1915,7 @@ impl KeyStore {
             token_id,
             key_id,
             request_kinds,
+            exclude_silenced: false,
         };
         let source = if self.alert_projection_is_complete().await? {
             AlertReadSource::Projected
@@ -1924,6 +1951,7 @@ impl KeyStore {
             token_id: None,
             key_id: None,
             request_kinds: &[],
+            exclude_silenced: false,
         };
         let source = if self.alert_projection_is_complete().await? {
             AlertReadSource::Projected
@@ -2083,6 +2111,7 @@ impl KeyStore {
             token_id: None,
             key_id: None,
             request_kinds: &[],
+            exclude_silenced: false,
         };
         let mut total_query = QueryBuilder::new("");
         Self::push_alert_events_cte(&mut total_query, filters);
@@ -2104,6 +2133,7 @@ impl KeyStore {
                 token_id: None,
                 key_id: None,
                 request_kinds: &[],
+                exclude_silenced: false,
             };
             let mut grouped_count_query = QueryBuilder::new("");
             Self::push_alert_groups_cte(&mut grouped_count_query, grouped_filters);
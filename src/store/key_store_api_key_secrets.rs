@@ -0,0 +1,247 @@
+/// Column expression yielding what is stored for a key's secret: the sealed envelope once
+/// encrypted, the plaintext otherwise. Feed the value through `open_api_key_secret`.
+const API_KEY_SECRET_COLUMN: &str = "COALESCE(api_key_ciphertext, api_key)";
+/// `(id, secret)` columns read by key selection before building an `ApiKeyLease`.
+const API_KEY_LEASE_COLUMNS: &str = "id, COALESCE(api_key_ciphertext, api_key)";
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiKeySecretRotationReport {
+    pub rotated: i64,
+    pub elapsed_ms: u128,
+}
+
+impl KeyStore {
+    pub(crate) async fn ensure_api_key_secret_ciphertext_column(&self) -> Result<(), ProxyError> {
+        if !self.api_keys_column_exists("api_key_ciphertext").await? {
+            sqlx::query("ALTER TABLE api_keys ADD COLUMN api_key_ciphertext TEXT")
+                .execute(&self.pool)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Install the master-key cipher for upstream API key secrets and encrypt any rows still
+    /// stored in plaintext. Without a cipher the store keeps plaintext secrets, but refuses to
+    /// start over a database that already holds encrypted ones.
+    pub(crate) async fn configure_api_key_secret_cipher(
+        &self,
+        cipher: Option<ApiKeySecretCipher>,
+    ) -> Result<(), ProxyError> {
+        let sample = sqlx::query_scalar::<_, String>(
+            "SELECT api_key_ciphertext FROM api_keys WHERE api_key_ciphertext IS NOT NULL LIMIT 1",
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+        let Some(cipher) = cipher else {
+            if sample.is_some() {
+                return Err(ProxyError::Other(format!(
+                    "api key secrets are encrypted at rest; {API_KEY_SECRET_MASTER_KEY_ENV} is required"
+                )));
+            }
+            return Ok(());
+        };
+        if let Some(sealed) = sample {
+            cipher.open(&sealed)?;
+        }
+        self.api_key_secret_cipher.set(cipher).map_err(|_| {
+            ProxyError::Other("api key secret cipher is already configured".to_string())
+        })?;
+        self.encrypt_plaintext_api_key_secrets().await
+    }
+
+    async fn encrypt_plaintext_api_key_secrets(&self) -> Result<(), ProxyError> {
+        let Some(cipher) = self.api_key_secret_cipher.get() else {
+            return Ok(());
+        };
+        let mut tx = self.pool.begin().await?;
+        let rows = sqlx::query_as::<_, (String, String)>(
+            "SELECT id, api_key FROM api_keys WHERE api_key_ciphertext IS NULL",
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+        if rows.is_empty() {
+            return Ok(());
+        }
+        let mut sealed_by_secret = HashMap::with_capacity(rows.len());
+        for (id, secret) in rows {
+            let fingerprint = api_key_secret_fingerprint(&secret);
+            let sealed = cipher.seal(&secret)?;
+            sqlx::query("UPDATE api_keys SET api_key = ?, api_key_ciphertext = ? WHERE id = ?")
+                .bind(&fingerprint)
+                .bind(&sealed)
+                .bind(&id)
+                .execute(&mut *tx)
+                .await?;
+            sealed_by_secret.insert(secret, (fingerprint, sealed));
+        }
+
+        // Control events recorded before encryption still carry the plaintext rows; rewrite
+        // them so peers and baseline consumers only ever see ciphertext.
+        let events = sqlx::query_as::<_, (i64, String, Option<String>)>(
+            "SELECT seq, payload_json, checksum FROM ha_outbox WHERE resource = 'api_keys'",
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+        for (seq, payload_json, checksum) in events {
+            let Ok(mut payload) = serde_json::from_str::<Value>(&payload_json) else {
+                continue;
+            };
+            let Some(object) = payload.as_object_mut() else {
+                continue;
+            };
+            let Some((fingerprint, sealed)) = object
+                .get("api_key")
+                .and_then(Value::as_str)
+                .and_then(|secret| sealed_by_secret.get(secret))
+            else {
+                continue;
+            };
+            object.insert("api_key".to_string(), Value::String(fingerprint.clone()));
+            object.insert(
+                "api_key_ciphertext".to_string(),
+                Value::String(sealed.clone()),
+            );
+            let payload_json = payload.to_string();
+            let checksum = checksum.map(|_| sha256_hex_bytes(payload_json.as_bytes()));
+            sqlx::query("UPDATE ha_outbox SET payload_json = ?, checksum = ? WHERE seq = ?")
+                .bind(&payload_json)
+                .bind(checksum)
+                .bind(seq)
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await?;
+        tracing::info!(
+            component = "api_key_secrets",
+            event = "plaintext_secrets_encrypted",
+            key_count = sealed_by_secret.len() as u64,
+            "encrypted plaintext api key secrets at rest"
+        );
+        Ok(())
+    }
+
+    /// Value stored in `api_keys.api_key` for `secret`: its fingerprint once secrets are
+    /// encrypted, the secret itself otherwise.
+    pub(crate) fn api_key_lookup_value(&self, secret: &str) -> String {
+        match self.api_key_secret_cipher.get() {
+            Some(_) => api_key_secret_fingerprint(secret),
+            None => secret.to_string(),
+        }
+    }
+
+    /// `api_key_ciphertext` for a newly stored secret, when secrets are encrypted.
+    pub(crate) fn seal_api_key_secret(&self, secret: &str) -> Result<Option<String>, ProxyError> {
+        self.api_key_secret_cipher
+            .get()
+            .map(|cipher| cipher.seal(secret))
+            .transpose()
+    }
+
+    /// Recover the plaintext secret from a value read through `API_KEY_SECRET_COLUMN`.
+    pub(crate) fn open_api_key_secret(&self, stored: String) -> Result<String, ProxyError> {
+        if !is_sealed_api_key_secret(&stored) {
+            return Ok(stored);
+        }
+        match self.api_key_secret_cipher.get() {
+            Some(cipher) => cipher.open(&stored),
+            None => Err(ProxyError::Other(format!(
+                "api key secret is encrypted at rest; {API_KEY_SECRET_MASTER_KEY_ENV} is required"
+            ))),
+        }
+    }
+
+    /// Lease a key selected through `API_KEY_LEASE_COLUMNS` and mark it as just used.
+    pub(crate) async fn lease_selected_api_key(
+        &self,
+        id: String,
+        stored_secret: String,
+        now: i64,
+    ) -> Result<ApiKeyLease, ProxyError> {
+        let secret = self.open_api_key_secret(stored_secret)?;
+        self.touch_key(&secret, now).await?;
+        Ok(ApiKeyLease { id, secret })
+    }
+
+    /// Re-wrap every sealed secret under `next` in one transaction, including the copies carried
+    /// by HA control events. The secret ciphertext is left as is, so lookups stay valid.
+    pub(crate) async fn rotate_api_key_secret_master_key(
+        &self,
+        current: &ApiKeySecretCipher,
+        next: &ApiKeySecretCipher,
+    ) -> Result<i64, ProxyError> {
+        let mut tx = self.pool.begin().await?;
+        // Peers replay the outbox with the new master key, so its copies are rewrapped too. This
+        // runs before the row updates below, whose HA triggers append events already sealed under
+        // `next`.
+        let events = sqlx::query_as::<_, (i64, String, Option<String>)>(
+            "SELECT seq, payload_json, checksum FROM ha_outbox WHERE resource = 'api_keys'",
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+        for (seq, payload_json, checksum) in events {
+            let Ok(mut payload) = serde_json::from_str::<Value>(&payload_json) else {
+                continue;
+            };
+            let Some(object) = payload.as_object_mut() else {
+                continue;
+            };
+            let Some(sealed) = object
+                .get("api_key_ciphertext")
+                .and_then(Value::as_str)
+                .filter(|sealed| is_sealed_api_key_secret(sealed))
+            else {
+                continue;
+            };
+            let rewrapped = current.rewrap(sealed, next)?;
+            object.insert("api_key_ciphertext".to_string(), Value::String(rewrapped));
+            let payload_json = payload.to_string();
+            let checksum = checksum.map(|_| sha256_hex_bytes(payload_json.as_bytes()));
+            sqlx::query("UPDATE ha_outbox SET payload_json = ?, checksum = ? WHERE seq = ?")
+                .bind(&payload_json)
+                .bind(checksum)
+                .bind(seq)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        let rows = sqlx::query_as::<_, (String, String)>(
+            "SELECT id, api_key_ciphertext FROM api_keys WHERE api_key_ciphertext IS NOT NULL",
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+        let mut rotated = 0_i64;
+        for (id, sealed) in rows {
+            let rewrapped = current.rewrap(&sealed, next)?;
+            sqlx::query("UPDATE api_keys SET api_key_ciphertext = ? WHERE id = ?")
+                .bind(rewrapped)
+                .bind(id)
+                .execute(&mut *tx)
+                .await?;
+            rotated += 1;
+        }
+        tx.commit().await?;
+        Ok(rotated)
+    }
+}
+
+/// Re-wrap all encrypted API key secrets in `database_path` from `current_master_key` to
+/// `next_master_key`.
+pub async fn rotate_api_key_secret_master_key_once(
+    database_path: &str,
+    current_master_key: [u8; 32],
+    next_master_key: [u8; 32],
+) -> Result<ApiKeySecretRotationReport, ProxyError> {
+    let started = std::time::Instant::now();
+    let store = KeyStore::new_with_time(database_path, BackendTime::system()).await?;
+    let rotated = store
+        .rotate_api_key_secret_master_key(
+            &ApiKeySecretCipher::new(current_master_key),
+            &ApiKeySecretCipher::new(next_master_key),
+        )
+        .await?;
+    Ok(ApiKeySecretRotationReport {
+        rotated,
+        elapsed_ms: started.elapsed().as_millis(),
+    })
+}
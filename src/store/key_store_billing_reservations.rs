@@ -0,0 +1,104 @@
+impl KeyStore {
+    pub(crate) async fn ensure_billing_reservations_schema(&self) -> Result<(), ProxyError> {
+        // Credits held for in-flight requests that bill after the subject lock is released.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS billing_reservations (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                billing_subject TEXT NOT NULL,
+                token_id TEXT NOT NULL,
+                credits INTEGER NOT NULL,
+                created_at INTEGER NOT NULL,
+                expires_at INTEGER NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_billing_reservations_subject ON billing_reservations(billing_subject, expires_at)",
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Holds `credits` against `billing_subject` until the reservation is deleted or expires.
+    pub(crate) async fn insert_billing_reservation(
+        &self,
+        billing_subject: &str,
+        token_id: &str,
+        credits: i64,
+    ) -> Result<i64, ProxyError> {
+        let now = self.backend_time.now_ts();
+        sqlx::query("DELETE FROM billing_reservations WHERE expires_at <= ?")
+            .bind(now)
+            .execute(&self.pool)
+            .await?;
+        let id = sqlx::query_scalar::<_, i64>(
+            r#"
+            INSERT INTO billing_reservations
+                (billing_subject, token_id, credits, created_at, expires_at)
+            VALUES (?, ?, ?, ?, ?)
+            RETURNING id
+            "#,
+        )
+        .bind(billing_subject)
+        .bind(token_id)
+        .bind(credits)
+        .bind(now)
+        .bind(now + BILLING_RESERVATION_TTL_SECS)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(id)
+    }
+
+    pub(crate) async fn delete_billing_reservation(&self, id: i64) -> Result<(), ProxyError> {
+        sqlx::query("DELETE FROM billing_reservations WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    pub(crate) async fn sum_billing_reservations_for_subject(
+        &self,
+        billing_subject: &str,
+        now: i64,
+    ) -> Result<i64, ProxyError> {
+        let total = sqlx::query_scalar::<_, Option<i64>>(
+            "SELECT SUM(credits) FROM billing_reservations WHERE billing_subject = ? AND expires_at > ?",
+        )
+        .bind(billing_subject)
+        .bind(now)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(total.unwrap_or(0))
+    }
+
+    /// Reserved credits of every member account and team token of `team_id`.
+    pub(crate) async fn sum_billing_reservations_for_team(
+        &self,
+        team_id: &str,
+        now: i64,
+    ) -> Result<i64, ProxyError> {
+        let total = sqlx::query_scalar::<_, Option<i64>>(
+            r#"
+            SELECT SUM(br.credits) FROM billing_reservations br
+            WHERE br.expires_at > ?
+              AND (
+                (br.billing_subject LIKE 'account:%' AND SUBSTR(br.billing_subject, 9) IN
+                    (SELECT user_id FROM team_members WHERE team_id = ?))
+                OR (br.billing_subject LIKE 'token:%' AND SUBSTR(br.billing_subject, 7) IN
+                    (SELECT token_id FROM team_tokens WHERE team_id = ?))
+              )
+            "#,
+        )
+        .bind(now)
+        .bind(team_id)
+        .bind(team_id)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(total.unwrap_or(0))
+    }
+}
@@ -675,7 +675,10 @@ impl KeyStore {
             request_log_diagnostic_handoff: Mutex::new(RequestLogDiagnosticHandoff::default()),
             user_debug_info_shared_cache: RwLock::new(HashMap::new()),
             request_stats_coalescer: RequestStatsCoalescer::default(),
+            process_metrics: ProcessMetrics::default(),
             admin_heavy_read_semaphore: Semaphore::new(ADMIN_HEAVY_READ_CONCURRENCY),
+            api_key_secret_cipher: StdOnceLock::new(),
+            access_token_secret_hashing: std::sync::atomic::AtomicBool::new(false),
             #[cfg(test)]
             forced_pending_claim_miss_log_ids: Mutex::new(HashSet::new()),
             #[cfg(debug_assertions)]
@@ -773,7 +776,10 @@ impl KeyStore {
             request_log_diagnostic_handoff: Mutex::new(RequestLogDiagnosticHandoff::default()),
             user_debug_info_shared_cache: RwLock::new(HashMap::new()),
             request_stats_coalescer: RequestStatsCoalescer::default(),
+            process_metrics: ProcessMetrics::default(),
             admin_heavy_read_semaphore: Semaphore::new(ADMIN_HEAVY_READ_CONCURRENCY),
+            api_key_secret_cipher: StdOnceLock::new(),
+            access_token_secret_hashing: std::sync::atomic::AtomicBool::new(false),
             #[cfg(test)]
             forced_pending_claim_miss_log_ids: Mutex::new(HashSet::new()),
             #[cfg(debug_assertions)]
@@ -833,6 +839,7 @@ impl KeyStore {
                 client_ip_source TEXT,
                 client_ip_trusted INTEGER NOT NULL DEFAULT 0,
                 ip_headers TEXT,
+                trace_id TEXT,
                 visibility TEXT NOT NULL DEFAULT 'visible',
                 created_at INTEGER NOT NULL
             )
@@ -881,13 +888,15 @@ impl KeyStore {
                 quota_limit INTEGER,
                 quota_remaining INTEGER,
                 quota_synced_at INTEGER,
-                deleted_at INTEGER
+                deleted_at INTEGER,
+                api_key_ciphertext TEXT
             )
             "#,
         )
         .execute(&self.pool)
         .await?;
         self.upgrade_api_keys_schema().await?;
+        self.ensure_api_key_secret_ciphertext_column().await?;
         self.ensure_api_key_quarantines_schema().await?;
         self.ensure_api_key_maintenance_records_schema().await?;
         self.ensure_api_key_quota_sync_samples_schema().await?;
@@ -942,6 +951,7 @@ impl KeyStore {
                 client_ip_source TEXT,
                 client_ip_trusted INTEGER NOT NULL DEFAULT 0,
                 ip_headers TEXT,
+                trace_id TEXT,
                 visibility TEXT NOT NULL DEFAULT 'visible',
                 created_at INTEGER NOT NULL
             )
@@ -982,8 +992,12 @@ impl KeyStore {
         )
         .execute(&self.pool)
         .await?;
+        self.ensure_request_log_search_schema().await?;
+        self.ensure_request_log_replay_schema().await?;
 
         self.ensure_api_key_transient_backoffs_schema().await?;
+        self.ensure_api_key_group_routing_schema().await?;
+        self.ensure_api_key_rate_budgets_schema().await?;
 
         // API key usage rollups (for statistics that must not depend on request_logs retention).
         sqlx::query(
@@ -1072,7 +1086,11 @@ impl KeyStore {
                 total_requests INTEGER NOT NULL DEFAULT 0,
                 created_at INTEGER NOT NULL,
                 last_used_at INTEGER,
-                deleted_at INTEGER
+                deleted_at INTEGER,
+                secret_hash TEXT,              -- salted hash once the secret is not kept
+                not_before INTEGER,            -- optional start of the validity window
+                expires_at INTEGER,            -- optional end of the validity window
+                scopes TEXT                    -- JSON allow-list; NULL defers to the group default
             )
             "#,
         )
@@ -1080,6 +1098,20 @@ impl KeyStore {
         .await?;
 
         self.upgrade_auth_tokens_schema().await?;
+        self.ensure_access_token_secret_hash_column().await?;
+        self.ensure_access_token_lifetime_schema().await?;
+        self.ensure_access_token_scopes_schema().await?;
+        self.ensure_request_parameter_policies_schema().await?;
+        self.ensure_response_cache_schema().await?;
+        self.ensure_alert_webhooks_schema().await?;
+        self.ensure_alert_rules_schema().await?;
+        self.ensure_alert_workflow_schema().await?;
+        self.ensure_admin_audit_schema().await?;
+        self.ensure_registration_invites_schema().await?;
+        self.ensure_admin_accounts_schema().await?;
+        self.ensure_admin_api_tokens_schema().await?;
+        self.ensure_teams_schema().await?;
+        self.ensure_billing_reservations_schema().await?;
 
         // Persist research request ownership/key affinity so result polling survives
         // process restarts and multi-instance routing.
@@ -1529,6 +1561,17 @@ impl KeyStore {
                 .execute(&self.pool)
                 .await?;
         }
+        // OIDC logins keep their PKCE verifier and nonce server-side until the state is consumed,
+        // and either provider may carry the registration invite code entered before login.
+        for column in ["code_verifier", "nonce", "invite_code"] {
+            if !self.table_column_exists("oauth_login_states", column).await? {
+                sqlx::query(&format!(
+                    "ALTER TABLE oauth_login_states ADD COLUMN {column} TEXT"
+                ))
+                .execute(&self.pool)
+                .await?;
+            }
+        }
 
         sqlx::query(
             r#"
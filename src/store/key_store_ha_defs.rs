@@ -97,10 +97,18 @@ const HA_CONTROL_PLANE_EVENT_RETENTION_SECS: i64 = 7 * 24 * 60 * 60;
 const HA_CONTROL_BASELINE_TABLES: &[&str] = &[
     "admin_password_settings",
     "announcements",
+    "alert_webhook_sinks",
+    "alert_rules",
+    "alert_group_workflows",
+    "alert_group_workflow_events",
+    "alert_silences",
     "account_entitlements",
+    "api_key_group_bindings",
+    "api_key_group_tiers",
     "api_key_low_quota_depletions",
     "api_key_maintenance_records",
     "api_key_quarantines",
+    "api_key_rate_budgets",
     "api_keys",
     "auth_tokens",
     "forward_proxy_settings",
@@ -121,10 +129,18 @@ const HA_CONTROL_BASELINE_TABLES: &[&str] = &[
 const HA_CONTROL_EVENT_TABLES: &[&str] = &[
     "admin_password_settings",
     "announcements",
+    "alert_webhook_sinks",
+    "alert_rules",
+    "alert_group_workflows",
+    "alert_group_workflow_events",
+    "alert_silences",
     "account_entitlements",
+    "api_key_group_bindings",
+    "api_key_group_tiers",
     "api_key_low_quota_depletions",
     "api_key_maintenance_records",
     "api_key_quarantines",
+    "api_key_rate_budgets",
     "api_keys",
     "auth_tokens",
     "forward_proxy_settings",
@@ -207,6 +223,10 @@ const HA_META_KEYS: &[&str] = &[
     "upstream_reconciliation_local_last_recovered_at_v1",
     "global_ip_limit_v1",
     "ha_full_master_node_id_v1",
+    "key_selection_mode_v1",
+    "cross_key_retry_v1",
+    "response_cache_v1",
+    "request_coalescing_v1",
     "mcp_session_affinity_key_count_v1",
     "rebalance_mcp_enabled_v1",
     "rebalance_mcp_session_percent_v1",
@@ -38,7 +38,8 @@ impl KeyStore {
                 "linuxdo_user_tag_binding_refresh"
                 | "forward_proxy_geo_refresh"
                 | "linuxdo_credit_recharge_lifecycle"
-                | "linuxdo_user_status_sync",
+                | "linuxdo_user_status_sync"
+                | "token_expiry_notice",
             ) => 4,
             (_, "quota_sync" | "quota_sync/manual" | "quota_sync/hot") => 5,
             _ => 6,
@@ -61,7 +62,7 @@ impl KeyStore {
                 WHEN {trigger_source_column} = 'manual' THEN 1 \
                 WHEN {job_type_column} = 'request_logs_gc' OR {job_type_column} = 'db_compaction' THEN 2 \
                 WHEN {job_type_column} = 'auth_token_logs_gc' OR {job_type_column} = 'ha_outbox_gc' OR {job_type_column} = 'mcp_sessions_gc' OR {job_type_column} = 'mcp_session_init_backoffs_gc' OR {job_type_column} = 'token_usage_rollup' OR {job_type_column} = 'upstream_reconciliation' OR {job_type_column} = 'usage_aggregation' THEN 3 \
-                WHEN {job_type_column} = 'linuxdo_user_tag_binding_refresh' OR {job_type_column} = 'forward_proxy_geo_refresh' OR {job_type_column} = 'linuxdo_credit_recharge_lifecycle' OR {job_type_column} = 'linuxdo_user_status_sync' THEN 4 \
+                WHEN {job_type_column} = 'linuxdo_user_tag_binding_refresh' OR {job_type_column} = 'forward_proxy_geo_refresh' OR {job_type_column} = 'linuxdo_credit_recharge_lifecycle' OR {job_type_column} = 'linuxdo_user_status_sync' OR {job_type_column} = 'token_expiry_notice' THEN 4 \
                 WHEN {job_type_column} = 'quota_sync' OR {job_type_column} = 'quota_sync/manual' OR {job_type_column} = 'quota_sync/hot' THEN 5 \
                 ELSE 6 \
             END"
@@ -0,0 +1,512 @@
+/// Key-group constraints applied to one key selection pass: an optional allow-list of key groups
+/// (from token-group / user-tag bindings) and the lowest selection tier still eligible.
+/// Keys whose local rate budget is currently spent are listed in `saturated_key_ids`; pool
+/// selection only falls back to them when no key with headroom is left.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct KeyGroupRoute {
+    pub(crate) key_groups: Option<Vec<String>>,
+    pub(crate) tier_floor: Option<i64>,
+    pub(crate) saturated_key_ids: Vec<String>,
+}
+
+impl KeyGroupRoute {
+    fn push_group_filter(&self, builder: &mut QueryBuilder<'_, Sqlite>, alias: &str) {
+        let Some(key_groups) = self.key_groups.as_ref() else {
+            return;
+        };
+        if key_groups.is_empty() {
+            builder.push(" AND 0");
+            return;
+        }
+        builder.push(format!(" AND {alias}.group_name IN ("));
+        {
+            let mut separated = builder.separated(", ");
+            for group in key_groups {
+                separated.push_bind(group.clone());
+            }
+        }
+        builder.push(")");
+    }
+
+    /// Push the group allow-list and tier floor as `AND ...` clauses against `alias`.
+    fn push_filters(&self, builder: &mut QueryBuilder<'_, Sqlite>, alias: &str) {
+        self.push_group_filter(builder, alias);
+        if let Some(tier_floor) = self.tier_floor {
+            builder.push(format!(" AND {} >= ", api_key_tier_priority_sql(alias)));
+            builder.push_bind(tier_floor);
+        }
+    }
+
+    /// Push the `ORDER BY` prefix that ranks keys with rate-budget headroom first.
+    fn push_headroom_order(&self, builder: &mut QueryBuilder<'_, Sqlite>, alias: &str) {
+        builder.push(" ORDER BY ");
+        if self.saturated_key_ids.is_empty() {
+            return;
+        }
+        builder.push(format!("CASE WHEN {alias}.id IN ("));
+        {
+            let mut separated = builder.separated(", ");
+            for key_id in &self.saturated_key_ids {
+                separated.push_bind(key_id.clone());
+            }
+        }
+        builder.push(") THEN 1 ELSE 0 END ASC, ");
+    }
+
+    /// Drop saturated candidates as long as at least one candidate with headroom remains.
+    fn retain_headroom(&self, candidates: &mut Vec<(String, String)>) {
+        if candidates
+            .iter()
+            .any(|(id, _)| !self.saturated_key_ids.contains(id))
+        {
+            candidates.retain(|(id, _)| !self.saturated_key_ids.contains(id));
+        }
+    }
+
+    fn admits_key_group(&self, group: Option<&str>) -> bool {
+        match self.key_groups.as_ref() {
+            None => true,
+            Some(key_groups) => {
+                group.is_some_and(|group| key_groups.iter().any(|allowed| allowed == group))
+            }
+        }
+    }
+}
+
+fn api_key_tier_priority_sql(alias: &str) -> String {
+    format!(
+        "COALESCE((SELECT t.priority FROM api_key_group_tiers t WHERE t.group_name = {alias}.group_name), 0)"
+    )
+}
+
+fn normalize_key_group_name(value: &str) -> Result<String, ProxyError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(ProxyError::Other("key group name is required".to_string()));
+    }
+    if value.chars().count() > API_KEY_GROUP_NAME_MAX_LEN {
+        return Err(ProxyError::Other(format!(
+            "key group name must be at most {API_KEY_GROUP_NAME_MAX_LEN} characters"
+        )));
+    }
+    Ok(value.to_string())
+}
+
+fn normalize_api_key_group_routing(
+    routing: ApiKeyGroupRouting,
+) -> Result<ApiKeyGroupRouting, ProxyError> {
+    let mut tiers = Vec::with_capacity(routing.tiers.len());
+    for tier in routing.tiers {
+        let group = normalize_key_group_name(&tier.group)?;
+        if tiers
+            .iter()
+            .any(|existing: &ApiKeyGroupTier| existing.group == group)
+        {
+            return Err(ProxyError::Other(format!(
+                "duplicate priority for key group '{group}'"
+            )));
+        }
+        if !(API_KEY_GROUP_PRIORITY_MIN..=API_KEY_GROUP_PRIORITY_MAX).contains(&tier.priority) {
+            return Err(ProxyError::Other(format!(
+                "key group priority must be between {API_KEY_GROUP_PRIORITY_MIN} and {API_KEY_GROUP_PRIORITY_MAX}"
+            )));
+        }
+        tiers.push(ApiKeyGroupTier {
+            group,
+            priority: tier.priority,
+        });
+    }
+    tiers.sort_by(|left, right| {
+        right
+            .priority
+            .cmp(&left.priority)
+            .then_with(|| left.group.cmp(&right.group))
+    });
+
+    let mut bindings: Vec<ApiKeyGroupBinding> = Vec::with_capacity(routing.bindings.len());
+    for binding in routing.bindings {
+        let kind = binding.kind.trim().to_string();
+        if !is_supported_key_group_binding_kind(&kind) {
+            return Err(ProxyError::Other(format!(
+                "unsupported key group binding kind '{kind}'"
+            )));
+        }
+        let value = binding.value.trim().to_string();
+        if value.is_empty() {
+            return Err(ProxyError::Other(
+                "key group binding value is required".to_string(),
+            ));
+        }
+        if bindings
+            .iter()
+            .any(|existing| existing.kind == kind && existing.value == value)
+        {
+            return Err(ProxyError::Other(format!(
+                "duplicate key group binding for {kind} '{value}'"
+            )));
+        }
+        let mut key_groups = binding
+            .key_groups
+            .iter()
+            .map(|group| normalize_key_group_name(group))
+            .collect::<Result<Vec<_>, _>>()?;
+        key_groups.sort();
+        key_groups.dedup();
+        if key_groups.is_empty() {
+            return Err(ProxyError::Other(format!(
+                "key group binding for {kind} '{value}' must list at least one key group"
+            )));
+        }
+        bindings.push(ApiKeyGroupBinding {
+            kind,
+            value,
+            key_groups,
+        });
+    }
+    bindings.sort_by(|left, right| {
+        left.kind
+            .cmp(&right.kind)
+            .then_with(|| left.value.cmp(&right.value))
+    });
+
+    Ok(ApiKeyGroupRouting { tiers, bindings })
+}
+
+impl KeyStore {
+    pub(crate) async fn ensure_api_key_group_routing_schema(&self) -> Result<(), ProxyError> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS api_key_group_tiers (
+                group_name TEXT PRIMARY KEY,
+                priority INTEGER NOT NULL DEFAULT 0,
+                created_at INTEGER NOT NULL,
+                updated_at INTEGER NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS api_key_group_bindings (
+                subject_kind TEXT NOT NULL,
+                subject_value TEXT NOT NULL,
+                key_group TEXT NOT NULL,
+                created_at INTEGER NOT NULL,
+                PRIMARY KEY (subject_kind, subject_value, key_group)
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub(crate) async fn get_api_key_group_routing(&self) -> Result<ApiKeyGroupRouting, ProxyError> {
+        let tiers = sqlx::query_as::<_, (String, i64)>(
+            r#"
+            SELECT group_name, priority
+            FROM api_key_group_tiers
+            ORDER BY priority DESC, group_name ASC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .map(|(group, priority)| ApiKeyGroupTier { group, priority })
+        .collect();
+
+        let rows = sqlx::query_as::<_, (String, String, String)>(
+            r#"
+            SELECT subject_kind, subject_value, key_group
+            FROM api_key_group_bindings
+            ORDER BY subject_kind ASC, subject_value ASC, key_group ASC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        let mut bindings: Vec<ApiKeyGroupBinding> = Vec::new();
+        for (kind, value, key_group) in rows {
+            match bindings.last_mut() {
+                Some(last) if last.kind == kind && last.value == value => {
+                    last.key_groups.push(key_group);
+                }
+                _ => bindings.push(ApiKeyGroupBinding {
+                    kind,
+                    value,
+                    key_groups: vec![key_group],
+                }),
+            }
+        }
+
+        Ok(ApiKeyGroupRouting { tiers, bindings })
+    }
+
+    /// Replace every key group priority and binding with `routing`.
+    pub(crate) async fn replace_api_key_group_routing(
+        &self,
+        routing: ApiKeyGroupRouting,
+    ) -> Result<ApiKeyGroupRouting, ProxyError> {
+        let routing = normalize_api_key_group_routing(routing)?;
+        let now = self.backend_time.now_ts();
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("DELETE FROM api_key_group_tiers")
+            .execute(&mut *tx)
+            .await?;
+        for tier in &routing.tiers {
+            sqlx::query(
+                r#"
+                INSERT INTO api_key_group_tiers (group_name, priority, created_at, updated_at)
+                VALUES (?, ?, ?, ?)
+                "#,
+            )
+            .bind(&tier.group)
+            .bind(tier.priority)
+            .bind(now)
+            .bind(now)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        sqlx::query("DELETE FROM api_key_group_bindings")
+            .execute(&mut *tx)
+            .await?;
+        for binding in &routing.bindings {
+            for key_group in &binding.key_groups {
+                sqlx::query(
+                    r#"
+                    INSERT INTO api_key_group_bindings (subject_kind, subject_value, key_group, created_at)
+                    VALUES (?, ?, ?, ?)
+                    "#,
+                )
+                .bind(&binding.kind)
+                .bind(&binding.value)
+                .bind(key_group)
+                .bind(now)
+                .execute(&mut *tx)
+                .await?;
+            }
+        }
+
+        tx.commit().await?;
+        Ok(routing)
+    }
+
+    /// Key groups a token is restricted to through its token group or its owner's user tags.
+    ///
+    /// Returns `None` when no binding matches, meaning the token may use every key group.
+    pub(crate) async fn resolve_bound_key_groups_for_token(
+        &self,
+        token_id: &str,
+    ) -> Result<Option<Vec<String>>, ProxyError> {
+        let has_bindings =
+            sqlx::query_scalar::<_, i64>("SELECT EXISTS(SELECT 1 FROM api_key_group_bindings)")
+                .fetch_one(&self.pool)
+                .await?;
+        if has_bindings == 0 {
+            return Ok(None);
+        }
+
+        let user_id = self.find_user_id_by_token(token_id).await?;
+        let key_groups = sqlx::query_scalar::<_, String>(
+            r#"
+            SELECT DISTINCT b.key_group
+            FROM api_key_group_bindings b
+            WHERE (
+                    b.subject_kind = ?
+                    AND b.subject_value = (
+                        SELECT TRIM(COALESCE(t.group_name, ''))
+                        FROM auth_tokens t
+                        WHERE t.id = ?
+                    )
+                )
+               OR (
+                    b.subject_kind = ?
+                    AND b.subject_value IN (
+                        SELECT ut.name
+                        FROM user_tag_bindings utb
+                        JOIN user_tags ut ON ut.id = utb.tag_id
+                        WHERE utb.user_id = ?
+                    )
+                )
+            ORDER BY b.key_group ASC
+            "#,
+        )
+        .bind(KEY_GROUP_BINDING_TOKEN_GROUP)
+        .bind(token_id)
+        .bind(KEY_GROUP_BINDING_USER_TAG)
+        .bind(user_id.as_deref())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok((!key_groups.is_empty()).then_some(key_groups))
+    }
+
+    /// Highest selection tier that still has an available key inside `key_groups`.
+    ///
+    /// A key is available when it is active, not low-quota depleted, not quarantined and not
+    /// cooling down in any transient backoff scope. Returns `None` when nothing is available so
+    /// callers fall back to their usual exhausted/cooled ordering across every tier.
+    pub(crate) async fn api_key_tier_floor(
+        &self,
+        key_groups: Option<&[String]>,
+    ) -> Result<Option<i64>, ProxyError> {
+        let now = self.backend_time.now_ts();
+        let month_start = start_of_month(self.backend_time.now_utc()).timestamp();
+        let mut builder = QueryBuilder::<Sqlite>::new(format!(
+            "SELECT MAX({}) FROM api_keys WHERE status = ",
+            api_key_tier_priority_sql("api_keys")
+        ));
+        builder.push_bind(STATUS_ACTIVE);
+        builder.push(
+            r#" AND deleted_at IS NULL
+              AND NOT EXISTS (
+                  SELECT 1
+                  FROM api_key_low_quota_depletions d
+                  WHERE d.key_id = api_keys.id AND d.month_start = "#,
+        );
+        builder.push_bind(month_start);
+        builder.push(
+            r#"
+              )
+              AND NOT EXISTS (
+                  SELECT 1
+                  FROM api_key_quarantines q
+                  WHERE q.key_id = api_keys.id AND q.cleared_at IS NULL
+              )
+              AND NOT EXISTS (
+                  SELECT 1
+                  FROM api_key_transient_backoffs b
+                  WHERE b.key_id = api_keys.id AND b.cooldown_until > "#,
+        );
+        builder.push_bind(now);
+        builder.push(")");
+        KeyGroupRoute {
+            key_groups: key_groups.map(<[String]>::to_vec),
+            ..KeyGroupRoute::default()
+        }
+        .push_group_filter(&mut builder, "api_keys");
+
+        Ok(builder
+            .build_query_scalar::<Option<i64>>()
+            .fetch_one(&self.pool)
+            .await?)
+    }
+
+    pub(crate) async fn key_group_route(
+        &self,
+        key_groups: Option<&[String]>,
+    ) -> Result<KeyGroupRoute, ProxyError> {
+        Ok(KeyGroupRoute {
+            key_groups: key_groups.map(<[String]>::to_vec),
+            tier_floor: self.api_key_tier_floor(key_groups).await?,
+            saturated_key_ids: Vec::new(),
+        })
+    }
+
+    /// `(inside the route's key groups, inside its eligible tiers)` for `key_id`, or `None` when
+    /// the key does not exist.
+    pub(crate) async fn api_key_route_admission(
+        &self,
+        key_id: &str,
+        route: &KeyGroupRoute,
+    ) -> Result<Option<(bool, bool)>, ProxyError> {
+        let row = sqlx::query_as::<_, (Option<String>, i64)>(&format!(
+            "SELECT group_name, {} FROM api_keys WHERE id = ? LIMIT 1",
+            api_key_tier_priority_sql("api_keys")
+        ))
+        .bind(key_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row.map(|(group, priority)| {
+            (
+                route.admits_key_group(group.as_deref()),
+                route.tier_floor.is_none_or(|floor| priority >= floor),
+            )
+        }))
+    }
+
+    pub(crate) async fn fetch_api_key_tier_capacity(
+        &self,
+    ) -> Result<Vec<ApiKeyTierCapacity>, ProxyError> {
+        let mut conn = self.pool.acquire().await?;
+        Self::fetch_api_key_tier_capacity_on(&mut conn, self.backend_time.now_utc()).await
+    }
+
+    pub(crate) async fn fetch_api_key_tier_capacity_on(
+        conn: &mut SqliteConnection,
+        now: chrono::DateTime<Utc>,
+    ) -> Result<Vec<ApiKeyTierCapacity>, ProxyError> {
+        let month_start = start_of_month(now).timestamp();
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT
+                tier,
+                COALESCE(group_name, '') AS group_name,
+                COUNT(*) AS total_keys,
+                COALESCE(SUM(CASE WHEN NOT quarantined AND status = ? AND NOT depleted AND NOT cooling THEN 1 ELSE 0 END), 0) AS available_keys,
+                COALESCE(SUM(CASE WHEN NOT quarantined AND status = ? AND NOT depleted AND cooling THEN 1 ELSE 0 END), 0) AS cooling_keys,
+                COALESCE(SUM(CASE WHEN NOT quarantined AND (status = ? OR depleted) THEN 1 ELSE 0 END), 0) AS exhausted_keys,
+                COALESCE(SUM(CASE WHEN quarantined THEN 1 ELSE 0 END), 0) AS quarantined_keys,
+                COALESCE(SUM(CASE WHEN NOT quarantined THEN COALESCE(quota_limit, 0) ELSE 0 END), 0) AS quota_limit,
+                COALESCE(SUM(CASE WHEN NOT quarantined THEN COALESCE(quota_remaining, 0) ELSE 0 END), 0) AS quota_remaining
+            FROM (
+                SELECT
+                    ak.group_name,
+                    ak.status,
+                    ak.quota_limit,
+                    ak.quota_remaining,
+                    {tier} AS tier,
+                    EXISTS (
+                        SELECT 1 FROM api_key_quarantines q
+                        WHERE q.key_id = ak.id AND q.cleared_at IS NULL
+                    ) AS quarantined,
+                    EXISTS (
+                        SELECT 1 FROM api_key_low_quota_depletions d
+                        WHERE d.key_id = ak.id AND d.month_start = ?
+                    ) AS depleted,
+                    EXISTS (
+                        SELECT 1 FROM api_key_transient_backoffs b
+                        WHERE b.key_id = ak.id AND b.cooldown_until > ?
+                    ) AS cooling
+                FROM api_keys ak
+                WHERE ak.deleted_at IS NULL
+            )
+            GROUP BY tier, COALESCE(group_name, '')
+            ORDER BY tier DESC, group_name ASC
+            "#,
+            tier = api_key_tier_priority_sql("ak"),
+        ))
+        .bind(STATUS_ACTIVE)
+        .bind(STATUS_ACTIVE)
+        .bind(STATUS_EXHAUSTED)
+        .bind(month_start)
+        .bind(now.timestamp())
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut tiers: Vec<ApiKeyTierCapacity> = Vec::new();
+        for row in rows {
+            let priority: i64 = row.try_get("tier")?;
+            if tiers.last().is_none_or(|last| last.priority != priority) {
+                tiers.push(ApiKeyTierCapacity {
+                    priority,
+                    ..ApiKeyTierCapacity::default()
+                });
+            }
+            let tier = tiers.last_mut().expect("tier entry just pushed");
+            tier.groups.push(row.try_get("group_name")?);
+            tier.total_keys += row.try_get::<i64, _>("total_keys")?;
+            tier.available_keys += row.try_get::<i64, _>("available_keys")?;
+            tier.cooling_keys += row.try_get::<i64, _>("cooling_keys")?;
+            tier.exhausted_keys += row.try_get::<i64, _>("exhausted_keys")?;
+            tier.quarantined_keys += row.try_get::<i64, _>("quarantined_keys")?;
+            tier.quota_limit += row.try_get::<i64, _>("quota_limit")?;
+            tier.quota_remaining += row.try_get::<i64, _>("quota_remaining")?;
+        }
+        Ok(tiers)
+    }
+}
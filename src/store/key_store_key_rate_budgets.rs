@@ -0,0 +1,86 @@
+impl KeyStore {
+    pub(crate) async fn ensure_api_key_rate_budgets_schema(&self) -> Result<(), ProxyError> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS api_key_rate_budgets (
+                key_id TEXT PRIMARY KEY,
+                rpm_limit INTEGER NOT NULL,
+                created_at INTEGER NOT NULL,
+                updated_at INTEGER NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Requests-per-minute budgets for every key that has one configured.
+    pub(crate) async fn fetch_api_key_rate_budget_limits(
+        &self,
+    ) -> Result<HashMap<String, i64>, ProxyError> {
+        let rows = sqlx::query_as::<_, (String, i64)>(
+            r#"
+            SELECT b.key_id, b.rpm_limit
+            FROM api_key_rate_budgets b
+            JOIN api_keys k ON k.id = b.key_id
+            WHERE k.deleted_at IS NULL
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().collect())
+    }
+
+    /// Set (`Some`) or clear (`None`) a key's budget. Returns `false` when the key does not exist.
+    pub(crate) async fn set_api_key_rate_budget(
+        &self,
+        key_id: &str,
+        rpm_limit: Option<i64>,
+    ) -> Result<bool, ProxyError> {
+        if let Some(limit) = rpm_limit
+            && !(API_KEY_RATE_BUDGET_RPM_MIN..=API_KEY_RATE_BUDGET_RPM_MAX).contains(&limit)
+        {
+            return Err(ProxyError::Other(format!(
+                "rpm_limit must be between {API_KEY_RATE_BUDGET_RPM_MIN} and {API_KEY_RATE_BUDGET_RPM_MAX}",
+            )));
+        }
+        let exists = sqlx::query_scalar::<_, i64>(
+            "SELECT 1 FROM api_keys WHERE id = ? AND deleted_at IS NULL LIMIT 1",
+        )
+        .bind(key_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .is_some();
+        if !exists {
+            return Ok(false);
+        }
+        match rpm_limit {
+            Some(limit) => {
+                let now = self.backend_time.now_ts();
+                sqlx::query(
+                    r#"
+                    INSERT INTO api_key_rate_budgets (key_id, rpm_limit, created_at, updated_at)
+                    VALUES (?, ?, ?, ?)
+                    ON CONFLICT(key_id) DO UPDATE SET
+                        rpm_limit = excluded.rpm_limit,
+                        updated_at = excluded.updated_at
+                    "#,
+                )
+                .bind(key_id)
+                .bind(limit)
+                .bind(now)
+                .bind(now)
+                .execute(&self.pool)
+                .await?;
+            }
+            None => {
+                sqlx::query("DELETE FROM api_key_rate_budgets WHERE key_id = ?")
+                    .bind(key_id)
+                    .execute(&self.pool)
+                    .await?;
+            }
+        }
+        Ok(true)
+    }
+}
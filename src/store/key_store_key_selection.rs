@@ -0,0 +1,146 @@
+impl KeyStore {
+    pub(crate) async fn key_selection_mode(&self) -> Result<KeySelectionMode, ProxyError> {
+        Ok(self
+            .get_meta_string(META_KEY_KEY_SELECTION_MODE_V1)
+            .await?
+            .as_deref()
+            .and_then(KeySelectionMode::from_meta_value)
+            .unwrap_or_default())
+    }
+
+    /// Row limit for the primary active-key query: LRU only needs the head row, quota-weighted
+    /// selection re-ranks every eligible active key (`LIMIT -1` is unbounded in SQLite).
+    pub(crate) fn key_selection_candidate_limit(mode: KeySelectionMode) -> i64 {
+        match mode {
+            KeySelectionMode::Lru => 1,
+            KeySelectionMode::QuotaWeighted => -1,
+        }
+    }
+
+    /// Quota-weighted selection score per key, in credits: the latest synced remaining monthly
+    /// credits (from `api_key_quota_sync_samples`, falling back to `api_keys.quota_remaining`)
+    /// minus the business credits billed to the key inside the recent pressure window, which
+    /// the next quota sync has not observed yet.
+    ///
+    /// Keys without any quota data are absent from the map.
+    pub(crate) async fn list_api_key_quota_selection_scores(
+        &self,
+        key_ids: &[String],
+        now: i64,
+    ) -> Result<HashMap<String, i64>, ProxyError> {
+        if key_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let mut builder = QueryBuilder::<Sqlite>::new(
+            r#"
+            SELECT k.id,
+                   COALESCE(
+                       (
+                           SELECT s.quota_remaining
+                           FROM api_key_quota_sync_samples s
+                           WHERE s.key_id = k.id
+                           ORDER BY s.captured_at DESC, s.id DESC
+                           LIMIT 1
+                       ),
+                       k.quota_remaining
+                   ) AS quota_remaining
+            FROM api_keys k
+            WHERE k.id IN ("#,
+        );
+        {
+            let mut separated = builder.separated(", ");
+            for key_id in key_ids {
+                separated.push_bind(key_id);
+            }
+        }
+        builder.push(")");
+        let remaining = builder
+            .build_query_as::<(String, Option<i64>)>()
+            .fetch_all(&self.pool)
+            .await?;
+
+        let recent_credits = self
+            .list_recent_billed_credits_for_keys(
+                key_ids,
+                now - KEY_SELECTION_QUOTA_PRESSURE_WINDOW_SECS,
+            )
+            .await?;
+
+        Ok(remaining
+            .into_iter()
+            .filter_map(|(key_id, quota_remaining)| {
+                let quota_remaining = quota_remaining?;
+                let recent_credits = recent_credits.get(&key_id).copied().unwrap_or(0);
+                Some((key_id, quota_remaining.saturating_sub(recent_credits)))
+            })
+            .collect())
+    }
+
+    /// Business credits billed per key by request logs created at or after `since`.
+    pub(crate) async fn list_recent_billed_credits_for_keys(
+        &self,
+        key_ids: &[String],
+        since: i64,
+    ) -> Result<HashMap<String, i64>, ProxyError> {
+        if key_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let mut builder = QueryBuilder::<Sqlite>::new(
+            r#"
+            SELECT api_key_id, SUM(MAX(COALESCE(business_credits, 0), 0)) AS billed_credits
+            FROM request_logs
+            WHERE created_at >= "#,
+        );
+        builder.push_bind(since);
+        builder.push(" AND api_key_id IN (");
+        {
+            let mut separated = builder.separated(", ");
+            for key_id in key_ids {
+                separated.push_bind(key_id);
+            }
+        }
+        builder.push(") GROUP BY api_key_id");
+        let rows = builder
+            .build_query_as::<(String, i64)>()
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows.into_iter().collect())
+    }
+
+    /// Reorder LRU-ordered `(id, api_key)` rows by quota score, highest first.
+    ///
+    /// Keys without quota data sort after scored keys; ties keep the incoming LRU order.
+    pub(crate) async fn order_key_rows_by_quota_weight(
+        &self,
+        mut rows: Vec<(String, String)>,
+        now: i64,
+    ) -> Result<Vec<(String, String)>, ProxyError> {
+        if rows.len() < 2 {
+            return Ok(rows);
+        }
+        let key_ids = rows.iter().map(|(id, _)| id.clone()).collect::<Vec<_>>();
+        let scores = self
+            .list_api_key_quota_selection_scores(&key_ids, now)
+            .await?;
+        rows.sort_by_key(|(id, _)| std::cmp::Reverse(scores.get(id).copied()));
+        Ok(rows)
+    }
+
+    /// Pick the head of an LRU-ordered candidate list according to the selection mode.
+    pub(crate) async fn pick_key_row_for_selection_mode(
+        &self,
+        mode: KeySelectionMode,
+        rows: Vec<(String, String)>,
+        now: i64,
+    ) -> Result<Option<(String, String)>, ProxyError> {
+        let rows = match mode {
+            KeySelectionMode::Lru => rows,
+            KeySelectionMode::QuotaWeighted => {
+                self.order_key_rows_by_quota_weight(rows, now).await?
+            }
+        };
+        Ok(rows.into_iter().next())
+    }
+}
@@ -222,6 +222,9 @@ impl KeyStore {
             last_activity,
             total_quota_limit: 0,
             total_quota_remaining: 0,
+            key_tiers: Vec::new(),
+            key_rate_budgets: Vec::new(),
+            response_cache: ResponseCacheStats::default(),
         })
     }
 
@@ -248,7 +251,8 @@ impl KeyStore {
                        request_body_sha256, response_body_sha256,
                        body_cleaned_reason, body_cleaned_at,
                        created_at, forwarded_headers, dropped_headers,
-                       remote_addr, client_ip, client_ip_source, client_ip_trusted, ip_headers
+                       remote_addr, client_ip, client_ip_source, client_ip_trusted, ip_headers,
+                       trace_id
                 FROM request_logs
                 WHERE api_key_id = ? AND visibility = ? AND created_at >= ?
                 ORDER BY created_at DESC
@@ -277,7 +281,8 @@ impl KeyStore {
                        request_body_sha256, response_body_sha256,
                        body_cleaned_reason, body_cleaned_at,
                        created_at, forwarded_headers, dropped_headers,
-                       remote_addr, client_ip, client_ip_source, client_ip_trusted, ip_headers
+                       remote_addr, client_ip, client_ip_source, client_ip_trusted, ip_headers,
+                       trace_id
                 FROM request_logs
                 WHERE api_key_id = ? AND visibility = ?
                 ORDER BY created_at DESC
@@ -302,12 +307,16 @@ impl KeyStore {
 
         let now = self.backend_time.now_ts();
 
-        for key in keys {
+        let lookups = keys
+            .iter()
+            .map(|key| self.api_key_lookup_value(key))
+            .collect::<Vec<_>>();
+        for (key, lookup) in keys.iter().zip(&lookups) {
             // If key exists, undelete by clearing deleted_at
             if let Some((id, deleted_at)) = sqlx::query_as::<_, (String, Option<i64>)>(
                 "SELECT id, deleted_at FROM api_keys WHERE api_key = ? LIMIT 1",
             )
-            .bind(key)
+            .bind(lookup)
             .fetch_optional(&mut *tx)
             .await?
             {
@@ -323,12 +332,15 @@ impl KeyStore {
             let id = Self::generate_unique_key_id(&mut tx).await?;
             sqlx::query(
                 r#"
-                INSERT INTO api_keys (id, api_key, status, created_at, status_changed_at)
-                VALUES (?, ?, ?, ?, ?)
+                INSERT INTO api_keys (
+                    id, api_key, api_key_ciphertext, status, created_at, status_changed_at
+                )
+                VALUES (?, ?, ?, ?, ?, ?)
                 "#,
             )
             .bind(&id)
-            .bind(key)
+            .bind(lookup)
+            .bind(self.seal_api_key_secret(key)?)
             .bind(STATUS_ACTIVE)
             .bind(now)
             .bind(now)
@@ -348,8 +360,8 @@ impl KeyStore {
             builder.push(" WHERE deleted_at IS NULL AND api_key NOT IN (");
             {
                 let mut separated = builder.separated(", ");
-                for key in keys {
-                    separated.push_bind(key);
+                for lookup in &lookups {
+                    separated.push_bind(lookup);
                 }
             }
             builder.push(")");
@@ -360,119 +372,125 @@ impl KeyStore {
         Ok(())
     }
 
-    pub(crate) async fn acquire_key(&self) -> Result<ApiKeyLease, ProxyError> {
-        self.reset_monthly().await?;
-
-        let now = self.backend_time.now_ts();
-        let month_start = start_of_month(self.backend_time.now_utc()).timestamp();
-
-        if let Some((id, api_key)) = sqlx::query_as::<_, (String, String)>(
-            r#"
-            SELECT id, api_key
-            FROM api_keys
-            WHERE status = ? AND deleted_at IS NULL
-              AND NOT EXISTS (
-                  SELECT 1
-                  FROM api_key_low_quota_depletions d
-                  WHERE d.key_id = api_keys.id AND d.month_start = ?
-              )
-              AND NOT EXISTS (
-                  SELECT 1
-                  FROM api_key_quarantines q
-                  WHERE q.key_id = api_keys.id AND q.cleared_at IS NULL
-              )
-            ORDER BY last_used_at ASC, id ASC
-            LIMIT 1
-            "#,
-        )
-        .bind(STATUS_ACTIVE)
-        .bind(month_start)
-        .fetch_optional(&self.pool)
-        .await?
-        {
-            self.touch_key(&api_key, now).await?;
-            return Ok(ApiKeyLease {
-                id,
-                secret: api_key,
-            });
+    /// `SELECT {columns} FROM api_keys WHERE ...` over non-deleted, non-quarantined keys with the
+    /// given status. `low_quota_depleted` selects keys with (or without) a low-quota depletion
+    /// marker for the current month.
+    fn selectable_api_keys_query<'args>(
+        columns: &str,
+        status: &'args str,
+        month_start: i64,
+        low_quota_depleted: bool,
+        excluded_key_id: Option<&'args str>,
+    ) -> QueryBuilder<'args, Sqlite> {
+        let mut builder =
+            QueryBuilder::<Sqlite>::new(format!("SELECT {columns} FROM api_keys WHERE status = "));
+        builder.push_bind(status);
+        builder.push(" AND deleted_at IS NULL");
+        if let Some(excluded_key_id) = excluded_key_id {
+            builder.push(" AND id != ");
+            builder.push_bind(excluded_key_id);
         }
-
-        if let Some((id, api_key)) = sqlx::query_as::<_, (String, String)>(
+        builder.push(if low_quota_depleted {
+            " AND EXISTS ("
+        } else {
+            " AND NOT EXISTS ("
+        });
+        builder.push(
             r#"
-            SELECT id, api_key
-            FROM api_keys
-            WHERE status = ? AND deleted_at IS NULL
-              AND NOT EXISTS (
                   SELECT 1
                   FROM api_key_low_quota_depletions d
-                  WHERE d.key_id = api_keys.id AND d.month_start = ?
+                  WHERE d.key_id = api_keys.id AND d.month_start = "#,
+        );
+        builder.push_bind(month_start);
+        builder.push(
+            r#"
               )
               AND NOT EXISTS (
                   SELECT 1
                   FROM api_key_quarantines q
                   WHERE q.key_id = api_keys.id AND q.cleared_at IS NULL
-              )
-            ORDER BY
-                CASE WHEN status_changed_at IS NULL THEN 1 ELSE 0 END ASC,
-                status_changed_at ASC,
-                id ASC
-            LIMIT 1
-            "#,
-        )
-        .bind(STATUS_EXHAUSTED)
-        .bind(month_start)
-        .fetch_optional(&self.pool)
-        .await?
+              )"#,
+        );
+        builder
+    }
+
+    #[cfg(test)]
+    pub(crate) async fn acquire_key(&self) -> Result<ApiKeyLease, ProxyError> {
+        let route = self.key_group_route(None).await?;
+        self.acquire_key_with_route(&route).await
+    }
+
+    /// Global key scheduling inside `route`: active keys of the eligible tiers first, then
+    /// exhausted keys, then low-quota depleted keys.
+    pub(crate) async fn acquire_key_with_route(
+        &self,
+        route: &KeyGroupRoute,
+    ) -> Result<ApiKeyLease, ProxyError> {
+        self.reset_monthly().await?;
+
+        let now = self.backend_time.now_ts();
+        let month_start = start_of_month(self.backend_time.now_utc()).timestamp();
+        let selection_mode = self.key_selection_mode().await?;
+
+        let mut builder = Self::selectable_api_keys_query(
+            API_KEY_LEASE_COLUMNS,
+            STATUS_ACTIVE,
+            month_start,
+            false,
+            None,
+        );
+        route.push_filters(&mut builder, "api_keys");
+        route.push_headroom_order(&mut builder, "api_keys");
+        builder.push("last_used_at ASC, id ASC LIMIT ");
+        builder.push_bind(Self::key_selection_candidate_limit(selection_mode));
+        let mut active_candidates = builder
+            .build_query_as::<(String, String)>()
+            .fetch_all(&self.pool)
+            .await?;
+        route.retain_headroom(&mut active_candidates);
+        if let Some((id, api_key)) = self
+            .pick_key_row_for_selection_mode(selection_mode, active_candidates, now)
+            .await?
         {
-            self.touch_key(&api_key, now).await?;
-            return Ok(ApiKeyLease {
-                id,
-                secret: api_key,
-            });
+            return self.lease_selected_api_key(id, api_key, now).await;
         }
 
-        if let Some((id, api_key)) = sqlx::query_as::<_, (String, String)>(
-            r#"
-            SELECT id, api_key
-            FROM api_keys
-            WHERE status = ? AND deleted_at IS NULL
-              AND EXISTS (
-                  SELECT 1
-                  FROM api_key_low_quota_depletions d
-                  WHERE d.key_id = api_keys.id AND d.month_start = ?
-              )
-              AND NOT EXISTS (
-                  SELECT 1
-                  FROM api_key_quarantines q
-                  WHERE q.key_id = api_keys.id AND q.cleared_at IS NULL
-              )
+        for low_quota_depleted in [false, true] {
+            let mut builder = Self::selectable_api_keys_query(
+                API_KEY_LEASE_COLUMNS,
+                STATUS_EXHAUSTED,
+                month_start,
+                low_quota_depleted,
+                None,
+            );
+            route.push_group_filter(&mut builder, "api_keys");
+            builder.push(
+                r#"
             ORDER BY
                 CASE WHEN status_changed_at IS NULL THEN 1 ELSE 0 END ASC,
                 status_changed_at ASC,
                 id ASC
-            LIMIT 1
-            "#,
-        )
-        .bind(STATUS_EXHAUSTED)
-        .bind(month_start)
-        .fetch_optional(&self.pool)
-        .await?
-        {
-            self.touch_key(&api_key, now).await?;
-            return Ok(ApiKeyLease {
-                id,
-                secret: api_key,
-            });
+            LIMIT 1"#,
+            );
+            if let Some((id, api_key)) = builder
+                .build_query_as::<(String, String)>()
+                .fetch_optional(&self.pool)
+                .await?
+            {
+                return self.lease_selected_api_key(id, api_key, now).await;
+            }
         }
 
         Err(ProxyError::NoAvailableKeys)
     }
 
+    #[cfg(test)]
     pub(crate) async fn acquire_key_avoiding_transient_backoff(
         &self,
         scope: &str,
     ) -> Result<ApiKeyLease, ProxyError> {
-        self.acquire_key_avoiding_transient_backoff_excluding(scope, None)
+        let route = self.key_group_route(None).await?;
+        self.acquire_key_avoiding_transient_backoff_excluding(scope, None, &route)
             .await
     }
 
@@ -480,40 +498,23 @@ impl KeyStore {
         &self,
         scope: &str,
         excluded_key_id: Option<&str>,
+        route: &KeyGroupRoute,
     ) -> Result<ApiKeyLease, ProxyError> {
         self.reset_monthly().await?;
 
         let now = self.backend_time.now_ts();
         let month_start = start_of_month(self.backend_time.now_utc()).timestamp();
-
-        let mut builder = QueryBuilder::<Sqlite>::new(
-            r#"
-            SELECT id, api_key
-            FROM api_keys
-            WHERE status = "#,
+        let selection_mode = self.key_selection_mode().await?;
+
+        let mut builder = Self::selectable_api_keys_query(
+            API_KEY_LEASE_COLUMNS,
+            STATUS_ACTIVE,
+            month_start,
+            false,
+            excluded_key_id,
         );
-        builder.push_bind(STATUS_ACTIVE);
-        builder.push(" AND deleted_at IS NULL");
-        if let Some(excluded_key_id) = excluded_key_id {
-            builder.push(" AND id != ");
-            builder.push_bind(excluded_key_id);
-        }
-        builder.push(
-            r#"
-              AND NOT EXISTS (
-                  SELECT 1
-                  FROM api_key_low_quota_depletions d
-                  WHERE d.key_id = api_keys.id AND d.month_start = "#,
-        );
-        builder.push_bind(month_start);
         builder.push(
             r#"
-              )
-              AND NOT EXISTS (
-                  SELECT 1
-                  FROM api_key_quarantines q
-                  WHERE q.key_id = api_keys.id AND q.cleared_at IS NULL
-              )
               AND NOT EXISTS (
                   SELECT 1
                   FROM api_key_transient_backoffs b
@@ -523,113 +524,65 @@ impl KeyStore {
         builder.push_bind(scope);
         builder.push(" AND b.cooldown_until > ");
         builder.push_bind(now);
-        builder.push(
-            r#"
-              )
-            ORDER BY last_used_at ASC, id ASC
-            LIMIT 1
-            "#,
-        );
+        builder.push(")");
+        route.push_filters(&mut builder, "api_keys");
+        route.push_headroom_order(&mut builder, "api_keys");
+        builder.push("last_used_at ASC, id ASC LIMIT ");
+        builder.push_bind(Self::key_selection_candidate_limit(selection_mode));
 
-        if let Some((id, api_key)) = builder
+        let mut active_candidates = builder
             .build_query_as::<(String, String)>()
-            .fetch_optional(&self.pool)
+            .fetch_all(&self.pool)
+            .await?;
+        route.retain_headroom(&mut active_candidates);
+        if let Some((id, api_key)) = self
+            .pick_key_row_for_selection_mode(selection_mode, active_candidates, now)
             .await?
         {
-            self.touch_key(&api_key, now).await?;
-            return Ok(ApiKeyLease {
-                id,
-                secret: api_key,
-            });
+            return self.lease_selected_api_key(id, api_key, now).await;
         }
 
         match excluded_key_id {
-            Some(key_id) => self.acquire_active_key_excluding(Some(key_id)).await,
-            None => self.acquire_key().await,
+            Some(key_id) => self.acquire_active_key_excluding(Some(key_id), route).await,
+            None => self.acquire_key_with_route(route).await,
         }
     }
 
     pub(crate) async fn list_mcp_session_candidate_key_ids(
         &self,
+        route: &KeyGroupRoute,
     ) -> Result<Vec<String>, ProxyError> {
         self.reset_monthly().await?;
         let month_start = start_of_month(self.backend_time.now_utc()).timestamp();
 
-        let active = sqlx::query_scalar::<_, String>(
-            r#"
-            SELECT id
-            FROM api_keys
-            WHERE status = ? AND deleted_at IS NULL
-              AND NOT EXISTS (
-                  SELECT 1
-                  FROM api_key_low_quota_depletions d
-                  WHERE d.key_id = api_keys.id AND d.month_start = ?
-              )
-              AND NOT EXISTS (
-                  SELECT 1
-                  FROM api_key_quarantines q
-                  WHERE q.key_id = api_keys.id AND q.cleared_at IS NULL
-              )
-            ORDER BY id ASC
-            "#,
-        )
-        .bind(STATUS_ACTIVE)
-        .bind(month_start)
-        .fetch_all(&self.pool)
-        .await?;
-        if !active.is_empty() {
-            return Ok(active);
-        }
-
-        let exhausted = sqlx::query_scalar::<_, String>(
-            r#"
-            SELECT id
-            FROM api_keys
-            WHERE status = ? AND deleted_at IS NULL
-              AND NOT EXISTS (
-                  SELECT 1
-                  FROM api_key_low_quota_depletions d
-                  WHERE d.key_id = api_keys.id AND d.month_start = ?
-              )
-              AND NOT EXISTS (
-                  SELECT 1
-                  FROM api_key_quarantines q
-                  WHERE q.key_id = api_keys.id AND q.cleared_at IS NULL
-              )
-            ORDER BY id ASC
-            "#,
-        )
-        .bind(STATUS_EXHAUSTED)
-        .bind(month_start)
-        .fetch_all(&self.pool)
-        .await?;
-        if !exhausted.is_empty() {
-            return Ok(exhausted);
+        for (status, low_quota_depleted) in [
+            (STATUS_ACTIVE, false),
+            (STATUS_EXHAUSTED, false),
+            (STATUS_EXHAUSTED, true),
+        ] {
+            let mut builder = Self::selectable_api_keys_query(
+                "id",
+                status,
+                month_start,
+                low_quota_depleted,
+                None,
+            );
+            if status == STATUS_ACTIVE {
+                route.push_filters(&mut builder, "api_keys");
+            } else {
+                route.push_group_filter(&mut builder, "api_keys");
+            }
+            builder.push(" ORDER BY id ASC");
+            let candidates = builder
+                .build_query_scalar::<String>()
+                .fetch_all(&self.pool)
+                .await?;
+            if !candidates.is_empty() {
+                return Ok(candidates);
+            }
         }
 
-        sqlx::query_scalar::<_, String>(
-            r#"
-            SELECT id
-            FROM api_keys
-            WHERE status = ? AND deleted_at IS NULL
-              AND EXISTS (
-                  SELECT 1
-                  FROM api_key_low_quota_depletions d
-                  WHERE d.key_id = api_keys.id AND d.month_start = ?
-              )
-              AND NOT EXISTS (
-                  SELECT 1
-                  FROM api_key_quarantines q
-                  WHERE q.key_id = api_keys.id AND q.cleared_at IS NULL
-              )
-            ORDER BY id ASC
-            "#,
-        )
-        .bind(STATUS_EXHAUSTED)
-        .bind(month_start)
-        .fetch_all(&self.pool)
-        .await
-        .map_err(ProxyError::from)
+        Ok(Vec::new())
     }
 
     pub(crate) async fn try_acquire_specific_key(
@@ -651,17 +604,28 @@ impl KeyStore {
     pub(crate) async fn try_acquire_affinity_specific_key(
         &self,
         key_id: &str,
+        route: &KeyGroupRoute,
     ) -> Result<Option<ApiKeyLease>, ProxyError> {
         self.reset_monthly().await?;
 
-        if let Some(lease) = self
-            .try_acquire_specific_key_with_status(key_id, STATUS_ACTIVE, false)
-            .await?
+        let Some((in_key_groups, in_eligible_tier)) =
+            self.api_key_route_admission(key_id, route).await?
+        else {
+            return Ok(None);
+        };
+        if !in_key_groups {
+            return Ok(None);
+        }
+
+        if in_eligible_tier
+            && let Some(lease) = self
+                .try_acquire_specific_key_with_status(key_id, STATUS_ACTIVE, false)
+                .await?
         {
             return Ok(Some(lease));
         }
 
-        if self.has_available_active_key_excluding(None).await? {
+        if self.has_available_active_key_excluding(None, route).await? {
             return Ok(None);
         }
 
@@ -672,7 +636,10 @@ impl KeyStore {
             return Ok(Some(lease));
         }
 
-        if self.has_available_regular_exhausted_key_excluding(Some(key_id)).await? {
+        if self
+            .has_available_regular_exhausted_key_excluding(Some(key_id), route)
+            .await?
+        {
             return Ok(None);
         }
 
@@ -691,7 +658,7 @@ impl KeyStore {
 
         let lease = sqlx::query_as::<_, (String, String)>(
             r#"
-            SELECT id, api_key
+            SELECT id, COALESCE(api_key_ciphertext, api_key)
             FROM api_keys
             WHERE id = ? AND status = ? AND deleted_at IS NULL
               AND (
@@ -721,123 +688,50 @@ impl KeyStore {
             return Ok(None);
         };
 
-        self.touch_key(&api_key, now).await?;
-        Ok(Some(ApiKeyLease {
-            id,
-            secret: api_key,
-        }))
+        self.lease_selected_api_key(id, api_key, now)
+            .await
+            .map(Some)
     }
 
     pub(crate) async fn has_available_active_key_excluding(
         &self,
         excluded_key_id: Option<&str>,
+        route: &KeyGroupRoute,
     ) -> Result<bool, ProxyError> {
-        self.reset_monthly().await?;
-        let month_start = start_of_month(self.backend_time.now_utc()).timestamp();
-
-        let count: i64 = if let Some(excluded_key_id) = excluded_key_id {
-            sqlx::query_scalar(
-                r#"
-                SELECT COUNT(*)
-                FROM api_keys
-                WHERE id != ? AND status = ? AND deleted_at IS NULL
-                  AND NOT EXISTS (
-                      SELECT 1
-                      FROM api_key_low_quota_depletions d
-                      WHERE d.key_id = api_keys.id AND d.month_start = ?
-                  )
-                  AND NOT EXISTS (
-                      SELECT 1
-                      FROM api_key_quarantines q
-                      WHERE q.key_id = api_keys.id AND q.cleared_at IS NULL
-                  )
-                "#,
-            )
-            .bind(excluded_key_id)
-            .bind(STATUS_ACTIVE)
-            .bind(month_start)
-            .fetch_one(&self.pool)
-            .await?
-        } else {
-            sqlx::query_scalar(
-                r#"
-                SELECT COUNT(*)
-                FROM api_keys
-                WHERE status = ? AND deleted_at IS NULL
-                  AND NOT EXISTS (
-                      SELECT 1
-                      FROM api_key_low_quota_depletions d
-                      WHERE d.key_id = api_keys.id AND d.month_start = ?
-                  )
-                  AND NOT EXISTS (
-                      SELECT 1
-                      FROM api_key_quarantines q
-                      WHERE q.key_id = api_keys.id AND q.cleared_at IS NULL
-                  )
-                "#,
-            )
-            .bind(STATUS_ACTIVE)
-            .bind(month_start)
-            .fetch_one(&self.pool)
-            .await?
-        };
-
-        Ok(count > 0)
+        self.has_available_key_with_status_excluding(STATUS_ACTIVE, excluded_key_id, route)
+            .await
     }
 
     pub(crate) async fn has_available_regular_exhausted_key_excluding(
         &self,
         excluded_key_id: Option<&str>,
+        route: &KeyGroupRoute,
+    ) -> Result<bool, ProxyError> {
+        self.has_available_key_with_status_excluding(STATUS_EXHAUSTED, excluded_key_id, route)
+            .await
+    }
+
+    async fn has_available_key_with_status_excluding(
+        &self,
+        status: &str,
+        excluded_key_id: Option<&str>,
+        route: &KeyGroupRoute,
     ) -> Result<bool, ProxyError> {
         self.reset_monthly().await?;
         let month_start = start_of_month(self.backend_time.now_utc()).timestamp();
 
-        let count: i64 = if let Some(excluded_key_id) = excluded_key_id {
-            sqlx::query_scalar(
-                r#"
-                SELECT COUNT(*)
-                FROM api_keys
-                WHERE id != ? AND status = ? AND deleted_at IS NULL
-                  AND NOT EXISTS (
-                      SELECT 1
-                      FROM api_key_low_quota_depletions d
-                      WHERE d.key_id = api_keys.id AND d.month_start = ?
-                  )
-                  AND NOT EXISTS (
-                      SELECT 1
-                      FROM api_key_quarantines q
-                      WHERE q.key_id = api_keys.id AND q.cleared_at IS NULL
-                  )
-                "#,
-            )
-            .bind(excluded_key_id)
-            .bind(STATUS_EXHAUSTED)
-            .bind(month_start)
-            .fetch_one(&self.pool)
-            .await?
-        } else {
-            sqlx::query_scalar(
-                r#"
-                SELECT COUNT(*)
-                FROM api_keys
-                WHERE status = ? AND deleted_at IS NULL
-                  AND NOT EXISTS (
-                      SELECT 1
-                      FROM api_key_low_quota_depletions d
-                      WHERE d.key_id = api_keys.id AND d.month_start = ?
-                  )
-                  AND NOT EXISTS (
-                      SELECT 1
-                      FROM api_key_quarantines q
-                      WHERE q.key_id = api_keys.id AND q.cleared_at IS NULL
-                  )
-                "#,
-            )
-            .bind(STATUS_EXHAUSTED)
-            .bind(month_start)
+        let mut builder = Self::selectable_api_keys_query(
+            "COUNT(*)",
+            status,
+            month_start,
+            false,
+            excluded_key_id,
+        );
+        route.push_group_filter(&mut builder, "api_keys");
+        let count = builder
+            .build_query_scalar::<i64>()
             .fetch_one(&self.pool)
-            .await?
-        };
+            .await?;
 
         Ok(count > 0)
     }
@@ -912,72 +806,39 @@ impl KeyStore {
     pub(crate) async fn acquire_active_key_excluding(
         &self,
         excluded_key_id: Option<&str>,
+        route: &KeyGroupRoute,
     ) -> Result<ApiKeyLease, ProxyError> {
         self.reset_monthly().await?;
 
         let now = self.backend_time.now_ts();
         let month_start = start_of_month(self.backend_time.now_utc()).timestamp();
-
-        let active_candidate = if let Some(excluded_key_id) = excluded_key_id {
-            sqlx::query_as::<_, (String, String)>(
-                r#"
-                SELECT id, api_key
-                FROM api_keys
-                WHERE id != ? AND status = ? AND deleted_at IS NULL
-                  AND NOT EXISTS (
-                      SELECT 1
-                      FROM api_key_low_quota_depletions d
-                      WHERE d.key_id = api_keys.id AND d.month_start = ?
-                  )
-                  AND NOT EXISTS (
-                      SELECT 1
-                      FROM api_key_quarantines q
-                      WHERE q.key_id = api_keys.id AND q.cleared_at IS NULL
-                  )
-                ORDER BY last_used_at ASC, id ASC
-                LIMIT 1
-                "#,
-            )
-            .bind(excluded_key_id)
-            .bind(STATUS_ACTIVE)
-            .bind(month_start)
-            .fetch_optional(&self.pool)
-            .await?
-        } else {
-            sqlx::query_as::<_, (String, String)>(
-                r#"
-                SELECT id, api_key
-                FROM api_keys
-                WHERE status = ? AND deleted_at IS NULL
-                  AND NOT EXISTS (
-                      SELECT 1
-                      FROM api_key_low_quota_depletions d
-                      WHERE d.key_id = api_keys.id AND d.month_start = ?
-                  )
-                  AND NOT EXISTS (
-                      SELECT 1
-                      FROM api_key_quarantines q
-                      WHERE q.key_id = api_keys.id AND q.cleared_at IS NULL
-                  )
-                ORDER BY last_used_at ASC, id ASC
-                LIMIT 1
-            "#,
-            )
-            .bind(STATUS_ACTIVE)
-            .bind(month_start)
-            .fetch_optional(&self.pool)
-            .await?
-        };
+        let selection_mode = self.key_selection_mode().await?;
+
+        let mut builder = Self::selectable_api_keys_query(
+            API_KEY_LEASE_COLUMNS,
+            STATUS_ACTIVE,
+            month_start,
+            false,
+            excluded_key_id,
+        );
+        route.push_filters(&mut builder, "api_keys");
+        route.push_headroom_order(&mut builder, "api_keys");
+        builder.push("last_used_at ASC, id ASC LIMIT ");
+        builder.push_bind(Self::key_selection_candidate_limit(selection_mode));
+        let mut active_candidates = builder
+            .build_query_as::<(String, String)>()
+            .fetch_all(&self.pool)
+            .await?;
+        route.retain_headroom(&mut active_candidates);
+        let active_candidate = self
+            .pick_key_row_for_selection_mode(selection_mode, active_candidates, now)
+            .await?;
 
         let Some((id, api_key)) = active_candidate else {
             return Err(ProxyError::NoAvailableKeys);
         };
 
-        self.touch_key(&api_key, now).await?;
-        Ok(ApiKeyLease {
-            id,
-            secret: api_key,
-        })
+        self.lease_selected_api_key(id, api_key, now).await
     }
 
     pub(crate) async fn save_research_request_affinity(
@@ -1169,13 +1030,22 @@ impl KeyStore {
     }
 
     pub(crate) async fn validate_access_token(&self, token: &str) -> Result<bool, ProxyError> {
+        Ok(self.check_access_token(token).await?.is_valid())
+    }
+
+    /// Like [`Self::validate_access_token`], but tells tokens outside their lifetime window
+    /// apart from unknown or disabled ones.
+    pub(crate) async fn check_access_token(
+        &self,
+        token: &str,
+    ) -> Result<AccessTokenValidation, ProxyError> {
         // Expect format th-<id>-<secret>
         let Some(rest) = token.strip_prefix("th-") else {
-            return Ok(false);
+            return Ok(AccessTokenValidation::Invalid);
         };
         let parts: Vec<&str> = rest.splitn(2, '-').collect();
         if parts.len() != 2 {
-            return Ok(false);
+            return Ok(AccessTokenValidation::Invalid);
         }
         let id = parts[0];
         let secret = parts[1];
@@ -1185,46 +1055,68 @@ impl KeyStore {
         const NEW_SECRET_LEN: usize = 24; // chosen to significantly raise entropy
         let secret_len_ok = secret.len() == LEGACY_SECRET_LEN || secret.len() == NEW_SECRET_LEN;
         if id.len() != 4 || !secret_len_ok {
-            return Ok(false);
+            return Ok(AccessTokenValidation::Invalid);
         }
 
         // Validation should be a pure check. Do NOT mutate usage counters here,
         // otherwise the token's total_requests will be double-counted (once here,
         // and once when we actually record the attempt). Only return whether the
-        // token exists and is enabled.
-        let row = sqlx::query_as::<_, (i64, i64)>(
-            r#"SELECT t.enabled, COALESCE(u.active, 1) AS user_active
+        // token exists, is enabled and is inside its lifetime window.
+        let row =
+            sqlx::query_as::<_, (String, Option<String>, i64, i64, Option<i64>, Option<i64>)>(
+                r#"SELECT t.secret, t.secret_hash, t.enabled, COALESCE(u.active, 1) AS user_active,
+                      t.not_before, t.expires_at
                FROM auth_tokens t
                LEFT JOIN user_token_bindings b ON b.token_id = t.id
                LEFT JOIN users u ON u.id = b.user_id
-               WHERE t.id = ? AND t.secret = ? AND t.deleted_at IS NULL
+               WHERE t.id = ? AND t.deleted_at IS NULL
                LIMIT 1"#,
-        )
-        .bind(id)
-        .bind(secret)
-        .fetch_optional(&self.pool)
-        .await?;
+            )
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+        let Some((stored_secret, secret_hash, enabled, user_active, not_before, expires_at)) = row
+        else {
+            return Ok(AccessTokenValidation::Invalid);
+        };
+        let stored = StoredAccessTokenSecret::new(id.to_string(), stored_secret, secret_hash);
+        if !(stored.matches(secret) && enabled == 1 && user_active == 1) {
+            return Ok(AccessTokenValidation::Invalid);
+        }
 
-        Ok(matches!(row, Some((enabled, user_active)) if enabled == 1 && user_active == 1))
+        Ok(AccessTokenLifetime::new(not_before, expires_at).check(self.backend_time.now_ts()))
     }
 
     pub(crate) async fn create_access_token(
         &self,
         note: Option<&str>,
+    ) -> Result<AuthTokenSecret, ProxyError> {
+        self.create_access_token_with_lifetime(note, AccessTokenLifetime::default())
+            .await
+    }
+
+    pub(crate) async fn create_access_token_with_lifetime(
+        &self,
+        note: Option<&str>,
+        lifetime: AccessTokenLifetime,
     ) -> Result<AuthTokenSecret, ProxyError> {
         const ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
         loop {
             let id = random_string(ALPHABET, 4);
             // Increase secret length to strengthen token entropy while keeping id short.
             let secret = random_string(ALPHABET, 24);
+            let (stored_secret, secret_hash) = self.access_token_secret_columns(&secret);
             let res = sqlx::query(
-                r#"INSERT INTO auth_tokens (id, secret, enabled, note, group_name, total_requests, created_at, last_used_at, deleted_at)
-                   VALUES (?, ?, 1, ?, NULL, 0, ?, NULL, NULL)"#,
+                r#"INSERT INTO auth_tokens (id, secret, secret_hash, enabled, note, group_name, total_requests, created_at, last_used_at, deleted_at, not_before, expires_at)
+                   VALUES (?, ?, ?, 1, ?, NULL, 0, ?, NULL, NULL, ?, ?)"#,
             )
             .bind(&id)
-            .bind(&secret)
+            .bind(&stored_secret)
+            .bind(&secret_hash)
             .bind(note.unwrap_or(""))
             .bind(self.backend_time.now_ts())
+            .bind(lifetime.not_before)
+            .bind(lifetime.expires_at)
             .execute(&self.pool)
             .await;
 
@@ -1251,6 +1143,23 @@ impl KeyStore {
         group: &str,
         count: usize,
         note: Option<&str>,
+    ) -> Result<Vec<AuthTokenSecret>, ProxyError> {
+        self.create_access_tokens_batch_with_lifetime(
+            group,
+            count,
+            note,
+            AccessTokenLifetime::default(),
+        )
+        .await
+    }
+
+    /// Batch variant that applies the same lifetime window to every created token.
+    pub(crate) async fn create_access_tokens_batch_with_lifetime(
+        &self,
+        group: &str,
+        count: usize,
+        note: Option<&str>,
+        lifetime: AccessTokenLifetime,
     ) -> Result<Vec<AuthTokenSecret>, ProxyError> {
         const ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
         let mut tx = self.pool.begin().await?;
@@ -1259,15 +1168,19 @@ impl KeyStore {
             loop {
                 let id = random_string(ALPHABET, 4);
                 let secret = random_string(ALPHABET, 24);
+                let (stored_secret, secret_hash) = self.access_token_secret_columns(&secret);
                 let res = sqlx::query(
-                    r#"INSERT INTO auth_tokens (id, secret, enabled, note, group_name, total_requests, created_at, last_used_at, deleted_at)
-                       VALUES (?, ?, 1, ?, ?, 0, ?, NULL, NULL)"#,
+                    r#"INSERT INTO auth_tokens (id, secret, secret_hash, enabled, note, group_name, total_requests, created_at, last_used_at, deleted_at, not_before, expires_at)
+                       VALUES (?, ?, ?, 1, ?, ?, 0, ?, NULL, NULL, ?, ?)"#,
                 )
                 .bind(&id)
-                .bind(&secret)
+                .bind(&stored_secret)
+                .bind(&secret_hash)
                 .bind(note.unwrap_or(""))
                 .bind(group)
                 .bind(self.backend_time.now_ts())
+                .bind(lifetime.not_before)
+                .bind(lifetime.expires_at)
                 .execute(&mut *tx)
                 .await;
 
@@ -1300,19 +1213,9 @@ impl KeyStore {
             .acquire()
             .await
             .expect("admin heavy read semaphore is never closed");
-        let rows = sqlx::query_as::<
-            _,
-            (
-                String,
-                i64,
-                Option<String>,
-                Option<String>,
-                i64,
-                i64,
-                Option<i64>,
-            ),
-        >(
-            r#"SELECT id, enabled, note, group_name, total_requests, created_at, last_used_at
+        let rows = sqlx::query_as::<_, AuthTokenListRow>(
+            r#"SELECT id, enabled, note, group_name, total_requests, created_at, last_used_at,
+                      not_before, expires_at, scopes
                FROM auth_tokens
                WHERE deleted_at IS NULL
                ORDER BY created_at DESC, id DESC"#,
@@ -1320,24 +1223,7 @@ impl KeyStore {
         .fetch_all(&self.pool)
         .await?;
 
-        Ok(rows
-            .into_iter()
-            .map(
-                |(id, enabled, note, group_name, total, created_at, last_used)| AuthToken {
-                    id,
-                    enabled: enabled == 1,
-                    note,
-                    group_name,
-                    total_requests: total,
-                    created_at,
-                    last_used_at: last_used,
-                    quota: None,
-                    quota_hourly_reset_at: None,
-                    quota_daily_reset_at: None,
-                    quota_monthly_reset_at: None,
-                },
-            )
-            .collect())
+        Ok(rows.into_iter().map(Self::auth_token_from_row).collect())
     }
 
     pub(crate) async fn list_disabled_access_tokens(
@@ -1345,19 +1231,9 @@ impl KeyStore {
         limit: usize,
     ) -> Result<Vec<AuthToken>, ProxyError> {
         let limit = limit.clamp(1, 100) as i64;
-        let rows = sqlx::query_as::<
-            _,
-            (
-                String,
-                i64,
-                Option<String>,
-                Option<String>,
-                i64,
-                i64,
-                Option<i64>,
-            ),
-        >(
-            r#"SELECT id, enabled, note, group_name, total_requests, created_at, last_used_at
+        let rows = sqlx::query_as::<_, AuthTokenListRow>(
+            r#"SELECT id, enabled, note, group_name, total_requests, created_at, last_used_at,
+                      not_before, expires_at, scopes
                FROM auth_tokens
                WHERE deleted_at IS NULL AND enabled = 0
                ORDER BY created_at DESC, id DESC
@@ -1367,24 +1243,7 @@ impl KeyStore {
         .fetch_all(&self.pool)
         .await?;
 
-        Ok(rows
-            .into_iter()
-            .map(
-                |(id, enabled, note, group_name, total, created_at, last_used)| AuthToken {
-                    id,
-                    enabled: enabled == 1,
-                    note,
-                    group_name,
-                    total_requests: total,
-                    created_at,
-                    last_used_at: last_used,
-                    quota: None,
-                    quota_hourly_reset_at: None,
-                    quota_daily_reset_at: None,
-                    quota_monthly_reset_at: None,
-                },
-            )
-            .collect())
+        Ok(rows.into_iter().map(Self::auth_token_from_row).collect())
     }
 
     pub(crate) async fn list_disabled_access_token_ids(
@@ -1445,16 +1304,16 @@ impl KeyStore {
         &self,
         id: &str,
     ) -> Result<Option<AuthTokenSecret>, ProxyError> {
-        let row = sqlx::query_as::<_, (String,)>(
-            "SELECT secret FROM auth_tokens WHERE id = ? AND deleted_at IS NULL LIMIT 1",
+        let row = sqlx::query_as::<_, (String, Option<String>)>(
+            "SELECT secret, secret_hash FROM auth_tokens WHERE id = ? AND deleted_at IS NULL LIMIT 1",
         )
         .bind(id)
         .fetch_optional(&self.pool)
         .await?;
-        Ok(row.map(|(secret,)| AuthTokenSecret {
-            id: id.to_string(),
-            token: Self::compose_full_token(id, &secret),
-        }))
+        row.map(|(secret, secret_hash)| {
+            StoredAccessTokenSecret::new(id.to_string(), secret, secret_hash).reveal()
+        })
+        .transpose()
     }
 
     /// Update the secret for an existing token id and return the new full token string.
@@ -1476,10 +1335,14 @@ impl KeyStore {
         // Generate a new secret with the current strong length
         const ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
         let new_secret = random_string(ALPHABET, 24);
+        let (stored_secret, secret_hash) = self.access_token_secret_columns(&new_secret);
 
-        sqlx::query("UPDATE auth_tokens SET secret = ? WHERE id = ? AND deleted_at IS NULL")
-            .bind(&new_secret)
-            .bind(id)
+        sqlx::query(
+            "UPDATE auth_tokens SET secret = ?, secret_hash = ? WHERE id = ? AND deleted_at IS NULL",
+        )
+        .bind(&stored_secret)
+        .bind(&secret_hash)
+        .bind(id)
             .execute(&self.pool)
             .await?;
 
@@ -1493,19 +1356,9 @@ impl KeyStore {
         &self,
         user_id: &str,
     ) -> Result<Vec<AuthToken>, ProxyError> {
-        let rows = sqlx::query_as::<
-            _,
-            (
-                String,
-                i64,
-                Option<String>,
-                Option<String>,
-                i64,
-                i64,
-                Option<i64>,
-            ),
-        >(
-            r#"SELECT t.id, t.enabled, t.note, t.group_name, t.total_requests, t.created_at, t.last_used_at
+        let rows = sqlx::query_as::<_, AuthTokenListRow>(
+            r#"SELECT t.id, t.enabled, t.note, t.group_name, t.total_requests, t.created_at, t.last_used_at,
+                      t.not_before, t.expires_at, t.scopes
                FROM user_token_bindings b
                JOIN auth_tokens t ON t.id = b.token_id
                WHERE b.user_id = ? AND t.deleted_at IS NULL
@@ -1514,24 +1367,7 @@ impl KeyStore {
         .bind(user_id)
         .fetch_all(&self.pool)
         .await?;
-        Ok(rows
-            .into_iter()
-            .map(
-                |(id, enabled, note, group_name, total, created_at, last_used_at)| AuthToken {
-                    id,
-                    enabled: enabled == 1,
-                    note,
-                    group_name,
-                    total_requests: total,
-                    created_at,
-                    last_used_at,
-                    quota: None,
-                    quota_hourly_reset_at: None,
-                    quota_daily_reset_at: None,
-                    quota_monthly_reset_at: None,
-                },
-            )
-            .collect())
+        Ok(rows.into_iter().map(Self::auth_token_from_row).collect())
     }
 
     pub(crate) async fn is_user_token_bound(
@@ -1585,8 +1421,8 @@ impl KeyStore {
         user_id: &str,
         token_id: &str,
     ) -> Result<Option<AuthTokenSecret>, ProxyError> {
-        let row = sqlx::query_as::<_, (String,)>(
-            r#"SELECT t.secret
+        let row = sqlx::query_as::<_, (String, Option<String>)>(
+            r#"SELECT t.secret, t.secret_hash
                FROM user_token_bindings b
                JOIN auth_tokens t ON t.id = b.token_id
                WHERE b.user_id = ? AND b.token_id = ? AND t.deleted_at IS NULL AND t.enabled = 1
@@ -1596,10 +1432,10 @@ impl KeyStore {
         .bind(token_id)
         .fetch_optional(&self.pool)
         .await?;
-        Ok(row.map(|(secret,)| AuthTokenSecret {
-            id: token_id.to_string(),
-            token: Self::compose_full_token(token_id, &secret),
-        }))
+        row.map(|(secret, secret_hash)| {
+            StoredAccessTokenSecret::new(token_id.to_string(), secret, secret_hash).reveal()
+        })
+        .transpose()
     }
 
     #[allow(dead_code)]
@@ -1265,6 +1265,10 @@ impl KeyStore {
                 "body_cleaned_at",
                 "ALTER TABLE observability.request_logs ADD COLUMN body_cleaned_at INTEGER",
             ),
+            (
+                "trace_id",
+                "ALTER TABLE observability.request_logs ADD COLUMN trace_id TEXT",
+            ),
         ] {
             if !self.request_logs_column_exists(column).await? {
                 sqlx::query(sql).execute(&self.pool).await?;
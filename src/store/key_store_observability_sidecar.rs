@@ -211,6 +211,7 @@ impl KeyStore {
                 client_ip_source TEXT,
                 client_ip_trusted INTEGER NOT NULL DEFAULT 0,
                 ip_headers TEXT,
+                trace_id TEXT,
                 visibility TEXT NOT NULL DEFAULT 'visible',
                 created_at INTEGER NOT NULL
             )
@@ -1298,7 +1299,10 @@ impl KeyStore {
             request_log_diagnostic_handoff: Mutex::new(RequestLogDiagnosticHandoff::default()),
             user_debug_info_shared_cache: RwLock::new(HashMap::new()),
             request_stats_coalescer: RequestStatsCoalescer::default(),
+            process_metrics: ProcessMetrics::default(),
             admin_heavy_read_semaphore: Semaphore::new(ADMIN_HEAVY_READ_CONCURRENCY),
+            api_key_secret_cipher: StdOnceLock::new(),
+            access_token_secret_hashing: std::sync::atomic::AtomicBool::new(false),
             #[cfg(test)]
             forced_pending_claim_miss_log_ids: Mutex::new(HashSet::new()),
             #[cfg(debug_assertions)]
@@ -0,0 +1,152 @@
+/// Upper bounds, in seconds, of the upstream latency histogram buckets.
+pub(crate) const UPSTREAM_LATENCY_BUCKETS_SECS: [f64; 10] =
+    [0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 120.0];
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct LatencyHistogram {
+    /// Non-cumulative counts per bucket of `UPSTREAM_LATENCY_BUCKETS_SECS`.
+    pub(crate) buckets: [u64; UPSTREAM_LATENCY_BUCKETS_SECS.len()],
+    pub(crate) count: u64,
+    pub(crate) sum_secs: f64,
+}
+
+/// Process-lifetime counters behind the Prometheus endpoint. They start from zero on every
+/// restart, which `rate()` and `increase()` already account for.
+#[derive(Debug, Default)]
+pub(crate) struct ProcessMetrics {
+    /// Logged requests by `(request kind, outcome)`.
+    requests: StdMutex<BTreeMap<(String, String), u64>>,
+    /// Upstream round trips by `(operation, result)`.
+    upstream_latency: StdMutex<BTreeMap<(String, &'static str), LatencyHistogram>>,
+}
+
+impl ProcessMetrics {
+    pub(crate) fn record_request(&self, request_kind: &str, outcome: &str) {
+        let mut requests = self
+            .requests
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        *requests
+            .entry((
+                canonical_request_kind_key_for_filter(request_kind),
+                outcome.to_string(),
+            ))
+            .or_default() += 1;
+    }
+
+    pub(crate) fn observe_upstream_latency(
+        &self,
+        operation: &str,
+        result: &'static str,
+        elapsed: Duration,
+    ) {
+        let secs = elapsed.as_secs_f64();
+        let mut upstream_latency = self
+            .upstream_latency
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let histogram = upstream_latency
+            .entry((operation.to_string(), result))
+            .or_default();
+        if let Some(bucket) = UPSTREAM_LATENCY_BUCKETS_SECS
+            .iter()
+            .position(|upper| secs <= *upper)
+        {
+            histogram.buckets[bucket] += 1;
+        }
+        histogram.count += 1;
+        histogram.sum_secs += secs;
+    }
+
+    pub(crate) fn requests_snapshot(&self) -> Vec<((String, String), u64)> {
+        self.requests
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .iter()
+            .map(|(labels, count)| (labels.clone(), *count))
+            .collect()
+    }
+
+    pub(crate) fn upstream_latency_snapshot(
+        &self,
+    ) -> Vec<((String, &'static str), LatencyHistogram)> {
+        self.upstream_latency
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .iter()
+            .map(|(labels, histogram)| (labels.clone(), histogram.clone()))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub(crate) struct MetricsApiKeyRow {
+    pub(crate) id: String,
+    pub(crate) group_name: Option<String>,
+    pub(crate) status: String,
+    pub(crate) quota_limit: Option<i64>,
+    pub(crate) quota_remaining: Option<i64>,
+    pub(crate) quarantined: bool,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub(crate) struct MetricsScheduledJobRow {
+    pub(crate) job_type: String,
+    pub(crate) status: String,
+    pub(crate) runs: i64,
+    pub(crate) last_finished_at: Option<i64>,
+}
+
+impl KeyStore {
+    pub(crate) async fn metrics_api_key_rows(&self) -> Result<Vec<MetricsApiKeyRow>, ProxyError> {
+        Ok(sqlx::query_as::<_, MetricsApiKeyRow>(
+            r#"
+            SELECT k.id, k.group_name, k.status, k.quota_limit, k.quota_remaining,
+                   EXISTS (
+                       SELECT 1 FROM api_key_quarantines q
+                       WHERE q.key_id = k.id AND q.cleared_at IS NULL
+                   ) AS quarantined
+            FROM api_keys k
+            WHERE k.deleted_at IS NULL
+            ORDER BY k.id
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?)
+    }
+
+    /// Active quarantines grouped by reason code.
+    pub(crate) async fn metrics_quarantine_counts(&self) -> Result<Vec<(String, i64)>, ProxyError> {
+        Ok(sqlx::query_as::<_, (String, i64)>(
+            r#"
+            SELECT q.reason_code, COUNT(*)
+            FROM api_key_quarantines q
+            JOIN api_keys k ON k.id = q.key_id
+            WHERE q.cleared_at IS NULL AND k.deleted_at IS NULL
+            GROUP BY q.reason_code
+            ORDER BY q.reason_code
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?)
+    }
+
+    /// Scheduled job runs that finished at or after `since`, by job type and final status.
+    pub(crate) async fn metrics_scheduled_job_rows(
+        &self,
+        since: i64,
+    ) -> Result<Vec<MetricsScheduledJobRow>, ProxyError> {
+        Ok(sqlx::query_as::<_, MetricsScheduledJobRow>(
+            r#"
+            SELECT job_type, status, COUNT(*) AS runs, MAX(finished_at) AS last_finished_at
+            FROM scheduled_jobs
+            WHERE finished_at IS NOT NULL AND finished_at >= ?
+            GROUP BY job_type, status
+            ORDER BY job_type, status
+            "#,
+        )
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await?)
+    }
+}
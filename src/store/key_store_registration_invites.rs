@@ -0,0 +1,375 @@
+const REGISTRATION_INVITE_COLUMNS: &str = "id, code, note, max_uses, use_count, expires_at, \
+     tag_ids, quota, created_at, updated_at, revoked_at";
+
+fn registration_invite_from_row(
+    row: sqlx::sqlite::SqliteRow,
+) -> Result<RegistrationInvite, sqlx::Error> {
+    let tag_ids: String = row.try_get("tag_ids")?;
+    let quota: Option<String> = row.try_get("quota")?;
+    Ok(RegistrationInvite {
+        id: row.try_get("id")?,
+        code: row.try_get("code")?,
+        note: row.try_get("note")?,
+        max_uses: row.try_get("max_uses")?,
+        use_count: row.try_get("use_count")?,
+        expires_at: row.try_get("expires_at")?,
+        tag_ids: serde_json::from_str(&tag_ids).unwrap_or_default(),
+        quota: quota.and_then(|raw| serde_json::from_str(&raw).ok()),
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+        revoked_at: row.try_get("revoked_at")?,
+    })
+}
+
+impl KeyStore {
+    pub(crate) async fn ensure_registration_invites_schema(&self) -> Result<(), ProxyError> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS registration_invites (
+                id TEXT PRIMARY KEY,
+                code TEXT NOT NULL UNIQUE,
+                note TEXT,
+                max_uses INTEGER NOT NULL,
+                use_count INTEGER NOT NULL DEFAULT 0,
+                expires_at INTEGER,
+                tag_ids TEXT NOT NULL DEFAULT '[]',
+                quota TEXT,
+                created_at INTEGER NOT NULL,
+                updated_at INTEGER NOT NULL,
+                revoked_at INTEGER
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS registration_invite_redemptions (
+                invite_id TEXT NOT NULL,
+                user_id TEXT NOT NULL,
+                redeemed_at INTEGER NOT NULL,
+                PRIMARY KEY (invite_id, user_id)
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+        // One row per account registered while approval was required; `approved_at` stays NULL
+        // until an admin lets the account in.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS user_registration_approvals (
+                user_id TEXT PRIMARY KEY,
+                requested_at INTEGER NOT NULL,
+                approved_at INTEGER
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+        sqlx::query(
+            r#"CREATE INDEX IF NOT EXISTS idx_user_registration_approvals_pending
+               ON user_registration_approvals(requested_at)
+               WHERE approved_at IS NULL"#,
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub(crate) async fn registration_requires_approval(&self) -> Result<bool, ProxyError> {
+        Ok(self
+            .get_meta_i64(META_KEY_REGISTRATION_REQUIRES_APPROVAL_V1)
+            .await?
+            .unwrap_or(0)
+            != 0)
+    }
+
+    pub(crate) async fn set_registration_requires_approval(
+        &self,
+        required: bool,
+    ) -> Result<bool, ProxyError> {
+        self.set_meta_i64(
+            META_KEY_REGISTRATION_REQUIRES_APPROVAL_V1,
+            if required { 1 } else { 0 },
+        )
+        .await?;
+        Ok(required)
+    }
+
+    /// Returns `None` when the requested code is already taken.
+    pub(crate) async fn create_registration_invite(
+        &self,
+        input: &RegistrationInviteInput,
+    ) -> Result<Option<RegistrationInvite>, ProxyError> {
+        const ID_ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+        const CODE_ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+        let now = self.backend_time.now_ts();
+        let requested_code = input
+            .code
+            .as_deref()
+            .map(normalize_registration_invite_code)
+            .transpose()
+            .map_err(ProxyError::Other)?;
+        let note = input
+            .note
+            .as_deref()
+            .map(str::trim)
+            .filter(|note| !note.is_empty());
+        let tag_ids = serde_json::to_string(&input.tag_ids)
+            .map_err(|err| ProxyError::Other(err.to_string()))?;
+        let quota = input
+            .quota
+            .map(|quota| serde_json::to_string(&quota))
+            .transpose()
+            .map_err(|err| ProxyError::Other(err.to_string()))?;
+
+        for _ in 0..8 {
+            let id = random_string(ID_ALPHABET, 16);
+            let code = requested_code
+                .clone()
+                .unwrap_or_else(|| random_string(CODE_ALPHABET, 12));
+            let inserted = sqlx::query(
+                r#"INSERT INTO registration_invites
+                   (id, code, note, max_uses, use_count, expires_at, tag_ids, quota,
+                    created_at, updated_at, revoked_at)
+                   VALUES (?, ?, ?, ?, 0, ?, ?, ?, ?, ?, NULL)"#,
+            )
+            .bind(&id)
+            .bind(&code)
+            .bind(note)
+            .bind(input.max_uses)
+            .bind(input.expires_at)
+            .bind(&tag_ids)
+            .bind(quota.as_deref())
+            .bind(now)
+            .bind(now)
+            .execute(&self.pool)
+            .await;
+            match inserted {
+                Ok(_) => return self.fetch_registration_invite(&id).await,
+                Err(sqlx::Error::Database(db_err)) if db_err.is_unique_violation() => {
+                    if requested_code.is_some() {
+                        return Ok(None);
+                    }
+                }
+                Err(err) => return Err(ProxyError::Database(err)),
+            }
+        }
+        Err(ProxyError::Other(
+            "failed to allocate a unique invite code".to_string(),
+        ))
+    }
+
+    pub(crate) async fn fetch_registration_invite(
+        &self,
+        invite_id: &str,
+    ) -> Result<Option<RegistrationInvite>, ProxyError> {
+        let row = sqlx::query(&format!(
+            "SELECT {REGISTRATION_INVITE_COLUMNS} FROM registration_invites WHERE id = ?"
+        ))
+        .bind(invite_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row.map(registration_invite_from_row).transpose()?)
+    }
+
+    pub(crate) async fn list_registration_invites(
+        &self,
+    ) -> Result<Vec<RegistrationInvite>, ProxyError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {REGISTRATION_INVITE_COLUMNS} FROM registration_invites \
+             ORDER BY created_at DESC, id DESC"
+        ))
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(registration_invite_from_row)
+            .collect::<Result<_, _>>()?)
+    }
+
+    /// Revoking is idempotent and keeps the row so past redemptions stay attributable.
+    pub(crate) async fn revoke_registration_invite(
+        &self,
+        invite_id: &str,
+    ) -> Result<Option<RegistrationInvite>, ProxyError> {
+        let now = self.backend_time.now_ts();
+        sqlx::query(
+            r#"UPDATE registration_invites
+               SET revoked_at = COALESCE(revoked_at, ?), updated_at = ?
+               WHERE id = ?"#,
+        )
+        .bind(now)
+        .bind(now)
+        .bind(invite_id)
+        .execute(&self.pool)
+        .await?;
+        self.fetch_registration_invite(invite_id).await
+    }
+
+    /// Atomically takes one use of an invite. Returns `None` when the code is unknown, revoked,
+    /// expired or used up, so concurrent logins can never exceed `max_uses`.
+    pub(crate) async fn claim_registration_invite(
+        &self,
+        raw_code: &str,
+    ) -> Result<Option<RegistrationInvite>, ProxyError> {
+        let Ok(code) = normalize_registration_invite_code(raw_code) else {
+            return Ok(None);
+        };
+        let now = self.backend_time.now_ts();
+        let mut tx = self.pool.begin().await?;
+        let claimed = sqlx::query(
+            r#"UPDATE registration_invites
+               SET use_count = use_count + 1, updated_at = ?
+               WHERE code = ?
+                 AND revoked_at IS NULL
+                 AND (expires_at IS NULL OR expires_at > ?)
+                 AND use_count < max_uses"#,
+        )
+        .bind(now)
+        .bind(&code)
+        .bind(now)
+        .execute(&mut *tx)
+        .await?;
+        if claimed.rows_affected() == 0 {
+            tx.rollback().await.ok();
+            return Ok(None);
+        }
+        let row = sqlx::query(&format!(
+            "SELECT {REGISTRATION_INVITE_COLUMNS} FROM registration_invites WHERE code = ?"
+        ))
+        .bind(&code)
+        .fetch_one(&mut *tx)
+        .await?;
+        let invite = registration_invite_from_row(row)?;
+        tx.commit().await?;
+        Ok(Some(invite))
+    }
+
+    /// Applies an invite's pre-assigned tags and quota to the account it registered. Tags that
+    /// were deleted or turned into system tags since the invite was issued are skipped.
+    pub(crate) async fn apply_registration_invite(
+        &self,
+        invite: &RegistrationInvite,
+        user_id: &str,
+    ) -> Result<(), ProxyError> {
+        for tag_id in &invite.tag_ids {
+            match self.fetch_user_tag_by_id(tag_id).await? {
+                Some(tag) if !tag.is_system() => {
+                    self.bind_user_tag_to_user(user_id, tag_id).await?;
+                }
+                _ => {}
+            }
+        }
+        if let Some(quota) = invite.quota {
+            self.update_account_quota_limits(
+                user_id,
+                quota.business_calls_1h_limit,
+                quota.daily_credits_limit,
+                quota.monthly_credits_limit,
+            )
+            .await?;
+        }
+        sqlx::query(
+            r#"INSERT INTO registration_invite_redemptions (invite_id, user_id, redeemed_at)
+               VALUES (?, ?, ?)
+               ON CONFLICT(invite_id, user_id) DO NOTHING"#,
+        )
+        .bind(&invite.id)
+        .bind(user_id)
+        .bind(self.backend_time.now_ts())
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub(crate) async fn mark_user_pending_approval(&self, user_id: &str) -> Result<(), ProxyError> {
+        sqlx::query(
+            r#"INSERT INTO user_registration_approvals (user_id, requested_at, approved_at)
+               VALUES (?, ?, NULL)
+               ON CONFLICT(user_id) DO NOTHING"#,
+        )
+        .bind(user_id)
+        .bind(self.backend_time.now_ts())
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub(crate) async fn user_pending_approval(&self, user_id: &str) -> Result<bool, ProxyError> {
+        let pending = sqlx::query_scalar::<_, i64>(
+            r#"SELECT COUNT(*) FROM user_registration_approvals
+               WHERE user_id = ? AND approved_at IS NULL"#,
+        )
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(pending > 0)
+    }
+
+    pub(crate) async fn pending_approval_user_ids(
+        &self,
+        user_ids: &[String],
+    ) -> Result<HashSet<String>, ProxyError> {
+        if user_ids.is_empty() {
+            return Ok(HashSet::new());
+        }
+        let mut builder = QueryBuilder::new(
+            "SELECT user_id FROM user_registration_approvals WHERE approved_at IS NULL AND user_id IN (",
+        );
+        let mut separated = builder.separated(", ");
+        for user_id in user_ids {
+            separated.push_bind(user_id);
+        }
+        separated.push_unseparated(")");
+        let rows = builder
+            .build_query_scalar::<String>()
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows.into_iter().collect())
+    }
+
+    pub(crate) async fn list_pending_approval_users(
+        &self,
+    ) -> Result<Vec<PendingApprovalUser>, ProxyError> {
+        let rows = sqlx::query(
+            r#"SELECT a.user_id, u.display_name, u.username, a.requested_at,
+                      (SELECT o.provider FROM oauth_accounts o
+                       WHERE o.user_id = a.user_id
+                       ORDER BY o.created_at ASC
+                       LIMIT 1) AS provider
+               FROM user_registration_approvals a
+               JOIN users u ON u.id = a.user_id
+               WHERE a.approved_at IS NULL
+               ORDER BY a.requested_at ASC, a.user_id ASC"#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        rows.into_iter()
+            .map(|row| {
+                Ok(PendingApprovalUser {
+                    user_id: row.try_get("user_id")?,
+                    display_name: row.try_get("display_name")?,
+                    username: row.try_get("username")?,
+                    provider: row.try_get("provider")?,
+                    requested_at: row.try_get("requested_at")?,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns `false` when the user was not waiting for approval.
+    pub(crate) async fn approve_user_registration(&self, user_id: &str) -> Result<bool, ProxyError> {
+        let approved = sqlx::query(
+            r#"UPDATE user_registration_approvals
+               SET approved_at = ?
+               WHERE user_id = ? AND approved_at IS NULL"#,
+        )
+        .bind(self.backend_time.now_ts())
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+        Ok(approved.rows_affected() > 0)
+    }
+}
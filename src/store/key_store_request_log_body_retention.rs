@@ -1,3 +1,36 @@
+/// zstd frame magic. Valid UTF-8 request/response bodies never start with these bytes, so a
+/// stored body that does is a compressed transcript rather than a raw upstream payload.
+const REQUEST_LOG_BODY_ZSTD_MAGIC: [u8; 4] = [0x28, 0xB5, 0x2F, 0xFD];
+/// Decompression bound for stored request-log bodies.
+const REQUEST_LOG_BODY_DECODED_MAX_BYTES: u64 = 4 * 1024 * 1024;
+
+/// Compress a request-log body (for example a streamed research transcript) with zstd.
+pub(crate) fn compress_request_log_body(raw: &[u8]) -> Vec<u8> {
+    if raw.is_empty() {
+        return Vec::new();
+    }
+    zstd::encode_all(raw, 3).unwrap_or_else(|_| raw.to_vec())
+}
+
+/// Return the readable form of a stored request-log body, expanding zstd-compressed
+/// transcripts and passing raw bodies through unchanged.
+pub(crate) fn decode_request_log_body(stored: Vec<u8>) -> Vec<u8> {
+    if !stored.starts_with(&REQUEST_LOG_BODY_ZSTD_MAGIC) {
+        return stored;
+    }
+    let mut decoded = Vec::new();
+    let result = zstd::stream::read::Decoder::new(stored.as_slice()).and_then(|decoder| {
+        std::io::Read::read_to_end(
+            &mut std::io::Read::take(decoder, REQUEST_LOG_BODY_DECODED_MAX_BYTES),
+            &mut decoded,
+        )
+    });
+    match result {
+        Ok(_) => decoded,
+        Err(_) => stored,
+    }
+}
+
 impl KeyStore {
     fn request_log_body_days_for_profile(
         profile: &RequestLogRetentionProfile,
@@ -238,6 +271,12 @@ impl KeyStore {
     }
 
     pub(crate) async fn log_attempt(&self, entry: AttemptLog<'_>) -> Result<i64, ProxyError> {
+        self.insert_attempt_log(entry)
+            .instrument(tracing::info_span!("log_persist"))
+            .await
+    }
+
+    async fn insert_attempt_log(&self, entry: AttemptLog<'_>) -> Result<i64, ProxyError> {
         let created_at = self.backend_time.now_ts();
         let status_code = entry.status.map(|code| code.as_u16() as i64);
         let failure_kind = entry.failure_kind.map(str::to_string).or_else(|| {
@@ -398,8 +437,9 @@ impl KeyStore {
                 client_ip_source,
                 client_ip_trusted,
                 ip_headers,
+                trace_id,
                 created_at
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             RETURNING id
             "#,
         )
@@ -448,9 +488,12 @@ impl KeyStore {
         .bind(client_ip_source)
         .bind(client_ip_trusted)
         .bind(ip_headers_json)
+        .bind(crate::request_tracing::current_request_trace_id())
         .bind(created_at)
         .fetch_one(&self.pool)
         .await?;
+        self.process_metrics
+            .record_request(&request_kind.key, entry.outcome);
         if entry.auth_token_id.is_some() {
             self.request_log_diagnostic_handoff.lock().await.insert(
                 request_log_id,
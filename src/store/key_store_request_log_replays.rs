@@ -0,0 +1,157 @@
+const REQUEST_LOG_REPLAY_COLUMNS: &str = "id, source_log_id, api_key_id, upstream_base, method, \
+     path, status_code, duration_ms, request_body, response_body, error_message, created_at";
+/// Replays kept per source log; older ones are pruned when a new replay is recorded.
+const REQUEST_LOG_REPLAYS_PER_LOG: i64 = 20;
+
+fn request_log_replay_from_row(
+    row: sqlx::sqlite::SqliteRow,
+) -> Result<RequestLogReplay, sqlx::Error> {
+    Ok(RequestLogReplay {
+        id: row.try_get("id")?,
+        source_log_id: row.try_get("source_log_id")?,
+        api_key_id: row.try_get("api_key_id")?,
+        upstream_base: row.try_get("upstream_base")?,
+        method: row.try_get("method")?,
+        path: row.try_get("path")?,
+        status_code: row.try_get("status_code")?,
+        duration_ms: row.try_get("duration_ms")?,
+        request_body: row.try_get("request_body")?,
+        response_body: row.try_get("response_body")?,
+        error_message: row.try_get("error_message")?,
+        created_at: row.try_get("created_at")?,
+    })
+}
+
+impl KeyStore {
+    pub(crate) async fn ensure_request_log_replay_schema(&self) -> Result<(), ProxyError> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS observability.request_log_replays (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                source_log_id INTEGER NOT NULL,
+                api_key_id TEXT NOT NULL,
+                upstream_base TEXT NOT NULL,
+                method TEXT NOT NULL,
+                path TEXT NOT NULL,
+                status_code INTEGER,
+                duration_ms INTEGER NOT NULL DEFAULT 0,
+                request_body BLOB,
+                response_body BLOB,
+                error_message TEXT,
+                created_at INTEGER NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+        sqlx::query(
+            r#"CREATE INDEX IF NOT EXISTS observability.idx_request_log_replays_source
+               ON request_log_replays(source_log_id, id DESC)"#,
+        )
+        .execute(&self.pool)
+        .await?;
+        // Replays carry request/response bodies, so they must not outlive the log they replay.
+        sqlx::query(
+            r#"CREATE TRIGGER IF NOT EXISTS observability.trg_request_log_replays_source_deleted
+               AFTER DELETE ON request_logs
+               BEGIN
+                   DELETE FROM request_log_replays WHERE source_log_id = OLD.id;
+               END"#,
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub(crate) async fn fetch_request_log_replay_source(
+        &self,
+        log_id: i64,
+    ) -> Result<Option<RequestLogReplaySource>, ProxyError> {
+        let row = sqlx::query(
+            r#"SELECT api_key_id, method, path, query, request_body
+               FROM observability.request_logs
+               WHERE id = ? AND visibility = ?
+               LIMIT 1"#,
+        )
+        .bind(log_id)
+        .bind(REQUEST_LOG_VISIBILITY_VISIBLE)
+        .fetch_optional(&self.pool)
+        .await?;
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let request_body: Option<Vec<u8>> = row.try_get("request_body")?;
+        Ok(Some(RequestLogReplaySource {
+            log_id,
+            api_key_id: row.try_get("api_key_id")?,
+            method: row.try_get("method")?,
+            path: row.try_get("path")?,
+            query: row.try_get("query")?,
+            request_body: request_body.map(decode_request_log_body),
+        }))
+    }
+
+    pub(crate) async fn insert_request_log_replay(
+        &self,
+        replay: &RequestLogReplay,
+    ) -> Result<RequestLogReplay, ProxyError> {
+        let mut tx = self.pool.begin().await?;
+        let row = sqlx::query(&format!(
+            r#"INSERT INTO observability.request_log_replays (
+                   source_log_id, api_key_id, upstream_base, method, path, status_code,
+                   duration_ms, request_body, response_body, error_message, created_at
+               ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+               RETURNING {REQUEST_LOG_REPLAY_COLUMNS}"#
+        ))
+        .bind(replay.source_log_id)
+        .bind(&replay.api_key_id)
+        .bind(&replay.upstream_base)
+        .bind(&replay.method)
+        .bind(&replay.path)
+        .bind(replay.status_code)
+        .bind(replay.duration_ms)
+        .bind(replay.request_body.as_deref())
+        .bind(replay.response_body.as_deref())
+        .bind(replay.error_message.as_deref())
+        .bind(replay.created_at)
+        .fetch_one(&mut *tx)
+        .await?;
+        let stored = request_log_replay_from_row(row)?;
+        sqlx::query(
+            r#"DELETE FROM observability.request_log_replays
+               WHERE source_log_id = ?
+                 AND id NOT IN (
+                     SELECT id FROM observability.request_log_replays
+                     WHERE source_log_id = ?
+                     ORDER BY id DESC
+                     LIMIT ?
+                 )"#,
+        )
+        .bind(replay.source_log_id)
+        .bind(replay.source_log_id)
+        .bind(REQUEST_LOG_REPLAYS_PER_LOG)
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(stored)
+    }
+
+    pub(crate) async fn list_request_log_replays(
+        &self,
+        source_log_id: i64,
+    ) -> Result<Vec<RequestLogReplay>, ProxyError> {
+        let rows = sqlx::query(&format!(
+            r#"SELECT {REQUEST_LOG_REPLAY_COLUMNS}
+               FROM observability.request_log_replays
+               WHERE source_log_id = ?
+               ORDER BY id DESC"#
+        ))
+        .bind(source_log_id)
+        .fetch_all(&self.pool)
+        .await?;
+        rows.into_iter()
+            .map(request_log_replay_from_row)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(ProxyError::from)
+    }
+}
@@ -0,0 +1,213 @@
+const REQUEST_LOG_SEARCH_SLICE_ROWS: i64 = 200;
+/// Only the head of each body is indexed; large transcripts stay searchable by their opening
+/// arguments without multiplying the sidecar size.
+const REQUEST_LOG_SEARCH_BODY_MAX_BYTES: usize = 64 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RequestLogSearchSliceOutcome {
+    Advanced { rows: i64, complete: bool },
+    Idle,
+    Deferred { reason: SqliteAdmissionDeferReason },
+}
+
+type RequestLogSearchSourceRow = (
+    i64,
+    Option<String>,
+    Option<String>,
+    Option<Vec<u8>>,
+    Option<Vec<u8>>,
+);
+
+fn request_log_search_body_text(body: Option<Vec<u8>>) -> String {
+    let Some(body) = body else {
+        return String::new();
+    };
+    let decoded = decode_request_log_body(body);
+    let head = &decoded[..decoded.len().min(REQUEST_LOG_SEARCH_BODY_MAX_BYTES)];
+    String::from_utf8_lossy(head).into_owned()
+}
+
+impl KeyStore {
+    pub(crate) async fn ensure_request_log_search_schema(&self) -> Result<(), ProxyError> {
+        // Contentless: the index never holds a second copy of the bodies, and rows can still be
+        // deleted when retention drops a log or strips its bodies.
+        sqlx::query(
+            r#"CREATE VIRTUAL TABLE IF NOT EXISTS observability.request_log_search USING fts5(
+                query_text,
+                error_text,
+                request_text,
+                response_text,
+                content = '',
+                contentless_delete = 1,
+                tokenize = 'trigram'
+            )"#,
+        )
+        .execute(&self.pool)
+        .await?;
+        sqlx::query(
+            r#"CREATE TABLE IF NOT EXISTS observability.request_log_search_state (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                indexed_through_id INTEGER NOT NULL DEFAULT 0,
+                updated_at INTEGER NOT NULL DEFAULT 0
+            )"#,
+        )
+        .execute(&self.pool)
+        .await?;
+        sqlx::query("INSERT OR IGNORE INTO observability.request_log_search_state (id) VALUES (1)")
+            .execute(&self.pool)
+            .await?;
+        // Retention runs in several code paths (and in the standalone GC), so the index follows
+        // it through triggers instead of every caller remembering to touch it.
+        sqlx::query(
+            r#"CREATE TRIGGER IF NOT EXISTS observability.trg_request_log_search_deleted
+               AFTER DELETE ON request_logs
+               BEGIN
+                   DELETE FROM request_log_search WHERE rowid = OLD.id;
+               END"#,
+        )
+        .execute(&self.pool)
+        .await?;
+        sqlx::query(
+            r#"CREATE TRIGGER IF NOT EXISTS observability.trg_request_log_search_body_cleaned
+               AFTER UPDATE OF request_body, response_body ON request_logs
+               WHEN NEW.request_body IS NULL AND NEW.response_body IS NULL
+                    AND (OLD.request_body IS NOT NULL OR OLD.response_body IS NOT NULL)
+                    AND OLD.id <= (SELECT indexed_through_id FROM request_log_search_state WHERE id = 1)
+               BEGIN
+                   DELETE FROM request_log_search WHERE rowid = OLD.id;
+                   INSERT INTO request_log_search
+                       (rowid, query_text, error_text, request_text, response_text)
+                   VALUES (NEW.id, COALESCE(NEW.query, ''), COALESCE(NEW.error_message, ''), '', '');
+               END"#,
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Indexes the next batch of request logs. Runs as maintenance-bulk work and defers instead of
+    /// competing with foreground writers; the cursor only moves when a batch commits.
+    pub(crate) async fn advance_request_log_search_index_slice(
+        &self,
+    ) -> Result<RequestLogSearchSliceOutcome, ProxyError> {
+        self.sqlite_runtime
+            .prewarm_maintenance_bulk_capacity()
+            .await;
+        let _admission = match self
+            .sqlite_runtime
+            .try_admit_maintenance_bulk(SqliteOperation::RequestLogSearchIndex)
+        {
+            Ok(permit) => permit,
+            Err(reason) => return Ok(RequestLogSearchSliceOutcome::Deferred { reason }),
+        };
+        match self.advance_admitted_request_log_search_index_slice().await {
+            Ok(outcome) => Ok(outcome),
+            Err(err) if crate::is_transient_sqlite_write_error(&err) => {
+                self.sqlite_runtime.record_deferred(
+                    SqliteOperation::RequestLogSearchIndex,
+                    SqliteAdmissionDeferReason::RecentContention,
+                );
+                Ok(RequestLogSearchSliceOutcome::Deferred {
+                    reason: SqliteAdmissionDeferReason::RecentContention,
+                })
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn advance_admitted_request_log_search_index_slice(
+        &self,
+    ) -> Result<RequestLogSearchSliceOutcome, ProxyError> {
+        let mut conn = self
+            .sqlite_runtime
+            .acquire_operation_connection(SqliteOperation::RequestLogSearchIndex)
+            .await?;
+        let result = async {
+            let cursor: i64 = sqlx::query_scalar(
+                "SELECT indexed_through_id FROM observability.request_log_search_state WHERE id = 1",
+            )
+            .fetch_one(&mut *conn)
+            .await?;
+            let rows: Vec<RequestLogSearchSourceRow> = sqlx::query_as(
+                r#"SELECT id, query, error_message, request_body, response_body
+                   FROM observability.request_logs
+                   WHERE id > ?
+                   ORDER BY id ASC
+                   LIMIT ?"#,
+            )
+            .bind(cursor)
+            .bind(REQUEST_LOG_SEARCH_SLICE_ROWS)
+            .fetch_all(&mut *conn)
+            .await?;
+            Ok::<_, sqlx::Error>((cursor, rows))
+        }
+        .await;
+        let (cursor, rows) = conn.complete_query(result).await?;
+        let Some(next_cursor) = rows.last().map(|row| row.0) else {
+            return Ok(RequestLogSearchSliceOutcome::Idle);
+        };
+        let row_count = rows.len() as i64;
+        let documents = rows
+            .into_iter()
+            .map(|(id, query, error_message, request_body, response_body)| {
+                (
+                    id,
+                    query.unwrap_or_default(),
+                    error_message.unwrap_or_default(),
+                    request_log_search_body_text(request_body),
+                    request_log_search_body_text(response_body),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let now = self.backend_time.now_ts();
+        let mut tx = self
+            .sqlite_runtime
+            .begin_immediate(SqliteOperation::RequestLogSearchIndex)
+            .await?;
+        let result = async {
+            for (id, query_text, error_text, request_text, response_text) in &documents {
+                // Re-check the source row inside the write transaction: retention may have
+                // deleted it or stripped its bodies since the read above.
+                sqlx::query(
+                    r#"INSERT INTO observability.request_log_search
+                           (rowid, query_text, error_text, request_text, response_text)
+                       SELECT id, ?, ?,
+                              CASE WHEN request_body IS NULL THEN '' ELSE ? END,
+                              CASE WHEN response_body IS NULL THEN '' ELSE ? END
+                       FROM observability.request_logs
+                       WHERE id = ?"#,
+                )
+                .bind(query_text)
+                .bind(error_text)
+                .bind(request_text)
+                .bind(response_text)
+                .bind(id)
+                .execute(&mut *tx)
+                .await?;
+            }
+            let advanced = sqlx::query(
+                r#"UPDATE observability.request_log_search_state
+                   SET indexed_through_id = ?, updated_at = ?
+                   WHERE id = 1 AND indexed_through_id = ?"#,
+            )
+            .bind(next_cursor)
+            .bind(now)
+            .bind(cursor)
+            .execute(&mut *tx)
+            .await?;
+            if advanced.rows_affected() == 0 {
+                return Err(ProxyError::Other(
+                    "request log search cursor moved during indexing".to_string(),
+                ));
+            }
+            Ok(())
+        }
+        .await;
+        tx.finish(result).await?;
+        Ok(RequestLogSearchSliceOutcome::Advanced {
+            rows: row_count,
+            complete: row_count < REQUEST_LOG_SEARCH_SLICE_ROWS,
+        })
+    }
+}
@@ -1249,6 +1249,7 @@ impl KeyStore {
         auth_token_id: Option<&str>,
         key_id: Option<&str>,
         operational_class: Option<&str>,
+        search: Option<&str>,
         cursor: Option<&RequestLogsCursor>,
         direction: RequestLogsCursorDirection,
         page_size: i64,
@@ -1369,6 +1370,7 @@ impl KeyStore {
                 client_ip_source,
                 client_ip_trusted,
                 ip_headers,
+                trace_id,
                 {effective_operational_class_sql} AS operational_class,
                 {effective_request_kind_protocol_group_sql} AS request_kind_protocol_group,
                 {effective_request_kind_billing_group_sql} AS request_kind_billing_group,
@@ -1423,6 +1425,13 @@ impl KeyStore {
                 &legacy_operational_class_case_sql,
             );
         }
+        if let Some(search) = search {
+            items_query.push(
+                " AND id IN (SELECT rowid FROM observability.request_log_search WHERE request_log_search MATCH ",
+            );
+            items_query.push_bind(search.to_string());
+            items_query.push(")");
+        }
         Self::push_desc_cursor_clause(
             &mut items_query,
             "created_at",
@@ -1686,6 +1695,7 @@ impl KeyStore {
                 client_ip_source,
                 client_ip_trusted,
                 ip_headers,
+                trace_id,
                 {effective_operational_class_sql} AS operational_class,
                 {effective_request_kind_protocol_group_sql} AS request_kind_protocol_group,
                 {effective_request_kind_billing_group_sql} AS request_kind_billing_group,
@@ -2020,13 +2030,16 @@ impl KeyStore {
         &self,
         key_id: &str,
     ) -> Result<Option<String>, ProxyError> {
-        let secret =
-            sqlx::query_scalar::<_, String>("SELECT api_key FROM api_keys WHERE id = ? LIMIT 1")
-                .bind(key_id)
-                .fetch_optional(&self.pool)
-                .await?;
+        let secret = sqlx::query_scalar::<_, String>(&format!(
+            "SELECT {API_KEY_SECRET_COLUMN} FROM api_keys WHERE id = ? LIMIT 1"
+        ))
+        .bind(key_id)
+        .fetch_optional(&self.pool)
+        .await?;
 
-        Ok(secret)
+        secret
+            .map(|stored| self.open_api_key_secret(stored))
+            .transpose()
     }
 
     pub(crate) async fn fetch_api_key_id_by_secret(
@@ -2036,7 +2049,7 @@ impl KeyStore {
         sqlx::query_scalar::<_, String>(
             "SELECT id FROM api_keys WHERE api_key = ? AND deleted_at IS NULL LIMIT 1",
         )
-        .bind(secret)
+        .bind(self.api_key_lookup_value(secret))
         .fetch_optional(&self.pool)
         .await
         .map_err(ProxyError::from)
@@ -2510,6 +2523,7 @@ impl KeyStore {
         )
         .fetch_one(&mut **tx)
         .await?;
+        let key_tiers = Self::fetch_api_key_tier_capacity_on(tx, Utc::now()).await?;
 
         Ok(ProxySummary {
             total_requests: totals_row.try_get("total_requests")?,
@@ -2523,6 +2537,9 @@ impl KeyStore {
             last_activity,
             total_quota_limit: quotas_row.try_get("total_quota_limit")?,
             total_quota_remaining: quotas_row.try_get("total_quota_remaining")?,
+            key_tiers,
+            key_rate_budgets: Vec::new(),
+            response_cache: ResponseCacheStats::default(),
         })
     }
 
@@ -0,0 +1,155 @@
+impl KeyStore {
+    pub(crate) async fn ensure_request_parameter_policies_schema(&self) -> Result<(), ProxyError> {
+        // One policy per subject; `subject_id` is a token id, a trimmed token group name or a
+        // user tag id depending on `subject_kind`.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS request_parameter_policies (
+                subject_kind TEXT NOT NULL,
+                subject_id TEXT NOT NULL,
+                policy TEXT NOT NULL,
+                updated_at INTEGER NOT NULL,
+                PRIMARY KEY (subject_kind, subject_id)
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Set or clear (`None`) the parameter policy attached to one subject. Returns `false` when the
+    /// token or user tag does not exist; token groups need no row of their own.
+    pub(crate) async fn set_request_parameter_policy(
+        &self,
+        subject: RequestParameterPolicySubject,
+        subject_id: &str,
+        policy: Option<&RequestParameterPolicy>,
+    ) -> Result<bool, ProxyError> {
+        let subject_id = subject_id.trim();
+        let exists_sql = match subject {
+            RequestParameterPolicySubject::Token => {
+                Some("SELECT 1 FROM auth_tokens WHERE id = ? AND deleted_at IS NULL")
+            }
+            RequestParameterPolicySubject::UserTag => Some("SELECT 1 FROM user_tags WHERE id = ?"),
+            RequestParameterPolicySubject::TokenGroup => None,
+        };
+        if let Some(exists_sql) = exists_sql
+            && sqlx::query_scalar::<_, i64>(exists_sql)
+                .bind(subject_id)
+                .fetch_optional(&self.pool)
+                .await?
+                .is_none()
+        {
+            return Ok(false);
+        }
+        match policy {
+            Some(policy) => {
+                let stored = serde_json::to_string(policy)
+                    .map_err(|err| ProxyError::Other(err.to_string()))?;
+                sqlx::query(
+                    r#"INSERT INTO request_parameter_policies
+                           (subject_kind, subject_id, policy, updated_at)
+                       VALUES (?, ?, ?, ?)
+                       ON CONFLICT(subject_kind, subject_id) DO UPDATE SET
+                           policy = excluded.policy,
+                           updated_at = excluded.updated_at"#,
+                )
+                .bind(subject.as_str())
+                .bind(subject_id)
+                .bind(stored)
+                .bind(self.backend_time.now_ts())
+                .execute(&self.pool)
+                .await?;
+            }
+            None => {
+                sqlx::query(
+                    "DELETE FROM request_parameter_policies WHERE subject_kind = ? AND subject_id = ?",
+                )
+                .bind(subject.as_str())
+                .bind(subject_id)
+                .execute(&self.pool)
+                .await?;
+            }
+        }
+        Ok(true)
+    }
+
+    pub(crate) async fn list_request_parameter_policies(
+        &self,
+    ) -> Result<Vec<RequestParameterPolicyRecord>, ProxyError> {
+        let rows = sqlx::query_as::<_, (String, String, String, i64)>(
+            r#"SELECT subject_kind, subject_id, policy, updated_at
+               FROM request_parameter_policies
+               ORDER BY subject_kind, subject_id"#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .filter_map(|(kind, subject_id, raw, updated_at)| {
+                Some(RequestParameterPolicyRecord {
+                    subject: RequestParameterPolicySubject::parse(&kind)?,
+                    subject_id,
+                    policy: decode_request_parameter_policy(&raw)?,
+                    updated_at,
+                })
+            })
+            .collect())
+    }
+
+    /// Policy enforced for a token: its own, else its group's, else the strictest combination of
+    /// the policies on its owner's tags. `None` means the token is unrestricted.
+    pub(crate) async fn effective_request_parameter_policy(
+        &self,
+        token_id: &str,
+    ) -> Result<Option<RequestParameterPolicy>, ProxyError> {
+        let rows = sqlx::query_as::<_, (String, String)>(
+            r#"SELECT p.subject_kind, p.policy
+               FROM auth_tokens t
+               JOIN request_parameter_policies p
+                 ON (p.subject_kind = 'token' AND p.subject_id = t.id)
+                 OR (p.subject_kind = 'token_group' AND p.subject_id = TRIM(t.group_name))
+                 OR (p.subject_kind = 'user_tag' AND p.subject_id IN (
+                        SELECT tb.tag_id
+                        FROM user_token_bindings ub
+                        JOIN user_tag_bindings tb ON tb.user_id = ub.user_id
+                        WHERE ub.token_id = t.id
+                    ))
+               WHERE t.id = ? AND t.deleted_at IS NULL"#,
+        )
+        .bind(token_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut group_policy = None;
+        let mut tag_policy: Option<RequestParameterPolicy> = None;
+        for (kind, raw) in rows {
+            let Some(policy) = decode_request_parameter_policy(&raw) else {
+                continue;
+            };
+            match RequestParameterPolicySubject::parse(&kind) {
+                Some(RequestParameterPolicySubject::Token) => return Ok(Some(policy)),
+                Some(RequestParameterPolicySubject::TokenGroup) => group_policy = Some(policy),
+                Some(RequestParameterPolicySubject::UserTag) => {
+                    tag_policy = Some(match tag_policy {
+                        Some(current) => current.merge_strictest(&policy),
+                        None => policy,
+                    });
+                }
+                None => {}
+            }
+        }
+        Ok(group_policy.or(tag_policy))
+    }
+}
+
+fn decode_request_parameter_policy(raw: &str) -> Option<RequestParameterPolicy> {
+    match serde_json::from_str::<RequestParameterPolicy>(raw) {
+        Ok(policy) => Some(policy),
+        Err(err) => {
+            tracing::warn!(error = %err, "skipping undecodable request parameter policy");
+            None
+        }
+    }
+}
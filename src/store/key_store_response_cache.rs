@@ -0,0 +1,192 @@
+/// A stored upstream response served for a response cache hit.
+#[derive(Debug, Clone)]
+pub(crate) struct CachedResponse {
+    pub(crate) status: i64,
+    pub(crate) content_type: Option<String>,
+    pub(crate) body: Vec<u8>,
+    /// Credits the original upstream call cost.
+    pub(crate) credits: i64,
+}
+
+impl KeyStore {
+    pub(crate) async fn ensure_response_cache_schema(&self) -> Result<(), ProxyError> {
+        // Node-local: entries are never replicated, every HA node warms its own cache.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS response_cache_entries (
+                cache_key TEXT PRIMARY KEY,
+                endpoint TEXT NOT NULL,
+                status INTEGER NOT NULL,
+                content_type TEXT,
+                body BLOB NOT NULL,
+                credits INTEGER NOT NULL DEFAULT 0,
+                created_at INTEGER NOT NULL,
+                expires_at INTEGER NOT NULL,
+                hit_count INTEGER NOT NULL DEFAULT 0,
+                last_hit_at INTEGER
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+        sqlx::query(
+            r#"CREATE INDEX IF NOT EXISTS idx_response_cache_entries_expires
+               ON response_cache_entries(expires_at)"#,
+        )
+        .execute(&self.pool)
+        .await?;
+        sqlx::query(
+            r#"CREATE INDEX IF NOT EXISTS idx_response_cache_entries_created
+               ON response_cache_entries(created_at)"#,
+        )
+        .execute(&self.pool)
+        .await?;
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS response_cache_daily_stats (
+                day_start INTEGER PRIMARY KEY,
+                hits INTEGER NOT NULL DEFAULT 0,
+                credits_saved INTEGER NOT NULL DEFAULT 0
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Return the live entry for `cache_key` and count the hit, or `None` when it is missing or
+    /// expired.
+    pub(crate) async fn take_response_cache_hit(
+        &self,
+        cache_key: &str,
+        now: i64,
+    ) -> Result<Option<CachedResponse>, ProxyError> {
+        let row = sqlx::query_as::<_, (i64, Option<String>, Vec<u8>, i64)>(
+            r#"UPDATE response_cache_entries
+               SET hit_count = hit_count + 1, last_hit_at = ?
+               WHERE cache_key = ? AND expires_at > ?
+               RETURNING status, content_type, body, credits"#,
+        )
+        .bind(now)
+        .bind(cache_key)
+        .bind(now)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row.map(|(status, content_type, body, credits)| CachedResponse {
+            status,
+            content_type,
+            body,
+            credits,
+        }))
+    }
+
+    pub(crate) async fn record_response_cache_hit_stats(
+        &self,
+        day_start: i64,
+        credits_saved: i64,
+    ) -> Result<(), ProxyError> {
+        sqlx::query(
+            r#"INSERT INTO response_cache_daily_stats (day_start, hits, credits_saved)
+               VALUES (?, 1, ?)
+               ON CONFLICT(day_start) DO UPDATE SET
+                   hits = hits + 1,
+                   credits_saved = credits_saved + excluded.credits_saved"#,
+        )
+        .bind(day_start)
+        .bind(credits_saved.max(0))
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Store a response, then drop expired entries and the oldest ones beyond `max_entries`.
+    pub(crate) async fn store_response_cache_entry(
+        &self,
+        cache_key: &str,
+        endpoint: &str,
+        response: &CachedResponse,
+        now: i64,
+        ttl_secs: i64,
+        max_entries: i64,
+    ) -> Result<(), ProxyError> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query(
+            r#"INSERT INTO response_cache_entries
+                   (cache_key, endpoint, status, content_type, body, credits, created_at, expires_at)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+               ON CONFLICT(cache_key) DO UPDATE SET
+                   status = excluded.status,
+                   content_type = excluded.content_type,
+                   body = excluded.body,
+                   credits = excluded.credits,
+                   created_at = excluded.created_at,
+                   expires_at = excluded.expires_at,
+                   hit_count = 0,
+                   last_hit_at = NULL"#,
+        )
+        .bind(cache_key)
+        .bind(endpoint)
+        .bind(response.status)
+        .bind(response.content_type.as_deref())
+        .bind(&response.body)
+        .bind(response.credits)
+        .bind(now)
+        .bind(now.saturating_add(ttl_secs))
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query("DELETE FROM response_cache_entries WHERE expires_at <= ?")
+            .bind(now)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query(
+            r#"DELETE FROM response_cache_entries
+               WHERE cache_key IN (
+                   SELECT cache_key FROM response_cache_entries
+                   ORDER BY created_at DESC, rowid DESC
+                   LIMIT -1 OFFSET ?
+               )"#,
+        )
+        .bind(max_entries)
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub(crate) async fn clear_response_cache(&self) -> Result<(), ProxyError> {
+        sqlx::query("DELETE FROM response_cache_entries")
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    pub(crate) async fn response_cache_stats(
+        &self,
+        day_start: i64,
+    ) -> Result<ResponseCacheStats, ProxyError> {
+        let now = self.backend_time.now_ts();
+        let (entries, stored_bytes) = sqlx::query_as::<_, (i64, i64)>(
+            r#"SELECT COUNT(*), COALESCE(SUM(LENGTH(body)), 0)
+               FROM response_cache_entries
+               WHERE expires_at > ?"#,
+        )
+        .bind(now)
+        .fetch_one(&self.pool)
+        .await?;
+        let (hits_today, credits_saved_today) = sqlx::query_as::<_, (i64, i64)>(
+            "SELECT hits, credits_saved FROM response_cache_daily_stats WHERE day_start = ?",
+        )
+        .bind(day_start)
+        .fetch_optional(&self.pool)
+        .await?
+        .unwrap_or_default();
+        Ok(ResponseCacheStats {
+            enabled: self.response_cache_settings().await?.enabled,
+            entries,
+            stored_bytes,
+            hits_today,
+            credits_saved_today,
+        })
+    }
+}
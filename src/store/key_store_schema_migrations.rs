@@ -77,6 +77,60 @@ const RECONCILIATION_RESEARCH_PROGRESS_WINDOW_NAME: &str =
     "reconciliation-research-progress-window-v1";
 const RECONCILIATION_RESEARCH_PROGRESS_WINDOW_CHECKSUM: &str =
     "sha256:6431dd87e790811d9b05f32d7a2c54de";
+const API_KEY_ROUTING_CONTROLS_VERSION: i64 = 21;
+const API_KEY_ROUTING_CONTROLS_NAME: &str = "api-key-routing-controls-v1";
+const API_KEY_ROUTING_CONTROLS_CHECKSUM: &str = "sha256:c8bf5f11902b4f15fff8d101f8a84a50";
+const API_KEY_SECRET_CIPHERTEXT_VERSION: i64 = 22;
+const API_KEY_SECRET_CIPHERTEXT_NAME: &str = "api-key-secret-ciphertext-v1";
+const API_KEY_SECRET_CIPHERTEXT_CHECKSUM: &str = "sha256:888eafb94c0660697f059afe4a8be6af";
+const AUTH_TOKEN_SECRET_HASH_VERSION: i64 = 23;
+const AUTH_TOKEN_SECRET_HASH_NAME: &str = "auth-token-secret-hash-v1";
+const AUTH_TOKEN_SECRET_HASH_CHECKSUM: &str = "sha256:78c4d2da3a222662c395502b365c1644";
+const AUTH_TOKEN_LIFETIME_VERSION: i64 = 24;
+const AUTH_TOKEN_LIFETIME_NAME: &str = "auth-token-lifetime-v1";
+const AUTH_TOKEN_LIFETIME_CHECKSUM: &str = "sha256:fa148fc40bf5effda6c4967947f85c48";
+const AUTH_TOKEN_SCOPES_VERSION: i64 = 25;
+const AUTH_TOKEN_SCOPES_NAME: &str = "auth-token-scopes-v1";
+const AUTH_TOKEN_SCOPES_CHECKSUM: &str = "sha256:ee1961dc581896c5354cca9ce6eb275c";
+const REQUEST_PARAMETER_POLICIES_VERSION: i64 = 26;
+const REQUEST_PARAMETER_POLICIES_NAME: &str = "request-parameter-policies-v1";
+const REQUEST_PARAMETER_POLICIES_CHECKSUM: &str = "sha256:719b640317ae27b0bb442941a9edd976";
+const RESPONSE_CACHE_VERSION: i64 = 27;
+const RESPONSE_CACHE_NAME: &str = "response-cache-v1";
+const RESPONSE_CACHE_CHECKSUM: &str = "sha256:576481d6227b84589f60e363d7793479";
+const ALERT_WEBHOOKS_VERSION: i64 = 28;
+const ALERT_WEBHOOKS_NAME: &str = "alert-webhooks-v1";
+const ALERT_WEBHOOKS_CHECKSUM: &str = "sha256:3b9d0f4c7e21a6d58c0f91e2b4a7d36e";
+const ALERT_RULES_VERSION: i64 = 29;
+const ALERT_RULES_NAME: &str = "alert-rules-v1";
+const ALERT_RULES_CHECKSUM: &str = "sha256:9e4a61c0d2b7f83a5c16e0d94b2f7a18";
+const ALERT_WORKFLOW_VERSION: i64 = 30;
+const ALERT_WORKFLOW_NAME: &str = "alert-workflow-v1";
+const ALERT_WORKFLOW_CHECKSUM: &str = "sha256:c47e20b9a51d3f86e0b2d9a4176c5e38";
+const ADMIN_AUDIT_VERSION: i64 = 31;
+const ADMIN_AUDIT_NAME: &str = "admin-audit-log-v1";
+const ADMIN_AUDIT_CHECKSUM: &str = "sha256:5d0b8e27a9f4c3168e2a7d90b1c64f3e";
+const REQUEST_LOG_SEARCH_VERSION: i64 = 32;
+const REQUEST_LOG_SEARCH_NAME: &str = "request-log-search-v1";
+const REQUEST_LOG_SEARCH_CHECKSUM: &str = "sha256:a83f5c1e07d94b26c8e1f30d5b7a9c42";
+const REQUEST_LOG_REPLAYS_VERSION: i64 = 33;
+const REQUEST_LOG_REPLAYS_NAME: &str = "request-log-replays-v1";
+const REQUEST_LOG_REPLAYS_CHECKSUM: &str = "sha256:3f9d6a1b0e8c47d2a5b19e60c7f4d832";
+const REGISTRATION_INVITES_VERSION: i64 = 34;
+const REGISTRATION_INVITES_NAME: &str = "registration-invites-v1";
+const REGISTRATION_INVITES_CHECKSUM: &str = "sha256:c41e7b9d20a65f83e1d4a7c09b6f2e58";
+const ADMIN_ACCOUNTS_VERSION: i64 = 35;
+const ADMIN_ACCOUNTS_NAME: &str = "admin-accounts-v1";
+const ADMIN_ACCOUNTS_CHECKSUM: &str = "sha256:7b2e90d4c1a8f36e5d07b4a19c6e2f81";
+const ADMIN_API_TOKENS_VERSION: i64 = 36;
+const ADMIN_API_TOKENS_NAME: &str = "admin-api-tokens-v1";
+const ADMIN_API_TOKENS_CHECKSUM: &str = "sha256:e5a13c08f97b2d64a1c0e83f5b92d7a6";
+const TEAMS_VERSION: i64 = 37;
+const TEAMS_NAME: &str = "teams-v1";
+const TEAMS_CHECKSUM: &str = "sha256:3c9d1f70a2b84e65d0f7c12e8a49b5d3";
+const BILLING_RESERVATIONS_VERSION: i64 = 38;
+const BILLING_RESERVATIONS_NAME: &str = "billing-reservations-v1";
+const BILLING_RESERVATIONS_CHECKSUM: &str = "sha256:8f41c2d7a06e93b5c1d84f2e7a90b36c";
 const NEW_DATABASE_BOOTSTRAP_MARKER: &str = "tavily-hikari-schema-bootstrap-v1";
 
 impl KeyStore {
@@ -773,6 +827,76 @@ impl KeyStore {
                 RECONCILIATION_RESEARCH_PROGRESS_WINDOW_NAME,
                 RECONCILIATION_RESEARCH_PROGRESS_WINDOW_CHECKSUM,
             ),
+            (
+                API_KEY_ROUTING_CONTROLS_VERSION,
+                API_KEY_ROUTING_CONTROLS_NAME,
+                API_KEY_ROUTING_CONTROLS_CHECKSUM,
+            ),
+            (
+                API_KEY_SECRET_CIPHERTEXT_VERSION,
+                API_KEY_SECRET_CIPHERTEXT_NAME,
+                API_KEY_SECRET_CIPHERTEXT_CHECKSUM,
+            ),
+            (
+                AUTH_TOKEN_SECRET_HASH_VERSION,
+                AUTH_TOKEN_SECRET_HASH_NAME,
+                AUTH_TOKEN_SECRET_HASH_CHECKSUM,
+            ),
+            (
+                AUTH_TOKEN_LIFETIME_VERSION,
+                AUTH_TOKEN_LIFETIME_NAME,
+                AUTH_TOKEN_LIFETIME_CHECKSUM,
+            ),
+            (
+                AUTH_TOKEN_SCOPES_VERSION,
+                AUTH_TOKEN_SCOPES_NAME,
+                AUTH_TOKEN_SCOPES_CHECKSUM,
+            ),
+            (
+                REQUEST_PARAMETER_POLICIES_VERSION,
+                REQUEST_PARAMETER_POLICIES_NAME,
+                REQUEST_PARAMETER_POLICIES_CHECKSUM,
+            ),
+            (RESPONSE_CACHE_VERSION, RESPONSE_CACHE_NAME, RESPONSE_CACHE_CHECKSUM),
+            (ALERT_WEBHOOKS_VERSION, ALERT_WEBHOOKS_NAME, ALERT_WEBHOOKS_CHECKSUM),
+            (ALERT_RULES_VERSION, ALERT_RULES_NAME, ALERT_RULES_CHECKSUM),
+            (
+                ALERT_WORKFLOW_VERSION,
+                ALERT_WORKFLOW_NAME,
+                ALERT_WORKFLOW_CHECKSUM,
+            ),
+            (ADMIN_AUDIT_VERSION, ADMIN_AUDIT_NAME, ADMIN_AUDIT_CHECKSUM),
+            (
+                REQUEST_LOG_SEARCH_VERSION,
+                REQUEST_LOG_SEARCH_NAME,
+                REQUEST_LOG_SEARCH_CHECKSUM,
+            ),
+            (
+                REQUEST_LOG_REPLAYS_VERSION,
+                REQUEST_LOG_REPLAYS_NAME,
+                REQUEST_LOG_REPLAYS_CHECKSUM,
+            ),
+            (
+                REGISTRATION_INVITES_VERSION,
+                REGISTRATION_INVITES_NAME,
+                REGISTRATION_INVITES_CHECKSUM,
+            ),
+            (
+                ADMIN_ACCOUNTS_VERSION,
+                ADMIN_ACCOUNTS_NAME,
+                ADMIN_ACCOUNTS_CHECKSUM,
+            ),
+            (
+                ADMIN_API_TOKENS_VERSION,
+                ADMIN_API_TOKENS_NAME,
+                ADMIN_API_TOKENS_CHECKSUM,
+            ),
+            (TEAMS_VERSION, TEAMS_NAME, TEAMS_CHECKSUM),
+            (
+                BILLING_RESERVATIONS_VERSION,
+                BILLING_RESERVATIONS_NAME,
+                BILLING_RESERVATIONS_CHECKSUM,
+            ),
         ];
         let recorded: Vec<(i64, String, String)> = sqlx::query_as(
             "SELECT version, name, checksum FROM schema_migrations ORDER BY version",
@@ -906,6 +1030,209 @@ impl KeyStore {
                 "schema migration object validation failed at version 20".to_string(),
             ));
         }
+        if self
+            .schema_migration_applied(API_KEY_ROUTING_CONTROLS_VERSION)
+            .await?
+        {
+            for table in [
+                "api_key_group_tiers",
+                "api_key_group_bindings",
+                "api_key_rate_budgets",
+            ] {
+                if !self.schema_object_exists("main", table).await? {
+                    return Err(ProxyError::Other(
+                        "schema migration object validation failed at version 21".to_string(),
+                    ));
+                }
+            }
+        }
+        if self
+            .schema_migration_applied(API_KEY_SECRET_CIPHERTEXT_VERSION)
+            .await?
+            && !self
+                .table_column_exists("api_keys", "api_key_ciphertext")
+                .await?
+        {
+            return Err(ProxyError::Other(
+                "schema migration object validation failed at version 22".to_string(),
+            ));
+        }
+        if self
+            .schema_migration_applied(AUTH_TOKEN_SECRET_HASH_VERSION)
+            .await?
+            && !self
+                .table_column_exists("auth_tokens", "secret_hash")
+                .await?
+        {
+            return Err(ProxyError::Other(
+                "schema migration object validation failed at version 23".to_string(),
+            ));
+        }
+        if self
+            .schema_migration_applied(AUTH_TOKEN_LIFETIME_VERSION)
+            .await?
+            && (!self.table_column_exists("auth_tokens", "expires_at").await?
+                || !self
+                    .schema_object_exists("main", "auth_token_expiry_notices")
+                    .await?)
+        {
+            return Err(ProxyError::Other(
+                "schema migration object validation failed at version 24".to_string(),
+            ));
+        }
+        if self
+            .schema_migration_applied(AUTH_TOKEN_SCOPES_VERSION)
+            .await?
+            && (!self.table_column_exists("auth_tokens", "scopes").await?
+                || !self
+                    .schema_object_exists("main", "token_group_scopes")
+                    .await?)
+        {
+            return Err(ProxyError::Other(
+                "schema migration object validation failed at version 25".to_string(),
+            ));
+        }
+        if self
+            .schema_migration_applied(REQUEST_PARAMETER_POLICIES_VERSION)
+            .await?
+            && !self
+                .schema_object_exists("main", "request_parameter_policies")
+                .await?
+        {
+            return Err(ProxyError::Other(
+                "schema migration object validation failed at version 26".to_string(),
+            ));
+        }
+        if self
+            .schema_migration_applied(RESPONSE_CACHE_VERSION)
+            .await?
+            && (!self
+                .schema_object_exists("main", "response_cache_entries")
+                .await?
+                || !self
+                    .schema_object_exists("main", "response_cache_daily_stats")
+                    .await?)
+        {
+            return Err(ProxyError::Other(
+                "schema migration object validation failed at version 27".to_string(),
+            ));
+        }
+        if self
+            .schema_migration_applied(ALERT_WEBHOOKS_VERSION)
+            .await?
+            && (!self
+                .schema_object_exists("main", "alert_webhook_sinks")
+                .await?
+                || !self
+                    .schema_object_exists("main", "alert_webhook_deliveries")
+                    .await?)
+        {
+            return Err(ProxyError::Other(
+                "schema migration object validation failed at version 28".to_string(),
+            ));
+        }
+        if self.schema_migration_applied(ALERT_RULES_VERSION).await?
+            && (!self.schema_object_exists("main", "alert_rules").await?
+                || !self
+                    .schema_object_exists("main", "alert_rule_firings")
+                    .await?)
+        {
+            return Err(ProxyError::Other(
+                "schema migration object validation failed at version 29".to_string(),
+            ));
+        }
+        if self.schema_migration_applied(ALERT_WORKFLOW_VERSION).await?
+            && (!self
+                .schema_object_exists("main", "alert_group_workflows")
+                .await?
+                || !self.schema_object_exists("main", "alert_silences").await?)
+        {
+            return Err(ProxyError::Other(
+                "schema migration object validation failed at version 30".to_string(),
+            ));
+        }
+        if self.schema_migration_applied(ADMIN_AUDIT_VERSION).await?
+            && !self.schema_object_exists("main", "admin_audit_log").await?
+        {
+            return Err(ProxyError::Other(
+                "schema migration object validation failed at version 31".to_string(),
+            ));
+        }
+        if self
+            .schema_migration_applied(REQUEST_LOG_SEARCH_VERSION)
+            .await?
+            && (!self
+                .schema_object_exists("observability", "request_log_search")
+                .await?
+                || !self
+                    .schema_object_exists("observability", "request_log_search_state")
+                    .await?)
+        {
+            return Err(ProxyError::Other(
+                "schema migration object validation failed at version 32".to_string(),
+            ));
+        }
+        if self
+            .schema_migration_applied(REQUEST_LOG_REPLAYS_VERSION)
+            .await?
+            && !self
+                .schema_object_exists("observability", "request_log_replays")
+                .await?
+        {
+            return Err(ProxyError::Other(
+                "schema migration object validation failed at version 33".to_string(),
+            ));
+        }
+        if self
+            .schema_migration_applied(REGISTRATION_INVITES_VERSION)
+            .await?
+            && !self
+                .schema_object_exists("main", "user_registration_approvals")
+                .await?
+        {
+            return Err(ProxyError::Other(
+                "schema migration object validation failed at version 34".to_string(),
+            ));
+        }
+        if self.schema_migration_applied(ADMIN_ACCOUNTS_VERSION).await?
+            && !self
+                .schema_object_exists("main", "admin_account_sessions")
+                .await?
+        {
+            return Err(ProxyError::Other(
+                "schema migration object validation failed at version 35".to_string(),
+            ));
+        }
+        if self
+            .schema_migration_applied(ADMIN_API_TOKENS_VERSION)
+            .await?
+            && !self.schema_object_exists("main", "admin_api_tokens").await?
+        {
+            return Err(ProxyError::Other(
+                "schema migration object validation failed at version 36".to_string(),
+            ));
+        }
+        if self.schema_migration_applied(TEAMS_VERSION).await?
+            && (!self.schema_object_exists("main", "teams").await?
+                || !self.schema_object_exists("main", "team_members").await?
+                || !self.schema_object_exists("main", "team_tokens").await?
+                || !self.schema_object_exists("main", "team_usage_buckets").await?)
+        {
+            return Err(ProxyError::Other(
+                "schema migration object validation failed at version 37".to_string(),
+            ));
+        }
+        if self
+            .schema_migration_applied(BILLING_RESERVATIONS_VERSION)
+            .await?
+            && !self
+                .schema_object_exists("main", "billing_reservations")
+                .await?
+        {
+            return Err(ProxyError::Other(
+                "schema migration object validation failed at version 38".to_string(),
+            ));
+        }
         if self
             .schema_migration_applied(RECONCILIATION_CONTROLLER_VERSION)
             .await?
@@ -1630,6 +1957,216 @@ impl KeyStore {
         .await
     }
 
+    async fn apply_api_key_routing_controls_migration(&self) -> Result<(), ProxyError> {
+        self.ensure_api_key_group_routing_schema().await?;
+        self.ensure_api_key_rate_budgets_schema().await?;
+        self.record_schema_migration(
+            API_KEY_ROUTING_CONTROLS_VERSION,
+            API_KEY_ROUTING_CONTROLS_NAME,
+            API_KEY_ROUTING_CONTROLS_CHECKSUM,
+        )
+        .await
+    }
+
+    async fn apply_api_key_secret_ciphertext_migration(&self) -> Result<(), ProxyError> {
+        self.ensure_api_key_secret_ciphertext_column().await?;
+        self.record_schema_migration(
+            API_KEY_SECRET_CIPHERTEXT_VERSION,
+            API_KEY_SECRET_CIPHERTEXT_NAME,
+            API_KEY_SECRET_CIPHERTEXT_CHECKSUM,
+        )
+        .await
+    }
+
+    async fn apply_auth_token_secret_hash_migration(&self) -> Result<(), ProxyError> {
+        self.ensure_access_token_secret_hash_column().await?;
+        self.record_schema_migration(
+            AUTH_TOKEN_SECRET_HASH_VERSION,
+            AUTH_TOKEN_SECRET_HASH_NAME,
+            AUTH_TOKEN_SECRET_HASH_CHECKSUM,
+        )
+        .await
+    }
+
+    async fn apply_auth_token_lifetime_migration(&self) -> Result<(), ProxyError> {
+        self.ensure_access_token_lifetime_schema().await?;
+        self.seed_alert_projection_source_cursors(ALERT_SOURCE_AUTH_TOKEN_EXPIRY_NOTICE)
+            .await?;
+        self.record_schema_migration(
+            AUTH_TOKEN_LIFETIME_VERSION,
+            AUTH_TOKEN_LIFETIME_NAME,
+            AUTH_TOKEN_LIFETIME_CHECKSUM,
+        )
+        .await
+    }
+
+    /// Seeds the projection cursors of an alert source added after the projection itself.
+    /// Databases whose projection was seeded before the source existed get the same starting
+    /// cursors a fresh database would: the Dashboard tail starts at its bounded recent window
+    /// and the history lane below that boundary.
+    async fn seed_alert_projection_source_cursors(
+        &self,
+        source_kind: &str,
+    ) -> Result<(), ProxyError> {
+        let cursor_start = self
+            .backend_time
+            .now_ts()
+            .saturating_sub(ALERT_PROJECTION_RECENT_WINDOW_SECS);
+        sqlx::query(
+            r#"INSERT INTO observability.dashboard_alert_projection_state
+                    (source_kind, cursor_occurred_at)
+               VALUES (?, ?)
+               ON CONFLICT(source_kind) DO NOTHING"#,
+        )
+        .bind(source_kind)
+        .bind(cursor_start)
+        .execute(&self.pool)
+        .await?;
+        sqlx::query(
+            r#"INSERT INTO observability.dashboard_alert_projection_history_state
+                    (source_kind, cursor_occurred_at, cursor_row_sort_id,
+                     fence_occurred_at, fence_row_sort_id, generation, phase)
+               VALUES (?, 0, '', ?, '', 0, 'catching_up')
+               ON CONFLICT(source_kind) DO NOTHING"#,
+        )
+        .bind(source_kind)
+        .bind(cursor_start.saturating_sub(1))
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn apply_auth_token_scopes_migration(&self) -> Result<(), ProxyError> {
+        self.ensure_access_token_scopes_schema().await?;
+        self.record_schema_migration(
+            AUTH_TOKEN_SCOPES_VERSION,
+            AUTH_TOKEN_SCOPES_NAME,
+            AUTH_TOKEN_SCOPES_CHECKSUM,
+        )
+        .await
+    }
+
+    async fn apply_request_parameter_policies_migration(&self) -> Result<(), ProxyError> {
+        self.ensure_request_parameter_policies_schema().await?;
+        self.record_schema_migration(
+            REQUEST_PARAMETER_POLICIES_VERSION,
+            REQUEST_PARAMETER_POLICIES_NAME,
+            REQUEST_PARAMETER_POLICIES_CHECKSUM,
+        )
+        .await
+    }
+
+    async fn apply_response_cache_migration(&self) -> Result<(), ProxyError> {
+        self.ensure_response_cache_schema().await?;
+        self.record_schema_migration(
+            RESPONSE_CACHE_VERSION,
+            RESPONSE_CACHE_NAME,
+            RESPONSE_CACHE_CHECKSUM,
+        )
+        .await
+    }
+
+    async fn apply_alert_webhooks_migration(&self) -> Result<(), ProxyError> {
+        self.ensure_alert_webhooks_schema().await?;
+        self.record_schema_migration(
+            ALERT_WEBHOOKS_VERSION,
+            ALERT_WEBHOOKS_NAME,
+            ALERT_WEBHOOKS_CHECKSUM,
+        )
+        .await
+    }
+
+    async fn apply_alert_rules_migration(&self) -> Result<(), ProxyError> {
+        self.ensure_alert_rules_schema().await?;
+        self.seed_alert_projection_source_cursors(ALERT_SOURCE_ALERT_RULE_FIRING)
+            .await?;
+        self.record_schema_migration(ALERT_RULES_VERSION, ALERT_RULES_NAME, ALERT_RULES_CHECKSUM)
+            .await
+    }
+
+    async fn apply_alert_workflow_migration(&self) -> Result<(), ProxyError> {
+        self.ensure_alert_workflow_schema().await?;
+        self.ensure_alert_projection_silence_column().await?;
+        self.record_schema_migration(
+            ALERT_WORKFLOW_VERSION,
+            ALERT_WORKFLOW_NAME,
+            ALERT_WORKFLOW_CHECKSUM,
+        )
+        .await
+    }
+
+    async fn apply_admin_audit_migration(&self) -> Result<(), ProxyError> {
+        self.ensure_admin_audit_schema().await?;
+        self.record_schema_migration(ADMIN_AUDIT_VERSION, ADMIN_AUDIT_NAME, ADMIN_AUDIT_CHECKSUM)
+            .await
+    }
+
+    async fn apply_request_log_search_migration(&self) -> Result<(), ProxyError> {
+        self.ensure_request_log_search_schema().await?;
+        self.record_schema_migration(
+            REQUEST_LOG_SEARCH_VERSION,
+            REQUEST_LOG_SEARCH_NAME,
+            REQUEST_LOG_SEARCH_CHECKSUM,
+        )
+        .await
+    }
+
+    async fn apply_request_log_replays_migration(&self) -> Result<(), ProxyError> {
+        self.ensure_request_log_replay_schema().await?;
+        self.record_schema_migration(
+            REQUEST_LOG_REPLAYS_VERSION,
+            REQUEST_LOG_REPLAYS_NAME,
+            REQUEST_LOG_REPLAYS_CHECKSUM,
+        )
+        .await
+    }
+
+    async fn apply_registration_invites_migration(&self) -> Result<(), ProxyError> {
+        self.ensure_registration_invites_schema().await?;
+        self.record_schema_migration(
+            REGISTRATION_INVITES_VERSION,
+            REGISTRATION_INVITES_NAME,
+            REGISTRATION_INVITES_CHECKSUM,
+        )
+        .await
+    }
+
+    async fn apply_admin_accounts_migration(&self) -> Result<(), ProxyError> {
+        self.ensure_admin_accounts_schema().await?;
+        self.record_schema_migration(
+            ADMIN_ACCOUNTS_VERSION,
+            ADMIN_ACCOUNTS_NAME,
+            ADMIN_ACCOUNTS_CHECKSUM,
+        )
+        .await
+    }
+
+    async fn apply_admin_api_tokens_migration(&self) -> Result<(), ProxyError> {
+        self.ensure_admin_api_tokens_schema().await?;
+        self.record_schema_migration(
+            ADMIN_API_TOKENS_VERSION,
+            ADMIN_API_TOKENS_NAME,
+            ADMIN_API_TOKENS_CHECKSUM,
+        )
+        .await
+    }
+
+    async fn apply_teams_migration(&self) -> Result<(), ProxyError> {
+        self.ensure_teams_schema().await?;
+        self.record_schema_migration(TEAMS_VERSION, TEAMS_NAME, TEAMS_CHECKSUM)
+            .await
+    }
+
+    async fn apply_billing_reservations_migration(&self) -> Result<(), ProxyError> {
+        self.ensure_billing_reservations_schema().await?;
+        self.record_schema_migration(
+            BILLING_RESERVATIONS_VERSION,
+            BILLING_RESERVATIONS_NAME,
+            BILLING_RESERVATIONS_CHECKSUM,
+        )
+        .await
+    }
+
     async fn apply_reconciliation_work_migration(&self) -> Result<(), ProxyError> {
         sqlx::query(
             r#"CREATE TABLE IF NOT EXISTS upstream_reconciliation_work (
@@ -2061,6 +2598,93 @@ impl KeyStore {
             self.apply_reconciliation_research_progress_window_migration()
                 .await?;
         }
+        if !self
+            .schema_migration_applied(API_KEY_ROUTING_CONTROLS_VERSION)
+            .await?
+        {
+            self.apply_api_key_routing_controls_migration().await?;
+        }
+        if !self
+            .schema_migration_applied(API_KEY_SECRET_CIPHERTEXT_VERSION)
+            .await?
+        {
+            self.apply_api_key_secret_ciphertext_migration().await?;
+        }
+        if !self
+            .schema_migration_applied(AUTH_TOKEN_SECRET_HASH_VERSION)
+            .await?
+        {
+            self.apply_auth_token_secret_hash_migration().await?;
+        }
+        if !self
+            .schema_migration_applied(AUTH_TOKEN_LIFETIME_VERSION)
+            .await?
+        {
+            self.apply_auth_token_lifetime_migration().await?;
+        }
+        if !self
+            .schema_migration_applied(AUTH_TOKEN_SCOPES_VERSION)
+            .await?
+        {
+            self.apply_auth_token_scopes_migration().await?;
+        }
+        if !self
+            .schema_migration_applied(REQUEST_PARAMETER_POLICIES_VERSION)
+            .await?
+        {
+            self.apply_request_parameter_policies_migration().await?;
+        }
+        if !self.schema_migration_applied(RESPONSE_CACHE_VERSION).await? {
+            self.apply_response_cache_migration().await?;
+        }
+        if !self.schema_migration_applied(ALERT_WEBHOOKS_VERSION).await? {
+            self.apply_alert_webhooks_migration().await?;
+        }
+        if !self.schema_migration_applied(ALERT_RULES_VERSION).await? {
+            self.apply_alert_rules_migration().await?;
+        }
+        if !self.schema_migration_applied(ALERT_WORKFLOW_VERSION).await? {
+            self.apply_alert_workflow_migration().await?;
+        }
+        if !self.schema_migration_applied(ADMIN_AUDIT_VERSION).await? {
+            self.apply_admin_audit_migration().await?;
+        }
+        if !self
+            .schema_migration_applied(REQUEST_LOG_SEARCH_VERSION)
+            .await?
+        {
+            self.apply_request_log_search_migration().await?;
+        }
+        if !self
+            .schema_migration_applied(REQUEST_LOG_REPLAYS_VERSION)
+            .await?
+        {
+            self.apply_request_log_replays_migration().await?;
+        }
+        if !self
+            .schema_migration_applied(REGISTRATION_INVITES_VERSION)
+            .await?
+        {
+            self.apply_registration_invites_migration().await?;
+        }
+        if !self.schema_migration_applied(ADMIN_ACCOUNTS_VERSION).await? {
+            self.apply_admin_accounts_migration().await?;
+        }
+        if !self
+            .schema_migration_applied(ADMIN_API_TOKENS_VERSION)
+            .await?
+        {
+            self.apply_admin_api_tokens_migration().await?;
+        }
+        if !self.schema_migration_applied(TEAMS_VERSION).await? {
+            self.apply_teams_migration().await?;
+        }
+        if !self
+            .schema_migration_applied(BILLING_RESERVATIONS_VERSION)
+            .await?
+        {
+            self.apply_billing_reservations_migration().await?;
+        }
         self.validate_applied_migration_objects().await?;
         self.clear_new_database_bootstrap_marker().await?;
         tracing::debug!(
@@ -2114,6 +2738,24 @@ impl KeyStore {
         self.apply_reconciliation_transport_state_migration().await?;
         self.apply_reconciliation_research_progress_window_migration()
             .await?;
+        self.apply_api_key_routing_controls_migration().await?;
+        self.apply_api_key_secret_ciphertext_migration().await?;
+        self.apply_auth_token_secret_hash_migration().await?;
+        self.apply_auth_token_lifetime_migration().await?;
+        self.apply_auth_token_scopes_migration().await?;
+        self.apply_request_parameter_policies_migration().await?;
+        self.apply_response_cache_migration().await?;
+        self.apply_alert_webhooks_migration().await?;
+        self.apply_alert_rules_migration().await?;
+        self.apply_alert_workflow_migration().await?;
+        self.apply_admin_audit_migration().await?;
+        self.apply_request_log_search_migration().await?;
+        self.apply_request_log_replays_migration().await?;
+        self.apply_registration_invites_migration().await?;
+        self.apply_admin_accounts_migration().await?;
+        self.apply_admin_api_tokens_migration().await?;
+        self.apply_teams_migration().await?;
+        self.apply_billing_reservations_migration().await?;
         self.validate_applied_migration_objects().await?;
         self.clear_new_database_bootstrap_marker().await?;
         tracing::info!(
@@ -2121,7 +2763,7 @@ impl KeyStore {
             event = "baseline_adopted",
             outcome = "applied",
             elapsed_ms = started.elapsed().as_millis() as u64,
-            migration_count = 20_i64,
+            migration_count = 38_i64,
         );
         Ok(())
     }
@@ -2915,135 +2915,4 @@ impl KeyStore {
             )
             .collect())
     }
-
-    pub(crate) async fn insert_oauth_login_state(
-        &self,
-        provider: &str,
-        redirect_to: Option<&str>,
-        ttl_secs: i64,
-        binding_hash: Option<&str>,
-        bind_token_id: Option<&str>,
-    ) -> Result<String, ProxyError> {
-        const ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
-        let now = self.backend_time.now_ts();
-        let expires_at = now + ttl_secs.max(60);
-
-        sqlx::query(
-            "DELETE FROM oauth_login_states WHERE expires_at < ? OR consumed_at IS NOT NULL",
-        )
-        .bind(now)
-        .execute(&self.pool)
-        .await?;
-
-        loop {
-            let state = random_string(ALPHABET, 48);
-            let res = sqlx::query(
-                r#"INSERT INTO oauth_login_states
-                   (state, provider, redirect_to, binding_hash, bind_token_id, created_at, expires_at, consumed_at)
-                   VALUES (?, ?, ?, ?, ?, ?, ?, NULL)"#,
-            )
-            .bind(&state)
-            .bind(provider)
-            .bind(redirect_to.map(str::trim).filter(|value| !value.is_empty()))
-            .bind(
-                binding_hash
-                    .map(str::trim)
-                    .filter(|value| !value.is_empty()),
-            )
-            .bind(bind_token_id.map(str::trim).filter(|value| !value.is_empty()))
-            .bind(now)
-            .bind(expires_at)
-            .execute(&self.pool)
-            .await;
-
-            match res {
-                Ok(_) => return Ok(state),
-                Err(sqlx::Error::Database(db_err)) if db_err.is_unique_violation() => continue,
-                Err(err) => return Err(ProxyError::Database(err)),
-            }
-        }
-    }
-
-    pub(crate) async fn consume_oauth_login_state(
-        &self,
-        provider: &str,
-        state: &str,
-        binding_hash: Option<&str>,
-    ) -> Result<Option<OAuthLoginStatePayload>, ProxyError> {
-        let now = self.backend_time.now_ts();
-        let mut tx = self.pool.begin().await?;
-
-        sqlx::query(
-            "DELETE FROM oauth_login_states WHERE expires_at < ? OR consumed_at IS NOT NULL",
-        )
-        .bind(now)
-        .execute(&mut *tx)
-        .await?;
-
-        let row = if let Some(hash) = binding_hash
-            .map(str::trim)
-            .filter(|value| !value.is_empty())
-        {
-            sqlx::query_as::<_, (Option<String>, Option<String>)>(
-                r#"SELECT redirect_to, bind_token_id
-                   FROM oauth_login_states
-                   WHERE state = ?
-                     AND provider = ?
-                     AND consumed_at IS NULL
-                     AND expires_at >= ?
-                     AND binding_hash = ?
-                   LIMIT 1"#,
-            )
-            .bind(state)
-            .bind(provider)
-            .bind(now)
-            .bind(hash)
-            .fetch_optional(&mut *tx)
-            .await?
-        } else {
-            sqlx::query_as::<_, (Option<String>, Option<String>)>(
-                r#"SELECT redirect_to, bind_token_id
-                   FROM oauth_login_states
-                   WHERE state = ?
-                     AND provider = ?
-                     AND consumed_at IS NULL
-                     AND expires_at >= ?
-                     AND binding_hash IS NULL
-                   LIMIT 1"#,
-            )
-            .bind(state)
-            .bind(provider)
-            .bind(now)
-            .fetch_optional(&mut *tx)
-            .await?
-        };
-
-        let Some((redirect_to, bind_token_id)) = row else {
-            tx.rollback().await.ok();
-            return Ok(None);
-        };
-
-        let updated = sqlx::query(
-            r#"UPDATE oauth_login_states
-               SET consumed_at = ?
-               WHERE state = ? AND provider = ? AND consumed_at IS NULL"#,
-        )
-        .bind(now)
-        .bind(state)
-        .bind(provider)
-        .execute(&mut *tx)
-        .await?;
-
-        if updated.rows_affected() == 0 {
-            tx.rollback().await.ok();
-            return Ok(None);
-        }
-
-        tx.commit().await?;
-        Ok(Some(OAuthLoginStatePayload {
-            redirect_to,
-            bind_token_id,
-        }))
-    }
-
 }
@@ -32,6 +32,37 @@ impl KeyStore {
         Ok(allow)
     }
 
+    pub(crate) async fn cross_key_retry_settings(
+        &self,
+    ) -> Result<CrossKeyRetrySettings, ProxyError> {
+        Ok(self
+            .get_meta_string(META_KEY_CROSS_KEY_RETRY_V1)
+            .await?
+            .and_then(|raw| serde_json::from_str::<CrossKeyRetrySettings>(&raw).ok())
+            .and_then(|settings| normalize_cross_key_retry_settings(&settings).ok())
+            .unwrap_or_default())
+    }
+
+    pub(crate) async fn response_cache_settings(&self) -> Result<ResponseCacheSettings, ProxyError> {
+        Ok(self
+            .get_meta_string(META_KEY_RESPONSE_CACHE_V1)
+            .await?
+            .and_then(|raw| serde_json::from_str::<ResponseCacheSettings>(&raw).ok())
+            .and_then(|settings| normalize_response_cache_settings(&settings).ok())
+            .unwrap_or_default())
+    }
+
+    pub(crate) async fn request_coalescing_settings(
+        &self,
+    ) -> Result<RequestCoalescingSettings, ProxyError> {
+        Ok(self
+            .get_meta_string(META_KEY_REQUEST_COALESCING_V1)
+            .await?
+            .and_then(|raw| serde_json::from_str::<RequestCoalescingSettings>(&raw).ok())
+            .and_then(|settings| normalize_request_coalescing_settings(&settings).ok())
+            .unwrap_or_default())
+    }
+
     pub(crate) async fn get_system_settings(&self) -> Result<SystemSettings, ProxyError> {
         let request_rate_limit = self
             .get_meta_i64(META_KEY_REQUEST_RATE_LIMIT_V1)
@@ -73,6 +104,10 @@ impl KeyStore {
             .unwrap_or(API_REBALANCE_PERCENT_DEFAULT)
             .clamp(API_REBALANCE_PERCENT_MIN, API_REBALANCE_PERCENT_MAX);
         let api_rebalance_percent = normalized_api_rebalance_percent(api_rebalance_enabled);
+        let key_selection_mode = self.key_selection_mode().await?;
+        let cross_key_retry = self.cross_key_retry_settings().await?;
+        let response_cache = self.response_cache_settings().await?;
+        let request_coalescing = self.request_coalescing_settings().await?;
         let upstream_project_id_mode = self
             .get_meta_string(META_KEY_UPSTREAM_PROJECT_ID_MODE_V1)
             .await?
@@ -191,6 +226,7 @@ impl KeyStore {
             rebalance_mcp_session_percent,
             api_rebalance_enabled,
             api_rebalance_percent,
+            key_selection_mode,
             upstream_project_id_mode,
             upstream_project_id_fixed_value,
             upstream_mcp_user_agent,
@@ -203,6 +239,9 @@ impl KeyStore {
             trusted_proxy_cidrs,
             trusted_client_ip_headers,
             request_log_retention,
+            cross_key_retry,
+            response_cache,
+            request_coalescing,
         };
         Ok(settings)
     }
@@ -289,6 +328,10 @@ impl KeyStore {
         let previous_request_log_retention = current_settings.request_log_retention;
         let request_log_retention =
             normalize_request_log_retention_settings(&settings.request_log_retention)?;
+        let cross_key_retry = normalize_cross_key_retry_settings(&settings.cross_key_retry)?;
+        let response_cache = normalize_response_cache_settings(&settings.response_cache)?;
+        let request_coalescing =
+            normalize_request_coalescing_settings(&settings.request_coalescing)?;
         if settings.auth_token_log_retention_days < current_settings.auth_token_log_retention_days {
             self.rebuild_account_usage_rollup_buckets_v1().await?;
         }
@@ -324,6 +367,30 @@ impl KeyStore {
             normalized_api_rebalance_percent,
         )
         .await?;
+        self.set_meta_string(
+            META_KEY_KEY_SELECTION_MODE_V1,
+            settings.key_selection_mode.as_meta_value(),
+        )
+        .await?;
+        self.set_meta_string(
+            META_KEY_CROSS_KEY_RETRY_V1,
+            &serde_json::to_string(&cross_key_retry).unwrap_or_else(|_| "{}".to_string()),
+        )
+        .await?;
+        self.set_meta_string(
+            META_KEY_RESPONSE_CACHE_V1,
+            &serde_json::to_string(&response_cache).unwrap_or_else(|_| "{}".to_string()),
+        )
+        .await?;
+        if !response_cache.enabled {
+            // Do not resurrect stale answers if the cache is switched back on later.
+            self.clear_response_cache().await?;
+        }
+        self.set_meta_string(
+            META_KEY_REQUEST_COALESCING_V1,
+            &serde_json::to_string(&request_coalescing).unwrap_or_else(|_| "{}".to_string()),
+        )
+        .await?;
         self.set_meta_string(
             META_KEY_UPSTREAM_PROJECT_ID_MODE_V1,
             settings.upstream_project_id_mode.as_meta_value(),
@@ -446,6 +513,7 @@ impl KeyStore {
             rebalance_mcp_session_percent: normalized_rebalance_mcp_session_percent,
             api_rebalance_enabled: settings.api_rebalance_enabled,
             api_rebalance_percent: normalized_api_rebalance_percent,
+            key_selection_mode: settings.key_selection_mode,
             upstream_project_id_mode: settings.upstream_project_id_mode,
             upstream_project_id_fixed_value: settings.upstream_project_id_fixed_value.clone(),
             upstream_mcp_user_agent: settings.upstream_mcp_user_agent.clone(),
@@ -459,6 +527,9 @@ impl KeyStore {
             trusted_proxy_cidrs: trusted_client_ip.trusted_proxy_cidrs,
             trusted_client_ip_headers: trusted_client_ip.trusted_client_ip_headers,
             request_log_retention: request_log_retention.clone(),
+            cross_key_retry,
+            response_cache,
+            request_coalescing,
         };
         *self.request_log_retention_cache.write().await = Some(request_log_retention.clone());
         if previous_request_log_retention.max_log_retention_days
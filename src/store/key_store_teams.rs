@@ -0,0 +1,499 @@
+const TEAM_COLUMNS: &str = "t.id, t.name, t.hourly_credits_limit, t.daily_credits_limit, \
+     t.monthly_credits_limit, t.enforce_member_limits, t.created_at, t.updated_at, \
+     (SELECT COUNT(*) FROM team_members tm WHERE tm.team_id = t.id) AS member_count, \
+     (SELECT COUNT(*) FROM team_tokens tt JOIN auth_tokens at ON at.id = tt.token_id \
+      WHERE tt.team_id = t.id AND at.deleted_at IS NULL) AS token_count";
+
+fn team_from_row(row: &sqlx::sqlite::SqliteRow) -> Result<Team, sqlx::Error> {
+    Ok(Team {
+        id: row.try_get("id")?,
+        name: row.try_get("name")?,
+        limits: TeamQuotaLimits {
+            hourly_credits_limit: row.try_get("hourly_credits_limit")?,
+            daily_credits_limit: row.try_get("daily_credits_limit")?,
+            monthly_credits_limit: row.try_get("monthly_credits_limit")?,
+        },
+        enforce_member_limits: row.try_get::<i64, _>("enforce_member_limits")? != 0,
+        member_count: row.try_get("member_count")?,
+        token_count: row.try_get("token_count")?,
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
+
+impl KeyStore {
+    pub(crate) async fn ensure_teams_schema(&self) -> Result<(), ProxyError> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS teams (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL,
+                hourly_credits_limit INTEGER NOT NULL,
+                daily_credits_limit INTEGER NOT NULL,
+                monthly_credits_limit INTEGER NOT NULL,
+                enforce_member_limits INTEGER NOT NULL DEFAULT 1,
+                created_at INTEGER NOT NULL,
+                updated_at INTEGER NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        // A user belongs to at most one team, so every account charge maps to one pool.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS team_members (
+                user_id TEXT PRIMARY KEY,
+                team_id TEXT NOT NULL,
+                role TEXT NOT NULL,
+                joined_at INTEGER NOT NULL,
+                FOREIGN KEY (user_id) REFERENCES users(id),
+                FOREIGN KEY (team_id) REFERENCES teams(id)
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_team_members_team ON team_members(team_id, joined_at)",
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS team_tokens (
+                token_id TEXT PRIMARY KEY,
+                team_id TEXT NOT NULL,
+                created_by TEXT,
+                created_at INTEGER NOT NULL,
+                FOREIGN KEY (token_id) REFERENCES auth_tokens(id),
+                FOREIGN KEY (team_id) REFERENCES teams(id)
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_team_tokens_team ON team_tokens(team_id)")
+            .execute(&self.pool)
+            .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS team_usage_buckets (
+                team_id TEXT NOT NULL,
+                bucket_start INTEGER NOT NULL,
+                granularity TEXT NOT NULL,
+                count INTEGER NOT NULL,
+                PRIMARY KEY (team_id, bucket_start, granularity)
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub(crate) async fn list_teams(&self) -> Result<Vec<Team>, ProxyError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {TEAM_COLUMNS} FROM teams t ORDER BY t.name COLLATE NOCASE, t.id"
+        ))
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.iter().map(team_from_row).collect::<Result<_, _>>()?)
+    }
+
+    pub(crate) async fn fetch_team(&self, team_id: &str) -> Result<Option<Team>, ProxyError> {
+        let row = sqlx::query(&format!("SELECT {TEAM_COLUMNS} FROM teams t WHERE t.id = ?"))
+            .bind(team_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(row.as_ref().map(team_from_row).transpose()?)
+    }
+
+    pub(crate) async fn create_team(&self, input: &TeamInput) -> Result<Team, ProxyError> {
+        const ID_ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+        let now = self.backend_time.now_ts();
+        loop {
+            let id = random_string(ID_ALPHABET, 12);
+            let res = sqlx::query(
+                r#"INSERT INTO teams
+                   (id, name, hourly_credits_limit, daily_credits_limit, monthly_credits_limit,
+                    enforce_member_limits, created_at, updated_at)
+                   VALUES (?, ?, ?, ?, ?, ?, ?, ?)"#,
+            )
+            .bind(&id)
+            .bind(input.name.trim())
+            .bind(input.limits.hourly_credits_limit)
+            .bind(input.limits.daily_credits_limit)
+            .bind(input.limits.monthly_credits_limit)
+            .bind(if input.enforce_member_limits { 1 } else { 0 })
+            .bind(now)
+            .bind(now)
+            .execute(&self.pool)
+            .await;
+            match res {
+                Ok(_) => {
+                    return self.fetch_team(&id).await?.ok_or_else(|| {
+                        ProxyError::Other("team vanished after insert".to_string())
+                    });
+                }
+                Err(sqlx::Error::Database(db_err)) if db_err.is_unique_violation() => continue,
+                Err(err) => return Err(ProxyError::Database(err)),
+            }
+        }
+    }
+
+    pub(crate) async fn update_team(
+        &self,
+        team_id: &str,
+        input: &TeamInput,
+    ) -> Result<Option<Team>, ProxyError> {
+        let updated = sqlx::query(
+            r#"UPDATE teams
+               SET name = ?,
+                   hourly_credits_limit = ?,
+                   daily_credits_limit = ?,
+                   monthly_credits_limit = ?,
+                   enforce_member_limits = ?,
+                   updated_at = ?
+               WHERE id = ?"#,
+        )
+        .bind(input.name.trim())
+        .bind(input.limits.hourly_credits_limit)
+        .bind(input.limits.daily_credits_limit)
+        .bind(input.limits.monthly_credits_limit)
+        .bind(if input.enforce_member_limits { 1 } else { 0 })
+        .bind(self.backend_time.now_ts())
+        .bind(team_id)
+        .execute(&self.pool)
+        .await?;
+        if updated.rows_affected() == 0 {
+            return Ok(None);
+        }
+        self.fetch_team(team_id).await
+    }
+
+    /// Deletes a team, releases its members back to their personal limits and soft-deletes its
+    /// tokens so they can not keep running without a quota pool. Returns `false` when the team
+    /// does not exist.
+    pub(crate) async fn delete_team(&self, team_id: &str) -> Result<bool, ProxyError> {
+        let now = self.backend_time.now_ts();
+        let mut tx = self.pool.begin().await?;
+        sqlx::query(
+            r#"UPDATE auth_tokens
+               SET enabled = 0, deleted_at = COALESCE(deleted_at, ?)
+               WHERE id IN (SELECT token_id FROM team_tokens WHERE team_id = ?)"#,
+        )
+        .bind(now)
+        .bind(team_id)
+        .execute(&mut *tx)
+        .await?;
+        for table in ["team_tokens", "team_members", "team_usage_buckets"] {
+            sqlx::query(&format!("DELETE FROM {table} WHERE team_id = ?"))
+                .bind(team_id)
+                .execute(&mut *tx)
+                .await?;
+        }
+        let deleted = sqlx::query("DELETE FROM teams WHERE id = ?")
+            .bind(team_id)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(deleted.rows_affected() > 0)
+    }
+
+    pub(crate) async fn list_team_members(
+        &self,
+        team_id: &str,
+    ) -> Result<Vec<TeamMember>, ProxyError> {
+        let rows = sqlx::query_as::<_, (String, Option<String>, Option<String>, String, i64)>(
+            r#"SELECT tm.user_id, u.display_name, u.username, tm.role, tm.joined_at
+               FROM team_members tm
+               LEFT JOIN users u ON u.id = tm.user_id
+               WHERE tm.team_id = ?
+               ORDER BY tm.joined_at ASC, tm.user_id ASC"#,
+        )
+        .bind(team_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(
+                |(user_id, display_name, username, role, joined_at)| TeamMember {
+                    user_id,
+                    display_name,
+                    username,
+                    role: TeamRole::parse(&role).unwrap_or(TeamRole::Member),
+                    joined_at,
+                },
+            )
+            .collect())
+    }
+
+    /// Adds a user to a team or changes their role there. Returns `false` when the user already
+    /// belongs to another team.
+    pub(crate) async fn upsert_team_member(
+        &self,
+        team_id: &str,
+        user_id: &str,
+        role: TeamRole,
+    ) -> Result<bool, ProxyError> {
+        let updated = sqlx::query(
+            r#"INSERT INTO team_members (user_id, team_id, role, joined_at)
+               VALUES (?, ?, ?, ?)
+               ON CONFLICT(user_id) DO UPDATE SET role = excluded.role
+               WHERE team_members.team_id = excluded.team_id"#,
+        )
+        .bind(user_id)
+        .bind(team_id)
+        .bind(role.as_str())
+        .bind(self.backend_time.now_ts())
+        .execute(&self.pool)
+        .await?;
+        Ok(updated.rows_affected() > 0)
+    }
+
+    pub(crate) async fn remove_team_member(
+        &self,
+        team_id: &str,
+        user_id: &str,
+    ) -> Result<bool, ProxyError> {
+        let deleted = sqlx::query("DELETE FROM team_members WHERE team_id = ? AND user_id = ?")
+            .bind(team_id)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(deleted.rows_affected() > 0)
+    }
+
+    pub(crate) async fn fetch_user_team_membership(
+        &self,
+        user_id: &str,
+    ) -> Result<Option<TeamMembership>, ProxyError> {
+        let Some((team_id, role)) = sqlx::query_as::<_, (String, String)>(
+            "SELECT team_id, role FROM team_members WHERE user_id = ?",
+        )
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?
+        else {
+            return Ok(None);
+        };
+        Ok(self.fetch_team(&team_id).await?.map(|team| TeamMembership {
+            team,
+            role: TeamRole::parse(&role).unwrap_or(TeamRole::Member),
+        }))
+    }
+
+    pub(crate) async fn fetch_team_for_token(
+        &self,
+        token_id: &str,
+    ) -> Result<Option<Team>, ProxyError> {
+        let team_id = sqlx::query_scalar::<_, String>(
+            "SELECT team_id FROM team_tokens WHERE token_id = ?",
+        )
+        .bind(token_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        match team_id {
+            Some(team_id) => self.fetch_team(&team_id).await,
+            None => Ok(None),
+        }
+    }
+
+    pub(crate) async fn list_team_tokens(
+        &self,
+        team_id: &str,
+    ) -> Result<Vec<TeamToken>, ProxyError> {
+        let rows = sqlx::query_as::<_, (String, Option<String>, i64, Option<String>, i64, Option<i64>)>(
+            r#"SELECT tt.token_id, at.note, at.enabled, tt.created_by, tt.created_at, at.last_used_at
+               FROM team_tokens tt
+               JOIN auth_tokens at ON at.id = tt.token_id
+               WHERE tt.team_id = ? AND at.deleted_at IS NULL
+               ORDER BY tt.created_at DESC, tt.token_id ASC"#,
+        )
+        .bind(team_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(
+                |(token_id, note, enabled, created_by, created_at, last_used_at)| TeamToken {
+                    token_id,
+                    note: note.filter(|note| !note.is_empty()),
+                    enabled: enabled != 0,
+                    created_by,
+                    created_at,
+                    last_used_at,
+                },
+            )
+            .collect())
+    }
+
+    /// Issues an access token owned by the team. `created_by` records the admin or member who
+    /// asked for it.
+    pub(crate) async fn create_team_token(
+        &self,
+        team_id: &str,
+        note: Option<&str>,
+        created_by: Option<&str>,
+    ) -> Result<AuthTokenSecret, ProxyError> {
+        let secret = self.create_access_token(note).await?;
+        sqlx::query(
+            "INSERT INTO team_tokens (token_id, team_id, created_by, created_at) VALUES (?, ?, ?, ?)",
+        )
+        .bind(&secret.id)
+        .bind(team_id)
+        .bind(created_by)
+        .bind(self.backend_time.now_ts())
+        .execute(&self.pool)
+        .await?;
+        Ok(secret)
+    }
+
+    /// Soft-deletes a team-owned token. Returns `false` when the token is not owned by the team.
+    pub(crate) async fn delete_team_token(
+        &self,
+        team_id: &str,
+        token_id: &str,
+    ) -> Result<bool, ProxyError> {
+        let owned = sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM team_tokens WHERE team_id = ? AND token_id = ?",
+        )
+        .bind(team_id)
+        .bind(token_id)
+        .fetch_one(&self.pool)
+        .await?;
+        if owned == 0 {
+            return Ok(false);
+        }
+        self.delete_access_token(token_id).await?;
+        Ok(true)
+    }
+
+    /// Adds settled credits to the pool of the team behind `billing_subject`, if any. Runs inside
+    /// the billing transaction so team counters never drift from the member's own counters.
+    pub(crate) async fn record_team_usage_for_billing_subject(
+        &self,
+        tx: &mut Transaction<'_, Sqlite>,
+        billing_subject: &str,
+        charge_ts: i64,
+        credits: i64,
+    ) -> Result<(), ProxyError> {
+        if credits <= 0 {
+            return Ok(());
+        }
+        let team_id = if let Some(user_id) = billing_subject.strip_prefix("account:") {
+            sqlx::query_scalar::<_, String>("SELECT team_id FROM team_members WHERE user_id = ?")
+                .bind(user_id)
+                .fetch_optional(&mut **tx)
+                .await?
+        } else if let Some(token_id) = billing_subject.strip_prefix("token:") {
+            sqlx::query_scalar::<_, String>("SELECT team_id FROM team_tokens WHERE token_id = ?")
+                .bind(token_id)
+                .fetch_optional(&mut **tx)
+                .await?
+        } else {
+            None
+        };
+        let Some(team_id) = team_id else {
+            return Ok(());
+        };
+
+        let charge_time = Utc
+            .timestamp_opt(charge_ts, 0)
+            .single()
+            .unwrap_or_else(|| self.backend_time.now_utc());
+        for (bucket_start, granularity) in [
+            (charge_ts - charge_ts.rem_euclid(SECS_PER_MINUTE), GRANULARITY_MINUTE),
+            (local_day_bucket_start_utc_ts(charge_ts), GRANULARITY_DAY),
+            (start_of_month(charge_time).timestamp(), GRANULARITY_MONTH),
+        ] {
+            sqlx::query(
+                r#"
+                INSERT INTO team_usage_buckets (team_id, bucket_start, granularity, count)
+                VALUES (?, ?, ?, ?)
+                ON CONFLICT(team_id, bucket_start, granularity)
+                DO UPDATE SET count = team_usage_buckets.count + excluded.count
+                "#,
+            )
+            .bind(&team_id)
+            .bind(bucket_start)
+            .bind(granularity)
+            .bind(credits)
+            .execute(&mut **tx)
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// Same as [`Self::record_team_usage_for_billing_subject`] for charges made outside a
+    /// billing transaction.
+    pub(crate) async fn increment_team_usage_for_billing_subject(
+        &self,
+        billing_subject: &str,
+        charge_ts: i64,
+        credits: i64,
+    ) -> Result<(), ProxyError> {
+        let mut tx = self.pool.begin().await?;
+        self.record_team_usage_for_billing_subject(&mut tx, billing_subject, charge_ts, credits)
+            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Sum of the team's buckets of `granularity` starting at or after `since`.
+    pub(crate) async fn sum_team_usage_buckets(
+        &self,
+        team_id: &str,
+        granularity: &str,
+        since: i64,
+    ) -> Result<i64, ProxyError> {
+        let total = sqlx::query_scalar::<_, Option<i64>>(
+            r#"SELECT SUM(count) FROM team_usage_buckets
+               WHERE team_id = ? AND granularity = ? AND bucket_start >= ?"#,
+        )
+        .bind(team_id)
+        .bind(granularity)
+        .bind(since)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(total.unwrap_or(0))
+    }
+
+    pub(crate) async fn fetch_team_usage_values(
+        &self,
+        team_id: &str,
+        granularity: &str,
+        bucket_start_at_least: i64,
+        bucket_start_before: i64,
+    ) -> Result<HashMap<i64, i64>, ProxyError> {
+        let rows = sqlx::query_as::<_, (i64, i64)>(
+            r#"SELECT bucket_start, count FROM team_usage_buckets
+               WHERE team_id = ? AND granularity = ? AND bucket_start >= ? AND bucket_start < ?
+               ORDER BY bucket_start ASC"#,
+        )
+        .bind(team_id)
+        .bind(granularity)
+        .bind(bucket_start_at_least)
+        .bind(bucket_start_before)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().collect())
+    }
+
+    pub(crate) async fn delete_old_team_usage_buckets(
+        &self,
+        granularity: &str,
+        threshold: i64,
+    ) -> Result<(), ProxyError> {
+        sqlx::query("DELETE FROM team_usage_buckets WHERE granularity = ? AND bucket_start < ?")
+            .bind(granularity)
+            .bind(threshold)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
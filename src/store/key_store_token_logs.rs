@@ -459,6 +459,7 @@ impl KeyStore {
         selection_effect_code: Option<&str>,
         key_id: Option<&str>,
         operational_class: Option<&str>,
+        search: Option<&str>,
         cursor: Option<&RequestLogsCursor>,
         direction: RequestLogsCursorDirection,
     ) -> Result<TokenLogsCursorPage, ProxyError> {
@@ -566,6 +567,13 @@ impl KeyStore {
                 &legacy_operational_class_case_sql,
             );
         }
+        if let Some(search) = search {
+            rows_query.push(
+                " AND auth_token_logs.request_log_id IN (SELECT rowid FROM observability.request_log_search WHERE request_log_search MATCH ",
+            );
+            rows_query.push_bind(search);
+            rows_query.push(")");
+        }
         Self::push_desc_cursor_clause(
             &mut rows_query,
             "auth_token_logs.created_at",
@@ -1435,7 +1443,7 @@ impl KeyStore {
         .bind(STATUS_EXHAUSTED)
         .bind(now)
         .bind(now)
-        .bind(key)
+        .bind(self.api_key_lookup_value(key))
         .bind(STATUS_DISABLED)
         .bind(STATUS_EXHAUSTED)
         .execute(&self.pool)
@@ -1454,7 +1462,7 @@ impl KeyStore {
         )
         .bind(STATUS_ACTIVE)
         .bind(now)
-        .bind(key)
+        .bind(self.api_key_lookup_value(key))
         .bind(STATUS_EXHAUSTED)
         .execute(&self.pool)
         .await?;
@@ -1546,9 +1554,13 @@ impl KeyStore {
         let mut builder = QueryBuilder::new(
             "SELECT api_key FROM api_keys WHERE deleted_at IS NULL AND api_key IN (",
         );
+        let lookups = api_keys
+            .iter()
+            .map(|api_key| (self.api_key_lookup_value(api_key), api_key))
+            .collect::<HashMap<_, _>>();
         let mut separated = builder.separated(", ");
-        for api_key in api_keys {
-            separated.push_bind(api_key);
+        for lookup in lookups.keys() {
+            separated.push_bind(lookup);
         }
         separated.push_unseparated(")");
 
@@ -1556,7 +1568,10 @@ impl KeyStore {
             .build_query_scalar::<String>()
             .fetch_all(&self.pool)
             .await?;
-        Ok(rows.into_iter().collect())
+        Ok(rows
+            .into_iter()
+            .filter_map(|lookup| lookups.get(&lookup).map(|api_key| (*api_key).clone()))
+            .collect())
     }
 
     // Admin ops: add/undelete key by secret and optionally assign a group.
@@ -1665,6 +1680,7 @@ impl KeyStore {
         proxy_affinity: Option<&forward_proxy::ForwardProxyAffinityRecord>,
         hint_only_proxy_affinity: bool,
     ) -> Result<(String, ApiKeyUpsertStatus), ProxyError> {
+        let lookup = self.api_key_lookup_value(api_key);
         let mut tx = self.pool.begin().await?;
         let now = self.backend_time.now_ts();
 
@@ -1673,7 +1689,7 @@ impl KeyStore {
                 sqlx::query_as::<_, (String, Option<i64>, Option<String>, Option<String>, Option<String>)>(
                     "SELECT id, deleted_at, group_name, registration_ip, registration_region FROM api_keys WHERE api_key = ? LIMIT 1",
                 )
-                .bind(api_key)
+                .bind(&lookup)
                 .fetch_optional(&mut *tx)
                 .await?
             {
@@ -1754,6 +1770,7 @@ impl KeyStore {
                 INSERT INTO api_keys (
                     id,
                     api_key,
+                    api_key_ciphertext,
                     group_name,
                     registration_ip,
                     registration_region,
@@ -1761,11 +1778,12 @@ impl KeyStore {
                     created_at,
                     status_changed_at
                 )
-                VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
                 "#,
             )
             .bind(&id)
-            .bind(api_key)
+            .bind(&lookup)
+            .bind(self.seal_api_key_secret(api_key)?)
             .bind(group)
             .bind(registration_ip)
             .bind(registration_region)
@@ -1863,7 +1881,7 @@ impl KeyStore {
             "#,
         )
         .bind(timestamp)
-        .bind(key)
+        .bind(self.api_key_lookup_value(key))
         .execute(&self.pool)
         .await?;
         Ok(())
@@ -2083,6 +2101,7 @@ impl KeyStore {
         let region_counts = self
             .fetch_api_key_region_facets(&groups, &statuses, registration_ip)
             .await?;
+        let tiers = self.fetch_api_key_tier_capacity().await?;
 
         Ok(PaginatedApiKeyMetrics {
             items,
@@ -2094,6 +2113,7 @@ impl KeyStore {
                 statuses: status_counts,
                 regions: region_counts,
             },
+            tiers,
         })
     }
 
@@ -2170,6 +2190,7 @@ impl KeyStore {
                 client_ip_source,
                 client_ip_trusted,
                 ip_headers,
+                trace_id,
                 created_at
             FROM request_logs
             WHERE visibility = ? AND created_at >= ?
@@ -2229,6 +2250,7 @@ impl KeyStore {
                 client_ip_source,
                 client_ip_trusted,
                 ip_headers,
+                trace_id,
                 created_at
             FROM request_logs
             WHERE visibility = ?
@@ -2551,7 +2573,7 @@ impl KeyStore {
             fallback_reason: row.try_get("fallback_reason")?,
             operational_class,
             request_body: request_body.unwrap_or_default(),
-            response_body: response_body.unwrap_or_default(),
+            response_body: decode_request_log_body(response_body.unwrap_or_default()),
             request_body_bytes: row.try_get("request_body_bytes")?,
             response_body_bytes: row.try_get("response_body_bytes")?,
             request_body_sha256: row.try_get("request_body_sha256")?,
@@ -2566,6 +2588,7 @@ impl KeyStore {
             client_ip_source: row.try_get("client_ip_source")?,
             client_ip_trusted: row.try_get::<i64, _>("client_ip_trusted")? != 0,
             ip_headers,
+            trace_id: row.try_get("trace_id")?,
         })
     }
 
@@ -2574,7 +2597,9 @@ impl KeyStore {
     ) -> Result<RequestLogBodiesRecord, sqlx::Error> {
         Ok(RequestLogBodiesRecord {
             request_body: row.try_get("request_body")?,
-            response_body: row.try_get("response_body")?,
+            response_body: row
+                .try_get::<Option<Vec<u8>>, _>("response_body")?
+                .map(decode_request_log_body),
             request_body_bytes: row.try_get("request_body_bytes")?,
             response_body_bytes: row.try_get("response_body_bytes")?,
             request_body_sha256: row.try_get("request_body_sha256")?,
@@ -580,13 +580,15 @@ impl KeyStore {
             for _ in 0..8 {
                 let token_id = random_string(ALPHABET, 4);
                 let secret = random_string(ALPHABET, 24);
+                let (stored_secret, secret_hash) = self.access_token_secret_columns(&secret);
                 let inserted_token = sqlx::query(
                     r#"INSERT INTO auth_tokens
-                       (id, secret, enabled, note, group_name, total_requests, created_at, last_used_at, deleted_at)
-                       VALUES (?, ?, 1, ?, NULL, 0, ?, NULL, NULL)"#,
+                       (id, secret, secret_hash, enabled, note, group_name, total_requests, created_at, last_used_at, deleted_at)
+                       VALUES (?, ?, ?, 1, ?, NULL, 0, ?, NULL, NULL)"#,
                 )
                 .bind(&token_id)
-                .bind(&secret)
+                .bind(&stored_secret)
+                .bind(&secret_hash)
                 .bind(&note)
                 .bind(now)
                 .execute(&mut *tx)
@@ -717,9 +719,9 @@ impl KeyStore {
     pub(crate) async fn fetch_active_token_secret_by_id(
         &self,
         token_id: &str,
-    ) -> Result<Option<AuthTokenSecret>, ProxyError> {
-        let row = sqlx::query_as::<_, (String,)>(
-            r#"SELECT secret
+    ) -> Result<Option<StoredAccessTokenSecret>, ProxyError> {
+        let row = sqlx::query_as::<_, (String, Option<String>)>(
+            r#"SELECT secret, secret_hash
                FROM auth_tokens
                WHERE id = ? AND enabled = 1 AND deleted_at IS NULL
                LIMIT 1"#,
@@ -728,9 +730,8 @@ impl KeyStore {
         .fetch_optional(&self.pool)
         .await?;
 
-        Ok(row.map(|(secret,)| AuthTokenSecret {
-            id: token_id.to_string(),
-            token: Self::compose_full_token(token_id, &secret),
+        Ok(row.map(|(secret, secret_hash)| {
+            StoredAccessTokenSecret::new(token_id.to_string(), secret, secret_hash)
         }))
     }
 
@@ -819,7 +820,7 @@ impl KeyStore {
                                 }
                                 self.cache_token_binding(preferred_token_id, Some(user_id))
                                     .await;
-                                return Ok(preferred_secret);
+                                return preferred_secret.reveal();
                             }
                             Err(sqlx::Error::Database(db_err))
                                 if db_err.message().contains("database is locked") =>
@@ -880,7 +881,7 @@ impl KeyStore {
                                 }
                                 self.cache_token_binding(preferred_token_id, Some(user_id))
                                     .await;
-                                return Ok(preferred_secret);
+                                return preferred_secret.reveal();
                             }
                             Err(sqlx::Error::Database(db_err)) if db_err.is_unique_violation() => {
                                 tx.rollback().await.ok();
@@ -917,7 +918,7 @@ impl KeyStore {
 
         if let Some(existing) = self.fetch_user_token_any_status(user_id).await? {
             self.cache_token_binding(&existing.id, Some(user_id)).await;
-            return Ok(existing);
+            return existing.reveal();
         }
 
         const ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
@@ -944,8 +945,9 @@ impl KeyStore {
                     return Err(err);
                 }
             };
-            if let Some((token_id, secret)) = sqlx::query_as::<_, (String, String)>(
-                r#"SELECT b.token_id, t.secret
+            if let Some((token_id, secret, secret_hash)) =
+                sqlx::query_as::<_, (String, String, Option<String>)>(
+                r#"SELECT b.token_id, t.secret, t.secret_hash
                    FROM user_token_bindings b
                    JOIN auth_tokens t ON t.id = b.token_id
                    WHERE b.user_id = ?
@@ -957,10 +959,7 @@ impl KeyStore {
             .await?
             {
                 tx.rollback().await.ok();
-                return Ok(AuthTokenSecret {
-                    id: token_id.clone(),
-                    token: Self::compose_full_token(&token_id, &secret),
-                });
+                return StoredAccessTokenSecret::new(token_id, secret, secret_hash).reveal();
             }
 
             let mut created: Option<(String, String)> = None;
@@ -968,13 +967,15 @@ impl KeyStore {
                 let token_id = random_string(ALPHABET, 4);
                 let secret = random_string(ALPHABET, 24);
 
+                let (stored_secret, secret_hash) = self.access_token_secret_columns(&secret);
                 let inserted_token = sqlx::query(
                     r#"INSERT INTO auth_tokens
-                       (id, secret, enabled, note, group_name, total_requests, created_at, last_used_at, deleted_at)
-                       VALUES (?, ?, 1, ?, NULL, 0, ?, NULL, NULL)"#,
+                       (id, secret, secret_hash, enabled, note, group_name, total_requests, created_at, last_used_at, deleted_at)
+                       VALUES (?, ?, ?, 1, ?, NULL, 0, ?, NULL, NULL)"#,
                 )
                 .bind(&token_id)
-                .bind(&secret)
+                .bind(&stored_secret)
+                .bind(&secret_hash)
                 .bind(&note)
                 .bind(now)
                 .execute(&mut *tx)
@@ -1073,9 +1074,9 @@ impl KeyStore {
     pub(crate) async fn fetch_user_token_any_status(
         &self,
         user_id: &str,
-    ) -> Result<Option<AuthTokenSecret>, ProxyError> {
-        let row = sqlx::query_as::<_, (String, String)>(
-            r#"SELECT b.token_id, t.secret
+    ) -> Result<Option<StoredAccessTokenSecret>, ProxyError> {
+        let row = sqlx::query_as::<_, (String, String, Option<String>)>(
+            r#"SELECT b.token_id, t.secret, t.secret_hash
                FROM user_token_bindings b
                JOIN auth_tokens t ON t.id = b.token_id
                WHERE b.user_id = ?
@@ -1086,9 +1087,8 @@ impl KeyStore {
         .fetch_optional(&self.pool)
         .await?;
 
-        Ok(row.map(|(token_id, secret)| AuthTokenSecret {
-            id: token_id.clone(),
-            token: Self::compose_full_token(&token_id, &secret),
+        Ok(row.map(|(token_id, secret, secret_hash)| {
+            StoredAccessTokenSecret::new(token_id, secret, secret_hash)
         }))
     }
 
@@ -1096,8 +1096,17 @@ impl KeyStore {
         &self,
         user_id: &str,
     ) -> Result<UserTokenLookup, ProxyError> {
-        let row = sqlx::query_as::<_, (String, Option<String>, Option<i64>, Option<i64>)>(
-            r#"SELECT b.token_id, t.secret, t.enabled, t.deleted_at
+        let row = sqlx::query_as::<
+            _,
+            (
+                String,
+                Option<String>,
+                Option<String>,
+                Option<i64>,
+                Option<i64>,
+            ),
+        >(
+            r#"SELECT b.token_id, t.secret, t.secret_hash, t.enabled, t.deleted_at
                FROM user_token_bindings b
                LEFT JOIN auth_tokens t ON t.id = b.token_id
                WHERE b.user_id = ?
@@ -1108,7 +1117,8 @@ impl KeyStore {
         .fetch_optional(&self.pool)
         .await?;
 
-        let Some((token_id, maybe_secret, maybe_enabled, maybe_deleted_at)) = row else {
+        let Some((token_id, maybe_secret, secret_hash, maybe_enabled, maybe_deleted_at)) = row
+        else {
             return Ok(UserTokenLookup::MissingBinding);
         };
         let Some(secret) = maybe_secret else {
@@ -1119,10 +1129,9 @@ impl KeyStore {
             return Ok(UserTokenLookup::Unavailable);
         }
 
-        Ok(UserTokenLookup::Found(AuthTokenSecret {
-            id: token_id.clone(),
-            token: Self::compose_full_token(&token_id, &secret),
-        }))
+        StoredAccessTokenSecret::new(token_id, secret, secret_hash)
+            .reveal()
+            .map(UserTokenLookup::Found)
     }
 
     pub(crate) async fn create_user_session(
@@ -2308,6 +2317,8 @@ impl KeyStore {
                 ),
             });
         }
+        self.record_team_usage_for_billing_subject(&mut tx, &billing_subject, charge_ts, credits)
+            .await?;
 
         let settled_at = self.backend_time.now_ts();
         let updated = sqlx::query(
@@ -14,14 +14,14 @@ use std::os::fd::AsRawFd;
 use std::sync::{Mutex as StdMutex, OnceLock as StdOnceLock};
 use tokio::io::{AsyncWrite, AsyncWriteExt};
 use tracing::log::LevelFilter;
-use tracing::{error, info, warn};
+use tracing::{Instrument, error, info, warn};
 
 mod immediate_transaction;
 mod sqlite_runtime;
 pub(crate) use immediate_transaction::ImmediateSqliteTransaction;
 pub(crate) use sqlite_runtime::{
     SqliteAdmissionDeferReason, SqliteImmediateTransaction, SqliteMaintenanceBulkPermit,
-    SqliteOperation, SqliteReadSnapshot, SqliteRuntime,
+    SqliteOperation, SqliteOperationTotals, SqliteReadSnapshot, SqliteRuntime,
 };
 
 pub(crate) struct ObservabilityOfflineGuard {
@@ -1356,6 +1356,7 @@ CREATE TABLE observability.request_logs_new (
     client_ip_source TEXT,
     client_ip_trusted INTEGER NOT NULL DEFAULT 0,
     ip_headers TEXT,
+    trace_id TEXT,
     visibility TEXT NOT NULL DEFAULT 'visible',
     created_at INTEGER NOT NULL
 )
@@ -2658,7 +2659,10 @@ pub(crate) struct KeyStore {
     pub(crate) request_log_diagnostic_handoff: Mutex<RequestLogDiagnosticHandoff>,
     pub(crate) user_debug_info_shared_cache: RwLock<HashMap<String, UserDebugInfoSharedCacheEntry>>,
     pub(crate) request_stats_coalescer: RequestStatsCoalescer,
+    pub(crate) process_metrics: ProcessMetrics,
     pub(crate) admin_heavy_read_semaphore: Semaphore,
+    pub(crate) api_key_secret_cipher: StdOnceLock<ApiKeySecretCipher>,
+    pub(crate) access_token_secret_hashing: std::sync::atomic::AtomicBool,
     #[cfg(test)]
     pub(crate) forced_pending_claim_miss_log_ids: Mutex<HashSet<i64>>,
     #[cfg(debug_assertions)]
@@ -2720,10 +2724,30 @@ include!("key_store_migrations_b.rs");
 include!("key_store_admin_user_listing.rs");
 include!("key_store_admin_tokens.rs");
 include!("key_store_keys.rs");
+include!("key_store_api_key_secrets.rs");
+include!("key_store_access_token_secrets.rs");
+include!("key_store_access_token_lifetimes.rs");
+include!("key_store_access_token_scopes.rs");
+include!("key_store_request_parameter_policies.rs");
+include!("key_store_response_cache.rs");
+include!("key_store_alert_webhooks.rs");
+include!("key_store_alert_rules.rs");
+include!("key_store_alert_workflow.rs");
+include!("key_store_admin_audit.rs");
+include!("key_store_process_metrics.rs");
+include!("key_store_key_selection.rs");
+include!("key_store_key_groups.rs");
+include!("key_store_key_rate_budgets.rs");
 include!("key_store_account_base_entitlement_backfill.rs");
 include!("key_store_admin_passkey_schema.rs");
 include!("key_store_admin_passkeys.rs");
+include!("key_store_admin_accounts.rs");
+include!("key_store_admin_api_tokens.rs");
+include!("key_store_teams.rs");
+include!("key_store_billing_reservations.rs");
 include!("key_store_sessions.rs");
+include!("key_store_oauth_login_states.rs");
+include!("key_store_registration_invites.rs");
 include!("key_store_mcp_session_bindings.rs");
 include!("key_store_system_settings.rs");
 include!("key_store_upstream_reconciliation.rs");
@@ -2735,6 +2759,8 @@ include!("key_store_upstream_reconciliation_admission.rs");
 include!("key_store_users_and_oauth.rs");
 include!("key_store_linuxdo_credit_recharge.rs");
 include!("key_store_request_log_body_retention.rs");
+include!("key_store_request_log_replays.rs");
+include!("key_store_request_log_search.rs");
 include!("key_store_token_logs.rs");
 include!("key_store_alert_models.rs");
 include!("key_store_alerts.rs");
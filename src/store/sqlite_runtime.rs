@@ -62,6 +62,7 @@ pub(crate) enum SqliteOperation {
     HaEventsRead,
     HaOutboxGc,
     HaOutboxGcWatchdog,
+    RequestLogSearchIndex,
     RequestLogsGc,
     RequestStatsFlush,
     ObservabilityDeferredWrite,
@@ -83,6 +84,7 @@ impl SqliteOperation {
             Self::HaEventsRead => "ha_events_read",
             Self::HaOutboxGc => "ha_outbox_gc",
             Self::HaOutboxGcWatchdog => "ha_outbox_gc_watchdog",
+            Self::RequestLogSearchIndex => "request_log_search_index",
             Self::RequestLogsGc => "request_logs_gc",
             Self::RequestStatsFlush => "request_stats_flush",
             Self::ObservabilityDeferredWrite => "observability_deferred_write",
@@ -102,6 +104,7 @@ impl SqliteOperation {
             | Self::AlertProjection
             | Self::DashboardIntegrityWrite
             | Self::HaOutboxGc
+            | Self::RequestLogSearchIndex
             | Self::RequestLogsGc
             | Self::RequestStatsFlush
             | Self::ObservabilityDeferredWrite
@@ -122,6 +125,7 @@ impl SqliteOperation {
             | Self::HaOutboxGcWatchdog => Duration::from_millis(100),
             Self::ForegroundJobTrigger => Duration::from_millis(250),
             Self::HaOutboxGc
+            | Self::RequestLogSearchIndex
             | Self::RequestLogsGc
             | Self::RequestStatsFlush
             | Self::ObservabilityDeferredWrite
@@ -142,6 +146,7 @@ impl SqliteOperation {
             | Self::HaOutboxGcWatchdog => Duration::from_millis(100),
             Self::ForegroundJobTrigger => Duration::from_millis(100),
             Self::HaOutboxGc
+            | Self::RequestLogSearchIndex
             | Self::RequestLogsGc
             | Self::ServerPressureRebuild
             | Self::ReconciliationProjection => Duration::from_millis(250),
@@ -161,7 +166,8 @@ impl SqliteOperation {
             | Self::AlertProjection
             | Self::DashboardIntegrityWrite
             | Self::ObservabilityDeferredWrite
-            | Self::ReconciliationProjection => Some(100),
+            | Self::ReconciliationProjection
+            | Self::RequestLogSearchIndex => Some(100),
             _ => None,
         }
     }
@@ -173,6 +179,7 @@ impl SqliteOperation {
                 | Self::AlertProjection
                 | Self::DashboardIntegrityWrite
                 | Self::HaOutboxGc
+                | Self::RequestLogSearchIndex
                 | Self::RequestLogsGc
                 | Self::RequestStatsFlush
                 | Self::ObservabilityDeferredWrite
@@ -223,6 +230,30 @@ struct WorkloadWindow {
     minimum_idle_connections: Option<u32>,
     maximum_in_use_connections: u32,
     maximum_acquire_waiters: u32,
+    /// Process-lifetime totals; unlike the window above they are never reset.
+    totals: BTreeMap<SqliteOperation, SqliteOperationTotals>,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct SqliteOperationTotals {
+    pub(crate) calls: u64,
+    pub(crate) errors: u64,
+    pub(crate) deferred: u64,
+    pub(crate) pool_wait_ms: u64,
+    pub(crate) hold_ms: u64,
+}
+
+/// Point-in-time pool state and lifetime per-operation totals for the metrics endpoint.
+#[derive(Clone, Debug)]
+pub(crate) struct SqliteRuntimeMetrics {
+    pub(crate) pool_size: u32,
+    pub(crate) idle_connections: u32,
+    pub(crate) maximum_connections: u32,
+    pub(crate) acquire_waiters: u32,
+    pub(crate) contention_active: bool,
+    pub(crate) contention_events: u64,
+    /// `(operation, workload_class, totals)` rows.
+    pub(crate) operations: Vec<(&'static str, &'static str, SqliteOperationTotals)>,
 }
 
 impl Default for WorkloadWindow {
@@ -235,6 +266,7 @@ impl Default for WorkloadWindow {
             minimum_idle_connections: None,
             maximum_in_use_connections: 0,
             maximum_acquire_waiters: 0,
+            totals: BTreeMap::new(),
         }
     }
 }
@@ -250,6 +282,7 @@ struct SqliteRuntimeInner {
     last_bulk_heap_trim_at: Mutex<Option<Instant>>,
     last_contention_at: Mutex<Option<Instant>>,
     contention_warning_active: AtomicBool,
+    contention_events: AtomicU64,
     foreground_activity: ForegroundActivityMeter,
     acquire_waiters: AtomicU32,
     peak_acquire_waiters: AtomicU32,
@@ -395,6 +428,7 @@ impl SqliteRuntime {
                 last_bulk_heap_trim_at: Mutex::new(None),
                 last_contention_at: Mutex::new(None),
                 contention_warning_active: AtomicBool::new(false),
+                contention_events: AtomicU64::new(0),
                 foreground_activity: ForegroundActivityMeter::new(),
                 acquire_waiters: AtomicU32::new(0),
                 peak_acquire_waiters: AtomicU32::new(0),
@@ -660,6 +694,27 @@ impl SqliteRuntime {
         }
     }
 
+    pub(crate) fn metrics_snapshot(&self) -> SqliteRuntimeMetrics {
+        let operations = self
+            .inner
+            .workload
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .totals
+            .iter()
+            .map(|(operation, totals)| (operation.as_str(), operation.workload_class(), *totals))
+            .collect();
+        SqliteRuntimeMetrics {
+            pool_size: self.inner.pool.size(),
+            idle_connections: self.inner.pool.num_idle().min(u32::MAX as usize) as u32,
+            maximum_connections: self.inner.maximum_connections,
+            acquire_waiters: self.inner.acquire_waiters.load(AtomicOrdering::Acquire),
+            contention_active: self.recent_contention_active(),
+            contention_events: self.inner.contention_events.load(AtomicOrdering::Relaxed),
+            operations,
+        }
+    }
+
     pub(crate) fn record_retry(&self, operation: SqliteOperation) {
         let mut window = self
             .inner
@@ -955,6 +1010,9 @@ impl SqliteRuntime {
     ) {
         let transient = is_transient_sqlite_write_error(err);
         let contention_entered = if transient {
+            self.inner
+                .contention_events
+                .fetch_add(1, AtomicOrdering::Relaxed);
             *self
                 .inner
                 .last_contention_at
@@ -1096,6 +1154,16 @@ impl SqliteRuntime {
                 metrics.hold_histogram[hold_bucket].saturating_add(1);
         }
         metrics.rows_affected = metrics.rows_affected.saturating_add(rows_affected);
+        let totals = window.totals.entry(operation).or_default();
+        totals.calls = totals.calls.saturating_add(1);
+        totals.errors = totals.errors.saturating_add(u64::from(error));
+        totals.deferred = totals
+            .deferred
+            .saturating_add(u64::from(deferred.is_some()));
+        totals.pool_wait_ms = totals
+            .pool_wait_ms
+            .saturating_add(pool_wait.as_millis() as u64);
+        totals.hold_ms = totals.hold_ms.saturating_add(hold.as_millis() as u64);
         let idle_connections = self.inner.pool.num_idle().min(u32::MAX as usize) as u32;
         let in_use_connections = self.inner.pool.size().saturating_sub(idle_connections);
         window.minimum_idle_connections = Some(
@@ -12,6 +12,7 @@ use std::sync::{
     OnceLock,
     atomic::{AtomicBool, AtomicI64, AtomicU8, AtomicU64, Ordering},
 };
+use tracing::Instrument;
 
 #[derive(Clone, Debug)]
 struct TokenQuota {
@@ -800,6 +801,7 @@ pub struct TavilyProxy {
     pub(crate) api_key_geo_origin: String,
     token_quota: TokenQuota,
     token_request_limit: TokenRequestLimit,
+    key_rate_budgets: KeyRateBudgets,
     user_business_calls_1h_window: UserBusinessCalls1hWindow,
     user_business_call_bridge_diagnostics: Arc<Mutex<UserBusinessCallBridgeDiagnostics>>,
     pub(crate) research_request_affinity: Arc<Mutex<TokenAffinityState>>,
@@ -810,6 +812,9 @@ pub struct TavilyProxy {
     user_rankings_cache: Arc<Mutex<UserRankingsCacheState>>,
     analysis_pressure_cache: Arc<Mutex<AnalysisPressureCacheState>>,
     pub(crate) ha_state_coalescer: HaStateCoalescer,
+    request_flights: RequestFlights,
+    metrics_bearer_token: Option<String>,
+    replay_upstream_bases: Vec<String>,
     // External `TavilyProxy` clones own this token. Background loops only
     // retain a weak reference so they cannot keep a discarded runtime alive.
     background_task_owner: Arc<()>,
@@ -878,6 +883,15 @@ pub struct TavilyProxyOptions {
     pub forward_proxy_trace_url: Url,
     pub low_quota_depletion_threshold: i64,
     pub health_readiness_grace_period: Duration,
+    /// Encrypts upstream API key secrets at rest when set.
+    pub api_key_secret_cipher: Option<ApiKeySecretCipher>,
+    /// Persist only salted hashes of access-token secrets and hash existing ones on startup.
+    pub hash_access_token_secrets: bool,
+    /// Lets Prometheus scrape `/metrics` with this bearer token instead of an admin session.
+    pub metrics_bearer_token: Option<String>,
+    /// Alternate upstream bases (for example a local `mock_tavily`) admins may replay logged
+    /// requests against, in addition to the primary Tavily base.
+    pub replay_upstream_bases: Vec<String>,
 }
 
 impl TavilyProxyOptions {
@@ -889,6 +903,10 @@ impl TavilyProxyOptions {
             forward_proxy_trace_url: default_forward_proxy_trace_url(),
             low_quota_depletion_threshold: low_quota_depletion_threshold_from_env(),
             health_readiness_grace_period: Duration::from_secs(90),
+            api_key_secret_cipher: api_key_secret_cipher_from_env(),
+            hash_access_token_secrets: false,
+            metrics_bearer_token: None,
+            replay_upstream_bases: Vec::new(),
         }
     }
 }
@@ -1039,6 +1057,8 @@ pub(crate) struct HttpProjectAffinityCandidate {
     pub(crate) cooldown_until: Option<i64>,
     pub(crate) recent_rate_limited_count: i64,
     pub(crate) recent_billable_request_count: i64,
+    /// Remaining-credit score in quota-weighted selection mode; `None` keeps LRU ordering.
+    pub(crate) quota_score: Option<i64>,
     pub(crate) last_used_at: i64,
 }
 
@@ -1090,13 +1110,37 @@ fn low_quota_depletion_threshold_from_env() -> i64 {
     )
 }
 
+fn api_key_secret_cipher_from_env() -> Option<ApiKeySecretCipher> {
+    let raw = std::env::var(API_KEY_SECRET_MASTER_KEY_ENV).ok();
+    match parse_api_key_secret_master_key(raw.as_deref()) {
+        Ok(master_key) => master_key.map(ApiKeySecretCipher::new),
+        Err(err) => {
+            tracing::warn!(%err, "ignoring invalid api key secret master key");
+            None
+        }
+    }
+}
+
 include!("proxy_core.rs");
 include!("proxy_affinity.rs");
 include!("proxy_http_and_logs.rs");
+include!("proxy_http_research_stream.rs");
 include!("proxy_auth_and_oauth.rs");
 include!("proxy_usage_and_metrics.rs");
 include!("proxy_request_limits.rs");
+include!("proxy_key_rate_budget.rs");
+include!("proxy_response_cache.rs");
+include!("proxy_request_coalescing.rs");
+include!("proxy_prometheus_metrics.rs");
 include!("proxy_alerts.rs");
+include!("proxy_alert_webhooks.rs");
+include!("proxy_alert_rules.rs");
+include!("proxy_alert_workflow.rs");
+include!("proxy_admin_audit.rs");
+include!("proxy_admin_accounts.rs");
+include!("proxy_admin_api_tokens.rs");
+include!("proxy_teams.rs");
+include!("proxy_request_log_replay.rs");
 include!("proxy_announcements.rs");
 include!("proxy_admin_user_usage_series.rs");
 include!("proxy_user_dashboard_overview.rs");
@@ -1276,11 +1320,12 @@ impl TokenQuota {
         let day_bucket = start_of_local_day_utc_ts(now.with_timezone(&Local));
         let month_start = start_of_month(now).timestamp();
 
-        match self.resolve_subject(token_id).await? {
+        let subject = self.resolve_subject(token_id).await?;
+        match &subject {
             QuotaSubject::Account(user_id) => {
                 self.store
                     .increment_account_usage_bucket_by(
-                        &user_id,
+                        user_id,
                         minute_bucket,
                         GRANULARITY_MINUTE,
                         credits,
@@ -1288,7 +1333,7 @@ impl TokenQuota {
                     .await?;
                 self.store
                     .increment_account_usage_bucket_by(
-                        &user_id,
+                        user_id,
                         day_bucket,
                         GRANULARITY_DAY,
                         credits,
@@ -1296,27 +1341,25 @@ impl TokenQuota {
                     .await?;
                 let _ = self
                     .store
-                    .increment_account_monthly_quota_by(&user_id, month_start, credits)
+                    .increment_account_monthly_quota_by(user_id, month_start, credits)
                     .await?;
             }
             QuotaSubject::Token(token_id) => {
                 self.store
-                    .increment_usage_bucket_by(
-                        &token_id,
-                        minute_bucket,
-                        GRANULARITY_MINUTE,
-                        credits,
-                    )
+                    .increment_usage_bucket_by(token_id, minute_bucket, GRANULARITY_MINUTE, credits)
                     .await?;
                 self.store
-                    .increment_usage_bucket_by(&token_id, day_bucket, GRANULARITY_DAY, credits)
+                    .increment_usage_bucket_by(token_id, day_bucket, GRANULARITY_DAY, credits)
                     .await?;
                 let _ = self
                     .store
-                    .increment_monthly_quota_by(&token_id, month_start, credits)
+                    .increment_monthly_quota_by(token_id, month_start, credits)
                     .await?;
             }
         }
+        self.store
+            .increment_team_usage_for_billing_subject(&subject.billing_subject(), now_ts, credits)
+            .await?;
 
         self.maybe_cleanup(now_ts).await?;
         Ok(())
@@ -1340,12 +1383,80 @@ impl TokenQuota {
         self.snapshot_for_subject(&subject, now).await
     }
 
+    /// Snapshot for a quota subject, including the pool of the team it draws from. Team-owned
+    /// tokens and members of teams that replace personal limits only answer to the team pool.
     pub(crate) async fn snapshot_for_subject(
         &self,
         subject: &QuotaSubject,
         now: chrono::DateTime<Utc>,
+    ) -> Result<TokenQuotaVerdict, ProxyError> {
+        let team = match subject {
+            QuotaSubject::Account(user_id) => self
+                .store
+                .fetch_user_team_membership(user_id)
+                .await?
+                .map(|membership| membership.team),
+            QuotaSubject::Token(token_id) => self.store.fetch_team_for_token(token_id).await?,
+        };
+        let Some(team) = team else {
+            return self.personal_snapshot_for_subject(subject, now).await;
+        };
+        let pool = self.team_pool_snapshot(&team, now).await?;
+        match subject {
+            QuotaSubject::Account(_) if team.enforce_member_limits => Ok(self
+                .personal_snapshot_for_subject(subject, now)
+                .await?
+                .with_team_pool(pool)),
+            _ => Ok(pool),
+        }
+    }
+
+    pub(crate) async fn team_pool_snapshot(
+        &self,
+        team: &Team,
+        now: chrono::DateTime<Utc>,
+    ) -> Result<TokenQuotaVerdict, ProxyError> {
+        let now_ts = now.timestamp();
+        let minute_bucket = now_ts - (now_ts % SECS_PER_MINUTE);
+        let hour_window_start = minute_bucket - 59 * SECS_PER_MINUTE;
+        let day_window_start = start_of_local_day_utc_ts(now.with_timezone(&Local));
+        let month_start = start_of_month(now).timestamp();
+        let hourly_used = self
+            .store
+            .sum_team_usage_buckets(&team.id, GRANULARITY_MINUTE, hour_window_start)
+            .await?;
+        let daily_used = self
+            .store
+            .sum_team_usage_buckets(&team.id, GRANULARITY_DAY, day_window_start)
+            .await?;
+        let monthly_used = self
+            .store
+            .sum_team_usage_buckets(&team.id, GRANULARITY_MONTH, month_start)
+            .await?;
+        let reserved = self
+            .store
+            .sum_billing_reservations_for_team(&team.id, now_ts)
+            .await?;
+        Ok(TokenQuotaVerdict::for_team(
+            team.limits,
+            hourly_used + reserved,
+            daily_used + reserved,
+            monthly_used + reserved,
+        ))
+    }
+
+    /// Personal usage of `subject`, counting credits still reserved by in-flight requests as
+    /// already used in every window.
+    async fn personal_snapshot_for_subject(
+        &self,
+        subject: &QuotaSubject,
+        now: chrono::DateTime<Utc>,
     ) -> Result<TokenQuotaVerdict, ProxyError> {
         let now_ts = now.timestamp();
+        let reserved = self
+            .store
+            .sum_billing_reservations_for_subject(&subject.billing_subject(), now_ts)
+            .await?;
         let minute_bucket = now_ts - (now_ts % SECS_PER_MINUTE);
         let local_now = now.with_timezone(&Local);
         let hour_window_start = minute_bucket - 59 * SECS_PER_MINUTE;
@@ -1371,11 +1482,11 @@ impl TokenQuota {
                     .fetch_account_monthly_count(user_id, month_start)
                     .await?;
                 Ok(TokenQuotaVerdict::new_without_hourly_enforcement(
-                    hourly_used,
+                    hourly_used + reserved,
                     limits.business_calls_1h_limit,
-                    daily_used,
+                    daily_used + reserved,
                     limits.daily_credits_limit,
-                    monthly_used,
+                    monthly_used + reserved,
                     limits.monthly_credits_limit,
                 ))
             }
@@ -1392,11 +1503,11 @@ impl TokenQuota {
                     .fetch_monthly_count(token_id, month_start)
                     .await?;
                 Ok(TokenQuotaVerdict::new(
-                    hourly_used,
+                    hourly_used + reserved,
                     self.hourly_limit,
-                    daily_used,
+                    daily_used + reserved,
                     self.daily_limit,
-                    monthly_used,
+                    monthly_used + reserved,
                     self.monthly_limit,
                 ))
             }
@@ -1572,6 +1683,24 @@ impl TokenQuota {
             self.store
                 .delete_old_account_usage_buckets(GRANULARITY_DAY, threshold)
                 .await?;
+            self.store
+                .delete_old_team_usage_buckets(GRANULARITY_MINUTE, threshold)
+                .await?;
+            self.store
+                .delete_old_team_usage_buckets(
+                    GRANULARITY_DAY,
+                    now_ts.saturating_sub(ACCOUNT_USAGE_ROLLUP_DAY_RETENTION_SECS),
+                )
+                .await?;
+            self.store
+                .delete_old_team_usage_buckets(
+                    GRANULARITY_MONTH,
+                    shift_month_start_utc_ts(
+                        start_of_month(self.backend_time.now_utc()).timestamp(),
+                        -ACCOUNT_USAGE_ROLLUP_MONTH_RETENTION_MONTHS,
+                    ),
+                )
+                .await?;
             self.store
                 .delete_old_account_usage_rollup_buckets(
                     AccountUsageRollupMetricKind::RequestCount,
@@ -0,0 +1,88 @@
+impl TavilyProxy {
+    pub async fn list_admin_accounts(&self) -> Result<Vec<AdminAccount>, ProxyError> {
+        self.key_store.list_admin_accounts().await
+    }
+
+    pub async fn fetch_admin_account(
+        &self,
+        account_id: &str,
+    ) -> Result<Option<AdminAccount>, ProxyError> {
+        self.key_store.fetch_admin_account(account_id).await
+    }
+
+    /// Whether at least one enabled named admin account can sign in.
+    pub async fn has_active_admin_accounts(&self) -> Result<bool, ProxyError> {
+        self.key_store.has_active_admin_accounts().await
+    }
+
+    /// Returns `None` when the username is already taken.
+    pub async fn create_admin_account(
+        &self,
+        username: &str,
+        display_name: Option<&str>,
+        role: AdminRole,
+        password_hash: &str,
+    ) -> Result<Option<AdminAccount>, ProxyError> {
+        self.key_store
+            .create_admin_account(username, display_name, role, password_hash)
+            .await
+    }
+
+    pub async fn update_admin_account(
+        &self,
+        account_id: &str,
+        display_name: Option<Option<&str>>,
+        role: Option<AdminRole>,
+        disabled: Option<bool>,
+    ) -> Result<Option<AdminAccount>, ProxyError> {
+        self.key_store
+            .update_admin_account(account_id, display_name, role, disabled)
+            .await
+    }
+
+    pub async fn set_admin_account_password_hash(
+        &self,
+        account_id: &str,
+        password_hash: &str,
+    ) -> Result<bool, ProxyError> {
+        self.key_store
+            .set_admin_account_password_hash(account_id, password_hash)
+            .await
+    }
+
+    pub async fn delete_admin_account(&self, account_id: &str) -> Result<bool, ProxyError> {
+        self.key_store.delete_admin_account(account_id).await
+    }
+
+    pub async fn admin_account_credential(
+        &self,
+        username: &str,
+    ) -> Result<Option<AdminAccountCredential>, ProxyError> {
+        self.key_store.admin_account_credential(username).await
+    }
+
+    pub async fn create_admin_account_session(
+        &self,
+        account_id: &str,
+        ttl_secs: i64,
+    ) -> Result<String, ProxyError> {
+        self.key_store
+            .create_admin_account_session(account_id, ttl_secs)
+            .await
+    }
+
+    pub async fn get_active_admin_account_session(
+        &self,
+        token: &str,
+    ) -> Result<Option<AdminAccountSession>, ProxyError> {
+        self.key_store.get_active_admin_account_session(token).await
+    }
+
+    pub async fn revoke_admin_account_session(&self, token: &str) -> Result<(), ProxyError> {
+        self.key_store.revoke_admin_account_session(token).await
+    }
+
+    pub async fn revoke_admin_account_sessions(&self, account_id: &str) -> Result<u64, ProxyError> {
+        self.key_store.revoke_admin_account_sessions(account_id).await
+    }
+}
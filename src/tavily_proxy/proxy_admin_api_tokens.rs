@@ -0,0 +1,44 @@
+impl TavilyProxy {
+    pub async fn list_admin_api_tokens(&self) -> Result<Vec<AdminApiToken>, ProxyError> {
+        self.key_store.list_admin_api_tokens().await
+    }
+
+    /// Issues a token and returns it with its full value, which is only available now.
+    pub async fn create_admin_api_token(
+        &self,
+        name: &str,
+        scopes: &AdminApiTokenScopes,
+        ip_allowlist: &[String],
+        expires_at: Option<i64>,
+        created_by: Option<&str>,
+    ) -> Result<(AdminApiToken, String), ProxyError> {
+        self.key_store
+            .create_admin_api_token(name, scopes, ip_allowlist, expires_at, created_by)
+            .await
+    }
+
+    pub async fn revoke_admin_api_token(
+        &self,
+        token_id: &str,
+    ) -> Result<Option<AdminApiToken>, ProxyError> {
+        self.key_store.revoke_admin_api_token(token_id).await
+    }
+
+    /// Resolves a presented token; revoked, expired and unknown tokens resolve to `None`.
+    pub async fn authenticate_admin_api_token(
+        &self,
+        token: &str,
+    ) -> Result<Option<AdminApiToken>, ProxyError> {
+        self.key_store.authenticate_admin_api_token(token).await
+    }
+
+    pub async fn touch_admin_api_token(
+        &self,
+        token_id: &str,
+        client_ip: Option<&str>,
+    ) -> Result<(), ProxyError> {
+        self.key_store
+            .touch_admin_api_token(token_id, client_ip)
+            .await
+    }
+}
@@ -0,0 +1,23 @@
+impl TavilyProxy {
+    pub async fn append_admin_audit_entry(
+        &self,
+        input: AdminAuditEntryInput,
+    ) -> Result<AdminAuditEntry, ProxyError> {
+        self.key_store.append_admin_audit_entry(input).await
+    }
+
+    pub async fn admin_audit_entries_page(
+        &self,
+        filters: &AdminAuditFilters,
+        page: i64,
+        per_page: i64,
+    ) -> Result<PaginatedAdminAuditEntries, ProxyError> {
+        self.key_store
+            .admin_audit_entries_page(filters, page, per_page)
+            .await
+    }
+
+    pub async fn verify_admin_audit_chain(&self) -> Result<AdminAuditVerification, ProxyError> {
+        self.key_store.verify_admin_audit_chain().await
+    }
+}
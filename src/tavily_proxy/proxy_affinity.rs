@@ -92,21 +92,41 @@ impl TavilyProxy {
         })
     }
 
+    /// Resolve the key-group restriction and tier floor that apply to one request.
+    async fn key_group_route_for_token(
+        &self,
+        auth_token_id: Option<&str>,
+    ) -> Result<KeyGroupRoute, ProxyError> {
+        let key_groups = match auth_token_id {
+            Some(token_id) => {
+                self.key_store
+                    .resolve_bound_key_groups_for_token(token_id)
+                    .await?
+            }
+            None => None,
+        };
+        let mut route = self.key_store.key_group_route(key_groups.as_deref()).await?;
+        route.saturated_key_ids = self.saturated_rate_budget_key_ids().await?;
+        Ok(route)
+    }
+
     async fn rebind_user_primary_affinity(
         &self,
         user_id: &str,
         old_key_id: Option<&str>,
+        route: &KeyGroupRoute,
     ) -> Result<ApiKeyLease, ProxyError> {
         let lease = match self
             .key_store
             .acquire_key_avoiding_transient_backoff_excluding(
                 HTTP_GLOBAL_BACKOFF_SCOPE,
                 old_key_id,
+                route,
             )
             .await
         {
             Ok(lease) => lease,
-            Err(ProxyError::NoAvailableKeys) => self.key_store.acquire_key().await?,
+            Err(ProxyError::NoAvailableKeys) => self.key_store.acquire_key_with_route(route).await?,
             Err(err) => return Err(err),
         };
         self.key_store
@@ -126,17 +146,19 @@ impl TavilyProxy {
         &self,
         token_id: &str,
         old_key_id: Option<&str>,
+        route: &KeyGroupRoute,
     ) -> Result<ApiKeyLease, ProxyError> {
         let lease = match self
             .key_store
             .acquire_key_avoiding_transient_backoff_excluding(
                 HTTP_GLOBAL_BACKOFF_SCOPE,
                 old_key_id,
+                route,
             )
             .await
         {
             Ok(lease) => lease,
-            Err(ProxyError::NoAvailableKeys) => self.key_store.acquire_key().await?,
+            Err(ProxyError::NoAvailableKeys) => self.key_store.acquire_key_with_route(route).await?,
             Err(err) => return Err(err),
         };
         self.key_store
@@ -157,8 +179,9 @@ impl TavilyProxy {
         token_id: &str,
         user_id: Option<&str>,
         desired_count: i64,
+        route: &KeyGroupRoute,
     ) -> Result<Vec<String>, ProxyError> {
-        let mut candidates = self.key_store.list_mcp_session_candidate_key_ids().await?;
+        let mut candidates = self.key_store.list_mcp_session_candidate_key_ids(route).await?;
         if candidates.is_empty() {
             return Err(ProxyError::NoAvailableKeys);
         }
@@ -289,9 +312,10 @@ impl TavilyProxy {
         &self,
         auth_token_id: Option<&str>,
     ) -> Result<McpSessionInitSelection, ProxyError> {
+        let route = self.key_group_route_for_token(auth_token_id).await?;
         let Some(token_id) = auth_token_id else {
             return Ok(McpSessionInitSelection {
-                lease: self.key_store.acquire_key().await?,
+                lease: self.key_store.acquire_key_with_route(&route).await?,
                 key_effect: KeyEffect::none(),
             });
         };
@@ -303,6 +327,7 @@ impl TavilyProxy {
                 token_id,
                 user_id.as_deref(),
                 settings.mcp_session_affinity_key_count,
+                &route,
             )
             .await?;
         let now = self.backend_time.now_ts();
@@ -316,7 +341,7 @@ impl TavilyProxy {
             let key_id = candidate.key_id.clone();
             if let Some(lease) = self
                 .key_store
-                .try_acquire_affinity_specific_key(&key_id)
+                .try_acquire_affinity_specific_key(&key_id, &route)
                 .await?
             {
                 let key_effect = if preferred_key_id.as_deref() == Some(key_id.as_str()) {
@@ -387,8 +412,9 @@ impl TavilyProxy {
         &self,
         affinity_subject: &str,
         desired_count: i64,
+        route: &KeyGroupRoute,
     ) -> Result<Vec<String>, ProxyError> {
-        let mut candidates = self.key_store.list_mcp_session_candidate_key_ids().await?;
+        let mut candidates = self.key_store.list_mcp_session_candidate_key_ids(route).await?;
         if candidates.is_empty() {
             return Err(ProxyError::NoAvailableKeys);
         }
@@ -406,8 +432,9 @@ impl TavilyProxy {
         &self,
         affinity_subject: &str,
         desired_count: Option<i64>,
+        route: &KeyGroupRoute,
     ) -> Result<Vec<String>, ProxyError> {
-        let mut candidates = self.key_store.list_mcp_session_candidate_key_ids().await?;
+        let mut candidates = self.key_store.list_mcp_session_candidate_key_ids(route).await?;
         if candidates.is_empty() {
             return Err(ProxyError::NoAvailableKeys);
         }
@@ -424,7 +451,7 @@ impl TavilyProxy {
     }
 
     #[allow(dead_code)]
-    async fn build_http_project_affinity_candidates(
+    pub(crate) async fn build_http_project_affinity_candidates(
         &self,
         ranked: &[String],
         now: i64,
@@ -455,6 +482,7 @@ impl TavilyProxy {
                 now - HTTP_PROJECT_AFFINITY_RECENT_PRESSURE_WINDOW_SECS,
             )
             .await?;
+        let quota_scores = self.key_quota_selection_scores(ranked, now).await?;
         let last_used_at = self.key_store.list_api_key_last_used_at(ranked).await?;
 
         let mut candidates = ranked
@@ -477,6 +505,7 @@ impl TavilyProxy {
                     .get(key_id)
                     .copied()
                     .unwrap_or(0),
+                quota_score: quota_scores.get(key_id).copied(),
                 last_used_at: last_used_at.get(key_id).copied().unwrap_or(0),
             })
             .collect::<Vec<_>>();
@@ -484,6 +513,22 @@ impl TavilyProxy {
         Ok(candidates)
     }
 
+    /// Quota-weighted selection scores for `ranked`, or an empty map in LRU selection mode.
+    async fn key_quota_selection_scores(
+        &self,
+        ranked: &[String],
+        now: i64,
+    ) -> Result<HashMap<String, i64>, ProxyError> {
+        match self.key_store.key_selection_mode().await? {
+            KeySelectionMode::Lru => Ok(HashMap::new()),
+            KeySelectionMode::QuotaWeighted => {
+                self.key_store
+                    .list_api_key_quota_selection_scores(ranked, now)
+                    .await
+            }
+        }
+    }
+
     async fn build_api_rebalance_candidates(
         &self,
         ranked: &[String],
@@ -507,6 +552,7 @@ impl TavilyProxy {
                 now - HTTP_PROJECT_AFFINITY_RECENT_PRESSURE_WINDOW_SECS,
             )
             .await?;
+        let quota_scores = self.key_quota_selection_scores(ranked, now).await?;
         let last_used_at = self.key_store.list_api_key_last_used_at(ranked).await?;
 
         let mut candidates = ranked
@@ -524,6 +570,7 @@ impl TavilyProxy {
                     .get(key_id)
                     .copied()
                     .unwrap_or(0),
+                quota_score: quota_scores.get(key_id).copied(),
                 last_used_at: last_used_at.get(key_id).copied().unwrap_or(0),
             })
             .collect::<Vec<_>>();
@@ -650,6 +697,7 @@ impl TavilyProxy {
         else {
             return Ok(None);
         };
+        let route = self.key_group_route_for_token(auth_token_id).await?;
 
         let existing_binding = self
             .key_store
@@ -682,7 +730,7 @@ impl TavilyProxy {
             if !is_cooled
                 && let Some(lease) = self
                     .key_store
-                    .try_acquire_affinity_specific_key(existing_key_id)
+                    .try_acquire_affinity_specific_key(existing_key_id, &route)
                     .await?
             {
                 return Ok(Some(HttpProjectAffinitySelection {
@@ -699,7 +747,11 @@ impl TavilyProxy {
             .await?
             .mcp_session_affinity_key_count;
         let ranked = self
-            .rank_http_project_affinity_candidate_keys(&context.affinity_subject, desired_count)
+            .rank_http_project_affinity_candidate_keys(
+                &context.affinity_subject,
+                desired_count,
+                &route,
+            )
             .await?;
         let ordered = self
             .build_http_project_affinity_candidates(&ranked, now)
@@ -717,7 +769,7 @@ impl TavilyProxy {
             let key_id = candidate.key_id.clone();
             if let Some(lease) = self
                 .key_store
-                .try_acquire_affinity_specific_key(&key_id)
+                .try_acquire_affinity_specific_key(&key_id, &route)
                 .await?
             {
                 self.key_store
@@ -777,6 +829,7 @@ impl TavilyProxy {
         let context = self
             .resolve_api_route_affinity_context(auth_token_id, route_key)
             .await?;
+        let route = self.key_group_route_for_token(auth_token_id).await?;
         let now = self.backend_time.now_ts();
 
         if let Some(context) = context.as_ref() {
@@ -800,7 +853,7 @@ impl TavilyProxy {
                 if !backoff.contains_key(existing_key_id)
                     && let Some(lease) = self
                         .key_store
-                        .try_acquire_affinity_specific_key(existing_key_id)
+                        .try_acquire_affinity_specific_key(existing_key_id, &route)
                         .await?
                 {
                     return Ok(ApiRouteAffinitySelection {
@@ -820,6 +873,7 @@ impl TavilyProxy {
                 .rank_api_route_affinity_candidate_keys(
                     &context.affinity_subject,
                     Some(desired_count),
+                    &route,
                 )
                 .await?;
             let ordered = self.build_api_rebalance_candidates(&ranked, now).await?;
@@ -836,7 +890,7 @@ impl TavilyProxy {
                 let key_id = candidate.key_id.clone();
                 if let Some(lease) = self
                     .key_store
-                    .try_acquire_affinity_specific_key(&key_id)
+                    .try_acquire_affinity_specific_key(&key_id, &route)
                     .await?
                 {
                     self.key_store
@@ -899,7 +953,7 @@ impl TavilyProxy {
             None => "anonymous:api".to_string(),
         };
         let ranked = self
-            .rank_api_route_affinity_candidate_keys(&affinity_subject, None)
+            .rank_api_route_affinity_candidate_keys(&affinity_subject, None, &route)
             .await?;
         let ordered = self.build_api_rebalance_candidates(&ranked, now).await?;
         let selection_effect = Self::api_rebalance_selection_effect(&ordered);
@@ -909,7 +963,7 @@ impl TavilyProxy {
             let key_id = candidate.key_id.clone();
             if let Some(lease) = self
                 .key_store
-                .try_acquire_affinity_specific_key(&key_id)
+                .try_acquire_affinity_specific_key(&key_id, &route)
                 .await?
             {
                 let selection_effect = if preferred_key_id.as_deref() == Some(key_id.as_str()) {
@@ -930,8 +984,10 @@ impl TavilyProxy {
 
     pub(crate) async fn acquire_key_for_rebalance_mcp_http_call(
         &self,
+        auth_token_id: Option<&str>,
     ) -> Result<ApiKeyLease, ProxyError> {
-        let ranked = self.key_store.list_mcp_session_candidate_key_ids().await?;
+        let route = self.key_group_route_for_token(auth_token_id).await?;
+        let ranked = self.key_store.list_mcp_session_candidate_key_ids(&route).await?;
         if ranked.is_empty() {
             return Err(ProxyError::NoAvailableKeys);
         }
@@ -943,7 +999,7 @@ impl TavilyProxy {
         for candidate in ordered {
             if let Some(lease) = self
                 .key_store
-                .try_acquire_affinity_specific_key(&candidate.key_id)
+                .try_acquire_affinity_specific_key(&candidate.key_id, &route)
                 .await?
             {
                 return Ok(lease);
@@ -976,6 +1032,7 @@ impl TavilyProxy {
                 now - HTTP_PROJECT_AFFINITY_RECENT_PRESSURE_WINDOW_SECS,
             )
             .await?;
+        let quota_scores = self.key_quota_selection_scores(ranked, now).await?;
         let last_used_at = self.key_store.list_api_key_last_used_at(ranked).await?;
 
         let mut candidates = ranked
@@ -993,6 +1050,7 @@ impl TavilyProxy {
                     .get(key_id)
                     .copied()
                     .unwrap_or(0),
+                quota_score: quota_scores.get(key_id).copied(),
                 last_used_at: last_used_at.get(key_id).copied().unwrap_or(0),
             })
             .collect::<Vec<_>>();
@@ -1034,11 +1092,16 @@ impl TavilyProxy {
         &self,
         auth_token_id: Option<&str>,
     ) -> Result<ApiKeyLease, ProxyError> {
+        let route = self.key_group_route_for_token(auth_token_id).await?;
         let Some(token_id) = auth_token_id else {
             // No token id (e.g. certain internal or dev flows) → plain global scheduling.
             return self
                 .key_store
-                .acquire_key_avoiding_transient_backoff(HTTP_GLOBAL_BACKOFF_SCOPE)
+                .acquire_key_avoiding_transient_backoff_excluding(
+                    HTTP_GLOBAL_BACKOFF_SCOPE,
+                    None,
+                    &route,
+                )
                 .await;
         };
 
@@ -1085,7 +1148,7 @@ impl TavilyProxy {
                 }
                 if let Some(lease) = self
                     .key_store
-                    .try_acquire_affinity_specific_key(&key_id)
+                    .try_acquire_affinity_specific_key(&key_id, &route)
                     .await?
                 {
                     if sync_on_acquire {
@@ -1099,13 +1162,17 @@ impl TavilyProxy {
 
             if user_primary.is_some() || token_primary.is_some() {
                 return self
-                    .rebind_user_primary_affinity(&user_id, user_primary.as_deref())
+                    .rebind_user_primary_affinity(&user_id, user_primary.as_deref(), &route)
                     .await;
             }
 
             let lease = self
                 .key_store
-                .acquire_key_avoiding_transient_backoff(HTTP_GLOBAL_BACKOFF_SCOPE)
+                .acquire_key_avoiding_transient_backoff_excluding(
+                    HTTP_GLOBAL_BACKOFF_SCOPE,
+                    None,
+                    &route,
+                )
                 .await?;
             self.key_store
                 .sync_user_primary_api_key_affinity(&user_id, &lease.id)
@@ -1123,20 +1190,20 @@ impl TavilyProxy {
                 .await?
                 && let Some(lease) = self
                     .key_store
-                    .try_acquire_affinity_specific_key(&token_primary.api_key_id)
+                    .try_acquire_affinity_specific_key(&token_primary.api_key_id, &route)
                     .await?
             {
                 return Ok(lease);
             }
 
             return self
-                .rebind_token_primary_affinity(token_id, Some(&token_primary.api_key_id))
+                .rebind_token_primary_affinity(token_id, Some(&token_primary.api_key_id), &route)
                 .await;
         }
 
         let lease = self
             .key_store
-            .acquire_key_avoiding_transient_backoff(HTTP_GLOBAL_BACKOFF_SCOPE)
+            .acquire_key_avoiding_transient_backoff_excluding(HTTP_GLOBAL_BACKOFF_SCOPE, None, &route)
             .await?;
         self.key_store
             .set_token_primary_api_key_affinity(token_id, None, &lease.id)
@@ -1174,9 +1241,11 @@ impl TavilyProxy {
             }
 
             if let Some(key_id) = candidate_key_id {
+                // Research continuations must stay on the key that started the task, regardless
+                // of the tier that is currently preferred.
                 if let Some(lease) = self
                     .key_store
-                    .try_acquire_affinity_specific_key(&key_id)
+                    .try_acquire_affinity_specific_key(&key_id, &KeyGroupRoute::default())
                     .await?
                 {
                     return Ok(lease);
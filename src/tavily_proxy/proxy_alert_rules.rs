@@ -0,0 +1,89 @@
+impl TavilyProxy {
+    pub async fn list_alert_rules(&self) -> Result<Vec<AlertRule>, ProxyError> {
+        self.key_store.list_alert_rules().await
+    }
+
+    pub async fn create_alert_rule(&self, input: AlertRuleMutation) -> Result<AlertRule, ProxyError> {
+        self.key_store.create_alert_rule(input).await
+    }
+
+    pub async fn update_alert_rule(
+        &self,
+        id: &str,
+        input: AlertRuleMutation,
+    ) -> Result<Option<AlertRule>, ProxyError> {
+        self.key_store.update_alert_rule(id, input).await
+    }
+
+    pub async fn delete_alert_rule(&self, id: &str) -> Result<bool, ProxyError> {
+        self.key_store.delete_alert_rule(id).await
+    }
+
+    /// Evaluates every enabled rule against the current metric values. Returns how many rules
+    /// started firing; their firings reach the alert center through the alert projection.
+    pub async fn evaluate_alert_rules(&self, ha: &HaRuntime) -> Result<usize, ProxyError> {
+        let rules = self.key_store.list_alert_rules().await?;
+        let mut fired = 0;
+        for rule in rules.iter().filter(|rule| rule.enabled) {
+            let value = self.alert_rule_metric_value(rule, ha).await?;
+            if self.key_store.record_alert_rule_evaluation(rule, value).await? {
+                fired += 1;
+                tracing::warn!(
+                    rule_id = %rule.id,
+                    rule_name = %rule.name,
+                    metric = %rule.metric,
+                    value = value.unwrap_or_default(),
+                    threshold = rule.threshold,
+                    "alert rule threshold breached"
+                );
+            }
+        }
+        Ok(fired)
+    }
+
+    /// Current value of the rule metric, or `None` when it cannot be measured right now, such
+    /// as an error rate over too few requests or outbox lag without HA peers.
+    async fn alert_rule_metric_value(
+        &self,
+        rule: &AlertRule,
+        ha: &HaRuntime,
+    ) -> Result<Option<f64>, ProxyError> {
+        match rule.metric.as_str() {
+            ALERT_RULE_METRIC_POOL_CREDITS_REMAINING => self
+                .key_store
+                .alert_rule_pool_credits_remaining()
+                .await
+                .map(Some),
+            ALERT_RULE_METRIC_REQUEST_ERROR_RATE => {
+                let since = self.backend_time().now_ts() - rule.window_secs;
+                self.key_store
+                    .alert_rule_request_error_rate(since, rule.request_kind.as_deref())
+                    .await
+            }
+            ALERT_RULE_METRIC_FORWARD_PROXY_HEALTHY_NODES => {
+                let summary = self.get_forward_proxy_dashboard_summary().await?;
+                Ok(Some(summary.available_nodes as f64))
+            }
+            ALERT_RULE_METRIC_HA_OUTBOX_ACK_LAG => {
+                let mut max_lag = None;
+                for peer in ha.peer_nodes() {
+                    if peer.node_id == ha.node_id() {
+                        continue;
+                    }
+                    for channel in [
+                        HaSyncChannel::Control,
+                        HaSyncChannel::Billing,
+                        HaSyncChannel::Runtime,
+                    ] {
+                        let stats = self
+                            .ha_channel_outbox_stats(channel, Some(&peer.node_id))
+                            .await?;
+                        max_lag = max_lag.max(stats.ack_lag);
+                    }
+                }
+                Ok(max_lag.map(|lag| lag as f64))
+            }
+            _ => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,130 @@
+const ALERT_WEBHOOK_DISPATCH_BATCH: i64 = 20;
+const ALERT_WEBHOOK_REQUEST_TIMEOUT_SECS: u64 = 10;
+const ALERT_WEBHOOK_RESPONSE_SNIPPET_CHARS: usize = 200;
+
+impl TavilyProxy {
+    pub async fn list_alert_webhook_sinks(&self) -> Result<Vec<AlertWebhookSink>, ProxyError> {
+        self.key_store.list_alert_webhook_sinks().await
+    }
+
+    pub async fn create_alert_webhook_sink(
+        &self,
+        input: AlertWebhookSinkMutation,
+    ) -> Result<AlertWebhookSink, ProxyError> {
+        self.key_store.create_alert_webhook_sink(input).await
+    }
+
+    pub async fn update_alert_webhook_sink(
+        &self,
+        id: &str,
+        input: AlertWebhookSinkMutation,
+    ) -> Result<Option<AlertWebhookSink>, ProxyError> {
+        self.key_store.update_alert_webhook_sink(id, input).await
+    }
+
+    pub async fn delete_alert_webhook_sink(&self, id: &str) -> Result<bool, ProxyError> {
+        self.key_store.delete_alert_webhook_sink(id).await
+    }
+
+    pub async fn alert_webhook_deliveries(
+        &self,
+        sink_id: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<AlertWebhookDelivery>, ProxyError> {
+        self.key_store.list_alert_webhook_deliveries(sink_id, limit).await
+    }
+
+    /// Sends a synthetic alert to the sink right away and returns the recorded attempt. A failed
+    /// test is not retried.
+    pub async fn test_fire_alert_webhook(
+        &self,
+        id: &str,
+    ) -> Result<Option<AlertWebhookDelivery>, ProxyError> {
+        let Some(sink) = self.key_store.get_alert_webhook_sink(id).await? else {
+            return Ok(None);
+        };
+        let delivery = self.key_store.insert_alert_webhook_test_delivery(&sink).await?;
+        let outcome = self.send_alert_webhook(&sink, &delivery).await;
+        self.key_store
+            .record_alert_webhook_attempt(&delivery, &outcome, 1)
+            .await
+            .map(Some)
+    }
+
+    /// Attempts every due delivery once and prunes old history. Returns how many were attempted.
+    pub async fn dispatch_alert_webhook_deliveries(&self) -> Result<usize, ProxyError> {
+        let now = self.backend_time().now_ts();
+        let due = self
+            .key_store
+            .due_alert_webhook_deliveries(now, ALERT_WEBHOOK_DISPATCH_BATCH)
+            .await?;
+        for (delivery, sink) in &due {
+            let outcome = self.send_alert_webhook(sink, delivery).await;
+            let recorded = self
+                .key_store
+                .record_alert_webhook_attempt(delivery, &outcome, ALERT_WEBHOOK_MAX_ATTEMPTS)
+                .await?;
+            if recorded.status == ALERT_WEBHOOK_DELIVERY_FAILED {
+                tracing::warn!(
+                    component = "alert_webhooks",
+                    event = "delivery_failed",
+                    sink_id = %sink.id,
+                    delivery_id = recorded.id,
+                    attempts = recorded.attempts,
+                    status_code = recorded.last_status_code,
+                    err = recorded.last_error.as_deref().unwrap_or(""),
+                    "alert webhook delivery gave up"
+                );
+            }
+        }
+        self.key_store.prune_alert_webhook_deliveries(now).await?;
+        Ok(due.len())
+    }
+
+    async fn send_alert_webhook(
+        &self,
+        sink: &AlertWebhookSink,
+        delivery: &AlertWebhookDelivery,
+    ) -> AlertWebhookAttemptOutcome {
+        let timestamp = self.backend_time().now_ts();
+        let body = delivery.payload_json.clone().into_bytes();
+        let signature = sign_alert_webhook_body(&sink.secret, timestamp, &body);
+        let response = self
+            .client
+            .post(&sink.url)
+            .timeout(Duration::from_secs(ALERT_WEBHOOK_REQUEST_TIMEOUT_SECS))
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(ALERT_WEBHOOK_SIGNATURE_HEADER, signature)
+            .header(ALERT_WEBHOOK_TIMESTAMP_HEADER, timestamp.to_string())
+            .header(ALERT_WEBHOOK_EVENT_HEADER, &delivery.alert_type)
+            .header(ALERT_WEBHOOK_DELIVERY_HEADER, delivery.id.to_string())
+            .body(body)
+            .send()
+            .await;
+        match response {
+            Ok(response) => {
+                let status = response.status();
+                let error = if status.is_success() {
+                    None
+                } else {
+                    let snippet = response
+                        .text()
+                        .await
+                        .unwrap_or_default()
+                        .chars()
+                        .take(ALERT_WEBHOOK_RESPONSE_SNIPPET_CHARS)
+                        .collect::<String>();
+                    Some(format!("HTTP {}: {}", status.as_u16(), snippet.trim()))
+                };
+                AlertWebhookAttemptOutcome {
+                    status_code: Some(status.as_u16()),
+                    error,
+                }
+            }
+            Err(err) => AlertWebhookAttemptOutcome {
+                status_code: None,
+                error: Some(err.to_string()),
+            },
+        }
+    }
+}
@@ -0,0 +1,40 @@
+impl TavilyProxy {
+    pub async fn apply_alert_group_action(
+        &self,
+        group_id: &str,
+        action: &str,
+        actor: &str,
+        note: Option<String>,
+    ) -> Result<AlertGroupWorkflow, ProxyError> {
+        self.key_store
+            .apply_alert_group_action(group_id, action, actor, note)
+            .await
+    }
+
+    pub async fn alert_group_workflow_history(
+        &self,
+        group_id: &str,
+    ) -> Result<Vec<AlertGroupWorkflowEvent>, ProxyError> {
+        self.key_store.alert_group_workflow_history(group_id).await
+    }
+
+    pub async fn list_alert_silences(
+        &self,
+        include_expired: bool,
+    ) -> Result<Vec<AlertSilence>, ProxyError> {
+        self.key_store.list_alert_silences(include_expired).await
+    }
+
+    /// Creates a silence and marks already recorded events inside its window as silenced.
+    pub async fn create_alert_silence(
+        &self,
+        input: AlertSilenceMutation,
+        actor: &str,
+    ) -> Result<AlertSilence, ProxyError> {
+        self.key_store.create_alert_silence(input, actor).await
+    }
+
+    pub async fn expire_alert_silence(&self, id: &str) -> Result<Option<AlertSilence>, ProxyError> {
+        self.key_store.expire_alert_silence(id).await
+    }
+}
@@ -150,7 +150,8 @@ impl TavilyProxy {
         page: i64,
         per_page: i64,
     ) -> Result<PaginatedAlertGroups, ProxyError> {
-        self.key_store
+        let mut groups = self
+            .key_store
             .fetch_alert_groups_page(
                 alert_type,
                 since,
@@ -162,7 +163,11 @@ impl TavilyProxy {
                 page,
                 per_page,
             )
-            .await
+            .await?;
+        self.key_store
+            .attach_alert_group_workflows(&mut groups.items)
+            .await?;
+        Ok(groups)
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -13,7 +13,21 @@ impl TavilyProxy {
     /// Validate an access token in format `th-<id>-<secret>` and record usage.
     /// Returns true if valid and enabled.
     pub async fn validate_access_token(&self, token: &str) -> Result<bool, ProxyError> {
-        self.key_store.validate_access_token(token).await
+        self.key_store
+            .validate_access_token(token)
+            .instrument(tracing::info_span!("auth"))
+            .await
+    }
+
+    /// Authenticate an access token, reporting lifetime-window rejections separately.
+    pub async fn check_access_token(
+        &self,
+        token: &str,
+    ) -> Result<AccessTokenValidation, ProxyError> {
+        self.key_store
+            .check_access_token(token)
+            .instrument(tracing::info_span!("auth"))
+            .await
     }
 
     pub async fn admin_passkey_enabled(&self, scope: &AdminPasskeyScope) -> Result<bool, ProxyError> {
@@ -286,6 +300,17 @@ impl TavilyProxy {
         self.key_store.create_access_token(note).await
     }
 
+    /// Admin: create a new access token that is only accepted inside `lifetime`.
+    pub async fn create_access_token_with_lifetime(
+        &self,
+        note: Option<&str>,
+        lifetime: AccessTokenLifetime,
+    ) -> Result<AuthTokenSecret, ProxyError> {
+        self.key_store
+            .create_access_token_with_lifetime(note, lifetime)
+            .await
+    }
+
     /// Admin: create a new access token and bind it to the specified user.
     pub async fn create_user_bound_access_token(
         &self,
@@ -309,6 +334,102 @@ impl TavilyProxy {
             .await
     }
 
+    /// Admin: batch create tokens that share one lifetime window.
+    pub async fn create_access_tokens_batch_with_lifetime(
+        &self,
+        group: &str,
+        count: usize,
+        note: Option<&str>,
+        lifetime: AccessTokenLifetime,
+    ) -> Result<Vec<AuthTokenSecret>, ProxyError> {
+        self.key_store
+            .create_access_tokens_batch_with_lifetime(group, count, note, lifetime)
+            .await
+    }
+
+    /// Admin: replace a token's lifetime window. Returns `false` when the token does not exist.
+    pub async fn set_access_token_lifetime(
+        &self,
+        id: &str,
+        lifetime: AccessTokenLifetime,
+    ) -> Result<bool, ProxyError> {
+        self.key_store.set_access_token_lifetime(id, lifetime).await
+    }
+
+    /// Raise a `token_expiring` alert for every enabled token that expires within the notice
+    /// lead time and has not been noticed for its current expiry yet.
+    pub async fn record_access_token_expiry_notices(&self) -> Result<u64, ProxyError> {
+        self.key_store
+            .record_access_token_expiry_notices(ACCESS_TOKEN_EXPIRY_NOTICE_LEAD_SECS_DEFAULT)
+            .await
+    }
+
+    /// Scopes enforced for a token: its own list, else its group default. `None` is unrestricted.
+    pub async fn effective_access_token_scopes(
+        &self,
+        token_id: &str,
+    ) -> Result<Option<AccessTokenScopes>, ProxyError> {
+        self.key_store.effective_access_token_scopes(token_id).await
+    }
+
+    /// Admin: set or clear (`None`) the scopes of the given tokens. Returns how many were updated.
+    pub async fn set_access_tokens_scopes(
+        &self,
+        ids: &[String],
+        scopes: Option<&AccessTokenScopes>,
+    ) -> Result<u64, ProxyError> {
+        self.key_store.set_access_tokens_scopes(ids, scopes).await
+    }
+
+    /// Admin: set or clear (`None`) the default scopes of a token group.
+    pub async fn set_token_group_default_scopes(
+        &self,
+        group_name: &str,
+        scopes: Option<&AccessTokenScopes>,
+    ) -> Result<(), ProxyError> {
+        self.key_store
+            .set_token_group_default_scopes(group_name, scopes)
+            .await
+    }
+
+    /// Admin: default scopes keyed by token group name.
+    pub async fn list_token_group_default_scopes(
+        &self,
+    ) -> Result<HashMap<String, AccessTokenScopes>, ProxyError> {
+        self.key_store.list_token_group_default_scopes().await
+    }
+
+    /// Parameter policy enforced for a token: its own, else its group's, else the strictest
+    /// combination of its owner's tag policies. `None` is unrestricted.
+    pub async fn effective_request_parameter_policy(
+        &self,
+        token_id: &str,
+    ) -> Result<Option<RequestParameterPolicy>, ProxyError> {
+        self.key_store
+            .effective_request_parameter_policy(token_id)
+            .await
+    }
+
+    /// Admin: set or clear (`None`) the parameter policy of a token, token group or user tag.
+    /// Returns `false` when the token or tag does not exist.
+    pub async fn set_request_parameter_policy(
+        &self,
+        subject: RequestParameterPolicySubject,
+        subject_id: &str,
+        policy: Option<&RequestParameterPolicy>,
+    ) -> Result<bool, ProxyError> {
+        self.key_store
+            .set_request_parameter_policy(subject, subject_id, policy)
+            .await
+    }
+
+    /// Admin: every stored parameter policy with its subject.
+    pub async fn list_request_parameter_policies(
+        &self,
+    ) -> Result<Vec<RequestParameterPolicyRecord>, ProxyError> {
+        self.key_store.list_request_parameter_policies().await
+    }
+
     /// Admin: list tokens for management.
     pub async fn list_access_tokens(&self) -> Result<Vec<AuthToken>, ProxyError> {
         let mut tokens = self.key_store.list_access_tokens().await?;
@@ -550,7 +671,39 @@ impl TavilyProxy {
         bind_token_id: Option<&str>,
     ) -> Result<String, ProxyError> {
         self.key_store
-            .insert_oauth_login_state(provider, redirect_to, ttl_secs, binding_hash, bind_token_id)
+            .insert_oauth_login_state(
+                provider,
+                redirect_to,
+                ttl_secs,
+                binding_hash,
+                bind_token_id,
+                None,
+                None,
+            )
+            .await
+    }
+
+    /// Create a one-time user login state bound to the browser, optionally keeping an OIDC
+    /// PKCE verifier/nonce and the registration invite code entered before login.
+    pub async fn create_user_login_state(
+        &self,
+        provider: &str,
+        ttl_secs: i64,
+        binding_hash: &str,
+        bind_token_id: Option<&str>,
+        pkce: Option<&OAuthLoginPkce>,
+        invite_code: Option<&str>,
+    ) -> Result<String, ProxyError> {
+        self.key_store
+            .insert_oauth_login_state(
+                provider,
+                None,
+                ttl_secs,
+                Some(binding_hash),
+                bind_token_id,
+                pkce,
+                invite_code,
+            )
             .await
     }
 
@@ -821,6 +974,91 @@ impl TavilyProxy {
         self.key_store.set_allow_registration(allow).await
     }
 
+    /// Read whether accounts registered without an invite wait for admin approval.
+    pub async fn registration_requires_approval(&self) -> Result<bool, ProxyError> {
+        self.key_store.registration_requires_approval().await
+    }
+
+    /// Persist whether accounts registered without an invite wait for admin approval.
+    pub async fn set_registration_requires_approval(
+        &self,
+        required: bool,
+    ) -> Result<bool, ProxyError> {
+        self.key_store
+            .set_registration_requires_approval(required)
+            .await
+    }
+
+    /// Admin: issue an invite code. Returns `None` when the requested code is already taken.
+    pub async fn create_registration_invite(
+        &self,
+        input: &RegistrationInviteInput,
+    ) -> Result<Option<RegistrationInvite>, ProxyError> {
+        self.key_store.create_registration_invite(input).await
+    }
+
+    /// Admin: list every invite code, newest first.
+    pub async fn list_registration_invites(&self) -> Result<Vec<RegistrationInvite>, ProxyError> {
+        self.key_store.list_registration_invites().await
+    }
+
+    /// Admin: revoke an invite code. Returns `None` when it does not exist.
+    pub async fn revoke_registration_invite(
+        &self,
+        invite_id: &str,
+    ) -> Result<Option<RegistrationInvite>, ProxyError> {
+        self.key_store.revoke_registration_invite(invite_id).await
+    }
+
+    /// Take one use of an invite code during a first login; `None` when it cannot be used.
+    pub async fn claim_registration_invite(
+        &self,
+        code: &str,
+    ) -> Result<Option<RegistrationInvite>, ProxyError> {
+        self.key_store.claim_registration_invite(code).await
+    }
+
+    /// Apply an invite's pre-assigned tags and quota to the account it registered.
+    pub async fn apply_registration_invite(
+        &self,
+        invite: &RegistrationInvite,
+        user_id: &str,
+    ) -> Result<(), ProxyError> {
+        self.key_store
+            .apply_registration_invite(invite, user_id)
+            .await
+    }
+
+    /// Put a newly registered account into the approval queue.
+    pub async fn mark_user_pending_approval(&self, user_id: &str) -> Result<(), ProxyError> {
+        self.key_store.mark_user_pending_approval(user_id).await
+    }
+
+    /// Whether the account is still waiting for admin approval.
+    pub async fn user_pending_approval(&self, user_id: &str) -> Result<bool, ProxyError> {
+        self.key_store.user_pending_approval(user_id).await
+    }
+
+    /// The subset of `user_ids` still waiting for admin approval.
+    pub async fn pending_approval_user_ids(
+        &self,
+        user_ids: &[String],
+    ) -> Result<HashSet<String>, ProxyError> {
+        self.key_store.pending_approval_user_ids(user_ids).await
+    }
+
+    /// Admin: accounts waiting for approval, oldest request first.
+    pub async fn list_pending_approval_users(
+        &self,
+    ) -> Result<Vec<PendingApprovalUser>, ProxyError> {
+        self.key_store.list_pending_approval_users().await
+    }
+
+    /// Admin: approve a pending account. Returns `false` when it was not pending.
+    pub async fn approve_user_registration(&self, user_id: &str) -> Result<bool, ProxyError> {
+        self.key_store.approve_user_registration(user_id).await
+    }
+
     /// Ensure one-to-one user token binding exists, creating a token only when missing.
     pub async fn ensure_user_token_binding(
         &self,
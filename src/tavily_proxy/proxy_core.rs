@@ -106,6 +106,13 @@ impl TavilyProxy {
         )
     }
 
+    fn cross_key_retry_effect(attempt: i64, failed_key_id: &str, reason: &str) -> KeyEffect {
+        KeyEffect::new(
+            KEY_EFFECT_CROSS_KEY_RETRY,
+            format!("Cross-key retry after attempt {attempt} on key {failed_key_id} failed ({reason})"),
+        )
+    }
+
     fn primary_request_effect(
         key_effect: &KeyEffect,
         binding_effect: &KeyEffect,
@@ -255,6 +262,7 @@ impl TavilyProxy {
                     left.recent_rate_limited_count
                         .cmp(&right.recent_rate_limited_count)
                 })
+                .then_with(|| right.quota_score.cmp(&left.quota_score))
                 .then_with(|| {
                     left.recent_billable_request_count
                         .cmp(&right.recent_billable_request_count)
@@ -498,6 +506,12 @@ impl TavilyProxy {
         let key_store_started = Instant::now();
         let key_store = KeyStore::new_with_time(database_path, backend_time.clone()).await?;
         key_store.configure_ha_event_writes(ha_mode).await?;
+        key_store
+            .configure_api_key_secret_cipher(options.api_key_secret_cipher.clone())
+            .await?;
+        key_store
+            .configure_access_token_secret_hashing(options.hash_access_token_secrets)
+            .await?;
         tracing::debug!(
             component = "forward_proxy",
             event = "startup_sqlite_initialized",
@@ -580,6 +594,7 @@ impl TavilyProxy {
                 .unwrap_or_else(|| "https://api.country.is".to_string()),
             token_quota,
             token_request_limit,
+            key_rate_budgets: KeyRateBudgets::default(),
             user_business_calls_1h_window,
             user_business_call_bridge_diagnostics: Arc::new(Mutex::new(
                 UserBusinessCallBridgeDiagnostics::new(backend_time.instant_now()),
@@ -600,6 +615,18 @@ impl TavilyProxy {
             user_rankings_cache: Arc::new(Mutex::new(UserRankingsCacheState::default())),
             analysis_pressure_cache: Arc::new(Mutex::new(AnalysisPressureCacheState::default())),
             ha_state_coalescer,
+            request_flights: RequestFlights::default(),
+            metrics_bearer_token: options
+                .metrics_bearer_token
+                .as_deref()
+                .map(str::trim)
+                .filter(|token| !token.is_empty())
+                .map(str::to_string),
+            replay_upstream_bases: options
+                .replay_upstream_bases
+                .iter()
+                .filter_map(|base| normalize_request_log_replay_base(base).ok())
+                .collect(),
             background_task_owner: Arc::new(()),
             token_billing_locks: shared_token_billing_locks(),
             mcp_session_init_locks: Arc::new(Mutex::new(HashMap::new())),
@@ -1844,6 +1871,17 @@ impl TavilyProxy {
         self.key_store.get_system_settings().await
     }
 
+    pub async fn get_api_key_group_routing(&self) -> Result<ApiKeyGroupRouting, ProxyError> {
+        self.key_store.get_api_key_group_routing().await
+    }
+
+    pub async fn replace_api_key_group_routing(
+        &self,
+        routing: ApiKeyGroupRouting,
+    ) -> Result<ApiKeyGroupRouting, ProxyError> {
+        self.key_store.replace_api_key_group_routing(routing).await
+    }
+
     pub async fn set_user_debug_info_shared(
         &self,
         user_id: &str,
@@ -1657,36 +1657,41 @@ impl TavilyProxy {
     where
         F: FnMut(Client) -> reqwest::RequestBuilder,
     {
+        let plan_started = Instant::now();
         let result = async {
             let mut last_error: Option<ProxyError> = None;
             for candidate in plan {
                 let mut candidate = candidate;
                 let mut attempted_recovery = false;
-                let relay_lease = loop {
-                    if let Some(relay_lease) =
-                        forward_proxy::ForwardProxyRelayLease::acquire_for_selection(
-                            Arc::clone(&self.xray_supervisor),
-                            &candidate,
-                        )
-                        .await
-                    {
-                        break Some(relay_lease);
-                    }
-                    if !candidate.uses_local_relay() || attempted_recovery {
-                        break None;
-                    }
-                    attempted_recovery = true;
-                    match self.recover_forward_proxy_candidate(&candidate.key).await {
-                        Ok(Some(recovered_candidate)) => {
-                            candidate = recovered_candidate;
+                let relay_lease = async {
+                    loop {
+                        if let Some(relay_lease) =
+                            forward_proxy::ForwardProxyRelayLease::acquire_for_selection(
+                                Arc::clone(&self.xray_supervisor),
+                                &candidate,
+                            )
+                            .await
+                        {
+                            break Some(relay_lease);
                         }
-                        Ok(None) => break None,
-                        Err(err) => {
-                            last_error = Some(err);
+                        if !candidate.uses_local_relay() || attempted_recovery {
                             break None;
                         }
+                        attempted_recovery = true;
+                        match self.recover_forward_proxy_candidate(&candidate.key).await {
+                            Ok(Some(recovered_candidate)) => {
+                                candidate = recovered_candidate;
+                            }
+                            Ok(None) => break None,
+                            Err(err) => {
+                                last_error = Some(err);
+                                break None;
+                            }
+                        }
                     }
-                };
+                }
+                .instrument(tracing::info_span!("forward_proxy_lease"))
+                .await;
                 let Some(relay_lease) = relay_lease else {
                     let _ = self
                         .record_forward_proxy_attempt(
@@ -1727,7 +1732,16 @@ impl TavilyProxy {
                     }
                 };
                 let started = Instant::now();
-                match build(client).send().await {
+                match build(client)
+                    .send()
+                    .instrument(tracing::info_span!(
+                        "upstream_call",
+                        otel.kind = "client",
+                        operation = request_kind,
+                        via = "forward_proxy",
+                    ))
+                    .await
+                {
                     Ok(response) => {
                         let latency_ms = started.elapsed().as_secs_f64() * 1000.0;
                         let _ = self
@@ -1779,7 +1793,16 @@ impl TavilyProxy {
             };
             let client = self.forward_proxy_clients.direct_client();
             let started = Instant::now();
-            match build(client).send().await {
+            match build(client)
+                .send()
+                .instrument(tracing::info_span!(
+                    "upstream_call",
+                    otel.kind = "client",
+                    operation = request_kind,
+                    via = "direct",
+                ))
+                .await
+            {
                 Ok(response) => {
                     let _ = self
                         .record_forward_proxy_attempt(
@@ -1814,6 +1837,11 @@ impl TavilyProxy {
             }
         }
         .await;
+        self.key_store.process_metrics.observe_upstream_latency(
+            request_kind,
+            if result.is_ok() { "ok" } else { "error" },
+            plan_started.elapsed(),
+        );
         self.xray_supervisor
             .lock()
             .await
@@ -1831,6 +1859,7 @@ impl TavilyProxy {
     where
         F: FnMut(Client) -> reqwest::RequestBuilder,
     {
+        self.admit_key_rate_budget(api_key_id, request_kind).await?;
         {
             let mut manager = self.forward_proxy.lock().await;
             manager.note_request();
@@ -11,6 +11,34 @@ fn http_upstream_operation(upstream_path: &str) -> Option<&'static str> {
     }
 }
 
+/// Key chosen for one `proxy_http_json_endpoint` attempt plus the routing effects to log with it.
+struct HttpJsonKeySelection {
+    lease: ApiKeyLease,
+    binding_effect: KeyEffect,
+    selection_effect: KeyEffect,
+    used_api_rebalance: bool,
+    used_http_project_affinity: bool,
+}
+
+/// Why a finished attempt may be replayed on another key, if it may at all.
+fn cross_key_retry_reason(
+    result: &Result<(ProxyResponse, AttemptAnalysis), ProxyError>,
+) -> Option<String> {
+    match result {
+        Ok((response, analysis)) => {
+            let code = analysis
+                .tavily_status_code
+                .unwrap_or(i64::from(response.status.as_u16()));
+            is_cross_key_retryable_status(code).then(|| format!("upstream status {code}"))
+        }
+        Err(ProxyError::Http(_)) => Some("upstream transport error".to_string()),
+        Err(ProxyError::KeyRateBudgetExhausted { .. }) => {
+            Some("local key rate budget exhausted".to_string())
+        }
+        Err(_) => None,
+    }
+}
+
 impl TavilyProxy {
     async fn select_http_json_key(
         &self,
@@ -19,60 +47,67 @@ impl TavilyProxy {
         api_routing_key: Option<&str>,
         http_project_id: Option<&str>,
     ) -> Result<(ApiKeyLease, KeyEffect, KeyEffect, bool, bool), ProxyError> {
-        if use_api_rebalance {
-            let selection = self
-                .acquire_key_for_api_route(auth_token_id, api_routing_key)
-                .await?;
-            return Ok((
-                selection.lease,
-                selection.binding_effect,
-                selection.selection_effect,
-                true,
-                false,
-            ));
-        }
+        async {
+            if use_api_rebalance {
+                let selection = self
+                    .acquire_key_for_api_route(auth_token_id, api_routing_key)
+                    .await?;
+                return Ok((
+                    selection.lease,
+                    selection.binding_effect,
+                    selection.selection_effect,
+                    true,
+                    false,
+                ));
+            }
 
-        if let Some(selection) = self
-            .acquire_key_for_http_project(auth_token_id, http_project_id)
-            .await?
-        {
-            return Ok((
-                selection.lease,
-                selection.binding_effect,
-                selection.selection_effect,
+            if let Some(selection) = self
+                .acquire_key_for_http_project(auth_token_id, http_project_id)
+                .await?
+            {
+                return Ok((
+                    selection.lease,
+                    selection.binding_effect,
+                    selection.selection_effect,
+                    false,
+                    true,
+                ));
+            }
+
+            Ok((
+                self.acquire_key_for(auth_token_id).await?,
+                KeyEffect::none(),
+                KeyEffect::none(),
                 false,
-                true,
-            ));
+                false,
+            ))
         }
-
-        Ok((
-            self.acquire_key_for(auth_token_id).await?,
-            KeyEffect::none(),
-            KeyEffect::none(),
-            false,
-            false,
-        ))
+        .instrument(tracing::info_span!("key_acquire"))
+        .await
     }
 
 
     /// 将请求透传到 Tavily upstream 并记录日志。
     pub async fn proxy_request(&self, request: ProxyRequest) -> Result<ProxyResponse, ProxyError> {
         let mut mcp_session_init_effect = KeyEffect::none();
-        let lease = if let Some(key_id) = request.pinned_api_key_id.as_deref() {
-            let Some(lease) = self.key_store.try_acquire_specific_key(key_id).await? else {
-                return Err(ProxyError::PinnedMcpSessionUnavailable);
-            };
-            lease
-        } else if request.prefer_mcp_session_affinity {
-            let selection = self
-                .acquire_key_for_mcp_session_init(request.auth_token_id.as_deref())
-                .await?;
-            mcp_session_init_effect = selection.key_effect;
-            selection.lease
-        } else {
-            self.acquire_key_for(request.auth_token_id.as_deref())
-                .await?
-        };
+        let lease = async {
+            if let Some(key_id) = request.pinned_api_key_id.as_deref() {
+                self.key_store
+                    .try_acquire_specific_key(key_id)
+                    .await?
+                    .ok_or(ProxyError::PinnedMcpSessionUnavailable)
+            } else if request.prefer_mcp_session_affinity {
+                let selection = self
+                    .acquire_key_for_mcp_session_init(request.auth_token_id.as_deref())
+                    .await?;
+                mcp_session_init_effect = selection.key_effect;
+                Ok(selection.lease)
+            } else {
+                self.acquire_key_for(request.auth_token_id.as_deref()).await
+            }
+        }
+        .instrument(tracing::info_span!("key_acquire"))
+        .await?;
 
         let mut url = build_mcp_upstream_url(&self.upstream, request.path.as_str());
 
@@ -283,6 +318,9 @@ impl TavilyProxy {
     /// Generic helper to proxy a Tavily HTTP JSON endpoint (e.g. `/search`, `/extract`).
     /// It injects the Tavily key into the `api_key` field, performs header sanitization,
     /// records request logs with sensitive fields redacted, and updates key quota state.
+    /// When the cross-key retry policy covers the endpoint, retryable upstream failures are
+    /// replayed on a different key; every attempt gets its own request log row and only the
+    /// final response is returned for billing.
     #[allow(clippy::too_many_arguments)]
     pub async fn proxy_http_json_endpoint(
         &self,
@@ -299,21 +337,100 @@ impl TavilyProxy {
         inject_upstream_bearer_auth: bool,
         client_ip: Option<&ClientIpInfo>,
     ) -> Result<(ProxyResponse, AttemptAnalysis), ProxyError> {
-        let upstream_operation = http_upstream_operation(upstream_path);
-        let (
-            lease,
-            api_route_binding_effect,
-            api_route_selection_effect,
-            used_api_rebalance,
-            used_http_project_affinity,
-        ) = self
-            .select_http_json_key(
+        let (lease, binding_effect, selection_effect, used_api_rebalance, used_http_project_affinity) =
+            self.select_http_json_key(
                 auth_token_id,
                 use_api_rebalance,
                 api_routing_key,
                 http_project_id,
             )
             .await?;
+        let retry_policy = self.key_store.cross_key_retry_settings().await?;
+        let retry_enabled = retry_policy.covers_upstream_path(upstream_path);
+        let started = Instant::now();
+        let mut tried_key_ids = vec![lease.id.clone()];
+        let mut selection = HttpJsonKeySelection {
+            lease,
+            binding_effect,
+            selection_effect,
+            used_api_rebalance,
+            used_http_project_affinity,
+        };
+        loop {
+            let failed_key_id = selection.lease.id.clone();
+            let result = self
+                .proxy_http_json_attempt(
+                    selection,
+                    usage_base,
+                    upstream_path,
+                    auth_token_id,
+                    method,
+                    display_path,
+                    options.clone(),
+                    original_headers,
+                    inject_upstream_bearer_auth,
+                    client_ip,
+                )
+                .await;
+            let attempts = tried_key_ids.len() as i64;
+            let Some(reason) = cross_key_retry_reason(&result) else {
+                return result;
+            };
+            if !retry_enabled
+                || attempts >= retry_policy.max_attempts
+                || started.elapsed().as_millis() >= retry_policy.budget_ms as u128
+            {
+                return result;
+            }
+            let route = self.key_group_route_for_token(auth_token_id).await?;
+            let lease = match self
+                .key_store
+                .acquire_key_avoiding_transient_backoff_excluding(
+                    HTTP_GLOBAL_BACKOFF_SCOPE,
+                    Some(&failed_key_id),
+                    &route,
+                )
+                .await
+            {
+                Ok(lease) if !tried_key_ids.contains(&lease.id) => lease,
+                Ok(_) | Err(ProxyError::NoAvailableKeys) => return result,
+                Err(err) => return Err(err),
+            };
+            tried_key_ids.push(lease.id.clone());
+            selection = HttpJsonKeySelection {
+                lease,
+                binding_effect: KeyEffect::none(),
+                selection_effect: Self::cross_key_retry_effect(attempts, &failed_key_id, &reason),
+                used_api_rebalance: false,
+                used_http_project_affinity: false,
+            };
+        }
+    }
+
+    /// Sends one upstream attempt for [`Self::proxy_http_json_endpoint`] with an already
+    /// selected key and records it as its own request log row.
+    #[allow(clippy::too_many_arguments)]
+    async fn proxy_http_json_attempt(
+        &self,
+        selection: HttpJsonKeySelection,
+        usage_base: &str,
+        upstream_path: &str,
+        auth_token_id: Option<&str>,
+        method: &Method,
+        display_path: &str,
+        options: Value,
+        original_headers: &HeaderMap,
+        inject_upstream_bearer_auth: bool,
+        client_ip: Option<&ClientIpInfo>,
+    ) -> Result<(ProxyResponse, AttemptAnalysis), ProxyError> {
+        let upstream_operation = http_upstream_operation(upstream_path);
+        let HttpJsonKeySelection {
+            lease,
+            binding_effect: api_route_binding_effect,
+            selection_effect: api_route_selection_effect,
+            used_api_rebalance,
+            used_http_project_affinity,
+        } = selection;
 
         let base = Url::parse(usage_base).map_err(|source| ProxyError::InvalidEndpoint {
             endpoint: usage_base.to_owned(),
@@ -660,7 +777,36 @@ impl TavilyProxy {
         upstream_operation: &str,
         client_ip: Option<&ClientIpInfo>,
     ) -> Result<ProxyResponse, ProxyError> {
-        let lease = self.acquire_key_for_rebalance_mcp_http_call().await?;
+        let coalescing = self.request_coalescing_settings().await?;
+        let mut flight_leader = None;
+        if coalescing.covers_upstream_path(upstream_path) {
+            match self.join_request_flight(&request_coalescing_key(upstream_path, &options)) {
+                RequestFlight::Leader(leader) => flight_leader = Some(leader),
+                RequestFlight::Follower(follower) => {
+                    if let Some(shared) = follower.wait().await {
+                        return self
+                            .serve_coalesced_rebalance_mcp_response(
+                                &coalescing,
+                                shared,
+                                auth_token_id,
+                                method,
+                                display_path,
+                                original_request_body,
+                                response_id,
+                                proxy_session_id,
+                                routing_subject_hash,
+                                upstream_operation,
+                                client_ip,
+                            )
+                            .await;
+                    }
+                }
+            }
+        }
+        let lease = self
+            .acquire_key_for_rebalance_mcp_http_call(auth_token_id)
+            .instrument(tracing::info_span!("key_acquire"))
+            .await?;
 
         let base = Url::parse(usage_base).map_err(|source| ProxyError::InvalidEndpoint {
             endpoint: usage_base.to_owned(),
@@ -724,6 +870,20 @@ impl TavilyProxy {
 
                 let mut analysis = analyze_http_attempt(upstream_status, &upstream_body);
                 analysis.api_key_id = Some(lease.id.clone());
+                if let Some(leader) = flight_leader.take()
+                    && upstream_status.is_success()
+                    && analysis.status == OUTCOME_SUCCESS
+                {
+                    leader.publish(SharedUpstreamResponse {
+                        status: upstream_status,
+                        content_type: upstream_headers
+                            .get(reqwest::header::CONTENT_TYPE)
+                            .and_then(|value| value.to_str().ok())
+                            .map(str::to_string),
+                        body: upstream_body.clone(),
+                        credits: extract_usage_credits_from_json_bytes(&upstream_body).unwrap_or(0),
+                    });
+                }
                 if analysis.failure_kind.is_none() && analysis.status == OUTCOME_ERROR {
                     analysis.failure_kind = classify_failure_kind(
                         display_path,
@@ -905,7 +1065,10 @@ impl TavilyProxy {
         upstream_operation: &str,
         client_ip: Option<&ClientIpInfo>,
     ) -> Result<ProxyResponse, ProxyError> {
-        let lease = self.acquire_key_for_rebalance_mcp_http_call().await?;
+        let lease = self
+            .acquire_key_for_rebalance_mcp_http_call(auth_token_id)
+            .instrument(tracing::info_span!("key_acquire"))
+            .await?;
 
         let base = Url::parse(usage_base).map_err(|source| ProxyError::InvalidEndpoint {
             endpoint: usage_base.to_owned(),
@@ -1160,269 +1323,32 @@ impl TavilyProxy {
         inject_upstream_bearer_auth: bool,
         client_ip: Option<&ClientIpInfo>,
     ) -> Result<(ProxyResponse, AttemptAnalysis, Option<i64>), ProxyError> {
-        let (
-            lease,
-            api_route_binding_effect,
-            api_route_selection_effect,
-            used_api_rebalance,
-            used_http_project_affinity,
-        ) = self
-            .select_http_json_key(
+        let (attempt, url, request_body) = self
+            .prepare_http_research_attempt(
+                usage_base,
                 auth_token_id,
                 use_api_rebalance,
                 api_routing_key,
                 http_project_id,
+                method,
+                display_path,
+                options,
+                original_headers,
+                client_ip,
             )
             .await?;
-        let base = Url::parse(usage_base).map_err(|source| ProxyError::InvalidEndpoint {
-            endpoint: usage_base.to_owned(),
-            source,
-        })?;
-        let origin = origin_from_url(&base);
-
-        let url = build_path_prefixed_url(&base, "/research");
-
-        let mut sanitized_headers = sanitize_headers_inner(original_headers, &base, &origin);
-        self.apply_upstream_project_id_header(
-            &mut sanitized_headers,
-            original_headers,
-            auth_token_id,
-        )
-        .await?;
-
-        // Build upstream request body by injecting Tavily key into api_key field.
-        let mut upstream_options = options;
-        if let Value::Object(ref mut map) = upstream_options {
-            let keys_to_remove: Vec<String> = map
-                .keys()
-                .filter(|k| k.eq_ignore_ascii_case("api_key"))
-                .cloned()
-                .collect();
-            for key in keys_to_remove {
-                map.remove(&key);
-            }
-            map.insert("api_key".to_string(), Value::String(lease.secret.clone()));
-        } else {
-            let mut map = serde_json::Map::new();
-            map.insert("api_key".to_string(), Value::String(lease.secret.clone()));
-            map.insert("payload".to_string(), upstream_options);
-            upstream_options = Value::Object(map);
-        }
-
-        let request_body =
-            serde_json::to_vec(&upstream_options).map_err(|e| ProxyError::Other(e.to_string()))?;
-        let redacted_request_body = redact_api_key_bytes(&request_body);
-
-        let request_method = method.clone();
-        let request_url = url.clone();
-        let upstream_secret = lease.secret.clone();
         let response = self
-            .send_with_forward_proxy(&lease.id, "research", |client| {
-                let mut builder = client.request(request_method.clone(), request_url.clone());
-                for (name, value) in sanitized_headers.headers.iter() {
-                    if name == HOST || name == CONTENT_LENGTH {
-                        continue;
-                    }
-                    builder = builder.header(name, value);
-                }
-                if inject_upstream_bearer_auth {
-                    builder =
-                        builder.header("Authorization", format!("Bearer {}", upstream_secret));
-                }
-                builder.body(request_body.clone())
-            })
+            .send_http_research_attempt(&attempt, &url, request_body, inject_upstream_bearer_auth)
             .await;
 
         match response {
             Ok((response, _relay_lease)) => {
-                let status = response.status();
-                let headers = response.headers().clone();
-                let body_bytes = response.bytes().await.map_err(ProxyError::Http)?;
-
-                let mut analysis = analyze_http_attempt(status, &body_bytes);
-                analysis.api_key_id = Some(lease.id.clone());
-                if analysis.failure_kind.is_none() && analysis.status == OUTCOME_ERROR {
-                    analysis.failure_kind = classify_failure_kind(
-                        display_path,
-                        Some(status.as_u16() as i64),
-                        analysis.tavily_status_code,
-                        None,
-                        &body_bytes,
-                    );
-                }
-                let redacted_response_body = redact_api_key_bytes(&body_bytes);
-                if status.is_success()
-                    && let Some(request_id) = extract_research_request_id(&body_bytes)
-                    && let Some(token_id) = auth_token_id
-                {
-                    self.record_research_request_affinity(&request_id, &lease.id, token_id)
-                        .await?;
-                }
-
-                let mut key_effect = self
-                    .reconcile_key_health(&lease, display_path, &analysis, auth_token_id)
-                    .await?;
-                if key_effect.code == KEY_EFFECT_NONE && analysis.status == OUTCOME_SUCCESS {
-                    key_effect = self
-                        .clear_transient_backoffs_after_success(&lease.id, display_path, auth_token_id)
-                        .await?;
-                }
-                let armed_api_rebalance_backoff = if used_api_rebalance {
-                    self.maybe_arm_api_rebalance_backoff(&lease.id, &headers, &analysis)
-                        .await?
-                } else {
-                    false
-                };
-                let armed_http_global_backoff = if used_api_rebalance {
-                    false
-                } else {
-                    self.maybe_arm_http_global_backoff(&lease.id, &headers, &analysis)
-                        .await?
-                };
-                let armed_http_project_affinity_backoff = if used_http_project_affinity {
-                    self.maybe_arm_http_project_affinity_backoff(
-                        &lease.id,
-                        &headers,
-                        &analysis,
-                        true,
-                    )
-                    .await?
-                } else {
-                    false
-                };
-                if key_effect.code == KEY_EFFECT_NONE
-                    && (armed_api_rebalance_backoff
-                        || armed_http_project_affinity_backoff
-                        || armed_http_global_backoff)
-                {
-                    key_effect = Self::transient_backoff_set_effect();
-                }
-                let primary_effect = Self::primary_request_effect(
-                    &key_effect,
-                    &api_route_binding_effect,
-                    &api_route_selection_effect,
-                );
-
-                let request_log_id = self
-                    .key_store
-                    .log_attempt(AttemptLog {
-                        key_id: Some(&lease.id),
-                        auth_token_id,
-                        method,
-                        path: display_path,
-                        query: None,
-                        status: Some(status),
-                        tavily_status_code: analysis.tavily_status_code,
-                        error: None,
-                        request_body: &redacted_request_body,
-                        response_body: &redacted_response_body,
-                        outcome: analysis.status,
-                        failure_kind: analysis.failure_kind.as_deref(),
-                        key_effect_code: key_effect.code.as_str(),
-                        key_effect_summary: key_effect.summary.as_deref(),
-                        binding_effect_code: api_route_binding_effect.code.as_str(),
-                        binding_effect_summary: api_route_binding_effect.summary.as_deref(),
-                        selection_effect_code: api_route_selection_effect.code.as_str(),
-                        selection_effect_summary: api_route_selection_effect.summary.as_deref(),
-                        gateway_mode: None,
-                        experiment_variant: None,
-                        proxy_session_id: None,
-                        routing_subject_hash: None,
-                        upstream_operation: None,
-                        fallback_reason: None,
-                        forwarded_headers: &sanitized_headers.forwarded,
-                        dropped_headers: &sanitized_headers.dropped,
-                        client_ip,
-                    })
-                    .await?;
-                self.link_transient_backoff_clear_request_log(
-                    &key_effect,
-                    &lease.id,
-                    request_log_id,
-                )
-                .await?;
-                if armed_api_rebalance_backoff {
-                    self.key_store
-                        .set_api_key_transient_backoff_request_log_id(
-                            &lease.id,
-                            API_REBALANCE_HTTP_BACKOFF_SCOPE,
-                            request_log_id,
-                            self.backend_time.now_ts(),
-                        )
-                        .await?;
-                } else if armed_http_project_affinity_backoff {
-                    self.key_store
-                        .set_api_key_transient_backoff_request_log_id(
-                            &lease.id,
-                            HTTP_PROJECT_AFFINITY_BACKOFF_SCOPE,
-                            request_log_id,
-                            self.backend_time.now_ts(),
-                        )
-                        .await?;
-                } else if armed_http_global_backoff {
-                    self.key_store
-                        .set_api_key_transient_backoff_request_log_id(
-                            &lease.id,
-                            HTTP_GLOBAL_BACKOFF_SCOPE,
-                            request_log_id,
-                            self.backend_time.now_ts(),
-                        )
-                        .await?;
-                }
-                analysis.key_effect = primary_effect;
-
-                Ok((
-                    ProxyResponse {
-                        status,
-                        headers,
-                        body: body_bytes,
-                        api_key_id: Some(lease.id.clone()),
-                        request_log_id: Some(request_log_id),
-                        key_effect_code: key_effect.code,
-                        key_effect_summary: key_effect.summary,
-                        binding_effect_code: api_route_binding_effect.code,
-                        binding_effect_summary: api_route_binding_effect.summary,
-                        selection_effect_code: api_route_selection_effect.code,
-                        selection_effect_summary: api_route_selection_effect.summary,
-                    },
-                    analysis,
-                    None,
-                ))
+                let (response, analysis) =
+                    self.complete_http_research_buffered(&attempt, response).await?;
+                Ok((response, analysis, None))
             }
             Err(err) => {
-                log_proxy_error(&lease.secret, method, display_path, None, &err);
-                let redacted_empty: Vec<u8> = Vec::new();
-                self.key_store
-                    .log_attempt(AttemptLog {
-                        key_id: Some(&lease.id),
-                        auth_token_id,
-                        method,
-                        path: display_path,
-                        query: None,
-                        status: None,
-                        tavily_status_code: None,
-                        error: Some(&err.to_string()),
-                        request_body: &redacted_request_body,
-                        response_body: &redacted_empty,
-                        outcome: OUTCOME_ERROR,
-                        failure_kind: None,
-                        key_effect_code: KEY_EFFECT_NONE,
-                        key_effect_summary: None,
-                        binding_effect_code: KEY_EFFECT_NONE,
-                        binding_effect_summary: None,
-                        selection_effect_code: KEY_EFFECT_NONE,
-                        selection_effect_summary: None,
-                        gateway_mode: None,
-                        experiment_variant: None,
-                        proxy_session_id: None,
-                        routing_subject_hash: None,
-                        upstream_operation: None,
-                        fallback_reason: None,
-                        forwarded_headers: &sanitized_headers.forwarded,
-                        dropped_headers: &sanitized_headers.dropped,
-                        client_ip,
-                    })
-                    .await?;
+                self.log_http_research_transport_error(&attempt, &err).await?;
                 Err(err)
             }
         }
@@ -1451,6 +1377,7 @@ impl TavilyProxy {
                 .ok_or(ProxyError::NoAvailableKeys)?
         } else {
             self.acquire_key_for_research_request(auth_token_id, research_request_id.as_deref())
+                .instrument(tracing::info_span!("key_acquire"))
                 .await?
         };
 
@@ -1901,6 +1828,7 @@ impl TavilyProxy {
         auth_token_id: Option<&str>,
         key_id: Option<&str>,
         operational_class: Option<&str>,
+        search: Option<&str>,
         until: Option<i64>,
         cursor: Option<&RequestLogsCursor>,
         direction: RequestLogsCursorDirection,
@@ -1922,6 +1850,7 @@ impl TavilyProxy {
                 auth_token_id,
                 key_id,
                 operational_class,
+                search,
                 cursor,
                 direction,
                 page_size,
@@ -1929,6 +1858,20 @@ impl TavilyProxy {
             .await
     }
 
+    /// Indexes the next batch of request logs for full-text search. Returns `true` while a
+    /// backlog remains so the scheduler can continue without waiting a full interval.
+    pub async fn advance_request_log_search_index(&self) -> Result<bool, ProxyError> {
+        Ok(matches!(
+            self.key_store
+                .advance_request_log_search_index_slice()
+                .await?,
+            RequestLogSearchSliceOutcome::Advanced {
+                complete: false,
+                ..
+            }
+        ))
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub async fn request_logs_catalog(
         &self,
@@ -1988,7 +1931,9 @@ impl TavilyProxy {
         key_id: &str,
         since: i64,
     ) -> Result<ProxySummary, ProxyError> {
-        self.key_store.fetch_key_summary_since(key_id, since).await
+        let mut summary = self.key_store.fetch_key_summary_since(key_id, since).await?;
+        summary.key_rate_budgets = self.api_key_rate_budget_usage_for(key_id).await?;
+        Ok(summary)
     }
 
     /// 获取指定 key 的最近日志（可选起始时间过滤）。
@@ -2053,6 +1998,7 @@ impl TavilyProxy {
         selection_effect_code: Option<&str>,
         auth_token_id: Option<&str>,
         operational_class: Option<&str>,
+        search: Option<&str>,
         cursor: Option<&RequestLogsCursor>,
         direction: RequestLogsCursorDirection,
         page_size: i64,
@@ -2073,6 +2019,7 @@ impl TavilyProxy {
                 auth_token_id,
                 None,
                 operational_class,
+                search,
                 cursor,
                 direction,
                 page_size,
@@ -0,0 +1,729 @@
+/// Upper bound for the raw SSE transcript kept for a streamed research request log.
+///
+/// The transcript is zstd-compressed before it reaches `request_logs`, but the raw capture is
+/// bounded first so one long-running research stream cannot grow the log row without limit.
+pub const RESEARCH_STREAM_TRANSCRIPT_MAX_BYTES: usize = 256 * 1024;
+/// Upper bound for one not-yet-terminated SSE event while parsing the research stream.
+const RESEARCH_STREAM_PENDING_EVENT_MAX_BYTES: usize = 1024 * 1024;
+const RESEARCH_STREAM_TRANSCRIPT_TRUNCATED_MARKER: &[u8] =
+    b"\n: hikari transcript truncated\n\n";
+
+/// Owned context for one Tavily `/research` attempt, shared by the buffered and streamed paths.
+struct HttpResearchAttempt {
+    lease: ApiKeyLease,
+    auth_token_id: Option<String>,
+    method: Method,
+    display_path: String,
+    redacted_request_body: Vec<u8>,
+    sanitized_headers: SanitizedHeaders,
+    client_ip: Option<ClientIpInfo>,
+    binding_effect: KeyEffect,
+    selection_effect: KeyEffect,
+    used_api_rebalance: bool,
+    used_http_project_affinity: bool,
+}
+
+/// Incremental SSE observer for Tavily research streams.
+///
+/// It keeps a bounded copy of the raw stream for request logs and extracts the facts billing
+/// needs (research request id, final usage credits, terminal/error events) without buffering
+/// the whole response.
+#[derive(Debug, Default)]
+struct ResearchStreamTranscript {
+    captured: Vec<u8>,
+    truncated: bool,
+    pending: Vec<u8>,
+    research_request_id: Option<String>,
+    usage_credits: Option<i64>,
+    error_message: Option<String>,
+    completed: bool,
+}
+
+impl ResearchStreamTranscript {
+    fn observe(&mut self, chunk: &[u8]) {
+        let remaining = RESEARCH_STREAM_TRANSCRIPT_MAX_BYTES.saturating_sub(self.captured.len());
+        if chunk.len() > remaining {
+            self.captured.extend_from_slice(&chunk[..remaining]);
+            self.truncated = true;
+        } else {
+            self.captured.extend_from_slice(chunk);
+        }
+
+        self.pending.extend(chunk.iter().copied().filter(|byte| *byte != b'\r'));
+        while let Some(end) = self
+            .pending
+            .windows(2)
+            .position(|window| window == b"\n\n")
+        {
+            let event = self.pending.drain(..end + 2).collect::<Vec<u8>>();
+            self.observe_event(&event);
+        }
+        if self.pending.len() > RESEARCH_STREAM_PENDING_EVENT_MAX_BYTES {
+            self.pending.clear();
+        }
+    }
+
+    fn finish(&mut self) {
+        if !self.pending.is_empty() {
+            let event = std::mem::take(&mut self.pending);
+            self.observe_event(&event);
+        }
+    }
+
+    fn observe_event(&mut self, raw: &[u8]) {
+        let Ok(text) = std::str::from_utf8(raw) else {
+            return;
+        };
+        let mut event_name: Option<&str> = None;
+        let mut data = String::new();
+        for line in text.lines() {
+            if let Some(rest) = line.strip_prefix("event:") {
+                event_name = Some(rest.trim());
+            } else if let Some(rest) = line.strip_prefix("data:") {
+                if !data.is_empty() {
+                    data.push('\n');
+                }
+                data.push_str(rest.strip_prefix(' ').unwrap_or(rest));
+            }
+        }
+        if event_name.is_none() && data.is_empty() {
+            return;
+        }
+
+        let event_name = event_name.map(str::to_ascii_lowercase);
+        if matches!(event_name.as_deref(), Some("done" | "complete" | "completed"))
+            || data.trim() == "[DONE]"
+        {
+            self.completed = true;
+        }
+
+        let Ok(value) = serde_json::from_str::<Value>(&data) else {
+            if event_name.as_deref() == Some("error") && self.error_message.is_none() {
+                self.error_message = Some(data.trim().to_string()).filter(|msg| !msg.is_empty());
+            }
+            return;
+        };
+
+        if self.research_request_id.is_none() {
+            self.research_request_id = ["request_id", "requestId", "id"]
+                .iter()
+                .find_map(|field| value.get(*field).and_then(Value::as_str))
+                .map(str::trim)
+                .filter(|id| !id.is_empty())
+                .map(ToOwned::to_owned);
+        }
+        if let Some(credits) = extract_usage_credits_from_value(&value) {
+            self.usage_credits = Some(credits);
+        }
+        if value
+            .get("status")
+            .and_then(Value::as_str)
+            .is_some_and(|status| status.eq_ignore_ascii_case("completed"))
+        {
+            self.completed = true;
+        }
+        if self.error_message.is_none()
+            && (event_name.as_deref() == Some("error") || value.get("error").is_some())
+        {
+            self.error_message = Some(
+                value
+                    .get("error")
+                    .and_then(|error| {
+                        error
+                            .as_str()
+                            .map(ToOwned::to_owned)
+                            .or_else(|| error.get("message").and_then(Value::as_str).map(ToOwned::to_owned))
+                    })
+                    .or_else(|| value.get("message").and_then(Value::as_str).map(ToOwned::to_owned))
+                    .unwrap_or_else(|| "research stream reported an error".to_string()),
+            );
+        }
+    }
+
+    /// Bounded, zstd-compressed transcript for `request_logs.response_body`.
+    fn compressed_for_log(&self) -> Vec<u8> {
+        let mut raw = redact_research_stream_transcript(&self.captured);
+        if self.truncated {
+            raw.extend_from_slice(RESEARCH_STREAM_TRANSCRIPT_TRUNCATED_MARKER);
+        }
+        compress_request_log_body(&raw)
+    }
+}
+
+/// Redact `api_key` fields inside each JSON `data:` line while keeping the SSE framing intact.
+fn redact_research_stream_transcript(raw: &[u8]) -> Vec<u8> {
+    let text = String::from_utf8_lossy(raw);
+    let mut redacted = Vec::with_capacity(raw.len());
+    for line in text.split_inclusive('\n') {
+        let (content, newline) = match line.strip_suffix('\n') {
+            Some(content) => (content, "\n"),
+            None => (line, ""),
+        };
+        let payload = content
+            .strip_prefix("data:")
+            .map(|rest| rest.strip_prefix(' ').unwrap_or(rest));
+        match payload.and_then(|payload| serde_json::from_str::<Value>(payload).ok()) {
+            Some(mut value) if content.contains("api_key") => {
+                redact_api_key_fields(&mut value);
+                redacted.extend_from_slice(b"data: ");
+                redacted.extend_from_slice(value.to_string().as_bytes());
+            }
+            _ => redacted.extend_from_slice(content.as_bytes()),
+        }
+        redacted.extend_from_slice(newline.as_bytes());
+    }
+    redacted
+}
+
+/// Result of starting a Tavily `/research` call with `stream=true`.
+pub enum HttpResearchStreamStart {
+    /// Upstream answered with an SSE body; the caller forwards it chunk by chunk.
+    Streaming(Box<HttpResearchStream>),
+    /// Upstream answered with a regular body (validation errors, 4xx/5xx, or a non-SSE
+    /// response). The attempt is already logged exactly like non-streaming research.
+    Buffered(Box<ProxyResponse>, AttemptAnalysis),
+}
+
+/// A live upstream research SSE stream pinned to one API key.
+pub struct HttpResearchStream {
+    status: StatusCode,
+    headers: HeaderMap,
+    response: reqwest::Response,
+    attempt: HttpResearchAttempt,
+    transcript: ResearchStreamTranscript,
+    affinity_recorded: bool,
+    transport_error: Option<String>,
+    _relay_lease: forward_proxy::ForwardProxyRelayLease,
+}
+
+/// Settled facts about a finished research stream.
+#[derive(Debug, Clone)]
+pub struct HttpResearchStreamOutcome {
+    /// Attempt metadata; `body` is always empty because the stream was forwarded live.
+    pub response: ProxyResponse,
+    pub analysis: AttemptAnalysis,
+    /// Credits reported by the final upstream usage event, when present.
+    pub usage_credits: Option<i64>,
+    pub research_request_id: Option<String>,
+    /// Whether upstream signalled a terminal event before the stream closed.
+    pub completed: bool,
+}
+
+impl HttpResearchStream {
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Read the next upstream chunk, feeding the transcript and pinning the research request
+    /// id to the selected key as soon as upstream reveals it.
+    pub async fn next_chunk(&mut self, proxy: &TavilyProxy) -> Option<Bytes> {
+        if self.transport_error.is_some() {
+            return None;
+        }
+        match self.response.chunk().await {
+            Ok(Some(chunk)) => {
+                self.transcript.observe(&chunk);
+                if !self.affinity_recorded
+                    && let Some(request_id) = self.transcript.research_request_id.as_deref()
+                    && let Some(token_id) = self.attempt.auth_token_id.as_deref()
+                {
+                    self.affinity_recorded = true;
+                    if let Err(err) = proxy
+                        .record_research_request_affinity(request_id, &self.attempt.lease.id, token_id)
+                        .await
+                    {
+                        warn!(
+                            component = "proxy",
+                            event = "research_stream_affinity_failed",
+                            key_id = %self.attempt.lease.id,
+                            err = %err,
+                            "record research stream affinity failed"
+                        );
+                    }
+                }
+                Some(chunk)
+            }
+            Ok(None) => None,
+            Err(err) => {
+                log_error(
+                    &self.attempt.lease.secret,
+                    &self.attempt.method,
+                    &self.attempt.display_path,
+                    None,
+                    &err,
+                );
+                self.transport_error = Some(err.to_string());
+                None
+            }
+        }
+    }
+
+    /// Close the stream: classify the outcome, update key health, and write one request log
+    /// row carrying the compressed transcript.
+    pub async fn finish(
+        mut self,
+        proxy: &TavilyProxy,
+    ) -> Result<HttpResearchStreamOutcome, ProxyError> {
+        self.transcript.finish();
+        let transcript = self.transcript;
+        let mut analysis = AttemptAnalysis {
+            status: OUTCOME_SUCCESS,
+            tavily_status_code: Some(self.status.as_u16() as i64),
+            key_health_action: KeyHealthAction::None,
+            failure_kind: None,
+            key_effect: KeyEffect::none(),
+            api_key_id: Some(self.attempt.lease.id.clone()),
+        };
+        let error_message = self
+            .transport_error
+            .clone()
+            .or_else(|| transcript.error_message.clone());
+        if error_message.is_some() {
+            analysis.status = OUTCOME_ERROR;
+            analysis.failure_kind = classify_failure_kind(
+                &self.attempt.display_path,
+                Some(self.status.as_u16() as i64),
+                None,
+                error_message.as_deref(),
+                &[],
+            );
+        }
+
+        let response_body = transcript.compressed_for_log();
+        let (response, analysis) = proxy
+            .complete_http_research_attempt(
+                &self.attempt,
+                self.status,
+                &self.headers,
+                analysis,
+                None,
+                &response_body,
+                error_message.as_deref(),
+            )
+            .await?;
+        Ok(HttpResearchStreamOutcome {
+            response,
+            analysis,
+            usage_credits: transcript.usage_credits,
+            research_request_id: transcript.research_request_id,
+            completed: transcript.completed && error_message.is_none(),
+        })
+    }
+}
+
+fn research_response_is_event_stream(headers: &HeaderMap) -> bool {
+    headers
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| {
+            value
+                .split(';')
+                .next()
+                .is_some_and(|mime| mime.trim().eq_ignore_ascii_case("text/event-stream"))
+        })
+}
+
+impl TavilyProxy {
+    #[allow(clippy::too_many_arguments)]
+    async fn prepare_http_research_attempt(
+        &self,
+        usage_base: &str,
+        auth_token_id: Option<&str>,
+        use_api_rebalance: bool,
+        api_routing_key: Option<&str>,
+        http_project_id: Option<&str>,
+        method: &Method,
+        display_path: &str,
+        options: Value,
+        original_headers: &HeaderMap,
+        client_ip: Option<&ClientIpInfo>,
+    ) -> Result<(HttpResearchAttempt, Url, Vec<u8>), ProxyError> {
+        let (
+            lease,
+            binding_effect,
+            selection_effect,
+            used_api_rebalance,
+            used_http_project_affinity,
+        ) = self
+            .select_http_json_key(
+                auth_token_id,
+                use_api_rebalance,
+                api_routing_key,
+                http_project_id,
+            )
+            .await?;
+        let base = Url::parse(usage_base).map_err(|source| ProxyError::InvalidEndpoint {
+            endpoint: usage_base.to_owned(),
+            source,
+        })?;
+        let origin = origin_from_url(&base);
+
+        let url = build_path_prefixed_url(&base, "/research");
+
+        let mut sanitized_headers = sanitize_headers_inner(original_headers, &base, &origin);
+        self.apply_upstream_project_id_header(
+            &mut sanitized_headers,
+            original_headers,
+            auth_token_id,
+        )
+        .await?;
+
+        // Build upstream request body by injecting Tavily key into api_key field.
+        let mut upstream_options = options;
+        if let Value::Object(ref mut map) = upstream_options {
+            let keys_to_remove: Vec<String> = map
+                .keys()
+                .filter(|k| k.eq_ignore_ascii_case("api_key"))
+                .cloned()
+                .collect();
+            for key in keys_to_remove {
+                map.remove(&key);
+            }
+            map.insert("api_key".to_string(), Value::String(lease.secret.clone()));
+        } else {
+            let mut map = serde_json::Map::new();
+            map.insert("api_key".to_string(), Value::String(lease.secret.clone()));
+            map.insert("payload".to_string(), upstream_options);
+            upstream_options = Value::Object(map);
+        }
+
+        let request_body =
+            serde_json::to_vec(&upstream_options).map_err(|e| ProxyError::Other(e.to_string()))?;
+        let redacted_request_body = redact_api_key_bytes(&request_body);
+
+        Ok((
+            HttpResearchAttempt {
+                lease,
+                auth_token_id: auth_token_id.map(ToOwned::to_owned),
+                method: method.clone(),
+                display_path: display_path.to_owned(),
+                redacted_request_body,
+                sanitized_headers,
+                client_ip: client_ip.cloned(),
+                binding_effect,
+                selection_effect,
+                used_api_rebalance,
+                used_http_project_affinity,
+            },
+            url,
+            request_body,
+        ))
+    }
+
+    async fn send_http_research_attempt(
+        &self,
+        attempt: &HttpResearchAttempt,
+        url: &Url,
+        request_body: Vec<u8>,
+        inject_upstream_bearer_auth: bool,
+    ) -> Result<(reqwest::Response, forward_proxy::ForwardProxyRelayLease), ProxyError> {
+        let request_method = attempt.method.clone();
+        let upstream_secret = attempt.lease.secret.clone();
+        self.send_with_forward_proxy(&attempt.lease.id, "research", |client| {
+            let mut builder = client.request(request_method.clone(), url.clone());
+            for (name, value) in attempt.sanitized_headers.headers.iter() {
+                if name == HOST || name == CONTENT_LENGTH {
+                    continue;
+                }
+                builder = builder.header(name, value);
+            }
+            if inject_upstream_bearer_auth {
+                builder = builder.header("Authorization", format!("Bearer {}", upstream_secret));
+            }
+            builder.body(request_body.clone())
+        })
+        .await
+    }
+
+    async fn complete_http_research_buffered(
+        &self,
+        attempt: &HttpResearchAttempt,
+        response: reqwest::Response,
+    ) -> Result<(ProxyResponse, AttemptAnalysis), ProxyError> {
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body_bytes = response.bytes().await.map_err(ProxyError::Http)?;
+
+        let mut analysis = analyze_http_attempt(status, &body_bytes);
+        analysis.api_key_id = Some(attempt.lease.id.clone());
+        if analysis.failure_kind.is_none() && analysis.status == OUTCOME_ERROR {
+            analysis.failure_kind = classify_failure_kind(
+                &attempt.display_path,
+                Some(status.as_u16() as i64),
+                analysis.tavily_status_code,
+                None,
+                &body_bytes,
+            );
+        }
+        let redacted_response_body = redact_api_key_bytes(&body_bytes);
+        let research_request_id = status
+            .is_success()
+            .then(|| extract_research_request_id(&body_bytes))
+            .flatten();
+        let (mut response, analysis) = self
+            .complete_http_research_attempt(
+                attempt,
+                status,
+                &headers,
+                analysis,
+                research_request_id.as_deref(),
+                &redacted_response_body,
+                None,
+            )
+            .await?;
+        response.body = body_bytes;
+        Ok((response, analysis))
+    }
+
+    /// Apply key-health, backoff, and request-log bookkeeping for a finished research attempt.
+    /// The returned response carries attempt metadata with an empty body.
+    #[allow(clippy::too_many_arguments)]
+    async fn complete_http_research_attempt(
+        &self,
+        attempt: &HttpResearchAttempt,
+        status: StatusCode,
+        headers: &HeaderMap,
+        mut analysis: AttemptAnalysis,
+        research_request_id: Option<&str>,
+        response_body_for_log: &[u8],
+        error: Option<&str>,
+    ) -> Result<(ProxyResponse, AttemptAnalysis), ProxyError> {
+        let lease = &attempt.lease;
+        let auth_token_id = attempt.auth_token_id.as_deref();
+        let display_path = attempt.display_path.as_str();
+        if let Some(request_id) = research_request_id
+            && let Some(token_id) = auth_token_id
+        {
+            self.record_research_request_affinity(request_id, &lease.id, token_id)
+                .await?;
+        }
+
+        let mut key_effect = self
+            .reconcile_key_health(lease, display_path, &analysis, auth_token_id)
+            .await?;
+        if key_effect.code == KEY_EFFECT_NONE && analysis.status == OUTCOME_SUCCESS {
+            key_effect = self
+                .clear_transient_backoffs_after_success(&lease.id, display_path, auth_token_id)
+                .await?;
+        }
+        let armed_api_rebalance_backoff = if attempt.used_api_rebalance {
+            self.maybe_arm_api_rebalance_backoff(&lease.id, headers, &analysis)
+                .await?
+        } else {
+            false
+        };
+        let armed_http_global_backoff = if attempt.used_api_rebalance {
+            false
+        } else {
+            self.maybe_arm_http_global_backoff(&lease.id, headers, &analysis)
+                .await?
+        };
+        let armed_http_project_affinity_backoff = if attempt.used_http_project_affinity {
+            self.maybe_arm_http_project_affinity_backoff(&lease.id, headers, &analysis, true)
+                .await?
+        } else {
+            false
+        };
+        if key_effect.code == KEY_EFFECT_NONE
+            && (armed_api_rebalance_backoff
+                || armed_http_project_affinity_backoff
+                || armed_http_global_backoff)
+        {
+            key_effect = Self::transient_backoff_set_effect();
+        }
+        let primary_effect = Self::primary_request_effect(
+            &key_effect,
+            &attempt.binding_effect,
+            &attempt.selection_effect,
+        );
+
+        let request_log_id = self
+            .key_store
+            .log_attempt(AttemptLog {
+                key_id: Some(&lease.id),
+                auth_token_id,
+                method: &attempt.method,
+                path: display_path,
+                query: None,
+                status: Some(status),
+                tavily_status_code: analysis.tavily_status_code,
+                error,
+                request_body: &attempt.redacted_request_body,
+                response_body: response_body_for_log,
+                outcome: analysis.status,
+                failure_kind: analysis.failure_kind.as_deref(),
+                key_effect_code: key_effect.code.as_str(),
+                key_effect_summary: key_effect.summary.as_deref(),
+                binding_effect_code: attempt.binding_effect.code.as_str(),
+                binding_effect_summary: attempt.binding_effect.summary.as_deref(),
+                selection_effect_code: attempt.selection_effect.code.as_str(),
+                selection_effect_summary: attempt.selection_effect.summary.as_deref(),
+                gateway_mode: None,
+                experiment_variant: None,
+                proxy_session_id: None,
+                routing_subject_hash: None,
+                upstream_operation: None,
+                fallback_reason: None,
+                forwarded_headers: &attempt.sanitized_headers.forwarded,
+                dropped_headers: &attempt.sanitized_headers.dropped,
+                client_ip: attempt.client_ip.as_ref(),
+            })
+            .await?;
+        self.link_transient_backoff_clear_request_log(&key_effect, &lease.id, request_log_id)
+            .await?;
+        let armed_scope = if armed_api_rebalance_backoff {
+            Some(API_REBALANCE_HTTP_BACKOFF_SCOPE)
+        } else if armed_http_project_affinity_backoff {
+            Some(HTTP_PROJECT_AFFINITY_BACKOFF_SCOPE)
+        } else if armed_http_global_backoff {
+            Some(HTTP_GLOBAL_BACKOFF_SCOPE)
+        } else {
+            None
+        };
+        if let Some(scope) = armed_scope {
+            self.key_store
+                .set_api_key_transient_backoff_request_log_id(
+                    &lease.id,
+                    scope,
+                    request_log_id,
+                    self.backend_time.now_ts(),
+                )
+                .await?;
+        }
+        analysis.key_effect = primary_effect;
+
+        Ok((
+            ProxyResponse {
+                status,
+                headers: headers.clone(),
+                body: Bytes::new(),
+                api_key_id: Some(lease.id.clone()),
+                request_log_id: Some(request_log_id),
+                key_effect_code: key_effect.code,
+                key_effect_summary: key_effect.summary,
+                binding_effect_code: attempt.binding_effect.code.clone(),
+                binding_effect_summary: attempt.binding_effect.summary.clone(),
+                selection_effect_code: attempt.selection_effect.code.clone(),
+                selection_effect_summary: attempt.selection_effect.summary.clone(),
+            },
+            analysis,
+        ))
+    }
+
+    async fn log_http_research_transport_error(
+        &self,
+        attempt: &HttpResearchAttempt,
+        err: &ProxyError,
+    ) -> Result<(), ProxyError> {
+        log_proxy_error(
+            &attempt.lease.secret,
+            &attempt.method,
+            &attempt.display_path,
+            None,
+            err,
+        );
+        let redacted_empty: Vec<u8> = Vec::new();
+        self.key_store
+            .log_attempt(AttemptLog {
+                key_id: Some(&attempt.lease.id),
+                auth_token_id: attempt.auth_token_id.as_deref(),
+                method: &attempt.method,
+                path: &attempt.display_path,
+                query: None,
+                status: None,
+                tavily_status_code: None,
+                error: Some(&err.to_string()),
+                request_body: &attempt.redacted_request_body,
+                response_body: &redacted_empty,
+                outcome: OUTCOME_ERROR,
+                failure_kind: None,
+                key_effect_code: KEY_EFFECT_NONE,
+                key_effect_summary: None,
+                binding_effect_code: KEY_EFFECT_NONE,
+                binding_effect_summary: None,
+                selection_effect_code: KEY_EFFECT_NONE,
+                selection_effect_summary: None,
+                gateway_mode: None,
+                experiment_variant: None,
+                proxy_session_id: None,
+                routing_subject_hash: None,
+                upstream_operation: None,
+                fallback_reason: None,
+                forwarded_headers: &attempt.sanitized_headers.forwarded,
+                dropped_headers: &attempt.sanitized_headers.dropped,
+                client_ip: attempt.client_ip.as_ref(),
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Proxy Tavily `/research` with `stream=true`.
+    ///
+    /// Key selection, header sanitization, and research affinity match
+    /// [`TavilyProxy::proxy_http_research`]. A successful SSE response is handed back as a
+    /// [`HttpResearchStream`]; the request log row is written by
+    /// [`HttpResearchStream::finish`] once the stream closes.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn proxy_http_research_stream(
+        &self,
+        usage_base: &str,
+        auth_token_id: Option<&str>,
+        use_api_rebalance: bool,
+        api_routing_key: Option<&str>,
+        http_project_id: Option<&str>,
+        method: &Method,
+        display_path: &str,
+        options: Value,
+        original_headers: &HeaderMap,
+        inject_upstream_bearer_auth: bool,
+        client_ip: Option<&ClientIpInfo>,
+    ) -> Result<HttpResearchStreamStart, ProxyError> {
+        let (attempt, url, request_body) = self
+            .prepare_http_research_attempt(
+                usage_base,
+                auth_token_id,
+                use_api_rebalance,
+                api_routing_key,
+                http_project_id,
+                method,
+                display_path,
+                options,
+                original_headers,
+                client_ip,
+            )
+            .await?;
+        let response = self
+            .send_http_research_attempt(&attempt, &url, request_body, inject_upstream_bearer_auth)
+            .await;
+
+        match response {
+            Ok((response, relay_lease)) => {
+                let status = response.status();
+                if !status.is_success() || !research_response_is_event_stream(response.headers()) {
+                    let (response, analysis) =
+                        self.complete_http_research_buffered(&attempt, response).await?;
+                    return Ok(HttpResearchStreamStart::Buffered(Box::new(response), analysis));
+                }
+                let headers = response.headers().clone();
+                Ok(HttpResearchStreamStart::Streaming(Box::new(HttpResearchStream {
+                    status,
+                    headers,
+                    response,
+                    attempt,
+                    transcript: ResearchStreamTranscript::default(),
+                    affinity_recorded: false,
+                    transport_error: None,
+                    _relay_lease: relay_lease,
+                })))
+            }
+            Err(err) => {
+                self.log_http_research_transport_error(&attempt, &err).await?;
+                Err(err)
+            }
+        }
+    }
+}
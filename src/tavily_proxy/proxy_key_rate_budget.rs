@@ -0,0 +1,272 @@
+/// Upstream request kinds that spend a key's local rate budget. Quota sync and reconciliation
+/// probes stay outside so maintenance never competes with client traffic for headroom.
+const KEY_RATE_BUDGET_REQUEST_KINDS: &[&str] = &[
+    "mcp",
+    "search",
+    "extract",
+    "crawl",
+    "map",
+    "research",
+    "research_result",
+];
+const KEY_RATE_BUDGET_WINDOW: Duration = Duration::from_secs(60);
+const KEY_RATE_BUDGET_LIMITS_TTL: Duration = Duration::from_secs(30);
+
+type KeyRateBudgetLimitsCache = Option<(Instant, HashMap<String, i64>)>;
+
+/// One key's sliding one-minute window. `gate` is a FIFO admission lock: waiters queue on it in
+/// arrival order, so the request at the head of the queue is the next one admitted.
+#[derive(Debug, Default)]
+struct KeyRateBudgetLane {
+    gate: Mutex<()>,
+    admitted: StdMutex<std::collections::VecDeque<Instant>>,
+    queued: std::sync::atomic::AtomicI64,
+    delayed_total: std::sync::atomic::AtomicI64,
+    rejected_total: std::sync::atomic::AtomicI64,
+}
+
+impl KeyRateBudgetLane {
+    fn used_at(&self, now: Instant) -> i64 {
+        let mut admitted = self
+            .admitted
+            .lock()
+            .expect("key rate budget window poisoned");
+        while admitted
+            .front()
+            .is_some_and(|at| now.duration_since(*at) >= KEY_RATE_BUDGET_WINDOW)
+        {
+            admitted.pop_front();
+        }
+        admitted.len() as i64
+    }
+
+    /// Record an admission when the window has room, otherwise report when it frees up.
+    fn try_admit(&self, now: Instant, rpm_limit: i64) -> Result<(), Instant> {
+        let mut admitted = self
+            .admitted
+            .lock()
+            .expect("key rate budget window poisoned");
+        while admitted
+            .front()
+            .is_some_and(|at| now.duration_since(*at) >= KEY_RATE_BUDGET_WINDOW)
+        {
+            admitted.pop_front();
+        }
+        if (admitted.len() as i64) < rpm_limit {
+            admitted.push_back(now);
+            return Ok(());
+        }
+        Err(admitted
+            .front()
+            .map(|oldest| *oldest + KEY_RATE_BUDGET_WINDOW)
+            .unwrap_or(now))
+    }
+}
+
+/// In-process per-key requests-per-minute budgets, enforced before a request is forwarded.
+#[derive(Clone, Debug, Default)]
+struct KeyRateBudgets {
+    limits: Arc<Mutex<KeyRateBudgetLimitsCache>>,
+    lanes: Arc<StdMutex<HashMap<String, Arc<KeyRateBudgetLane>>>>,
+}
+
+impl KeyRateBudgets {
+    async fn limits(&self, store: &KeyStore) -> Result<HashMap<String, i64>, ProxyError> {
+        let mut cached = self.limits.lock().await;
+        if let Some((loaded_at, limits)) = cached.as_ref()
+            && loaded_at.elapsed() < KEY_RATE_BUDGET_LIMITS_TTL
+        {
+            return Ok(limits.clone());
+        }
+        let limits = store.fetch_api_key_rate_budget_limits().await?;
+        *cached = Some((Instant::now(), limits.clone()));
+        Ok(limits)
+    }
+
+    async fn invalidate_limits(&self) {
+        *self.limits.lock().await = None;
+    }
+
+    fn lane(&self, key_id: &str) -> Arc<KeyRateBudgetLane> {
+        let mut lanes = self.lanes.lock().expect("key rate budget lanes poisoned");
+        lanes.entry(key_id.to_string()).or_default().clone()
+    }
+
+    fn existing_lane(&self, key_id: &str) -> Option<Arc<KeyRateBudgetLane>> {
+        self.lanes
+            .lock()
+            .expect("key rate budget lanes poisoned")
+            .get(key_id)
+            .cloned()
+    }
+
+    /// Wait in FIFO order until `key_id` has headroom, or give up once `max_wait` has elapsed.
+    async fn admit(
+        &self,
+        key_id: &str,
+        rpm_limit: i64,
+        max_wait: Duration,
+    ) -> Result<(), ProxyError> {
+        let lane = self.lane(key_id);
+        let deadline = Instant::now() + max_wait;
+        lane.queued.fetch_add(1, AtomicOrdering::Relaxed);
+        let result = async {
+            let Ok(_gate) = tokio::time::timeout_at(deadline, lane.gate.lock()).await else {
+                return Err(deadline);
+            };
+            let mut delayed = false;
+            loop {
+                match lane.try_admit(Instant::now(), rpm_limit) {
+                    Ok(()) => {
+                        if delayed {
+                            lane.delayed_total.fetch_add(1, AtomicOrdering::Relaxed);
+                        }
+                        return Ok(());
+                    }
+                    Err(ready_at) if ready_at > deadline => return Err(ready_at),
+                    Err(ready_at) => {
+                        delayed = true;
+                        tokio::time::sleep_until(ready_at).await;
+                    }
+                }
+            }
+        }
+        .await;
+        lane.queued.fetch_sub(1, AtomicOrdering::Relaxed);
+        result.map_err(|ready_at| {
+            lane.rejected_total.fetch_add(1, AtomicOrdering::Relaxed);
+            ProxyError::KeyRateBudgetExhausted {
+                key_id: key_id.to_string(),
+                retry_after_ms: ready_at
+                    .saturating_duration_since(Instant::now())
+                    .as_millis() as i64,
+            }
+        })
+    }
+
+    fn usage(&self, limits: &HashMap<String, i64>, key_id: &str) -> ApiKeyRateBudgetUsage {
+        let rpm_limit = limits.get(key_id).copied().unwrap_or_default();
+        let Some(lane) = self.existing_lane(key_id) else {
+            return ApiKeyRateBudgetUsage {
+                key_id: key_id.to_string(),
+                rpm_limit,
+                ..ApiKeyRateBudgetUsage::default()
+            };
+        };
+        ApiKeyRateBudgetUsage {
+            key_id: key_id.to_string(),
+            rpm_limit,
+            used_last_minute: lane.used_at(Instant::now()),
+            queued: lane.queued.load(AtomicOrdering::Relaxed),
+            delayed_total: lane.delayed_total.load(AtomicOrdering::Relaxed),
+            rejected_total: lane.rejected_total.load(AtomicOrdering::Relaxed),
+        }
+    }
+}
+
+impl TavilyProxy {
+    /// Set or clear the local requests-per-minute budget of one key. Returns `false` when the key
+    /// does not exist.
+    pub async fn set_api_key_rate_budget(
+        &self,
+        key_id: &str,
+        rpm_limit: Option<i64>,
+    ) -> Result<bool, ProxyError> {
+        let updated = self
+            .key_store
+            .set_api_key_rate_budget(key_id, rpm_limit)
+            .await?;
+        self.key_rate_budgets.invalidate_limits().await;
+        Ok(updated)
+    }
+
+    /// Budget usage for every key with a configured budget, ordered by key id.
+    pub async fn api_key_rate_budget_usage(
+        &self,
+    ) -> Result<Vec<ApiKeyRateBudgetUsage>, ProxyError> {
+        let limits = self.key_rate_budgets.limits(&self.key_store).await?;
+        let mut key_ids = limits.keys().cloned().collect::<Vec<_>>();
+        key_ids.sort();
+        Ok(key_ids
+            .iter()
+            .map(|key_id| self.key_rate_budgets.usage(&limits, key_id))
+            .collect())
+    }
+
+    async fn api_key_rate_budget_usage_for(
+        &self,
+        key_id: &str,
+    ) -> Result<Vec<ApiKeyRateBudgetUsage>, ProxyError> {
+        let limits = self.key_rate_budgets.limits(&self.key_store).await?;
+        Ok(if limits.contains_key(key_id) {
+            vec![self.key_rate_budgets.usage(&limits, key_id)]
+        } else {
+            Vec::new()
+        })
+    }
+
+    /// Keys whose budget is fully spent right now, so selection can prefer keys with headroom.
+    async fn saturated_rate_budget_key_ids(&self) -> Result<Vec<String>, ProxyError> {
+        let limits = self.key_rate_budgets.limits(&self.key_store).await?;
+        let now = Instant::now();
+        Ok(limits
+            .iter()
+            .filter(|(key_id, rpm_limit)| {
+                self.key_rate_budgets
+                    .existing_lane(key_id)
+                    .is_some_and(|lane| lane.used_at(now) >= **rpm_limit)
+            })
+            .map(|(key_id, _)| key_id.clone())
+            .collect())
+    }
+
+    /// Spend one unit of `api_key_id`'s budget before forwarding a client request upstream.
+    async fn admit_key_rate_budget(
+        &self,
+        api_key_id: &str,
+        request_kind: &str,
+    ) -> Result<(), ProxyError> {
+        if !KEY_RATE_BUDGET_REQUEST_KINDS.contains(&request_kind) {
+            return Ok(());
+        }
+        let Some(rpm_limit) = self
+            .key_rate_budgets
+            .limits(&self.key_store)
+            .await?
+            .get(api_key_id)
+            .copied()
+        else {
+            return Ok(());
+        };
+        self.key_rate_budgets
+            .admit(
+                api_key_id,
+                rpm_limit,
+                Duration::from_millis(API_KEY_RATE_BUDGET_MAX_WAIT_MS),
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+impl TavilyProxy {
+    pub(crate) async fn debug_admit_key_rate_budget(
+        &self,
+        key_id: &str,
+        rpm_limit: i64,
+        max_wait: Duration,
+    ) -> Result<(), ProxyError> {
+        self.key_rate_budgets
+            .admit(key_id, rpm_limit, max_wait)
+            .await
+    }
+
+    pub(crate) fn debug_key_rate_budget_usage(
+        &self,
+        key_id: &str,
+        rpm_limit: i64,
+    ) -> ApiKeyRateBudgetUsage {
+        let limits = HashMap::from([(key_id.to_string(), rpm_limit)]);
+        self.key_rate_budgets.usage(&limits, key_id)
+    }
+}
@@ -0,0 +1,494 @@
+/// Scheduled job outcomes are reported over this trailing window.
+const METRICS_SCHEDULED_JOB_WINDOW_SECS: i64 = 24 * 60 * 60;
+
+/// `(metric name, help text, value extractor)` for one per-operation SQLite counter family.
+type SqliteOperationFamily = (&'static str, &'static str, fn(&SqliteOperationTotals) -> f64);
+
+/// Minimal writer for the Prometheus text exposition format (version 0.0.4).
+#[derive(Default)]
+struct PrometheusText {
+    out: String,
+}
+
+impl PrometheusText {
+    fn family(&mut self, name: &str, kind: &str, help: &str) {
+        self.out
+            .push_str(&format!("# HELP {name} {help}\n# TYPE {name} {kind}\n"));
+    }
+
+    fn sample(&mut self, name: &str, labels: &[(&str, &str)], value: impl std::fmt::Display) {
+        self.out.push_str(name);
+        if !labels.is_empty() {
+            self.out.push('{');
+            for (index, (label, label_value)) in labels.iter().enumerate() {
+                if index > 0 {
+                    self.out.push(',');
+                }
+                self.out.push_str(label);
+                self.out.push_str("=\"");
+                for ch in label_value.chars() {
+                    match ch {
+                        '\\' => self.out.push_str("\\\\"),
+                        '"' => self.out.push_str("\\\""),
+                        '\n' => self.out.push_str("\\n"),
+                        _ => self.out.push(ch),
+                    }
+                }
+                self.out.push('"');
+            }
+            self.out.push('}');
+        }
+        self.out.push_str(&format!(" {value}\n"));
+    }
+}
+
+impl TavilyProxy {
+    /// Whether `candidate` is the configured Prometheus scrape token. Always false when no
+    /// token is configured.
+    pub fn metrics_bearer_token_matches(&self, candidate: Option<&str>) -> bool {
+        match (self.metrics_bearer_token.as_deref(), candidate) {
+            (Some(expected), Some(candidate)) => expected == candidate.trim(),
+            _ => false,
+        }
+    }
+
+    /// Render the Prometheus `/metrics` exposition for this node.
+    pub async fn render_prometheus_metrics(&self, ha: &HaRuntime) -> Result<String, ProxyError> {
+        let mut text = PrometheusText::default();
+        self.write_request_metrics(&mut text);
+        self.write_api_key_metrics(&mut text).await?;
+        self.write_forward_proxy_metrics(&mut text).await;
+        self.write_sqlite_metrics(&mut text);
+        self.write_ha_metrics(&mut text, ha).await?;
+        self.write_scheduled_job_metrics(&mut text).await?;
+        Ok(text.out)
+    }
+
+    fn write_request_metrics(&self, text: &mut PrometheusText) {
+        let metrics = &self.key_store.process_metrics;
+        text.family(
+            "tavily_hikari_requests_total",
+            "counter",
+            "Logged requests by request kind and outcome.",
+        );
+        for ((request_kind, outcome), count) in metrics.requests_snapshot() {
+            text.sample(
+                "tavily_hikari_requests_total",
+                &[("request_kind", &request_kind), ("outcome", &outcome)],
+                count,
+            );
+        }
+
+        text.family(
+            "tavily_hikari_upstream_request_duration_seconds",
+            "histogram",
+            "Time until the upstream answered, including forward-proxy failover.",
+        );
+        for ((operation, result), histogram) in metrics.upstream_latency_snapshot() {
+            let labels = [("operation", operation.as_str()), ("result", result)];
+            let mut cumulative = 0;
+            for (upper, count) in UPSTREAM_LATENCY_BUCKETS_SECS.iter().zip(histogram.buckets) {
+                cumulative += count;
+                let le = upper.to_string();
+                text.sample(
+                    "tavily_hikari_upstream_request_duration_seconds_bucket",
+                    &[labels[0], labels[1], ("le", &le)],
+                    cumulative,
+                );
+            }
+            text.sample(
+                "tavily_hikari_upstream_request_duration_seconds_bucket",
+                &[labels[0], labels[1], ("le", "+Inf")],
+                histogram.count,
+            );
+            text.sample(
+                "tavily_hikari_upstream_request_duration_seconds_sum",
+                &labels,
+                histogram.sum_secs,
+            );
+            text.sample(
+                "tavily_hikari_upstream_request_duration_seconds_count",
+                &labels,
+                histogram.count,
+            );
+        }
+    }
+
+    async fn write_api_key_metrics(&self, text: &mut PrometheusText) -> Result<(), ProxyError> {
+        let keys = self.key_store.metrics_api_key_rows().await?;
+        text.family(
+            "tavily_hikari_api_key_status",
+            "gauge",
+            "Current status of each upstream API key; quarantined keys report `quarantined`.",
+        );
+        for key in &keys {
+            let status = if key.quarantined {
+                "quarantined"
+            } else {
+                key.status.as_str()
+            };
+            text.sample(
+                "tavily_hikari_api_key_status",
+                &[
+                    ("key_id", &key.id),
+                    ("group", key.group_name.as_deref().unwrap_or_default()),
+                    ("status", status),
+                ],
+                1,
+            );
+        }
+        text.family(
+            "tavily_hikari_api_key_quota_remaining",
+            "gauge",
+            "Remaining upstream credits of each API key at its last quota sync.",
+        );
+        for key in &keys {
+            if let Some(remaining) = key.quota_remaining {
+                text.sample(
+                    "tavily_hikari_api_key_quota_remaining",
+                    &[
+                        ("key_id", &key.id),
+                        ("group", key.group_name.as_deref().unwrap_or_default()),
+                    ],
+                    remaining,
+                );
+            }
+        }
+        text.family(
+            "tavily_hikari_api_key_quota_limit",
+            "gauge",
+            "Upstream credit limit of each API key at its last quota sync.",
+        );
+        for key in &keys {
+            if let Some(limit) = key.quota_limit {
+                text.sample(
+                    "tavily_hikari_api_key_quota_limit",
+                    &[
+                        ("key_id", &key.id),
+                        ("group", key.group_name.as_deref().unwrap_or_default()),
+                    ],
+                    limit,
+                );
+            }
+        }
+
+        text.family(
+            "tavily_hikari_api_key_quarantines",
+            "gauge",
+            "Active API key quarantines by reason.",
+        );
+        for (reason_code, count) in self.key_store.metrics_quarantine_counts().await? {
+            text.sample(
+                "tavily_hikari_api_key_quarantines",
+                &[("reason_code", &reason_code)],
+                count,
+            );
+        }
+        Ok(())
+    }
+
+    async fn write_forward_proxy_metrics(&self, text: &mut PrometheusText) {
+        let (nodes, disabled_keys) = {
+            let manager = self.forward_proxy.lock().await;
+            (manager.snapshot_runtime(), manager.disabled_keys())
+        };
+        // Node keys can embed proxy credentials, so nodes are identified by a short digest.
+        let node_labels = |node: &forward_proxy::ForwardProxyRuntimeState| {
+            let digest = Sha256::digest(node.proxy_key.as_bytes());
+            let node_key: String = digest[..6].iter().map(|byte| format!("{byte:02x}")).collect();
+            (node.display_name.clone(), node_key, node.source.clone())
+        };
+        text.family(
+            "tavily_hikari_forward_proxy_node_available",
+            "gauge",
+            "Whether a forward-proxy node is enabled, reachable and not penalized.",
+        );
+        for node in &nodes {
+            let (name, node_key, source) = node_labels(node);
+            let available = node.available
+                && !node.is_penalized()
+                && !disabled_keys.contains(&node.proxy_key);
+            text.sample(
+                "tavily_hikari_forward_proxy_node_available",
+                &[("node", &name), ("node_key", &node_key), ("source", &source)],
+                u8::from(available),
+            );
+        }
+        text.family(
+            "tavily_hikari_forward_proxy_node_consecutive_failures",
+            "gauge",
+            "Consecutive failed attempts through a forward-proxy node.",
+        );
+        for node in &nodes {
+            let (name, node_key, source) = node_labels(node);
+            text.sample(
+                "tavily_hikari_forward_proxy_node_consecutive_failures",
+                &[("node", &name), ("node_key", &node_key), ("source", &source)],
+                node.consecutive_failures,
+            );
+        }
+        text.family(
+            "tavily_hikari_forward_proxy_node_success_ratio",
+            "gauge",
+            "Moving average of the success ratio through a forward-proxy node.",
+        );
+        for node in &nodes {
+            let (name, node_key, source) = node_labels(node);
+            text.sample(
+                "tavily_hikari_forward_proxy_node_success_ratio",
+                &[("node", &name), ("node_key", &node_key), ("source", &source)],
+                node.success_ema,
+            );
+        }
+        text.family(
+            "tavily_hikari_forward_proxy_node_latency_seconds",
+            "gauge",
+            "Moving average of the latency through a forward-proxy node.",
+        );
+        for node in &nodes {
+            if let Some(latency_ms) = node.latency_ema_ms {
+                let (name, node_key, source) = node_labels(node);
+                text.sample(
+                    "tavily_hikari_forward_proxy_node_latency_seconds",
+                    &[("node", &name), ("node_key", &node_key), ("source", &source)],
+                    latency_ms / 1000.0,
+                );
+            }
+        }
+    }
+
+    fn write_sqlite_metrics(&self, text: &mut PrometheusText) {
+        let sqlite = self.key_store.sqlite_runtime.metrics_snapshot();
+        text.family(
+            "tavily_hikari_sqlite_pool_connections",
+            "gauge",
+            "Open SQLite pool connections by state.",
+        );
+        text.sample(
+            "tavily_hikari_sqlite_pool_connections",
+            &[("state", "idle")],
+            sqlite.idle_connections,
+        );
+        text.sample(
+            "tavily_hikari_sqlite_pool_connections",
+            &[("state", "in_use")],
+            sqlite.pool_size.saturating_sub(sqlite.idle_connections),
+        );
+        text.family(
+            "tavily_hikari_sqlite_pool_max_connections",
+            "gauge",
+            "Configured SQLite pool size.",
+        );
+        text.sample(
+            "tavily_hikari_sqlite_pool_max_connections",
+            &[],
+            sqlite.maximum_connections,
+        );
+        text.family(
+            "tavily_hikari_sqlite_pool_acquire_waiters",
+            "gauge",
+            "Operations currently waiting for a SQLite pool connection.",
+        );
+        text.sample(
+            "tavily_hikari_sqlite_pool_acquire_waiters",
+            &[],
+            sqlite.acquire_waiters,
+        );
+        text.family(
+            "tavily_hikari_sqlite_writer_contention_active",
+            "gauge",
+            "Whether SQLite writer contention was seen within the maintenance cooldown.",
+        );
+        text.sample(
+            "tavily_hikari_sqlite_writer_contention_active",
+            &[],
+            u8::from(sqlite.contention_active),
+        );
+        text.family(
+            "tavily_hikari_sqlite_writer_contention_total",
+            "counter",
+            "SQLite operations that failed on a busy or locked writer.",
+        );
+        text.sample(
+            "tavily_hikari_sqlite_writer_contention_total",
+            &[],
+            sqlite.contention_events,
+        );
+
+        let families: [SqliteOperationFamily; 5] = [
+            (
+                "tavily_hikari_sqlite_operations_total",
+                "Managed SQLite operations by operation.",
+                |totals| totals.calls as f64,
+            ),
+            (
+                "tavily_hikari_sqlite_operation_errors_total",
+                "Managed SQLite operations that failed.",
+                |totals| totals.errors as f64,
+            ),
+            (
+                "tavily_hikari_sqlite_operation_deferred_total",
+                "Managed SQLite operations deferred by admission control.",
+                |totals| totals.deferred as f64,
+            ),
+            (
+                "tavily_hikari_sqlite_operation_pool_wait_seconds_total",
+                "Time managed SQLite operations waited for a pool connection.",
+                |totals| totals.pool_wait_ms as f64 / 1000.0,
+            ),
+            (
+                "tavily_hikari_sqlite_operation_hold_seconds_total",
+                "Time managed SQLite operations held their transaction open.",
+                |totals| totals.hold_ms as f64 / 1000.0,
+            ),
+        ];
+        for (name, help, value) in families {
+            text.family(name, "counter", help);
+            for (operation, workload_class, totals) in &sqlite.operations {
+                text.sample(
+                    name,
+                    &[("operation", operation), ("workload_class", workload_class)],
+                    value(totals),
+                );
+            }
+        }
+    }
+
+    async fn write_ha_metrics(
+        &self,
+        text: &mut PrometheusText,
+        ha: &HaRuntime,
+    ) -> Result<(), ProxyError> {
+        let status = ha.status().await;
+        text.family(
+            "tavily_hikari_ha_role",
+            "gauge",
+            "HA role of this node: 1 for the current role, 0 otherwise.",
+        );
+        for role in [
+            HaNodeRole::FullMaster,
+            HaNodeRole::ProvisionalMaster,
+            HaNodeRole::Standby,
+            HaNodeRole::Recovery,
+        ] {
+            text.sample(
+                "tavily_hikari_ha_role",
+                &[
+                    ("mode", status.mode.as_str()),
+                    ("node_id", &status.node_id),
+                    ("role", role.as_str()),
+                ],
+                u8::from(role == status.role),
+            );
+        }
+        text.family(
+            "tavily_hikari_ha_sync_lag_seconds",
+            "gauge",
+            "Seconds since this node last synced from its peer.",
+        );
+        if let Some(lag) = status.sync_lag_seconds {
+            text.sample("tavily_hikari_ha_sync_lag_seconds", &[], lag);
+        }
+
+        let channels = [
+            HaSyncChannel::Control,
+            HaSyncChannel::Billing,
+            HaSyncChannel::Runtime,
+        ];
+        let peers: Vec<String> = ha
+            .peer_nodes()
+            .into_iter()
+            .map(|peer| peer.node_id)
+            .filter(|node_id| node_id != ha.node_id())
+            .collect();
+        let mut outbox = Vec::with_capacity(channels.len());
+        for channel in channels {
+            let mut ack_lags = Vec::with_capacity(peers.len());
+            for peer in &peers {
+                let stats = self.ha_channel_outbox_stats(channel, Some(peer)).await?;
+                ack_lags.push((peer.as_str(), stats.ack_lag));
+            }
+            outbox.push((
+                channel,
+                self.ha_channel_outbox_stats(channel, None).await?,
+                ack_lags,
+            ));
+        }
+        text.family(
+            "tavily_hikari_ha_outbox_events",
+            "gauge",
+            "Estimated events retained in the HA outbox of each sync channel.",
+        );
+        for (channel, stats, _) in &outbox {
+            text.sample(
+                "tavily_hikari_ha_outbox_events",
+                &[("channel", channel.as_str())],
+                stats.sequence_span_estimate,
+            );
+        }
+        text.family(
+            "tavily_hikari_ha_outbox_oldest_event_age_seconds",
+            "gauge",
+            "Age of the oldest event retained in the HA outbox of each sync channel.",
+        );
+        for (channel, stats, _) in &outbox {
+            text.sample(
+                "tavily_hikari_ha_outbox_oldest_event_age_seconds",
+                &[("channel", channel.as_str())],
+                stats.oldest_age_secs,
+            );
+        }
+        text.family(
+            "tavily_hikari_ha_outbox_ack_lag_events",
+            "gauge",
+            "HA outbox events each peer has not acknowledged yet.",
+        );
+        for (channel, _, ack_lags) in &outbox {
+            for (peer, ack_lag) in ack_lags {
+                if let Some(ack_lag) = ack_lag {
+                    text.sample(
+                        "tavily_hikari_ha_outbox_ack_lag_events",
+                        &[("channel", channel.as_str()), ("peer_node_id", peer)],
+                        ack_lag,
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn write_scheduled_job_metrics(
+        &self,
+        text: &mut PrometheusText,
+    ) -> Result<(), ProxyError> {
+        let since = self.backend_time.now_ts() - METRICS_SCHEDULED_JOB_WINDOW_SECS;
+        let jobs = self.key_store.metrics_scheduled_job_rows(since).await?;
+        text.family(
+            "tavily_hikari_scheduled_job_runs",
+            "gauge",
+            "Scheduled job runs finished in the last 24 hours by job type and status.",
+        );
+        for job in &jobs {
+            text.sample(
+                "tavily_hikari_scheduled_job_runs",
+                &[("job_type", &job.job_type), ("status", &job.status)],
+                job.runs,
+            );
+        }
+        text.family(
+            "tavily_hikari_scheduled_job_last_finished_timestamp_seconds",
+            "gauge",
+            "Unix time a job type last finished with each status in the last 24 hours.",
+        );
+        for job in &jobs {
+            if let Some(finished_at) = job.last_finished_at {
+                text.sample(
+                    "tavily_hikari_scheduled_job_last_finished_timestamp_seconds",
+                    &[("job_type", &job.job_type), ("status", &job.status)],
+                    finished_at,
+                );
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,219 @@
+/// Upstream answer of a coalesced call, handed to every request that waited on it.
+#[derive(Debug, Clone)]
+pub struct SharedUpstreamResponse {
+    pub status: StatusCode,
+    pub content_type: Option<String>,
+    pub body: Bytes,
+    /// Credits the upstream call cost.
+    pub credits: i64,
+}
+
+type RequestFlightReceiver = tokio::sync::watch::Receiver<Option<SharedUpstreamResponse>>;
+
+/// Calls currently upstream, keyed by `request_coalescing_key`. Node-local and in-memory:
+/// waiting only makes sense within one process.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct RequestFlights {
+    // A std mutex so the leader can deregister from `Drop`; it is never held across an await.
+    inflight: Arc<std::sync::Mutex<HashMap<String, RequestFlightReceiver>>>,
+}
+
+/// Outcome of joining the in-flight registry for one call.
+pub enum RequestFlight {
+    /// No identical call is upstream: this request goes upstream and publishes its answer.
+    Leader(RequestFlightLeader),
+    /// An identical call is already upstream: wait for its answer instead.
+    Follower(RequestFlightFollower),
+}
+
+pub struct RequestFlightLeader {
+    key: String,
+    sender: tokio::sync::watch::Sender<Option<SharedUpstreamResponse>>,
+    inflight: Arc<std::sync::Mutex<HashMap<String, RequestFlightReceiver>>>,
+}
+
+impl RequestFlightLeader {
+    /// Hand a successful upstream answer to every waiting request. Dropping the leader without
+    /// publishing sends the waiters upstream on their own.
+    pub fn publish(self, response: SharedUpstreamResponse) {
+        self.sender.send_replace(Some(response));
+    }
+}
+
+impl Drop for RequestFlightLeader {
+    fn drop(&mut self) {
+        // Only the leader registers its key, so the entry is always this flight's.
+        if let Ok(mut inflight) = self.inflight.lock() {
+            inflight.remove(&self.key);
+        }
+    }
+}
+
+pub struct RequestFlightFollower {
+    receiver: RequestFlightReceiver,
+}
+
+impl RequestFlightFollower {
+    /// The leader's answer, or `None` when it failed and this request should go upstream itself.
+    pub async fn wait(mut self) -> Option<SharedUpstreamResponse> {
+        self.receiver
+            .wait_for(Option::is_some)
+            .await
+            .ok()
+            .and_then(|shared| shared.clone())
+    }
+}
+
+impl RequestFlights {
+    fn join(&self, key: &str) -> RequestFlight {
+        let mut inflight = self
+            .inflight
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(receiver) = inflight.get(key) {
+            return RequestFlight::Follower(RequestFlightFollower {
+                receiver: receiver.clone(),
+            });
+        }
+        let (sender, receiver) = tokio::sync::watch::channel(None);
+        inflight.insert(key.to_string(), receiver);
+        RequestFlight::Leader(RequestFlightLeader {
+            key: key.to_string(),
+            sender,
+            inflight: self.inflight.clone(),
+        })
+    }
+}
+
+impl TavilyProxy {
+    pub async fn request_coalescing_settings(
+        &self,
+    ) -> Result<RequestCoalescingSettings, ProxyError> {
+        self.key_store.request_coalescing_settings().await
+    }
+
+    /// Become the leader for `key`, or follow the identical call that is already upstream.
+    pub fn join_request_flight(&self, key: &str) -> RequestFlight {
+        self.request_flights.join(key)
+    }
+
+    /// Answer an HTTP API call with the response its flight leader received.
+    ///
+    /// The waiter gets its own request log with the `request_coalesced` key effect. Returns the
+    /// response, its analysis and the credits to bill under the sharing policy.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn serve_coalesced_http_response(
+        &self,
+        settings: &RequestCoalescingSettings,
+        shared: SharedUpstreamResponse,
+        auth_token_id: Option<&str>,
+        method: &Method,
+        display_path: &str,
+        options: &Value,
+        client_ip: Option<&ClientIpInfo>,
+    ) -> Result<(ProxyResponse, AttemptAnalysis, i64), ProxyError> {
+        let billed_credits = settings.follower_credits(shared.credits);
+        let (response, analysis) = self
+            .answer_http_locally(
+                Self::request_coalesced_effect(billed_credits, shared.credits),
+                shared.status,
+                shared.content_type.as_deref(),
+                shared.body,
+                auth_token_id,
+                method,
+                display_path,
+                options,
+                client_ip,
+            )
+            .await?;
+        Ok((response, analysis, billed_credits))
+    }
+
+    /// Answer a rebalance MCP tool call with the response its flight leader received. The
+    /// tool result reports the billed credits as its usage, which is what the MCP handler charges.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) async fn serve_coalesced_rebalance_mcp_response(
+        &self,
+        settings: &RequestCoalescingSettings,
+        shared: SharedUpstreamResponse,
+        auth_token_id: Option<&str>,
+        method: &Method,
+        display_path: &str,
+        original_request_body: &[u8],
+        response_id: Option<&Value>,
+        proxy_session_id: Option<&str>,
+        routing_subject_hash: Option<&str>,
+        upstream_operation: &str,
+        client_ip: Option<&ClientIpInfo>,
+    ) -> Result<ProxyResponse, ProxyError> {
+        let billed_credits = settings.follower_credits(shared.credits);
+        let key_effect = Self::request_coalesced_effect(billed_credits, shared.credits);
+        let response_body = Self::build_rebalance_mcp_tool_result_body(
+            response_id,
+            shared.status,
+            &shared.body,
+            Some(billed_credits),
+        );
+        let mcp_analysis = analyze_mcp_attempt(StatusCode::OK, &response_body);
+        let request_log_id = self
+            .key_store
+            .log_attempt(AttemptLog {
+                key_id: None,
+                auth_token_id,
+                method,
+                path: display_path,
+                query: None,
+                status: Some(StatusCode::OK),
+                tavily_status_code: mcp_analysis.tavily_status_code,
+                error: None,
+                request_body: original_request_body,
+                response_body: &response_body,
+                outcome: mcp_analysis.status,
+                failure_kind: mcp_analysis.failure_kind.as_deref(),
+                key_effect_code: key_effect.code.as_str(),
+                key_effect_summary: key_effect.summary.as_deref(),
+                binding_effect_code: KEY_EFFECT_NONE,
+                binding_effect_summary: None,
+                selection_effect_code: KEY_EFFECT_NONE,
+                selection_effect_summary: None,
+                gateway_mode: Some(MCP_GATEWAY_MODE_REBALANCE),
+                experiment_variant: Some(MCP_EXPERIMENT_VARIANT_REBALANCE),
+                proxy_session_id,
+                routing_subject_hash,
+                upstream_operation: Some(upstream_operation),
+                fallback_reason: None,
+                forwarded_headers: &[],
+                dropped_headers: &[],
+                client_ip,
+            })
+            .await?;
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            reqwest::header::CONTENT_TYPE,
+            HeaderValue::from_static("text/event-stream"),
+        );
+        Ok(ProxyResponse {
+            status: StatusCode::OK,
+            headers,
+            body: Self::wrap_rebalance_mcp_sse_message_body(&response_body),
+            api_key_id: None,
+            request_log_id: Some(request_log_id),
+            key_effect_code: key_effect.code,
+            key_effect_summary: key_effect.summary,
+            binding_effect_code: KEY_EFFECT_NONE.to_string(),
+            binding_effect_summary: None,
+            selection_effect_code: KEY_EFFECT_NONE.to_string(),
+            selection_effect_summary: None,
+        })
+    }
+
+    fn request_coalesced_effect(billed_credits: i64, credits: i64) -> KeyEffect {
+        KeyEffect::new(
+            KEY_EFFECT_REQUEST_COALESCED,
+            format!(
+                "Shared the response of an identical in-flight request; {billed_credits} of {credits} upstream credits billed"
+            ),
+        )
+    }
+}
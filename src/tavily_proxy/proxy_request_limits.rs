@@ -614,7 +614,10 @@ impl TavilyProxy {
         &self,
         token_id: &str,
     ) -> Result<TokenHourlyRequestVerdict, ProxyError> {
-        self.token_request_limit.check(token_id).await
+        self.token_request_limit
+            .check(token_id)
+            .instrument(tracing::info_span!("quota_check", limiter = "hourly_requests"))
+            .await
     }
 
     /// Read-only snapshot of hourly raw request usage for a set of tokens.
@@ -704,6 +707,7 @@ impl TavilyProxy {
         selection_effect_code: Option<&str>,
         key_id: Option<&str>,
         operational_class: Option<&str>,
+        search: Option<&str>,
         cursor: Option<&RequestLogsCursor>,
         direction: RequestLogsCursorDirection,
     ) -> Result<TokenLogsCursorPage, ProxyError> {
@@ -720,6 +724,7 @@ impl TavilyProxy {
                 selection_effect_code,
                 key_id,
                 operational_class,
+                search,
                 cursor,
                 direction,
             )
@@ -1020,11 +1025,23 @@ impl TavilyProxy {
 
     /// 获取整体运行情况汇总。
     pub async fn summary(&self) -> Result<ProxySummary, ProxyError> {
-        self.key_store.fetch_summary().await
+        let mut summary = self.key_store.fetch_summary().await?;
+        summary.key_rate_budgets = self.api_key_rate_budget_usage().await?;
+        summary.response_cache = self
+            .key_store
+            .response_cache_stats(start_of_local_day_utc_ts(self.backend_time.local_now()))
+            .await?;
+        Ok(summary)
     }
 
     pub async fn summary_without_flush(&self) -> Result<ProxySummary, ProxyError> {
-        self.key_store.fetch_summary_without_flush().await
+        let mut summary = self.key_store.fetch_summary_without_flush().await?;
+        summary.key_rate_budgets = self.api_key_rate_budget_usage().await?;
+        summary.response_cache = self
+            .key_store
+            .response_cache_stats(start_of_local_day_utc_ts(self.backend_time.local_now()))
+            .await?;
+        Ok(summary)
     }
 
     /// Admin dashboard period summary windows based on server-local day/month boundaries.
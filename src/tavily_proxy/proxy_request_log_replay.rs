@@ -0,0 +1,156 @@
+const REQUEST_LOG_REPLAY_TIMEOUT_SECS: u64 = 120;
+
+impl TavilyProxy {
+    /// Alternate upstream bases admins may replay against besides the primary Tavily base.
+    pub fn request_log_replay_upstream_bases(&self) -> &[String] {
+        &self.replay_upstream_bases
+    }
+
+    /// Re-sends a logged Tavily HTTP API request with the original or an overridden body and
+    /// records the outcome as a diagnostic replay. Replays bypass billing, quota accounting and
+    /// `request_logs`; requests to `primary_base` still go through the key's forward proxy while
+    /// configured alternate bases are called directly. Returns `None` when the log is unknown.
+    pub async fn replay_request_log(
+        &self,
+        log_id: i64,
+        input: RequestLogReplayInput,
+        primary_base: &str,
+    ) -> Result<Option<RequestLogReplay>, ProxyError> {
+        let Some(source) = self.key_store.fetch_request_log_replay_source(log_id).await? else {
+            return Ok(None);
+        };
+        let upstream_path = request_log_replay_upstream_path(&source.path).ok_or_else(|| {
+            ProxyError::Other(format!(
+                "only Tavily HTTP API logs can be replayed, not {}",
+                source.path
+            ))
+        })?;
+        let (upstream_base, alternate) = match input.upstream_base.as_deref() {
+            None => (primary_base.trim_end_matches('/').to_string(), false),
+            Some(raw) => {
+                let base = normalize_request_log_replay_base(raw).map_err(ProxyError::Other)?;
+                if base == primary_base.trim_end_matches('/') {
+                    (base, false)
+                } else if self.replay_upstream_bases.contains(&base) {
+                    (base, true)
+                } else {
+                    return Err(ProxyError::Other(format!(
+                        "replay upstream {base} is not configured"
+                    )));
+                }
+            }
+        };
+        let api_key_id = input
+            .api_key_id
+            .or_else(|| source.api_key_id.clone())
+            .ok_or_else(|| ProxyError::Other("choose a key to replay this request".to_string()))?;
+        let secret = self
+            .key_store
+            .fetch_api_key_secret(&api_key_id)
+            .await?
+            .ok_or_else(|| ProxyError::Other(format!("unknown API key {api_key_id}")))?;
+        let method = Method::from_bytes(source.method.as_bytes())
+            .map_err(|_| ProxyError::Other(format!("unsupported method {}", source.method)))?;
+
+        let body = match input.body {
+            Some(body) => Some(body),
+            None => source
+                .request_body
+                .as_deref()
+                .filter(|body| !body.is_empty())
+                .map(serde_json::from_slice::<Value>)
+                .transpose()
+                .map_err(|err| {
+                    ProxyError::Other(format!("logged request body is not JSON: {err}"))
+                })?,
+        };
+        let body = match body {
+            Some(Value::Object(mut map)) => {
+                map.retain(|key, _| !key.eq_ignore_ascii_case("api_key"));
+                map.insert("api_key".to_string(), Value::String(secret.clone()));
+                Some(Value::Object(map))
+            }
+            Some(_) => {
+                return Err(ProxyError::Other(
+                    "replay body must be a JSON object".to_string(),
+                ));
+            }
+            None if method == Method::GET => None,
+            None => {
+                return Err(ProxyError::Other(
+                    "the logged request body is no longer retained; provide a body".to_string(),
+                ));
+            }
+        };
+        let request_body = body
+            .as_ref()
+            .map(serde_json::to_vec)
+            .transpose()
+            .map_err(|err| ProxyError::Other(err.to_string()))?;
+
+        let base = Url::parse(&upstream_base).map_err(|source| ProxyError::InvalidEndpoint {
+            endpoint: upstream_base.clone(),
+            source,
+        })?;
+        let mut url = build_path_prefixed_url(&base, upstream_path);
+        url.set_query(source.query.as_deref());
+        let build = |client: Client| {
+            let mut builder = client
+                .request(method.clone(), url.clone())
+                .header("Authorization", format!("Bearer {secret}"))
+                .timeout(Duration::from_secs(REQUEST_LOG_REPLAY_TIMEOUT_SECS));
+            if let Some(body) = request_body.as_ref() {
+                builder = builder
+                    .header(reqwest::header::CONTENT_TYPE, "application/json")
+                    .body(body.clone());
+            }
+            builder
+        };
+        let started = Instant::now();
+        let response = if alternate {
+            build(self.client.clone())
+                .send()
+                .await
+                .map_err(ProxyError::Http)
+        } else {
+            self.send_with_forward_proxy(&api_key_id, upstream_path.trim_start_matches('/'), build)
+                .await
+                .map(|(response, _relay_lease)| response)
+        };
+        let (status_code, response_body, error_message) = match response {
+            Ok(response) => {
+                let status = response.status().as_u16() as i64;
+                match response.bytes().await {
+                    Ok(bytes) => (Some(status), Some(redact_api_key_bytes(&bytes)), None),
+                    Err(err) => (Some(status), None, Some(err.to_string())),
+                }
+            }
+            Err(err) => (None, None, Some(err.to_string())),
+        };
+        let replay = RequestLogReplay {
+            id: 0,
+            source_log_id: source.log_id,
+            api_key_id,
+            upstream_base,
+            method: source.method,
+            path: source.path,
+            status_code,
+            duration_ms: started.elapsed().as_millis() as i64,
+            request_body: request_body.as_deref().map(redact_api_key_bytes),
+            response_body,
+            error_message,
+            created_at: self.backend_time.now_ts(),
+        };
+        self.key_store
+            .insert_request_log_replay(&replay)
+            .await
+            .map(Some)
+    }
+
+    pub async fn request_log_replays(
+        &self,
+        log_id: i64,
+    ) -> Result<Vec<RequestLogReplay>, ProxyError> {
+        self.key_store.list_request_log_replays(log_id).await
+    }
+}
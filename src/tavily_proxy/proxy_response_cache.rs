@@ -0,0 +1,165 @@
+impl TavilyProxy {
+    pub async fn response_cache_settings(&self) -> Result<ResponseCacheSettings, ProxyError> {
+        self.key_store.response_cache_settings().await
+    }
+
+    /// Answer an HTTP API call from the response cache without selecting an upstream key.
+    ///
+    /// A hit is logged as its own request with the `response_cache_hit` key effect. Returns the
+    /// response, its analysis and the credits to bill for the hit, or `None` on a miss.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn serve_cached_http_response(
+        &self,
+        settings: &ResponseCacheSettings,
+        cache_key: &str,
+        auth_token_id: Option<&str>,
+        method: &Method,
+        display_path: &str,
+        options: &Value,
+        client_ip: Option<&ClientIpInfo>,
+    ) -> Result<Option<(ProxyResponse, AttemptAnalysis, i64)>, ProxyError> {
+        let now = self.backend_time.now_ts();
+        let Some(cached) = self.key_store.take_response_cache_hit(cache_key, now).await? else {
+            return Ok(None);
+        };
+        let status = StatusCode::from_u16(cached.status as u16).unwrap_or(StatusCode::OK);
+        let billed_credits = settings.hit_credits(cached.credits);
+        self.key_store
+            .record_response_cache_hit_stats(
+                start_of_local_day_utc_ts(self.backend_time.local_now()),
+                cached.credits.saturating_sub(billed_credits),
+            )
+            .await?;
+
+        let key_effect = KeyEffect::new(
+            KEY_EFFECT_RESPONSE_CACHE_HIT,
+            format!(
+                "Served from the response cache; {} of {} upstream credits billed",
+                billed_credits, cached.credits
+            ),
+        );
+        let (response, analysis) = self
+            .answer_http_locally(
+                key_effect,
+                status,
+                cached.content_type.as_deref(),
+                Bytes::from(cached.body),
+                auth_token_id,
+                method,
+                display_path,
+                options,
+                client_ip,
+            )
+            .await?;
+        Ok(Some((response, analysis, billed_credits)))
+    }
+
+    /// Log and build the response for an HTTP API call answered without an upstream key.
+    #[allow(clippy::too_many_arguments)]
+    async fn answer_http_locally(
+        &self,
+        key_effect: KeyEffect,
+        status: StatusCode,
+        content_type: Option<&str>,
+        body: Bytes,
+        auth_token_id: Option<&str>,
+        method: &Method,
+        display_path: &str,
+        options: &Value,
+        client_ip: Option<&ClientIpInfo>,
+    ) -> Result<(ProxyResponse, AttemptAnalysis), ProxyError> {
+        let request_body = redact_api_key_bytes(
+            &serde_json::to_vec(options).map_err(|e| ProxyError::Other(e.to_string()))?,
+        );
+        let mut analysis = analyze_http_attempt(status, &body);
+        let request_log_id = self
+            .key_store
+            .log_attempt(AttemptLog {
+                key_id: None,
+                auth_token_id,
+                method,
+                path: display_path,
+                query: None,
+                status: Some(status),
+                tavily_status_code: analysis.tavily_status_code,
+                error: None,
+                request_body: &request_body,
+                response_body: &body,
+                outcome: analysis.status,
+                failure_kind: analysis.failure_kind.as_deref(),
+                key_effect_code: key_effect.code.as_str(),
+                key_effect_summary: key_effect.summary.as_deref(),
+                binding_effect_code: KEY_EFFECT_NONE,
+                binding_effect_summary: None,
+                selection_effect_code: KEY_EFFECT_NONE,
+                selection_effect_summary: None,
+                gateway_mode: None,
+                experiment_variant: None,
+                proxy_session_id: None,
+                routing_subject_hash: None,
+                upstream_operation: None,
+                fallback_reason: None,
+                forwarded_headers: &[],
+                dropped_headers: &[],
+                client_ip,
+            })
+            .await?;
+        analysis.key_effect = key_effect.clone();
+
+        let mut headers = HeaderMap::new();
+        if let Some(value) = content_type.and_then(|value| HeaderValue::from_str(value).ok()) {
+            headers.insert(reqwest::header::CONTENT_TYPE, value);
+        }
+        Ok((
+            ProxyResponse {
+                status,
+                headers,
+                body,
+                api_key_id: None,
+                request_log_id: Some(request_log_id),
+                key_effect_code: key_effect.code,
+                key_effect_summary: key_effect.summary,
+                binding_effect_code: KEY_EFFECT_NONE.to_string(),
+                binding_effect_summary: None,
+                selection_effect_code: KEY_EFFECT_NONE.to_string(),
+                selection_effect_summary: None,
+            },
+            analysis,
+        ))
+    }
+
+    /// Store a successful upstream response so identical calls can be served from the cache.
+    /// Oversized bodies are skipped.
+    pub async fn store_cached_http_response(
+        &self,
+        settings: &ResponseCacheSettings,
+        upstream_path: &str,
+        cache_key: &str,
+        response: &ProxyResponse,
+        credits: i64,
+    ) -> Result<(), ProxyError> {
+        if !response.status.is_success() || response.body.len() as i64 > settings.max_body_bytes {
+            return Ok(());
+        }
+        let cached = CachedResponse {
+            status: i64::from(response.status.as_u16()),
+            content_type: response
+                .headers
+                .get(reqwest::header::CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string),
+            body: response.body.to_vec(),
+            credits,
+        };
+        self.key_store
+            .store_response_cache_entry(
+                cache_key,
+                upstream_path.trim_start_matches('/'),
+                &cached,
+                self.backend_time.now_ts(),
+                settings.ttl_secs_for(upstream_path),
+                settings.max_entries,
+            )
+            .await
+    }
+}
@@ -0,0 +1,159 @@
+impl TavilyProxy {
+    pub async fn list_teams(&self) -> Result<Vec<Team>, ProxyError> {
+        self.key_store.list_teams().await
+    }
+
+    pub async fn get_team(&self, team_id: &str) -> Result<Option<Team>, ProxyError> {
+        self.key_store.fetch_team(team_id).await
+    }
+
+    pub async fn create_team(&self, input: &TeamInput) -> Result<Team, ProxyError> {
+        self.key_store.create_team(input).await
+    }
+
+    pub async fn update_team(
+        &self,
+        team_id: &str,
+        input: &TeamInput,
+    ) -> Result<Option<Team>, ProxyError> {
+        self.key_store.update_team(team_id, input).await
+    }
+
+    /// Deletes a team and soft-deletes its tokens. Members fall back to their personal limits.
+    pub async fn delete_team(&self, team_id: &str) -> Result<bool, ProxyError> {
+        self.key_store.delete_team(team_id).await
+    }
+
+    pub async fn list_team_members(&self, team_id: &str) -> Result<Vec<TeamMember>, ProxyError> {
+        self.key_store.list_team_members(team_id).await
+    }
+
+    /// Adds a user to a team or changes their role. Returns `false` when the user already
+    /// belongs to a different team.
+    pub async fn upsert_team_member(
+        &self,
+        team_id: &str,
+        user_id: &str,
+        role: TeamRole,
+    ) -> Result<bool, ProxyError> {
+        self.key_store
+            .upsert_team_member(team_id, user_id, role)
+            .await
+    }
+
+    pub async fn remove_team_member(
+        &self,
+        team_id: &str,
+        user_id: &str,
+    ) -> Result<bool, ProxyError> {
+        self.key_store.remove_team_member(team_id, user_id).await
+    }
+
+    pub async fn user_team_membership(
+        &self,
+        user_id: &str,
+    ) -> Result<Option<TeamMembership>, ProxyError> {
+        self.key_store.fetch_user_team_membership(user_id).await
+    }
+
+    pub async fn list_team_tokens(&self, team_id: &str) -> Result<Vec<TeamToken>, ProxyError> {
+        self.key_store.list_team_tokens(team_id).await
+    }
+
+    pub async fn create_team_token(
+        &self,
+        team_id: &str,
+        note: Option<&str>,
+        created_by: Option<&str>,
+    ) -> Result<AuthTokenSecret, ProxyError> {
+        self.key_store
+            .create_team_token(team_id, note, created_by)
+            .await
+    }
+
+    pub async fn delete_team_token(
+        &self,
+        team_id: &str,
+        token_id: &str,
+    ) -> Result<bool, ProxyError> {
+        self.key_store.delete_team_token(team_id, token_id).await
+    }
+
+    /// Current hour/day/month usage of the team pool against its limits.
+    pub async fn team_quota_snapshot(&self, team: &Team) -> Result<TokenQuotaVerdict, ProxyError> {
+        let now = self.backend_time.now_utc();
+        self.token_quota.team_pool_snapshot(team, now).await
+    }
+
+    /// Credits charged to the team pool per local day (last 7 days) or per month (last 12
+    /// months). Other series kinds are per-user only.
+    pub async fn team_usage_series(
+        &self,
+        team: &Team,
+        series: AdminUserUsageSeriesKind,
+    ) -> Result<AdminUserUsageSeries, ProxyError> {
+        let now = self.backend_time.now_utc();
+        let (granularity, bucket_starts, bucket_start_before, limit) = match series {
+            AdminUserUsageSeriesKind::DailyCredits => {
+                let current_bucket_start =
+                    server_local_day_window_utc(now.with_timezone(&Local)).start;
+                let mut bucket_starts = Vec::with_capacity(7);
+                let mut cursor = shift_local_day_start_utc_ts(current_bucket_start, -6);
+                for _ in 0..7 {
+                    bucket_starts.push(cursor);
+                    cursor = shift_local_day_start_utc_ts(cursor, 1);
+                }
+                (
+                    GRANULARITY_DAY,
+                    bucket_starts,
+                    cursor,
+                    team.limits.daily_credits_limit,
+                )
+            }
+            AdminUserUsageSeriesKind::MonthlyCredits => {
+                let current_bucket_start = start_of_month(now).timestamp();
+                let mut bucket_starts = Vec::with_capacity(12);
+                let mut cursor = shift_month_start_utc_ts(current_bucket_start, -11);
+                for _ in 0..12 {
+                    bucket_starts.push(cursor);
+                    cursor = shift_month_start_utc_ts(cursor, 1);
+                }
+                (
+                    GRANULARITY_MONTH,
+                    bucket_starts,
+                    cursor,
+                    team.limits.monthly_credits_limit,
+                )
+            }
+            AdminUserUsageSeriesKind::Rate5m | AdminUserUsageSeriesKind::BusinessCalls1h => {
+                return Err(ProxyError::Other(format!(
+                    "unsupported team usage series: {series:?}"
+                )));
+            }
+        };
+
+        let values = self
+            .key_store
+            .fetch_team_usage_values(
+                &team.id,
+                granularity,
+                bucket_starts.first().copied().unwrap_or(bucket_start_before),
+                bucket_start_before,
+            )
+            .await?;
+        // Team limits are not versioned, so every bucket is drawn against the current limit.
+        let limit_values = vec![Some(limit); bucket_starts.len()];
+        Ok(AdminUserUsageSeries {
+            limit,
+            points: build_admin_user_usage_series_points(
+                series,
+                bucket_starts,
+                bucket_start_before,
+                &values,
+                None,
+                Some(team.created_at),
+                limit_values,
+            ),
+        })
+    }
+}
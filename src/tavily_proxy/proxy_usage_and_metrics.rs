@@ -1280,7 +1280,10 @@ impl TavilyProxy {
         &self,
         log_id: i64,
     ) -> Result<PendingBillingSettleOutcome, ProxyError> {
-        self.key_store.apply_pending_billing_log(log_id).await
+        self.key_store
+            .apply_pending_billing_log(log_id)
+            .instrument(tracing::info_span!("billing", request_log_id = log_id))
+            .await
     }
 
     pub async fn annotate_pending_billing_attempt(
@@ -1352,14 +1355,20 @@ impl TavilyProxy {
 
     /// Check and update quota usage for a token. Returns the latest counts and verdict.
     pub async fn check_token_quota(&self, token_id: &str) -> Result<TokenQuotaVerdict, ProxyError> {
-        self.token_quota.check(token_id).await
+        self.token_quota
+            .check(token_id)
+            .instrument(tracing::info_span!("quota_check"))
+            .await
     }
 
     /// Read-only snapshot of the current business quota usage for a token (hour/day/month).
     /// This does NOT increment any counters.
     pub async fn peek_token_quota(&self, token_id: &str) -> Result<TokenQuotaVerdict, ProxyError> {
         let now = self.backend_time.now_utc();
-        self.token_quota.snapshot_for_token(token_id, now).await
+        self.token_quota
+            .snapshot_for_token(token_id, now)
+            .instrument(tracing::info_span!("quota_check"))
+            .await
     }
 
     /// Read-only snapshot for a locked billing subject. Use this when a request must keep the
@@ -1371,12 +1380,36 @@ impl TavilyProxy {
         let now = self.backend_time.now_utc();
         self.token_quota
             .snapshot_for_billing_subject(billing_subject, now)
+            .instrument(tracing::info_span!("quota_check"))
+            .await
+    }
+
+    /// Holds `credits` against a locked billing subject so later quota prechecks count them
+    /// while the request bills outside the subject lock. Returns the reservation id to pass to
+    /// [`Self::release_quota_reservation`] once the real charge is recorded or abandoned.
+    pub async fn reserve_quota_credits_for_subject(
+        &self,
+        token_id: &str,
+        billing_subject: &str,
+        credits: i64,
+    ) -> Result<i64, ProxyError> {
+        self.key_store
+            .insert_billing_reservation(billing_subject, token_id, credits)
+            .await
+    }
+
+    pub async fn release_quota_reservation(&self, reservation_id: i64) -> Result<(), ProxyError> {
+        self.key_store
+            .delete_billing_reservation(reservation_id)
             .await
     }
 
     /// Charge business quota usage for a token by Tavily credits (1:1).
     /// `credits <= 0` is treated as a no-op.
     pub async fn charge_token_quota(&self, token_id: &str, credits: i64) -> Result<(), ProxyError> {
-        self.token_quota.charge(token_id, credits).await
+        self.token_quota
+            .charge(token_id, credits)
+            .instrument(tracing::info_span!("billing", credits))
+            .await
     }
 }
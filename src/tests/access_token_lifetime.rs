@@ -0,0 +1,236 @@
+use super::*;
+
+async fn proxy_at(db_path: &std::path::Path, now: i64) -> (TavilyProxy, ManualBackendTime) {
+    let db_str = db_path.to_string_lossy().to_string();
+    let (backend_time, manual_clock) = BackendTime::manual_from_ts(now);
+    let proxy = TavilyProxy::with_options_and_time(
+        Vec::<String>::new(),
+        DEFAULT_UPSTREAM,
+        &db_str,
+        TavilyProxyOptions::from_database_path(&db_str),
+        backend_time,
+    )
+    .await
+    .expect("proxy created");
+    (proxy, manual_clock)
+}
+
+#[test]
+fn access_token_lifetime_rejects_inverted_or_negative_windows() {
+    assert!(AccessTokenLifetime::default().validate().is_ok());
+    assert!(
+        AccessTokenLifetime::new(Some(10), Some(20))
+            .validate()
+            .is_ok()
+    );
+    assert!(
+        AccessTokenLifetime::new(Some(20), Some(20))
+            .validate()
+            .is_err()
+    );
+    assert!(AccessTokenLifetime::new(None, Some(-1)).validate().is_err());
+    assert!(AccessTokenLifetime::new(Some(-5), None).validate().is_err());
+}
+
+#[tokio::test]
+async fn access_token_lifetime_is_enforced_at_validation() {
+    let db_path = temp_db_path("access-token-lifetime-enforced");
+    let now = 1_760_000_000;
+    let (proxy, manual_clock) = proxy_at(&db_path, now).await;
+
+    let issued = proxy
+        .create_access_token_with_lifetime(
+            Some("contractor"),
+            AccessTokenLifetime::new(Some(now + 60), Some(now + 3_600)),
+        )
+        .await
+        .expect("create windowed token");
+
+    assert_eq!(
+        proxy
+            .check_access_token(&issued.token)
+            .await
+            .expect("check before window"),
+        AccessTokenValidation::NotYetValid {
+            not_before: now + 60
+        }
+    );
+    assert!(
+        !proxy
+            .validate_access_token(&issued.token)
+            .await
+            .expect("validate before window")
+    );
+
+    manual_clock.set_now_ts(now + 60);
+    assert_eq!(
+        proxy
+            .check_access_token(&issued.token)
+            .await
+            .expect("check inside window"),
+        AccessTokenValidation::Valid
+    );
+
+    manual_clock.set_now_ts(now + 3_600);
+    assert_eq!(
+        proxy
+            .check_access_token(&issued.token)
+            .await
+            .expect("check after expiry"),
+        AccessTokenValidation::Expired {
+            expires_at: now + 3_600
+        }
+    );
+
+    let listed = proxy
+        .list_access_tokens()
+        .await
+        .expect("list tokens")
+        .into_iter()
+        .find(|token| token.id == issued.id)
+        .expect("token listed");
+    assert!(listed.enabled, "expiry does not flip the enabled flag");
+    assert_eq!(listed.lifetime.expires_at, Some(now + 3_600));
+
+    assert!(
+        proxy
+            .set_access_token_lifetime(&issued.id, AccessTokenLifetime::default())
+            .await
+            .expect("clear lifetime")
+    );
+    assert!(
+        proxy
+            .validate_access_token(&issued.token)
+            .await
+            .expect("validate after clearing lifetime")
+    );
+    assert!(
+        !proxy
+            .set_access_token_lifetime("zzzz", AccessTokenLifetime::default())
+            .await
+            .expect("update missing token")
+    );
+
+    drop(proxy);
+    remove_db_files(&db_path);
+}
+
+#[tokio::test]
+async fn access_token_batch_shares_lifetime_and_wrong_secret_stays_invalid() {
+    let db_path = temp_db_path("access-token-lifetime-batch");
+    let now = 1_760_100_000;
+    let (proxy, _manual_clock) = proxy_at(&db_path, now).await;
+
+    let issued = proxy
+        .create_access_tokens_batch_with_lifetime(
+            "ci",
+            3,
+            None,
+            AccessTokenLifetime::new(None, Some(now - 1)),
+        )
+        .await
+        .expect("create expired batch");
+    assert_eq!(issued.len(), 3);
+    for token in &issued {
+        assert_eq!(
+            proxy
+                .check_access_token(&token.token)
+                .await
+                .expect("check batch token"),
+            AccessTokenValidation::Expired {
+                expires_at: now - 1
+            }
+        );
+    }
+    assert_eq!(
+        proxy
+            .check_access_token(&format!("th-{}-{}", issued[0].id, "x".repeat(24)))
+            .await
+            .expect("check wrong secret"),
+        AccessTokenValidation::Invalid,
+        "lifetime verdicts never leak for an unauthenticated secret"
+    );
+
+    drop(proxy);
+    remove_db_files(&db_path);
+}
+
+#[tokio::test]
+async fn access_token_expiry_notices_raise_one_alert_per_expiry() {
+    let db_path = temp_db_path("access-token-lifetime-notices");
+    let now = 1_760_200_000;
+    let (proxy, manual_clock) = proxy_at(&db_path, now).await;
+
+    let soon = proxy
+        .create_access_token_with_lifetime(
+            Some("soon"),
+            AccessTokenLifetime::new(None, Some(now + 3_600)),
+        )
+        .await
+        .expect("create expiring token");
+    proxy
+        .create_access_token_with_lifetime(
+            Some("later"),
+            AccessTokenLifetime::new(None, Some(now + 30 * 24 * 3_600)),
+        )
+        .await
+        .expect("create long-lived token");
+    proxy
+        .create_access_token(Some("forever"))
+        .await
+        .expect("create unbounded token");
+
+    assert_eq!(
+        proxy
+            .record_access_token_expiry_notices()
+            .await
+            .expect("first scan"),
+        1
+    );
+    manual_clock.set_now_ts(now + 60);
+    assert_eq!(
+        proxy
+            .record_access_token_expiry_notices()
+            .await
+            .expect("repeat scan"),
+        0,
+        "a token is noticed once per expiry"
+    );
+
+    let events = proxy
+        .alert_events_page(
+            Some(ALERT_TYPE_TOKEN_EXPIRING),
+            None,
+            None,
+            None,
+            None,
+            None,
+            &[],
+            1,
+            20,
+        )
+        .await
+        .expect("read token expiry alerts");
+    assert_eq!(events.total, 1);
+    let event = &events.items[0];
+    assert_eq!(event.alert_type, ALERT_TYPE_TOKEN_EXPIRING);
+    assert_eq!(event.subject_kind, "token");
+    assert_eq!(event.subject_id, soon.id);
+    assert_eq!(event.source.kind, ALERT_SOURCE_AUTH_TOKEN_EXPIRY_NOTICE);
+
+    // Extending the lifetime arms a fresh notice for the new expiry.
+    proxy
+        .set_access_token_lifetime(&soon.id, AccessTokenLifetime::new(None, Some(now + 7_200)))
+        .await
+        .expect("extend token");
+    assert_eq!(
+        proxy
+            .record_access_token_expiry_notices()
+            .await
+            .expect("scan after extension"),
+        1
+    );
+
+    drop(proxy);
+    remove_db_files(&db_path);
+}
@@ -0,0 +1,125 @@
+use super::*;
+
+#[test]
+fn access_token_scopes_parse_normalizes_and_rejects_unknown_keys() {
+    let scopes =
+        AccessTokenScopes::parse(["mcp:search", " api:search ", "mcp:search"]).expect("parse");
+    assert_eq!(scopes.keys(), ["api:search", "mcp:search"]);
+    assert!(AccessTokenScopes::parse(["api:usage"]).is_err());
+    assert!(AccessTokenScopes::parse(["mcp:deep-research"]).is_err());
+
+    assert!(scopes.allows("api:search"));
+    assert!(scopes.allows("mcp:search"));
+    assert!(!scopes.allows("api:research"));
+    assert!(!scopes.allows("mcp:research"));
+    // Control-plane kinds are never scoped.
+    assert!(scopes.allows("mcp:tools/list"));
+    assert!(scopes.allows("mcp:initialize"));
+
+    let research = AccessTokenScopes::parse(["api:research"]).expect("parse research");
+    assert!(research.allows("api:research-result"));
+    assert!(!scopes.allows("api:research-result"));
+
+    assert_eq!(
+        AccessTokenScopes::from_stored(r#"["mcp:map","bogus","api:crawl"]"#).keys(),
+        ["api:crawl", "mcp:map"]
+    );
+    assert!(AccessTokenScopes::from_stored("not json").keys().is_empty());
+}
+
+#[tokio::test]
+async fn access_token_scopes_prefer_token_over_group_default() {
+    let db_path = temp_db_path("access-token-scopes-precedence");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+        .await
+        .expect("proxy created");
+
+    let issued = proxy
+        .create_access_tokens_batch("cheap", 2, None)
+        .await
+        .expect("create batch");
+    let (first, second) = (&issued[0], &issued[1]);
+    assert_eq!(
+        proxy
+            .effective_access_token_scopes(&first.id)
+            .await
+            .expect("unscoped token"),
+        None
+    );
+
+    let search_only = AccessTokenScopes::parse(["api:search", "mcp:search"]).expect("parse");
+    proxy
+        .set_token_group_default_scopes(" cheap ", Some(&search_only))
+        .await
+        .expect("set group default");
+    assert_eq!(
+        proxy
+            .effective_access_token_scopes(&second.id)
+            .await
+            .expect("group default"),
+        Some(search_only.clone())
+    );
+
+    let map_only = AccessTokenScopes::parse(["api:map"]).expect("parse map");
+    assert_eq!(
+        proxy
+            .set_access_tokens_scopes(std::slice::from_ref(&first.id), Some(&map_only))
+            .await
+            .expect("set token scopes"),
+        1
+    );
+    assert_eq!(
+        proxy
+            .effective_access_token_scopes(&first.id)
+            .await
+            .expect("token override"),
+        Some(map_only.clone())
+    );
+
+    let listed = proxy.list_access_tokens().await.expect("list tokens");
+    let listed_first = listed
+        .iter()
+        .find(|token| token.id == first.id)
+        .expect("first listed");
+    assert_eq!(listed_first.scopes, Some(map_only));
+    let listed_second = listed
+        .iter()
+        .find(|token| token.id == second.id)
+        .expect("second listed");
+    assert_eq!(
+        listed_second.scopes, None,
+        "list shows only token-level scopes"
+    );
+
+    let defaults = proxy
+        .list_token_group_default_scopes()
+        .await
+        .expect("list group defaults");
+    assert_eq!(defaults.get("cheap"), Some(&search_only));
+
+    proxy
+        .set_access_tokens_scopes(std::slice::from_ref(&first.id), None)
+        .await
+        .expect("clear token scopes");
+    proxy
+        .set_token_group_default_scopes("cheap", None)
+        .await
+        .expect("clear group default");
+    assert_eq!(
+        proxy
+            .effective_access_token_scopes(&first.id)
+            .await
+            .expect("cleared"),
+        None
+    );
+    assert_eq!(
+        proxy
+            .set_access_tokens_scopes(&["zzzz".to_string()], None)
+            .await
+            .expect("missing token"),
+        0
+    );
+
+    let _ = std::fs::remove_file(db_path);
+}
@@ -0,0 +1,270 @@
+use super::*;
+
+fn hashing_options(db_str: &str, enabled: bool) -> TavilyProxyOptions {
+    TavilyProxyOptions {
+        hash_access_token_secrets: enabled,
+        ..TavilyProxyOptions::from_database_path(db_str)
+    }
+}
+
+#[tokio::test]
+async fn hashed_access_tokens_validate_but_only_reveal_on_issue() {
+    let db_path = temp_db_path("access-token-secret-hashing-issue");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_options(
+        Vec::<String>::new(),
+        DEFAULT_UPSTREAM,
+        &db_str,
+        hashing_options(&db_str, true),
+    )
+    .await
+    .expect("proxy created");
+
+    let issued = proxy
+        .create_access_token(Some("hashed"))
+        .await
+        .expect("create token");
+    let (secret, secret_hash): (String, Option<String>) =
+        sqlx::query_as("SELECT secret, secret_hash FROM auth_tokens WHERE id = ?")
+            .bind(&issued.id)
+            .fetch_one(&proxy.key_store.pool)
+            .await
+            .expect("read stored token");
+    assert_eq!(secret, "", "plaintext secret is not persisted");
+    let secret_hash = secret_hash.expect("secret hash stored");
+    assert!(secret_hash.starts_with("sha256:"));
+    assert!(!secret_hash.contains(issued.token.rsplit('-').next().unwrap()));
+
+    assert!(
+        proxy
+            .validate_access_token(&issued.token)
+            .await
+            .expect("validate issued token")
+    );
+    assert!(
+        !proxy
+            .validate_access_token(&format!("th-{}-{}", issued.id, "x".repeat(24)))
+            .await
+            .expect("validate wrong secret")
+    );
+    assert!(
+        !proxy
+            .validate_access_token(&format!("th-{}-", issued.id))
+            .await
+            .expect("validate empty secret")
+    );
+    let err = proxy
+        .get_access_token_secret(&issued.id)
+        .await
+        .expect_err("hashed secret can not be revealed");
+    assert!(
+        matches!(&err, ProxyError::TokenSecretNotRecoverable { token_id } if token_id == &issued.id),
+        "{err:?}"
+    );
+
+    let rotated = proxy
+        .rotate_access_token_secret(&issued.id)
+        .await
+        .expect("rotate token");
+    assert_ne!(rotated.token, issued.token);
+    assert!(
+        proxy
+            .validate_access_token(&rotated.token)
+            .await
+            .expect("validate rotated token")
+    );
+    assert!(
+        !proxy
+            .validate_access_token(&issued.token)
+            .await
+            .expect("validate replaced token")
+    );
+
+    let batch = proxy
+        .create_access_tokens_batch("hashed-batch", 2, None)
+        .await
+        .expect("create batch");
+    for token in &batch {
+        assert!(
+            proxy
+                .validate_access_token(&token.token)
+                .await
+                .expect("validate batch token")
+        );
+    }
+    let plaintext_rows: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM auth_tokens WHERE secret_hash IS NULL")
+            .fetch_one(&proxy.key_store.pool)
+            .await
+            .expect("count plaintext rows");
+    assert_eq!(plaintext_rows, 0);
+
+    drop(proxy);
+    remove_db_files(&db_path);
+}
+
+#[tokio::test]
+async fn enabling_access_token_hashing_migrates_existing_tokens_and_ha_outbox() {
+    let db_path = temp_db_path("access-token-secret-hashing-migrate");
+    let db_str = db_path.to_string_lossy().to_string();
+    let plaintext = TavilyProxy::with_options_in_ha_mode(
+        Vec::<String>::new(),
+        DEFAULT_UPSTREAM,
+        &db_str,
+        hashing_options(&db_str, false),
+        HaMode::ActiveStandby,
+    )
+    .await
+    .expect("plaintext proxy created");
+    let existing = plaintext
+        .create_access_token(Some("existing"))
+        .await
+        .expect("create plaintext token");
+    let existing_secret = existing.token.rsplit('-').next().unwrap().to_string();
+    let leaked_before: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM ha_outbox WHERE resource = 'auth_tokens' AND instr(payload_json, ?) > 0",
+    )
+    .bind(&existing_secret)
+    .fetch_one(&plaintext.key_store.pool)
+    .await
+    .expect("scan ha outbox before hashing");
+    assert!(leaked_before > 0, "HA outbox carries the plaintext row");
+    drop(plaintext);
+
+    let hashed = TavilyProxy::with_options_in_ha_mode(
+        Vec::<String>::new(),
+        DEFAULT_UPSTREAM,
+        &db_str,
+        hashing_options(&db_str, true),
+        HaMode::ActiveStandby,
+    )
+    .await
+    .expect("hashing proxy created");
+    let stored_secret: String = sqlx::query_scalar("SELECT secret FROM auth_tokens WHERE id = ?")
+        .bind(&existing.id)
+        .fetch_one(&hashed.key_store.pool)
+        .await
+        .expect("read migrated token");
+    assert_eq!(stored_secret, "");
+    let leaked_after: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM ha_outbox WHERE resource = 'auth_tokens' AND instr(payload_json, ?) > 0",
+    )
+    .bind(&existing_secret)
+    .fetch_one(&hashed.key_store.pool)
+    .await
+    .expect("scan ha outbox after hashing");
+    assert_eq!(leaked_after, 0, "HA outbox only carries the hash");
+    assert!(
+        hashed
+            .validate_access_token(&existing.token)
+            .await
+            .expect("validate migrated token")
+    );
+    drop(hashed);
+
+    let reverted = TavilyProxy::with_options(
+        Vec::<String>::new(),
+        DEFAULT_UPSTREAM,
+        &db_str,
+        hashing_options(&db_str, false),
+    )
+    .await
+    .expect("proxy restarted without hashing");
+    assert!(
+        reverted
+            .validate_access_token(&existing.token)
+            .await
+            .expect("hashed token keeps validating")
+    );
+    assert!(matches!(
+        reverted.get_access_token_secret(&existing.id).await,
+        Err(ProxyError::TokenSecretNotRecoverable { .. })
+    ));
+    let fresh = reverted
+        .create_access_token(Some("fresh"))
+        .await
+        .expect("create plaintext token");
+    assert_eq!(
+        reverted
+            .get_access_token_secret(&fresh.id)
+            .await
+            .expect("reveal plaintext token")
+            .map(|secret| secret.token),
+        Some(fresh.token)
+    );
+
+    drop(reverted);
+    remove_db_files(&db_path);
+}
+
+#[tokio::test]
+async fn hashed_user_tokens_keep_binding_but_report_not_recoverable() {
+    let db_path = temp_db_path("access-token-secret-hashing-user");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_options(
+        Vec::<String>::new(),
+        DEFAULT_UPSTREAM,
+        &db_str,
+        hashing_options(&db_str, true),
+    )
+    .await
+    .expect("proxy created");
+    let user = proxy
+        .upsert_oauth_account(&OAuthAccountProfile {
+            provider: "linuxdo".to_string(),
+            provider_user_id: "hashed-token-user".to_string(),
+            username: Some("hashed_token_user".to_string()),
+            name: Some("Hashed Token User".to_string()),
+            avatar_template: None,
+            active: true,
+            trust_level: Some(1),
+            raw_payload_json: None,
+        })
+        .await
+        .expect("upsert user");
+
+    let issued = proxy
+        .ensure_user_token_binding(&user.user_id, Some("linuxdo:hashed_token_user"))
+        .await
+        .expect("first binding reveals the new token");
+    assert!(
+        proxy
+            .validate_access_token(&issued.token)
+            .await
+            .expect("validate bound token")
+    );
+
+    let err = proxy
+        .ensure_user_token_binding(&user.user_id, Some("linuxdo:hashed_token_user"))
+        .await
+        .expect_err("existing hashed binding can not be revealed again");
+    assert!(matches!(err, ProxyError::TokenSecretNotRecoverable { .. }));
+    let err = proxy
+        .ensure_user_token_binding_with_preferred(
+            &user.user_id,
+            Some("linuxdo:hashed_token_user"),
+            Some(&issued.id),
+        )
+        .await
+        .expect_err("preferred hashed binding can not be revealed again");
+    assert!(matches!(err, ProxyError::TokenSecretNotRecoverable { .. }));
+    let bindings: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM user_token_bindings WHERE user_id = ?")
+            .bind(&user.user_id)
+            .fetch_one(&proxy.key_store.pool)
+            .await
+            .expect("count bindings");
+    assert_eq!(bindings, 1, "no extra token is issued for a hashed binding");
+
+    assert!(matches!(
+        proxy.get_user_token(&user.user_id).await,
+        Err(ProxyError::TokenSecretNotRecoverable { .. })
+    ));
+    assert!(matches!(
+        proxy.get_user_token_secret(&user.user_id, &issued.id).await,
+        Err(ProxyError::TokenSecretNotRecoverable { .. })
+    ));
+
+    drop(proxy);
+    remove_db_files(&db_path);
+}
@@ -0,0 +1,159 @@
+use super::*;
+
+#[test]
+fn admin_roles_are_ordered_and_parse_case_insensitively() {
+    assert!(AdminRole::Owner.allows(AdminRole::Operator));
+    assert!(AdminRole::Operator.allows(AdminRole::Viewer));
+    assert!(AdminRole::Viewer.allows(AdminRole::Viewer));
+    assert!(!AdminRole::Viewer.allows(AdminRole::Operator));
+    assert!(!AdminRole::Operator.allows(AdminRole::Owner));
+    assert_eq!(AdminRole::parse(" Owner "), Some(AdminRole::Owner));
+    assert_eq!(AdminRole::parse("root"), None);
+    assert_eq!(
+        normalize_admin_account_username(" Ops.Lead ").as_deref(),
+        Ok("ops.lead")
+    );
+    assert!(normalize_admin_account_username("ab").is_err());
+    assert!(normalize_admin_account_username("ops lead").is_err());
+}
+
+#[tokio::test]
+async fn admin_account_sessions_follow_role_changes_and_end_on_disable() {
+    let db_path = temp_db_path("admin-account-sessions");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+        .await
+        .expect("proxy created");
+    assert!(
+        !proxy
+            .has_active_admin_accounts()
+            .await
+            .expect("no accounts yet")
+    );
+
+    let account = proxy
+        .create_admin_account("Ops-Lead", Some(" Ops Lead "), AdminRole::Viewer, "hash-1")
+        .await
+        .expect("create account")
+        .expect("username is free");
+    assert_eq!(account.username, "ops-lead");
+    assert_eq!(account.display_name.as_deref(), Some("Ops Lead"));
+    assert!(
+        proxy
+            .create_admin_account("OPS-LEAD", None, AdminRole::Owner, "hash-2")
+            .await
+            .expect("create duplicate")
+            .is_none()
+    );
+    assert!(
+        proxy
+            .has_active_admin_accounts()
+            .await
+            .expect("has accounts")
+    );
+
+    let credential = proxy
+        .admin_account_credential("ops-lead")
+        .await
+        .expect("load credential")
+        .expect("credential exists");
+    assert_eq!(credential.password_hash, "hash-1");
+    let token = proxy
+        .create_admin_account_session(&account.id, 3600)
+        .await
+        .expect("create session");
+    let session = proxy
+        .get_active_admin_account_session(&token)
+        .await
+        .expect("read session")
+        .expect("session active");
+    assert_eq!(session.role, AdminRole::Viewer);
+    let listed = proxy.list_admin_accounts().await.expect("list accounts");
+    assert_eq!(listed[0].active_sessions, 1);
+    assert!(listed[0].last_login_at.is_some());
+
+    proxy
+        .update_admin_account(&account.id, None, Some(AdminRole::Operator), None)
+        .await
+        .expect("promote")
+        .expect("account exists");
+    let session = proxy
+        .get_active_admin_account_session(&token)
+        .await
+        .expect("read promoted session")
+        .expect("session still active");
+    assert_eq!(
+        session.role,
+        AdminRole::Operator,
+        "role changes apply to live sessions"
+    );
+
+    let disabled = proxy
+        .update_admin_account(&account.id, Some(None), None, Some(true))
+        .await
+        .expect("disable")
+        .expect("account exists");
+    assert!(disabled.disabled_at.is_some());
+    assert_eq!(disabled.display_name, None);
+    assert_eq!(disabled.active_sessions, 0);
+    assert!(
+        proxy
+            .get_active_admin_account_session(&token)
+            .await
+            .expect("read disabled session")
+            .is_none()
+    );
+    assert!(
+        proxy
+            .admin_account_credential("ops-lead")
+            .await
+            .expect("load disabled credential")
+            .is_none()
+    );
+
+    proxy
+        .update_admin_account(&account.id, None, None, Some(false))
+        .await
+        .expect("enable")
+        .expect("account exists");
+    let token = proxy
+        .create_admin_account_session(&account.id, 3600)
+        .await
+        .expect("create second session");
+    assert!(
+        proxy
+            .set_admin_account_password_hash(&account.id, "hash-3")
+            .await
+            .expect("rotate password")
+    );
+    assert!(
+        proxy
+            .get_active_admin_account_session(&token)
+            .await
+            .expect("read rotated session")
+            .is_none(),
+        "password changes end existing sessions"
+    );
+
+    assert!(
+        proxy
+            .delete_admin_account(&account.id)
+            .await
+            .expect("delete")
+    );
+    assert!(
+        !proxy
+            .delete_admin_account(&account.id)
+            .await
+            .expect("delete twice")
+    );
+    assert!(
+        proxy
+            .update_admin_account(&account.id, None, Some(AdminRole::Owner), None)
+            .await
+            .expect("update deleted")
+            .is_none()
+    );
+
+    let _ = std::fs::remove_file(db_path);
+}
@@ -0,0 +1,132 @@
+use super::*;
+
+#[test]
+fn admin_api_token_scopes_and_ip_allowlists_validate() {
+    let scopes = AdminApiTokenScopes::parse(["logs:read", "keys:write", "keys:write"])
+        .expect("valid scopes");
+    assert_eq!(scopes.keys(), ["keys:write", "logs:read"]);
+    assert!(scopes.allows("keys:read"));
+    assert!(scopes.allows("keys:write"));
+    assert!(scopes.allows("logs:read"));
+    assert!(!scopes.allows("logs:write"));
+    assert!(!scopes.allows("tokens:read"));
+    assert!(AdminApiTokenScopes::parse(["keys:admin"]).is_err());
+    assert!(AdminApiTokenScopes::parse(Vec::<String>::new()).is_err());
+    assert_eq!(
+        AdminApiTokenScopes::from_stored(r#"["stats:read","root"]"#).keys(),
+        ["stats:read"]
+    );
+
+    let allowlist = normalize_admin_api_token_ip_allowlist(&[
+        " 10.0.0.0/8 ".to_string(),
+        "10.0.0.0/8".to_string(),
+        "2001:db8::1".to_string(),
+    ])
+    .expect("valid allowlist");
+    assert_eq!(allowlist, ["10.0.0.0/8", "2001:db8::1"]);
+    assert!(normalize_admin_api_token_ip_allowlist(&["not-an-ip".to_string()]).is_err());
+
+    let mut token = AdminApiToken {
+        id: "abcdefgh".to_string(),
+        name: "ci".to_string(),
+        scopes,
+        ip_allowlist: Vec::new(),
+        created_by: None,
+        created_at: 0,
+        expires_at: None,
+        last_used_at: None,
+        last_used_ip: None,
+        revoked_at: None,
+    };
+    assert!(token.allows_ip(None));
+    token.ip_allowlist = allowlist;
+    assert!(token.allows_ip(Some("10.20.30.40")));
+    assert!(token.allows_ip(Some("2001:db8::1")));
+    assert!(!token.allows_ip(Some("192.168.1.1")));
+    assert!(!token.allows_ip(None));
+}
+
+#[tokio::test]
+async fn admin_api_tokens_authenticate_until_expired_or_revoked() {
+    let db_path = temp_db_path("admin-api-token-auth");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+        .await
+        .expect("proxy created");
+    let scopes = AdminApiTokenScopes::parse(["keys:write"]).expect("scopes");
+
+    let (token, value) = proxy
+        .create_admin_api_token(" deploy ", &scopes, &[], None, Some("builtin-admin"))
+        .await
+        .expect("create token");
+    assert_eq!(token.name, "deploy");
+    assert_eq!(token.created_by.as_deref(), Some("builtin-admin"));
+    assert!(value.starts_with(&format!("{ADMIN_API_TOKEN_PREFIX}{}-", token.id)));
+    let resolved = proxy
+        .authenticate_admin_api_token(&value)
+        .await
+        .expect("authenticate")
+        .expect("token matches");
+    assert_eq!(resolved.id, token.id);
+    assert_eq!(resolved.scopes, scopes);
+
+    let tampered = format!("{}x", &value[..value.len() - 1]);
+    assert!(
+        proxy
+            .authenticate_admin_api_token(&tampered)
+            .await
+            .expect("authenticate tampered")
+            .is_none()
+    );
+    assert!(
+        proxy
+            .authenticate_admin_api_token("th-abcd-efghijklmnopqrstuvwxyz12")
+            .await
+            .expect("authenticate user token")
+            .is_none()
+    );
+
+    proxy
+        .touch_admin_api_token(&token.id, Some("10.0.0.7"))
+        .await
+        .expect("touch token");
+    let listed = proxy.list_admin_api_tokens().await.expect("list tokens");
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].last_used_ip.as_deref(), Some("10.0.0.7"));
+    assert!(listed[0].last_used_at.is_some());
+
+    let revoked = proxy
+        .revoke_admin_api_token(&token.id)
+        .await
+        .expect("revoke token")
+        .expect("token exists");
+    assert!(revoked.revoked_at.is_some());
+    assert!(
+        proxy
+            .authenticate_admin_api_token(&value)
+            .await
+            .expect("authenticate revoked")
+            .is_none()
+    );
+
+    let (_, expired) = proxy
+        .create_admin_api_token("expired", &scopes, &[], Some(1), None)
+        .await
+        .expect("create expired token");
+    assert!(
+        proxy
+            .authenticate_admin_api_token(&expired)
+            .await
+            .expect("authenticate expired")
+            .is_none()
+    );
+    assert!(
+        proxy
+            .revoke_admin_api_token("missing")
+            .await
+            .expect("revoke missing")
+            .is_none()
+    );
+
+    let _ = std::fs::remove_file(db_path);
+}
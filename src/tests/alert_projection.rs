@@ -730,11 +730,11 @@ async fn alert_projection_admin_history_migration_preserves_sidecar_and_restarts
     .await
     .expect("verify cursor-only administrator history migration");
     assert_eq!(
-        preserved_tail_sources, 3,
+        preserved_tail_sources, 5,
         "Dashboard tail must keep its complete cursor"
     );
     assert_eq!(
-        reset_history_sources, 3,
+        reset_history_sources, 5,
         "admin history starts from an independent cursor"
     );
     assert_eq!(retained_events, 1, "migration must not rewrite the sidecar");
@@ -827,7 +827,7 @@ async fn alert_projection_history_fence_repair_replays_old_v14_gap() {
     .await
     .expect("read repaired history state");
     assert_eq!(
-        repaired_sources, 3,
+        repaired_sources, 5,
         "repair must reset derived history only"
     );
     let v15_recorded: i64 = sqlx::query_scalar(
@@ -919,7 +919,7 @@ async fn alert_projection_exact_tail_boundary_belongs_to_recent_lane() {
     .fetch_one(&proxy.key_store.pool)
     .await
     .expect("read repaired history fence");
-    assert_eq!(repaired_fences, 3);
+    assert_eq!(repaired_fences, 5);
 
     for _ in 0..6 {
         let outcome = proxy
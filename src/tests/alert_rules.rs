@@ -0,0 +1,145 @@
+use super::*;
+
+fn pool_credit_rule() -> AlertRuleMutation {
+    AlertRuleMutation {
+        name: "Pool low".to_string(),
+        metric: ALERT_RULE_METRIC_POOL_CREDITS_REMAINING.to_string(),
+        comparator: ALERT_RULE_COMPARATOR_BELOW.to_string(),
+        threshold: 100.0,
+        clear_threshold: Some(150.0),
+        window_secs: None,
+        request_kind: None,
+        enabled: true,
+    }
+}
+
+async fn set_pool_credits(proxy: &TavilyProxy, remaining: i64) {
+    sqlx::query(
+        r#"INSERT INTO api_keys (id, api_key, status, created_at, quota_remaining)
+           VALUES ('rule-key', 'tvly-rule-key', 'active', 0, ?)
+           ON CONFLICT(id) DO UPDATE SET quota_remaining = excluded.quota_remaining"#,
+    )
+    .bind(remaining)
+    .execute(&proxy.key_store.pool)
+    .await
+    .expect("set pool credits");
+}
+
+#[test]
+fn alert_rule_mutation_validates_and_hysteresis_holds_until_clear_threshold() {
+    let mut wrong_side = pool_credit_rule();
+    wrong_side.clear_threshold = Some(50.0);
+    assert!(wrong_side.normalized().is_err());
+    let mut stray_kind = pool_credit_rule();
+    stray_kind.request_kind = Some("api_search".to_string());
+    assert!(stray_kind.normalized().is_err());
+    let mut bad_window = pool_credit_rule();
+    bad_window.window_secs = Some(5);
+    assert!(bad_window.normalized().is_err());
+
+    let input = pool_credit_rule().normalized().expect("valid rule");
+    let rule = AlertRule {
+        id: "rule".to_string(),
+        name: input.name,
+        metric: input.metric,
+        comparator: input.comparator,
+        threshold: input.threshold,
+        clear_threshold: input.clear_threshold,
+        window_secs: ALERT_RULE_WINDOW_SECS_DEFAULT,
+        request_kind: None,
+        enabled: true,
+        created_at: 0,
+        updated_at: 0,
+        state: ALERT_RULE_STATE_OK.to_string(),
+        last_value: None,
+        last_evaluated_at: None,
+        last_fired_at: None,
+    };
+    assert!(!rule.next_firing(false, 120.0));
+    assert!(rule.next_firing(false, 99.0));
+    assert!(rule.next_firing(true, 120.0), "still below the clear level");
+    assert!(!rule.next_firing(true, 150.0));
+    assert_eq!(
+        rule.describe_breach(99.0),
+        "pool_credits_remaining 99 below 100"
+    );
+}
+
+#[tokio::test]
+async fn pool_credit_rule_fires_once_per_breach_and_surfaces_as_rule_alert() {
+    let db_path = temp_db_path("alert-rules-pool");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+        .await
+        .expect("proxy created");
+    let ha = HaRuntime::new(HaConfig::default());
+    let rule = proxy
+        .create_alert_rule(pool_credit_rule())
+        .await
+        .expect("create rule");
+
+    set_pool_credits(&proxy, 500).await;
+    assert_eq!(proxy.evaluate_alert_rules(&ha).await.expect("evaluate"), 0);
+    set_pool_credits(&proxy, 80).await;
+    assert_eq!(proxy.evaluate_alert_rules(&ha).await.expect("evaluate"), 1);
+    set_pool_credits(&proxy, 120).await;
+    assert_eq!(
+        proxy.evaluate_alert_rules(&ha).await.expect("evaluate"),
+        0,
+        "a value between threshold and clear level does not re-fire"
+    );
+    set_pool_credits(&proxy, 90).await;
+    assert_eq!(proxy.evaluate_alert_rules(&ha).await.expect("evaluate"), 0);
+
+    let stored = &proxy.list_alert_rules().await.expect("list rules")[0];
+    assert_eq!(stored.state, ALERT_RULE_STATE_FIRING);
+    assert_eq!(stored.last_value, Some(90.0));
+
+    set_pool_credits(&proxy, 200).await;
+    assert_eq!(proxy.evaluate_alert_rules(&ha).await.expect("evaluate"), 0);
+    set_pool_credits(&proxy, 10).await;
+    assert_eq!(
+        proxy.evaluate_alert_rules(&ha).await.expect("evaluate"),
+        1,
+        "a cleared rule fires again on the next breach"
+    );
+
+    let events = proxy
+        .alert_events_page(
+            Some(ALERT_TYPE_THRESHOLD_RULE),
+            None,
+            None,
+            None,
+            None,
+            None,
+            &[],
+            1,
+            20,
+        )
+        .await
+        .expect("read rule alerts");
+    assert_eq!(events.total, 2);
+    let event = &events.items[0];
+    assert_eq!(event.subject_kind, ALERT_SUBJECT_RULE);
+    assert_eq!(event.subject_id, rule.id);
+    assert_eq!(event.subject_label, "Pool low");
+    assert_eq!(event.source.kind, ALERT_SOURCE_ALERT_RULE_FIRING);
+    assert!(event.summary.contains("below 100"));
+
+    assert!(
+        proxy
+            .delete_alert_rule(&rule.id)
+            .await
+            .expect("delete rule")
+    );
+    assert!(
+        proxy
+            .list_alert_rules()
+            .await
+            .expect("list rules")
+            .is_empty()
+    );
+
+    drop(proxy);
+    let _ = std::fs::remove_file(db_path);
+}
@@ -0,0 +1,298 @@
+use super::*;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU16, Ordering};
+
+type ReceivedWebhooks = Arc<Mutex<Vec<(HeaderMap, String)>>>;
+
+async fn spawn_webhook_receiver(status: Arc<AtomicU16>) -> (String, ReceivedWebhooks) {
+    let received: ReceivedWebhooks = Arc::new(Mutex::new(Vec::new()));
+    let app = Router::new().route(
+        "/hook",
+        post({
+            let received = received.clone();
+            move |headers: HeaderMap, body: String| {
+                let received = received.clone();
+                let status = status.clone();
+                async move {
+                    received.lock().unwrap().push((headers, body));
+                    StatusCode::from_u16(status.load(Ordering::SeqCst)).unwrap()
+                }
+            }
+        }),
+    );
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app.into_make_service())
+            .await
+            .unwrap();
+    });
+    (format!("http://{addr}/hook"), received)
+}
+
+async fn insert_rate_limit_alert(proxy: &TavilyProxy, token_id: &str, created_at: i64) {
+    sqlx::query("INSERT OR IGNORE INTO auth_tokens (id, secret, created_at) VALUES (?, ?, ?)")
+        .bind(token_id)
+        .bind(format!("secret-{token_id}"))
+        .bind(created_at)
+        .execute(&proxy.key_store.pool)
+        .await
+        .expect("insert webhook token");
+    sqlx::query(
+        r#"INSERT INTO auth_token_logs (
+             token_id, method, path, request_kind_key, request_kind_label,
+             request_kind_detail, result_status, error_message, key_effect_code,
+             binding_effect_code, selection_effect_code, counts_business_quota, created_at
+           ) VALUES (?, 'POST', '/mcp', 'mcp_call', 'MCP call', 'MCP call',
+                     'quota_exhausted', 'user request rate limit exceeded on rolling 5m window (limit 25, used 25)',
+                     'none', 'none', 'none', 0, ?)"#,
+    )
+    .bind(token_id)
+    .bind(created_at)
+    .execute(&proxy.key_store.pool)
+    .await
+    .expect("insert webhook alert");
+}
+
+async fn project_alerts_until_queued(proxy: &TavilyProxy, deliveries: usize) {
+    for _ in 0..48 {
+        proxy
+            .advance_dashboard_alert_projection_slice()
+            .await
+            .expect("advance alert projection slice");
+        let queued = proxy
+            .alert_webhook_deliveries(None, 50)
+            .await
+            .expect("list deliveries");
+        let status = proxy
+            .dashboard_alert_projection_status()
+            .await
+            .expect("projection status");
+        if queued.len() >= deliveries && status.coverage == "ok" {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    panic!("alert projection did not queue {deliveries} webhook deliveries");
+}
+
+fn sink_mutation(url: &str, template: &str) -> AlertWebhookSinkMutation {
+    AlertWebhookSinkMutation {
+        name: "On-call".to_string(),
+        url: url.to_string(),
+        template: template.to_string(),
+        alert_types: vec![ALERT_TYPE_USER_REQUEST_RATE_LIMITED.to_string()],
+        telegram_chat_id: None,
+        secret: Some("whsec_test".to_string()),
+        repeat_interval_secs: None,
+        enabled: true,
+    }
+}
+
+#[test]
+fn alert_webhook_sink_mutation_validates_template_types_and_chat() {
+    let valid = sink_mutation("https://hooks.example.com/x", " Slack ")
+        .normalized()
+        .expect("valid sink");
+    assert_eq!(valid.template, ALERT_WEBHOOK_TEMPLATE_SLACK);
+
+    let mut unknown_type = sink_mutation("https://hooks.example.com/x", "generic");
+    unknown_type.alert_types = vec!["not_an_alert".to_string()];
+    assert!(unknown_type.normalized().is_err());
+    assert!(
+        sink_mutation("ftp://hooks.example.com/x", "generic")
+            .normalized()
+            .is_err()
+    );
+    assert!(
+        sink_mutation("https://api.telegram.org/botX/sendMessage", "telegram")
+            .normalized()
+            .is_err(),
+        "telegram sinks need a chat id"
+    );
+
+    assert_eq!(alert_webhook_retry_delay_secs(1), 30);
+    assert_eq!(alert_webhook_retry_delay_secs(3), 120);
+    assert_eq!(alert_webhook_retry_delay_secs(20), 3_600);
+}
+
+#[test]
+fn alert_webhook_templates_render_slack_and_telegram_bodies() {
+    let mut sink = AlertWebhookSink {
+        id: "sink-1".to_string(),
+        name: "Ops".to_string(),
+        url: "https://hooks.slack.com/services/x".to_string(),
+        template: ALERT_WEBHOOK_TEMPLATE_SLACK.to_string(),
+        alert_types: Vec::new(),
+        telegram_chat_id: Some("-100200".to_string()),
+        secret: "whsec_test".to_string(),
+        repeat_interval_secs: 0,
+        enabled: true,
+        created_at: 0,
+        updated_at: 0,
+    };
+    let event = alert_webhook_test_event(&sink, 1_700_000_000);
+
+    let slack = render_alert_webhook_payload(&sink, &event);
+    let text = slack["text"].as_str().expect("slack text");
+    assert!(text.starts_with("[Tavily Hikari] Webhook test"));
+    assert!(text.contains("2023-11-14 22:13:20 UTC"));
+
+    sink.template = ALERT_WEBHOOK_TEMPLATE_TELEGRAM.to_string();
+    let telegram = render_alert_webhook_payload(&sink, &event);
+    assert_eq!(telegram["chat_id"], "-100200");
+    assert_eq!(telegram["text"].as_str(), Some(text));
+
+    sink.template = ALERT_WEBHOOK_TEMPLATE_GENERIC.to_string();
+    let generic = render_alert_webhook_payload(&sink, &event);
+    assert_eq!(generic["event"], "alert");
+    assert_eq!(
+        generic["alert"]["alert_type"],
+        ALERT_WEBHOOK_TEST_EVENT_TYPE
+    );
+}
+
+#[tokio::test]
+async fn projected_alerts_queue_signed_webhook_deliveries_once_per_repeat_window() {
+    let db_path = temp_db_path("alert-webhooks-delivery");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+        .await
+        .expect("proxy created");
+    let status = Arc::new(AtomicU16::new(200));
+    let (url, received) = spawn_webhook_receiver(status.clone()).await;
+    let sink = proxy
+        .create_alert_webhook_sink(sink_mutation(&url, "generic"))
+        .await
+        .expect("create sink");
+    let mut other_types = sink_mutation(&url, "generic");
+    other_types.alert_types = vec![ALERT_TYPE_JOB_FAILED.to_string()];
+    proxy
+        .create_alert_webhook_sink(other_types)
+        .await
+        .expect("create unsubscribed sink");
+
+    let now = Utc::now().timestamp();
+    insert_rate_limit_alert(&proxy, "hook", now).await;
+    insert_rate_limit_alert(&proxy, "hook", now + 1).await;
+    project_alerts_until_queued(&proxy, 1).await;
+
+    let queued = proxy
+        .alert_webhook_deliveries(None, 50)
+        .await
+        .expect("list deliveries");
+    assert_eq!(queued.len(), 1, "the repeat within 5 minutes is suppressed");
+    assert_eq!(queued[0].sink_id, sink.id);
+    assert_eq!(queued[0].status, ALERT_WEBHOOK_DELIVERY_PENDING);
+
+    assert_eq!(
+        proxy
+            .dispatch_alert_webhook_deliveries()
+            .await
+            .expect("dispatch"),
+        1
+    );
+    let (headers, body) = received.lock().unwrap().pop().expect("webhook received");
+    let timestamp: i64 = headers[ALERT_WEBHOOK_TIMESTAMP_HEADER]
+        .to_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert_eq!(
+        headers[ALERT_WEBHOOK_SIGNATURE_HEADER].to_str().unwrap(),
+        sign_alert_webhook_body("whsec_test", timestamp, body.as_bytes())
+    );
+    assert_eq!(
+        headers[ALERT_WEBHOOK_EVENT_HEADER].to_str().unwrap(),
+        ALERT_TYPE_USER_REQUEST_RATE_LIMITED
+    );
+    let payload: Value = serde_json::from_str(&body).expect("json payload");
+    assert_eq!(payload["alert"]["subject_id"], "hook");
+
+    let delivered = proxy
+        .alert_webhook_deliveries(Some(&sink.id), 10)
+        .await
+        .expect("list deliveries");
+    assert_eq!(delivered[0].status, ALERT_WEBHOOK_DELIVERY_DELIVERED);
+    assert_eq!(delivered[0].attempts, 1);
+    assert_eq!(delivered[0].last_status_code, Some(200));
+
+    let _ = std::fs::remove_file(db_path);
+}
+
+#[tokio::test]
+async fn webhook_deliveries_retry_server_errors_and_give_up_on_client_errors() {
+    let db_path = temp_db_path("alert-webhooks-retry");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+        .await
+        .expect("proxy created");
+    let status = Arc::new(AtomicU16::new(503));
+    let (url, received) = spawn_webhook_receiver(status.clone()).await;
+    let sink = proxy
+        .create_alert_webhook_sink(sink_mutation(&url, "slack"))
+        .await
+        .expect("create sink");
+
+    insert_rate_limit_alert(&proxy, "retry", Utc::now().timestamp()).await;
+    project_alerts_until_queued(&proxy, 1).await;
+    proxy
+        .dispatch_alert_webhook_deliveries()
+        .await
+        .expect("dispatch");
+    let retried = &proxy
+        .alert_webhook_deliveries(Some(&sink.id), 10)
+        .await
+        .expect("list deliveries")[0];
+    assert_eq!(retried.status, ALERT_WEBHOOK_DELIVERY_PENDING);
+    assert_eq!(retried.attempts, 1);
+    assert_eq!(retried.last_status_code, Some(503));
+    assert!(retried.next_attempt_at.expect("rescheduled") >= retried.updated_at + 30);
+    assert_eq!(
+        proxy
+            .dispatch_alert_webhook_deliveries()
+            .await
+            .expect("dispatch before backoff"),
+        0
+    );
+
+    status.store(400, Ordering::SeqCst);
+    let test = proxy
+        .test_fire_alert_webhook(&sink.id)
+        .await
+        .expect("test fire")
+        .expect("sink exists");
+    assert_eq!(test.status, ALERT_WEBHOOK_DELIVERY_FAILED);
+    assert_eq!(test.alert_type, ALERT_WEBHOOK_TEST_EVENT_TYPE);
+    assert!(
+        test.last_error
+            .as_deref()
+            .unwrap_or("")
+            .starts_with("HTTP 400")
+    );
+    let slack_body: Value =
+        serde_json::from_str(&received.lock().unwrap().last().expect("test received").1)
+            .expect("slack json");
+    assert!(
+        slack_body["text"]
+            .as_str()
+            .unwrap()
+            .contains("Webhook test")
+    );
+
+    assert!(
+        proxy
+            .delete_alert_webhook_sink(&sink.id)
+            .await
+            .expect("delete sink")
+    );
+    assert!(
+        proxy
+            .alert_webhook_deliveries(None, 10)
+            .await
+            .expect("list deliveries")
+            .is_empty()
+    );
+
+    let _ = std::fs::remove_file(db_path);
+}
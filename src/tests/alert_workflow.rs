@@ -0,0 +1,287 @@
+use super::*;
+
+async fn insert_token_rate_limit_alert(proxy: &TavilyProxy, token_id: &str, created_at: i64) {
+    sqlx::query("INSERT OR IGNORE INTO auth_tokens (id, secret, created_at) VALUES (?, ?, ?)")
+        .bind(token_id)
+        .bind(format!("secret-{token_id}"))
+        .bind(created_at)
+        .execute(&proxy.key_store.pool)
+        .await
+        .expect("insert workflow token");
+    sqlx::query(
+        r#"INSERT INTO auth_token_logs (
+             token_id, method, path, request_kind_key, request_kind_label,
+             request_kind_detail, result_status, error_message, key_effect_code,
+             binding_effect_code, selection_effect_code, counts_business_quota, created_at
+           ) VALUES (?, 'POST', '/mcp', 'mcp_call', 'MCP call', 'MCP call',
+                     'quota_exhausted', 'user request rate limit exceeded on rolling 5m window (limit 25, used 25)',
+                     'none', 'none', 'none', 0, ?)"#,
+    )
+    .bind(token_id)
+    .bind(created_at)
+    .execute(&proxy.key_store.pool)
+    .await
+    .expect("insert workflow alert");
+}
+
+async fn project_alerts_until_count(proxy: &TavilyProxy, events: i64) {
+    for _ in 0..48 {
+        proxy
+            .advance_dashboard_alert_projection_slice()
+            .await
+            .expect("advance alert projection slice");
+        let projected: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM observability.dashboard_alert_projection_events",
+        )
+        .fetch_one(&proxy.key_store.pool)
+        .await
+        .expect("count projected events");
+        let status = proxy
+            .dashboard_alert_projection_status()
+            .await
+            .expect("projection status");
+        if projected >= events && status.coverage == "ok" {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    panic!("alert projection did not reach {events} events");
+}
+
+async fn projected_silence_ids(proxy: &TavilyProxy) -> Vec<Option<String>> {
+    sqlx::query_scalar(
+        "SELECT silence_id FROM observability.dashboard_alert_projection_events ORDER BY occurred_at, source_id",
+    )
+    .fetch_all(&proxy.key_store.pool)
+    .await
+    .expect("read projected silence ids")
+}
+
+async fn token_alert_group(proxy: &TavilyProxy, token_id: &str) -> AlertGroupRecord {
+    proxy
+        .alert_groups_page(None, None, None, None, Some(token_id), None, &[], 1, 20)
+        .await
+        .expect("read alert groups")
+        .items
+        .into_iter()
+        .next()
+        .expect("token alert group")
+}
+
+fn token_silence(token_id: &str, now: i64) -> AlertSilenceMutation {
+    AlertSilenceMutation {
+        alert_type: None,
+        subject_kind: Some(" Token ".to_string()),
+        subject_id: Some(token_id.to_string()),
+        starts_at: Some(now - 60),
+        ends_at: now + 3_600,
+        note: Some("maintenance".to_string()),
+    }
+}
+
+#[test]
+fn alert_silence_mutation_validates_scope_and_window() {
+    let now = 1_700_000_000;
+    let valid = token_silence("tok", now).normalized(now).expect("valid");
+    assert_eq!(valid.subject_kind.as_deref(), Some(ALERT_SUBJECT_TOKEN));
+
+    let mut unscoped = token_silence("tok", now);
+    unscoped.subject_kind = None;
+    unscoped.subject_id = None;
+    assert!(unscoped.normalized(now).is_err(), "needs a type or subject");
+    let mut half_subject = token_silence("tok", now);
+    half_subject.subject_id = None;
+    assert!(half_subject.normalized(now).is_err());
+    let mut unknown_type = token_silence("tok", now);
+    unknown_type.alert_type = Some("not_an_alert".to_string());
+    assert!(unknown_type.normalized(now).is_err());
+    let mut ended = token_silence("tok", now);
+    ended.ends_at = now - 1;
+    assert!(ended.normalized(now).is_err());
+    let mut too_long = token_silence("tok", now);
+    too_long.ends_at = now + 31 * 24 * 60 * 60;
+    assert!(too_long.normalized(now).is_err());
+
+    let resolved = AlertGroupWorkflow::effective(
+        ALERT_GROUP_STATUS_RESOLVED,
+        Some("ops".to_string()),
+        None,
+        100,
+        100,
+    );
+    assert_eq!(resolved.status, ALERT_GROUP_STATUS_RESOLVED);
+    assert!(!resolved.reopened);
+    let reopened = AlertGroupWorkflow::effective(ALERT_GROUP_STATUS_RESOLVED, None, None, 100, 101);
+    assert_eq!(reopened.status, ALERT_GROUP_STATUS_OPEN);
+    assert!(reopened.reopened);
+    assert_eq!(alert_group_status_for_action("snooze"), None);
+}
+
+#[tokio::test]
+async fn silenced_alerts_are_recorded_but_skip_dashboard_counts_and_webhooks() {
+    let db_path = temp_db_path("alert-workflow-silence");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+        .await
+        .expect("proxy created");
+    proxy
+        .create_alert_webhook_sink(AlertWebhookSinkMutation {
+            name: "On-call".to_string(),
+            url: "https://hooks.example.com/x".to_string(),
+            template: ALERT_WEBHOOK_TEMPLATE_GENERIC.to_string(),
+            alert_types: Vec::new(),
+            telegram_chat_id: None,
+            secret: None,
+            repeat_interval_secs: None,
+            enabled: true,
+        })
+        .await
+        .expect("create sink");
+    // Sinks only notify events newer than themselves.
+    sqlx::query("UPDATE alert_webhook_sinks SET created_at = created_at - 3600")
+        .execute(&proxy.key_store.pool)
+        .await
+        .expect("backdate sink");
+
+    let now = Utc::now().timestamp();
+    let silence = proxy
+        .create_alert_silence(token_silence("muted", now), "ops")
+        .await
+        .expect("create silence");
+    insert_token_rate_limit_alert(&proxy, "muted", now - 2).await;
+    insert_token_rate_limit_alert(&proxy, "loud", now - 1).await;
+    project_alerts_until_count(&proxy, 2).await;
+
+    assert_eq!(
+        projected_silence_ids(&proxy).await,
+        vec![Some(silence.id.clone()), None],
+        "the silenced event is still recorded"
+    );
+    let deliveries = proxy
+        .alert_webhook_deliveries(None, 50)
+        .await
+        .expect("list deliveries");
+    assert_eq!(deliveries.len(), 1, "only the unsilenced alert is notified");
+
+    proxy
+        .key_store
+        .refresh_dashboard_alert_projection_summary()
+        .await
+        .expect("refresh summary");
+    let summary = proxy
+        .recent_alerts_summary(24)
+        .await
+        .expect("recent alerts summary");
+    assert_eq!(summary.total_events, 1);
+    assert_eq!(summary.grouped_count, 1);
+
+    let late = proxy
+        .create_alert_silence(token_silence("loud", now), "ops")
+        .await
+        .expect("create late silence");
+    assert_eq!(
+        projected_silence_ids(&proxy).await,
+        vec![Some(silence.id.clone()), Some(late.id.clone())],
+        "a new silence covers events already recorded in its window"
+    );
+
+    let listed = proxy.list_alert_silences(false).await.expect("list");
+    assert_eq!(listed.len(), 2);
+    assert!(
+        proxy
+            .expire_alert_silence(&late.id)
+            .await
+            .expect("expire")
+            .is_some()
+    );
+    assert_eq!(
+        proxy.list_alert_silences(false).await.expect("list").len(),
+        1
+    );
+    assert_eq!(
+        proxy.list_alert_silences(true).await.expect("list").len(),
+        2
+    );
+    assert!(
+        proxy
+            .expire_alert_silence("missing")
+            .await
+            .expect("expire missing")
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn resolved_alert_groups_reopen_when_new_events_arrive() {
+    let db_path = temp_db_path("alert-workflow-lifecycle");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+        .await
+        .expect("proxy created");
+    let now = Utc::now().timestamp();
+    insert_token_rate_limit_alert(&proxy, "flaky", now - 120).await;
+    project_alerts_until_count(&proxy, 1).await;
+
+    let group = token_alert_group(&proxy, "flaky").await;
+    assert_eq!(
+        group.workflow, None,
+        "groups start open without stored state"
+    );
+    assert!(
+        proxy
+            .apply_alert_group_action(&group.id, "snooze", "ops", None)
+            .await
+            .is_err()
+    );
+
+    let acked = proxy
+        .apply_alert_group_action(
+            &group.id,
+            ALERT_GROUP_ACTION_ACKNOWLEDGE,
+            "ops",
+            Some(" looking ".to_string()),
+        )
+        .await
+        .expect("acknowledge");
+    assert_eq!(acked.status, ALERT_GROUP_STATUS_ACKNOWLEDGED);
+    assert_eq!(acked.note.as_deref(), Some("looking"));
+    proxy
+        .apply_alert_group_action(&group.id, ALERT_GROUP_ACTION_RESOLVE, "ops", None)
+        .await
+        .expect("resolve");
+    let workflow = token_alert_group(&proxy, "flaky")
+        .await
+        .workflow
+        .expect("workflow attached");
+    assert_eq!(workflow.status, ALERT_GROUP_STATUS_RESOLVED);
+    assert_eq!(workflow.actor.as_deref(), Some("ops"));
+
+    sqlx::query("UPDATE alert_group_workflows SET updated_at = ? WHERE group_id = ?")
+        .bind(now - 60)
+        .bind(&group.id)
+        .execute(&proxy.key_store.pool)
+        .await
+        .expect("backdate resolution");
+    insert_token_rate_limit_alert(&proxy, "flaky", now).await;
+    project_alerts_until_count(&proxy, 2).await;
+    let workflow = token_alert_group(&proxy, "flaky")
+        .await
+        .workflow
+        .expect("workflow attached");
+    assert_eq!(workflow.status, ALERT_GROUP_STATUS_OPEN);
+    assert!(workflow.reopened);
+
+    let history = proxy
+        .alert_group_workflow_history(&group.id)
+        .await
+        .expect("history");
+    let mut actions = history
+        .iter()
+        .map(|event| event.action.as_str())
+        .collect::<Vec<_>>();
+    actions.sort_unstable();
+    assert_eq!(
+        actions,
+        vec![ALERT_GROUP_ACTION_ACKNOWLEDGE, ALERT_GROUP_ACTION_RESOLVE]
+    );
+}
@@ -0,0 +1,290 @@
+use super::*;
+
+const MASTER_KEY: [u8; 32] = *b"0123456789abcdef0123456789abcdef";
+const NEXT_MASTER_KEY: [u8; 32] = *b"fedcba9876543210fedcba9876543210";
+
+fn encrypted_options(db_str: &str, master_key: [u8; 32]) -> TavilyProxyOptions {
+    TavilyProxyOptions {
+        api_key_secret_cipher: Some(ApiKeySecretCipher::new(master_key)),
+        ..TavilyProxyOptions::from_database_path(db_str)
+    }
+}
+
+fn plaintext_options(db_str: &str) -> TavilyProxyOptions {
+    TavilyProxyOptions {
+        api_key_secret_cipher: None,
+        ..TavilyProxyOptions::from_database_path(db_str)
+    }
+}
+
+#[test]
+fn api_key_secret_cipher_round_trips_and_rewraps_without_touching_ciphertext() {
+    let cipher = ApiKeySecretCipher::new(MASTER_KEY);
+    let next = ApiKeySecretCipher::new(NEXT_MASTER_KEY);
+
+    let sealed = cipher.seal("tvly-secret-round-trip").expect("seal");
+    assert!(is_sealed_api_key_secret(&sealed));
+    assert!(!sealed.contains("tvly-secret-round-trip"));
+    assert_ne!(
+        sealed,
+        cipher.seal("tvly-secret-round-trip").expect("seal again"),
+        "each seal uses a fresh data key and nonce"
+    );
+    assert_eq!(
+        cipher.open(&sealed).expect("open"),
+        "tvly-secret-round-trip"
+    );
+    assert!(next.open(&sealed).is_err(), "wrong master key is rejected");
+
+    let rewrapped = cipher.rewrap(&sealed, &next).expect("rewrap");
+    assert_eq!(
+        rewrapped.rsplitn(3, '.').take(2).collect::<Vec<_>>(),
+        sealed.rsplitn(3, '.').take(2).collect::<Vec<_>>(),
+        "rotation only rewraps the data key"
+    );
+    assert_eq!(
+        next.open(&rewrapped).expect("open rewrapped"),
+        "tvly-secret-round-trip"
+    );
+    assert!(cipher.open(&rewrapped).is_err());
+
+    assert!(!format!("{cipher:?}").contains("0123456789abcdef"));
+    assert_eq!(
+        api_key_secret_fingerprint("tvly-secret-round-trip"),
+        api_key_secret_fingerprint("tvly-secret-round-trip")
+    );
+    assert!(api_key_secret_fingerprint("tvly-secret-round-trip").starts_with("sha256:"));
+}
+
+#[test]
+fn parse_api_key_secret_master_key_accepts_raw_and_base64_inputs() {
+    use base64::Engine as _;
+
+    let raw = String::from_utf8(MASTER_KEY.to_vec()).unwrap();
+    assert_eq!(
+        parse_api_key_secret_master_key(Some(&raw)).expect("raw key"),
+        Some(MASTER_KEY)
+    );
+    let encoded = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(MASTER_KEY);
+    assert_eq!(
+        parse_api_key_secret_master_key(Some(&encoded)).expect("base64url key"),
+        Some(MASTER_KEY)
+    );
+    assert_eq!(
+        parse_api_key_secret_master_key(Some("  ")).expect("blank"),
+        None
+    );
+    let err = parse_api_key_secret_master_key(Some("short-key")).expect_err("short key");
+    assert!(err.contains("API_KEY_SECRET_MASTER_KEY"));
+}
+
+#[tokio::test]
+async fn api_key_secret_encryption_migrates_plaintext_rows_and_scrubs_ha_outbox() {
+    let db_path = temp_db_path("api-key-secret-encryption-migrate");
+    let db_str = db_path.to_string_lossy().to_string();
+    let plaintext = TavilyProxy::with_options_in_ha_mode(
+        vec!["tvly-encrypt-existing".to_string()],
+        DEFAULT_UPSTREAM,
+        &db_str,
+        plaintext_options(&db_str),
+        HaMode::ActiveStandby,
+    )
+    .await
+    .expect("plaintext proxy created");
+    drop(plaintext);
+
+    let proxy = TavilyProxy::with_options_in_ha_mode(
+        vec!["tvly-encrypt-existing".to_string()],
+        DEFAULT_UPSTREAM,
+        &db_str,
+        encrypted_options(&db_str, MASTER_KEY),
+        HaMode::ActiveStandby,
+    )
+    .await
+    .expect("encrypted proxy created");
+    proxy
+        .add_or_undelete_key("tvly-encrypt-added")
+        .await
+        .expect("add key under encryption");
+
+    let rows: Vec<(String, String, Option<String>)> =
+        sqlx::query_as("SELECT id, api_key, api_key_ciphertext FROM api_keys ORDER BY api_key")
+            .fetch_all(&proxy.key_store.pool)
+            .await
+            .expect("read stored keys");
+    assert_eq!(rows.len(), 2);
+    for (_, lookup, ciphertext) in &rows {
+        assert!(
+            lookup.starts_with("sha256:"),
+            "lookup column holds a fingerprint"
+        );
+        assert!(is_sealed_api_key_secret(
+            ciphertext.as_deref().expect("ciphertext stored")
+        ));
+    }
+    let key_ids = rows.iter().map(|(id, _, _)| id.clone()).collect::<Vec<_>>();
+    let mut secrets = Vec::new();
+    for id in &key_ids {
+        secrets.push(
+            proxy
+                .key_store
+                .fetch_api_key_secret(id)
+                .await
+                .expect("fetch secret")
+                .expect("secret exists"),
+        );
+    }
+    secrets.sort();
+    assert_eq!(secrets, vec!["tvly-encrypt-added", "tvly-encrypt-existing"]);
+
+    let lease = proxy.key_store.acquire_key().await.expect("lease key");
+    assert!(lease.secret.starts_with("tvly-encrypt-"));
+    assert!(
+        proxy
+            .key_store
+            .mark_quota_exhausted(&lease.secret)
+            .await
+            .expect("mark exhausted by secret")
+    );
+    let existing = proxy
+        .fetch_active_existing_api_keys(&[
+            "tvly-encrypt-existing".to_string(),
+            "tvly-encrypt-missing".to_string(),
+        ])
+        .await
+        .expect("existing keys");
+    assert_eq!(
+        existing,
+        ["tvly-encrypt-existing".to_string()].into_iter().collect()
+    );
+
+    let leaked_events: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM ha_outbox WHERE resource = 'api_keys' AND payload_json LIKE '%tvly-encrypt-%'",
+    )
+    .fetch_one(&proxy.key_store.pool)
+    .await
+    .expect("scan ha outbox");
+    assert_eq!(leaked_events, 0, "HA outbox only carries ciphertext");
+    let api_key_events: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM ha_outbox WHERE resource = 'api_keys'")
+            .fetch_one(&proxy.key_store.pool)
+            .await
+            .expect("count api key events");
+    assert!(api_key_events > 0);
+
+    drop(proxy);
+    remove_db_files(&db_path);
+}
+
+#[tokio::test]
+async fn api_key_secret_encryption_requires_the_master_key_once_enabled() {
+    let db_path = temp_db_path("api-key-secret-encryption-required");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_options(
+        vec!["tvly-encrypt-required".to_string()],
+        DEFAULT_UPSTREAM,
+        &db_str,
+        encrypted_options(&db_str, MASTER_KEY),
+    )
+    .await
+    .expect("encrypted proxy created");
+    drop(proxy);
+
+    let err = TavilyProxy::with_options(
+        vec!["tvly-encrypt-required".to_string()],
+        DEFAULT_UPSTREAM,
+        &db_str,
+        plaintext_options(&db_str),
+    )
+    .await
+    .expect_err("startup without the master key fails");
+    assert!(
+        err.to_string().contains("API_KEY_SECRET_MASTER_KEY"),
+        "{err}"
+    );
+
+    let err = TavilyProxy::with_options(
+        vec!["tvly-encrypt-required".to_string()],
+        DEFAULT_UPSTREAM,
+        &db_str,
+        encrypted_options(&db_str, NEXT_MASTER_KEY),
+    )
+    .await
+    .expect_err("startup with the wrong master key fails");
+    assert!(
+        err.to_string().contains("API_KEY_SECRET_MASTER_KEY"),
+        "{err}"
+    );
+
+    remove_db_files(&db_path);
+}
+
+#[tokio::test]
+async fn api_key_secret_rotation_rewraps_rows_for_the_next_master_key() {
+    let db_path = temp_db_path("api-key-secret-encryption-rotate");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_options_in_ha_mode(
+        vec!["tvly-rotate-a".to_string(), "tvly-rotate-b".to_string()],
+        DEFAULT_UPSTREAM,
+        &db_str,
+        encrypted_options(&db_str, MASTER_KEY),
+        HaMode::ActiveStandby,
+    )
+    .await
+    .expect("encrypted proxy created");
+    drop(proxy);
+
+    let report = rotate_api_key_secret_master_key_once(&db_str, MASTER_KEY, NEXT_MASTER_KEY)
+        .await
+        .expect("rotate master key");
+    assert_eq!(report.rotated, 2);
+    assert!(
+        rotate_api_key_secret_master_key_once(&db_str, MASTER_KEY, NEXT_MASTER_KEY)
+            .await
+            .is_err(),
+        "a second rotation from the old key fails without partial writes"
+    );
+
+    let proxy = TavilyProxy::with_options(
+        vec!["tvly-rotate-a".to_string(), "tvly-rotate-b".to_string()],
+        DEFAULT_UPSTREAM,
+        &db_str,
+        encrypted_options(&db_str, NEXT_MASTER_KEY),
+    )
+    .await
+    .expect("proxy starts with the rotated master key");
+    let active_keys: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM api_keys WHERE deleted_at IS NULL")
+            .fetch_one(&proxy.key_store.pool)
+            .await
+            .expect("count active keys");
+    assert_eq!(active_keys, 2, "rotation keeps startup key sync stable");
+    let lease = proxy.key_store.acquire_key().await.expect("lease key");
+    assert!(lease.secret.starts_with("tvly-rotate-"));
+
+    let next = ApiKeySecretCipher::new(NEXT_MASTER_KEY);
+    let outbox_payloads: Vec<(String, Option<String>)> =
+        sqlx::query_as("SELECT payload_json, checksum FROM ha_outbox WHERE resource = 'api_keys'")
+            .fetch_all(&proxy.key_store.pool)
+            .await
+            .expect("read api key events");
+    assert!(!outbox_payloads.is_empty());
+    for (payload_json, checksum) in outbox_payloads {
+        let payload: serde_json::Value =
+            serde_json::from_str(&payload_json).expect("decode api key event");
+        let sealed = payload["api_key_ciphertext"]
+            .as_str()
+            .expect("event carries ciphertext");
+        assert!(
+            next.open(sealed)
+                .expect("event opens with the next master key")
+                .starts_with("tvly-rotate-")
+        );
+        if let Some(checksum) = checksum {
+            assert_eq!(checksum, sha256_hex_bytes(payload_json.as_bytes()));
+        }
+    }
+
+    drop(proxy);
+    remove_db_files(&db_path);
+}
@@ -0,0 +1,229 @@
+use super::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+async fn spawn_first_attempt_rate_limited_upstream(hits: Arc<AtomicUsize>) -> String {
+    let app = Router::new().route(
+        "/search",
+        post(move |Json(body): Json<Value>| {
+            let hits = hits.clone();
+            async move {
+                let api_key = body
+                    .get("api_key")
+                    .and_then(|value| value.as_str())
+                    .unwrap_or_default()
+                    .to_string();
+                if hits.fetch_add(1, Ordering::SeqCst) == 0 {
+                    (
+                        StatusCode::TOO_MANY_REQUESTS,
+                        Json(serde_json::json!({ "detail": { "error": "rate limited" } })),
+                    )
+                } else {
+                    (
+                        StatusCode::OK,
+                        Json(serde_json::json!({
+                            "results": [],
+                            "served_by": api_key,
+                            "usage": { "credits": 1 },
+                        })),
+                    )
+                }
+            }
+        }),
+    );
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app.into_make_service())
+            .await
+            .unwrap();
+    });
+    format!("http://{addr}")
+}
+
+async fn set_cross_key_retry(proxy: &TavilyProxy, enabled: bool) {
+    let settings = SystemSettings {
+        cross_key_retry: CrossKeyRetrySettings {
+            enabled,
+            ..CrossKeyRetrySettings::default()
+        },
+        ..proxy
+            .get_system_settings()
+            .await
+            .expect("get system settings")
+    };
+    proxy
+        .set_system_settings(&settings)
+        .await
+        .expect("persist cross-key retry settings");
+}
+
+async fn proxy_search(proxy: &TavilyProxy, usage_base: &str) -> (ProxyResponse, AttemptAnalysis) {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        axum::http::header::CONTENT_TYPE,
+        axum::http::HeaderValue::from_static("application/json"),
+    );
+    proxy
+        .proxy_http_json_endpoint(
+            usage_base,
+            "/search",
+            None,
+            false,
+            None,
+            None,
+            &Method::POST,
+            "/api/tavily/search",
+            serde_json::json!({ "query": "retry" }),
+            &headers,
+            false,
+            None,
+        )
+        .await
+        .expect("proxy search")
+}
+
+async fn logged_attempts(proxy: &TavilyProxy) -> Vec<(String, Option<i64>, String)> {
+    sqlx::query_as(
+        "SELECT api_key_id, status_code, selection_effect_code FROM request_logs ORDER BY id ASC",
+    )
+    .fetch_all(&proxy.key_store.pool)
+    .await
+    .expect("load request logs")
+}
+
+#[tokio::test]
+async fn cross_key_retry_replays_rate_limited_search_on_another_key() {
+    let db_path = temp_db_path("cross-key-retry-search");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(
+        vec![
+            "tvly-cross-retry-a".to_string(),
+            "tvly-cross-retry-b".to_string(),
+        ],
+        DEFAULT_UPSTREAM,
+        &db_str,
+    )
+    .await
+    .expect("proxy created");
+    set_cross_key_retry(&proxy, true).await;
+    let hits = Arc::new(AtomicUsize::new(0));
+    let usage_base = spawn_first_attempt_rate_limited_upstream(hits.clone()).await;
+
+    let (response, analysis) = proxy_search(&proxy, &usage_base).await;
+
+    assert_eq!(response.status, StatusCode::OK);
+    assert_eq!(analysis.status, OUTCOME_SUCCESS);
+    assert_eq!(hits.load(Ordering::SeqCst), 2);
+    assert_eq!(response.selection_effect_code, KEY_EFFECT_CROSS_KEY_RETRY);
+    let attempts = logged_attempts(&proxy).await;
+    assert_eq!(attempts.len(), 2, "every attempt gets its own request log");
+    assert_eq!(attempts[0].1, Some(429));
+    assert_eq!(attempts[0].2, KEY_EFFECT_NONE);
+    assert_eq!(attempts[1].1, Some(200));
+    assert_eq!(attempts[1].2, KEY_EFFECT_CROSS_KEY_RETRY);
+    assert_ne!(
+        attempts[0].0, attempts[1].0,
+        "retry must use a different key"
+    );
+    assert_eq!(response.api_key_id.as_deref(), Some(attempts[1].0.as_str()));
+
+    let _ = std::fs::remove_file(db_path);
+}
+
+#[tokio::test]
+async fn cross_key_retry_stays_off_until_enabled() {
+    let db_path = temp_db_path("cross-key-retry-disabled");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(
+        vec![
+            "tvly-cross-retry-off-a".to_string(),
+            "tvly-cross-retry-off-b".to_string(),
+        ],
+        DEFAULT_UPSTREAM,
+        &db_str,
+    )
+    .await
+    .expect("proxy created");
+    let hits = Arc::new(AtomicUsize::new(0));
+    let usage_base = spawn_first_attempt_rate_limited_upstream(hits.clone()).await;
+
+    let (response, _analysis) = proxy_search(&proxy, &usage_base).await;
+
+    assert_eq!(response.status, StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+    assert_eq!(logged_attempts(&proxy).await.len(), 1);
+
+    let _ = std::fs::remove_file(db_path);
+}
+
+#[tokio::test]
+async fn cross_key_retry_settings_reject_research_and_out_of_range_limits() {
+    let db_path = temp_db_path("cross-key-retry-validation");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+        .await
+        .expect("proxy created");
+    let current = proxy
+        .get_system_settings()
+        .await
+        .expect("get system settings");
+    assert_eq!(current.cross_key_retry, CrossKeyRetrySettings::default());
+    assert!(!current.cross_key_retry.enabled);
+
+    for cross_key_retry in [
+        CrossKeyRetrySettings {
+            endpoints: vec!["search".to_string(), "research".to_string()],
+            ..CrossKeyRetrySettings::default()
+        },
+        CrossKeyRetrySettings {
+            max_attempts: CROSS_KEY_RETRY_MAX_ATTEMPTS_MAX + 1,
+            ..CrossKeyRetrySettings::default()
+        },
+        CrossKeyRetrySettings {
+            budget_ms: CROSS_KEY_RETRY_BUDGET_MS_MIN - 1,
+            ..CrossKeyRetrySettings::default()
+        },
+    ] {
+        let err = proxy
+            .set_system_settings(&SystemSettings {
+                cross_key_retry,
+                ..current.clone()
+            })
+            .await
+            .expect_err("invalid cross-key retry settings rejected");
+        assert!(
+            matches!(err, ProxyError::Other(_)),
+            "unexpected error: {err}"
+        );
+    }
+
+    let saved = proxy
+        .set_system_settings(&SystemSettings {
+            cross_key_retry: CrossKeyRetrySettings {
+                enabled: true,
+                endpoints: vec![" /Map ".to_string(), "crawl".to_string(), "map".to_string()],
+                max_attempts: 2,
+                budget_ms: 1_000,
+            },
+            ..current.clone()
+        })
+        .await
+        .expect("save cross-key retry settings");
+    assert_eq!(
+        saved.cross_key_retry.endpoints,
+        vec!["crawl".to_string(), "map".to_string()]
+    );
+    assert!(saved.cross_key_retry.covers_upstream_path("/crawl"));
+    assert!(!saved.cross_key_retry.covers_upstream_path("/search"));
+    assert!(!saved.cross_key_retry.covers_upstream_path("/research"));
+    assert_eq!(
+        proxy
+            .get_system_settings()
+            .await
+            .expect("reload system settings")
+            .cross_key_retry,
+        saved.cross_key_retry
+    );
+
+    let _ = std::fs::remove_file(db_path);
+}
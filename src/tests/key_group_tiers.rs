@@ -0,0 +1,261 @@
+use super::*;
+
+async fn tiered_proxy(name: &str) -> (TavilyProxy, std::path::PathBuf) {
+    let db_path = temp_db_path(name);
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+        .await
+        .expect("proxy created");
+    (proxy, db_path)
+}
+
+async fn add_grouped_key(proxy: &TavilyProxy, secret: &str, group: Option<&str>) -> String {
+    proxy
+        .add_or_undelete_key_in_group(secret, group)
+        .await
+        .expect("add grouped key")
+}
+
+fn tier(group: &str, priority: i64) -> ApiKeyGroupTier {
+    ApiKeyGroupTier {
+        group: group.to_string(),
+        priority,
+    }
+}
+
+async fn arm_global_cooldown(proxy: &TavilyProxy, key_id: &str) {
+    let now = Utc::now().timestamp();
+    proxy
+        .key_store
+        .arm_api_key_transient_backoff(ApiKeyTransientBackoffArm {
+            key_id,
+            scope: HTTP_GLOBAL_BACKOFF_SCOPE,
+            cooldown_until: now + 300,
+            retry_after_secs: 300,
+            reason_code: Some(FAILURE_KIND_UPSTREAM_RATE_LIMITED_429),
+            source_request_log_id: None,
+            now,
+        })
+        .await
+        .expect("arm global cooldown");
+}
+
+#[tokio::test]
+async fn key_group_tiers_drain_higher_priority_before_overflowing() {
+    let (proxy, db_path) = tiered_proxy("key-group-tiers-overflow").await;
+    let primary_id = add_grouped_key(&proxy, "tvly-tier-primary", Some("primary")).await;
+    let overflow_id = add_grouped_key(&proxy, "tvly-tier-overflow", Some("overflow")).await;
+    let ungrouped_id = add_grouped_key(&proxy, "tvly-tier-ungrouped", None).await;
+    proxy
+        .replace_api_key_group_routing(ApiKeyGroupRouting {
+            tiers: vec![tier("primary", 10), tier("overflow", -5)],
+            bindings: Vec::new(),
+        })
+        .await
+        .expect("save tiers");
+
+    let token = proxy
+        .create_access_token(Some("tier-overflow"))
+        .await
+        .expect("create token");
+    for _ in 0..3 {
+        let lease = proxy.acquire_key_for(Some(&token.id)).await.expect("lease");
+        assert_eq!(
+            lease.id, primary_id,
+            "primary tier keeps serving while available"
+        );
+    }
+
+    arm_global_cooldown(&proxy, &primary_id).await;
+    let lease = proxy
+        .key_store
+        .acquire_key_avoiding_transient_backoff(HTTP_GLOBAL_BACKOFF_SCOPE)
+        .await
+        .expect("lease after primary cooled");
+    assert_eq!(
+        lease.id, ungrouped_id,
+        "tier 0 is preferred over negative tiers"
+    );
+
+    proxy
+        .key_store
+        .quarantine_key_by_id(
+            &ungrouped_id,
+            "/api/tavily/search",
+            "test",
+            "quarantined for tier overflow test",
+            "",
+        )
+        .await
+        .expect("quarantine ungrouped key");
+    let lease = proxy
+        .key_store
+        .acquire_key_avoiding_transient_backoff(HTTP_GLOBAL_BACKOFF_SCOPE)
+        .await
+        .expect("lease from overflow tier");
+    assert_eq!(lease.id, overflow_id);
+
+    let _ = std::fs::remove_file(db_path);
+}
+
+#[tokio::test]
+async fn key_group_bindings_restrict_tokens_to_bound_groups() {
+    let (proxy, db_path) = tiered_proxy("key-group-tiers-bindings").await;
+    let shared_id = add_grouped_key(&proxy, "tvly-binding-shared", Some("shared")).await;
+    let vip_id = add_grouped_key(&proxy, "tvly-binding-vip", Some("vip")).await;
+    proxy
+        .replace_api_key_group_routing(ApiKeyGroupRouting {
+            tiers: vec![tier("shared", 50)],
+            bindings: vec![ApiKeyGroupBinding {
+                kind: KEY_GROUP_BINDING_TOKEN_GROUP.to_string(),
+                value: "enterprise".to_string(),
+                key_groups: vec!["vip".to_string()],
+            }],
+        })
+        .await
+        .expect("save bindings");
+
+    let bound = proxy
+        .create_access_token(Some("bound"))
+        .await
+        .expect("create bound token");
+    sqlx::query("UPDATE auth_tokens SET group_name = 'enterprise' WHERE id = ?")
+        .bind(&bound.id)
+        .execute(&proxy.key_store.pool)
+        .await
+        .expect("assign token group");
+    let unbound = proxy
+        .create_access_token(Some("unbound"))
+        .await
+        .expect("create unbound token");
+
+    let lease = proxy
+        .acquire_key_for(Some(&bound.id))
+        .await
+        .expect("bound lease");
+    assert_eq!(
+        lease.id, vip_id,
+        "bindings win over the higher shared tier for bound tokens"
+    );
+    let lease = proxy
+        .acquire_key_for(Some(&unbound.id))
+        .await
+        .expect("unbound lease");
+    assert_eq!(lease.id, shared_id);
+
+    sqlx::query("UPDATE api_keys SET status = ? WHERE id = ?")
+        .bind(STATUS_DISABLED)
+        .bind(&vip_id)
+        .execute(&proxy.key_store.pool)
+        .await
+        .expect("disable vip key");
+    let err = proxy
+        .acquire_key_for(Some(&bound.id))
+        .await
+        .expect_err("bound token must not fall back outside its groups");
+    assert!(matches!(err, ProxyError::NoAvailableKeys));
+
+    let _ = std::fs::remove_file(db_path);
+}
+
+#[tokio::test]
+async fn key_group_routing_rejects_invalid_entries() {
+    let (proxy, db_path) = tiered_proxy("key-group-tiers-validation").await;
+    for routing in [
+        ApiKeyGroupRouting {
+            tiers: vec![tier("primary", API_KEY_GROUP_PRIORITY_MAX + 1)],
+            bindings: Vec::new(),
+        },
+        ApiKeyGroupRouting {
+            tiers: vec![tier("  ", 1)],
+            bindings: Vec::new(),
+        },
+        ApiKeyGroupRouting {
+            tiers: vec![tier("high", 3), tier(" high ", 4)],
+            bindings: Vec::new(),
+        },
+        ApiKeyGroupRouting {
+            tiers: Vec::new(),
+            bindings: vec![ApiKeyGroupBinding {
+                kind: "ip_range".to_string(),
+                value: "10.0.0.0/8".to_string(),
+                key_groups: vec!["primary".to_string()],
+            }],
+        },
+        ApiKeyGroupRouting {
+            tiers: Vec::new(),
+            bindings: vec![ApiKeyGroupBinding {
+                kind: KEY_GROUP_BINDING_USER_TAG.to_string(),
+                value: "vip".to_string(),
+                key_groups: Vec::new(),
+            }],
+        },
+    ] {
+        let err = proxy
+            .replace_api_key_group_routing(routing)
+            .await
+            .expect_err("invalid routing rejected");
+        assert!(
+            matches!(err, ProxyError::Other(_)),
+            "unexpected error: {err}"
+        );
+    }
+
+    let saved = proxy
+        .replace_api_key_group_routing(ApiKeyGroupRouting {
+            tiers: vec![tier(" low ", -1), tier("high", 4)],
+            bindings: Vec::new(),
+        })
+        .await
+        .expect("save normalized routing");
+    assert_eq!(saved.tiers, vec![tier("high", 4), tier("low", -1)]);
+    assert_eq!(
+        proxy
+            .get_api_key_group_routing()
+            .await
+            .expect("load routing"),
+        saved
+    );
+
+    let _ = std::fs::remove_file(db_path);
+}
+
+#[tokio::test]
+async fn key_tier_capacity_reports_per_tier_availability() {
+    let (proxy, db_path) = tiered_proxy("key-group-tiers-capacity").await;
+    let primary_a = add_grouped_key(&proxy, "tvly-capacity-primary-a", Some("primary")).await;
+    let _primary_b = add_grouped_key(&proxy, "tvly-capacity-primary-b", Some("primary")).await;
+    let _ungrouped = add_grouped_key(&proxy, "tvly-capacity-ungrouped", None).await;
+    let backup = add_grouped_key(&proxy, "tvly-capacity-backup", Some("backup")).await;
+    proxy
+        .replace_api_key_group_routing(ApiKeyGroupRouting {
+            tiers: vec![tier("primary", 5), tier("backup", -1)],
+            bindings: Vec::new(),
+        })
+        .await
+        .expect("save tiers");
+    arm_global_cooldown(&proxy, &primary_a).await;
+    sqlx::query("UPDATE api_keys SET status = ? WHERE id = ?")
+        .bind(STATUS_EXHAUSTED)
+        .bind(&backup)
+        .execute(&proxy.key_store.pool)
+        .await
+        .expect("exhaust backup key");
+
+    let summary = proxy.summary().await.expect("summary");
+    let tiers = summary.key_tiers;
+    assert_eq!(
+        tiers.iter().map(|tier| tier.priority).collect::<Vec<_>>(),
+        vec![5, 0, -1]
+    );
+    assert_eq!(tiers[0].groups, vec!["primary".to_string()]);
+    assert_eq!(tiers[0].total_keys, 2);
+    assert_eq!(tiers[0].available_keys, 1);
+    assert_eq!(tiers[0].cooling_keys, 1);
+    assert_eq!(tiers[1].groups, vec![String::new()]);
+    assert_eq!(tiers[1].available_keys, 1);
+    assert_eq!(tiers[2].exhausted_keys, 1);
+    assert_eq!(tiers[2].available_keys, 0);
+
+    let _ = std::fs::remove_file(db_path);
+}
@@ -0,0 +1,265 @@
+use super::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+async fn spawn_counting_search_upstream(hits: Arc<AtomicUsize>) -> String {
+    let app = Router::new().route(
+        "/search",
+        post(move || {
+            let hits = hits.clone();
+            async move {
+                hits.fetch_add(1, Ordering::SeqCst);
+                Json(serde_json::json!({ "results": [], "usage": { "credits": 1 } }))
+            }
+        }),
+    );
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app.into_make_service())
+            .await
+            .unwrap();
+    });
+    format!("http://{addr}")
+}
+
+async fn try_proxy_search(
+    proxy: &TavilyProxy,
+    usage_base: &str,
+) -> Result<(ProxyResponse, AttemptAnalysis), ProxyError> {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        axum::http::header::CONTENT_TYPE,
+        axum::http::HeaderValue::from_static("application/json"),
+    );
+    proxy
+        .proxy_http_json_endpoint(
+            usage_base,
+            "/search",
+            None,
+            false,
+            None,
+            None,
+            &Method::POST,
+            "/api/tavily/search",
+            serde_json::json!({ "query": "budget" }),
+            &headers,
+            false,
+            None,
+        )
+        .await
+}
+
+async fn key_ids(proxy: &TavilyProxy) -> Vec<String> {
+    let mut ids = proxy
+        .list_api_key_metrics()
+        .await
+        .expect("list api key metrics")
+        .into_iter()
+        .map(|key| key.id)
+        .collect::<Vec<_>>();
+    ids.sort();
+    ids
+}
+
+#[tokio::test]
+async fn key_rate_budget_rejects_requests_beyond_bounded_wait() {
+    let db_path = temp_db_path("key-rate-budget-reject");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(
+        vec!["tvly-rate-budget-only".to_string()],
+        DEFAULT_UPSTREAM,
+        &db_str,
+    )
+    .await
+    .expect("proxy created");
+    let key_id = key_ids(&proxy).await.remove(0);
+    assert!(
+        proxy
+            .set_api_key_rate_budget(&key_id, Some(1))
+            .await
+            .expect("set budget")
+    );
+    let hits = Arc::new(AtomicUsize::new(0));
+    let usage_base = spawn_counting_search_upstream(hits.clone()).await;
+
+    let (response, _analysis) = try_proxy_search(&proxy, &usage_base)
+        .await
+        .expect("first request fits the budget");
+    assert_eq!(response.status, StatusCode::OK);
+
+    let err = try_proxy_search(&proxy, &usage_base)
+        .await
+        .expect_err("second request exceeds the budget");
+    let ProxyError::KeyRateBudgetExhausted {
+        key_id: rejected_key_id,
+        retry_after_ms,
+    } = &err
+    else {
+        panic!("unexpected error: {err}");
+    };
+    assert_eq!(rejected_key_id, &key_id);
+    assert!(
+        *retry_after_ms > API_KEY_RATE_BUDGET_MAX_WAIT_MS as i64,
+        "requests that cannot be admitted within the bounded wait fail fast"
+    );
+    assert_eq!(
+        hits.load(Ordering::SeqCst),
+        1,
+        "rejected request never reaches upstream"
+    );
+
+    let usage = proxy.summary().await.expect("summary").key_rate_budgets;
+    assert_eq!(
+        usage,
+        vec![ApiKeyRateBudgetUsage {
+            key_id: key_id.clone(),
+            rpm_limit: 1,
+            used_last_minute: 1,
+            queued: 0,
+            delayed_total: 0,
+            rejected_total: 1,
+        }]
+    );
+
+    let _ = std::fs::remove_file(db_path);
+}
+
+#[tokio::test]
+async fn key_rate_budget_selection_prefers_keys_with_headroom() {
+    let db_path = temp_db_path("key-rate-budget-headroom");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(
+        vec![
+            "tvly-rate-budget-a".to_string(),
+            "tvly-rate-budget-b".to_string(),
+        ],
+        DEFAULT_UPSTREAM,
+        &db_str,
+    )
+    .await
+    .expect("proxy created");
+    let ids = key_ids(&proxy).await;
+    let (tight, roomy) = (ids[0].clone(), ids[1].clone());
+    proxy
+        .set_api_key_rate_budget(&tight, Some(1))
+        .await
+        .expect("set tight budget");
+    proxy
+        .set_api_key_rate_budget(&roomy, Some(100))
+        .await
+        .expect("set roomy budget");
+    let hits = Arc::new(AtomicUsize::new(0));
+    let usage_base = spawn_counting_search_upstream(hits.clone()).await;
+
+    // Spend the tight key's single slot; least-recently-used ordering reaches it within two calls.
+    for _ in 0..2 {
+        let (response, _analysis) = try_proxy_search(&proxy, &usage_base)
+            .await
+            .expect("warm-up request");
+        if response.api_key_id.as_deref() == Some(tight.as_str()) {
+            break;
+        }
+    }
+
+    for _ in 0..3 {
+        let (response, _analysis) = try_proxy_search(&proxy, &usage_base)
+            .await
+            .expect("request routed to key with headroom");
+        assert_eq!(response.api_key_id.as_deref(), Some(roomy.as_str()));
+    }
+    let usage = proxy.summary().await.expect("summary").key_rate_budgets;
+    assert_eq!(usage.len(), 2);
+    assert!(usage.iter().all(|budget| budget.rejected_total == 0));
+
+    let _ = std::fs::remove_file(db_path);
+}
+
+#[tokio::test]
+async fn key_rate_budget_validates_limits_and_unknown_keys() {
+    let db_path = temp_db_path("key-rate-budget-validation");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(
+        vec!["tvly-rate-budget-validate".to_string()],
+        DEFAULT_UPSTREAM,
+        &db_str,
+    )
+    .await
+    .expect("proxy created");
+    let key_id = key_ids(&proxy).await.remove(0);
+
+    for limit in [
+        API_KEY_RATE_BUDGET_RPM_MIN - 1,
+        API_KEY_RATE_BUDGET_RPM_MAX + 1,
+    ] {
+        let err = proxy
+            .set_api_key_rate_budget(&key_id, Some(limit))
+            .await
+            .expect_err("out-of-range budget rejected");
+        assert!(
+            matches!(err, ProxyError::Other(_)),
+            "unexpected error: {err}"
+        );
+    }
+    assert!(
+        !proxy
+            .set_api_key_rate_budget("missing-key", Some(10))
+            .await
+            .expect("unknown key")
+    );
+    assert!(
+        proxy
+            .summary()
+            .await
+            .expect("summary")
+            .key_rate_budgets
+            .is_empty()
+    );
+
+    let _ = std::fs::remove_file(db_path);
+}
+
+#[tokio::test]
+async fn key_rate_budget_queues_excess_requests_in_arrival_order() {
+    let db_path = temp_db_path("key-rate-budget-queue");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = Arc::new(
+        TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+            .await
+            .expect("proxy created"),
+    );
+    tokio::time::pause();
+    let max_wait = Duration::from_secs(90);
+    proxy
+        .debug_admit_key_rate_budget("queued-key", 1, max_wait)
+        .await
+        .expect("first request admitted immediately");
+
+    let started = tokio::time::Instant::now();
+    let mut waiters = Vec::new();
+    for _ in 0..2 {
+        let proxy = proxy.clone();
+        waiters.push(tokio::spawn(async move {
+            let result = proxy
+                .debug_admit_key_rate_budget("queued-key", 1, max_wait)
+                .await;
+            (result.is_ok(), started.elapsed())
+        }));
+        tokio::task::yield_now().await;
+    }
+    let (first_admitted, first_waited) = waiters.remove(0).await.expect("first waiter");
+    let (second_admitted, _) = waiters.remove(0).await.expect("second waiter");
+
+    assert!(first_admitted, "head of the queue gets the next free slot");
+    assert!(first_waited >= Duration::from_secs(60));
+    assert!(
+        !second_admitted,
+        "a slot beyond the bounded wait is rejected instead of queued"
+    );
+    let usage = proxy.debug_key_rate_budget_usage("queued-key", 1);
+    assert_eq!(usage.used_last_minute, 1);
+    assert_eq!(usage.queued, 0);
+    assert_eq!(usage.delayed_total, 1);
+    assert_eq!(usage.rejected_total, 1);
+
+    let _ = std::fs::remove_file(db_path);
+}
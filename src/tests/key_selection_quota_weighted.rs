@@ -0,0 +1,231 @@
+use super::*;
+
+async fn set_key_selection_mode(proxy: &TavilyProxy, mode: KeySelectionMode) {
+    let settings = SystemSettings {
+        key_selection_mode: mode,
+        ..proxy
+            .get_system_settings()
+            .await
+            .expect("get system settings")
+    };
+    proxy
+        .set_system_settings(&settings)
+        .await
+        .expect("persist key selection mode");
+}
+
+async fn seed_quota_sample(proxy: &TavilyProxy, key_id: &str, remaining: i64, captured_at: i64) {
+    sqlx::query(
+        r#"
+        INSERT INTO api_key_quota_sync_samples (
+            key_id,
+            quota_limit,
+            quota_remaining,
+            captured_at,
+            source
+        ) VALUES (?, 1000, ?, ?, 'key_selection_test')
+        "#,
+    )
+    .bind(key_id)
+    .bind(remaining)
+    .bind(captured_at)
+    .execute(&proxy.key_store.pool)
+    .await
+    .expect("seed quota sample");
+}
+
+async fn key_id_for_secret(proxy: &TavilyProxy, secret: &str) -> String {
+    sqlx::query_scalar("SELECT id FROM api_keys WHERE api_key = ?")
+        .bind(secret)
+        .fetch_one(&proxy.key_store.pool)
+        .await
+        .expect("key id")
+}
+
+#[tokio::test]
+async fn quota_weighted_selection_prefers_most_remaining_credits_over_lru() {
+    let db_path = temp_db_path("key-selection-quota-weighted");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(
+        vec![
+            "tvly-key-selection-low".to_string(),
+            "tvly-key-selection-high".to_string(),
+            "tvly-key-selection-mid".to_string(),
+        ],
+        DEFAULT_UPSTREAM,
+        &db_str,
+    )
+    .await
+    .expect("proxy created");
+
+    let low_id = key_id_for_secret(&proxy, "tvly-key-selection-low").await;
+    let high_id = key_id_for_secret(&proxy, "tvly-key-selection-high").await;
+    let mid_id = key_id_for_secret(&proxy, "tvly-key-selection-mid").await;
+    for (key_id, last_used_at) in [(&low_id, 10_i64), (&high_id, 30), (&mid_id, 20)] {
+        sqlx::query("UPDATE api_keys SET last_used_at = ? WHERE id = ?")
+            .bind(last_used_at)
+            .bind(key_id)
+            .execute(&proxy.key_store.pool)
+            .await
+            .expect("seed last_used_at");
+    }
+    let now = Utc::now().timestamp();
+    // An older sample must not outrank the latest one for the same key.
+    seed_quota_sample(&proxy, &low_id, 990, now - 7_200).await;
+    seed_quota_sample(&proxy, &low_id, 50, now - 600).await;
+    seed_quota_sample(&proxy, &high_id, 900, now - 600).await;
+    seed_quota_sample(&proxy, &mid_id, 400, now - 600).await;
+
+    let lease = proxy.key_store.acquire_key().await.expect("lru lease");
+    assert_eq!(lease.id, low_id, "LRU mode keeps least-recently-used order");
+
+    set_key_selection_mode(&proxy, KeySelectionMode::QuotaWeighted).await;
+    assert_eq!(
+        proxy
+            .get_system_settings()
+            .await
+            .expect("settings")
+            .key_selection_mode,
+        KeySelectionMode::QuotaWeighted
+    );
+
+    let lease = proxy.key_store.acquire_key().await.expect("weighted lease");
+    assert_eq!(lease.id, high_id);
+    let lease = proxy
+        .key_store
+        .acquire_key_avoiding_transient_backoff(HTTP_GLOBAL_BACKOFF_SCOPE)
+        .await
+        .expect("weighted lease avoiding backoff");
+    assert_eq!(lease.id, high_id);
+    let lease = proxy
+        .key_store
+        .acquire_active_key_excluding(Some(&high_id), &KeyGroupRoute::default())
+        .await
+        .expect("weighted lease excluding best key");
+    assert_eq!(lease.id, mid_id);
+
+    let _ = std::fs::remove_file(db_path);
+}
+
+#[tokio::test]
+async fn quota_weighted_selection_subtracts_recently_billed_credits() {
+    let db_path = temp_db_path("key-selection-quota-pressure");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(
+        vec![
+            "tvly-key-selection-busy".to_string(),
+            "tvly-key-selection-idle".to_string(),
+        ],
+        DEFAULT_UPSTREAM,
+        &db_str,
+    )
+    .await
+    .expect("proxy created");
+    set_key_selection_mode(&proxy, KeySelectionMode::QuotaWeighted).await;
+
+    let busy_id = key_id_for_secret(&proxy, "tvly-key-selection-busy").await;
+    let idle_id = key_id_for_secret(&proxy, "tvly-key-selection-idle").await;
+    let now = Utc::now().timestamp();
+    seed_quota_sample(&proxy, &busy_id, 500, now - 300).await;
+    seed_quota_sample(&proxy, &idle_id, 450, now - 300).await;
+
+    let lease = proxy.key_store.acquire_key().await.expect("weighted lease");
+    assert_eq!(lease.id, busy_id);
+
+    insert_summary_window_charged_logs(&proxy, &busy_id, now - 120, 2, 40).await;
+    let scores = proxy
+        .key_store
+        .list_api_key_quota_selection_scores(&[busy_id.clone(), idle_id.clone()], now)
+        .await
+        .expect("quota scores");
+    assert_eq!(scores.get(&busy_id).copied(), Some(420));
+    assert_eq!(scores.get(&idle_id).copied(), Some(450));
+
+    let lease = proxy.key_store.acquire_key().await.expect("weighted lease");
+    assert_eq!(lease.id, idle_id);
+
+    let _ = std::fs::remove_file(db_path);
+}
+
+#[tokio::test]
+async fn http_project_affinity_candidates_rank_by_quota_score_in_quota_weighted_mode() {
+    let db_path = temp_db_path("key-selection-quota-affinity");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(
+        vec![
+            "tvly-key-selection-affinity-low".to_string(),
+            "tvly-key-selection-affinity-high".to_string(),
+        ],
+        DEFAULT_UPSTREAM,
+        &db_str,
+    )
+    .await
+    .expect("proxy created");
+
+    let low_id = key_id_for_secret(&proxy, "tvly-key-selection-affinity-low").await;
+    let high_id = key_id_for_secret(&proxy, "tvly-key-selection-affinity-high").await;
+    let now = Utc::now().timestamp();
+    seed_quota_sample(&proxy, &low_id, 50, now - 600).await;
+    seed_quota_sample(&proxy, &high_id, 900, now - 600).await;
+    let ranked = vec![low_id.clone(), high_id.clone()];
+
+    let candidates = proxy
+        .build_http_project_affinity_candidates(&ranked, now)
+        .await
+        .expect("lru affinity candidates");
+    assert_eq!(
+        candidates[0].key_id, low_id,
+        "LRU mode keeps the stable rank"
+    );
+    assert!(
+        candidates
+            .iter()
+            .all(|candidate| candidate.quota_score.is_none())
+    );
+
+    set_key_selection_mode(&proxy, KeySelectionMode::QuotaWeighted).await;
+    let candidates = proxy
+        .build_http_project_affinity_candidates(&ranked, now)
+        .await
+        .expect("weighted affinity candidates");
+    assert_eq!(
+        candidates
+            .iter()
+            .map(|candidate| (candidate.key_id.clone(), candidate.quota_score))
+            .collect::<Vec<_>>(),
+        vec![(high_id, Some(900)), (low_id, Some(50))]
+    );
+
+    let _ = std::fs::remove_file(db_path);
+}
+
+#[test]
+fn http_project_affinity_candidate_order_prefers_higher_quota_score_before_pressure() {
+    let candidate = |key_id: &str, stable_rank_index, quota_score, recent_billable| {
+        HttpProjectAffinityCandidate {
+            key_id: key_id.to_string(),
+            stable_rank_index,
+            cooldown_until: None,
+            recent_rate_limited_count: 0,
+            recent_billable_request_count: recent_billable,
+            quota_score,
+            last_used_at: 0,
+        }
+    };
+    let mut candidates = vec![
+        candidate("unknown", 0, None, 0),
+        candidate("low", 1, Some(50), 0),
+        candidate("high", 2, Some(900), 4),
+        candidate("mid", 3, Some(400), 0),
+    ];
+
+    TavilyProxy::order_http_project_affinity_candidates(&mut candidates);
+
+    assert_eq!(
+        candidates
+            .iter()
+            .map(|candidate| candidate.key_id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["high", "mid", "low", "unknown"]
+    );
+}
@@ -763,6 +763,7 @@ fn http_project_affinity_candidate_order_prefers_cooldown_then_rate_limit_then_p
             cooldown_until: Some(200),
             recent_rate_limited_count: 0,
             recent_billable_request_count: 0,
+            quota_score: None,
             last_used_at: 100,
         },
         HttpProjectAffinityCandidate {
@@ -771,6 +772,7 @@ fn http_project_affinity_candidate_order_prefers_cooldown_then_rate_limit_then_p
             cooldown_until: None,
             recent_rate_limited_count: 1,
             recent_billable_request_count: 1,
+            quota_score: None,
             last_used_at: 30,
         },
         HttpProjectAffinityCandidate {
@@ -779,6 +781,7 @@ fn http_project_affinity_candidate_order_prefers_cooldown_then_rate_limit_then_p
             cooldown_until: None,
             recent_rate_limited_count: 0,
             recent_billable_request_count: 5,
+            quota_score: None,
             last_used_at: 10,
         },
         HttpProjectAffinityCandidate {
@@ -787,6 +790,7 @@ fn http_project_affinity_candidate_order_prefers_cooldown_then_rate_limit_then_p
             cooldown_until: None,
             recent_rate_limited_count: 0,
             recent_billable_request_count: 1,
+            quota_score: None,
             last_used_at: 5,
         },
     ];
@@ -21,30 +21,52 @@ use std::sync::{Arc, OnceLock};
 use std::time::Duration;
 use tokio::net::TcpListener;
 
+mod access_token_lifetime;
+mod access_token_scopes;
+mod access_token_secret_hashing;
 mod account_quota_and_billing;
 mod account_quota_schema_migration;
 mod account_usage_rollup_request_days;
+mod admin_accounts;
+mod admin_api_tokens;
+mod admin_audit;
 mod alert_projection;
+mod alert_rules;
+mod alert_webhooks;
+mod alert_workflow;
+mod api_key_secret_encryption;
+mod cross_key_retry;
 mod dashboard_hourly_credits;
 mod dashboard_month_series;
 mod dashboard_rollup_integrity;
 mod ha_baseline_streaming_and_sessions;
 mod ha_outbox_and_compaction;
 mod jobs_and_request_log_retention;
+mod key_group_tiers;
+mod key_rate_budget;
+mod key_selection_quota_weighted;
 mod linuxdo_credit_recharge;
 mod maintenance_and_mcp_affinity;
 mod maintenance_control_admission;
 mod maintenance_queue_performance;
 mod observability_and_lifecycle;
+mod prometheus_metrics;
 mod proxy_affinity_and_summary;
 mod proxy_affinity_runtime_geo;
 mod reconciliation_controller;
+mod registration_invites;
+mod request_coalescing;
 mod request_kind_and_core;
+mod request_log_replay;
+mod request_log_search;
 mod request_logs_gc_admission;
+mod request_parameter_policies;
 mod request_rollup;
 mod request_rollup_public_metrics;
+mod response_cache;
 mod schema_migrations;
 mod support;
+mod teams;
 mod upstream_reconciliation;
 mod upstream_reconciliation_continuation;
 mod upstream_reconciliation_engine;
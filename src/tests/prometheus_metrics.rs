@@ -0,0 +1,60 @@
+use super::*;
+
+#[tokio::test]
+async fn prometheus_metrics_render_counters_histograms_and_key_state() {
+    let db_path = temp_db_path("prometheus-metrics-render");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_options(
+        vec!["tvly-metrics".to_string()],
+        DEFAULT_UPSTREAM,
+        &db_str,
+        TavilyProxyOptions {
+            metrics_bearer_token: Some(" scrape-secret ".to_string()),
+            ..TavilyProxyOptions::from_database_path(&db_str)
+        },
+    )
+    .await
+    .expect("proxy created");
+    assert!(proxy.metrics_bearer_token_matches(Some("scrape-secret")));
+    assert!(!proxy.metrics_bearer_token_matches(Some("other")));
+    assert!(!proxy.metrics_bearer_token_matches(None));
+
+    let metrics = &proxy.key_store.process_metrics;
+    metrics.record_request("api:search", OUTCOME_SUCCESS);
+    metrics.record_request("api:search", OUTCOME_SUCCESS);
+    // Raw MCP tool names fold into their canonical request kind.
+    metrics.record_request("mcp:tool:some_vendor_tool", OUTCOME_ERROR);
+    metrics.observe_upstream_latency("search", "ok", Duration::from_millis(200));
+    metrics.observe_upstream_latency("search", "ok", Duration::from_secs(3));
+    sqlx::query("UPDATE api_keys SET group_name = ?, quota_limit = 1000, quota_remaining = 640")
+        .bind("team \"a\"")
+        .execute(&proxy.key_store.pool)
+        .await
+        .expect("set key quota");
+
+    let ha = HaRuntime::new(HaConfig::default());
+    let text = proxy
+        .render_prometheus_metrics(&ha)
+        .await
+        .expect("render metrics");
+    for expected in [
+        "# TYPE tavily_hikari_requests_total counter",
+        r#"tavily_hikari_requests_total{request_kind="api:search",outcome="success"} 2"#,
+        r#"tavily_hikari_requests_total{request_kind="mcp:third-party-tool",outcome="error"} 1"#,
+        "# TYPE tavily_hikari_upstream_request_duration_seconds histogram",
+        r#"tavily_hikari_upstream_request_duration_seconds_bucket{operation="search",result="ok",le="0.25"} 1"#,
+        r#"tavily_hikari_upstream_request_duration_seconds_bucket{operation="search",result="ok",le="5"} 2"#,
+        r#"tavily_hikari_upstream_request_duration_seconds_bucket{operation="search",result="ok",le="+Inf"} 2"#,
+        r#"tavily_hikari_upstream_request_duration_seconds_count{operation="search",result="ok"} 2"#,
+        r#"group="team \"a\"",status="active"} 1"#,
+        r#"group="team \"a\""} 640"#,
+        r#"tavily_hikari_ha_role{mode="single",node_id="single",role="full_master"} 1"#,
+        r#"tavily_hikari_ha_role{mode="single",node_id="single",role="standby"} 0"#,
+        "tavily_hikari_sqlite_pool_max_connections ",
+        "# TYPE tavily_hikari_scheduled_job_runs gauge",
+    ] {
+        assert!(text.contains(expected), "missing {expected:?} in:\n{text}");
+    }
+
+    let _ = std::fs::remove_file(db_path);
+}
@@ -0,0 +1,343 @@
+use super::*;
+
+fn invite_profile(provider_user_id: &str) -> OAuthAccountProfile {
+    OAuthAccountProfile {
+        provider: "linuxdo".to_string(),
+        provider_user_id: provider_user_id.to_string(),
+        username: Some(provider_user_id.to_string()),
+        name: None,
+        avatar_template: None,
+        active: true,
+        trust_level: None,
+        raw_payload_json: None,
+    }
+}
+
+#[tokio::test]
+async fn registration_invite_claims_stop_at_max_uses_and_after_revocation() {
+    let db_path = temp_db_path("registration-invite-claims");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+        .await
+        .expect("proxy created");
+
+    let invite = proxy
+        .create_registration_invite(&RegistrationInviteInput {
+            code: Some("team-alpha".to_string()),
+            max_uses: 2,
+            ..Default::default()
+        })
+        .await
+        .expect("create invite")
+        .expect("invite code is free");
+    assert_eq!(invite.code, "TEAM-ALPHA");
+    assert!(
+        proxy
+            .create_registration_invite(&RegistrationInviteInput {
+                code: Some("TEAM-ALPHA".to_string()),
+                max_uses: 1,
+                ..Default::default()
+            })
+            .await
+            .expect("create duplicate invite")
+            .is_none(),
+        "codes are unique case-insensitively"
+    );
+
+    for _ in 0..2 {
+        assert!(
+            proxy
+                .claim_registration_invite(" team-alpha ")
+                .await
+                .expect("claim invite")
+                .is_some()
+        );
+    }
+    assert!(
+        proxy
+            .claim_registration_invite("TEAM-ALPHA")
+            .await
+            .expect("claim exhausted invite")
+            .is_none()
+    );
+    let listed = proxy
+        .list_registration_invites()
+        .await
+        .expect("list invites");
+    assert_eq!(listed[0].use_count, 2);
+    assert_eq!(listed[0].status(Utc::now().timestamp()), "exhausted");
+
+    let generated = proxy
+        .create_registration_invite(&RegistrationInviteInput {
+            max_uses: 5,
+            ..Default::default()
+        })
+        .await
+        .expect("create generated invite")
+        .expect("generated code");
+    assert_eq!(generated.code.len(), 12);
+    let revoked = proxy
+        .revoke_registration_invite(&generated.id)
+        .await
+        .expect("revoke invite")
+        .expect("invite exists");
+    assert!(revoked.revoked_at.is_some());
+    assert!(
+        proxy
+            .claim_registration_invite(&generated.code)
+            .await
+            .expect("claim revoked invite")
+            .is_none()
+    );
+    assert!(
+        proxy
+            .claim_registration_invite("no such code!")
+            .await
+            .expect("claim malformed code")
+            .is_none()
+    );
+
+    let _ = std::fs::remove_file(db_path);
+}
+
+#[tokio::test]
+async fn registration_invite_rejects_expired_codes_and_invalid_input() {
+    let db_path = temp_db_path("registration-invite-expiry");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+        .await
+        .expect("proxy created");
+    let now = Utc::now().timestamp();
+
+    let invite = proxy
+        .create_registration_invite(&RegistrationInviteInput {
+            code: Some("SHORT-LIVED".to_string()),
+            max_uses: 3,
+            expires_at: Some(now + 3600),
+            ..Default::default()
+        })
+        .await
+        .expect("create invite")
+        .expect("invite code is free");
+    sqlx::query("UPDATE registration_invites SET expires_at = ? WHERE id = ?")
+        .bind(now - 1)
+        .bind(&invite.id)
+        .execute(&proxy.key_store.pool)
+        .await
+        .expect("expire invite");
+    assert!(
+        proxy
+            .claim_registration_invite("SHORT-LIVED")
+            .await
+            .expect("claim expired invite")
+            .is_none()
+    );
+
+    let base = RegistrationInviteInput {
+        max_uses: 1,
+        ..Default::default()
+    };
+    for input in [
+        RegistrationInviteInput {
+            max_uses: 0,
+            ..base.clone()
+        },
+        RegistrationInviteInput {
+            expires_at: Some(now - 10),
+            ..base.clone()
+        },
+        RegistrationInviteInput {
+            code: Some("ab".to_string()),
+            ..base.clone()
+        },
+        RegistrationInviteInput {
+            quota: Some(RegistrationInviteQuota {
+                business_calls_1h_limit: -1,
+                daily_credits_limit: 0,
+                monthly_credits_limit: 0,
+            }),
+            ..base.clone()
+        },
+    ] {
+        assert!(validate_registration_invite_input(&input, now).is_err());
+    }
+    assert!(validate_registration_invite_input(&base, now).is_ok());
+
+    let _ = std::fs::remove_file(db_path);
+}
+
+#[tokio::test]
+async fn registration_invite_applies_tags_and_quota_to_the_new_account() {
+    let db_path = temp_db_path("registration-invite-apply");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+        .await
+        .expect("proxy created");
+    let tag = proxy
+        .create_user_tag(
+            "invite_cohort",
+            "Invite Cohort",
+            None,
+            USER_TAG_EFFECT_QUOTA_DELTA,
+            1,
+            2,
+            3,
+        )
+        .await
+        .expect("create tag");
+    proxy
+        .create_registration_invite(&RegistrationInviteInput {
+            code: Some("COHORT-1".to_string()),
+            max_uses: 1,
+            tag_ids: vec![tag.id.clone()],
+            quota: Some(RegistrationInviteQuota {
+                business_calls_1h_limit: 40,
+                daily_credits_limit: 500,
+                monthly_credits_limit: 9000,
+            }),
+            ..Default::default()
+        })
+        .await
+        .expect("create invite")
+        .expect("invite code is free");
+
+    let invite = proxy
+        .claim_registration_invite("cohort-1")
+        .await
+        .expect("claim invite")
+        .expect("invite usable");
+    let user = proxy
+        .upsert_oauth_account(&invite_profile("invited-user"))
+        .await
+        .expect("upsert user");
+    proxy
+        .apply_registration_invite(&invite, &user.user_id)
+        .await
+        .expect("apply invite");
+
+    let tags = proxy
+        .list_user_tag_bindings_for_users(std::slice::from_ref(&user.user_id))
+        .await
+        .expect("list tags");
+    assert!(
+        tags.get(&user.user_id)
+            .is_some_and(|bindings| bindings.iter().any(|binding| binding.tag_id == tag.id))
+    );
+    let resolution = proxy
+        .key_store
+        .resolve_account_quota_resolution(&user.user_id)
+        .await
+        .expect("resolve quota");
+    assert_eq!(resolution.base.business_calls_1h_limit, 40);
+    assert_eq!(resolution.base.daily_credits_limit, 500);
+    assert_eq!(resolution.base.monthly_credits_limit, 9000);
+    let redemptions: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM registration_invite_redemptions WHERE invite_id = ? AND user_id = ?",
+    )
+    .bind(&invite.id)
+    .bind(&user.user_id)
+    .fetch_one(&proxy.key_store.pool)
+    .await
+    .expect("count redemptions");
+    assert_eq!(redemptions, 1);
+
+    let _ = std::fs::remove_file(db_path);
+}
+
+#[tokio::test]
+async fn registration_approval_queue_tracks_pending_users_until_approved() {
+    let db_path = temp_db_path("registration-approval-queue");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+        .await
+        .expect("proxy created");
+    assert!(
+        !proxy
+            .registration_requires_approval()
+            .await
+            .expect("read default")
+    );
+    assert!(
+        proxy
+            .set_registration_requires_approval(true)
+            .await
+            .expect("enable approval")
+    );
+    assert!(
+        proxy
+            .registration_requires_approval()
+            .await
+            .expect("read approval")
+    );
+
+    let pending = proxy
+        .upsert_oauth_account(&invite_profile("pending-user"))
+        .await
+        .expect("upsert pending user");
+    let other = proxy
+        .upsert_oauth_account(&invite_profile("other-user"))
+        .await
+        .expect("upsert other user");
+    proxy
+        .mark_user_pending_approval(&pending.user_id)
+        .await
+        .expect("mark pending");
+
+    assert!(
+        proxy
+            .user_pending_approval(&pending.user_id)
+            .await
+            .expect("read pending")
+    );
+    assert!(
+        !proxy
+            .user_pending_approval(&other.user_id)
+            .await
+            .expect("read other")
+    );
+    let ids = proxy
+        .pending_approval_user_ids(&[pending.user_id.clone(), other.user_id.clone()])
+        .await
+        .expect("pending ids");
+    assert_eq!(ids.len(), 1);
+    assert!(ids.contains(&pending.user_id));
+    let queue = proxy
+        .list_pending_approval_users()
+        .await
+        .expect("list pending");
+    assert_eq!(queue.len(), 1);
+    assert_eq!(queue[0].provider.as_deref(), Some("linuxdo"));
+    assert_eq!(queue[0].username.as_deref(), Some("pending-user"));
+
+    assert!(
+        proxy
+            .approve_user_registration(&pending.user_id)
+            .await
+            .expect("approve")
+    );
+    assert!(
+        !proxy
+            .approve_user_registration(&pending.user_id)
+            .await
+            .expect("approve twice")
+    );
+    assert!(
+        !proxy
+            .user_pending_approval(&pending.user_id)
+            .await
+            .expect("read approved")
+    );
+    proxy
+        .mark_user_pending_approval(&pending.user_id)
+        .await
+        .expect("re-mark approved user");
+    assert!(
+        !proxy
+            .user_pending_approval(&pending.user_id)
+            .await
+            .expect("approval is sticky"),
+        "an approved account never returns to the queue"
+    );
+
+    let _ = std::fs::remove_file(db_path);
+}
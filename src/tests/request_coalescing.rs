@@ -0,0 +1,96 @@
+use super::*;
+
+fn shared_search_response(credits: i64) -> SharedUpstreamResponse {
+    SharedUpstreamResponse {
+        status: StatusCode::OK,
+        content_type: Some("application/json".to_string()),
+        body: Bytes::from(format!(
+            r#"{{"results":[],"usage":{{"credits":{credits}}}}}"#
+        )),
+        credits,
+    }
+}
+
+#[test]
+fn request_coalescing_settings_normalize_and_price_followers() {
+    let settings = normalize_request_coalescing_settings(&RequestCoalescingSettings {
+        enabled: true,
+        endpoints: vec![
+            " /Map ".to_string(),
+            "search".to_string(),
+            "map".to_string(),
+        ],
+        follower_billing_percent: 50,
+    })
+    .expect("valid settings");
+    assert_eq!(settings.endpoints, vec!["search", "map"]);
+    assert!(settings.covers_upstream_path("/search"));
+    assert!(!settings.covers_upstream_path("/extract"));
+    assert!(!settings.covers_upstream_path("/research"));
+    assert_eq!(settings.follower_credits(3), 2);
+    assert_eq!(RequestCoalescingSettings::default().follower_credits(3), 3);
+    assert_eq!(
+        request_coalescing_key("/search", &serde_json::json!({ "a": 1, "b": null })),
+        request_coalescing_key("search", &serde_json::json!({ "a": 1 }))
+    );
+
+    for invalid in [
+        RequestCoalescingSettings {
+            endpoints: vec!["research".to_string()],
+            ..RequestCoalescingSettings::default()
+        },
+        RequestCoalescingSettings {
+            follower_billing_percent: -1,
+            ..RequestCoalescingSettings::default()
+        },
+    ] {
+        assert!(normalize_request_coalescing_settings(&invalid).is_err());
+    }
+}
+
+#[tokio::test]
+async fn request_flights_fan_out_published_answers_and_release_failed_ones() {
+    let db_path = temp_db_path("request-coalescing-flights");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+        .await
+        .expect("proxy created");
+
+    let RequestFlight::Leader(leader) = proxy.join_request_flight("same") else {
+        panic!("first caller should lead");
+    };
+    let RequestFlight::Follower(first) = proxy.join_request_flight("same") else {
+        panic!("second caller should follow");
+    };
+    let RequestFlight::Follower(second) = proxy.join_request_flight("same") else {
+        panic!("third caller should follow");
+    };
+    assert!(matches!(
+        proxy.join_request_flight("other"),
+        RequestFlight::Leader(_)
+    ));
+    leader.publish(shared_search_response(2));
+    let (first, second) = tokio::join!(first.wait(), second.wait());
+    assert_eq!(first.expect("first waiter answered").credits, 2);
+    assert_eq!(
+        second.expect("second waiter answered").body,
+        shared_search_response(2).body
+    );
+
+    // A leader that fails drops out without publishing; its waiters go upstream themselves and
+    // the next caller starts a fresh flight.
+    let RequestFlight::Leader(failed) = proxy.join_request_flight("same") else {
+        panic!("finished flights must not be reused");
+    };
+    let RequestFlight::Follower(waiter) = proxy.join_request_flight("same") else {
+        panic!("second caller should follow");
+    };
+    drop(failed);
+    assert!(waiter.wait().await.is_none());
+    assert!(matches!(
+        proxy.join_request_flight("same"),
+        RequestFlight::Leader(_)
+    ));
+
+    let _ = std::fs::remove_file(db_path);
+}
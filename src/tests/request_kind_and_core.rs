@@ -2299,7 +2299,10 @@ async fn request_kind_database_migration_retries_after_transient_write_lock() {
         request_log_diagnostic_handoff: Mutex::new(Default::default()),
         user_debug_info_shared_cache: RwLock::new(std::collections::HashMap::new()),
         request_stats_coalescer: RequestStatsCoalescer::default(),
+        process_metrics: ProcessMetrics::default(),
         admin_heavy_read_semaphore: Semaphore::new(ADMIN_HEAVY_READ_CONCURRENCY),
+        api_key_secret_cipher: std::sync::OnceLock::new(),
+        access_token_secret_hashing: std::sync::atomic::AtomicBool::new(false),
         #[cfg(test)]
         forced_pending_claim_miss_log_ids: Mutex::new(std::collections::HashSet::new()),
         #[cfg(test)]
@@ -0,0 +1,187 @@
+use super::*;
+use std::sync::Mutex;
+
+type ReceivedReplays = Arc<Mutex<Vec<(HeaderMap, String)>>>;
+
+async fn spawn_mock_tavily() -> (String, ReceivedReplays) {
+    let received: ReceivedReplays = Arc::new(Mutex::new(Vec::new()));
+    let app = Router::new().route(
+        "/search",
+        post({
+            let received = received.clone();
+            move |headers: HeaderMap, body: String| {
+                let received = received.clone();
+                async move {
+                    received.lock().unwrap().push((headers, body));
+                    (
+                        StatusCode::OK,
+                        r#"{"answer":"mocked","results":[]}"#.to_string(),
+                    )
+                }
+            }
+        }),
+    );
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app.into_make_service())
+            .await
+            .unwrap();
+    });
+    (format!("http://{addr}"), received)
+}
+
+async fn insert_replayable_log(proxy: &TavilyProxy, key_id: &str, path: &str) -> i64 {
+    sqlx::query(
+        r#"INSERT INTO observability.request_logs (
+             api_key_id, method, path, result_status, request_body, response_body, created_at
+           ) VALUES (?, 'POST', ?, 'success', ?, ?, ?)"#,
+    )
+    .bind(key_id)
+    .bind(path)
+    .bind(br#"{"query":"rust sqlite","api_key":"[REDACTED]","include_usage":true}"#.to_vec())
+    .bind(br#"{"answer":"original","results":[]}"#.to_vec())
+    .bind(Utc::now().timestamp())
+    .execute(&proxy.key_store.pool)
+    .await
+    .expect("insert request log")
+    .last_insert_rowid()
+}
+
+#[tokio::test]
+async fn request_log_replay_sends_to_configured_mock_and_records_diagnostics() {
+    let db_path = temp_db_path("request-log-replay");
+    let db_str = db_path.to_string_lossy().to_string();
+    let (mock_base, received) = spawn_mock_tavily().await;
+    let proxy = TavilyProxy::with_options(
+        vec!["tvly-replay-secret".to_string()],
+        DEFAULT_UPSTREAM,
+        &db_str,
+        TavilyProxyOptions {
+            replay_upstream_bases: vec![format!("{mock_base}/")],
+            ..TavilyProxyOptions::from_database_path(&db_str)
+        },
+    )
+    .await
+    .expect("proxy created");
+    assert_eq!(
+        proxy.request_log_replay_upstream_bases(),
+        std::slice::from_ref(&mock_base)
+    );
+    let key_id: String = sqlx::query_scalar("SELECT id FROM api_keys LIMIT 1")
+        .fetch_one(&proxy.key_store.pool)
+        .await
+        .expect("key id");
+    let log_id = insert_replayable_log(&proxy, &key_id, "/api/tavily/search").await;
+
+    let replay = proxy
+        .replay_request_log(
+            log_id,
+            RequestLogReplayInput {
+                upstream_base: Some(mock_base.clone()),
+                ..RequestLogReplayInput::default()
+            },
+            "http://127.0.0.1:9",
+        )
+        .await
+        .expect("replay")
+        .expect("log exists");
+    assert_eq!(replay.source_log_id, log_id);
+    assert_eq!(replay.api_key_id, key_id);
+    assert_eq!(replay.upstream_base, mock_base);
+    assert_eq!(replay.status_code, Some(200));
+    assert_eq!(
+        replay.response_body.as_deref(),
+        Some(br#"{"answer":"mocked","results":[]}"#.as_slice())
+    );
+    let stored_request = String::from_utf8(replay.request_body.clone().unwrap()).unwrap();
+    assert!(!stored_request.contains("tvly-replay-secret"));
+    {
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 1);
+        let (headers, body) = &received[0];
+        assert_eq!(
+            headers.get("authorization").and_then(|v| v.to_str().ok()),
+            Some("Bearer tvly-replay-secret")
+        );
+        let body: Value = serde_json::from_str(body).unwrap();
+        assert_eq!(body["query"], "rust sqlite");
+        assert_eq!(body["api_key"], "tvly-replay-secret");
+    }
+
+    let overridden = proxy
+        .replay_request_log(
+            log_id,
+            RequestLogReplayInput {
+                upstream_base: Some(mock_base.clone()),
+                body: Some(serde_json::json!({"query": "overridden"})),
+                ..RequestLogReplayInput::default()
+            },
+            "http://127.0.0.1:9",
+        )
+        .await
+        .expect("replay override")
+        .expect("log exists");
+    let body: Value =
+        serde_json::from_str(&received.lock().unwrap()[1].1).expect("override body json");
+    assert_eq!(body["query"], "overridden");
+    assert_eq!(
+        proxy
+            .request_log_replays(log_id)
+            .await
+            .expect("list replays")
+            .iter()
+            .map(|replay| replay.id)
+            .collect::<Vec<_>>(),
+        vec![overridden.id, replay.id]
+    );
+
+    // Replays are diagnostics: nothing is added to request_logs.
+    let request_logs: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM observability.request_logs")
+        .fetch_one(&proxy.key_store.pool)
+        .await
+        .expect("count request logs");
+    assert_eq!(request_logs, 1);
+
+    let err = proxy
+        .replay_request_log(
+            log_id,
+            RequestLogReplayInput {
+                upstream_base: Some("http://127.0.0.1:1".to_string()),
+                ..RequestLogReplayInput::default()
+            },
+            "http://127.0.0.1:9",
+        )
+        .await
+        .expect_err("unconfigured upstream is rejected");
+    assert!(matches!(err, ProxyError::Other(_)));
+    let mcp_log = insert_replayable_log(&proxy, &key_id, "/mcp").await;
+    assert!(matches!(
+        proxy
+            .replay_request_log(mcp_log, RequestLogReplayInput::default(), &mock_base)
+            .await,
+        Err(ProxyError::Other(_))
+    ));
+    assert!(
+        proxy
+            .replay_request_log(i64::MAX, RequestLogReplayInput::default(), &mock_base)
+            .await
+            .expect("missing log")
+            .is_none()
+    );
+
+    sqlx::query("DELETE FROM observability.request_logs WHERE id = ?")
+        .bind(log_id)
+        .execute(&proxy.key_store.pool)
+        .await
+        .expect("delete source log");
+    assert!(
+        proxy
+            .request_log_replays(log_id)
+            .await
+            .expect("list replays")
+            .is_empty()
+    );
+
+    let _ = std::fs::remove_file(db_path);
+}
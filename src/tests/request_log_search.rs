@@ -0,0 +1,224 @@
+use super::*;
+
+async fn insert_search_request_log(
+    proxy: &TavilyProxy,
+    key_id: &str,
+    query: Option<&str>,
+    error_message: Option<&str>,
+    request_body: Option<Vec<u8>>,
+    response_body: Option<Vec<u8>>,
+    created_at: i64,
+) -> i64 {
+    sqlx::query(
+        r#"INSERT INTO observability.request_logs (
+             api_key_id, auth_token_id, method, path, query, error_message, result_status,
+             request_body, response_body, created_at
+           ) VALUES (?, 'search-token', 'POST', '/api/tavily/search', ?, ?, 'success', ?, ?, ?)"#,
+    )
+    .bind(key_id)
+    .bind(query)
+    .bind(error_message)
+    .bind(request_body)
+    .bind(response_body)
+    .bind(created_at)
+    .execute(&proxy.key_store.pool)
+    .await
+    .expect("insert request log")
+    .last_insert_rowid()
+}
+
+async fn index_all_request_logs(proxy: &TavilyProxy) {
+    for _ in 0..48 {
+        let outcome = proxy
+            .key_store
+            .advance_request_log_search_index_slice()
+            .await
+            .expect("advance search index");
+        if outcome == RequestLogSearchSliceOutcome::Idle {
+            return;
+        }
+        if matches!(outcome, RequestLogSearchSliceOutcome::Deferred { .. }) {
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+    }
+    panic!("request log search index remained admission-deferred");
+}
+
+async fn search_request_log_ids(proxy: &TavilyProxy, raw: &str) -> Vec<i64> {
+    let search = request_log_search_match_query(raw).expect("searchable input");
+    proxy
+        .request_logs_list(
+            None,
+            &[],
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(&search),
+            None,
+            None,
+            RequestLogsCursorDirection::Older,
+            50,
+        )
+        .await
+        .expect("search request logs")
+        .items
+        .into_iter()
+        .map(|item| item.id)
+        .collect()
+}
+
+#[test]
+fn request_log_search_match_query_quotes_terms() {
+    assert_eq!(request_log_search_match_query("  ab  "), None);
+    assert_eq!(
+        request_log_search_match_query(r#"tvly OR "quoted col:x"#),
+        Some(r#""tvly" """quoted" "col:x""#.to_string())
+    );
+}
+
+#[tokio::test]
+async fn request_log_search_indexes_bodies_and_follows_retention() {
+    let db_path = temp_db_path("request-log-search");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+        .await
+        .expect("proxy created");
+    let now = Utc::now().timestamp();
+    let quantum = insert_search_request_log(
+        &proxy,
+        "key-a",
+        Some("topic=news"),
+        None,
+        Some(br#"{"query":"quantum entanglement"}"#.to_vec()),
+        Some(crate::store::compress_request_log_body(
+            br#"{"results":[{"title":"Spooky action"}]}"#,
+        )),
+        now - 3,
+    )
+    .await;
+    let timeout = insert_search_request_log(
+        &proxy,
+        "key-b",
+        None,
+        Some("upstream timeout after 30s"),
+        None,
+        None,
+        now - 2,
+    )
+    .await;
+    insert_search_request_log(
+        &proxy,
+        "key-a",
+        None,
+        None,
+        Some(br#"{"query":"weather"}"#.to_vec()),
+        None,
+        now - 1,
+    )
+    .await;
+    sqlx::query(
+        r#"INSERT INTO auth_token_logs (
+             token_id, method, path, result_status, request_log_id, created_at
+           ) VALUES ('search-token', 'POST', '/api/tavily/search', 'success', ?, ?)"#,
+    )
+    .bind(quantum)
+    .bind(now - 3)
+    .execute(&proxy.key_store.pool)
+    .await
+    .expect("insert token log");
+
+    index_all_request_logs(&proxy).await;
+    assert_eq!(
+        search_request_log_ids(&proxy, "ENTANGLE").await,
+        vec![quantum]
+    );
+    assert_eq!(
+        search_request_log_ids(&proxy, "spooky").await,
+        vec![quantum]
+    );
+    assert_eq!(
+        search_request_log_ids(&proxy, "timeout 30s").await,
+        vec![timeout]
+    );
+    assert!(
+        search_request_log_ids(&proxy, "timeout weather")
+            .await
+            .is_empty()
+    );
+
+    let search = request_log_search_match_query("quantum").expect("searchable input");
+    let key_page = proxy
+        .key_logs_list(
+            "key-b",
+            None,
+            &[],
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(&search),
+            None,
+            RequestLogsCursorDirection::Older,
+            50,
+        )
+        .await
+        .expect("search key logs");
+    assert!(key_page.items.is_empty(), "search stays scoped to the key");
+    let token_page = proxy
+        .token_logs_list(
+            "search-token",
+            50,
+            now - 60,
+            None,
+            &[],
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(&search),
+            None,
+            RequestLogsCursorDirection::Older,
+        )
+        .await
+        .expect("search token logs");
+    assert_eq!(token_page.items.len(), 1);
+
+    // Body retention strips the bodies from the index but keeps the log findable by its query.
+    sqlx::query(
+        "UPDATE observability.request_logs SET request_body = NULL, response_body = NULL WHERE id = ?",
+    )
+    .bind(quantum)
+    .execute(&proxy.key_store.pool)
+    .await
+    .expect("clean bodies");
+    assert!(search_request_log_ids(&proxy, "quantum").await.is_empty());
+    assert_eq!(
+        search_request_log_ids(&proxy, "topic=news").await,
+        vec![quantum]
+    );
+
+    sqlx::query("DELETE FROM observability.request_logs WHERE id = ?")
+        .bind(timeout)
+        .execute(&proxy.key_store.pool)
+        .await
+        .expect("delete request log");
+    let indexed: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM observability.request_log_search WHERE request_log_search MATCH ?",
+    )
+    .bind(request_log_search_match_query("timeout").expect("searchable input"))
+    .fetch_one(&proxy.key_store.pool)
+    .await
+    .expect("count indexed rows");
+    assert_eq!(indexed, 0);
+
+    let _ = std::fs::remove_file(db_path);
+}
@@ -0,0 +1,292 @@
+use super::*;
+
+fn search_cap(mode: RequestParameterPolicyMode) -> RequestParameterPolicy {
+    RequestParameterPolicy {
+        mode,
+        max_search_depth: Some("basic".to_string()),
+        max_results: Some(3),
+        allow_raw_content: Some(false),
+        ..RequestParameterPolicy::default()
+    }
+}
+
+#[test]
+fn request_parameter_policy_rejects_or_clamps_over_limit_parameters() {
+    let reject = search_cap(RequestParameterPolicyMode::Reject);
+    let mut options = serde_json::json!({ "query": "q", "search_depth": "Advanced" });
+    let violation = reject
+        .apply("search", &mut options)
+        .expect_err("depth rejected");
+    assert_eq!(violation.parameter, "search_depth");
+    let mut options = serde_json::json!({ "query": "q", "include_raw_content": "markdown" });
+    assert_eq!(
+        reject
+            .apply("search", &mut options)
+            .expect_err("raw content rejected")
+            .parameter,
+        "include_raw_content"
+    );
+
+    // An omitted parameter whose upstream default exceeds the cap is filled in even when
+    // rejecting, so the caller is never refused for something they did not ask for.
+    let mut options = serde_json::json!({ "query": "q" });
+    assert!(
+        reject
+            .apply("search", &mut options)
+            .expect("default capped")
+    );
+    assert_eq!(options["max_results"], 3);
+    assert!(options.get("search_depth").is_none());
+
+    let clamp = search_cap(RequestParameterPolicyMode::Clamp);
+    let mut options = serde_json::json!({
+        "query": "q",
+        "search_depth": "advanced",
+        "max_results": 20,
+        "include_raw_content": true,
+    });
+    assert!(clamp.apply("search", &mut options).expect("clamped"));
+    assert_eq!(options["search_depth"], "basic");
+    assert_eq!(options["max_results"], 3);
+    assert_eq!(options["include_raw_content"], false);
+    let mut options = serde_json::json!({ "query": "q", "search_depth": "fast" });
+    assert!(
+        clamp
+            .apply("search", &mut options)
+            .expect("default result count capped")
+    );
+    assert_eq!(options["search_depth"], "fast");
+    assert_eq!(options["max_results"], 3);
+
+    let crawl = RequestParameterPolicy {
+        mode: RequestParameterPolicyMode::Clamp,
+        max_crawl_limit: Some(10),
+        max_crawl_depth: Some(2),
+        ..RequestParameterPolicy::default()
+    };
+    let mut options =
+        serde_json::json!({ "url": "https://example.com", "limit": 500, "max_depth": 5 });
+    assert!(crawl.apply("crawl", &mut options).expect("crawl clamped"));
+    assert_eq!(
+        (options["limit"].as_i64(), options["max_depth"].as_i64()),
+        (Some(10), Some(2))
+    );
+
+    // Research models are always rejected, whatever the mode.
+    let research = RequestParameterPolicy {
+        mode: RequestParameterPolicyMode::Clamp,
+        allowed_research_models: Some(vec!["mini".to_string()]),
+        ..RequestParameterPolicy::default()
+    };
+    let mut options = serde_json::json!({ "input": "topic" });
+    assert_eq!(
+        research
+            .apply("research", &mut options)
+            .expect_err("default auto model rejected")
+            .parameter,
+        "model"
+    );
+    let mut options = serde_json::json!({ "input": "topic", "model": "MINI" });
+    assert!(
+        !research
+            .apply("research", &mut options)
+            .expect("mini allowed")
+    );
+
+    let credits = RequestParameterPolicy {
+        max_expected_credits: Some(2),
+        ..RequestParameterPolicy::default()
+    };
+    assert!(credits.check_expected_credits(2).is_ok());
+    assert_eq!(
+        credits
+            .check_expected_credits(3)
+            .expect_err("too expensive")
+            .parameter,
+        "expected_credits"
+    );
+}
+
+#[test]
+fn request_parameter_policy_normalizes_and_merges_strictest() {
+    let parsed: RequestParameterPolicy = serde_json::from_value(serde_json::json!({
+        "mode": "clamp",
+        "maxSearchDepth": " ADVANCED ",
+        "allowedResearchModels": ["Pro", "mini", "pro"],
+    }))
+    .expect("decode policy");
+    let parsed = parsed.normalized().expect("valid policy");
+    assert_eq!(parsed.max_search_depth.as_deref(), Some("advanced"));
+    assert_eq!(
+        parsed.allowed_research_models,
+        Some(vec!["pro".to_string(), "mini".to_string()])
+    );
+    assert!(
+        RequestParameterPolicy {
+            max_search_depth: Some("deep".to_string()),
+            ..RequestParameterPolicy::default()
+        }
+        .normalized()
+        .is_err()
+    );
+    assert!(
+        RequestParameterPolicy {
+            max_crawl_limit: Some(0),
+            ..RequestParameterPolicy::default()
+        }
+        .normalized()
+        .is_err()
+    );
+
+    let merged = parsed.merge_strictest(&RequestParameterPolicy {
+        max_search_depth: Some("fast".to_string()),
+        max_results: Some(4),
+        allowed_research_models: Some(vec!["mini".to_string(), "auto".to_string()]),
+        ..RequestParameterPolicy::default()
+    });
+    assert_eq!(merged.mode, RequestParameterPolicyMode::Reject);
+    assert_eq!(merged.max_search_depth.as_deref(), Some("fast"));
+    assert_eq!(merged.max_results, Some(4));
+    assert_eq!(
+        merged.allowed_research_models,
+        Some(vec!["mini".to_string()])
+    );
+}
+
+#[tokio::test]
+async fn request_parameter_policy_prefers_token_then_group_then_user_tags() {
+    let db_path = temp_db_path("request-parameter-policy-precedence");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+        .await
+        .expect("proxy created");
+    let user = proxy
+        .upsert_oauth_account(&OAuthAccountProfile {
+            provider: "github".to_string(),
+            provider_user_id: "parameter-policy-user".to_string(),
+            username: Some("parameter_policy_user".to_string()),
+            name: None,
+            avatar_template: None,
+            active: true,
+            trust_level: None,
+            raw_payload_json: None,
+        })
+        .await
+        .expect("upsert user");
+    let token = proxy
+        .ensure_user_token_binding(&user.user_id, Some("policy"))
+        .await
+        .expect("bind token");
+    assert_eq!(
+        proxy
+            .effective_request_parameter_policy(&token.id)
+            .await
+            .expect("unrestricted"),
+        None
+    );
+
+    for (name, max_results) in [("policy_a", 8), ("policy_b", 6)] {
+        let tag = proxy
+            .create_user_tag(name, name, None, USER_TAG_EFFECT_QUOTA_DELTA, 0, 0, 0)
+            .await
+            .expect("create tag");
+        proxy
+            .bind_user_tag_to_user(&user.user_id, &tag.id)
+            .await
+            .expect("bind tag");
+        let policy = RequestParameterPolicy {
+            mode: RequestParameterPolicyMode::Clamp,
+            max_results: Some(max_results),
+            ..RequestParameterPolicy::default()
+        };
+        assert!(
+            proxy
+                .set_request_parameter_policy(
+                    RequestParameterPolicySubject::UserTag,
+                    &tag.id,
+                    Some(&policy),
+                )
+                .await
+                .expect("set tag policy")
+        );
+    }
+    let from_tags = proxy
+        .effective_request_parameter_policy(&token.id)
+        .await
+        .expect("tag policies")
+        .expect("merged tag policy");
+    assert_eq!(from_tags.max_results, Some(6));
+    assert_eq!(from_tags.mode, RequestParameterPolicyMode::Clamp);
+
+    sqlx::query("UPDATE auth_tokens SET group_name = 'metered' WHERE id = ?")
+        .bind(&token.id)
+        .execute(&proxy.key_store.pool)
+        .await
+        .expect("assign group");
+    let group_policy = RequestParameterPolicy {
+        max_results: Some(10),
+        ..RequestParameterPolicy::default()
+    };
+    assert!(
+        proxy
+            .set_request_parameter_policy(
+                RequestParameterPolicySubject::TokenGroup,
+                " metered ",
+                Some(&group_policy),
+            )
+            .await
+            .expect("set group policy")
+    );
+    assert_eq!(
+        proxy
+            .effective_request_parameter_policy(&token.id)
+            .await
+            .expect("group policy"),
+        Some(group_policy)
+    );
+
+    let token_policy = RequestParameterPolicy {
+        max_expected_credits: Some(1),
+        ..RequestParameterPolicy::default()
+    };
+    assert!(
+        proxy
+            .set_request_parameter_policy(
+                RequestParameterPolicySubject::Token,
+                &token.id,
+                Some(&token_policy),
+            )
+            .await
+            .expect("set token policy")
+    );
+    assert_eq!(
+        proxy
+            .effective_request_parameter_policy(&token.id)
+            .await
+            .expect("token policy"),
+        Some(token_policy)
+    );
+    assert_eq!(
+        proxy
+            .list_request_parameter_policies()
+            .await
+            .expect("list policies")
+            .len(),
+        4
+    );
+
+    assert!(
+        !proxy
+            .set_request_parameter_policy(RequestParameterPolicySubject::Token, "zzzz", None)
+            .await
+            .expect("missing token")
+    );
+    assert!(
+        !proxy
+            .set_request_parameter_policy(RequestParameterPolicySubject::UserTag, "missing", None)
+            .await
+            .expect("missing tag")
+    );
+
+    let _ = std::fs::remove_file(db_path);
+}
@@ -0,0 +1,208 @@
+use super::*;
+
+fn cached_json_response(body: &str) -> ProxyResponse {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        reqwest::header::CONTENT_TYPE,
+        HeaderValue::from_static("application/json"),
+    );
+    ProxyResponse {
+        status: StatusCode::OK,
+        headers,
+        body: Bytes::from(body.to_string()),
+        api_key_id: Some("key-1".to_string()),
+        request_log_id: None,
+        key_effect_code: KEY_EFFECT_NONE.to_string(),
+        key_effect_summary: None,
+        binding_effect_code: KEY_EFFECT_NONE.to_string(),
+        binding_effect_summary: None,
+        selection_effect_code: KEY_EFFECT_NONE.to_string(),
+        selection_effect_summary: None,
+    }
+}
+
+#[test]
+fn response_cache_key_ignores_field_order_nulls_and_credentials() {
+    let base = response_cache_key(
+        "/search",
+        &serde_json::json!({ "query": "q", "max_results": 3, "include_domains": ["a", "b"] }),
+    );
+    assert_eq!(
+        base,
+        response_cache_key(
+            "search",
+            &serde_json::json!({
+                "include_domains": ["a", "b"],
+                "API_KEY": "tvly-secret",
+                "include_usage": true,
+                "topic": null,
+                "max_results": 3,
+                "query": "q",
+            }),
+        )
+    );
+    assert_ne!(
+        base,
+        response_cache_key(
+            "/search",
+            &serde_json::json!({ "query": "q", "max_results": 3, "include_domains": ["b", "a"] }),
+        )
+    );
+    assert_ne!(
+        base,
+        response_cache_key(
+            "/extract",
+            &serde_json::json!({ "query": "q", "max_results": 3, "include_domains": ["a", "b"] }),
+        )
+    );
+}
+
+#[test]
+fn response_cache_settings_normalize_and_price_hits() {
+    let settings = normalize_response_cache_settings(&ResponseCacheSettings {
+        enabled: true,
+        endpoints: vec![
+            " /Map ".to_string(),
+            "search".to_string(),
+            "map".to_string(),
+        ],
+        endpoint_ttl_secs: [("/Extract".to_string(), 3_600)].into_iter().collect(),
+        hit_billing_percent: 50,
+        ..ResponseCacheSettings::default()
+    })
+    .expect("valid settings");
+    assert_eq!(settings.endpoints, vec!["search", "map"]);
+    assert!(settings.covers_upstream_path("/map"));
+    assert!(!settings.covers_upstream_path("/extract"));
+    assert_eq!(settings.ttl_secs_for("/extract"), 3_600);
+    assert_eq!(
+        settings.ttl_secs_for("/search"),
+        RESPONSE_CACHE_TTL_SECS_DEFAULT
+    );
+    assert_eq!(settings.hit_credits(4), 2);
+    assert_eq!(settings.hit_credits(1), 1);
+    assert_eq!(ResponseCacheSettings::default().hit_credits(8), 0);
+
+    for invalid in [
+        ResponseCacheSettings {
+            endpoints: vec!["research".to_string()],
+            ..ResponseCacheSettings::default()
+        },
+        ResponseCacheSettings {
+            ttl_secs: 1,
+            ..ResponseCacheSettings::default()
+        },
+        ResponseCacheSettings {
+            hit_billing_percent: 101,
+            ..ResponseCacheSettings::default()
+        },
+    ] {
+        assert!(normalize_response_cache_settings(&invalid).is_err());
+    }
+}
+
+#[tokio::test]
+async fn response_cache_serves_logs_and_evicts_entries() {
+    let db_path = temp_db_path("response-cache-store");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+        .await
+        .expect("proxy created");
+    let settings = ResponseCacheSettings {
+        enabled: true,
+        max_entries: 1,
+        max_body_bytes: 1_024,
+        ..ResponseCacheSettings::default()
+    };
+    let first_options = serde_json::json!({ "query": "first" });
+    let first_key = response_cache_key("/search", &first_options);
+    let method = Method::POST;
+
+    assert!(
+        proxy
+            .serve_cached_http_response(
+                &settings,
+                &first_key,
+                None,
+                &method,
+                "/api/tavily/search",
+                &first_options,
+                None,
+            )
+            .await
+            .expect("lookup miss")
+            .is_none()
+    );
+    proxy
+        .store_cached_http_response(
+            &settings,
+            "/search",
+            &first_key,
+            &cached_json_response(r#"{"results":[],"usage":{"credits":2}}"#),
+            2,
+        )
+        .await
+        .expect("store first");
+    let (response, analysis, billed) = proxy
+        .serve_cached_http_response(
+            &settings,
+            &first_key,
+            None,
+            &method,
+            "/api/tavily/search",
+            &first_options,
+            None,
+        )
+        .await
+        .expect("lookup hit")
+        .expect("cached response");
+    assert_eq!(response.status, StatusCode::OK);
+    assert_eq!(
+        response.headers.get(reqwest::header::CONTENT_TYPE),
+        Some(&HeaderValue::from_static("application/json"))
+    );
+    assert_eq!(response.key_effect_code, KEY_EFFECT_RESPONSE_CACHE_HIT);
+    assert_eq!(analysis.status, OUTCOME_SUCCESS);
+    assert_eq!(response.api_key_id, None);
+    assert_eq!(billed, 0);
+    let logs = proxy.recent_request_logs(1).await.expect("request logs");
+    assert_eq!(Some(logs[0].id), response.request_log_id);
+    assert_eq!(logs[0].key_effect_code, KEY_EFFECT_RESPONSE_CACHE_HIT);
+
+    // Oversized bodies are never stored; a second entry evicts the first at max_entries = 1.
+    let large_options = serde_json::json!({ "query": "large" });
+    let large_key = response_cache_key("/search", &large_options);
+    proxy
+        .store_cached_http_response(
+            &settings,
+            "/search",
+            &large_key,
+            &cached_json_response(&"x".repeat(2_048)),
+            1,
+        )
+        .await
+        .expect("skip oversized");
+    let second_key = response_cache_key("/search", &serde_json::json!({ "query": "second" }));
+    proxy
+        .store_cached_http_response(
+            &settings,
+            "/search",
+            &second_key,
+            &cached_json_response(r#"{"results":[]}"#),
+            1,
+        )
+        .await
+        .expect("store second");
+    let stored: Vec<String> =
+        sqlx::query_scalar("SELECT cache_key FROM response_cache_entries ORDER BY cache_key")
+            .fetch_all(&proxy.key_store.pool)
+            .await
+            .expect("read entries");
+    assert_eq!(stored, vec![second_key]);
+
+    let stats = proxy.summary().await.expect("summary").response_cache;
+    assert_eq!((stats.entries, stats.hits_today), (1, 1));
+    assert_eq!(stats.credits_saved_today, 2);
+
+    let _ = std::fs::remove_file(db_path);
+}
@@ -31,7 +31,8 @@ async fn versioned_schema_migrations_are_idempotent_and_fail_closed_on_drift() {
     assert_eq!(
         versions,
         vec![
-            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+            25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38,
         ]
     );
     let transport_observation_column: i64 = sqlx::query_scalar(
@@ -84,7 +85,7 @@ async fn versioned_schema_migrations_are_idempotent_and_fail_closed_on_drift() {
             .fetch_one(&pool)
             .await
             .expect("read fresh alert projection sources");
-    assert_eq!(projection_sources, 3);
+    assert_eq!(projection_sources, 5);
     let recent_tail_sources: i64 = sqlx::query_scalar(
         "SELECT COUNT(*) FROM observability.dashboard_alert_projection_state \
          WHERE cursor_occurred_at = 0 AND cursor_row_sort_id = '' AND phase = 'catching_up'",
@@ -104,7 +105,7 @@ async fn versioned_schema_migrations_are_idempotent_and_fail_closed_on_drift() {
     .await
     .expect("read fresh full-history alert projection cursors");
     assert_eq!(
-        full_history_cursor_sources, 3,
+        full_history_cursor_sources, 5,
         "the administrator sidecar starts from a durable full-history cursor without startup scans"
     );
     sqlx::query("UPDATE schema_migrations SET checksum = 'drifted' WHERE version = 2")
@@ -498,6 +499,61 @@ async fn warm_schema_migration_adds_the_per_channel_legacy_cursor() {
     let _ = std::fs::remove_file(db_path.with_extension("db-wal"));
 }
 
+#[tokio::test]
+async fn api_key_routing_controls_migration_creates_tables_on_existing_database() {
+    let db_path = temp_db_path("schema-migration-api-key-routing-controls");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(
+        vec!["tvly-schema-migration-api-key-routing-controls".to_string()],
+        DEFAULT_UPSTREAM,
+        &db_str,
+    )
+    .await
+    .expect("create migrated database");
+
+    sqlx::query("DELETE FROM schema_migrations WHERE version = 21")
+        .execute(&proxy.key_store.pool)
+        .await
+        .expect("simulate an existing database before v21");
+    for table in [
+        "api_key_group_tiers",
+        "api_key_group_bindings",
+        "api_key_rate_budgets",
+    ] {
+        sqlx::query(&format!("DROP TABLE {table}"))
+            .execute(&proxy.key_store.pool)
+            .await
+            .expect("simulate the pre-v21 schema");
+    }
+
+    assert!(
+        !proxy
+            .key_store
+            .prepare_versioned_schema()
+            .await
+            .expect("warm migration must converge an existing database"),
+        "an existing database must not request full bootstrap"
+    );
+    let routing_tables: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name IN ('api_key_group_tiers', 'api_key_group_bindings', 'api_key_rate_budgets')",
+    )
+    .fetch_one(&proxy.key_store.pool)
+    .await
+    .expect("read routing control tables");
+    assert_eq!(routing_tables, 3);
+    let v21_recorded: i64 =
+        sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM schema_migrations WHERE version = 21)")
+            .fetch_one(&proxy.key_store.pool)
+            .await
+            .expect("read v21 migration ledger record");
+    assert_eq!(v21_recorded, 1);
+
+    drop(proxy);
+    let _ = std::fs::remove_file(&db_path);
+    let _ = std::fs::remove_file(db_path.with_extension("db-shm"));
+    let _ = std::fs::remove_file(db_path.with_extension("db-wal"));
+}
+
 #[tokio::test]
 async fn versioned_schema_migrations_reject_missing_recorded_objects() {
     let db_path = temp_db_path("schema-migration-missing-object");
@@ -927,7 +983,8 @@ async fn baseline_adoption_records_compatible_existing_schema_without_full_boots
     assert_eq!(
         versions,
         vec![
-            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+            25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38
         ]
     );
 
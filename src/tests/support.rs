@@ -110,6 +110,12 @@ pub(super) fn temp_db_path(prefix: &str) -> PathBuf {
     dir.join(format!("{prefix}.db"))
 }
 
+pub(super) fn remove_db_files(db_path: &std::path::Path) {
+    let _ = std::fs::remove_file(db_path);
+    let _ = std::fs::remove_file(db_path.with_extension("db-shm"));
+    let _ = std::fs::remove_file(db_path.with_extension("db-wal"));
+}
+
 fn cleanup_stale_test_db_dirs() {
     let tmp_dir = std::env::temp_dir();
     let Ok(entries) = std::fs::read_dir(&tmp_dir) else {
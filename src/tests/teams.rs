@@ -0,0 +1,317 @@
+use super::*;
+
+fn team_member_profile(provider_user_id: &str) -> OAuthAccountProfile {
+    OAuthAccountProfile {
+        provider: "linuxdo".to_string(),
+        provider_user_id: provider_user_id.to_string(),
+        username: Some(provider_user_id.to_string()),
+        name: None,
+        avatar_template: None,
+        active: true,
+        trust_level: Some(2),
+        raw_payload_json: None,
+    }
+}
+
+fn team_input(name: &str, hourly: i64, daily: i64, monthly: i64, enforce: bool) -> TeamInput {
+    TeamInput {
+        name: name.to_string(),
+        limits: TeamQuotaLimits {
+            hourly_credits_limit: hourly,
+            daily_credits_limit: daily,
+            monthly_credits_limit: monthly,
+        },
+        enforce_member_limits: enforce,
+    }
+}
+
+async fn charge_team_attempt(proxy: &TavilyProxy, token_id: &str, credits: i64) {
+    let log_id = proxy
+        .record_pending_billing_attempt(
+            token_id,
+            &Method::POST,
+            "/api/tavily/search",
+            None,
+            Some(StatusCode::OK.as_u16() as i64),
+            Some(200),
+            true,
+            OUTCOME_SUCCESS,
+            None,
+            credits,
+            None,
+        )
+        .await
+        .expect("record pending billing attempt");
+    let outcome = proxy
+        .settle_pending_billing_attempt(log_id)
+        .await
+        .expect("settle pending billing attempt");
+    assert_eq!(outcome, PendingBillingSettleOutcome::Charged);
+}
+
+#[test]
+fn team_pool_verdict_reports_the_tighter_window_and_blocks_on_either_side() {
+    let personal = TokenQuotaVerdict::new(10, 100, 20, 500, 30, 5000);
+    let team = TokenQuotaVerdict::for_team(
+        TeamQuotaLimits {
+            hourly_credits_limit: 50,
+            daily_credits_limit: 1000,
+            monthly_credits_limit: 40,
+        },
+        45,
+        200,
+        40,
+    );
+
+    let combined = personal.with_team_pool(team);
+    assert_eq!(combined.effective_window(), Some(QuotaWindow::Month));
+    assert_eq!((combined.hourly_used, combined.hourly_limit), (45, 50));
+    assert_eq!((combined.daily_used, combined.daily_limit), (20, 500));
+    assert_eq!((combined.monthly_used, combined.monthly_limit), (40, 40));
+}
+
+#[tokio::test]
+async fn team_pool_blocks_members_once_shared_credits_run_out() {
+    let db_path = temp_db_path("team-pool-blocks-members");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+        .await
+        .expect("proxy created");
+
+    let team = proxy
+        .create_team(&team_input("alpha", 100, 5, 100, true))
+        .await
+        .expect("create team");
+    let alice = proxy
+        .upsert_oauth_account(&team_member_profile("team-alice"))
+        .await
+        .expect("upsert alice");
+    let bob = proxy
+        .upsert_oauth_account(&team_member_profile("team-bob"))
+        .await
+        .expect("upsert bob");
+    for (user, role) in [(&alice, TeamRole::Owner), (&bob, TeamRole::Member)] {
+        assert!(
+            proxy
+                .upsert_team_member(&team.id, &user.user_id, role)
+                .await
+                .expect("add member")
+        );
+    }
+    let alice_token = proxy
+        .ensure_user_token_binding(&alice.user_id, Some("linuxdo:team-alice"))
+        .await
+        .expect("bind alice token");
+    let bob_token = proxy
+        .ensure_user_token_binding(&bob.user_id, Some("linuxdo:team-bob"))
+        .await
+        .expect("bind bob token");
+
+    charge_team_attempt(&proxy, &alice_token.id, 3).await;
+    let bob_verdict = proxy
+        .peek_token_quota(&bob_token.id)
+        .await
+        .expect("peek bob quota");
+    assert_eq!(bob_verdict.effective_window(), None);
+    assert_eq!(
+        (bob_verdict.daily_used, bob_verdict.daily_limit),
+        (3, 5),
+        "bob sees the team pool because it has less headroom than his own daily limit"
+    );
+
+    charge_team_attempt(&proxy, &bob_token.id, 2).await;
+    let alice_verdict = proxy
+        .peek_token_quota(&alice_token.id)
+        .await
+        .expect("peek alice quota");
+    assert_eq!(alice_verdict.effective_window(), Some(QuotaWindow::Day));
+
+    let snapshot = proxy
+        .team_quota_snapshot(&team)
+        .await
+        .expect("team snapshot");
+    assert_eq!(snapshot.daily_used, 5);
+    assert_eq!(snapshot.monthly_used, 5);
+
+    assert!(
+        proxy
+            .remove_team_member(&team.id, &alice.user_id)
+            .await
+            .expect("remove alice")
+    );
+    assert_eq!(
+        proxy
+            .peek_token_quota(&alice_token.id)
+            .await
+            .expect("peek alice personal quota")
+            .effective_window(),
+        None,
+        "leaving the team falls back to personal limits"
+    );
+
+    let _ = std::fs::remove_file(db_path);
+}
+
+#[tokio::test]
+async fn team_without_member_limit_enforcement_replaces_personal_limits() {
+    let db_path = temp_db_path("team-replaces-personal-limits");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+        .await
+        .expect("proxy created");
+
+    let team = proxy
+        .create_team(&team_input("beta", 100, 100, 100, false))
+        .await
+        .expect("create team");
+    let carol = proxy
+        .upsert_oauth_account(&team_member_profile("team-carol"))
+        .await
+        .expect("upsert carol");
+    proxy
+        .upsert_team_member(&team.id, &carol.user_id, TeamRole::Member)
+        .await
+        .expect("add carol");
+    let token = proxy
+        .ensure_user_token_binding(&carol.user_id, Some("linuxdo:team-carol"))
+        .await
+        .expect("bind carol token");
+    assert!(
+        proxy
+            .update_account_quota_limits(&carol.user_id, 1, 1, 1)
+            .await
+            .expect("shrink personal limits")
+    );
+
+    charge_team_attempt(&proxy, &token.id, 4).await;
+    let verdict = proxy
+        .peek_token_quota(&token.id)
+        .await
+        .expect("peek carol quota");
+    assert_eq!(
+        verdict.effective_window(),
+        None,
+        "personal limits are ignored"
+    );
+    assert_eq!((verdict.daily_used, verdict.daily_limit), (4, 100));
+
+    let other = proxy
+        .create_team(&team_input("gamma", 1, 1, 1, true))
+        .await
+        .expect("create other team");
+    assert!(
+        !proxy
+            .upsert_team_member(&other.id, &carol.user_id, TeamRole::Admin)
+            .await
+            .expect("add carol elsewhere"),
+        "a user belongs to at most one team"
+    );
+
+    let _ = std::fs::remove_file(db_path);
+}
+
+#[tokio::test]
+async fn team_tokens_bill_the_pool_and_are_revoked_with_the_team() {
+    let db_path = temp_db_path("team-tokens-bill-pool");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+        .await
+        .expect("proxy created");
+
+    let team = proxy
+        .create_team(&team_input("delta", 100, 100, 2, true))
+        .await
+        .expect("create team");
+    let secret = proxy
+        .create_team_token(&team.id, Some("team:delta"), Some("admin"))
+        .await
+        .expect("create team token");
+    let tokens = proxy
+        .list_team_tokens(&team.id)
+        .await
+        .expect("list team tokens");
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0].token_id, secret.id);
+    assert_eq!(tokens[0].created_by.as_deref(), Some("admin"));
+
+    charge_team_attempt(&proxy, &secret.id, 2).await;
+    let verdict = proxy
+        .peek_token_quota(&secret.id)
+        .await
+        .expect("peek team token quota");
+    assert_eq!(verdict.effective_window(), Some(QuotaWindow::Month));
+
+    let series = proxy
+        .team_usage_series(&team, AdminUserUsageSeriesKind::DailyCredits)
+        .await
+        .expect("team daily series");
+    assert_eq!(series.limit, 100);
+    assert_eq!(series.points.len(), 7);
+    assert_eq!(series.points.last().and_then(|point| point.value), Some(2));
+
+    assert!(proxy.delete_team(&team.id).await.expect("delete team"));
+    assert!(proxy.get_team(&team.id).await.expect("get team").is_none());
+    let deleted_at: Option<i64> =
+        sqlx::query_scalar("SELECT deleted_at FROM auth_tokens WHERE id = ?")
+            .bind(&secret.id)
+            .fetch_one(&proxy.key_store.pool)
+            .await
+            .expect("read team token");
+    assert!(deleted_at.is_some(), "team tokens are soft-deleted");
+
+    let _ = std::fs::remove_file(db_path);
+}
+
+#[tokio::test]
+async fn team_pool_counts_credits_reserved_by_member_requests() {
+    let db_path = temp_db_path("team-pool-reservations");
+    let db_str = db_path.to_string_lossy().to_string();
+    let proxy = TavilyProxy::with_endpoint(Vec::<String>::new(), DEFAULT_UPSTREAM, &db_str)
+        .await
+        .expect("proxy created");
+
+    let team = proxy
+        .create_team(&team_input("reserved", 100, 10, 100, true))
+        .await
+        .expect("create team");
+    let alice = proxy
+        .upsert_oauth_account(&team_member_profile("team-reserved-alice"))
+        .await
+        .expect("upsert alice");
+    assert!(
+        proxy
+            .upsert_team_member(&team.id, &alice.user_id, TeamRole::Owner)
+            .await
+            .expect("add member")
+    );
+    let alice_token = proxy
+        .ensure_user_token_binding(&alice.user_id, Some("linuxdo:team-reserved-alice"))
+        .await
+        .expect("bind alice token");
+
+    let reservation_id = proxy
+        .reserve_quota_credits_for_subject(
+            &alice_token.id,
+            &format!("account:{}", alice.user_id),
+            4,
+        )
+        .await
+        .expect("reserve credits");
+    let snapshot = proxy
+        .team_quota_snapshot(&team)
+        .await
+        .expect("team snapshot");
+    assert_eq!(snapshot.daily_used, 4, "member reservations hold the pool");
+
+    proxy
+        .release_quota_reservation(reservation_id)
+        .await
+        .expect("release reservation");
+    let snapshot = proxy
+        .team_quota_snapshot(&team)
+        .await
+        .expect("team snapshot after release");
+    assert_eq!(snapshot.daily_used, 0);
+
+    let _ = std::fs::remove_file(db_path);
+}
@@ -12,6 +12,7 @@ import {
   fetchUserToken,
   fetchPublicLogs,
   millisecondsUntilNextBrowserDayBoundary,
+  resolveUserLoginProviders,
   type Profile,
   type PublicMetrics,
   type Summary,
@@ -319,7 +320,9 @@ function PublicHome(): JSX.Element {
   const isLoggedOut = profile?.userLoggedIn === false
   const showAuthStatusLoading = profileLoading
   const showAuthStatusUnavailable = !profileLoading && profileUnavailable
-  const showLinuxDoLogin = isLoggedOut
+  const loginProviders = resolveUserLoginProviders(profile)
+  const showLinuxDoLogin = isLoggedOut && loginProviders.some((provider) => provider.id === 'linuxdo')
+  const oidcLoginProvider = isLoggedOut ? loginProviders.find((provider) => provider.id === 'oidc') ?? null : null
   const showRegistrationPausedNotice = isLoggedOut && profile?.allowRegistration === false
   const hasTokenInfo = token.trim().length > 0
   const canRevealGuideToken = isFullToken(token)
@@ -394,10 +397,10 @@ function PublicHome(): JSX.Element {
     window.setTimeout(() => setCopyState('idle'), 2500)
   }, [focusManualTokenField, isTokenAccessDialogOpen])
 
-  const startLinuxDoLogin = useCallback((candidateToken?: string) => {
+  const startUserLogin = useCallback((startPath: string, candidateToken?: string) => {
     const form = document.createElement('form')
     form.method = 'POST'
-    form.action = '/auth/linuxdo'
+    form.action = startPath
     form.style.display = 'none'
 
     const trimmed = candidateToken?.trim() ?? ''
@@ -568,7 +571,9 @@ function PublicHome(): JSX.Element {
         summaryLoading={summaryLoading}
         showAuthStatusLoading={showAuthStatusLoading}
         showAuthStatusUnavailable={showAuthStatusUnavailable}
-        onLinuxDoLogin={() => startLinuxDoLogin(token)}
+        onLinuxDoLogin={() => startUserLogin('/auth/linuxdo', token)}
+        oidcLoginLabel={oidcLoginProvider?.label ?? null}
+        onOidcLogin={() => startUserLogin(oidcLoginProvider?.startPath ?? '/auth/oidc', token)}
         onTokenAccessClick={openTokenAccessDialog}
         onAdminActionClick={() => { window.location.href = isAdmin ? '/admin' : '/login' }}
       />
@@ -958,14 +963,16 @@ function PublicHome(): JSX.Element {
           <p className="opacity-80" style={{ marginTop: 14, marginBottom: 0 }}>
             {publicStrings.tokenAccess.dialog.loginHint}{' '}
             <a
-              href="/auth/linuxdo"
+              href={loginProviders[0]?.startPath ?? '/auth/linuxdo'}
               className="link"
               onClick={(event) => {
                 event.preventDefault()
-                startLinuxDoLogin(tokenDraft)
+                startUserLogin(event.currentTarget.getAttribute('href') ?? '/auth/linuxdo', tokenDraft)
               }}
             >
-              {publicStrings.linuxDoLogin.button}
+              {loginProviders[0]?.id === 'oidc'
+                ? publicStrings.oidcLogin.button.replace('{provider}', loginProviders[0].label)
+                : publicStrings.linuxDoLogin.button}
             </a>
           </p>
           <div className="modal-action">
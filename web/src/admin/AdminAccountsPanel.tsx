@@ -0,0 +1,347 @@
+import { useCallback, useEffect, useState } from 'react'
+
+import {
+  createAdminAccount,
+  deleteAdminAccount,
+  fetchAdminAccounts,
+  revokeAdminAccountSessions,
+  setAdminAccountPassword,
+  updateAdminAccount,
+  type AdminAccount,
+  type AdminRole,
+} from '../api'
+import AdminApiTokensPanel from './AdminApiTokensPanel'
+import AdminModuleSurface from './AdminModuleSurface'
+import AdminLoadingRegion from '../components/AdminLoadingRegion'
+import { StatusBadge } from '../components/StatusBadge'
+import { Button } from '../components/ui/button'
+import { Input } from '../components/ui/input'
+import { Icon } from '../lib/icons'
+import type { Language } from '../i18n'
+
+interface AdminAccountsPanelProps {
+  language: Language
+}
+
+interface AccountDraft {
+  username: string
+  displayName: string
+  role: AdminRole
+  password: string
+}
+
+const EMPTY_DRAFT: AccountDraft = { username: '', displayName: '', role: 'viewer', password: '' }
+
+const ADMIN_ROLES: AdminRole[] = ['viewer', 'operator', 'owner']
+
+function copy(language: Language) {
+  return language === 'zh'
+    ? {
+        title: '管理员账户',
+        description: '为每位管理员创建具名账户并分配角色：查看者只读，操作员可管理密钥、令牌与用户，所有者还可修改设置、查看密钥明文、处理充值并管理管理员账户。审计日志会记录具体账户。',
+        loading: '正在加载管理员账户…',
+        error: '管理员账户加载失败。',
+        createTitle: '创建账户',
+        username: '用户名',
+        displayName: '显示名称（可选）',
+        role: '角色',
+        password: '初始密码（至少 8 位）',
+        create: '创建',
+        empty: '还没有具名管理员账户，当前仍使用内置管理员或 Passkey 登录。',
+        roles: { viewer: '查看者', operator: '操作员', owner: '所有者' } as Record<AdminRole, string>,
+        active: '启用',
+        disabled: '已停用',
+        never: '从未登录',
+        sessions: (count: number) => `${count} 个会话`,
+        disable: '停用',
+        enable: '启用',
+        resetPassword: '重置密码',
+        newPasswordPrompt: '输入新密码（至少 8 位）：',
+        revokeSessions: '注销会话',
+        remove: '删除',
+        confirmRemove: (username: string) => `确认删除管理员账户 ${username}？`,
+        table: { account: '账户', role: '角色', status: '状态', lastLogin: '最近登录', actions: '操作' },
+      }
+    : {
+        title: 'Admin accounts',
+        description:
+          'Give each admin a named account with a role: viewers are read-only, operators manage keys, tokens and users, and owners can also change settings, reveal secrets, handle recharges and manage admin accounts. Audit logs record the account.',
+        loading: 'Loading admin accounts…',
+        error: 'Failed to load admin accounts.',
+        createTitle: 'Create account',
+        username: 'Username',
+        displayName: 'Display name (optional)',
+        role: 'Role',
+        password: 'Initial password (8+ characters)',
+        create: 'Create',
+        empty: 'No named admin accounts yet; admins still sign in with the built-in password or passkeys.',
+        roles: { viewer: 'Viewer', operator: 'Operator', owner: 'Owner' } as Record<AdminRole, string>,
+        active: 'Active',
+        disabled: 'Disabled',
+        never: 'Never',
+        sessions: (count: number) => `${count} session${count === 1 ? '' : 's'}`,
+        disable: 'Disable',
+        enable: 'Enable',
+        resetPassword: 'Reset password',
+        newPasswordPrompt: 'New password (8+ characters):',
+        revokeSessions: 'Sign out sessions',
+        remove: 'Delete',
+        confirmRemove: (username: string) => `Delete admin account ${username}?`,
+        table: { account: 'Account', role: 'Role', status: 'Status', lastLogin: 'Last login', actions: 'Actions' },
+      }
+}
+
+function formatTimestamp(ts: number, language: Language): string {
+  return new Date(ts * 1000).toLocaleString(language === 'zh' ? 'zh-CN' : 'en-US', { hour12: false })
+}
+
+function isForbidden(err: unknown): boolean {
+  return (err as { status?: number } | null)?.status === 403
+}
+
+export default function AdminAccountsPanel({ language }: AdminAccountsPanelProps): JSX.Element | null {
+  const strings = copy(language)
+  const [accounts, setAccounts] = useState<AdminAccount[]>([])
+  const [draft, setDraft] = useState<AccountDraft>(EMPTY_DRAFT)
+  const [loading, setLoading] = useState(true)
+  const [forbidden, setForbidden] = useState(false)
+  const [loaded, setLoaded] = useState(false)
+  const [busy, setBusy] = useState(false)
+  const [error, setError] = useState<string | null>(null)
+
+  const load = useCallback(async (signal?: AbortSignal) => {
+    try {
+      setAccounts(await fetchAdminAccounts(signal))
+      setLoaded(true)
+      setError(null)
+    } catch (err) {
+      if (signal?.aborted) return
+      if (isForbidden(err)) setForbidden(true)
+      setError(err instanceof Error ? err.message : String(err))
+    } finally {
+      if (!signal?.aborted) setLoading(false)
+    }
+  }, [])
+
+  useEffect(() => {
+    const controller = new AbortController()
+    void load(controller.signal)
+    return () => controller.abort()
+  }, [load])
+
+  const run = async (action: () => Promise<unknown>) => {
+    setBusy(true)
+    try {
+      await action()
+      await load()
+    } catch (err) {
+      setError(err instanceof Error ? err.message : String(err))
+    } finally {
+      setBusy(false)
+    }
+  }
+
+  const submitAccount = () =>
+    run(async () => {
+      await createAdminAccount({
+        username: draft.username.trim(),
+        displayName: draft.displayName.trim() || undefined,
+        role: draft.role,
+        password: draft.password,
+      })
+      setDraft(EMPTY_DRAFT)
+    })
+
+  const resetPassword = (account: AdminAccount) => {
+    const password = window.prompt(strings.newPasswordPrompt)
+    if (password) void run(() => setAdminAccountPassword(account.id, password))
+  }
+
+  const removeAccount = (account: AdminAccount) => {
+    if (window.confirm(strings.confirmRemove(account.username))) void run(() => deleteAdminAccount(account.id))
+  }
+
+  // Only owners may manage admin accounts; other roles simply do not see the panel.
+  if (forbidden) return null
+
+  return (
+    <>
+      <AdminModuleSurface className="admin-accounts-panel">
+        <div className="announcements-list-header">
+          <div>
+            <h3>{strings.title}</h3>
+            <p>{strings.description}</p>
+          </div>
+        </div>
+
+        <AdminLoadingRegion
+          loadState={loading ? 'initial_loading' : error && !loaded ? 'error' : 'ready'}
+          loadingLabel={strings.loading}
+          errorLabel={error ?? strings.error}
+          minHeight={160}
+        >
+          {error && loaded ? <div className="alert alert-error">{error}</div> : null}
+
+          <form
+            className="system-settings-config-section"
+            onSubmit={(event) => {
+              event.preventDefault()
+              void submitAccount()
+            }}
+          >
+            <h4>{strings.createTitle}</h4>
+            <div className="system-settings-field-grid">
+              <div className="system-settings-field">
+                <label className="text-sm font-medium" htmlFor="admin-account-username">{strings.username}</label>
+                <Input
+                  id="admin-account-username"
+                  autoComplete="off"
+                  value={draft.username}
+                  onChange={(event) => setDraft({ ...draft, username: event.target.value })}
+                />
+              </div>
+              <div className="system-settings-field">
+                <label className="text-sm font-medium" htmlFor="admin-account-display-name">{strings.displayName}</label>
+                <Input
+                  id="admin-account-display-name"
+                  value={draft.displayName}
+                  onChange={(event) => setDraft({ ...draft, displayName: event.target.value })}
+                />
+              </div>
+              <div className="system-settings-field">
+                <label className="text-sm font-medium" htmlFor="admin-account-role">{strings.role}</label>
+                <select
+                  id="admin-account-role"
+                  value={draft.role}
+                  onChange={(event) => setDraft({ ...draft, role: event.target.value as AdminRole })}
+                >
+                  {ADMIN_ROLES.map((role) => (
+                    <option key={role} value={role}>
+                      {strings.roles[role]}
+                    </option>
+                  ))}
+                </select>
+              </div>
+              <div className="system-settings-field">
+                <label className="text-sm font-medium" htmlFor="admin-account-password">{strings.password}</label>
+                <Input
+                  id="admin-account-password"
+                  type="password"
+                  autoComplete="new-password"
+                  value={draft.password}
+                  onChange={(event) => setDraft({ ...draft, password: event.target.value })}
+                />
+              </div>
+            </div>
+            <div className="table-actions">
+              <Button
+                type="submit"
+                size="sm"
+                disabled={busy || draft.username.trim().length === 0 || draft.password.length < 8}
+              >
+                {strings.create}
+              </Button>
+            </div>
+          </form>
+
+          <section className="system-settings-config-section">
+            {accounts.length === 0 ? (
+              <div className="empty-state alert">{strings.empty}</div>
+            ) : (
+              <div className="table-wrapper">
+                <table className="jobs-table">
+                  <thead>
+                    <tr>
+                      <th>{strings.table.account}</th>
+                      <th>{strings.table.role}</th>
+                      <th>{strings.table.status}</th>
+                      <th>{strings.table.lastLogin}</th>
+                      <th>{strings.table.actions}</th>
+                    </tr>
+                  </thead>
+                  <tbody>
+                    {accounts.map((account) => (
+                      <tr key={account.id}>
+                        <td>
+                          {account.displayName ?? account.username}
+                          <div className="text-xs text-muted-foreground">
+                            @{account.username} · {strings.sessions(account.activeSessions)}
+                          </div>
+                        </td>
+                        <td>
+                          <select
+                            aria-label={strings.role}
+                            value={account.role}
+                            disabled={busy}
+                            onChange={(event) =>
+                              void run(() => updateAdminAccount(account.id, { role: event.target.value as AdminRole }))
+                            }
+                          >
+                            {ADMIN_ROLES.map((role) => (
+                              <option key={role} value={role}>
+                                {strings.roles[role]}
+                              </option>
+                            ))}
+                          </select>
+                        </td>
+                        <td>
+                          <StatusBadge tone={account.disabledAt == null ? 'success' : 'neutral'}>
+                            {account.disabledAt == null ? strings.active : strings.disabled}
+                          </StatusBadge>
+                        </td>
+                        <td>{account.lastLoginAt ? formatTimestamp(account.lastLoginAt, language) : strings.never}</td>
+                        <td className="table-actions">
+                          <Button
+                            type="button"
+                            size="sm"
+                            variant="outline"
+                            disabled={busy}
+                            onClick={() =>
+                              void run(() => updateAdminAccount(account.id, { disabled: account.disabledAt == null }))
+                            }
+                          >
+                            {account.disabledAt == null ? strings.disable : strings.enable}
+                          </Button>
+                          <Button
+                            type="button"
+                            size="sm"
+                            variant="outline"
+                            disabled={busy}
+                            onClick={() => resetPassword(account)}
+                          >
+                            <Icon icon="mdi:key-outline" width={16} height={16} aria-hidden="true" />
+                            <span>{strings.resetPassword}</span>
+                          </Button>
+                          <Button
+                            type="button"
+                            size="sm"
+                            variant="outline"
+                            disabled={busy || account.activeSessions === 0}
+                            onClick={() => void run(() => revokeAdminAccountSessions(account.id))}
+                          >
+                            {strings.revokeSessions}
+                          </Button>
+                          <Button
+                            type="button"
+                            size="sm"
+                            variant="ghost"
+                            disabled={busy}
+                            aria-label={strings.remove}
+                            onClick={() => removeAccount(account)}
+                          >
+                            <Icon icon="mdi:trash-can-outline" width={16} height={16} aria-hidden="true" />
+                          </Button>
+                        </td>
+                      </tr>
+                    ))}
+                  </tbody>
+                </table>
+              </div>
+            )}
+          </section>
+        </AdminLoadingRegion>
+      </AdminModuleSurface>
+      <AdminApiTokensPanel language={language} />
+    </>
+  )
+}
@@ -0,0 +1,320 @@
+import { useCallback, useEffect, useState } from 'react'
+
+import {
+  ADMIN_API_TOKEN_SCOPES,
+  createAdminApiToken,
+  fetchAdminApiTokens,
+  revokeAdminApiToken,
+  type AdminApiToken,
+  type AdminApiTokenScope,
+  type AdminApiTokenStatus,
+} from '../api'
+import AdminModuleSurface from './AdminModuleSurface'
+import AdminLoadingRegion from '../components/AdminLoadingRegion'
+import { StatusBadge, type StatusTone } from '../components/StatusBadge'
+import { Button } from '../components/ui/button'
+import { Input } from '../components/ui/input'
+import { copyText } from '../lib/clipboard'
+import { Icon } from '../lib/icons'
+import type { Language } from '../i18n'
+
+interface AdminApiTokensPanelProps {
+  language: Language
+}
+
+interface TokenDraft {
+  name: string
+  scopes: AdminApiTokenScope[]
+  ipAllowlist: string
+  expiresAt: string
+}
+
+const EMPTY_DRAFT: TokenDraft = { name: '', scopes: [], ipAllowlist: '', expiresAt: '' }
+
+const STATUS_TONES: Record<AdminApiTokenStatus, StatusTone> = {
+  active: 'success',
+  expired: 'warning',
+  revoked: 'error',
+}
+
+function copy(language: Language) {
+  return language === 'zh'
+    ? {
+        title: '管理 API 令牌',
+        description: '供自动化脚本使用的长期管理令牌，以 Authorization: Bearer 方式调用管理接口。每个令牌只能访问所选范围（write 包含 read），可设置过期时间与 IP 白名单；凭据、审计与充值接口不对令牌开放。',
+        loading: '正在加载管理 API 令牌…',
+        error: '管理 API 令牌加载失败。',
+        createTitle: '创建令牌',
+        name: '名称',
+        scopes: '授权范围',
+        ipAllowlist: 'IP 白名单（可选，逗号或换行分隔 IP/CIDR）',
+        expiresAt: '过期时间（可选）',
+        create: '创建',
+        createdTitle: '令牌已创建，请立即复制，它不会再次显示：',
+        copyToken: '复制令牌',
+        dismiss: '我已保存',
+        empty: '还没有管理 API 令牌。',
+        revoke: '撤销',
+        never: '从未',
+        anyIp: '任意 IP',
+        noExpiry: '不过期',
+        statuses: { active: '可用', expired: '已过期', revoked: '已撤销' } as Record<AdminApiTokenStatus, string>,
+        table: { name: '名称', status: '状态', scopes: '范围', ips: 'IP 白名单', expires: '过期', lastUsed: '最近使用', actions: '操作' },
+      }
+    : {
+        title: 'Admin API tokens',
+        description:
+          'Long-lived admin tokens for automation, sent as Authorization: Bearer on admin routes. Each token only reaches the selected scopes (write includes read) and can expire or be limited to an IP allowlist; credential, audit and recharge routes stay closed to tokens.',
+        loading: 'Loading admin API tokens…',
+        error: 'Failed to load admin API tokens.',
+        createTitle: 'Create token',
+        name: 'Name',
+        scopes: 'Scopes',
+        ipAllowlist: 'IP allowlist (optional, IPs or CIDRs separated by commas or lines)',
+        expiresAt: 'Expires at (optional)',
+        create: 'Create',
+        createdTitle: 'Token created. Copy it now; it will not be shown again:',
+        copyToken: 'Copy token',
+        dismiss: 'I saved it',
+        empty: 'No admin API tokens yet.',
+        revoke: 'Revoke',
+        never: 'Never',
+        anyIp: 'Any IP',
+        noExpiry: 'Never',
+        statuses: { active: 'Active', expired: 'Expired', revoked: 'Revoked' } as Record<AdminApiTokenStatus, string>,
+        table: { name: 'Name', status: 'Status', scopes: 'Scopes', ips: 'IP allowlist', expires: 'Expires', lastUsed: 'Last used', actions: 'Actions' },
+      }
+}
+
+function formatTimestamp(ts: number, language: Language): string {
+  return new Date(ts * 1000).toLocaleString(language === 'zh' ? 'zh-CN' : 'en-US', { hour12: false })
+}
+
+export default function AdminApiTokensPanel({ language }: AdminApiTokensPanelProps): JSX.Element | null {
+  const strings = copy(language)
+  const [tokens, setTokens] = useState<AdminApiToken[]>([])
+  const [draft, setDraft] = useState<TokenDraft>(EMPTY_DRAFT)
+  const [createdToken, setCreatedToken] = useState<string | null>(null)
+  const [loading, setLoading] = useState(true)
+  const [forbidden, setForbidden] = useState(false)
+  const [loaded, setLoaded] = useState(false)
+  const [busy, setBusy] = useState(false)
+  const [error, setError] = useState<string | null>(null)
+
+  const load = useCallback(async (signal?: AbortSignal) => {
+    try {
+      setTokens(await fetchAdminApiTokens(signal))
+      setLoaded(true)
+      setError(null)
+    } catch (err) {
+      if (signal?.aborted) return
+      if ((err as { status?: number } | null)?.status === 403) setForbidden(true)
+      setError(err instanceof Error ? err.message : String(err))
+    } finally {
+      if (!signal?.aborted) setLoading(false)
+    }
+  }, [])
+
+  useEffect(() => {
+    const controller = new AbortController()
+    void load(controller.signal)
+    return () => controller.abort()
+  }, [load])
+
+  const run = async (action: () => Promise<unknown>) => {
+    setBusy(true)
+    try {
+      await action()
+      await load()
+    } catch (err) {
+      setError(err instanceof Error ? err.message : String(err))
+    } finally {
+      setBusy(false)
+    }
+  }
+
+  const submitToken = () =>
+    run(async () => {
+      const created = await createAdminApiToken({
+        name: draft.name.trim(),
+        scopes: draft.scopes,
+        ipAllowlist: draft.ipAllowlist
+          .split(/[\s,]+/)
+          .map((entry) => entry.trim())
+          .filter(Boolean),
+        expiresAt: draft.expiresAt ? Math.floor(new Date(draft.expiresAt).getTime() / 1000) : null,
+      })
+      setCreatedToken(created.token)
+      setDraft(EMPTY_DRAFT)
+    })
+
+  const toggleScope = (scope: AdminApiTokenScope) =>
+    setDraft({
+      ...draft,
+      scopes: draft.scopes.includes(scope) ? draft.scopes.filter((item) => item !== scope) : [...draft.scopes, scope],
+    })
+
+  // Like admin accounts, token management is reserved for owners.
+  if (forbidden) return null
+
+  return (
+    <AdminModuleSurface className="admin-api-tokens-panel">
+      <div className="announcements-list-header">
+        <div>
+          <h3>{strings.title}</h3>
+          <p>{strings.description}</p>
+        </div>
+      </div>
+
+      <AdminLoadingRegion
+        loadState={loading ? 'initial_loading' : error && !loaded ? 'error' : 'ready'}
+        loadingLabel={strings.loading}
+        errorLabel={error ?? strings.error}
+        minHeight={160}
+      >
+        {error && loaded ? <div className="alert alert-error">{error}</div> : null}
+
+        {createdToken ? (
+          <div className="alert alert-warning">
+            <span>{strings.createdTitle}</span>
+            <code>{createdToken}</code>
+            <div className="table-actions">
+              <Button type="button" size="sm" variant="outline" onClick={() => void copyText(createdToken)}>
+                <Icon icon="mdi:content-copy" width={16} height={16} aria-hidden="true" />
+                <span>{strings.copyToken}</span>
+              </Button>
+              <Button type="button" size="sm" variant="ghost" onClick={() => setCreatedToken(null)}>
+                {strings.dismiss}
+              </Button>
+            </div>
+          </div>
+        ) : null}
+
+        <form
+          className="system-settings-config-section"
+          onSubmit={(event) => {
+            event.preventDefault()
+            void submitToken()
+          }}
+        >
+          <h4>{strings.createTitle}</h4>
+          <div className="system-settings-field-grid">
+            <div className="system-settings-field">
+              <label className="text-sm font-medium" htmlFor="admin-api-token-name">{strings.name}</label>
+              <Input
+                id="admin-api-token-name"
+                value={draft.name}
+                onChange={(event) => setDraft({ ...draft, name: event.target.value })}
+              />
+            </div>
+            <div className="system-settings-field">
+              <label className="text-sm font-medium" htmlFor="admin-api-token-expires">{strings.expiresAt}</label>
+              <Input
+                id="admin-api-token-expires"
+                type="datetime-local"
+                value={draft.expiresAt}
+                onChange={(event) => setDraft({ ...draft, expiresAt: event.target.value })}
+              />
+            </div>
+            <div className="system-settings-field">
+              <label className="text-sm font-medium" htmlFor="admin-api-token-ips">{strings.ipAllowlist}</label>
+              <Input
+                id="admin-api-token-ips"
+                placeholder="10.0.0.0/8, 203.0.113.7"
+                value={draft.ipAllowlist}
+                onChange={(event) => setDraft({ ...draft, ipAllowlist: event.target.value })}
+              />
+            </div>
+          </div>
+          <div className="system-settings-field">
+            <span className="text-sm font-medium">{strings.scopes}</span>
+            <div className="table-actions">
+              {ADMIN_API_TOKEN_SCOPES.map((scope) => (
+                <Button
+                  key={scope}
+                  type="button"
+                  size="sm"
+                  variant={draft.scopes.includes(scope) ? 'default' : 'outline'}
+                  aria-pressed={draft.scopes.includes(scope)}
+                  onClick={() => toggleScope(scope)}
+                >
+                  {scope}
+                </Button>
+              ))}
+            </div>
+          </div>
+          <div className="table-actions">
+            <Button
+              type="submit"
+              size="sm"
+              disabled={busy || draft.name.trim().length === 0 || draft.scopes.length === 0}
+            >
+              {strings.create}
+            </Button>
+          </div>
+        </form>
+
+        <section className="system-settings-config-section">
+          {tokens.length === 0 ? (
+            <div className="empty-state alert">{strings.empty}</div>
+          ) : (
+            <div className="table-wrapper">
+              <table className="jobs-table">
+                <thead>
+                  <tr>
+                    <th>{strings.table.name}</th>
+                    <th>{strings.table.status}</th>
+                    <th>{strings.table.scopes}</th>
+                    <th>{strings.table.ips}</th>
+                    <th>{strings.table.expires}</th>
+                    <th>{strings.table.lastUsed}</th>
+                    <th>{strings.table.actions}</th>
+                  </tr>
+                </thead>
+                <tbody>
+                  {tokens.map((token) => (
+                    <tr key={token.id}>
+                      <td>
+                        {token.name}
+                        <div className="text-xs text-muted-foreground">
+                          <code>tha-{token.id}-…</code>
+                          {token.createdBy ? ` · ${token.createdBy}` : ''}
+                        </div>
+                      </td>
+                      <td>
+                        <StatusBadge tone={STATUS_TONES[token.status] ?? 'neutral'}>
+                          {strings.statuses[token.status] ?? token.status}
+                        </StatusBadge>
+                      </td>
+                      <td>{token.scopes.join(', ')}</td>
+                      <td>{token.ipAllowlist.length > 0 ? token.ipAllowlist.join(', ') : strings.anyIp}</td>
+                      <td>{token.expiresAt ? formatTimestamp(token.expiresAt, language) : strings.noExpiry}</td>
+                      <td>
+                        {token.lastUsedAt ? formatTimestamp(token.lastUsedAt, language) : strings.never}
+                        {token.lastUsedIp ? <div className="text-xs text-muted-foreground">{token.lastUsedIp}</div> : null}
+                      </td>
+                      <td className="table-actions">
+                        {token.status !== 'revoked' ? (
+                          <Button
+                            type="button"
+                            size="sm"
+                            variant="outline"
+                            disabled={busy}
+                            onClick={() => void run(() => revokeAdminApiToken(token.id))}
+                          >
+                            <Icon icon="mdi:trash-can-outline" width={16} height={16} aria-hidden="true" />
+                            <span>{strings.revoke}</span>
+                          </Button>
+                        ) : null}
+                      </td>
+                    </tr>
+                  ))}
+                </tbody>
+              </table>
+            </div>
+          )}
+        </section>
+      </AdminLoadingRegion>
+    </AdminModuleSurface>
+  )
+}
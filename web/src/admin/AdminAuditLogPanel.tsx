@@ -0,0 +1,310 @@
+import { useCallback, useEffect, useState } from 'react'
+
+import {
+  fetchAdminAuditEntries,
+  verifyAdminAuditChain,
+  type AdminAuditKind,
+  type AdminAuditVerification,
+  type PaginatedAdminAuditEntries,
+} from '../api'
+import AdminModuleSurface from './AdminModuleSurface'
+import AdminLoadingRegion from '../components/AdminLoadingRegion'
+import { StatusBadge } from '../components/StatusBadge'
+import { Button } from '../components/ui/button'
+import { Input } from '../components/ui/input'
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from '../components/ui/select'
+import { Icon } from '../lib/icons'
+import type { Language } from '../i18n'
+
+interface AdminAuditLogPanelProps {
+  language: Language
+}
+
+interface AuditFilters {
+  actor: string
+  route: string
+  kind: AdminAuditKind | 'any'
+}
+
+const PER_PAGE = 50
+const EMPTY_FILTERS: AuditFilters = { actor: '', route: '', kind: 'any' }
+
+function copy(language: Language) {
+  return language === 'zh'
+    ? {
+        title: '管理审计日志',
+        description: '记录每一次管理写操作与密钥查看。日志只追加、按哈希串联，可通过 `tavily-hikari admin audit export` 导出并校验。',
+        loading: '正在加载审计日志…',
+        error: '审计日志加载失败。',
+        empty: '没有匹配的审计记录。',
+        verify: '校验哈希链',
+        verifying: '校验中…',
+        chainOk: (entries: number) => `哈希链完整，共 ${entries} 条`,
+        chainBroken: (seq: number | null, reason: string | null) => `哈希链在 #${seq ?? '?'} 处断开：${reason ?? ''}`,
+        actor: '操作人',
+        route: '路由',
+        anyKind: '全部类型',
+        kinds: { mutation: '写操作', sensitive_read: '敏感读取' } as Record<AdminAuditKind, string>,
+        apply: '筛选',
+        reset: '重置',
+        prev: '上一页',
+        next: '下一页',
+        table: { time: '时间', actor: '操作人', action: '操作', status: '状态', change: '变更' },
+      }
+    : {
+        title: 'Admin audit log',
+        description:
+          'Every admin mutation and secret reveal is recorded here. The log is append-only and hash-chained; export and verify it with `tavily-hikari admin audit export`.',
+        loading: 'Loading audit log…',
+        error: 'Failed to load audit log.',
+        empty: 'No matching audit entries.',
+        verify: 'Verify chain',
+        verifying: 'Verifying…',
+        chainOk: (entries: number) => `Chain intact across ${entries} entries`,
+        chainBroken: (seq: number | null, reason: string | null) => `Chain broken at #${seq ?? '?'}: ${reason ?? ''}`,
+        actor: 'Actor',
+        route: 'Route',
+        anyKind: 'Any kind',
+        kinds: { mutation: 'Mutation', sensitive_read: 'Sensitive read' } as Record<AdminAuditKind, string>,
+        apply: 'Filter',
+        reset: 'Reset',
+        prev: 'Previous',
+        next: 'Next',
+        table: { time: 'Time', actor: 'Actor', action: 'Action', status: 'Status', change: 'Change' },
+      }
+}
+
+function formatTimestamp(ts: number, language: Language): string {
+  return new Date(ts * 1000).toLocaleString(language === 'zh' ? 'zh-CN' : 'en-US', { hour12: false })
+}
+
+function formatChange(before: unknown, after: unknown): string | null {
+  if (before == null && after == null) return null
+  if (before == null) return JSON.stringify(after)
+  return `${JSON.stringify(before)} → ${JSON.stringify(after)}`
+}
+
+export default function AdminAuditLogPanel({ language }: AdminAuditLogPanelProps): JSX.Element {
+  const strings = copy(language)
+  const [draft, setDraft] = useState<AuditFilters>(EMPTY_FILTERS)
+  const [filters, setFilters] = useState<AuditFilters>(EMPTY_FILTERS)
+  const [page, setPage] = useState(1)
+  const [data, setData] = useState<PaginatedAdminAuditEntries | null>(null)
+  const [loading, setLoading] = useState(true)
+  const [error, setError] = useState<string | null>(null)
+  const [verification, setVerification] = useState<AdminAuditVerification | null>(null)
+  const [verifying, setVerifying] = useState(false)
+
+  const load = useCallback(
+    async (signal?: AbortSignal) => {
+      try {
+        const response = await fetchAdminAuditEntries(
+          {
+            page,
+            perPage: PER_PAGE,
+            actor: filters.actor,
+            route: filters.route,
+            kind: filters.kind === 'any' ? null : filters.kind,
+          },
+          signal,
+        )
+        setData(response)
+        setError(null)
+      } catch (err) {
+        if (signal?.aborted) return
+        setError(err instanceof Error ? err.message : String(err))
+      } finally {
+        if (!signal?.aborted) setLoading(false)
+      }
+    },
+    [filters, page],
+  )
+
+  useEffect(() => {
+    const controller = new AbortController()
+    void load(controller.signal)
+    return () => controller.abort()
+  }, [load])
+
+  const verify = async () => {
+    setVerifying(true)
+    try {
+      setVerification(await verifyAdminAuditChain())
+    } catch (err) {
+      setError(err instanceof Error ? err.message : String(err))
+    } finally {
+      setVerifying(false)
+    }
+  }
+
+  const totalPages = data ? Math.max(1, Math.ceil(data.total / data.perPage)) : 1
+
+  return (
+    <AdminModuleSurface className="admin-audit-log-panel">
+      <div className="announcements-list-header">
+        <div>
+          <h3>{strings.title}</h3>
+          <p>{strings.description}</p>
+        </div>
+        <div className="table-actions">
+          {verification ? (
+            <StatusBadge tone={verification.ok ? 'success' : 'error'}>
+              {verification.ok
+                ? strings.chainOk(verification.entries)
+                : strings.chainBroken(verification.brokenAtSeq, verification.reason)}
+            </StatusBadge>
+          ) : null}
+          <Button type="button" size="sm" variant="outline" disabled={verifying} onClick={() => void verify()}>
+            <Icon icon="mdi:shield-check-outline" width={16} height={16} aria-hidden="true" />
+            <span>{verifying ? strings.verifying : strings.verify}</span>
+          </Button>
+        </div>
+      </div>
+
+      <form
+        className="system-settings-config-section"
+        onSubmit={(event) => {
+          event.preventDefault()
+          setPage(1)
+          setFilters(draft)
+        }}
+      >
+        <div className="system-settings-field-grid">
+          <div className="system-settings-field">
+            <label className="text-sm font-medium" htmlFor="admin-audit-actor">{strings.actor}</label>
+            <Input
+              id="admin-audit-actor"
+              value={draft.actor}
+              onChange={(event) => setDraft({ ...draft, actor: event.target.value })}
+            />
+          </div>
+          <div className="system-settings-field">
+            <label className="text-sm font-medium" htmlFor="admin-audit-route">{strings.route}</label>
+            <Input
+              id="admin-audit-route"
+              value={draft.route}
+              placeholder="/api/settings"
+              onChange={(event) => setDraft({ ...draft, route: event.target.value })}
+            />
+          </div>
+          <div className="system-settings-field">
+            <label className="text-sm font-medium">{strings.anyKind}</label>
+            <Select
+              value={draft.kind}
+              onValueChange={(value) => setDraft({ ...draft, kind: value as AuditFilters['kind'] })}
+            >
+              <SelectTrigger aria-label={strings.anyKind}>
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent>
+                <SelectItem value="any">{strings.anyKind}</SelectItem>
+                <SelectItem value="mutation">{strings.kinds.mutation}</SelectItem>
+                <SelectItem value="sensitive_read">{strings.kinds.sensitive_read}</SelectItem>
+              </SelectContent>
+            </Select>
+          </div>
+        </div>
+        <div className="table-actions">
+          <Button type="submit" size="sm">
+            {strings.apply}
+          </Button>
+          <Button
+            type="button"
+            variant="outline"
+            size="sm"
+            onClick={() => {
+              setDraft(EMPTY_FILTERS)
+              setFilters(EMPTY_FILTERS)
+              setPage(1)
+            }}
+          >
+            {strings.reset}
+          </Button>
+        </div>
+      </form>
+
+      <AdminLoadingRegion
+        loadState={loading ? 'initial_loading' : error ? 'error' : 'ready'}
+        loadingLabel={strings.loading}
+        errorLabel={error ?? strings.error}
+        minHeight={160}
+      >
+        {!data || data.items.length === 0 ? (
+          <div className="empty-state alert">{strings.empty}</div>
+        ) : (
+          <div className="table-wrapper">
+            <table className="jobs-table">
+              <thead>
+                <tr>
+                  <th>{strings.table.time}</th>
+                  <th>{strings.table.actor}</th>
+                  <th>{strings.table.action}</th>
+                  <th>{strings.table.status}</th>
+                  <th>{strings.table.change}</th>
+                </tr>
+              </thead>
+              <tbody>
+                {data.items.map((entry) => {
+                  const change = formatChange(entry.before, entry.after)
+                  return (
+                    <tr key={entry.seq}>
+                      <td>
+                        {formatTimestamp(entry.occurredAt, language)}
+                        <div className="text-xs text-muted-foreground">#{entry.seq}</div>
+                      </td>
+                      <td>
+                        {entry.actorName ?? entry.actorId ?? entry.actorKind}
+                        <div className="text-xs text-muted-foreground">
+                          {entry.actorKind}
+                          {entry.clientIp ? ` · ${entry.clientIp}` : ''}
+                        </div>
+                      </td>
+                      <td>
+                        <StatusBadge tone={entry.kind === 'sensitive_read' ? 'warning' : 'info'}>
+                          {strings.kinds[entry.kind] ?? entry.kind}
+                        </StatusBadge>
+                        <div className="text-xs">
+                          <code>
+                            {entry.method} {entry.path}
+                          </code>
+                        </div>
+                      </td>
+                      <td>{entry.status}</td>
+                      <td>
+                        {change ? (
+                          <code className="text-xs break-all">{change}</code>
+                        ) : (
+                          <span className="text-muted-foreground">—</span>
+                        )}
+                      </td>
+                    </tr>
+                  )
+                })}
+              </tbody>
+            </table>
+          </div>
+        )}
+      </AdminLoadingRegion>
+
+      {data && totalPages > 1 ? (
+        <div className="table-actions">
+          <Button type="button" variant="outline" size="sm" disabled={page <= 1} onClick={() => setPage(page - 1)}>
+            {strings.prev}
+          </Button>
+          <span className="text-sm text-muted-foreground">
+            {page} / {totalPages}
+          </span>
+          <Button
+            type="button"
+            variant="outline"
+            size="sm"
+            disabled={page >= totalPages}
+            onClick={() => setPage(page + 1)}
+          >
+            {strings.next}
+          </Button>
+        </div>
+      ) : null}
+    </AdminModuleSurface>
+  )
+}
@@ -78,6 +78,8 @@ import AdminUserRankingsPage, { RankingsMeta } from './AdminUserRankingsPage'
 import PressureAnalysisScreen from './PressureAnalysisScreen'
 import AdminJobTriggerMenu from './AdminJobTriggerMenu'
 import McpSessionBindingsStatusTabs from './McpSessionBindingsStatusTabs'
+import KeyTierCapacityStrip from './KeyTierCapacityStrip'
+import KeyRateBudgetPanel, { KeyRateBudgetStrip } from './KeyRateBudgetPanel'
 import { AnchoredApiKeyBulkSyncProgressBubble } from './ApiKeyBulkSyncProgressBubble'
 import {
   createDashboardMonthMetrics,
@@ -219,6 +221,7 @@ import {
   fetchSummary,
   fetchVersion,
   type ApiKeyStats,
+  type ApiKeyTierCapacity,
   type DashboardMonthSeries,
   type DashboardSnapshotEvent,
   type DashboardHourlyRequestWindow,
@@ -337,7 +340,7 @@ import {
   fetchAdminUserRankings,
   type AdminUserRankingsSnapshot,
 } from '../api/adminRankings'
-import type { TokenGroup } from '../api/tokens'
+import type { TokenGroup, TokenLifetime } from '../api/tokens'
 import {
   createDialogProgressState,
   type ForwardProxyDialogProgressState,
@@ -355,6 +358,8 @@ import {
   type TokenLogRequestKindQuickProtocol,
 } from '../tokenLogRequestKinds'
 import { finalizeForwardProxyRevalidate } from './forwardProxyRevalidate'
+import RequestLogReplayPanel from './RequestLogReplayPanel'
+import AdminUserStatusCell from './AdminUserStatusCell'
 
 const LazyAdminRecentRequestsPanel = lazy(() => import('../components/AdminRecentRequestsPanel'))
 const LazyApiKeysValidationDialog = lazy(async () =>
@@ -366,11 +371,18 @@ const LazyTokenDetail = lazy(() => import('../pages/TokenDetail'))
 const LazyForwardProxySettingsModule = lazy(() => import('./ForwardProxySettingsModule'))
 const LazyKeyStickyPanels = lazy(() => import('./KeyStickyPanels'))
 const LazyAlertsCenter = lazy(() => import('./AlertsCenter'))
+const LazyAlertWebhooksPanel = lazy(() => import('./AlertWebhooksPanel'))
+const LazyAlertRulesPanel = lazy(() => import('./AlertRulesPanel'))
+const LazyAlertSilencesPanel = lazy(() => import('./AlertSilencesPanel'))
 const LazyAnnouncementsModule = lazy(() => import('./AnnouncementsModule'))
 const LazySystemSettingsModule = lazy(() => import('./SystemSettingsModule'))
 const LazyUpstreamPrivacyStatusModule = lazy(() => import('./UpstreamPrivacyStatusModule'))
 const LazyMcpSessionBindingsModule = lazy(() => import('./McpSessionBindingsModule'))
 const LazyAdminSecuritySettingsModule = lazy(() => import('./AdminSecuritySettingsModule'))
+const LazyAdminAuditLogPanel = lazy(() => import('./AdminAuditLogPanel'))
+const LazyRegistrationInvitesPanel = lazy(() => import('./RegistrationInvitesPanel'))
+const LazyTeamsPanel = lazy(() => import('./TeamsPanel'))
+const LazyAdminAccountsPanel = lazy(() => import('./AdminAccountsPanel'))
 const LazyAdminRechargeRecordsModule = lazy(() => import('./AdminRechargeRecordsModule'))
 const LazyUserDetailSharedUsagePanel = lazy(async () =>
   import('./UserDetailSharedUsagePanel').then((module) => ({
@@ -556,6 +568,12 @@ const EMPTY_USER_TAG_FORM: UserTagFormState = {
   monthlyCreditsDelta: '0',
 }
 
+function localDateTimeInputToUnixSeconds(value: string): number | null {
+  if (!value) return null
+  const millis = new Date(value).getTime()
+  return Number.isFinite(millis) ? Math.floor(millis / 1000) : null
+}
+
 function splitMultilineEntries(value: string): string[] {
   const seen = new Set<string>()
   const entries: string[] = []
@@ -1662,6 +1680,14 @@ function formatKeyEffectSummary(
         : 'A later successful request cleared this key temporary cooldown'
     case 'cleared_quarantine':
       return language === 'zh' ? '管理员已解除该 Key 的隔离' : 'An admin cleared the quarantine on this key'
+    case 'response_cache_hit':
+      return language === 'zh'
+        ? '由响应缓存直接应答，未使用上游 Key'
+        : 'Answered from the response cache without using an upstream key'
+    case 'request_coalesced':
+      return language === 'zh'
+        ? '共享了同一时间相同请求的上游响应，未使用上游 Key'
+        : 'Shared the upstream response of an identical in-flight request without using an upstream key'
     case 'none':
       return strings.logDetails.noKeyEffect
     default:
@@ -1686,6 +1712,8 @@ function keyEffectTone(code: string | null | undefined): StatusTone {
     case 'restored_active':
     case 'transient_backoff_cleared':
     case 'cleared_quarantine':
+    case 'response_cache_hit':
+    case 'request_coalesced':
       return 'success'
     case 'transient_backoff_set':
       return 'warning'
@@ -1708,6 +1736,10 @@ function keyEffectBadgeLabel(log: RequestLog, strings: AdminTranslations): strin
       return strings.logs.keyEffects.transientBackoffCleared
     case 'cleared_quarantine':
       return strings.logs.keyEffects.clearedQuarantine
+    case 'response_cache_hit':
+      return strings.logs.keyEffects.responseCacheHit
+    case 'request_coalesced':
+      return strings.logs.keyEffects.requestCoalesced
     case 'none':
     case '':
       return strings.logs.keyEffects.none
@@ -1814,6 +1846,7 @@ function AdminDashboard(): JSX.Element {
   const [keyGroupFacets, setKeyGroupFacets] = useState<Array<{ value: string; count: number }>>([])
   const [keyStatusFacets, setKeyStatusFacets] = useState<Array<{ value: string; count: number }>>([])
   const [keyRegionFacets, setKeyRegionFacets] = useState<Array<{ value: string; count: number }>>([])
+  const [keyTiers, setKeyTiers] = useState<ApiKeyTierCapacity[]>([])
   const [tokens, setTokens] = useState<AuthToken[]>([])
   const tokenPanelRef = useRef<HTMLElement | null>(null)
   const [tokenBulkPanelLeft, setTokenBulkPanelLeft] = useState('50%')
@@ -1894,6 +1927,7 @@ function AdminDashboard(): JSX.Element {
   const [requestLogsCatalog, setRequestLogsCatalog] = useState<RequestLogsCatalog | null>(null)
   const [requestLogOutcomeFilter, setRequestLogOutcomeFilter] = useState<RecentRequestsOutcomeFilter | null>(null)
   const [requestLogKeyFilter, setRequestLogKeyFilter] = useState<string | null>(null)
+  const [requestLogSearch, setRequestLogSearch] = useState('')
   const [requestsLoadState, setRequestsLoadState] = useState<QueryLoadState>('initial_loading')
   const [requestsError, setRequestsError] = useState<string | null>(null)
   const [requestEntityDrawer, setRequestEntityDrawer] = useState<{ kind: 'key' | 'token'; id: string } | null>(null)
@@ -2144,6 +2178,8 @@ function AdminDashboard(): JSX.Element {
   const keysValidateRunIdRef = useRef(0)
   const [keysValidation, setKeysValidation] = useState<KeysValidationState | null>(null)
   const [newTokenNote, setNewTokenNote] = useState('')
+  const [newTokenNotBefore, setNewTokenNotBefore] = useState('')
+  const [newTokenExpiresAt, setNewTokenExpiresAt] = useState('')
   const [submitting, setSubmitting] = useState(false)
   const [deletingId, setDeletingId] = useState<string | null>(null)
   const [togglingId, setTogglingId] = useState<string | null>(null)
@@ -3889,6 +3925,7 @@ function AdminDashboard(): JSX.Element {
         bindingEffect: requestLogBindingEffectFilter,
         selectionEffect: requestLogSelectionEffectFilter,
         keyId: requestLogKeyFilter ?? undefined,
+        search: requestLogSearch,
       },
       request.signal,
     )
@@ -3930,6 +3967,7 @@ function AdminDashboard(): JSX.Element {
     requestLogBindingEffectFilter,
     requestLogSelectionEffectFilter,
     requestLogKeyFilter,
+    requestLogSearch,
     requestLogResultFilter,
   ])
 
@@ -4017,6 +4055,12 @@ function AdminDashboard(): JSX.Element {
     setLogsDirection('older')
   }, [])
 
+  const handleRequestLogSearch = useCallback((value: string) => {
+    setRequestLogSearch(value)
+    setLogsCursor(null)
+    setLogsDirection('older')
+  }, [])
+
   const loadRequestLogBodies = useCallback(
     (log: RequestLog, signal: AbortSignal) => fetchRequestLogDetails(log.id, signal),
     [],
@@ -4103,6 +4147,7 @@ function AdminDashboard(): JSX.Element {
         setKeyGroupFacets(result.facets.groups)
         setKeyStatusFacets(result.facets.statuses)
         setKeyRegionFacets(result.facets.regions)
+        setKeyTiers(result.tiers ?? [])
         setKeysLoadState('ready')
         keysLoadedRef.current = true
         keysQueryKeyRef.current = nextQueryKey
@@ -5219,6 +5264,7 @@ function AdminDashboard(): JSX.Element {
         bindingEffect: requestLogBindingEffectFilter,
         selectionEffect: requestLogSelectionEffectFilter,
         keyId: requestLogKeyFilter,
+        search: requestLogSearch,
       })
       if (listPlan.kind === 'empty') {
         setLogs([])
@@ -5364,6 +5410,7 @@ function AdminDashboard(): JSX.Element {
             setKeyGroupFacets(result.facets.groups)
             setKeyStatusFacets(result.facets.statuses)
             setKeyRegionFacets(result.facets.regions)
+            setKeyTiers(result.tiers ?? [])
             setKeysLoadState('ready')
             keysLoadedRef.current = true
             keysQueryKeyRef.current = nextQueryKey
@@ -6485,6 +6532,11 @@ function AdminDashboard(): JSX.Element {
     }
   }
 
+  const newTokenLifetime = (): TokenLifetime => ({
+    not_before: localDateTimeInputToUnixSeconds(newTokenNotBefore),
+    expires_at: localDateTimeInputToUnixSeconds(newTokenExpiresAt),
+  })
+
   const handleAddToken = async (anchorEl?: HTMLElement | null) => {
     const note = newTokenNote.trim()
     void anchorEl
@@ -6492,8 +6544,10 @@ function AdminDashboard(): JSX.Element {
     setManualCopyDialog(null)
     setSubmitting(true)
     try {
-      const { token } = await createToken(note || undefined)
+      const { token } = await createToken(note || undefined, newTokenLifetime())
       setNewTokenNote('')
+      setNewTokenNotBefore('')
+      setNewTokenExpiresAt('')
       const copyResult = await copyToClipboard(token)
       if (!copyResult.ok) {
         setManualCopyDialog({
@@ -6773,6 +6827,7 @@ function AdminDashboard(): JSX.Element {
     setKeyGroupFacets(pagedKeys.facets.groups)
     setKeyStatusFacets(pagedKeys.facets.statuses)
     setKeyRegionFacets(pagedKeys.facets.regions)
+    setKeyTiers(pagedKeys.tiers ?? [])
     return pagedKeys
   }
   const toggleAllowRegistration = async () => {
@@ -7274,7 +7329,12 @@ function AdminDashboard(): JSX.Element {
     if (!group) return
     setBatchCreating(true)
     try {
-      const res = await createTokensBatch(group, Math.max(1, Math.min(1000, batchCount)), newTokenNote.trim() || undefined)
+      const res = await createTokensBatch(
+        group,
+        Math.max(1, Math.min(1000, batchCount)),
+        newTokenNote.trim() || undefined,
+        newTokenLifetime(),
+      )
       const links = res.tokens.map((t) => `${window.location.origin}/#${encodeURIComponent(t)}`).join('\n')
       setBatchShareText(links)
       // refresh list to first page
@@ -10270,6 +10330,22 @@ function AdminDashboard(): JSX.Element {
             onChange={(e) => setNewTokenNote(e.target.value)}
             aria-label={tokenStrings.notePlaceholder}
           />
+          <Input
+            type="datetime-local"
+            name="new-token-not-before"
+            title={tokenStrings.notBeforeLabel}
+            value={newTokenNotBefore}
+            onChange={(e) => setNewTokenNotBefore(e.target.value)}
+            aria-label={tokenStrings.notBeforeLabel}
+          />
+          <Input
+            type="datetime-local"
+            name="new-token-expires-at"
+            title={tokenStrings.expiresAtLabel}
+            value={newTokenExpiresAt}
+            onChange={(e) => setNewTokenExpiresAt(e.target.value)}
+            aria-label={tokenStrings.expiresAtLabel}
+          />
           <Button
             type="button"
             onClick={(event) => void handleAddToken(event.currentTarget)}
@@ -10659,6 +10735,7 @@ function AdminDashboard(): JSX.Element {
           onOpenUser={(userId) => navigateUser(userId, { preserveUsersContext: true })}
         />
       )}
+      {showDashboard && <KeyRateBudgetStrip language={language} budgets={summary?.key_rate_budgets ?? []} />}
 
       {showRankings && (
         <AdminUserRankingsPage
@@ -11146,6 +11223,7 @@ function AdminDashboard(): JSX.Element {
                 {renderKeyQuickAddToolbar()}
               </div>
             )}
+          <KeyTierCapacityStrip language={language} tiers={keyTiers} />
           <div style={keysUtilityRowStyle}>
             <div style={keysFilterClusterStyle}>
               <div style={{ display: 'flex', alignItems: 'center', gap: 8 }}>
@@ -11862,6 +11940,8 @@ function AdminDashboard(): JSX.Element {
           keyOptions={requestLogFacets.keys}
           selectedKeyId={requestLogKeyFilter}
           onKeyFilterChange={handleRequestLogKeyFilter}
+          searchQuery={requestLogSearch}
+          onSearchChange={handleRequestLogSearch}
           showKeyColumn
           showTokenColumn
           perPage={logsPerPage}
@@ -11877,6 +11957,15 @@ function AdminDashboard(): JSX.Element {
           onOpenKey={openRequestKeyDrawer}
           onOpenToken={openRequestTokenDrawer}
           loadLogBodies={loadRequestLogBodies}
+          renderLogReplay={(log, original) => (
+            <RequestLogReplayPanel
+              key={log.id}
+              log={log}
+              originalRequestBody={original.requestBody}
+              originalResponseBody={original.responseBody}
+              language={language}
+            />
+          )}
           />
         </AdminLazyBoundary>
       )}
@@ -12311,9 +12400,7 @@ function AdminDashboard(): JSX.Element {
                           )}
                         </td>
                         <td>
-                          <StatusBadge tone={item.active ? 'success' : 'neutral'}>
-                            {item.active ? usersStrings.status.active : usersStrings.status.inactive}
-                          </StatusBadge>
+                          <AdminUserStatusCell item={item} usersStrings={usersStrings} onApproved={refreshUsersList} />
                         </td>
                         <td className="admin-users-tags-cell">
                           <UserTagBadgeList
@@ -12388,9 +12475,7 @@ function AdminDashboard(): JSX.Element {
                     </div>
                     <div className="admin-mobile-kv">
                       <span>{usersStrings.table.status}</span>
-                      <StatusBadge tone={item.active ? 'success' : 'neutral'}>
-                        {item.active ? usersStrings.status.active : usersStrings.status.inactive}
-                      </StatusBadge>
+                      <AdminUserStatusCell item={item} usersStrings={usersStrings} onApproved={refreshUsersList} />
                     </div>
                     <div className="admin-mobile-kv">
                       <span>{usersStrings.table.tags}</span>
@@ -12455,6 +12540,9 @@ function AdminDashboard(): JSX.Element {
           </section>
 
           {renderUserTagSummaryPanel()}
+          <AdminLazyBoundary loadingLabel={loadingStateStrings.switching} minHeight={160}>
+            <LazyTeamsPanel language={language} />
+          </AdminLazyBoundary>
         </>
       )}
       {showAlerts && (
@@ -12471,6 +12559,9 @@ function AdminDashboard(): JSX.Element {
             formatTimeDetail={formatMonthDay}
             inlineTabsVariant="mobile"
           />
+          <LazyAlertRulesPanel language={language} refreshToken={alertsRefreshToken} />
+          <LazyAlertSilencesPanel language={language} refreshToken={alertsRefreshToken} />
+          <LazyAlertWebhooksPanel language={language} refreshToken={alertsRefreshToken} />
         </AdminLazyBoundary>
       )}
 
@@ -12514,6 +12605,7 @@ function AdminDashboard(): JSX.Element {
             onOpenMcpSessionBindings={() => navigateMcpSessionBindings()}
             onApply={saveSystemSettings}
           />
+          <LazyRegistrationInvitesPanel language={language} />
         </AdminLazyBoundary>
       )}
 
@@ -12559,6 +12651,8 @@ function AdminDashboard(): JSX.Element {
             strings={systemSettingsStrings}
             profile={profile}
           />
+          <LazyAdminAccountsPanel language={language} />
+          <LazyAdminAuditLogPanel language={language} />
         </AdminLazyBoundary>
       )}
 
@@ -13662,6 +13756,8 @@ export function KeyDetails({
         </AdminLoadingRegion>
       </section>
 
+      <KeyRateBudgetPanel language={language} keyId={id} usage={summary?.key_rate_budgets?.[0] ?? null} onSaved={() => void load('refresh')} />
+
       <section className="surface panel">
         <div className="panel-header">
           <div>
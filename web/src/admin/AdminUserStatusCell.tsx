@@ -0,0 +1,48 @@
+import { useState } from 'react'
+
+import { approveUserRegistration, type AdminUserSummary } from '../api'
+import { StatusBadge } from '../components/StatusBadge'
+import { Button } from '../components/ui/button'
+import type { AdminTranslations } from '../i18n'
+
+/** Users-list status badge; accounts waiting for registration approval get an inline approve action. */
+export default function AdminUserStatusCell({
+  item,
+  usersStrings,
+  onApproved,
+}: {
+  item: AdminUserSummary
+  usersStrings: AdminTranslations['users']
+  onApproved: () => unknown
+}): JSX.Element {
+  const [approving, setApproving] = useState(false)
+
+  if (!item.pendingApproval) {
+    return (
+      <StatusBadge tone={item.active ? 'success' : 'neutral'}>
+        {item.active ? usersStrings.status.active : usersStrings.status.inactive}
+      </StatusBadge>
+    )
+  }
+
+  const approve = async () => {
+    setApproving(true)
+    try {
+      await approveUserRegistration(item.userId)
+      await onApproved()
+    } catch (err) {
+      console.error(err)
+    } finally {
+      setApproving(false)
+    }
+  }
+
+  return (
+    <span className="table-actions">
+      <StatusBadge tone="warning">{usersStrings.status.pendingApproval}</StatusBadge>
+      <Button type="button" size="sm" variant="outline" disabled={approving} onClick={() => void approve()}>
+        {usersStrings.status.approve}
+      </Button>
+    </span>
+  )
+}
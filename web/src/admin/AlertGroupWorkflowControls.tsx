@@ -0,0 +1,85 @@
+import { useEffect, useState } from 'react'
+
+import { applyAlertGroupAction, type AlertGroup, type AlertGroupAction, type AlertGroupWorkflow } from '../api'
+import { StatusBadge, type StatusTone } from '../components/StatusBadge'
+import { Button } from '../components/ui/button'
+import type { Language } from '../i18n'
+
+interface AlertGroupWorkflowControlsProps {
+  group: AlertGroup
+  language: Language
+}
+
+function copy(language: Language) {
+  return language === 'zh'
+    ? {
+        status: { open: '未处理', acknowledged: '已确认', resolved: '已解决' },
+        reopened: '已重新打开',
+        actions: { acknowledge: '确认', resolve: '解决', reopen: '重新打开' },
+        notePrompt: '备注（可选）',
+        by: '处理人',
+      }
+    : {
+        status: { open: 'Open', acknowledged: 'Acknowledged', resolved: 'Resolved' },
+        reopened: 'Reopened',
+        actions: { acknowledge: 'Acknowledge', resolve: 'Resolve', reopen: 'Reopen' },
+        notePrompt: 'Note (optional)',
+        by: 'By',
+      }
+}
+
+const STATUS_TONES: Record<AlertGroupWorkflow['status'], StatusTone> = {
+  open: 'warning',
+  acknowledged: 'info',
+  resolved: 'success',
+}
+
+export default function AlertGroupWorkflowControls({ group, language }: AlertGroupWorkflowControlsProps): JSX.Element {
+  const strings = copy(language)
+  const [workflow, setWorkflow] = useState<AlertGroupWorkflow | null>(group.workflow ?? null)
+  const [busy, setBusy] = useState(false)
+  const [error, setError] = useState<string | null>(null)
+
+  useEffect(() => {
+    setWorkflow(group.workflow ?? null)
+  }, [group.workflow])
+
+  const status = workflow?.status ?? 'open'
+  const actions: AlertGroupAction[] =
+    status === 'open' ? ['acknowledge', 'resolve'] : status === 'acknowledged' ? ['resolve', 'reopen'] : ['reopen']
+
+  const run = async (action: AlertGroupAction) => {
+    const note = window.prompt(strings.notePrompt)
+    if (note == null) return
+    setBusy(true)
+    setError(null)
+    try {
+      setWorkflow(await applyAlertGroupAction(group.id, action, note.trim() || null))
+    } catch (err) {
+      setError(err instanceof Error ? err.message : String(err))
+    } finally {
+      setBusy(false)
+    }
+  }
+
+  return (
+    <div className="alerts-center-workflow">
+      <div className="table-actions">
+        <StatusBadge tone={STATUS_TONES[status]}>
+          {workflow?.reopened ? strings.reopened : strings.status[status]}
+        </StatusBadge>
+        {actions.map((action) => (
+          <Button key={action} type="button" variant="outline" size="xs" disabled={busy} onClick={() => void run(action)}>
+            {strings.actions[action]}
+          </Button>
+        ))}
+      </div>
+      {workflow?.actor ? (
+        <span className="text-xs text-muted-foreground">
+          {`${strings.by}: ${workflow.actor}${workflow.note ? ` · ${workflow.note}` : ''}`}
+        </span>
+      ) : null}
+      {error ? <span className="text-xs font-medium text-destructive">{error}</span> : null}
+    </div>
+  )
+}
@@ -0,0 +1,461 @@
+import { useCallback, useEffect, useState } from 'react'
+
+import {
+  createAlertRule,
+  deleteAlertRule,
+  fetchAlertRules,
+  updateAlertRule,
+  type AlertRule,
+  type AlertRuleComparator,
+  type AlertRuleMetric,
+  type AlertRuleMutationPayload,
+} from '../api'
+import AdminModuleSurface from './AdminModuleSurface'
+import AdminLoadingRegion from '../components/AdminLoadingRegion'
+import { StatusBadge } from '../components/StatusBadge'
+import { Button } from '../components/ui/button'
+import { Input } from '../components/ui/input'
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from '../components/ui/select'
+import { Switch } from '../components/ui/switch'
+import { Icon } from '../lib/icons'
+import type { Language } from '../i18n'
+
+interface AlertRulesPanelProps {
+  language: Language
+  refreshToken?: number
+}
+
+interface RuleDraft {
+  name: string
+  metric: AlertRuleMetric
+  comparator: AlertRuleComparator
+  threshold: string
+  clearThreshold: string
+  windowSecs: string
+  requestKind: string
+  enabled: boolean
+}
+
+const METRICS: AlertRuleMetric[] = [
+  'pool_credits_remaining',
+  'request_error_rate',
+  'forward_proxy_healthy_nodes',
+  'ha_outbox_ack_lag',
+]
+
+const COMPARATORS: AlertRuleComparator[] = ['below', 'above']
+
+const EMPTY_DRAFT: RuleDraft = {
+  name: '',
+  metric: 'pool_credits_remaining',
+  comparator: 'below',
+  threshold: '',
+  clearThreshold: '',
+  windowSecs: '900',
+  requestKind: '',
+  enabled: true,
+}
+
+function copy(language: Language) {
+  return language === 'zh'
+    ? {
+        title: '阈值告警规则',
+        description: '对已有指标设置阈值，越过阈值时生成告警；恢复阈值用于防止告警反复抖动。',
+        loading: '正在加载规则…',
+        error: '规则加载失败。',
+        empty: '还没有配置阈值规则。',
+        add: '新增规则',
+        save: '保存',
+        saving: '保存中…',
+        cancel: '取消',
+        edit: '编辑',
+        remove: '删除',
+        removeConfirm: '确定删除这条规则吗？已产生的告警会保留。',
+        form: {
+          name: '名称',
+          metric: '指标',
+          comparator: '触发条件',
+          threshold: '阈值',
+          clearThreshold: '恢复阈值',
+          clearThresholdHint: '规则触发后，指标需回到该值之外才会恢复。留空表示与阈值相同。',
+          windowSecs: '统计窗口（秒）',
+          requestKind: '请求类型',
+          requestKindHint: '留空表示统计全部请求。',
+          enabled: '启用',
+        },
+        table: { condition: '条件', state: '状态', lastValue: '最近取值' },
+        state: { ok: '正常', firing: '触发中' },
+        metrics: {
+          pool_credits_remaining: '号池剩余额度',
+          request_error_rate: '请求错误率（%）',
+          forward_proxy_healthy_nodes: '健康转发代理节点数',
+          ha_outbox_ack_lag: 'HA Outbox 确认滞后',
+        } as Record<AlertRuleMetric, string>,
+        comparators: { above: '高于', below: '低于' } as Record<AlertRuleComparator, string>,
+      }
+    : {
+        title: 'Threshold alert rules',
+        description:
+          'Raise alerts when an existing metric crosses a threshold. The clear threshold keeps alerts from flapping.',
+        loading: 'Loading rules…',
+        error: 'Failed to load rules.',
+        empty: 'No threshold rules configured yet.',
+        add: 'Add rule',
+        save: 'Save',
+        saving: 'Saving…',
+        cancel: 'Cancel',
+        edit: 'Edit',
+        remove: 'Delete',
+        removeConfirm: 'Delete this rule? Alerts it already raised are kept.',
+        form: {
+          name: 'Name',
+          metric: 'Metric',
+          comparator: 'Fires when',
+          threshold: 'Threshold',
+          clearThreshold: 'Clear threshold',
+          clearThresholdHint: 'A firing rule recovers only once the value is back past this level. Empty means the threshold.',
+          windowSecs: 'Window (seconds)',
+          requestKind: 'Request kind',
+          requestKindHint: 'Leave empty to include every request.',
+          enabled: 'Enabled',
+        },
+        table: { condition: 'Condition', state: 'State', lastValue: 'Last value' },
+        state: { ok: 'OK', firing: 'Firing' },
+        metrics: {
+          pool_credits_remaining: 'Pool credits remaining',
+          request_error_rate: 'Request error rate (%)',
+          forward_proxy_healthy_nodes: 'Healthy forward-proxy nodes',
+          ha_outbox_ack_lag: 'HA outbox ack lag',
+        } as Record<AlertRuleMetric, string>,
+        comparators: { above: 'above', below: 'below' } as Record<AlertRuleComparator, string>,
+      }
+}
+
+function draftFromRule(rule: AlertRule): RuleDraft {
+  return {
+    name: rule.name,
+    metric: rule.metric,
+    comparator: rule.comparator,
+    threshold: String(rule.threshold),
+    clearThreshold: rule.clearThreshold != null ? String(rule.clearThreshold) : '',
+    windowSecs: String(rule.windowSecs),
+    requestKind: rule.requestKind ?? '',
+    enabled: rule.enabled,
+  }
+}
+
+function parseOptionalNumber(value: string): number | null {
+  const parsed = Number.parseFloat(value)
+  return value.trim() && Number.isFinite(parsed) ? parsed : null
+}
+
+function payloadFromDraft(draft: RuleDraft): AlertRuleMutationPayload {
+  const isErrorRate = draft.metric === 'request_error_rate'
+  return {
+    name: draft.name,
+    metric: draft.metric,
+    comparator: draft.comparator,
+    threshold: Number.parseFloat(draft.threshold),
+    clearThreshold: parseOptionalNumber(draft.clearThreshold),
+    windowSecs: isErrorRate ? parseOptionalNumber(draft.windowSecs) : null,
+    requestKind: isErrorRate ? draft.requestKind.trim() || null : null,
+    enabled: draft.enabled,
+  }
+}
+
+function formatRuleValue(value: number | null): string {
+  if (value == null) return '—'
+  return Number.isInteger(value) ? String(value) : value.toFixed(2)
+}
+
+export default function AlertRulesPanel({ language, refreshToken = 0 }: AlertRulesPanelProps): JSX.Element {
+  const strings = copy(language)
+  const [rules, setRules] = useState<AlertRule[]>([])
+  const [loading, setLoading] = useState(true)
+  const [error, setError] = useState<string | null>(null)
+  const [editing, setEditing] = useState<{ id: string | null; draft: RuleDraft } | null>(null)
+  const [saving, setSaving] = useState(false)
+  const [formError, setFormError] = useState<string | null>(null)
+  const [busyId, setBusyId] = useState<string | null>(null)
+
+  const load = useCallback(async (signal?: AbortSignal) => {
+    try {
+      const response = await fetchAlertRules(signal)
+      setRules(response.items)
+      setError(null)
+    } catch (err) {
+      if (signal?.aborted) return
+      setError(err instanceof Error ? err.message : String(err))
+    } finally {
+      if (!signal?.aborted) setLoading(false)
+    }
+  }, [])
+
+  useEffect(() => {
+    const controller = new AbortController()
+    void load(controller.signal)
+    return () => controller.abort()
+  }, [load, refreshToken])
+
+  const updateDraft = (patch: Partial<RuleDraft>) => {
+    setEditing((current) => (current ? { ...current, draft: { ...current.draft, ...patch } } : current))
+  }
+
+  const submit = async () => {
+    if (!editing) return
+    setSaving(true)
+    setFormError(null)
+    try {
+      const payload = payloadFromDraft(editing.draft)
+      if (editing.id) {
+        await updateAlertRule(editing.id, payload)
+      } else {
+        await createAlertRule(payload)
+      }
+      setEditing(null)
+      await load()
+    } catch (err) {
+      setFormError(err instanceof Error ? err.message : String(err))
+    } finally {
+      setSaving(false)
+    }
+  }
+
+  const remove = async (id: string) => {
+    setBusyId(id)
+    try {
+      await deleteAlertRule(id)
+      await load()
+    } catch (err) {
+      setError(err instanceof Error ? err.message : String(err))
+    } finally {
+      setBusyId(null)
+    }
+  }
+
+  const isErrorRate = editing?.draft.metric === 'request_error_rate'
+
+  return (
+    <AdminModuleSurface className="alert-rules-panel">
+      <div className="announcements-list-header">
+        <div>
+          <h3>{strings.title}</h3>
+          <p>{strings.description}</p>
+        </div>
+        {!editing ? (
+          <Button type="button" size="sm" onClick={() => setEditing({ id: null, draft: EMPTY_DRAFT })}>
+            <Icon icon="mdi:plus" width={16} height={16} aria-hidden="true" />
+            <span>{strings.add}</span>
+          </Button>
+        ) : null}
+      </div>
+
+      {editing ? (
+        <form
+          className="system-settings-config-section"
+          onSubmit={(event) => {
+            event.preventDefault()
+            void submit()
+          }}
+        >
+          <div className="system-settings-field-grid">
+            <div className="system-settings-field">
+              <label className="text-sm font-medium" htmlFor="alert-rule-name">{strings.form.name}</label>
+              <Input
+                id="alert-rule-name"
+                value={editing.draft.name}
+                disabled={saving}
+                onChange={(event) => updateDraft({ name: event.target.value })}
+              />
+            </div>
+            <div className="system-settings-field">
+              <label className="text-sm font-medium">{strings.form.metric}</label>
+              <Select
+                value={editing.draft.metric}
+                onValueChange={(value) => updateDraft({ metric: value as AlertRuleMetric })}
+                disabled={saving}
+              >
+                <SelectTrigger aria-label={strings.form.metric}>
+                  <SelectValue />
+                </SelectTrigger>
+                <SelectContent>
+                  {METRICS.map((metric) => (
+                    <SelectItem key={metric} value={metric}>
+                      {strings.metrics[metric]}
+                    </SelectItem>
+                  ))}
+                </SelectContent>
+              </Select>
+            </div>
+            <div className="system-settings-field">
+              <label className="text-sm font-medium">{strings.form.comparator}</label>
+              <Select
+                value={editing.draft.comparator}
+                onValueChange={(value) => updateDraft({ comparator: value as AlertRuleComparator })}
+                disabled={saving}
+              >
+                <SelectTrigger aria-label={strings.form.comparator}>
+                  <SelectValue />
+                </SelectTrigger>
+                <SelectContent>
+                  {COMPARATORS.map((comparator) => (
+                    <SelectItem key={comparator} value={comparator}>
+                      {strings.comparators[comparator]}
+                    </SelectItem>
+                  ))}
+                </SelectContent>
+              </Select>
+            </div>
+            <div className="system-settings-field">
+              <label className="text-sm font-medium" htmlFor="alert-rule-threshold">{strings.form.threshold}</label>
+              <Input
+                id="alert-rule-threshold"
+                type="number"
+                inputMode="decimal"
+                step="any"
+                value={editing.draft.threshold}
+                disabled={saving}
+                onChange={(event) => updateDraft({ threshold: event.target.value })}
+              />
+            </div>
+            <div className="system-settings-field">
+              <label className="text-sm font-medium" htmlFor="alert-rule-clear">{strings.form.clearThreshold}</label>
+              <Input
+                id="alert-rule-clear"
+                type="number"
+                inputMode="decimal"
+                step="any"
+                value={editing.draft.clearThreshold}
+                disabled={saving}
+                onChange={(event) => updateDraft({ clearThreshold: event.target.value })}
+              />
+              <p className="system-settings-field-hint text-xs text-muted-foreground">
+                {strings.form.clearThresholdHint}
+              </p>
+            </div>
+            {isErrorRate ? (
+              <>
+                <div className="system-settings-field">
+                  <label className="text-sm font-medium" htmlFor="alert-rule-window">{strings.form.windowSecs}</label>
+                  <Input
+                    id="alert-rule-window"
+                    type="number"
+                    inputMode="numeric"
+                    min={60}
+                    step={60}
+                    value={editing.draft.windowSecs}
+                    disabled={saving}
+                    onChange={(event) => updateDraft({ windowSecs: event.target.value })}
+                  />
+                </div>
+                <div className="system-settings-field">
+                  <label className="text-sm font-medium" htmlFor="alert-rule-kind">{strings.form.requestKind}</label>
+                  <Input
+                    id="alert-rule-kind"
+                    value={editing.draft.requestKind}
+                    disabled={saving}
+                    placeholder="api_search"
+                    onChange={(event) => updateDraft({ requestKind: event.target.value })}
+                  />
+                  <p className="system-settings-field-hint text-xs text-muted-foreground">
+                    {strings.form.requestKindHint}
+                  </p>
+                </div>
+              </>
+            ) : null}
+          </div>
+          <label className="flex items-center gap-3 text-sm font-medium">
+            <Switch
+              checked={editing.draft.enabled}
+              disabled={saving}
+              onCheckedChange={(checked) => updateDraft({ enabled: checked })}
+            />
+            {strings.form.enabled}
+          </label>
+          {formError ? <p className="text-xs font-medium text-destructive">{formError}</p> : null}
+          <div className="table-actions">
+            <Button type="submit" size="sm" disabled={saving}>
+              {saving ? strings.saving : strings.save}
+            </Button>
+            <Button type="button" variant="outline" size="sm" disabled={saving} onClick={() => setEditing(null)}>
+              {strings.cancel}
+            </Button>
+          </div>
+        </form>
+      ) : null}
+
+      <AdminLoadingRegion
+        loadState={loading ? 'initial_loading' : error ? 'error' : 'ready'}
+        loadingLabel={strings.loading}
+        errorLabel={error ?? strings.error}
+        minHeight={120}
+      >
+        {rules.length === 0 ? (
+          <div className="empty-state alert">{strings.empty}</div>
+        ) : (
+          <div className="table-wrapper">
+            <table className="jobs-table">
+              <thead>
+                <tr>
+                  <th>{strings.form.name}</th>
+                  <th>{strings.table.condition}</th>
+                  <th>{strings.table.state}</th>
+                  <th>{strings.table.lastValue}</th>
+                  <th />
+                </tr>
+              </thead>
+              <tbody>
+                {rules.map((rule) => (
+                  <tr key={rule.id}>
+                    <td>
+                      <strong>{rule.name}</strong>
+                      {rule.requestKind ? (
+                        <div className="text-xs text-muted-foreground">{rule.requestKind}</div>
+                      ) : null}
+                    </td>
+                    <td>
+                      {strings.metrics[rule.metric] ?? rule.metric} {strings.comparators[rule.comparator]}{' '}
+                      {formatRuleValue(rule.threshold)}
+                      {rule.clearThreshold != null ? ` / ${formatRuleValue(rule.clearThreshold)}` : ''}
+                    </td>
+                    <td>
+                      <StatusBadge tone={!rule.enabled ? 'neutral' : rule.state === 'firing' ? 'warning' : 'success'}>
+                        {rule.enabled ? strings.state[rule.state] : '—'}
+                      </StatusBadge>
+                    </td>
+                    <td>{formatRuleValue(rule.lastValue)}</td>
+                    <td>
+                      <div className="table-actions">
+                        <Button
+                          type="button"
+                          variant="outline"
+                          size="xs"
+                          onClick={() => setEditing({ id: rule.id, draft: draftFromRule(rule) })}
+                        >
+                          {strings.edit}
+                        </Button>
+                        <Button
+                          type="button"
+                          variant="outline"
+                          size="xs"
+                          disabled={busyId === rule.id}
+                          onClick={() => {
+                            if (window.confirm(strings.removeConfirm)) {
+                              void remove(rule.id)
+                            }
+                          }}
+                        >
+                          {strings.remove}
+                        </Button>
+                      </div>
+                    </td>
+                  </tr>
+                ))}
+              </tbody>
+            </table>
+          </div>
+        )}
+      </AdminLoadingRegion>
+    </AdminModuleSurface>
+  )
+}
@@ -0,0 +1,383 @@
+import { useCallback, useEffect, useState } from 'react'
+
+import {
+  createAlertSilence,
+  expireAlertSilence,
+  fetchAlertSilences,
+  type AlertSilence,
+  type AlertSilenceSubjectKind,
+  type AlertType,
+} from '../api'
+import AdminModuleSurface from './AdminModuleSurface'
+import AdminLoadingRegion from '../components/AdminLoadingRegion'
+import { StatusBadge } from '../components/StatusBadge'
+import { Button } from '../components/ui/button'
+import { Input } from '../components/ui/input'
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from '../components/ui/select'
+import { Switch } from '../components/ui/switch'
+import { Icon } from '../lib/icons'
+import type { Language } from '../i18n'
+
+interface AlertSilencesPanelProps {
+  language: Language
+  refreshToken?: number
+}
+
+interface SilenceDraft {
+  type: AlertType | 'any'
+  subjectKind: AlertSilenceSubjectKind | 'any'
+  subjectId: string
+  durationHours: string
+  note: string
+}
+
+const ALERT_TYPES: AlertType[] = [
+  'upstream_rate_limited_429',
+  'upstream_usage_limit_432',
+  'upstream_key_blocked',
+  'user_request_rate_limited',
+  'user_quota_exhausted',
+  'api_key_exhausted',
+  'job_failed',
+  'token_expiring',
+  'threshold_rule',
+]
+
+const SUBJECT_KINDS: AlertSilenceSubjectKind[] = ['key', 'token', 'user', 'job', 'rule']
+
+const EMPTY_DRAFT: SilenceDraft = {
+  type: 'any',
+  subjectKind: 'key',
+  subjectId: '',
+  durationHours: '2',
+  note: '',
+}
+
+function copy(language: Language) {
+  return language === 'zh'
+    ? {
+        title: '告警静默',
+        description: '在维护窗口内静默匹配的告警。静默期间告警仍会记录，但不计入仪表盘，也不会推送通知。',
+        loading: '正在加载静默规则…',
+        error: '静默规则加载失败。',
+        empty: '当前没有生效中的静默。',
+        add: '新增静默',
+        save: '开始静默',
+        saving: '保存中…',
+        cancel: '取消',
+        expire: '立即结束',
+        showExpired: '显示已结束',
+        anyType: '全部类型',
+        anySubject: '全部对象',
+        active: '生效中',
+        scheduled: '未开始',
+        ended: '已结束',
+        form: {
+          type: '告警类型',
+          subjectKind: '对象类型',
+          subjectId: '对象 ID',
+          durationHours: '持续时间（小时）',
+          note: '备注',
+        },
+        table: { scope: '范围', window: '时间窗口', actor: '创建人', state: '状态' },
+        subjectKinds: { key: 'Key', token: '令牌', user: '用户', job: '任务', rule: '规则' } as Record<
+          AlertSilenceSubjectKind,
+          string
+        >,
+      }
+    : {
+        title: 'Alert silences',
+        description:
+          'Mute matching alerts for a maintenance window. Silenced alerts are still recorded but stay out of Dashboard counts and notifications.',
+        loading: 'Loading silences…',
+        error: 'Failed to load silences.',
+        empty: 'No active silences.',
+        add: 'Add silence',
+        save: 'Start silence',
+        saving: 'Saving…',
+        cancel: 'Cancel',
+        expire: 'End now',
+        showExpired: 'Show ended',
+        anyType: 'Any type',
+        anySubject: 'Any subject',
+        active: 'Active',
+        scheduled: 'Scheduled',
+        ended: 'Ended',
+        form: {
+          type: 'Alert type',
+          subjectKind: 'Subject kind',
+          subjectId: 'Subject ID',
+          durationHours: 'Duration (hours)',
+          note: 'Note',
+        },
+        table: { scope: 'Scope', window: 'Window', actor: 'Created by', state: 'State' },
+        subjectKinds: { key: 'Key', token: 'Token', user: 'User', job: 'Job', rule: 'Rule' } as Record<
+          AlertSilenceSubjectKind,
+          string
+        >,
+      }
+}
+
+function formatTimestamp(ts: number, language: Language): string {
+  return new Date(ts * 1000).toLocaleString(language === 'zh' ? 'zh-CN' : 'en-US', { hour12: false })
+}
+
+export default function AlertSilencesPanel({ language, refreshToken = 0 }: AlertSilencesPanelProps): JSX.Element {
+  const strings = copy(language)
+  const [silences, setSilences] = useState<AlertSilence[]>([])
+  const [includeExpired, setIncludeExpired] = useState(false)
+  const [loading, setLoading] = useState(true)
+  const [error, setError] = useState<string | null>(null)
+  const [draft, setDraft] = useState<SilenceDraft | null>(null)
+  const [saving, setSaving] = useState(false)
+  const [formError, setFormError] = useState<string | null>(null)
+  const [busyId, setBusyId] = useState<string | null>(null)
+
+  const load = useCallback(
+    async (signal?: AbortSignal) => {
+      try {
+        const response = await fetchAlertSilences(includeExpired, signal)
+        setSilences(response.items)
+        setError(null)
+      } catch (err) {
+        if (signal?.aborted) return
+        setError(err instanceof Error ? err.message : String(err))
+      } finally {
+        if (!signal?.aborted) setLoading(false)
+      }
+    },
+    [includeExpired],
+  )
+
+  useEffect(() => {
+    const controller = new AbortController()
+    void load(controller.signal)
+    return () => controller.abort()
+  }, [load, refreshToken])
+
+  const updateDraft = (patch: Partial<SilenceDraft>) => {
+    setDraft((current) => (current ? { ...current, ...patch } : current))
+  }
+
+  const submit = async () => {
+    if (!draft) return
+    setSaving(true)
+    setFormError(null)
+    try {
+      const hours = Number.parseFloat(draft.durationHours)
+      const hasSubject = draft.subjectKind !== 'any'
+      await createAlertSilence({
+        type: draft.type === 'any' ? null : draft.type,
+        subjectKind: hasSubject ? draft.subjectKind : null,
+        subjectId: hasSubject ? draft.subjectId.trim() || null : null,
+        endsAt: Math.floor(Date.now() / 1000) + Math.round((Number.isFinite(hours) ? hours : 0) * 3600),
+        note: draft.note.trim() || null,
+      })
+      setDraft(null)
+      await load()
+    } catch (err) {
+      setFormError(err instanceof Error ? err.message : String(err))
+    } finally {
+      setSaving(false)
+    }
+  }
+
+  const expire = async (id: string) => {
+    setBusyId(id)
+    try {
+      await expireAlertSilence(id)
+      await load()
+    } catch (err) {
+      setError(err instanceof Error ? err.message : String(err))
+    } finally {
+      setBusyId(null)
+    }
+  }
+
+  const now = Math.floor(Date.now() / 1000)
+
+  return (
+    <AdminModuleSurface className="alert-silences-panel">
+      <div className="announcements-list-header">
+        <div>
+          <h3>{strings.title}</h3>
+          <p>{strings.description}</p>
+        </div>
+        <div className="table-actions">
+          <label className="flex items-center gap-2 text-sm">
+            <Switch checked={includeExpired} onCheckedChange={setIncludeExpired} />
+            {strings.showExpired}
+          </label>
+          {!draft ? (
+            <Button type="button" size="sm" onClick={() => setDraft(EMPTY_DRAFT)}>
+              <Icon icon="mdi:bell-sleep-outline" width={16} height={16} aria-hidden="true" />
+              <span>{strings.add}</span>
+            </Button>
+          ) : null}
+        </div>
+      </div>
+
+      {draft ? (
+        <form
+          className="system-settings-config-section"
+          onSubmit={(event) => {
+            event.preventDefault()
+            void submit()
+          }}
+        >
+          <div className="system-settings-field-grid">
+            <div className="system-settings-field">
+              <label className="text-sm font-medium">{strings.form.type}</label>
+              <Select
+                value={draft.type}
+                onValueChange={(value) => updateDraft({ type: value as SilenceDraft['type'] })}
+                disabled={saving}
+              >
+                <SelectTrigger aria-label={strings.form.type}>
+                  <SelectValue />
+                </SelectTrigger>
+                <SelectContent>
+                  <SelectItem value="any">{strings.anyType}</SelectItem>
+                  {ALERT_TYPES.map((type) => (
+                    <SelectItem key={type} value={type}>
+                      {type}
+                    </SelectItem>
+                  ))}
+                </SelectContent>
+              </Select>
+            </div>
+            <div className="system-settings-field">
+              <label className="text-sm font-medium">{strings.form.subjectKind}</label>
+              <Select
+                value={draft.subjectKind}
+                onValueChange={(value) => updateDraft({ subjectKind: value as SilenceDraft['subjectKind'] })}
+                disabled={saving}
+              >
+                <SelectTrigger aria-label={strings.form.subjectKind}>
+                  <SelectValue />
+                </SelectTrigger>
+                <SelectContent>
+                  <SelectItem value="any">{strings.anySubject}</SelectItem>
+                  {SUBJECT_KINDS.map((kind) => (
+                    <SelectItem key={kind} value={kind}>
+                      {strings.subjectKinds[kind]}
+                    </SelectItem>
+                  ))}
+                </SelectContent>
+              </Select>
+            </div>
+            {draft.subjectKind !== 'any' ? (
+              <div className="system-settings-field">
+                <label className="text-sm font-medium" htmlFor="alert-silence-subject">{strings.form.subjectId}</label>
+                <Input
+                  id="alert-silence-subject"
+                  value={draft.subjectId}
+                  disabled={saving}
+                  onChange={(event) => updateDraft({ subjectId: event.target.value })}
+                />
+              </div>
+            ) : null}
+            <div className="system-settings-field">
+              <label className="text-sm font-medium" htmlFor="alert-silence-duration">{strings.form.durationHours}</label>
+              <Input
+                id="alert-silence-duration"
+                type="number"
+                inputMode="decimal"
+                min={0.25}
+                step="any"
+                value={draft.durationHours}
+                disabled={saving}
+                onChange={(event) => updateDraft({ durationHours: event.target.value })}
+              />
+            </div>
+            <div className="system-settings-field">
+              <label className="text-sm font-medium" htmlFor="alert-silence-note">{strings.form.note}</label>
+              <Input
+                id="alert-silence-note"
+                value={draft.note}
+                disabled={saving}
+                onChange={(event) => updateDraft({ note: event.target.value })}
+              />
+            </div>
+          </div>
+          {formError ? <p className="text-xs font-medium text-destructive">{formError}</p> : null}
+          <div className="table-actions">
+            <Button type="submit" size="sm" disabled={saving}>
+              {saving ? strings.saving : strings.save}
+            </Button>
+            <Button type="button" variant="outline" size="sm" disabled={saving} onClick={() => setDraft(null)}>
+              {strings.cancel}
+            </Button>
+          </div>
+        </form>
+      ) : null}
+
+      <AdminLoadingRegion
+        loadState={loading ? 'initial_loading' : error ? 'error' : 'ready'}
+        loadingLabel={strings.loading}
+        errorLabel={error ?? strings.error}
+        minHeight={120}
+      >
+        {silences.length === 0 ? (
+          <div className="empty-state alert">{strings.empty}</div>
+        ) : (
+          <div className="table-wrapper">
+            <table className="jobs-table">
+              <thead>
+                <tr>
+                  <th>{strings.table.scope}</th>
+                  <th>{strings.table.window}</th>
+                  <th>{strings.table.actor}</th>
+                  <th>{strings.table.state}</th>
+                  <th />
+                </tr>
+              </thead>
+              <tbody>
+                {silences.map((silence) => {
+                  const ended = silence.endsAt <= now
+                  return (
+                    <tr key={silence.id}>
+                      <td>
+                        <strong>{silence.type ?? strings.anyType}</strong>
+                        <div className="text-xs text-muted-foreground">
+                          {silence.subjectKind
+                            ? `${strings.subjectKinds[silence.subjectKind]} · ${silence.subjectId ?? ''}`
+                            : strings.anySubject}
+                        </div>
+                      </td>
+                      <td>
+                        {formatTimestamp(silence.startsAt, language)} → {formatTimestamp(silence.endsAt, language)}
+                      </td>
+                      <td>
+                        {silence.actor}
+                        {silence.note ? <div className="text-xs text-muted-foreground">{silence.note}</div> : null}
+                      </td>
+                      <td>
+                        <StatusBadge tone={silence.active ? 'warning' : ended ? 'neutral' : 'info'}>
+                          {silence.active ? strings.active : ended ? strings.ended : strings.scheduled}
+                        </StatusBadge>
+                      </td>
+                      <td>
+                        {!ended ? (
+                          <Button
+                            type="button"
+                            variant="outline"
+                            size="xs"
+                            disabled={busyId === silence.id}
+                            onClick={() => void expire(silence.id)}
+                          >
+                            {strings.expire}
+                          </Button>
+                        ) : null}
+                      </td>
+                    </tr>
+                  )
+                })}
+              </tbody>
+            </table>
+          </div>
+        )}
+      </AdminLoadingRegion>
+    </AdminModuleSurface>
+  )
+}
@@ -0,0 +1,564 @@
+import { useCallback, useEffect, useState } from 'react'
+
+import {
+  createAlertWebhook,
+  deleteAlertWebhook,
+  fetchAlertWebhookDeliveries,
+  fetchAlertWebhooks,
+  testAlertWebhook,
+  updateAlertWebhook,
+  type AlertType,
+  type AlertWebhookDelivery,
+  type AlertWebhookDeliveryStatus,
+  type AlertWebhookSink,
+  type AlertWebhookSinkMutationPayload,
+  type AlertWebhookTemplate,
+} from '../api'
+import AdminModuleSurface from './AdminModuleSurface'
+import AdminLoadingRegion from '../components/AdminLoadingRegion'
+import { StatusBadge, type StatusTone } from '../components/StatusBadge'
+import { Button } from '../components/ui/button'
+import { Input } from '../components/ui/input'
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from '../components/ui/select'
+import { Switch } from '../components/ui/switch'
+import { Icon } from '../lib/icons'
+import type { Language } from '../i18n'
+
+interface AlertWebhooksPanelProps {
+  language: Language
+  refreshToken?: number
+}
+
+interface WebhookDraft {
+  name: string
+  url: string
+  template: AlertWebhookTemplate
+  alertTypes: AlertType[]
+  telegramChatId: string
+  secret: string
+  repeatIntervalSecs: string
+  enabled: boolean
+}
+
+const ALERT_TYPES: AlertType[] = [
+  'upstream_rate_limited_429',
+  'upstream_usage_limit_432',
+  'upstream_key_blocked',
+  'user_request_rate_limited',
+  'user_quota_exhausted',
+  'api_key_exhausted',
+  'job_failed',
+  'token_expiring',
+  'threshold_rule',
+]
+
+const TEMPLATES: AlertWebhookTemplate[] = ['generic', 'slack', 'telegram']
+
+const EMPTY_DRAFT: WebhookDraft = {
+  name: '',
+  url: '',
+  template: 'generic',
+  alertTypes: [],
+  telegramChatId: '',
+  secret: '',
+  repeatIntervalSecs: '300',
+  enabled: true,
+}
+
+function copy(language: Language) {
+  return language === 'zh'
+    ? {
+        title: '告警 Webhook',
+        description: '将告警以签名 JSON 推送到外部系统。失败的投递会按退避策略重试。',
+        loading: '正在加载 Webhook…',
+        error: 'Webhook 加载失败。',
+        empty: '还没有配置 Webhook。',
+        add: '新增 Webhook',
+        save: '保存',
+        saving: '保存中…',
+        cancel: '取消',
+        edit: '编辑',
+        remove: '删除',
+        removeConfirm: '确定删除这个 Webhook 及其投递记录吗？',
+        test: '测试发送',
+        testing: '发送中…',
+        history: '投递记录',
+        noHistory: '暂无投递记录。',
+        form: {
+          name: '名称',
+          url: 'URL',
+          template: '模板',
+          telegramChatId: 'Telegram Chat ID',
+          secret: '签名密钥',
+          secretHint: '留空时新建会自动生成，编辑时保持不变。',
+          repeatInterval: '重复抑制（秒）',
+          repeatIntervalHint: '同一告警对象在此时间内只通知一次，0 表示不抑制。',
+          alertTypes: '订阅告警类型',
+          alertTypesHint: '不选表示订阅全部类型。',
+          enabled: '启用',
+        },
+        templates: { generic: '通用 JSON', slack: 'Slack', telegram: 'Telegram Bot' },
+        table: { time: '时间', alert: '告警', status: '状态', attempts: '尝试次数', result: '结果' },
+        status: { pending: '待重试', delivered: '已送达', failed: '失败' },
+        allTypes: '全部类型',
+        secretLabel: '密钥',
+        types: {
+          upstream_rate_limited_429: '上游 429',
+          upstream_usage_limit_432: '上游用量限制 432',
+          upstream_key_blocked: '上游 Key 封禁',
+          user_request_rate_limited: '用户请求限流',
+          user_quota_exhausted: '用户额度耗尽',
+          api_key_exhausted: 'API Key 耗尽',
+          job_failed: '任务失败',
+          token_expiring: '令牌即将过期',
+          threshold_rule: '阈值规则触发',
+        } as Record<AlertType, string>,
+      }
+    : {
+        title: 'Alert webhooks',
+        description: 'Push alerts to external systems as signed JSON. Failed deliveries are retried with backoff.',
+        loading: 'Loading webhooks…',
+        error: 'Failed to load webhooks.',
+        empty: 'No webhooks configured yet.',
+        add: 'Add webhook',
+        save: 'Save',
+        saving: 'Saving…',
+        cancel: 'Cancel',
+        edit: 'Edit',
+        remove: 'Delete',
+        removeConfirm: 'Delete this webhook and its delivery history?',
+        test: 'Send test',
+        testing: 'Sending…',
+        history: 'Delivery history',
+        noHistory: 'No deliveries yet.',
+        form: {
+          name: 'Name',
+          url: 'URL',
+          template: 'Template',
+          telegramChatId: 'Telegram chat ID',
+          secret: 'Signing secret',
+          secretHint: 'Leave empty to generate one for a new webhook or keep the current one when editing.',
+          repeatInterval: 'Repeat suppression (seconds)',
+          repeatIntervalHint: 'Notify once per alert subject within this window; 0 disables suppression.',
+          alertTypes: 'Subscribed alert types',
+          alertTypesHint: 'Select none to receive every type.',
+          enabled: 'Enabled',
+        },
+        templates: { generic: 'Generic JSON', slack: 'Slack', telegram: 'Telegram Bot' },
+        table: { time: 'Time', alert: 'Alert', status: 'Status', attempts: 'Attempts', result: 'Result' },
+        status: { pending: 'Retrying', delivered: 'Delivered', failed: 'Failed' },
+        allTypes: 'All types',
+        secretLabel: 'Secret',
+        types: {
+          upstream_rate_limited_429: 'Upstream 429',
+          upstream_usage_limit_432: 'Upstream usage limit 432',
+          upstream_key_blocked: 'Upstream key blocked',
+          user_request_rate_limited: 'User request rate limited',
+          user_quota_exhausted: 'User quota exhausted',
+          api_key_exhausted: 'API key exhausted',
+          job_failed: 'Job failed',
+          token_expiring: 'Token expiring',
+          threshold_rule: 'Threshold rule breached',
+        } as Record<AlertType, string>,
+      }
+}
+
+function deliveryTone(status: AlertWebhookDeliveryStatus): StatusTone {
+  if (status === 'delivered') return 'success'
+  if (status === 'failed') return 'error'
+  return 'warning'
+}
+
+function formatTimestamp(value: number, language: Language): string {
+  return new Intl.DateTimeFormat(language === 'zh' ? 'zh-CN' : 'en-US', {
+    month: '2-digit',
+    day: '2-digit',
+    hour: '2-digit',
+    minute: '2-digit',
+    second: '2-digit',
+  }).format(new Date(value * 1000))
+}
+
+function draftFromSink(sink: AlertWebhookSink): WebhookDraft {
+  return {
+    name: sink.name,
+    url: sink.url,
+    template: sink.template,
+    alertTypes: sink.alertTypes,
+    telegramChatId: sink.telegramChatId ?? '',
+    secret: '',
+    repeatIntervalSecs: String(sink.repeatIntervalSecs),
+    enabled: sink.enabled,
+  }
+}
+
+function payloadFromDraft(draft: WebhookDraft): AlertWebhookSinkMutationPayload {
+  const interval = Number.parseInt(draft.repeatIntervalSecs, 10)
+  return {
+    name: draft.name,
+    url: draft.url,
+    template: draft.template,
+    alertTypes: draft.alertTypes,
+    telegramChatId: draft.template === 'telegram' ? draft.telegramChatId : null,
+    secret: draft.secret.trim() || null,
+    repeatIntervalSecs: Number.isFinite(interval) ? interval : null,
+    enabled: draft.enabled,
+  }
+}
+
+export default function AlertWebhooksPanel({ language, refreshToken = 0 }: AlertWebhooksPanelProps): JSX.Element {
+  const strings = copy(language)
+  const [sinks, setSinks] = useState<AlertWebhookSink[]>([])
+  const [deliveries, setDeliveries] = useState<AlertWebhookDelivery[]>([])
+  const [loading, setLoading] = useState(true)
+  const [error, setError] = useState<string | null>(null)
+  const [editing, setEditing] = useState<{ id: string | null; draft: WebhookDraft } | null>(null)
+  const [saving, setSaving] = useState(false)
+  const [formError, setFormError] = useState<string | null>(null)
+  const [busyId, setBusyId] = useState<string | null>(null)
+  const [historySinkId, setHistorySinkId] = useState<string | null>(null)
+
+  const load = useCallback(async (signal?: AbortSignal) => {
+    try {
+      const [sinkResponse, deliveryResponse] = await Promise.all([
+        fetchAlertWebhooks(signal),
+        fetchAlertWebhookDeliveries(historySinkId, signal),
+      ])
+      setSinks(sinkResponse.items)
+      setDeliveries(deliveryResponse.items)
+      setError(null)
+    } catch (err) {
+      if (signal?.aborted) return
+      setError(err instanceof Error ? err.message : String(err))
+    } finally {
+      if (!signal?.aborted) setLoading(false)
+    }
+  }, [historySinkId])
+
+  useEffect(() => {
+    const controller = new AbortController()
+    void load(controller.signal)
+    return () => controller.abort()
+  }, [load, refreshToken])
+
+  const updateDraft = (patch: Partial<WebhookDraft>) => {
+    setEditing((current) => (current ? { ...current, draft: { ...current.draft, ...patch } } : current))
+  }
+
+  const toggleAlertType = (type: AlertType) => {
+    if (!editing) return
+    const selected = editing.draft.alertTypes
+    updateDraft({
+      alertTypes: selected.includes(type) ? selected.filter((value) => value !== type) : [...selected, type],
+    })
+  }
+
+  const submit = async () => {
+    if (!editing) return
+    setSaving(true)
+    setFormError(null)
+    try {
+      const payload = payloadFromDraft(editing.draft)
+      if (editing.id) {
+        await updateAlertWebhook(editing.id, payload)
+      } else {
+        await createAlertWebhook(payload)
+      }
+      setEditing(null)
+      await load()
+    } catch (err) {
+      setFormError(err instanceof Error ? err.message : String(err))
+    } finally {
+      setSaving(false)
+    }
+  }
+
+  const runAction = async (id: string, action: () => Promise<unknown>) => {
+    setBusyId(id)
+    try {
+      await action()
+      await load()
+    } catch (err) {
+      setError(err instanceof Error ? err.message : String(err))
+    } finally {
+      setBusyId(null)
+    }
+  }
+
+  const sinkName = (id: string) => sinks.find((sink) => sink.id === id)?.name ?? id
+  const alertLabel = (type: string) => strings.types[type as AlertType] ?? type
+
+  return (
+    <AdminModuleSurface className="alert-webhooks-panel">
+      <div className="announcements-list-header">
+        <div>
+          <h3>{strings.title}</h3>
+          <p>{strings.description}</p>
+        </div>
+        {!editing ? (
+          <Button type="button" size="sm" onClick={() => setEditing({ id: null, draft: EMPTY_DRAFT })}>
+            <Icon icon="mdi:plus" width={16} height={16} aria-hidden="true" />
+            <span>{strings.add}</span>
+          </Button>
+        ) : null}
+      </div>
+
+      {editing ? (
+        <form
+          className="system-settings-config-section"
+          onSubmit={(event) => {
+            event.preventDefault()
+            void submit()
+          }}
+        >
+          <div className="system-settings-field-grid">
+            <div className="system-settings-field">
+              <label className="text-sm font-medium" htmlFor="alert-webhook-name">{strings.form.name}</label>
+              <Input
+                id="alert-webhook-name"
+                value={editing.draft.name}
+                disabled={saving}
+                onChange={(event) => updateDraft({ name: event.target.value })}
+              />
+            </div>
+            <div className="system-settings-field">
+              <label className="text-sm font-medium" htmlFor="alert-webhook-url">{strings.form.url}</label>
+              <Input
+                id="alert-webhook-url"
+                type="url"
+                value={editing.draft.url}
+                disabled={saving}
+                onChange={(event) => updateDraft({ url: event.target.value })}
+              />
+            </div>
+            <div className="system-settings-field">
+              <label className="text-sm font-medium">{strings.form.template}</label>
+              <Select
+                value={editing.draft.template}
+                onValueChange={(value) => updateDraft({ template: value as AlertWebhookTemplate })}
+                disabled={saving}
+              >
+                <SelectTrigger aria-label={strings.form.template}>
+                  <SelectValue />
+                </SelectTrigger>
+                <SelectContent>
+                  {TEMPLATES.map((template) => (
+                    <SelectItem key={template} value={template}>
+                      {strings.templates[template]}
+                    </SelectItem>
+                  ))}
+                </SelectContent>
+              </Select>
+            </div>
+            {editing.draft.template === 'telegram' ? (
+              <div className="system-settings-field">
+                <label className="text-sm font-medium" htmlFor="alert-webhook-chat">
+                  {strings.form.telegramChatId}
+                </label>
+                <Input
+                  id="alert-webhook-chat"
+                  value={editing.draft.telegramChatId}
+                  disabled={saving}
+                  onChange={(event) => updateDraft({ telegramChatId: event.target.value })}
+                />
+              </div>
+            ) : null}
+            <div className="system-settings-field">
+              <label className="text-sm font-medium" htmlFor="alert-webhook-secret">{strings.form.secret}</label>
+              <Input
+                id="alert-webhook-secret"
+                value={editing.draft.secret}
+                disabled={saving}
+                autoComplete="off"
+                onChange={(event) => updateDraft({ secret: event.target.value })}
+              />
+              <p className="system-settings-field-hint text-xs text-muted-foreground">{strings.form.secretHint}</p>
+            </div>
+            <div className="system-settings-field">
+              <label className="text-sm font-medium" htmlFor="alert-webhook-repeat">
+                {strings.form.repeatInterval}
+              </label>
+              <Input
+                id="alert-webhook-repeat"
+                type="number"
+                inputMode="numeric"
+                min={0}
+                step={1}
+                value={editing.draft.repeatIntervalSecs}
+                disabled={saving}
+                onChange={(event) => updateDraft({ repeatIntervalSecs: event.target.value })}
+              />
+              <p className="system-settings-field-hint text-xs text-muted-foreground">
+                {strings.form.repeatIntervalHint}
+              </p>
+            </div>
+          </div>
+          <fieldset className="system-settings-field" disabled={saving}>
+            <legend className="text-sm font-medium">{strings.form.alertTypes}</legend>
+            <div className="flex flex-wrap gap-3">
+              {ALERT_TYPES.map((type) => (
+                <label key={type} className="flex items-center gap-2 text-sm">
+                  <input
+                    type="checkbox"
+                    checked={editing.draft.alertTypes.includes(type)}
+                    onChange={() => toggleAlertType(type)}
+                  />
+                  {strings.types[type]}
+                </label>
+              ))}
+            </div>
+            <p className="system-settings-field-hint text-xs text-muted-foreground">{strings.form.alertTypesHint}</p>
+          </fieldset>
+          <label className="flex items-center gap-3 text-sm font-medium">
+            <Switch
+              checked={editing.draft.enabled}
+              disabled={saving}
+              onCheckedChange={(checked) => updateDraft({ enabled: checked })}
+            />
+            {strings.form.enabled}
+          </label>
+          {formError ? <p className="text-xs font-medium text-destructive">{formError}</p> : null}
+          <div className="table-actions">
+            <Button type="submit" size="sm" disabled={saving}>
+              {saving ? strings.saving : strings.save}
+            </Button>
+            <Button type="button" variant="outline" size="sm" disabled={saving} onClick={() => setEditing(null)}>
+              {strings.cancel}
+            </Button>
+          </div>
+        </form>
+      ) : null}
+
+      <AdminLoadingRegion
+        loadState={loading ? 'initial_loading' : error ? 'error' : 'ready'}
+        loadingLabel={strings.loading}
+        errorLabel={error ?? strings.error}
+        minHeight={160}
+      >
+        {sinks.length === 0 ? (
+          <div className="empty-state alert">{strings.empty}</div>
+        ) : (
+          <div className="table-wrapper">
+            <table className="jobs-table">
+              <thead>
+                <tr>
+                  <th>{strings.form.name}</th>
+                  <th>{strings.form.template}</th>
+                  <th>{strings.form.alertTypes}</th>
+                  <th>{strings.form.enabled}</th>
+                  <th />
+                </tr>
+              </thead>
+              <tbody>
+                {sinks.map((sink) => (
+                  <tr key={sink.id}>
+                    <td>
+                      <strong>{sink.name}</strong>
+                      <div className="text-xs text-muted-foreground break-all">{sink.url}</div>
+                      <div className="text-xs text-muted-foreground">
+                        {strings.secretLabel}: <code>{sink.secret}</code>
+                      </div>
+                    </td>
+                    <td>{strings.templates[sink.template]}</td>
+                    <td>
+                      {sink.alertTypes.length === 0
+                        ? strings.allTypes
+                        : sink.alertTypes.map((type) => strings.types[type] ?? type).join(', ')}
+                    </td>
+                    <td>
+                      <StatusBadge tone={sink.enabled ? 'success' : 'neutral'}>
+                        {sink.enabled ? strings.form.enabled : '—'}
+                      </StatusBadge>
+                    </td>
+                    <td>
+                      <div className="table-actions">
+                        <Button
+                          type="button"
+                          size="xs"
+                          disabled={busyId === sink.id}
+                          onClick={() => void runAction(sink.id, () => testAlertWebhook(sink.id))}
+                        >
+                          {busyId === sink.id ? strings.testing : strings.test}
+                        </Button>
+                        <Button
+                          type="button"
+                          variant="outline"
+                          size="xs"
+                          onClick={() => setEditing({ id: sink.id, draft: draftFromSink(sink) })}
+                        >
+                          {strings.edit}
+                        </Button>
+                        <Button
+                          type="button"
+                          variant="outline"
+                          size="xs"
+                          onClick={() => setHistorySinkId((current) => (current === sink.id ? null : sink.id))}
+                        >
+                          {strings.history}
+                        </Button>
+                        <Button
+                          type="button"
+                          variant="outline"
+                          size="xs"
+                          disabled={busyId === sink.id}
+                          onClick={() => {
+                            if (window.confirm(strings.removeConfirm)) {
+                              void runAction(sink.id, () => deleteAlertWebhook(sink.id))
+                            }
+                          }}
+                        >
+                          {strings.remove}
+                        </Button>
+                      </div>
+                    </td>
+                  </tr>
+                ))}
+              </tbody>
+            </table>
+          </div>
+        )}
+
+        <h4>
+          {strings.history}
+          {historySinkId ? ` · ${sinkName(historySinkId)}` : ''}
+        </h4>
+        {deliveries.length === 0 ? (
+          <div className="empty-state alert">{strings.noHistory}</div>
+        ) : (
+          <div className="table-wrapper">
+            <table className="jobs-table">
+              <thead>
+                <tr>
+                  <th>{strings.table.time}</th>
+                  <th>{strings.form.name}</th>
+                  <th>{strings.table.alert}</th>
+                  <th>{strings.table.status}</th>
+                  <th>{strings.table.attempts}</th>
+                  <th>{strings.table.result}</th>
+                </tr>
+              </thead>
+              <tbody>
+                {deliveries.map((delivery) => (
+                  <tr key={delivery.id}>
+                    <td>{formatTimestamp(delivery.createdAt, language)}</td>
+                    <td>{sinkName(delivery.sinkId)}</td>
+                    <td>{alertLabel(delivery.alertType)}</td>
+                    <td>
+                      <StatusBadge tone={deliveryTone(delivery.status)}>
+                        {strings.status[delivery.status]}
+                      </StatusBadge>
+                    </td>
+                    <td>{delivery.attempts}</td>
+                    <td className="text-xs break-all">
+                      {delivery.lastError ?? (delivery.lastStatusCode != null ? `HTTP ${delivery.lastStatusCode}` : '—')}
+                    </td>
+                  </tr>
+                ))}
+              </tbody>
+            </table>
+          </div>
+        )}
+      </AdminLoadingRegion>
+    </AdminModuleSurface>
+  )
+}
@@ -21,6 +21,7 @@ import {
 } from '../api'
 import type { Language } from '../i18n'
 import { Icon } from '../lib/icons'
+import AlertGroupWorkflowControls from './AlertGroupWorkflowControls'
 import { getBlockingLoadState, getRefreshingLoadState, type QueryLoadState } from './queryLoadState'
 import {
   alertsPath,
@@ -79,6 +80,8 @@ function alertTypeTone(type: AlertType): StatusTone {
     case 'upstream_usage_limit_432':
     case 'upstream_rate_limited_429':
     case 'user_request_rate_limited':
+    case 'token_expiring':
+    case 'threshold_rule':
       return 'warning'
     default:
       return 'neutral'
@@ -187,7 +190,7 @@ function defaultCopy(language: Language) {
   return language === 'zh'
     ? {
         title: '告警中心',
-        description: '查看 429、上游用量限制 432、上游 Key 封禁、API Key 耗尽、任务失败、本地请求限流与额度耗尽事件，并按同一筛选口径聚合。',
+        description: '查看 429、上游用量限制 432、上游 Key 封禁、API Key 耗尽、任务失败、令牌即将过期、本地请求限流与额度耗尽事件，并按同一筛选口径聚合。',
         tabs: { events: '事件记录', groups: '聚合告警' },
         filters: {
           type: '告警类型',
@@ -285,11 +288,13 @@ function defaultCopy(language: Language) {
           user_quota_exhausted: '用户额度耗尽',
           api_key_exhausted: 'API Key 耗尽',
           job_failed: '任务失败',
+          token_expiring: '令牌即将过期',
+          threshold_rule: '阈值规则触发',
         },
       }
     : {
         title: 'Alerts',
-        description: 'Review upstream 429s, upstream usage-limit 432 events, upstream key blocks, API key exhaustion, job failures, local request-rate limits, and quota exhaustion with shared filters.',
+        description: 'Review upstream 429s, upstream usage-limit 432 events, upstream key blocks, API key exhaustion, job failures, expiring access tokens, local request-rate limits, and quota exhaustion with shared filters.',
         tabs: { events: 'Events', groups: 'Groups' },
         filters: {
           type: 'Alert type',
@@ -387,6 +392,8 @@ function defaultCopy(language: Language) {
           user_quota_exhausted: 'User quota exhausted',
           api_key_exhausted: 'API key exhausted',
           job_failed: 'Job failed',
+          token_expiring: 'Token expiring',
+          threshold_rule: 'Threshold rule breached',
         },
       }
 }
@@ -1191,6 +1198,7 @@ export default function AlertsCenter({
                               <strong>{group.latestEvent.title}</strong>
                               <span>{group.latestEvent.summary}</span>
                             </div>
+                            <AlertGroupWorkflowControls group={group} language={language} />
                           </TableCell>
                         </TableRow>
                         {canExpand && expanded
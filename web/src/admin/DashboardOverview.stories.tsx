@@ -131,6 +131,8 @@ const strings = {
     user_quota_exhausted: 'User quota exhausted',
     api_key_exhausted: 'API key exhausted',
     job_failed: 'Job failed',
+    token_expiring: 'Token expiring',
+    threshold_rule: 'Threshold rule breached',
   },
 }
 
@@ -607,6 +609,8 @@ const zhStrings = {
     user_quota_exhausted: '用户额度耗尽',
     api_key_exhausted: 'API Key 耗尽',
     job_failed: '任务失败',
+    token_expiring: '令牌即将过期',
+    threshold_rule: '阈值规则触发',
   },
 }
 
@@ -152,7 +152,9 @@ export interface DashboardOverviewStrings {
     | 'user_request_rate_limited'
     | 'user_quota_exhausted'
     | 'api_key_exhausted'
-    | 'job_failed',
+    | 'job_failed'
+    | 'token_expiring'
+    | 'threshold_rule',
     string
   >
 }
@@ -470,6 +472,8 @@ function alertSummaryTone(type: keyof DashboardOverviewStrings['recentAlertsType
     case 'upstream_usage_limit_432':
     case 'upstream_rate_limited_429':
     case 'user_request_rate_limited':
+    case 'token_expiring':
+    case 'threshold_rule':
       return 'warning'
     default:
       return 'neutral'
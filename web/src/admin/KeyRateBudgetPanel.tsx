@@ -0,0 +1,159 @@
+import { useEffect, useMemo, useState } from 'react'
+
+import { type ApiKeyRateBudgetUsage, updateApiKeyRateBudget } from '../api'
+import { Button } from '../components/ui/button'
+import { Input } from '../components/ui/input'
+import type { Language } from '../i18n'
+
+function copyFor(language: Language) {
+  if (language === 'zh') {
+    return {
+      title: '速率预算',
+      description: '转发前在本地限制此 Key 每分钟的请求数；超出的请求短暂排队，仍无余量则直接拒绝。',
+      stripLabel: 'Key 速率预算',
+      rpmLimit: '每分钟上限',
+      unlimited: '未设置预算',
+      used: '{used}/{limit} 次/分钟',
+      queued: '{count} 排队中',
+      delayed: '累计延迟 {count}',
+      rejected: '累计拒绝 {count}',
+      save: '保存',
+      clear: '清除',
+      saving: '保存中…',
+      invalid: '请输入 1 到 10000 之间的整数',
+    }
+  }
+
+  return {
+    title: 'Rate budget',
+    description:
+      'Caps requests per minute for this key before forwarding; excess requests queue briefly and are rejected when no headroom frees up.',
+    stripLabel: 'Key rate budgets',
+    rpmLimit: 'Requests per minute',
+    unlimited: 'No budget set',
+    used: '{used}/{limit} rpm',
+    queued: '{count} queued',
+    delayed: '{count} delayed',
+    rejected: '{count} rejected',
+    save: 'Save',
+    clear: 'Clear',
+    saving: 'Saving…',
+    invalid: 'Enter a whole number between 1 and 10000',
+  }
+}
+
+type Copy = ReturnType<typeof copyFor>
+
+function usageLine(copy: Copy, usage: ApiKeyRateBudgetUsage, formatNumber: Intl.NumberFormat): string {
+  const parts = [
+    copy.used
+      .replace('{used}', formatNumber.format(usage.usedLastMinute))
+      .replace('{limit}', formatNumber.format(usage.rpmLimit)),
+  ]
+  if (usage.queued > 0) parts.push(copy.queued.replace('{count}', formatNumber.format(usage.queued)))
+  if (usage.delayedTotal > 0) parts.push(copy.delayed.replace('{count}', formatNumber.format(usage.delayedTotal)))
+  if (usage.rejectedTotal > 0) parts.push(copy.rejected.replace('{count}', formatNumber.format(usage.rejectedTotal)))
+  return parts.join(' · ')
+}
+
+function useNumberFormat(language: Language): Intl.NumberFormat {
+  return useMemo(() => new Intl.NumberFormat(language === 'zh' ? 'zh-CN' : 'en-US'), [language])
+}
+
+interface KeyRateBudgetStripProps {
+  language: Language
+  budgets: ApiKeyRateBudgetUsage[]
+}
+
+/** Dashboard row of per-key budget consumption; hidden until some key carries a budget. */
+export function KeyRateBudgetStrip({ language, budgets }: KeyRateBudgetStripProps): JSX.Element | null {
+  const copy = useMemo(() => copyFor(language), [language])
+  const formatNumber = useNumberFormat(language)
+  if (budgets.length === 0) return null
+
+  return (
+    <div role="list" aria-label={copy.stripLabel} style={{ display: 'flex', flexWrap: 'wrap', gap: 8, marginBottom: 12 }}>
+      {budgets.map((usage) => (
+        <div
+          key={usage.keyId}
+          role="listitem"
+          className="panel-description"
+          style={{ border: '1px solid hsl(var(--border))', borderRadius: 10, padding: '6px 10px', minWidth: 180 }}
+        >
+          <div style={{ fontWeight: 600 }}>{usage.keyId}</div>
+          <div>{usageLine(copy, usage, formatNumber)}</div>
+        </div>
+      ))}
+    </div>
+  )
+}
+
+interface KeyRateBudgetPanelProps {
+  language: Language
+  keyId: string
+  usage: ApiKeyRateBudgetUsage | null
+  onSaved: () => void
+}
+
+/** Key detail panel showing the key's budget consumption with an inline editor for the limit. */
+export default function KeyRateBudgetPanel({ language, keyId, usage, onSaved }: KeyRateBudgetPanelProps): JSX.Element {
+  const copy = useMemo(() => copyFor(language), [language])
+  const formatNumber = useNumberFormat(language)
+  const [draft, setDraft] = useState(usage ? String(usage.rpmLimit) : '')
+  const [saving, setSaving] = useState(false)
+  const [error, setError] = useState<string | null>(null)
+
+  useEffect(() => {
+    setDraft(usage ? String(usage.rpmLimit) : '')
+  }, [usage?.rpmLimit])
+
+  const save = async (rpmLimit: number | null) => {
+    if (rpmLimit != null && (!Number.isInteger(rpmLimit) || rpmLimit < 1 || rpmLimit > 10_000)) {
+      setError(copy.invalid)
+      return
+    }
+    setSaving(true)
+    setError(null)
+    try {
+      await updateApiKeyRateBudget(keyId, rpmLimit)
+      onSaved()
+    } catch (err) {
+      setError(err instanceof Error ? err.message : String(err))
+    } finally {
+      setSaving(false)
+    }
+  }
+
+  return (
+    <section className="surface panel">
+      <div className="panel-header">
+        <div>
+          <h2>{copy.title}</h2>
+          <p className="panel-description">{copy.description}</p>
+        </div>
+        <div style={{ display: 'flex', gap: 8, alignItems: 'center', flexWrap: 'wrap' }}>
+          <Input
+            type="number"
+            name="key-rate-budget-rpm"
+            min={1}
+            max={10_000}
+            value={draft}
+            placeholder={copy.rpmLimit}
+            aria-label={copy.rpmLimit}
+            onChange={(event) => setDraft(event.target.value)}
+            className="w-[176px]"
+            disabled={saving}
+          />
+          <Button type="button" onClick={() => void save(Number(draft))} disabled={saving || draft.trim() === ''}>
+            {saving ? copy.saving : copy.save}
+          </Button>
+          <Button type="button" variant="ghost" onClick={() => void save(null)} disabled={saving || !usage}>
+            {copy.clear}
+          </Button>
+        </div>
+      </div>
+      <p className="panel-description">{usage ? usageLine(copy, usage, formatNumber) : copy.unlimited}</p>
+      {error && <div className="alert alert-error" role="alert">{error}</div>}
+    </section>
+  )
+}
@@ -0,0 +1,73 @@
+import { useMemo } from 'react'
+
+import type { ApiKeyTierCapacity } from '../api'
+import type { Language } from '../i18n'
+
+function copyFor(language: Language) {
+  if (language === 'zh') {
+    return {
+      ariaLabel: 'Key 优先级层容量',
+      tier: '优先级 {priority}',
+      ungrouped: '未分组',
+      available: '{available}/{total} 可用',
+      cooling: '{count} 冷却中',
+      exhausted: '{count} 已耗尽',
+      quota: '剩余额度 {remaining}',
+    }
+  }
+
+  return {
+    ariaLabel: 'Key tier capacity',
+    tier: 'Tier {priority}',
+    ungrouped: 'Ungrouped',
+    available: '{available}/{total} available',
+    cooling: '{count} cooling',
+    exhausted: '{count} exhausted',
+    quota: '{remaining} credits left',
+  }
+}
+
+interface KeyTierCapacityStripProps {
+  language: Language
+  tiers: ApiKeyTierCapacity[]
+}
+
+/** Compact per-tier capacity row; hidden while every key still shares the default tier. */
+export default function KeyTierCapacityStrip({ language, tiers }: KeyTierCapacityStripProps): JSX.Element | null {
+  const copy = useMemo(() => copyFor(language), [language])
+  const formatNumber = useMemo(() => new Intl.NumberFormat(language === 'zh' ? 'zh-CN' : 'en-US'), [language])
+  if (tiers.length === 0 || (tiers.length === 1 && tiers[0].priority === 0)) return null
+
+  return (
+    <div
+      role="list"
+      aria-label={copy.ariaLabel}
+      style={{ display: 'flex', flexWrap: 'wrap', gap: 8, marginBottom: 12 }}
+    >
+      {tiers.map((tier) => (
+        <div
+          key={tier.priority}
+          role="listitem"
+          className="panel-description"
+          style={{ border: '1px solid hsl(var(--border))', borderRadius: 10, padding: '6px 10px', minWidth: 180 }}
+        >
+          <div style={{ fontWeight: 600 }}>
+            {copy.tier.replace('{priority}', String(tier.priority))}
+            {' · '}
+            {tier.groups.map((group) => group || copy.ungrouped).join(', ')}
+          </div>
+          <div>
+            {copy.available
+              .replace('{available}', formatNumber.format(tier.availableKeys))
+              .replace('{total}', formatNumber.format(tier.totalKeys))}
+            {tier.coolingKeys > 0 ? ` · ${copy.cooling.replace('{count}', formatNumber.format(tier.coolingKeys))}` : null}
+            {tier.exhaustedKeys > 0
+              ? ` · ${copy.exhausted.replace('{count}', formatNumber.format(tier.exhaustedKeys))}`
+              : null}
+          </div>
+          <div>{copy.quota.replace('{remaining}', formatNumber.format(tier.quotaRemaining))}</div>
+        </div>
+      ))}
+    </div>
+  )
+}
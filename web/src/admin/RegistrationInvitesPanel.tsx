@@ -0,0 +1,459 @@
+import { useCallback, useEffect, useState } from 'react'
+
+import {
+  approveUserRegistration,
+  createRegistrationInvite,
+  fetchAdminRegistrationSettings,
+  fetchAdminUserTags,
+  fetchPendingApprovalUsers,
+  fetchRegistrationInvites,
+  revokeRegistrationInvite,
+  updateRegistrationApproval,
+  type AdminRegistrationSettings,
+  type AdminUserTag,
+  type PendingApprovalUser,
+  type RegistrationInvite,
+  type RegistrationInviteStatus,
+} from '../api'
+import AdminModuleSurface from './AdminModuleSurface'
+import AdminLoadingRegion from '../components/AdminLoadingRegion'
+import { StatusBadge, type StatusTone } from '../components/StatusBadge'
+import { Button } from '../components/ui/button'
+import { Input } from '../components/ui/input'
+import { Switch } from '../components/ui/switch'
+import { copyText } from '../lib/clipboard'
+import { Icon } from '../lib/icons'
+import type { Language } from '../i18n'
+
+interface RegistrationInvitesPanelProps {
+  language: Language
+}
+
+interface InviteDraft {
+  code: string
+  note: string
+  maxUses: string
+  expiresAt: string
+  tagIds: string[]
+  quotaEnabled: boolean
+  businessCalls1hLimit: string
+  dailyCreditsLimit: string
+  monthlyCreditsLimit: string
+}
+
+const EMPTY_DRAFT: InviteDraft = {
+  code: '',
+  note: '',
+  maxUses: '1',
+  expiresAt: '',
+  tagIds: [],
+  quotaEnabled: false,
+  businessCalls1hLimit: '0',
+  dailyCreditsLimit: '0',
+  monthlyCreditsLimit: '0',
+}
+
+const STATUS_TONES: Record<RegistrationInviteStatus, StatusTone> = {
+  active: 'success',
+  exhausted: 'neutral',
+  expired: 'warning',
+  revoked: 'error',
+}
+
+function copy(language: Language) {
+  return language === 'zh'
+    ? {
+        title: '邀请码与注册审批',
+        description: '邀请码可在注册暂停时放行首次登录，并可预设用户标签与基础额度；开启审批后，未使用邀请码的新用户可以登录，但需管理员批准后才会获得令牌。',
+        loading: '正在加载邀请码…',
+        error: '邀请码加载失败。',
+        requireApproval: '新用户需审批',
+        requireApprovalHint: '仅影响开放注册时未使用邀请码的首次登录。',
+        pendingTitle: '待审批用户',
+        pendingEmpty: '没有待审批的用户。',
+        approve: '批准',
+        requestedAt: '申请时间',
+        createTitle: '创建邀请码',
+        code: '邀请码（留空自动生成）',
+        note: '备注',
+        maxUses: '可用次数',
+        expiresAt: '过期时间（可选）',
+        tags: '预设标签',
+        quota: '预设基础额度',
+        businessCalls1h: '1 小时调用',
+        dailyCredits: '每日额度',
+        monthlyCredits: '每月额度',
+        create: '创建',
+        invitesTitle: '邀请码',
+        invitesEmpty: '还没有邀请码。',
+        revoke: '撤销',
+        copyCode: '复制邀请码',
+        uses: (used: number, max: number) => `${used} / ${max}`,
+        never: '不过期',
+        statuses: { active: '可用', exhausted: '已用完', expired: '已过期', revoked: '已撤销' } as Record<
+          RegistrationInviteStatus,
+          string
+        >,
+        table: { code: '邀请码', status: '状态', uses: '使用', expires: '过期', note: '备注', actions: '操作' },
+      }
+    : {
+        title: 'Invites & registration approval',
+        description:
+          'Invite codes let first logins through while registration is paused and can pre-assign user tags and base quota. With approval on, new users without an invite can sign in but get no token until an admin approves them.',
+        loading: 'Loading invites…',
+        error: 'Failed to load invites.',
+        requireApproval: 'Require approval for new users',
+        requireApprovalHint: 'Only affects first logins without an invite while registration is open.',
+        pendingTitle: 'Pending approval',
+        pendingEmpty: 'No users are waiting for approval.',
+        approve: 'Approve',
+        requestedAt: 'Requested',
+        createTitle: 'Create invite',
+        code: 'Code (blank to generate)',
+        note: 'Note',
+        maxUses: 'Max uses',
+        expiresAt: 'Expires at (optional)',
+        tags: 'Pre-assigned tags',
+        quota: 'Pre-assign base quota',
+        businessCalls1h: '1h calls',
+        dailyCredits: 'Daily credits',
+        monthlyCredits: 'Monthly credits',
+        create: 'Create',
+        invitesTitle: 'Invite codes',
+        invitesEmpty: 'No invite codes yet.',
+        revoke: 'Revoke',
+        copyCode: 'Copy code',
+        uses: (used: number, max: number) => `${used} / ${max}`,
+        never: 'Never',
+        statuses: { active: 'Active', exhausted: 'Used up', expired: 'Expired', revoked: 'Revoked' } as Record<
+          RegistrationInviteStatus,
+          string
+        >,
+        table: { code: 'Code', status: 'Status', uses: 'Uses', expires: 'Expires', note: 'Note', actions: 'Actions' },
+      }
+}
+
+function formatTimestamp(ts: number, language: Language): string {
+  return new Date(ts * 1000).toLocaleString(language === 'zh' ? 'zh-CN' : 'en-US', { hour12: false })
+}
+
+function readLimit(value: string): number {
+  const parsed = Number.parseInt(value, 10)
+  return Number.isFinite(parsed) ? parsed : 0
+}
+
+export default function RegistrationInvitesPanel({ language }: RegistrationInvitesPanelProps): JSX.Element {
+  const strings = copy(language)
+  const [settings, setSettings] = useState<AdminRegistrationSettings | null>(null)
+  const [invites, setInvites] = useState<RegistrationInvite[]>([])
+  const [pending, setPending] = useState<PendingApprovalUser[]>([])
+  const [tags, setTags] = useState<AdminUserTag[]>([])
+  const [draft, setDraft] = useState<InviteDraft>(EMPTY_DRAFT)
+  const [loading, setLoading] = useState(true)
+  const [busy, setBusy] = useState(false)
+  const [error, setError] = useState<string | null>(null)
+
+  const load = useCallback(async (signal?: AbortSignal) => {
+    try {
+      const [nextSettings, nextInvites, nextPending, nextTags] = await Promise.all([
+        fetchAdminRegistrationSettings(signal),
+        fetchRegistrationInvites(signal),
+        fetchPendingApprovalUsers(signal),
+        fetchAdminUserTags(signal),
+      ])
+      setSettings(nextSettings)
+      setInvites(nextInvites)
+      setPending(nextPending)
+      setTags(nextTags.filter((tag) => tag.systemKey == null))
+      setError(null)
+    } catch (err) {
+      if (signal?.aborted) return
+      setError(err instanceof Error ? err.message : String(err))
+    } finally {
+      if (!signal?.aborted) setLoading(false)
+    }
+  }, [])
+
+  useEffect(() => {
+    const controller = new AbortController()
+    void load(controller.signal)
+    return () => controller.abort()
+  }, [load])
+
+  const run = async (action: () => Promise<unknown>) => {
+    setBusy(true)
+    try {
+      await action()
+      await load()
+    } catch (err) {
+      setError(err instanceof Error ? err.message : String(err))
+    } finally {
+      setBusy(false)
+    }
+  }
+
+  const submitInvite = () =>
+    run(async () => {
+      await createRegistrationInvite({
+        code: draft.code.trim() || undefined,
+        note: draft.note.trim() || undefined,
+        maxUses: readLimit(draft.maxUses),
+        expiresAt: draft.expiresAt ? Math.floor(new Date(draft.expiresAt).getTime() / 1000) : null,
+        tagIds: draft.tagIds,
+        quota: draft.quotaEnabled
+          ? {
+              businessCalls1hLimit: readLimit(draft.businessCalls1hLimit),
+              dailyCreditsLimit: readLimit(draft.dailyCreditsLimit),
+              monthlyCreditsLimit: readLimit(draft.monthlyCreditsLimit),
+            }
+          : null,
+      })
+      setDraft(EMPTY_DRAFT)
+    })
+
+  const toggleTag = (tagId: string) =>
+    setDraft({
+      ...draft,
+      tagIds: draft.tagIds.includes(tagId) ? draft.tagIds.filter((id) => id !== tagId) : [...draft.tagIds, tagId],
+    })
+
+  return (
+    <AdminModuleSurface className="registration-invites-panel">
+      <div className="announcements-list-header">
+        <div>
+          <h3>{strings.title}</h3>
+          <p>{strings.description}</p>
+        </div>
+      </div>
+
+      <AdminLoadingRegion
+        loadState={loading ? 'initial_loading' : error && !settings ? 'error' : 'ready'}
+        loadingLabel={strings.loading}
+        errorLabel={error ?? strings.error}
+        minHeight={160}
+      >
+        {error && settings ? <div className="alert alert-error">{error}</div> : null}
+
+        <div className="system-settings-action-row">
+          <div className="system-settings-toggle-copy">
+            <span className="system-settings-setting-title">{strings.requireApproval}</span>
+            <p>{strings.requireApprovalHint}</p>
+          </div>
+          <Switch
+            checked={settings?.requireApproval ?? false}
+            disabled={busy || !settings}
+            aria-label={strings.requireApproval}
+            onCheckedChange={(checked) =>
+              settings &&
+              void run(() => updateRegistrationApproval(settings.allowRegistration, checked))
+            }
+          />
+        </div>
+
+        <section className="system-settings-config-section">
+          <h4>{strings.pendingTitle}</h4>
+          {pending.length === 0 ? (
+            <div className="empty-state alert">{strings.pendingEmpty}</div>
+          ) : (
+            <div className="table-wrapper">
+              <table className="jobs-table">
+                <tbody>
+                  {pending.map((user) => (
+                    <tr key={user.userId}>
+                      <td>
+                        {user.displayName ?? user.username ?? user.userId}
+                        <div className="text-xs text-muted-foreground">
+                          {user.provider ?? '—'}
+                          {user.username ? ` · @${user.username}` : ''}
+                        </div>
+                      </td>
+                      <td>
+                        {strings.requestedAt} {formatTimestamp(user.requestedAt, language)}
+                      </td>
+                      <td className="table-actions">
+                        <Button
+                          type="button"
+                          size="sm"
+                          disabled={busy}
+                          onClick={() => void run(() => approveUserRegistration(user.userId))}
+                        >
+                          <Icon icon="mdi:check" width={16} height={16} aria-hidden="true" />
+                          <span>{strings.approve}</span>
+                        </Button>
+                      </td>
+                    </tr>
+                  ))}
+                </tbody>
+              </table>
+            </div>
+          )}
+        </section>
+
+        <form
+          className="system-settings-config-section"
+          onSubmit={(event) => {
+            event.preventDefault()
+            void submitInvite()
+          }}
+        >
+          <h4>{strings.createTitle}</h4>
+          <div className="system-settings-field-grid">
+            <div className="system-settings-field">
+              <label className="text-sm font-medium" htmlFor="registration-invite-code">{strings.code}</label>
+              <Input
+                id="registration-invite-code"
+                value={draft.code}
+                onChange={(event) => setDraft({ ...draft, code: event.target.value })}
+              />
+            </div>
+            <div className="system-settings-field">
+              <label className="text-sm font-medium" htmlFor="registration-invite-note">{strings.note}</label>
+              <Input
+                id="registration-invite-note"
+                value={draft.note}
+                onChange={(event) => setDraft({ ...draft, note: event.target.value })}
+              />
+            </div>
+            <div className="system-settings-field">
+              <label className="text-sm font-medium" htmlFor="registration-invite-max-uses">{strings.maxUses}</label>
+              <Input
+                id="registration-invite-max-uses"
+                type="number"
+                min={1}
+                value={draft.maxUses}
+                onChange={(event) => setDraft({ ...draft, maxUses: event.target.value })}
+              />
+            </div>
+            <div className="system-settings-field">
+              <label className="text-sm font-medium" htmlFor="registration-invite-expires">{strings.expiresAt}</label>
+              <Input
+                id="registration-invite-expires"
+                type="datetime-local"
+                value={draft.expiresAt}
+                onChange={(event) => setDraft({ ...draft, expiresAt: event.target.value })}
+              />
+            </div>
+          </div>
+          {tags.length > 0 ? (
+            <div className="system-settings-field">
+              <span className="text-sm font-medium">{strings.tags}</span>
+              <div className="table-actions">
+                {tags.map((tag) => (
+                  <Button
+                    key={tag.id}
+                    type="button"
+                    size="sm"
+                    variant={draft.tagIds.includes(tag.id) ? 'default' : 'outline'}
+                    aria-pressed={draft.tagIds.includes(tag.id)}
+                    onClick={() => toggleTag(tag.id)}
+                  >
+                    {tag.displayName}
+                  </Button>
+                ))}
+              </div>
+            </div>
+          ) : null}
+          <div className="system-settings-action-row">
+            <div className="system-settings-toggle-copy">
+              <span className="system-settings-setting-title">{strings.quota}</span>
+            </div>
+            <Switch
+              checked={draft.quotaEnabled}
+              aria-label={strings.quota}
+              onCheckedChange={(checked) => setDraft({ ...draft, quotaEnabled: checked })}
+            />
+          </div>
+          {draft.quotaEnabled ? (
+            <div className="system-settings-field-grid">
+              {(
+                [
+                  ['businessCalls1hLimit', strings.businessCalls1h],
+                  ['dailyCreditsLimit', strings.dailyCredits],
+                  ['monthlyCreditsLimit', strings.monthlyCredits],
+                ] as const
+              ).map(([field, label]) => (
+                <div className="system-settings-field" key={field}>
+                  <label className="text-sm font-medium" htmlFor={`registration-invite-${field}`}>{label}</label>
+                  <Input
+                    id={`registration-invite-${field}`}
+                    type="number"
+                    min={0}
+                    value={draft[field]}
+                    onChange={(event) => setDraft({ ...draft, [field]: event.target.value })}
+                  />
+                </div>
+              ))}
+            </div>
+          ) : null}
+          <div className="table-actions">
+            <Button type="submit" size="sm" disabled={busy}>
+              {strings.create}
+            </Button>
+          </div>
+        </form>
+
+        <section className="system-settings-config-section">
+          <h4>{strings.invitesTitle}</h4>
+          {invites.length === 0 ? (
+            <div className="empty-state alert">{strings.invitesEmpty}</div>
+          ) : (
+            <div className="table-wrapper">
+              <table className="jobs-table">
+                <thead>
+                  <tr>
+                    <th>{strings.table.code}</th>
+                    <th>{strings.table.status}</th>
+                    <th>{strings.table.uses}</th>
+                    <th>{strings.table.expires}</th>
+                    <th>{strings.table.note}</th>
+                    <th>{strings.table.actions}</th>
+                  </tr>
+                </thead>
+                <tbody>
+                  {invites.map((invite) => (
+                    <tr key={invite.id}>
+                      <td>
+                        <code>{invite.code}</code>
+                      </td>
+                      <td>
+                        <StatusBadge tone={STATUS_TONES[invite.status] ?? 'neutral'}>
+                          {strings.statuses[invite.status] ?? invite.status}
+                        </StatusBadge>
+                      </td>
+                      <td>{strings.uses(invite.useCount, invite.maxUses)}</td>
+                      <td>{invite.expiresAt ? formatTimestamp(invite.expiresAt, language) : strings.never}</td>
+                      <td>{invite.note ?? '—'}</td>
+                      <td className="table-actions">
+                        <Button
+                          type="button"
+                          size="sm"
+                          variant="ghost"
+                          aria-label={strings.copyCode}
+                          onClick={() => void copyText(invite.code)}
+                        >
+                          <Icon icon="mdi:content-copy" width={16} height={16} aria-hidden="true" />
+                        </Button>
+                        {invite.status !== 'revoked' ? (
+                          <Button
+                            type="button"
+                            size="sm"
+                            variant="outline"
+                            disabled={busy}
+                            onClick={() => void run(() => revokeRegistrationInvite(invite.id))}
+                          >
+                            <Icon icon="mdi:trash-can-outline" width={16} height={16} aria-hidden="true" />
+                            <span>{strings.revoke}</span>
+                          </Button>
+                        ) : null}
+                      </td>
+                    </tr>
+                  ))}
+                </tbody>
+              </table>
+            </div>
+          )}
+        </section>
+      </AdminLoadingRegion>
+    </AdminModuleSurface>
+  )
+}
@@ -0,0 +1,229 @@
+import { useEffect, useState } from 'react'
+
+import {
+  fetchRequestLogReplays,
+  fetchRequestLogReplayUpstreams,
+  replayRequestLog,
+  type RequestLog,
+  type RequestLogReplay,
+  type RequestLogReplayUpstreams,
+} from '../api'
+import { StatusBadge } from '../components/StatusBadge'
+import { Button } from '../components/ui/button'
+import { Input } from '../components/ui/input'
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from '../components/ui/select'
+import { Textarea } from '../components/ui/textarea'
+import { Icon } from '../lib/icons'
+import type { Language } from '../i18n'
+
+interface RequestLogReplayPanelProps {
+  log: RequestLog
+  originalRequestBody: string | null
+  originalResponseBody: string
+  language: Language
+}
+
+const PRIMARY_UPSTREAM = '__primary__'
+
+function copy(language: Language) {
+  return language === 'zh'
+    ? {
+        title: '重放请求',
+        hint: '重放只用于诊断：不计费、不占配额，也不会写入请求日志。',
+        upstream: '上游',
+        primary: (base: string) => `主上游（${base}）`,
+        keyId: '密钥 ID',
+        body: '请求体（JSON）',
+        invalidBody: '请求体不是合法的 JSON。',
+        replay: '重放',
+        replaying: '重放中…',
+        original: '原始响应',
+        replayed: (status: string, ms: number) => `重放响应 · ${status} · ${ms} ms`,
+        noReplay: '还没有重放记录。',
+        noBody: '（无响应体）',
+        history: '历史重放',
+      }
+    : {
+        title: 'Replay request',
+        hint: 'Replays are diagnostics only: they are not billed, do not use quota and are not added to the request log.',
+        upstream: 'Upstream',
+        primary: (base: string) => `Primary (${base})`,
+        keyId: 'Key ID',
+        body: 'Request body (JSON)',
+        invalidBody: 'The request body is not valid JSON.',
+        replay: 'Replay',
+        replaying: 'Replaying…',
+        original: 'Original response',
+        replayed: (status: string, ms: number) => `Replay response · ${status} · ${ms} ms`,
+        noReplay: 'No replays yet.',
+        noBody: '(no response body)',
+        history: 'Replay history',
+      }
+}
+
+function formatTimestamp(ts: number, language: Language): string {
+  return new Date(ts * 1000).toLocaleString(language === 'zh' ? 'zh-CN' : 'en-US', { hour12: false })
+}
+
+/** Only logged Tavily HTTP API requests can be replayed; MCP traffic has no single upstream call. */
+export function isReplayableRequestLog(log: RequestLog): boolean {
+  return log.path.startsWith('/api/tavily/')
+}
+
+export default function RequestLogReplayPanel({
+  log,
+  originalRequestBody,
+  originalResponseBody,
+  language,
+}: RequestLogReplayPanelProps): JSX.Element | null {
+  const strings = copy(language)
+  const [upstreams, setUpstreams] = useState<RequestLogReplayUpstreams | null>(null)
+  const [upstream, setUpstream] = useState(PRIMARY_UPSTREAM)
+  const [keyId, setKeyId] = useState(log.key_id ?? '')
+  const [body, setBody] = useState(originalRequestBody ?? '')
+  const [replays, setReplays] = useState<RequestLogReplay[]>([])
+  const [selectedId, setSelectedId] = useState<number | null>(null)
+  const [running, setRunning] = useState(false)
+  const [error, setError] = useState<string | null>(null)
+  const replayable = isReplayableRequestLog(log)
+
+  useEffect(() => {
+    setBody(originalRequestBody ?? '')
+  }, [originalRequestBody])
+
+  useEffect(() => {
+    if (!replayable) return
+    const controller = new AbortController()
+    Promise.all([
+      fetchRequestLogReplayUpstreams(controller.signal),
+      fetchRequestLogReplays(log.id, controller.signal),
+    ])
+      .then(([nextUpstreams, nextReplays]) => {
+        setUpstreams(nextUpstreams)
+        setReplays(nextReplays)
+      })
+      .catch((err) => {
+        if (controller.signal.aborted) return
+        setError(err instanceof Error ? err.message : String(err))
+      })
+    return () => controller.abort()
+  }, [log.id, replayable])
+
+  if (!replayable) return null
+
+  const replay = async () => {
+    let parsedBody: unknown
+    const trimmed = body.trim()
+    if (trimmed && trimmed !== (originalRequestBody ?? '').trim()) {
+      try {
+        parsedBody = JSON.parse(trimmed)
+      } catch {
+        setError(strings.invalidBody)
+        return
+      }
+    }
+    setRunning(true)
+    setError(null)
+    try {
+      const created = await replayRequestLog(log.id, {
+        keyId: keyId.trim() || undefined,
+        upstream: upstream === PRIMARY_UPSTREAM ? undefined : upstream,
+        body: parsedBody,
+      })
+      setReplays((current) => [created, ...current.filter((item) => item.id !== created.id)])
+      setSelectedId(created.id)
+    } catch (err) {
+      setError(err instanceof Error ? err.message : String(err))
+    } finally {
+      setRunning(false)
+    }
+  }
+
+  const selected = replays.find((item) => item.id === selectedId) ?? replays[0] ?? null
+  const selectedStatus = selected?.statusCode != null ? String(selected.statusCode) : '—'
+
+  return (
+    <div className="log-details-replay">
+      <div className="log-details-section">
+        <header>{strings.title}</header>
+        <p className="log-details-feedback-message">{strings.hint}</p>
+      </div>
+      <div className="log-details-replay-form">
+        <div className="system-settings-field">
+          <label className="text-sm font-medium">{strings.upstream}</label>
+          <Select value={upstream} onValueChange={setUpstream}>
+            <SelectTrigger aria-label={strings.upstream}>
+              <SelectValue />
+            </SelectTrigger>
+            <SelectContent>
+              <SelectItem value={PRIMARY_UPSTREAM}>{strings.primary(upstreams?.primary ?? '…')}</SelectItem>
+              {(upstreams?.alternates ?? []).map((base) => (
+                <SelectItem key={base} value={base}>
+                  {base}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+        </div>
+        <div className="system-settings-field">
+          <label className="text-sm font-medium" htmlFor={`log-replay-key-${log.id}`}>{strings.keyId}</label>
+          <Input id={`log-replay-key-${log.id}`} value={keyId} onChange={(event) => setKeyId(event.target.value)} />
+        </div>
+      </div>
+      <div className="system-settings-field">
+        <label className="text-sm font-medium" htmlFor={`log-replay-body-${log.id}`}>{strings.body}</label>
+        <Textarea
+          id={`log-replay-body-${log.id}`}
+          className="log-details-replay-body"
+          value={body}
+          spellCheck={false}
+          onChange={(event) => setBody(event.target.value)}
+        />
+      </div>
+      <div className="log-details-feedback">
+        <span className="log-details-feedback-message" role={error ? 'alert' : undefined}>
+          {error ?? (selected ? `${strings.history}: ${replays.length}` : strings.noReplay)}
+        </span>
+        <Button type="button" size="sm" disabled={running} onClick={() => void replay()}>
+          <Icon icon="mdi:refresh" width={16} height={16} aria-hidden="true" />
+          <span>{running ? strings.replaying : strings.replay}</span>
+        </Button>
+      </div>
+      {selected ? (
+        <div className="log-details-body">
+          <div className="log-details-section">
+            <header>{strings.original}</header>
+            <pre>{originalResponseBody}</pre>
+          </div>
+          <div className="log-details-section">
+            <header>{strings.replayed(selectedStatus, selected.durationMs)}</header>
+            <pre>{selected.errorMessage ?? selected.responseBody ?? strings.noBody}</pre>
+          </div>
+        </div>
+      ) : null}
+      {replays.length > 1 ? (
+        <div className="log-details-section">
+          <header>{strings.history}</header>
+          <ul>
+            {replays.map((item) => (
+              <li key={item.id}>
+                <button
+                  type="button"
+                  className="log-details-replay-history-item"
+                  aria-pressed={item.id === selected?.id}
+                  onClick={() => setSelectedId(item.id)}
+                >
+                  <StatusBadge tone={item.statusCode != null && item.statusCode < 400 ? 'success' : 'error'}>
+                    {item.statusCode ?? '—'}
+                  </StatusBadge>
+                  <span>{formatTimestamp(item.createdAt, language)}</span>
+                  <code>{item.upstream}</code>
+                </button>
+              </li>
+            ))}
+          </ul>
+        </div>
+      ) : null}
+    </div>
+  )
+}
@@ -43,6 +43,18 @@ describe('SystemSettingsModule rendering', () => {
           rebalanceMcpSessionPercent: 0,
           apiRebalanceEnabled: false,
           apiRebalancePercent: 0,
+          keySelectionMode: 'lru',
+          crossKeyRetry: { enabled: false, endpoints: ['search', 'extract', 'map'], maxAttempts: 3, budgetMs: 8000 },
+          responseCache: {
+            enabled: false,
+            endpoints: ['search', 'extract', 'map'],
+            ttlSecs: 600,
+            endpointTtlSecs: {},
+            maxEntries: 5000,
+            maxBodyBytes: 524288,
+            hitBillingPercent: 0,
+          },
+          requestCoalescing: { enabled: false, endpoints: ['search', 'extract', 'crawl', 'map'], followerBillingPercent: 100 },
           upstreamProjectIdMode: 'accessToken',
           upstreamProjectIdFixedValue: '',
           upstreamMcpUserAgent: '',
@@ -119,6 +131,18 @@ describe('SystemSettingsModule rendering', () => {
           rebalanceMcpSessionPercent: 0,
           apiRebalanceEnabled: false,
           apiRebalancePercent: 0,
+          keySelectionMode: 'lru',
+          crossKeyRetry: { enabled: false, endpoints: ['search', 'extract', 'map'], maxAttempts: 3, budgetMs: 8000 },
+          responseCache: {
+            enabled: false,
+            endpoints: ['search', 'extract', 'map'],
+            ttlSecs: 600,
+            endpointTtlSecs: {},
+            maxEntries: 5000,
+            maxBodyBytes: 524288,
+            hitBillingPercent: 0,
+          },
+          requestCoalescing: { enabled: false, endpoints: ['search', 'extract', 'crawl', 'map'], followerBillingPercent: 100 },
           upstreamProjectIdMode: 'accessToken',
           upstreamProjectIdFixedValue: '',
           upstreamMcpUserAgent: '',
@@ -161,6 +185,18 @@ describe('SystemSettingsModule rendering', () => {
           rebalanceMcpSessionPercent: 100,
           apiRebalanceEnabled: true,
           apiRebalancePercent: 100,
+          keySelectionMode: 'lru',
+          crossKeyRetry: { enabled: false, endpoints: ['search', 'extract', 'map'], maxAttempts: 3, budgetMs: 8000 },
+          responseCache: {
+            enabled: false,
+            endpoints: ['search', 'extract', 'map'],
+            ttlSecs: 600,
+            endpointTtlSecs: {},
+            maxEntries: 5000,
+            maxBodyBytes: 524288,
+            hitBillingPercent: 0,
+          },
+          requestCoalescing: { enabled: false, endpoints: ['search', 'extract', 'crawl', 'map'], followerBillingPercent: 100 },
           upstreamProjectIdMode: 'accessToken',
           upstreamProjectIdFixedValue: '',
           upstreamMcpUserAgent: '',
@@ -202,6 +238,18 @@ describe('SystemSettingsModule rendering', () => {
           rebalanceMcpSessionPercent: 0,
           apiRebalanceEnabled: false,
           apiRebalancePercent: 0,
+          keySelectionMode: 'lru',
+          crossKeyRetry: { enabled: false, endpoints: ['search', 'extract', 'map'], maxAttempts: 3, budgetMs: 8000 },
+          responseCache: {
+            enabled: false,
+            endpoints: ['search', 'extract', 'map'],
+            ttlSecs: 600,
+            endpointTtlSecs: {},
+            maxEntries: 5000,
+            maxBodyBytes: 524288,
+            hitBillingPercent: 0,
+          },
+          requestCoalescing: { enabled: false, endpoints: ['search', 'extract', 'crawl', 'map'], followerBillingPercent: 100 },
           upstreamProjectIdMode: 'accessToken',
           upstreamProjectIdFixedValue: '',
           upstreamMcpUserAgent: '',
@@ -44,6 +44,18 @@ function SystemSettingsCanvas(props: {
     rebalanceMcpSessionPercent: props.rebalanceEnabled ? 100 : 0,
     apiRebalanceEnabled: props.apiRebalanceEnabled ?? false,
     apiRebalancePercent: props.apiRebalanceEnabled ? 100 : 0,
+    keySelectionMode: 'lru',
+    crossKeyRetry: { enabled: false, endpoints: ['search', 'extract', 'map'], maxAttempts: 3, budgetMs: 8000 },
+    responseCache: {
+      enabled: false,
+      endpoints: ['search', 'extract', 'map'],
+      ttlSecs: 600,
+      endpointTtlSecs: {},
+      maxEntries: 5000,
+      maxBodyBytes: 524288,
+      hitBillingPercent: 0,
+    },
+    requestCoalescing: { enabled: false, endpoints: ['search', 'extract', 'crawl', 'map'], followerBillingPercent: 100 },
     upstreamProjectIdMode: props.upstreamProjectIdMode ?? 'accessToken',
     upstreamProjectIdFixedValue: props.upstreamProjectIdFixedValue ?? '',
     upstreamMcpUserAgent: props.upstreamMcpUserAgent ?? '',
@@ -213,6 +225,18 @@ const meta = {
       rebalanceMcpSessionPercent: 0,
       apiRebalanceEnabled: false,
       apiRebalancePercent: 0,
+      keySelectionMode: 'lru',
+      crossKeyRetry: { enabled: false, endpoints: ['search', 'extract', 'map'], maxAttempts: 3, budgetMs: 8000 },
+      responseCache: {
+        enabled: false,
+        endpoints: ['search', 'extract', 'map'],
+        ttlSecs: 600,
+        endpointTtlSecs: {},
+        maxEntries: 5000,
+        maxBodyBytes: 524288,
+        hitBillingPercent: 0,
+      },
+      requestCoalescing: { enabled: false, endpoints: ['search', 'extract', 'crawl', 'map'], followerBillingPercent: 100 },
       upstreamProjectIdMode: 'accessToken',
       upstreamProjectIdFixedValue: '',
       upstreamMcpUserAgent: '',
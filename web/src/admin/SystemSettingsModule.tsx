@@ -2,6 +2,7 @@ import { useEffect, useState, type KeyboardEvent } from 'react'
 
 import {
   type AdminUserListStats,
+  type KeySelectionMode,
   fetchObservedClientIpRequests,
   type ObservedClientIpRequest,
   type RequestLogRetentionProfile,
@@ -59,6 +60,10 @@ type NormalSystemSettingsOverrides = Partial<
     | 'mcpSessionAffinityKeyCount'
     | 'rebalanceMcpEnabled'
     | 'apiRebalanceEnabled'
+    | 'keySelectionMode'
+    | 'crossKeyRetry'
+    | 'responseCache'
+    | 'requestCoalescing'
     | 'upstreamProjectIdMode'
     | 'upstreamProjectIdFixedValue'
     | 'upstreamMcpUserAgent'
@@ -273,6 +278,14 @@ export default function SystemSettingsModule({
   const [draftCount, setDraftCount] = useState(() => (settings ? String(settings.mcpSessionAffinityKeyCount) : ''))
   const [draftRebalanceEnabled, setDraftRebalanceEnabled] = useState(settings?.rebalanceMcpEnabled ?? false)
   const [draftApiRebalanceEnabled, setDraftApiRebalanceEnabled] = useState(settings?.apiRebalanceEnabled ?? false)
+  const [draftKeySelectionMode, setDraftKeySelectionMode] = useState<KeySelectionMode>(
+    settings?.keySelectionMode ?? 'lru',
+  )
+  const [draftCrossKeyRetryEnabled, setDraftCrossKeyRetryEnabled] = useState(settings?.crossKeyRetry?.enabled ?? false)
+  const [draftResponseCacheEnabled, setDraftResponseCacheEnabled] = useState(settings?.responseCache?.enabled ?? false)
+  const [draftRequestCoalescingEnabled, setDraftRequestCoalescingEnabled] = useState(
+    settings?.requestCoalescing?.enabled ?? false,
+  )
   const [draftUpstreamProjectIdMode, setDraftUpstreamProjectIdMode] = useState<UpstreamProjectIdMode>(
     settings?.upstreamProjectIdMode ?? 'accessToken',
   )
@@ -315,6 +328,10 @@ export default function SystemSettingsModule({
     setDraftCount(settings ? String(settings.mcpSessionAffinityKeyCount) : '')
     setDraftRebalanceEnabled(settings?.rebalanceMcpEnabled ?? false)
     setDraftApiRebalanceEnabled(settings?.apiRebalanceEnabled ?? false)
+    setDraftKeySelectionMode(settings?.keySelectionMode ?? 'lru')
+    setDraftCrossKeyRetryEnabled(settings?.crossKeyRetry?.enabled ?? false)
+    setDraftResponseCacheEnabled(settings?.responseCache?.enabled ?? false)
+    setDraftRequestCoalescingEnabled(settings?.requestCoalescing?.enabled ?? false)
     setDraftUpstreamProjectIdMode(settings?.upstreamProjectIdMode ?? 'accessToken')
     setDraftUpstreamProjectIdFixedValue(settings?.upstreamProjectIdFixedValue ?? '')
     setDraftUpstreamMcpUserAgent(settings?.upstreamMcpUserAgent ?? '')
@@ -335,6 +352,10 @@ export default function SystemSettingsModule({
     settings?.mcpSessionAffinityKeyCount,
     settings?.rebalanceMcpEnabled,
     settings?.apiRebalanceEnabled,
+    settings?.keySelectionMode,
+    settings?.crossKeyRetry?.enabled,
+    settings?.responseCache?.enabled,
+    settings?.requestCoalescing?.enabled,
     settings?.upstreamProjectIdMode,
     settings?.upstreamProjectIdFixedValue,
     settings?.upstreamMcpUserAgent,
@@ -414,6 +435,7 @@ export default function SystemSettingsModule({
       effectiveDraftRebalancePercent !== settings.rebalanceMcpSessionPercent ||
       draftApiRebalanceEnabled !== settings.apiRebalanceEnabled ||
       effectiveDraftApiRebalancePercent !== settings.apiRebalancePercent ||
+      draftKeySelectionMode !== settings.keySelectionMode ||
       draftUpstreamProjectIdMode !== settings.upstreamProjectIdMode ||
       normalizedUpstreamProjectIdFixedValue !== settings.upstreamProjectIdFixedValue ||
       normalizedUpstreamMcpUserAgent !== settings.upstreamMcpUserAgent ||
@@ -495,6 +517,10 @@ export default function SystemSettingsModule({
       rebalanceMcpSessionPercent: nextRebalanceMcpEnabled ? 100 : 0,
       apiRebalanceEnabled: nextApiRebalanceEnabled,
       apiRebalancePercent: nextApiRebalanceEnabled ? 100 : 0,
+      keySelectionMode: overrides.keySelectionMode ?? draftKeySelectionMode,
+      crossKeyRetry: overrides.crossKeyRetry ?? settings.crossKeyRetry,
+      responseCache: overrides.responseCache ?? settings.responseCache,
+      requestCoalescing: overrides.requestCoalescing ?? settings.requestCoalescing,
       upstreamProjectIdMode: nextUpstreamProjectIdMode,
       upstreamProjectIdFixedValue: nextUpstreamProjectIdFixedValue,
       upstreamMcpUserAgent: nextUpstreamMcpUserAgent,
@@ -521,6 +547,10 @@ export default function SystemSettingsModule({
       payload.rebalanceMcpSessionPercent !== settings.rebalanceMcpSessionPercent ||
       payload.apiRebalanceEnabled !== settings.apiRebalanceEnabled ||
       payload.apiRebalancePercent !== settings.apiRebalancePercent ||
+      payload.keySelectionMode !== settings.keySelectionMode ||
+      JSON.stringify(payload.crossKeyRetry) !== JSON.stringify(settings.crossKeyRetry) ||
+      JSON.stringify(payload.responseCache) !== JSON.stringify(settings.responseCache) ||
+      JSON.stringify(payload.requestCoalescing) !== JSON.stringify(settings.requestCoalescing) ||
       payload.upstreamProjectIdMode !== settings.upstreamProjectIdMode ||
       payload.upstreamProjectIdFixedValue !== settings.upstreamProjectIdFixedValue ||
       payload.upstreamMcpUserAgent !== settings.upstreamMcpUserAgent ||
@@ -1315,6 +1345,102 @@ export default function SystemSettingsModule({
                   disabled={saving}
                 />
               </div>
+
+              <div className="system-settings-toggle-row">
+                <div className="system-settings-toggle-copy">
+                  <label className="text-sm font-medium" htmlFor="system-settings-quota-weighted-selection-switch">
+                    {strings.form.quotaWeightedSelectionLabel}
+                  </label>
+                  <p className="text-xs text-muted-foreground">{strings.form.quotaWeightedSelectionHint}</p>
+                </div>
+                <Switch
+                  aria-label={strings.form.quotaWeightedSelectionLabel}
+                  id="system-settings-quota-weighted-selection-switch"
+                  checked={draftKeySelectionMode === 'quotaWeighted'}
+                  onCheckedChange={(checked) => {
+                    const nextMode: KeySelectionMode = checked ? 'quotaWeighted' : 'lru'
+                    setDraftKeySelectionMode(nextMode)
+                    void commitNormalSettings({
+                      keySelectionMode: nextMode,
+                    }).then((saved) => {
+                      if (!saved) setDraftKeySelectionMode(settings?.keySelectionMode ?? 'lru')
+                    })
+                  }}
+                  disabled={saving}
+                />
+              </div>
+
+              <div className="system-settings-toggle-row">
+                <div className="system-settings-toggle-copy">
+                  <label className="text-sm font-medium" htmlFor="system-settings-cross-key-retry-switch">
+                    {strings.form.crossKeyRetryLabel}
+                  </label>
+                  <p className="text-xs text-muted-foreground">{strings.form.crossKeyRetryHint}</p>
+                </div>
+                <Switch
+                  aria-label={strings.form.crossKeyRetryLabel}
+                  id="system-settings-cross-key-retry-switch"
+                  checked={draftCrossKeyRetryEnabled}
+                  onCheckedChange={(checked) => {
+                    if (!settings) return
+                    setDraftCrossKeyRetryEnabled(checked)
+                    void commitNormalSettings({
+                      crossKeyRetry: { ...settings.crossKeyRetry, enabled: checked },
+                    }).then((saved) => {
+                      if (!saved) setDraftCrossKeyRetryEnabled(settings.crossKeyRetry?.enabled ?? false)
+                    })
+                  }}
+                  disabled={saving}
+                />
+              </div>
+
+              <div className="system-settings-toggle-row">
+                <div className="system-settings-toggle-copy">
+                  <label className="text-sm font-medium" htmlFor="system-settings-response-cache-switch">
+                    {strings.form.responseCacheLabel}
+                  </label>
+                  <p className="text-xs text-muted-foreground">{strings.form.responseCacheHint}</p>
+                </div>
+                <Switch
+                  aria-label={strings.form.responseCacheLabel}
+                  id="system-settings-response-cache-switch"
+                  checked={draftResponseCacheEnabled}
+                  onCheckedChange={(checked) => {
+                    if (!settings) return
+                    setDraftResponseCacheEnabled(checked)
+                    void commitNormalSettings({
+                      responseCache: { ...settings.responseCache, enabled: checked },
+                    }).then((saved) => {
+                      if (!saved) setDraftResponseCacheEnabled(settings.responseCache?.enabled ?? false)
+                    })
+                  }}
+                  disabled={saving}
+                />
+              </div>
+
+              <div className="system-settings-toggle-row">
+                <div className="system-settings-toggle-copy">
+                  <label className="text-sm font-medium" htmlFor="system-settings-request-coalescing-switch">
+                    {strings.form.requestCoalescingLabel}
+                  </label>
+                  <p className="text-xs text-muted-foreground">{strings.form.requestCoalescingHint}</p>
+                </div>
+                <Switch
+                  aria-label={strings.form.requestCoalescingLabel}
+                  id="system-settings-request-coalescing-switch"
+                  checked={draftRequestCoalescingEnabled}
+                  onCheckedChange={(checked) => {
+                    if (!settings) return
+                    setDraftRequestCoalescingEnabled(checked)
+                    void commitNormalSettings({
+                      requestCoalescing: { ...settings.requestCoalescing, enabled: checked },
+                    }).then((saved) => {
+                      if (!saved) setDraftRequestCoalescingEnabled(settings.requestCoalescing?.enabled ?? false)
+                    })
+                  }}
+                  disabled={saving}
+                />
+              </div>
             </div>
           </section>
 
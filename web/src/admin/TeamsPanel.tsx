@@ -0,0 +1,576 @@
+import { useCallback, useEffect, useState } from 'react'
+
+import {
+  createTeam,
+  createTeamToken,
+  deleteTeam,
+  deleteTeamToken,
+  fetchTeamDetail,
+  fetchTeams,
+  fetchTeamUsageSeries,
+  putTeamMember,
+  removeTeamMember,
+  updateTeam,
+  type Team,
+  type TeamDetail,
+  type TeamRole,
+  type TeamUsageSeriesKey,
+} from '../api'
+import AdminModuleSurface from './AdminModuleSurface'
+import AdminLoadingRegion from '../components/AdminLoadingRegion'
+import TeamUsageSummary from '../components/TeamUsageSummary'
+import { Button } from '../components/ui/button'
+import { Input } from '../components/ui/input'
+import { Switch } from '../components/ui/switch'
+import { copyText } from '../lib/clipboard'
+import { Icon } from '../lib/icons'
+import type { Language } from '../i18n'
+
+interface TeamsPanelProps {
+  language: Language
+}
+
+interface TeamDraft {
+  name: string
+  hourlyCreditsLimit: string
+  dailyCreditsLimit: string
+  monthlyCreditsLimit: string
+  enforceMemberLimits: boolean
+}
+
+const EMPTY_DRAFT: TeamDraft = {
+  name: '',
+  hourlyCreditsLimit: '0',
+  dailyCreditsLimit: '0',
+  monthlyCreditsLimit: '0',
+  enforceMemberLimits: true,
+}
+
+const TEAM_ROLES: TeamRole[] = ['owner', 'admin', 'member']
+
+function copy(language: Language) {
+  return language === 'zh'
+    ? {
+        title: '团队',
+        description:
+          '团队共享一个按小时、日、月计算的额度池。成员的调用同时计入团队池；关闭“同时限制个人额度”后，成员只受团队池约束。团队令牌不绑定用户，直接消耗团队池。',
+        loading: '正在加载团队…',
+        error: '团队加载失败。',
+        createTitle: '创建团队',
+        editTitle: '团队设置',
+        name: '名称',
+        hourly: '每小时额度',
+        daily: '每日额度',
+        monthly: '每月额度',
+        enforceMemberLimits: '同时限制个人额度',
+        enforceMemberLimitsHint: '开启时成员需同时满足个人额度与团队池；关闭时团队池替代个人额度。',
+        create: '创建',
+        save: '保存',
+        delete: '删除团队',
+        empty: '还没有团队。',
+        manage: '管理',
+        membersTitle: '成员',
+        membersEmpty: '还没有成员。',
+        addMember: '添加成员',
+        userId: '用户 ID',
+        remove: '移除',
+        roles: { owner: '所有者', admin: '管理员', member: '成员' } as Record<TeamRole, string>,
+        tokensTitle: '团队令牌',
+        tokensEmpty: '还没有团队令牌。',
+        tokenNote: '令牌备注（可选）',
+        createToken: '创建令牌',
+        createdTitle: '新令牌只显示这一次，请立即复制保存：',
+        copyToken: '复制令牌',
+        dismiss: '关闭',
+        disabled: '已停用',
+        usageTitle: '团队用量',
+        table: { name: '名称', members: '成员', tokens: '令牌', limits: '时 / 日 / 月额度', actions: '操作' },
+        tokenTable: { note: '备注', createdBy: '创建者', lastUsed: '最近使用', actions: '操作' },
+        never: '从未',
+      }
+    : {
+        title: 'Teams',
+        description:
+          'A team shares one hourly, daily and monthly credit pool. Member calls also draw from the pool; with "Also enforce personal limits" off, members answer to the pool only. Team tokens are not bound to a user and draw from the pool directly.',
+        loading: 'Loading teams…',
+        error: 'Failed to load teams.',
+        createTitle: 'Create team',
+        editTitle: 'Team settings',
+        name: 'Name',
+        hourly: 'Hourly credits',
+        daily: 'Daily credits',
+        monthly: 'Monthly credits',
+        enforceMemberLimits: 'Also enforce personal limits',
+        enforceMemberLimitsHint:
+          'When on, members must fit both their personal limits and the team pool. When off, the pool replaces personal limits.',
+        create: 'Create',
+        save: 'Save',
+        delete: 'Delete team',
+        empty: 'No teams yet.',
+        manage: 'Manage',
+        membersTitle: 'Members',
+        membersEmpty: 'No members yet.',
+        addMember: 'Add member',
+        userId: 'User ID',
+        remove: 'Remove',
+        roles: { owner: 'Owner', admin: 'Admin', member: 'Member' } as Record<TeamRole, string>,
+        tokensTitle: 'Team tokens',
+        tokensEmpty: 'No team tokens yet.',
+        tokenNote: 'Token note (optional)',
+        createToken: 'Create token',
+        createdTitle: 'This token is shown only once. Copy it now:',
+        copyToken: 'Copy token',
+        dismiss: 'Dismiss',
+        disabled: 'Disabled',
+        usageTitle: 'Team usage',
+        table: { name: 'Name', members: 'Members', tokens: 'Tokens', limits: 'Hour / day / month', actions: 'Actions' },
+        tokenTable: { note: 'Note', createdBy: 'Created by', lastUsed: 'Last used', actions: 'Actions' },
+        never: 'Never',
+      }
+}
+
+function formatTimestamp(ts: number, language: Language): string {
+  return new Date(ts * 1000).toLocaleString(language === 'zh' ? 'zh-CN' : 'en-US', { hour12: false })
+}
+
+function readLimit(value: string): number {
+  const parsed = Number.parseInt(value, 10)
+  return Number.isFinite(parsed) ? parsed : 0
+}
+
+function draftFromTeam(team: Team): TeamDraft {
+  return {
+    name: team.name,
+    hourlyCreditsLimit: String(team.limits.hourlyCreditsLimit),
+    dailyCreditsLimit: String(team.limits.dailyCreditsLimit),
+    monthlyCreditsLimit: String(team.limits.monthlyCreditsLimit),
+    enforceMemberLimits: team.enforceMemberLimits,
+  }
+}
+
+function requestFromDraft(draft: TeamDraft) {
+  return {
+    name: draft.name.trim(),
+    limits: {
+      hourlyCreditsLimit: readLimit(draft.hourlyCreditsLimit),
+      dailyCreditsLimit: readLimit(draft.dailyCreditsLimit),
+      monthlyCreditsLimit: readLimit(draft.monthlyCreditsLimit),
+    },
+    enforceMemberLimits: draft.enforceMemberLimits,
+  }
+}
+
+interface TeamDraftFieldsProps {
+  idPrefix: string
+  draft: TeamDraft
+  strings: ReturnType<typeof copy>
+  onChange: (draft: TeamDraft) => void
+}
+
+function TeamDraftFields({ idPrefix, draft, strings, onChange }: TeamDraftFieldsProps): JSX.Element {
+  return (
+    <>
+      <div className="system-settings-field-grid">
+        <div className="system-settings-field">
+          <label className="text-sm font-medium" htmlFor={`${idPrefix}-name`}>{strings.name}</label>
+          <Input
+            id={`${idPrefix}-name`}
+            value={draft.name}
+            onChange={(event) => onChange({ ...draft, name: event.target.value })}
+          />
+        </div>
+        {(
+          [
+            ['hourlyCreditsLimit', strings.hourly],
+            ['dailyCreditsLimit', strings.daily],
+            ['monthlyCreditsLimit', strings.monthly],
+          ] as const
+        ).map(([field, label]) => (
+          <div className="system-settings-field" key={field}>
+            <label className="text-sm font-medium" htmlFor={`${idPrefix}-${field}`}>{label}</label>
+            <Input
+              id={`${idPrefix}-${field}`}
+              type="number"
+              min={0}
+              value={draft[field]}
+              onChange={(event) => onChange({ ...draft, [field]: event.target.value })}
+            />
+          </div>
+        ))}
+      </div>
+      <div className="system-settings-action-row">
+        <div className="system-settings-toggle-copy">
+          <span className="system-settings-setting-title">{strings.enforceMemberLimits}</span>
+          <p>{strings.enforceMemberLimitsHint}</p>
+        </div>
+        <Switch
+          checked={draft.enforceMemberLimits}
+          aria-label={strings.enforceMemberLimits}
+          onCheckedChange={(checked) => onChange({ ...draft, enforceMemberLimits: checked })}
+        />
+      </div>
+    </>
+  )
+}
+
+export default function TeamsPanel({ language }: TeamsPanelProps): JSX.Element {
+  const strings = copy(language)
+  const [teams, setTeams] = useState<Team[]>([])
+  const [detail, setDetail] = useState<TeamDetail | null>(null)
+  const [selectedId, setSelectedId] = useState<string | null>(null)
+  const [createDraft, setCreateDraft] = useState<TeamDraft>(EMPTY_DRAFT)
+  const [editDraft, setEditDraft] = useState<TeamDraft>(EMPTY_DRAFT)
+  const [memberUserId, setMemberUserId] = useState('')
+  const [memberRole, setMemberRole] = useState<TeamRole>('member')
+  const [tokenNote, setTokenNote] = useState('')
+  const [createdToken, setCreatedToken] = useState<string | null>(null)
+  const [loading, setLoading] = useState(true)
+  const [loaded, setLoaded] = useState(false)
+  const [busy, setBusy] = useState(false)
+  const [error, setError] = useState<string | null>(null)
+
+  const load = useCallback(
+    async (signal?: AbortSignal) => {
+      try {
+        setTeams(await fetchTeams(signal))
+        if (selectedId) {
+          const nextDetail = await fetchTeamDetail(selectedId, signal)
+          setDetail(nextDetail)
+          setEditDraft(draftFromTeam(nextDetail.team))
+        } else {
+          setDetail(null)
+        }
+        setLoaded(true)
+        setError(null)
+      } catch (err) {
+        if (signal?.aborted) return
+        setError(err instanceof Error ? err.message : String(err))
+      } finally {
+        if (!signal?.aborted) setLoading(false)
+      }
+    },
+    [selectedId],
+  )
+
+  useEffect(() => {
+    const controller = new AbortController()
+    void load(controller.signal)
+    return () => controller.abort()
+  }, [load])
+
+  const loadSeries = useCallback(
+    (series: TeamUsageSeriesKey, signal: AbortSignal) => fetchTeamUsageSeries(selectedId ?? '', series, signal),
+    [selectedId],
+  )
+
+  const run = async (action: () => Promise<unknown>) => {
+    setBusy(true)
+    try {
+      await action()
+      await load()
+    } catch (err) {
+      setError(err instanceof Error ? err.message : String(err))
+    } finally {
+      setBusy(false)
+    }
+  }
+
+  const submitTeam = () =>
+    run(async () => {
+      const created = await createTeam(requestFromDraft(createDraft))
+      setCreateDraft(EMPTY_DRAFT)
+      setSelectedId(created.id)
+    })
+
+  const removeSelectedTeam = (teamId: string) =>
+    run(async () => {
+      await deleteTeam(teamId)
+      setSelectedId(null)
+    })
+
+  const submitMember = (teamId: string) =>
+    run(async () => {
+      await putTeamMember(teamId, memberUserId.trim(), memberRole)
+      setMemberUserId('')
+    })
+
+  const submitToken = (teamId: string) =>
+    run(async () => {
+      const created = await createTeamToken(teamId, tokenNote.trim() || undefined)
+      setCreatedToken(created.token)
+      setTokenNote('')
+    })
+
+  return (
+    <AdminModuleSurface className="teams-panel">
+      <div className="announcements-list-header">
+        <div>
+          <h3>{strings.title}</h3>
+          <p>{strings.description}</p>
+        </div>
+      </div>
+
+      <AdminLoadingRegion
+        loadState={loading ? 'initial_loading' : error && !loaded ? 'error' : 'ready'}
+        loadingLabel={strings.loading}
+        errorLabel={error ?? strings.error}
+        minHeight={160}
+      >
+        {error && loaded ? <div className="alert alert-error">{error}</div> : null}
+
+        <section className="system-settings-config-section">
+          {teams.length === 0 ? (
+            <div className="empty-state alert">{strings.empty}</div>
+          ) : (
+            <div className="table-wrapper">
+              <table className="jobs-table">
+                <thead>
+                  <tr>
+                    <th>{strings.table.name}</th>
+                    <th>{strings.table.members}</th>
+                    <th>{strings.table.tokens}</th>
+                    <th>{strings.table.limits}</th>
+                    <th>{strings.table.actions}</th>
+                  </tr>
+                </thead>
+                <tbody>
+                  {teams.map((team) => (
+                    <tr key={team.id}>
+                      <td>{team.name}</td>
+                      <td>{team.memberCount}</td>
+                      <td>{team.tokenCount}</td>
+                      <td>
+                        {team.limits.hourlyCreditsLimit} / {team.limits.dailyCreditsLimit} /{' '}
+                        {team.limits.monthlyCreditsLimit}
+                      </td>
+                      <td className="table-actions">
+                        <Button
+                          type="button"
+                          size="sm"
+                          variant={selectedId === team.id ? 'default' : 'outline'}
+                          aria-pressed={selectedId === team.id}
+                          onClick={() => setSelectedId(selectedId === team.id ? null : team.id)}
+                        >
+                          {strings.manage}
+                        </Button>
+                      </td>
+                    </tr>
+                  ))}
+                </tbody>
+              </table>
+            </div>
+          )}
+        </section>
+
+        <form
+          className="system-settings-config-section"
+          onSubmit={(event) => {
+            event.preventDefault()
+            void submitTeam()
+          }}
+        >
+          <h4>{strings.createTitle}</h4>
+          <TeamDraftFields idPrefix="team-create" draft={createDraft} strings={strings} onChange={setCreateDraft} />
+          <div className="table-actions">
+            <Button type="submit" size="sm" disabled={busy}>
+              {strings.create}
+            </Button>
+          </div>
+        </form>
+
+        {detail ? (
+          <>
+            <form
+              className="system-settings-config-section"
+              onSubmit={(event) => {
+                event.preventDefault()
+                void run(() => updateTeam(detail.team.id, requestFromDraft(editDraft)))
+              }}
+            >
+              <h4>
+                {strings.editTitle} · {detail.team.name}
+              </h4>
+              <TeamDraftFields idPrefix="team-edit" draft={editDraft} strings={strings} onChange={setEditDraft} />
+              <div className="table-actions">
+                <Button type="submit" size="sm" disabled={busy}>
+                  {strings.save}
+                </Button>
+                <Button
+                  type="button"
+                  size="sm"
+                  variant="outline"
+                  disabled={busy}
+                  onClick={() => void removeSelectedTeam(detail.team.id)}
+                >
+                  <Icon icon="mdi:trash-can-outline" width={16} height={16} aria-hidden="true" />
+                  <span>{strings.delete}</span>
+                </Button>
+              </div>
+            </form>
+
+            <section className="system-settings-config-section">
+              <h4>{strings.usageTitle}</h4>
+              <TeamUsageSummary language={language} quota={detail.quota} loadSeries={loadSeries} />
+            </section>
+
+            <section className="system-settings-config-section">
+              <h4>{strings.membersTitle}</h4>
+              {detail.members.length === 0 ? (
+                <div className="empty-state alert">{strings.membersEmpty}</div>
+              ) : (
+                <div className="table-wrapper">
+                  <table className="jobs-table">
+                    <tbody>
+                      {detail.members.map((member) => (
+                        <tr key={member.userId}>
+                          <td>
+                            {member.displayName ?? member.username ?? member.userId}
+                            {member.username ? (
+                              <div className="text-xs text-muted-foreground">@{member.username}</div>
+                            ) : null}
+                          </td>
+                          <td className="table-actions">
+                            {TEAM_ROLES.map((role) => (
+                              <Button
+                                key={role}
+                                type="button"
+                                size="sm"
+                                variant={member.role === role ? 'default' : 'outline'}
+                                aria-pressed={member.role === role}
+                                disabled={busy}
+                                onClick={() => void run(() => putTeamMember(detail.team.id, member.userId, role))}
+                              >
+                                {strings.roles[role]}
+                              </Button>
+                            ))}
+                          </td>
+                          <td className="table-actions">
+                            <Button
+                              type="button"
+                              size="sm"
+                              variant="outline"
+                              disabled={busy}
+                              onClick={() => void run(() => removeTeamMember(detail.team.id, member.userId))}
+                            >
+                              {strings.remove}
+                            </Button>
+                          </td>
+                        </tr>
+                      ))}
+                    </tbody>
+                  </table>
+                </div>
+              )}
+              <form
+                className="table-actions"
+                onSubmit={(event) => {
+                  event.preventDefault()
+                  void submitMember(detail.team.id)
+                }}
+              >
+                <Input
+                  aria-label={strings.userId}
+                  placeholder={strings.userId}
+                  value={memberUserId}
+                  onChange={(event) => setMemberUserId(event.target.value)}
+                />
+                {TEAM_ROLES.map((role) => (
+                  <Button
+                    key={role}
+                    type="button"
+                    size="sm"
+                    variant={memberRole === role ? 'default' : 'outline'}
+                    aria-pressed={memberRole === role}
+                    onClick={() => setMemberRole(role)}
+                  >
+                    {strings.roles[role]}
+                  </Button>
+                ))}
+                <Button type="submit" size="sm" disabled={busy || !memberUserId.trim()}>
+                  {strings.addMember}
+                </Button>
+              </form>
+            </section>
+
+            <section className="system-settings-config-section">
+              <h4>{strings.tokensTitle}</h4>
+              {createdToken ? (
+                <div className="alert alert-warning">
+                  <span>{strings.createdTitle}</span>
+                  <code>{createdToken}</code>
+                  <div className="table-actions">
+                    <Button type="button" size="sm" variant="outline" onClick={() => void copyText(createdToken)}>
+                      <Icon icon="mdi:content-copy" width={16} height={16} aria-hidden="true" />
+                      <span>{strings.copyToken}</span>
+                    </Button>
+                    <Button type="button" size="sm" variant="ghost" onClick={() => setCreatedToken(null)}>
+                      {strings.dismiss}
+                    </Button>
+                  </div>
+                </div>
+              ) : null}
+              {detail.tokens.length === 0 ? (
+                <div className="empty-state alert">{strings.tokensEmpty}</div>
+              ) : (
+                <div className="table-wrapper">
+                  <table className="jobs-table">
+                    <thead>
+                      <tr>
+                        <th>{strings.tokenTable.note}</th>
+                        <th>{strings.tokenTable.createdBy}</th>
+                        <th>{strings.tokenTable.lastUsed}</th>
+                        <th>{strings.tokenTable.actions}</th>
+                      </tr>
+                    </thead>
+                    <tbody>
+                      {detail.tokens.map((token) => (
+                        <tr key={token.tokenId}>
+                          <td>
+                            <code>{token.tokenId}</code> {token.note ?? ''}
+                            {token.enabled ? null : (
+                              <div className="text-xs text-muted-foreground">{strings.disabled}</div>
+                            )}
+                          </td>
+                          <td>{token.createdBy ?? '—'}</td>
+                          <td>{token.lastUsedAt ? formatTimestamp(token.lastUsedAt, language) : strings.never}</td>
+                          <td className="table-actions">
+                            <Button
+                              type="button"
+                              size="sm"
+                              variant="outline"
+                              disabled={busy}
+                              onClick={() => void run(() => deleteTeamToken(detail.team.id, token.tokenId))}
+                            >
+                              <Icon icon="mdi:trash-can-outline" width={16} height={16} aria-hidden="true" />
+                            </Button>
+                          </td>
+                        </tr>
+                      ))}
+                    </tbody>
+                  </table>
+                </div>
+              )}
+              <form
+                className="table-actions"
+                onSubmit={(event) => {
+                  event.preventDefault()
+                  void submitToken(detail.team.id)
+                }}
+              >
+                <Input
+                  aria-label={strings.tokenNote}
+                  placeholder={strings.tokenNote}
+                  value={tokenNote}
+                  onChange={(event) => setTokenNote(event.target.value)}
+                />
+                <Button type="submit" size="sm" disabled={busy}>
+                  {strings.createToken}
+                </Button>
+              </form>
+            </section>
+          </>
+        ) : null}
+      </AdminLoadingRegion>
+    </AdminModuleSurface>
+  )
+}
@@ -18,6 +18,7 @@ export const MANUAL_JOB_ACTIONS = [
   'linuxdo_user_tag_binding_refresh',
   'forward_proxy_geo_refresh',
   'db_compaction',
+  'token_expiry_notice',
 ] as const
 
 const QUOTA_JOB_TYPES = new Set(['quota_sync', 'quota_sync/manual', 'quota_sync/hot'])
@@ -27,6 +27,7 @@ export interface RequestLogsListPlanInput extends RequestLogsFilterQueryInput {
   limit: number
   cursor?: string | null
   direction?: 'older' | 'newer'
+  search?: string
   hasEmptyMatch?: boolean
 }
 
@@ -82,6 +83,7 @@ export function buildRequestLogsListPlan(input: RequestLogsListPlanInput): Reque
       limit: input.limit,
       cursor: input.cursor,
       direction: input.direction,
+      search: input.search,
       ...buildRequestLogsFilterQuery(input),
     },
   }
@@ -6390,6 +6390,18 @@ function SystemSettingsPageCanvas({
           rebalanceMcpSessionPercent: 0,
           apiRebalanceEnabled: false,
           apiRebalancePercent: 0,
+          keySelectionMode: 'lru',
+          crossKeyRetry: { enabled: false, endpoints: ['search', 'extract', 'map'], maxAttempts: 3, budgetMs: 8000 },
+          responseCache: {
+            enabled: false,
+            endpoints: ['search', 'extract', 'map'],
+            ttlSecs: 600,
+            endpointTtlSecs: {},
+            maxEntries: 5000,
+            maxBodyBytes: 524288,
+            hitBillingPercent: 0,
+          },
+          requestCoalescing: { enabled: false, endpoints: ['search', 'extract', 'crawl', 'map'], followerBillingPercent: 100 },
           upstreamProjectIdMode: 'accessToken',
           upstreamProjectIdFixedValue: '',
           upstreamMcpUserAgent: '',
@@ -1531,6 +1531,18 @@ describe('admin user tag api helpers', () => {
               rebalanceMcpSessionPercent: 100,
               apiRebalanceEnabled: false,
               apiRebalancePercent: 0,
+              keySelectionMode: 'lru',
+              crossKeyRetry: { enabled: false, endpoints: ['search', 'extract', 'map'], maxAttempts: 3, budgetMs: 8000 },
+              responseCache: {
+                enabled: false,
+                endpoints: ['search', 'extract', 'map'],
+                ttlSecs: 600,
+                endpointTtlSecs: {},
+                maxEntries: 5000,
+                maxBodyBytes: 524288,
+                hitBillingPercent: 0,
+              },
+              requestCoalescing: { enabled: false, endpoints: ['search', 'extract', 'crawl', 'map'], followerBillingPercent: 100 },
               upstreamProjectIdMode: 'accessToken',
               upstreamProjectIdFixedValue: '',
               upstreamMcpUserAgent: '',
@@ -1569,6 +1581,18 @@ describe('admin user tag api helpers', () => {
       rebalanceMcpSessionPercent: 100,
       apiRebalanceEnabled: false,
       apiRebalancePercent: 0,
+      keySelectionMode: 'lru',
+      crossKeyRetry: { enabled: false, endpoints: ['search', 'extract', 'map'], maxAttempts: 3, budgetMs: 8000 },
+      responseCache: {
+        enabled: false,
+        endpoints: ['search', 'extract', 'map'],
+        ttlSecs: 600,
+        endpointTtlSecs: {},
+        maxEntries: 5000,
+        maxBodyBytes: 524288,
+        hitBillingPercent: 0,
+      },
+      requestCoalescing: { enabled: false, endpoints: ['search', 'extract', 'crawl', 'map'], followerBillingPercent: 100 },
       upstreamProjectIdMode: 'accessToken',
       upstreamProjectIdFixedValue: '',
       upstreamMcpUserAgent: '',
@@ -1684,6 +1708,18 @@ describe('admin user tag api helpers', () => {
             rebalanceMcpSessionPercent: 100,
             apiRebalanceEnabled: true,
             apiRebalancePercent: 100,
+            keySelectionMode: 'lru',
+            crossKeyRetry: { enabled: false, endpoints: ['search', 'extract', 'map'], maxAttempts: 3, budgetMs: 8000 },
+            responseCache: {
+              enabled: false,
+              endpoints: ['search', 'extract', 'map'],
+              ttlSecs: 600,
+              endpointTtlSecs: {},
+              maxEntries: 5000,
+              maxBodyBytes: 524288,
+              hitBillingPercent: 0,
+            },
+            requestCoalescing: { enabled: false, endpoints: ['search', 'extract', 'crawl', 'map'], followerBillingPercent: 100 },
             upstreamProjectIdMode: 'accessToken',
             upstreamProjectIdFixedValue: '',
             upstreamMcpUserAgent: '',
@@ -1717,6 +1753,18 @@ describe('admin user tag api helpers', () => {
         rebalanceMcpSessionPercent: 100,
         apiRebalanceEnabled: true,
         apiRebalancePercent: 100,
+        keySelectionMode: 'lru',
+        crossKeyRetry: { enabled: false, endpoints: ['search', 'extract', 'map'], maxAttempts: 3, budgetMs: 8000 },
+        responseCache: {
+          enabled: false,
+          endpoints: ['search', 'extract', 'map'],
+          ttlSecs: 600,
+          endpointTtlSecs: {},
+          maxEntries: 5000,
+          maxBodyBytes: 524288,
+          hitBillingPercent: 0,
+        },
+        requestCoalescing: { enabled: false, endpoints: ['search', 'extract', 'crawl', 'map'], followerBillingPercent: 100 },
         upstreamProjectIdMode: 'accessToken',
         upstreamProjectIdFixedValue: '',
         upstreamMcpUserAgent: '',
@@ -1743,6 +1791,18 @@ describe('admin user tag api helpers', () => {
         rebalanceMcpSessionPercent: 100,
         apiRebalanceEnabled: true,
         apiRebalancePercent: 100,
+        keySelectionMode: 'lru',
+        crossKeyRetry: { enabled: false, endpoints: ['search', 'extract', 'map'], maxAttempts: 3, budgetMs: 8000 },
+        responseCache: {
+          enabled: false,
+          endpoints: ['search', 'extract', 'map'],
+          ttlSecs: 600,
+          endpointTtlSecs: {},
+          maxEntries: 5000,
+          maxBodyBytes: 524288,
+          hitBillingPercent: 0,
+        },
+        requestCoalescing: { enabled: false, endpoints: ['search', 'extract', 'crawl', 'map'], followerBillingPercent: 100 },
         upstreamProjectIdMode: 'accessToken',
         upstreamProjectIdFixedValue: '',
         upstreamMcpUserAgent: '',
@@ -1774,6 +1834,18 @@ describe('admin user tag api helpers', () => {
         rebalanceMcpSessionPercent: 100,
         apiRebalanceEnabled: true,
         apiRebalancePercent: 100,
+        keySelectionMode: 'lru',
+        crossKeyRetry: { enabled: false, endpoints: ['search', 'extract', 'map'], maxAttempts: 3, budgetMs: 8000 },
+        responseCache: {
+          enabled: false,
+          endpoints: ['search', 'extract', 'map'],
+          ttlSecs: 600,
+          endpointTtlSecs: {},
+          maxEntries: 5000,
+          maxBodyBytes: 524288,
+          hitBillingPercent: 0,
+        },
+        requestCoalescing: { enabled: false, endpoints: ['search', 'extract', 'crawl', 'map'], followerBillingPercent: 100 },
         upstreamProjectIdMode: 'accessToken',
         upstreamProjectIdFixedValue: '',
         upstreamMcpUserAgent: '',
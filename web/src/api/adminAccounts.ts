@@ -0,0 +1,64 @@
+import { requestJson, requestNoContent } from './runtime'
+
+export type AdminRole = 'viewer' | 'operator' | 'owner'
+
+export interface AdminAccount {
+  id: string
+  username: string
+  displayName: string | null
+  role: AdminRole
+  createdAt: number
+  updatedAt: number
+  lastLoginAt: number | null
+  disabledAt: number | null
+  activeSessions: number
+}
+
+export interface CreateAdminAccountRequest {
+  username: string
+  displayName?: string
+  role: AdminRole
+  password: string
+}
+
+export interface UpdateAdminAccountRequest {
+  displayName?: string
+  role?: AdminRole
+  disabled?: boolean
+}
+
+export function fetchAdminAccounts(signal?: AbortSignal): Promise<AdminAccount[]> {
+  return requestJson('/api/admin/accounts', { signal })
+}
+
+export function createAdminAccount(request: CreateAdminAccountRequest): Promise<AdminAccount> {
+  return requestJson('/api/admin/accounts', {
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json' },
+    body: JSON.stringify(request),
+  })
+}
+
+export function updateAdminAccount(accountId: string, request: UpdateAdminAccountRequest): Promise<AdminAccount> {
+  return requestJson(`/api/admin/accounts/${encodeURIComponent(accountId)}`, {
+    method: 'PATCH',
+    headers: { 'Content-Type': 'application/json' },
+    body: JSON.stringify(request),
+  })
+}
+
+export function setAdminAccountPassword(accountId: string, password: string): Promise<void> {
+  return requestNoContent(`/api/admin/accounts/${encodeURIComponent(accountId)}/password`, {
+    method: 'PUT',
+    headers: { 'Content-Type': 'application/json' },
+    body: JSON.stringify({ password }),
+  })
+}
+
+export function revokeAdminAccountSessions(accountId: string): Promise<{ revoked: number }> {
+  return requestJson(`/api/admin/accounts/${encodeURIComponent(accountId)}/sessions`, { method: 'DELETE' })
+}
+
+export function deleteAdminAccount(accountId: string): Promise<void> {
+  return requestNoContent(`/api/admin/accounts/${encodeURIComponent(accountId)}`, { method: 'DELETE' })
+}
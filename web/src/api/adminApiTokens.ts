@@ -0,0 +1,69 @@
+import { requestJson } from './runtime'
+
+export type AdminApiTokenStatus = 'active' | 'expired' | 'revoked'
+
+/** Scopes an admin API token can hold; `<resource>:write` includes `<resource>:read`. */
+export const ADMIN_API_TOKEN_SCOPES = [
+  'keys:read',
+  'keys:write',
+  'tokens:read',
+  'tokens:write',
+  'users:read',
+  'users:write',
+  'logs:read',
+  'logs:write',
+  'jobs:read',
+  'jobs:write',
+  'alerts:read',
+  'alerts:write',
+  'announcements:read',
+  'announcements:write',
+  'stats:read',
+  'settings:read',
+  'settings:write',
+] as const
+
+export type AdminApiTokenScope = (typeof ADMIN_API_TOKEN_SCOPES)[number]
+
+export interface AdminApiToken {
+  id: string
+  name: string
+  status: AdminApiTokenStatus
+  scopes: AdminApiTokenScope[]
+  ipAllowlist: string[]
+  createdBy: string | null
+  createdAt: number
+  expiresAt: number | null
+  lastUsedAt: number | null
+  lastUsedIp: string | null
+  revokedAt: number | null
+}
+
+export interface CreateAdminApiTokenRequest {
+  name: string
+  scopes: AdminApiTokenScope[]
+  ipAllowlist?: string[]
+  expiresAt?: number | null
+}
+
+export interface CreatedAdminApiToken {
+  /** Full `tha-…` value; it is only returned once. */
+  token: string
+  apiToken: AdminApiToken
+}
+
+export function fetchAdminApiTokens(signal?: AbortSignal): Promise<AdminApiToken[]> {
+  return requestJson('/api/admin/api-tokens', { signal })
+}
+
+export function createAdminApiToken(request: CreateAdminApiTokenRequest): Promise<CreatedAdminApiToken> {
+  return requestJson('/api/admin/api-tokens', {
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json' },
+    body: JSON.stringify(request),
+  })
+}
+
+export function revokeAdminApiToken(tokenId: string): Promise<AdminApiToken> {
+  return requestJson(`/api/admin/api-tokens/${encodeURIComponent(tokenId)}`, { method: 'DELETE' })
+}
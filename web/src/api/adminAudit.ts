@@ -0,0 +1,66 @@
+import { requestJson } from './runtime'
+
+export type AdminAuditKind = 'mutation' | 'sensitive_read'
+export type AdminAuditActorKind = 'dev_open' | 'forward_auth' | 'builtin' | 'passkey'
+
+export interface AdminAuditEntry {
+  seq: number
+  occurredAt: number
+  kind: AdminAuditKind
+  actorKind: AdminAuditActorKind
+  actorId: string | null
+  actorName: string | null
+  clientIp: string | null
+  method: string
+  route: string
+  path: string
+  status: number
+  before: unknown
+  after: unknown
+  prevHash: string
+  hash: string
+}
+
+export interface PaginatedAdminAuditEntries {
+  items: AdminAuditEntry[]
+  total: number
+  page: number
+  perPage: number
+}
+
+export interface AdminAuditVerification {
+  ok: boolean
+  entries: number
+  headHash: string
+  brokenAtSeq: number | null
+  reason: string | null
+}
+
+export interface AdminAuditQuery {
+  page?: number
+  perPage?: number
+  actor?: string
+  route?: string
+  kind?: AdminAuditKind | null
+  since?: number | null
+  until?: number | null
+}
+
+export function fetchAdminAuditEntries(
+  query: AdminAuditQuery,
+  signal?: AbortSignal,
+): Promise<PaginatedAdminAuditEntries> {
+  const params = new URLSearchParams()
+  params.set('page', String(query.page ?? 1))
+  params.set('per_page', String(query.perPage ?? 50))
+  if (query.actor?.trim()) params.set('actor', query.actor.trim())
+  if (query.route?.trim()) params.set('route', query.route.trim())
+  if (query.kind) params.set('kind', query.kind)
+  if (query.since != null) params.set('since', String(query.since))
+  if (query.until != null) params.set('until', String(query.until))
+  return requestJson(`/api/admin/audit?${params.toString()}`, { signal })
+}
+
+export function verifyAdminAuditChain(signal?: AbortSignal): Promise<AdminAuditVerification> {
+  return requestJson('/api/admin/audit/verify', { signal })
+}
@@ -313,6 +313,7 @@ export function normalizeAdminUserSummary(value: unknown): AdminUserSummary {
     displayName: readNullableString(source, 'displayName', 'display_name'),
     username: readNullableString(source, 'username', 'username'),
     active: readBoolean(source, 'active'),
+    pendingApproval: readBoolean(source, 'pendingApproval', 'pending_approval'),
     lastLoginAt: readNullableNumber(source, 'lastLoginAt', 'last_login_at'),
     tokenCount: readNumber(source, 'tokenCount', 'token_count'),
     apiKeyCount: readNumber(source, 'apiKeyCount', 'api_key_count'),
@@ -0,0 +1,68 @@
+import { requestJson, requestNoContent } from './runtime'
+
+export type AlertRuleMetric =
+  | 'pool_credits_remaining'
+  | 'request_error_rate'
+  | 'forward_proxy_healthy_nodes'
+  | 'ha_outbox_ack_lag'
+export type AlertRuleComparator = 'above' | 'below'
+export type AlertRuleState = 'ok' | 'firing'
+
+export interface AlertRule {
+  id: string
+  name: string
+  metric: AlertRuleMetric
+  comparator: AlertRuleComparator
+  threshold: number
+  clearThreshold: number | null
+  windowSecs: number
+  requestKind: string | null
+  enabled: boolean
+  createdAt: number
+  updatedAt: number
+  state: AlertRuleState
+  lastValue: number | null
+  lastEvaluatedAt: number | null
+  lastFiredAt: number | null
+}
+
+export interface AlertRulesResponse {
+  items: AlertRule[]
+}
+
+export interface AlertRuleMutationPayload {
+  name: string
+  metric: AlertRuleMetric
+  comparator: AlertRuleComparator
+  threshold: number
+  clearThreshold?: number | null
+  windowSecs?: number | null
+  requestKind?: string | null
+  enabled: boolean
+}
+
+export function fetchAlertRules(signal?: AbortSignal): Promise<AlertRulesResponse> {
+  return requestJson('/api/alerts/rules', { signal })
+}
+
+export function createAlertRule(payload: AlertRuleMutationPayload): Promise<AlertRule> {
+  return requestJson('/api/alerts/rules', {
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json' },
+    body: JSON.stringify(payload),
+  })
+}
+
+export function updateAlertRule(id: string, payload: AlertRuleMutationPayload): Promise<AlertRule> {
+  const encoded = encodeURIComponent(id)
+  return requestJson(`/api/alerts/rules/${encoded}`, {
+    method: 'PATCH',
+    headers: { 'Content-Type': 'application/json' },
+    body: JSON.stringify(payload),
+  })
+}
+
+export async function deleteAlertRule(id: string): Promise<void> {
+  const encoded = encodeURIComponent(id)
+  await requestNoContent(`/api/alerts/rules/${encoded}`, { method: 'DELETE' })
+}
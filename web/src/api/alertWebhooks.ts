@@ -0,0 +1,95 @@
+import { requestJson, requestNoContent, type AlertType } from './runtime'
+
+export type AlertWebhookTemplate = 'generic' | 'slack' | 'telegram'
+export type AlertWebhookDeliveryStatus = 'pending' | 'delivered' | 'failed'
+
+export interface AlertWebhookSink {
+  id: string
+  name: string
+  url: string
+  template: AlertWebhookTemplate
+  alertTypes: AlertType[]
+  telegramChatId: string | null
+  secret: string
+  repeatIntervalSecs: number
+  enabled: boolean
+  createdAt: number
+  updatedAt: number
+}
+
+export interface AlertWebhookSinksResponse {
+  items: AlertWebhookSink[]
+}
+
+export interface AlertWebhookSinkMutationPayload {
+  name: string
+  url: string
+  template: AlertWebhookTemplate
+  alertTypes: AlertType[]
+  telegramChatId?: string | null
+  secret?: string | null
+  repeatIntervalSecs?: number | null
+  enabled: boolean
+}
+
+export interface AlertWebhookDelivery {
+  id: number
+  sinkId: string
+  eventId: string
+  alertType: string
+  eventOccurredAt: number
+  payload: unknown
+  status: AlertWebhookDeliveryStatus
+  attempts: number
+  nextAttemptAt: number | null
+  lastStatusCode: number | null
+  lastError: string | null
+  createdAt: number
+  updatedAt: number
+  deliveredAt: number | null
+}
+
+export interface AlertWebhookDeliveriesResponse {
+  items: AlertWebhookDelivery[]
+}
+
+export function fetchAlertWebhooks(signal?: AbortSignal): Promise<AlertWebhookSinksResponse> {
+  return requestJson('/api/alerts/webhooks', { signal })
+}
+
+export function createAlertWebhook(payload: AlertWebhookSinkMutationPayload): Promise<AlertWebhookSink> {
+  return requestJson('/api/alerts/webhooks', {
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json' },
+    body: JSON.stringify(payload),
+  })
+}
+
+export function updateAlertWebhook(id: string, payload: AlertWebhookSinkMutationPayload): Promise<AlertWebhookSink> {
+  const encoded = encodeURIComponent(id)
+  return requestJson(`/api/alerts/webhooks/${encoded}`, {
+    method: 'PATCH',
+    headers: { 'Content-Type': 'application/json' },
+    body: JSON.stringify(payload),
+  })
+}
+
+export async function deleteAlertWebhook(id: string): Promise<void> {
+  const encoded = encodeURIComponent(id)
+  await requestNoContent(`/api/alerts/webhooks/${encoded}`, { method: 'DELETE' })
+}
+
+export function testAlertWebhook(id: string): Promise<AlertWebhookDelivery> {
+  const encoded = encodeURIComponent(id)
+  return requestJson(`/api/alerts/webhooks/${encoded}/test`, { method: 'POST' })
+}
+
+export function fetchAlertWebhookDeliveries(
+  sinkId?: string | null,
+  signal?: AbortSignal,
+): Promise<AlertWebhookDeliveriesResponse> {
+  const params = new URLSearchParams()
+  if (sinkId) params.set('sinkId', sinkId)
+  const query = params.toString()
+  return requestJson(`/api/alerts/webhooks/deliveries${query ? `?${query}` : ''}`, { signal })
+}
@@ -0,0 +1,76 @@
+import { requestJson, requestNoContent, type AlertGroupWorkflow, type AlertType } from './runtime'
+
+export type AlertGroupAction = 'acknowledge' | 'resolve' | 'reopen'
+export type AlertSilenceSubjectKind = 'key' | 'token' | 'user' | 'job' | 'rule'
+
+export interface AlertGroupWorkflowEvent {
+  id: string
+  groupId: string
+  action: AlertGroupAction
+  actor: string
+  note: string | null
+  createdAt: number
+}
+
+export interface AlertSilence {
+  id: string
+  type: AlertType | null
+  subjectKind: AlertSilenceSubjectKind | null
+  subjectId: string | null
+  startsAt: number
+  endsAt: number
+  actor: string
+  note: string | null
+  createdAt: number
+  active: boolean
+}
+
+export interface AlertSilenceCreatePayload {
+  type?: AlertType | null
+  subjectKind?: AlertSilenceSubjectKind | null
+  subjectId?: string | null
+  startsAt?: number | null
+  endsAt: number
+  note?: string | null
+}
+
+export function applyAlertGroupAction(
+  groupId: string,
+  action: AlertGroupAction,
+  note?: string | null,
+): Promise<AlertGroupWorkflow> {
+  return requestJson('/api/alerts/groups/actions', {
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json' },
+    body: JSON.stringify({ groupId, action, note: note ?? null }),
+  })
+}
+
+export function fetchAlertGroupHistory(
+  groupId: string,
+  signal?: AbortSignal,
+): Promise<{ items: AlertGroupWorkflowEvent[] }> {
+  const params = new URLSearchParams({ groupId })
+  return requestJson(`/api/alerts/groups/history?${params.toString()}`, { signal })
+}
+
+export function fetchAlertSilences(
+  includeExpired = false,
+  signal?: AbortSignal,
+): Promise<{ items: AlertSilence[] }> {
+  const query = includeExpired ? '?includeExpired=true' : ''
+  return requestJson(`/api/alerts/silences${query}`, { signal })
+}
+
+export function createAlertSilence(payload: AlertSilenceCreatePayload): Promise<AlertSilence> {
+  return requestJson('/api/alerts/silences', {
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json' },
+    body: JSON.stringify(payload),
+  })
+}
+
+export async function expireAlertSilence(id: string): Promise<void> {
+  const encoded = encodeURIComponent(id)
+  await requestNoContent(`/api/alerts/silences/${encoded}`, { method: 'DELETE' })
+}
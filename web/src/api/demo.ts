@@ -217,6 +217,7 @@ function createDemoState() {
       passkeyAuthEnabled: true,
       adminLoginTotpRequired: true,
       allowRegistration: false,
+      adminRole: 'owner',
       userLoggedIn: true,
       userProvider: 'linuxdo',
       userDisplayName: 'Hikari Demo Admin',
@@ -244,7 +245,7 @@ function createDemoState() {
       dailyDelta: 200,
       monthlyDelta: 2000,
     }, 3)],
-    registration: { allowRegistration: false },
+    registration: { allowRegistration: false, requireApproval: false },
   }
 }
 
@@ -996,6 +997,18 @@ function createDemoSystemSettings() {
     rebalanceMcpSessionPercent: 100,
     apiRebalanceEnabled: true,
     apiRebalancePercent: 100,
+    keySelectionMode: 'lru',
+    crossKeyRetry: { enabled: false, endpoints: ['search', 'extract', 'map'], maxAttempts: 3, budgetMs: 8000 },
+    responseCache: {
+      enabled: false,
+      endpoints: ['search', 'extract', 'map'],
+      ttlSecs: 600,
+      endpointTtlSecs: {},
+      maxEntries: 5000,
+      maxBodyBytes: 524288,
+      hitBillingPercent: 0,
+    },
+    requestCoalescing: { enabled: false, endpoints: ['search', 'extract', 'crawl', 'map'], followerBillingPercent: 100 },
     upstreamProjectIdMode: 'accessToken',
     upstreamProjectIdFixedValue: '',
     upstreamMcpUserAgent: '',
@@ -2001,9 +2014,15 @@ async function handleDemoRoute(url: URL, method: string, init?: RequestInit): Pr
     if (method === 'PATCH') {
       const body = await readJsonBody(init)
       demoState.registration.allowRegistration = Boolean(body.allowRegistration)
+      if (typeof body.requireApproval === 'boolean') demoState.registration.requireApproval = body.requireApproval
     }
     return jsonResponse(demoState.registration)
   }
+  if (path === '/api/admin/registration/invites' && method === 'GET') return jsonResponse([])
+  if (path === '/api/admin/registration/approvals') return jsonResponse([])
+  if (path === '/api/admin/accounts' && method === 'GET') return jsonResponse([])
+  if (path === '/api/teams' && method === 'GET') return jsonResponse([])
+  if (path === '/api/user/team') return jsonResponse({ team: null, role: null, quota: null, members: [] })
   if (path === '/api/user/logout') return noContentResponse()
   if (path === '/api/user/token') return jsonResponse({ token: DEMO_TOKEN })
   if (path === '/api/user/dashboard') return jsonResponse(demoUserDashboardSummary())
@@ -2195,7 +2214,7 @@ function handleKeyRoute(path: string, url: URL, method: string): Response {
   const id = decodeURIComponent(match?.[1] ?? DEMO_KEY_ID)
   const key = demoState.keys.find((item) => item.id === id) ?? demoState.keys[0]
   if (method === 'DELETE') return noContentResponse()
-  if (path.endsWith('/sync-usage') || path.endsWith('/status') || path.endsWith('/quarantine')) return noContentResponse()
+  if (path.endsWith('/sync-usage') || path.endsWith('/status') || path.endsWith('/quarantine') || path.endsWith('/rate-budget')) return noContentResponse()
   if (path.endsWith('/secret')) return jsonResponse({ api_key: `tvly-dev-${id.replace(/[^a-z0-9]/gi, '').slice(0, 18)}demo` })
   if (path.includes('/metrics')) return jsonResponse({
     total_requests: key.total_requests,
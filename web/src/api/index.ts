@@ -6,6 +6,17 @@ export type * from './systemSettingsTypes'
 export * from './tokens'
 export * from './clientIp'
 export * from './announcements'
+export * from './alertRules'
+export * from './alertWebhooks'
+export * from './alertWorkflow'
+export * from './adminAudit'
+export * from './requestLogReplay'
+export * from './registrationInvites'
+export * from './teams'
+export * from './adminAccounts'
+export * from './adminApiTokens'
+export * from './keyGroupRouting'
+export type * from './keyRateBudgets'
 export * from './billing'
 export * from './recharge'
 export * from './adminRecharge'
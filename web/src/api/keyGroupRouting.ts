@@ -0,0 +1,44 @@
+import { requestJson } from './runtime'
+
+export type ApiKeyGroupBindingKind = 'token_group' | 'user_tag'
+
+export interface ApiKeyGroupTier {
+  group: string
+  priority: number
+}
+
+export interface ApiKeyGroupBinding {
+  kind: ApiKeyGroupBindingKind
+  value: string
+  keyGroups: string[]
+}
+
+export interface ApiKeyGroupRouting {
+  tiers: ApiKeyGroupTier[]
+  bindings: ApiKeyGroupBinding[]
+}
+
+/** Key pool capacity for one selection tier; the empty group name stands for ungrouped keys. */
+export interface ApiKeyTierCapacity {
+  priority: number
+  groups: string[]
+  totalKeys: number
+  availableKeys: number
+  coolingKeys: number
+  exhaustedKeys: number
+  quarantinedKeys: number
+  quotaLimit: number
+  quotaRemaining: number
+}
+
+export function fetchApiKeyGroupRouting(signal?: AbortSignal): Promise<ApiKeyGroupRouting> {
+  return requestJson('/api/keys/group-routing', { signal })
+}
+
+export function updateApiKeyGroupRouting(payload: ApiKeyGroupRouting): Promise<ApiKeyGroupRouting> {
+  return requestJson('/api/keys/group-routing', {
+    method: 'PUT',
+    headers: { 'Content-Type': 'application/json' },
+    body: JSON.stringify(payload),
+  })
+}
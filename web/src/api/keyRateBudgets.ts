@@ -0,0 +1,9 @@
+/** Local requests-per-minute budget of one key and how much of it the last minute consumed. */
+export interface ApiKeyRateBudgetUsage {
+  keyId: string
+  rpmLimit: number
+  usedLastMinute: number
+  queued: number
+  delayedTotal: number
+  rejectedTotal: number
+}
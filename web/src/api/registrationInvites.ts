@@ -0,0 +1,75 @@
+import { requestJson, requestNoContent, type AdminRegistrationSettings } from './runtime'
+
+export type RegistrationInviteStatus = 'active' | 'revoked' | 'expired' | 'exhausted'
+
+export interface RegistrationInviteQuota {
+  businessCalls1hLimit: number
+  dailyCreditsLimit: number
+  monthlyCreditsLimit: number
+}
+
+export interface RegistrationInvite {
+  id: string
+  code: string
+  note: string | null
+  status: RegistrationInviteStatus
+  maxUses: number
+  useCount: number
+  expiresAt: number | null
+  tagIds: string[]
+  quota: RegistrationInviteQuota | null
+  createdAt: number
+  revokedAt: number | null
+}
+
+export interface CreateRegistrationInviteRequest {
+  code?: string
+  note?: string
+  maxUses: number
+  expiresAt?: number | null
+  tagIds?: string[]
+  quota?: RegistrationInviteQuota | null
+}
+
+export interface PendingApprovalUser {
+  userId: string
+  displayName: string | null
+  username: string | null
+  provider: string | null
+  requestedAt: number
+}
+
+export function fetchRegistrationInvites(signal?: AbortSignal): Promise<RegistrationInvite[]> {
+  return requestJson('/api/admin/registration/invites', { signal })
+}
+
+export function createRegistrationInvite(request: CreateRegistrationInviteRequest): Promise<RegistrationInvite> {
+  return requestJson('/api/admin/registration/invites', {
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json' },
+    body: JSON.stringify(request),
+  })
+}
+
+export function revokeRegistrationInvite(inviteId: string): Promise<RegistrationInvite> {
+  return requestJson(`/api/admin/registration/invites/${encodeURIComponent(inviteId)}`, { method: 'DELETE' })
+}
+
+export function fetchPendingApprovalUsers(signal?: AbortSignal): Promise<PendingApprovalUser[]> {
+  return requestJson('/api/admin/registration/approvals', { signal })
+}
+
+export function approveUserRegistration(userId: string): Promise<void> {
+  return requestNoContent(`/api/users/${encodeURIComponent(userId)}/approve`, { method: 'POST' })
+}
+
+export function updateRegistrationApproval(
+  allowRegistration: boolean,
+  requireApproval: boolean,
+): Promise<AdminRegistrationSettings> {
+  return requestJson('/api/admin/registration', {
+    method: 'PATCH',
+    headers: { 'Content-Type': 'application/json' },
+    body: JSON.stringify({ allowRegistration, requireApproval }),
+  })
+}
@@ -0,0 +1,43 @@
+import { requestJson } from './runtime'
+
+export interface RequestLogReplay {
+  id: number
+  sourceLogId: number
+  keyId: string
+  upstream: string
+  method: string
+  path: string
+  statusCode: number | null
+  durationMs: number
+  requestBody: string | null
+  responseBody: string | null
+  errorMessage: string | null
+  createdAt: number
+}
+
+export interface RequestLogReplayUpstreams {
+  primary: string
+  alternates: string[]
+}
+
+export interface RequestLogReplayRequest {
+  keyId?: string
+  upstream?: string
+  body?: unknown
+}
+
+export function fetchRequestLogReplayUpstreams(signal?: AbortSignal): Promise<RequestLogReplayUpstreams> {
+  return requestJson('/api/logs/replay/upstreams', { signal })
+}
+
+export function replayRequestLog(logId: number, request: RequestLogReplayRequest): Promise<RequestLogReplay> {
+  return requestJson(`/api/logs/${encodeURIComponent(String(logId))}/replay`, {
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json' },
+    body: JSON.stringify(request),
+  })
+}
+
+export function fetchRequestLogReplays(logId: number, signal?: AbortSignal): Promise<RequestLogReplay[]> {
+  return requestJson(`/api/logs/${encodeURIComponent(String(logId))}/replays`, { signal })
+}
@@ -33,6 +33,8 @@ import {
   normalizeAdminUserTag,
   normalizeAdminUserTagList,
 } from './adminUserNormalization'
+import type { ApiKeyTierCapacity } from './keyGroupRouting'
+import type { ApiKeyRateBudgetUsage } from './keyRateBudgets'
 
 export type { HaChannelHealth, HaGcState } from './haTypes'
 export type { HaStatus } from './haStatus'
@@ -49,6 +51,17 @@ export interface Summary {
   last_activity: number | null
   total_quota_limit: number
   total_quota_remaining: number
+  key_tiers?: ApiKeyTierCapacity[]
+  key_rate_budgets?: ApiKeyRateBudgetUsage[]
+  response_cache?: ResponseCacheStats
+}
+
+export interface ResponseCacheStats {
+  enabled: boolean
+  entries: number
+  storedBytes: number
+  hitsToday: number
+  creditsSavedToday: number
 }
 
 export interface SummaryQuotaCharge {
@@ -186,6 +199,8 @@ export type AlertType =
   | 'user_quota_exhausted'
   | 'api_key_exhausted'
   | 'job_failed'
+  | 'token_expiring'
+  | 'threshold_rule'
 
 export interface AlertFacetOption {
   value: string
@@ -294,6 +309,17 @@ export interface AlertGroup {
   eventCount?: number
   children?: AlertGroup[]
   childEvents?: AlertEvent[]
+  workflow?: AlertGroupWorkflow | null
+}
+
+export type AlertGroupStatus = 'open' | 'acknowledged' | 'resolved'
+
+export interface AlertGroupWorkflow {
+  status: AlertGroupStatus
+  actor: string | null
+  note: string | null
+  updatedAt: number | null
+  reopened: boolean
 }
 
 export interface AlertTypeCount {
@@ -468,6 +494,7 @@ export interface RequestLog {
   routing_subject_hash?: string | null
   upstream_operation?: string | null
   fallback_reason?: string | null
+  trace_id?: string | null
   request_body: string | null
   response_body: string | null
   request_body_bytes?: number | null
@@ -667,6 +694,7 @@ interface ServerAlertGroup {
   children?: ServerAlertGroup[]
   childEvents?: ServerAlertEvent[]
   child_events?: ServerAlertEvent[]
+  workflow?: AlertGroupWorkflow | null
 }
 
 interface ServerAlertsPage<T> {
@@ -798,9 +826,10 @@ export interface RequestLogsListQuery
   limit?: number
   cursor?: string | null
   direction?: 'older' | 'newer'
+  search?: string
 }
 
-export type RequestLogsCatalogQuery = Omit<RequestLogsListQuery, 'limit' | 'cursor' | 'direction'>
+export type RequestLogsCatalogQuery = Omit<RequestLogsListQuery, 'limit' | 'cursor' | 'direction' | 'search'>
 
 function normalizeRequestLogFacets(value?: ServerRequestLogFacets): RequestLogFacets {
   return {
@@ -973,6 +1002,7 @@ function normalizeAlertGroup(value: ServerAlertGroup): AlertGroup {
     eventCount: value.eventCount ?? value.event_count ?? value.count ?? 0,
     children: (value.children ?? []).map(normalizeAlertGroup),
     childEvents: (value.childEvents ?? value.child_events ?? []).map(normalizeAlertEvent),
+    workflow: value.workflow ?? null,
   }
 }
 
@@ -1104,6 +1134,7 @@ function appendRequestLogsListParams(params: URLSearchParams, query: RequestLogs
   params.set('limit', String(query.limit ?? 20))
   if (query.cursor?.trim()) params.set('cursor', query.cursor.trim())
   if (query.direction) params.set('direction', query.direction)
+  if (query.search?.trim()) params.set('q', query.search.trim())
   appendRequestLogsPageFilters(params, query)
 }
 
@@ -1214,7 +1245,7 @@ export async function requestJson<T>(input: RequestInfo, init?: RequestInit): Pr
   return (await response.json()) as T
 }
 
-async function requestNoContent(input: RequestInfo, init?: RequestInit): Promise<void> {
+export async function requestNoContent(input: RequestInfo, init?: RequestInit): Promise<void> {
   const response = await fetchOrThrow(input, init)
   if (!response.ok) {
     const message = await response.text().catch(() => response.statusText)
@@ -1650,6 +1681,7 @@ export async function fetchPublicLogs(token: string, limit = 20, signal?: AbortS
 
 export interface PaginatedApiKeys extends Paginated<ApiKeyStats> {
   facets: ApiKeyListFacets
+  tiers?: ApiKeyTierCapacity[]
 }
 
 export type ApiKeyBulkAction = 'delete' | 'clear_quarantine' | 'sync_usage'
@@ -1788,6 +1820,15 @@ export async function syncApiKeyUsage(id: string): Promise<void> {
   }
 }
 
+/** Set a key's local requests-per-minute budget, or clear it with `null`. */
+export function updateApiKeyRateBudget(id: string, rpmLimit: number | null): Promise<void> {
+  return requestNoContent(`/api/keys/${encodeURIComponent(id)}/rate-budget`, {
+    method: 'PUT',
+    headers: { 'Content-Type': 'application/json' },
+    body: JSON.stringify({ rpmLimit }),
+  })
+}
+
 export function triggerJob(jobType: string, keyId?: string | null): Promise<TriggerJobResponse> {
   return requestJson<ServerTriggerJobResponse>('/api/jobs/trigger', {
     method: 'POST',
@@ -1976,12 +2017,24 @@ export interface Profile {
   forwardAuthEnabled: boolean
   builtinAuthEnabled: boolean
   passkeyAuthEnabled?: boolean
+  adminAccountsEnabled?: boolean
   adminLoginTotpRequired?: boolean
   allowRegistration: boolean
+  adminRole?: 'viewer' | 'operator' | 'owner'
   userLoggedIn?: boolean
-  userProvider?: 'linuxdo' | null
+  userProvider?: UserLoginProviderId | null
   userDisplayName?: string | null
   userAvatarUrl?: string | null
+  userPendingApproval?: boolean
+  userLoginProviders?: UserLoginProvider[]
+}
+
+export type UserLoginProviderId = 'linuxdo' | 'oidc'
+
+export interface UserLoginProvider {
+  id: UserLoginProviderId
+  label: string
+  startPath: string
 }
 
 export function fetchProfile(signal?: AbortSignal): Promise<Profile> {
@@ -2231,6 +2284,7 @@ export function setAdminLoginTotpRequired(loginTotpRequired: boolean): Promise<A
 
 export interface AdminRegistrationSettings {
   allowRegistration: boolean
+  requireApproval?: boolean
 }
 
 export function fetchAdminRegistrationSettings(
@@ -2307,6 +2361,7 @@ export interface AdminUserSummary {
   displayName: string | null
   username: string | null
   active: boolean
+  pendingApproval?: boolean
   lastLoginAt: number | null
   tokenCount: number
   apiKeyCount: number
@@ -3135,6 +3190,7 @@ export interface KeySummary {
   active_keys: number
   exhausted_keys: number
   last_activity: number | null
+  key_rate_budgets?: ApiKeyRateBudgetUsage[]
 }
 
 export interface StickyUserIdentity {
@@ -3884,6 +3940,18 @@ function createEmptySystemSettings(): SystemSettings {
     rebalanceMcpSessionPercent: 0,
     apiRebalanceEnabled: false,
     apiRebalancePercent: 0,
+    keySelectionMode: 'lru',
+    crossKeyRetry: { enabled: false, endpoints: ['search', 'extract', 'map'], maxAttempts: 3, budgetMs: 8000 },
+    responseCache: {
+      enabled: false,
+      endpoints: ['search', 'extract', 'map'],
+      ttlSecs: 600,
+      endpointTtlSecs: {},
+      maxEntries: 5000,
+      maxBodyBytes: 524288,
+      hitBillingPercent: 0,
+    },
+    requestCoalescing: { enabled: false, endpoints: ['search', 'extract', 'crawl', 'map'], followerBillingPercent: 100 },
     upstreamProjectIdMode: 'accessToken',
     upstreamProjectIdFixedValue: '',
     upstreamMcpUserAgent: '',
@@ -2,6 +2,37 @@ import type { RequestLogRetentionSettings } from './requestLogRetention'
 
 export type UpstreamProjectIdMode = 'passthrough' | 'fixed' | 'accessToken'
 
+export type KeySelectionMode = 'lru' | 'quotaWeighted'
+
+export type CrossKeyRetryEndpoint = 'search' | 'extract' | 'crawl' | 'map'
+
+export interface CrossKeyRetrySettings {
+  enabled: boolean
+  endpoints: CrossKeyRetryEndpoint[]
+  maxAttempts: number
+  budgetMs: number
+}
+
+export type ResponseCacheEndpoint = 'search' | 'extract' | 'crawl' | 'map'
+
+export interface ResponseCacheSettings {
+  enabled: boolean
+  endpoints: ResponseCacheEndpoint[]
+  ttlSecs: number
+  endpointTtlSecs: Partial<Record<ResponseCacheEndpoint, number>>
+  maxEntries: number
+  maxBodyBytes: number
+  hitBillingPercent: number
+}
+
+export type RequestCoalescingEndpoint = 'search' | 'extract' | 'crawl' | 'map'
+
+export interface RequestCoalescingSettings {
+  enabled: boolean
+  endpoints: RequestCoalescingEndpoint[]
+  followerBillingPercent: number
+}
+
 export interface SystemSettings {
   requestRateLimit: number
   authTokenLogRetentionDays: number
@@ -10,6 +41,10 @@ export interface SystemSettings {
   rebalanceMcpSessionPercent: number
   apiRebalanceEnabled: boolean
   apiRebalancePercent: number
+  keySelectionMode: KeySelectionMode
+  crossKeyRetry: CrossKeyRetrySettings
+  responseCache: ResponseCacheSettings
+  requestCoalescing: RequestCoalescingSettings
   upstreamProjectIdMode: UpstreamProjectIdMode
   upstreamProjectIdFixedValue: string
   upstreamMcpUserAgent: string
@@ -241,6 +276,7 @@ export interface UpdateSystemSettingsPayload {
   rebalanceMcpSessionPercent: number
   apiRebalanceEnabled: boolean
   apiRebalancePercent: number
+  keySelectionMode: KeySelectionMode
   upstreamProjectIdMode: UpstreamProjectIdMode
   upstreamProjectIdFixedValue: string
   upstreamMcpUserAgent: string
@@ -0,0 +1,167 @@
+import { requestJson, requestNoContent, type AdminUserUsageSeriesQuotaPoint } from './runtime'
+
+export type TeamRole = 'owner' | 'admin' | 'member'
+
+export type TeamUsageSeriesKey = 'dailyCredits' | 'monthlyCredits'
+
+export interface TeamQuotaLimits {
+  hourlyCreditsLimit: number
+  dailyCreditsLimit: number
+  monthlyCreditsLimit: number
+}
+
+export interface Team {
+  id: string
+  name: string
+  limits: TeamQuotaLimits
+  enforceMemberLimits: boolean
+  memberCount: number
+  tokenCount: number
+  createdAt: number
+  updatedAt: number
+}
+
+export interface TeamQuota {
+  hourlyUsed: number
+  hourlyLimit: number
+  dailyUsed: number
+  dailyLimit: number
+  monthlyUsed: number
+  monthlyLimit: number
+  window: 'hour' | 'day' | 'month' | null
+}
+
+export interface TeamMember {
+  userId: string
+  displayName: string | null
+  username: string | null
+  role: TeamRole
+  joinedAt: number
+}
+
+export interface TeamToken {
+  tokenId: string
+  note: string | null
+  enabled: boolean
+  createdBy: string | null
+  createdAt: number
+  lastUsedAt: number | null
+}
+
+export interface TeamDetail {
+  team: Team
+  quota: TeamQuota
+  members: TeamMember[]
+  tokens: TeamToken[]
+}
+
+export interface UserTeam {
+  team: Team | null
+  role: TeamRole | null
+  quota: TeamQuota | null
+  members: TeamMember[]
+}
+
+export interface TeamUsageSeries {
+  limit: number
+  points: AdminUserUsageSeriesQuotaPoint[]
+}
+
+export interface CreatedTeamToken {
+  token: string
+  tokenId: string
+}
+
+export interface TeamRequest {
+  name: string
+  limits: TeamQuotaLimits
+  enforceMemberLimits: boolean
+}
+
+const JSON_HEADERS = { 'Content-Type': 'application/json' }
+
+export function fetchTeams(signal?: AbortSignal): Promise<Team[]> {
+  return requestJson('/api/teams', { signal })
+}
+
+export function fetchTeamDetail(teamId: string, signal?: AbortSignal): Promise<TeamDetail> {
+  return requestJson(`/api/teams/${encodeURIComponent(teamId)}`, { signal })
+}
+
+export function createTeam(request: TeamRequest): Promise<Team> {
+  return requestJson('/api/teams', { method: 'POST', headers: JSON_HEADERS, body: JSON.stringify(request) })
+}
+
+export function updateTeam(teamId: string, request: TeamRequest): Promise<Team> {
+  return requestJson(`/api/teams/${encodeURIComponent(teamId)}`, {
+    method: 'PATCH',
+    headers: JSON_HEADERS,
+    body: JSON.stringify(request),
+  })
+}
+
+export function deleteTeam(teamId: string): Promise<void> {
+  return requestNoContent(`/api/teams/${encodeURIComponent(teamId)}`, { method: 'DELETE' })
+}
+
+export function putTeamMember(teamId: string, userId: string, role: TeamRole): Promise<void> {
+  return requestNoContent(`/api/teams/${encodeURIComponent(teamId)}/members/${encodeURIComponent(userId)}`, {
+    method: 'PUT',
+    headers: JSON_HEADERS,
+    body: JSON.stringify({ role }),
+  })
+}
+
+export function removeTeamMember(teamId: string, userId: string): Promise<void> {
+  return requestNoContent(`/api/teams/${encodeURIComponent(teamId)}/members/${encodeURIComponent(userId)}`, {
+    method: 'DELETE',
+  })
+}
+
+export function createTeamToken(teamId: string, note?: string): Promise<CreatedTeamToken> {
+  return requestJson(`/api/teams/${encodeURIComponent(teamId)}/tokens`, {
+    method: 'POST',
+    headers: JSON_HEADERS,
+    body: JSON.stringify({ note }),
+  })
+}
+
+export function deleteTeamToken(teamId: string, tokenId: string): Promise<void> {
+  return requestNoContent(`/api/teams/${encodeURIComponent(teamId)}/tokens/${encodeURIComponent(tokenId)}`, {
+    method: 'DELETE',
+  })
+}
+
+export function fetchTeamUsageSeries(
+  teamId: string,
+  series: TeamUsageSeriesKey,
+  signal?: AbortSignal,
+): Promise<TeamUsageSeries> {
+  const params = new URLSearchParams({ series })
+  return requestJson(`/api/teams/${encodeURIComponent(teamId)}/usage-series?${params.toString()}`, { signal })
+}
+
+export function fetchUserTeam(signal?: AbortSignal): Promise<UserTeam> {
+  return requestJson('/api/user/team', { signal })
+}
+
+export function fetchUserTeamUsageSeries(series: TeamUsageSeriesKey, signal?: AbortSignal): Promise<TeamUsageSeries> {
+  const params = new URLSearchParams({ series })
+  return requestJson(`/api/user/team/usage-series?${params.toString()}`, { signal })
+}
+
+export function fetchUserTeamTokens(signal?: AbortSignal): Promise<TeamToken[]> {
+  return requestJson('/api/user/team/tokens', { signal })
+}
+
+export function createUserTeamToken(note?: string): Promise<CreatedTeamToken> {
+  return requestJson('/api/user/team/tokens', {
+    method: 'POST',
+    headers: JSON_HEADERS,
+    body: JSON.stringify({ note }),
+  })
+}
+
+export function deleteUserTeamToken(tokenId: string): Promise<void> {
+  return requestNoContent(`/api/user/team/tokens/${encodeURIComponent(tokenId)}`, { method: 'DELETE' })
+}
@@ -19,6 +19,28 @@ export interface AuthToken {
   quota_hourly_reset_at: number | null
   quota_daily_reset_at: number | null
   quota_monthly_reset_at: number | null
+  not_before?: number | null
+  expires_at?: number | null
+  scopes?: TokenScope[] | null
+}
+
+/** Canonical request kinds a token scope can grant; `null` scopes mean unrestricted. */
+export type TokenScope =
+  | 'api:search'
+  | 'api:extract'
+  | 'api:crawl'
+  | 'api:map'
+  | 'api:research'
+  | 'mcp:search'
+  | 'mcp:extract'
+  | 'mcp:crawl'
+  | 'mcp:map'
+  | 'mcp:research'
+
+/** Optional validity window of an access token, in unix seconds. */
+export interface TokenLifetime {
+  not_before?: number | null
+  expires_at?: number | null
 }
 
 export type AdminTokenOwnerFilter = 'all' | 'bound' | 'unbound'
@@ -42,6 +64,7 @@ export interface TokenGroup {
   name: string
   tokenCount: number
   latestCreatedAt: number
+  defaultScopes?: TokenScope[] | null
 }
 
 interface BatchTokenMutationResponse {
@@ -76,11 +99,11 @@ export function fetchTokens(
   return requestJson(`/api/tokens?${params.toString()}`, { signal })
 }
 
-export function createToken(note?: string): Promise<AuthTokenSecret> {
+export function createToken(note?: string, lifetime?: TokenLifetime): Promise<AuthTokenSecret> {
   return requestJson('/api/tokens', {
     method: 'POST',
     headers: { 'Content-Type': 'application/json' },
-    body: JSON.stringify({ note }),
+    body: JSON.stringify({ note, ...lifetime }),
   })
 }
 
@@ -110,12 +133,93 @@ export function fetchTokenSecret(id: string, signal?: AbortSignal): Promise<Auth
   return requestJson(`/api/tokens/${encodeURIComponent(id)}/secret`, { signal })
 }
 
-export function createTokensBatch(group: string, count: number, note?: string): Promise<{ tokens: string[] }> {
+export function createTokensBatch(
+  group: string,
+  count: number,
+  note?: string,
+  lifetime?: TokenLifetime,
+  scopes?: TokenScope[] | null,
+): Promise<{ tokens: string[] }> {
   return requestJson('/api/tokens/batch', {
     method: 'POST',
     headers: { 'Content-Type': 'application/json' },
-    body: JSON.stringify({ group, count, note }),
+    body: JSON.stringify({ group, count, note, ...lifetime, scopes: scopes ?? null }),
+  })
+}
+
+export async function setTokenLifetime(id: string, lifetime: TokenLifetime): Promise<void> {
+  const response = await fetch(`/api/tokens/${encodeURIComponent(id)}/lifetime`, {
+    method: 'PATCH',
+    headers: { 'Content-Type': 'application/json' },
+    body: JSON.stringify({ not_before: lifetime.not_before ?? null, expires_at: lifetime.expires_at ?? null }),
+  })
+  if (!response.ok) throw new Error(`Failed to update token lifetime: ${response.status}`)
+}
+
+export async function setTokenScopes(id: string, scopes: TokenScope[] | null): Promise<void> {
+  const response = await fetch(`/api/tokens/${encodeURIComponent(id)}/scopes`, {
+    method: 'PATCH',
+    headers: { 'Content-Type': 'application/json' },
+    body: JSON.stringify({ scopes }),
+  })
+  if (!response.ok) throw new Error(`Failed to update token scopes: ${response.status}`)
+}
+
+export async function setTokenGroupScopes(group: string, scopes: TokenScope[] | null): Promise<void> {
+  const response = await fetch(`/api/tokens/groups/${encodeURIComponent(group)}/scopes`, {
+    method: 'PATCH',
+    headers: { 'Content-Type': 'application/json' },
+    body: JSON.stringify({ scopes }),
   })
+  if (!response.ok) throw new Error(`Failed to update token group scopes: ${response.status}`)
+}
+
+/** Caps on cost-driving request parameters; unset fields impose no limit. */
+export interface RequestParameterPolicy {
+  mode?: 'reject' | 'clamp'
+  maxSearchDepth?: 'ultra-fast' | 'fast' | 'basic' | 'advanced' | null
+  maxExtractDepth?: 'basic' | 'advanced' | null
+  maxResults?: number | null
+  allowRawContent?: boolean | null
+  maxCrawlLimit?: number | null
+  maxCrawlDepth?: number | null
+  allowedResearchModels?: Array<'mini' | 'auto' | 'pro'> | null
+  maxExpectedCredits?: number | null
+}
+
+export type RequestParameterPolicySubject = 'token' | 'token_group' | 'user_tag'
+
+export interface RequestParameterPolicyEntry {
+  subject: RequestParameterPolicySubject
+  subjectId: string
+  policy: RequestParameterPolicy
+  updatedAt: number
+}
+
+export function fetchRequestParameterPolicies(signal?: AbortSignal): Promise<RequestParameterPolicyEntry[]> {
+  return requestJson('/api/parameter-policies', { signal })
+}
+
+const PARAMETER_POLICY_PATHS: Record<RequestParameterPolicySubject, string> = {
+  token: '/api/tokens',
+  token_group: '/api/tokens/groups',
+  user_tag: '/api/user-tags',
+}
+
+export async function setRequestParameterPolicy(
+  subject: RequestParameterPolicySubject,
+  subjectId: string,
+  policy: RequestParameterPolicy | null,
+): Promise<void> {
+  const response = await fetch(
+    `${PARAMETER_POLICY_PATHS[subject]}/${encodeURIComponent(subjectId)}/parameter-policy`,
+    {
+      method: 'PATCH',
+      headers: { 'Content-Type': 'application/json' },
+      body: JSON.stringify({ policy }),
+    },
+  )
+  if (!response.ok) throw new Error(`Failed to update parameter policy: ${response.status}`)
 }
 
 export function deleteTokensBatch(ids: string[]): Promise<BatchTokenMutationResponse> {
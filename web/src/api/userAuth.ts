@@ -1,26 +1,37 @@
-import { requestJson } from './runtime'
+import { requestJson, type Profile, type UserLoginProvider, type UserLoginProviderId } from './runtime'
 
 export type LinuxDoFinalizeOutcome =
   | 'success'
   | 'invalid_state'
   | 'registration_paused'
   | 'inactive_user'
+  | 'invalid_invite'
   | 'upstream_failure'
   | 'server_error'
 
 export interface LinuxDoFinalizeResult {
   outcome: LinuxDoFinalizeOutcome
-  provider: 'linuxdo'
+  provider: UserLoginProviderId
   redirectTo: string | null
   detail: string | null
 }
 
-export function finalizeLinuxDoAuth(
+const LINUXDO_LOGIN_PROVIDER: UserLoginProvider = { id: 'linuxdo', label: 'LinuxDo', startPath: '/auth/linuxdo' }
+
+/** Login providers offered by the server; profiles without the list come from linux.do-only servers. */
+export function resolveUserLoginProviders(
+  profile: Pick<Profile, 'userLoginProviders'> | null | undefined,
+): UserLoginProvider[] {
+  return profile?.userLoginProviders ?? [LINUXDO_LOGIN_PROVIDER]
+}
+
+export function finalizeUserLogin(
+  provider: UserLoginProviderId,
   code: string,
   state: string,
   signal?: AbortSignal,
 ): Promise<LinuxDoFinalizeResult> {
-  return requestJson('/auth/linuxdo/finalize', {
+  return requestJson(`/auth/${provider}/finalize`, {
     method: 'POST',
     credentials: 'include',
     headers: { 'Content-Type': 'application/json' },
@@ -28,3 +39,11 @@ export function finalizeLinuxDoAuth(
     signal,
   })
 }
+
+export function finalizeLinuxDoAuth(
+  code: string,
+  state: string,
+  signal?: AbortSignal,
+): Promise<LinuxDoFinalizeResult> {
+  return finalizeUserLogin('linuxdo', code, state, signal)
+}
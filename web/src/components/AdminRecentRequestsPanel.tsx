@@ -1,4 +1,4 @@
-import { Fragment, useCallback, useEffect, useMemo, useRef, useState } from 'react'
+import { Fragment, type ReactNode, useCallback, useEffect, useMemo, useRef, useState } from 'react'
 import { createPortal } from 'react-dom'
 import type { QueryLoadState } from '../admin/queryLoadState'
 import type { LogFacetOption, RequestLog, RequestLogBodies } from '../api'
@@ -30,6 +30,7 @@ import {
   rebalanceMarkerLabel,
 } from './requestLogRebalance'
 import SearchableFacetSelect from './SearchableFacetSelect'
+import { Input } from './ui/input'
 import { StatusBadge, type StatusTone } from './StatusBadge'
 import { Button } from './ui/button'
 import {
@@ -83,6 +84,8 @@ export interface AdminRecentRequestsPanelProps {
   keyOptions?: LogFacetOption[]
   selectedKeyId?: string | null
   onKeyFilterChange?: (value: string | null) => void
+  searchQuery?: string
+  onSearchChange?: (value: string) => void
   showKeyColumn: boolean
   showTokenColumn: boolean
   perPage: number
@@ -98,6 +101,7 @@ export interface AdminRecentRequestsPanelProps {
   onOpenKey?: (id: string) => void
   onOpenToken?: (id: string) => void
   loadLogBodies: (log: RequestLog, signal: AbortSignal) => Promise<RequestLogBodies>
+  renderLogReplay?: (log: RequestLog, original: { requestBody: string | null; responseBody: string }) => ReactNode
 }
 type LogBodiesLoadState =
   | { status: 'loading' }
@@ -132,6 +136,8 @@ function keyEffectTone(code: string | null | undefined): StatusTone {
     case 'transient_backoff_set':
       return 'warning'
     case 'transient_backoff_cleared':
+    case 'response_cache_hit':
+    case 'request_coalesced':
       return 'success'
     case 'mcp_session_init_backoff_set':
     case 'mcp_session_retry_waited':
@@ -161,6 +167,10 @@ function keyEffectLabel(code: string | null | undefined, strings: AdminTranslati
       return strings.logs.keyEffects.mcpSessionRetryWaited
     case 'mcp_session_retry_scheduled':
       return strings.logs.keyEffects.mcpSessionRetryScheduled
+    case 'response_cache_hit':
+      return strings.logs.keyEffects.responseCacheHit
+    case 'request_coalesced':
+      return strings.logs.keyEffects.requestCoalesced
     case 'none':
     case '':
       return strings.logs.keyEffects.none
@@ -553,10 +563,12 @@ function RecentRequestDetails({
   strings,
   language,
   formatTime,
+  renderLogReplay,
 }: {
   log: RequestLog
   logBodiesState?: LogBodiesLoadState
   onRetryLoadBodies?: (() => void) | null
+  renderLogReplay?: AdminRecentRequestsPanelProps['renderLogReplay']
   strings: AdminTranslations
   language: Language
   formatTime: (ts: number | null) => string
@@ -630,6 +642,7 @@ function RecentRequestDetails({
     log.fallback_reason
       ? { label: strings.logDetails.fallbackReason, value: log.fallback_reason }
       : null,
+    log.trace_id ? { label: strings.logDetails.traceId, value: log.trace_id } : null,
   ].filter((entry): entry is { label: string; value: string } => entry != null)
   return (
     <div className="log-details-panel">
@@ -710,6 +723,11 @@ function RecentRequestDetails({
           ) : null}
         </div>
       ) : null}
+      {renderLogReplay?.(log, {
+        requestBody:
+          logBodiesState?.status === 'ready' ? logBodiesState.value.request_body ?? null : log.request_body ?? null,
+        responseBody,
+      })}
       <RequestIpDiagnostics log={log} language={language} />
       {(forwarded.length > 0 || dropped.length > 0) && (
         <div className="log-details-headers">
@@ -767,6 +785,8 @@ export default function AdminRecentRequestsPanel({
   keyOptions = [],
   selectedKeyId,
   onKeyFilterChange,
+  searchQuery = '',
+  onSearchChange,
   showKeyColumn,
   showTokenColumn,
   perPage,
@@ -782,10 +802,13 @@ export default function AdminRecentRequestsPanel({
   onOpenKey,
   onOpenToken,
   loadLogBodies,
+  renderLogReplay,
 }: AdminRecentRequestsPanelProps): JSX.Element {
   const [expandedLogs, setExpandedLogs] = useState<Set<number>>(() => new Set())
   const [logBodiesById, setLogBodiesById] = useState<Record<number, LogBodiesLoadState>>({})
   const [headerFiltersTarget, setHeaderFiltersTarget] = useState<HTMLElement | null>(null)
+  const [searchDraft, setSearchDraft] = useState(searchQuery)
+  useEffect(() => setSearchDraft(searchQuery), [searchQuery])
   const logBodyControllersRef = useRef<Map<number, AbortController>>(new Map())
   const viewportMode = useViewportMode()
   const isSmallViewport = viewportMode === 'small'
@@ -1050,6 +1073,24 @@ export default function AdminRecentRequestsPanel({
           />
         </div>
       ) : null}
+      {onSearchChange ? (
+        <form
+          className="recent-requests-filter-field"
+          onSubmit={(event) => {
+            event.preventDefault()
+            onSearchChange(searchDraft.trim())
+          }}
+        >
+          <span className="recent-requests-filter-label">{language === 'zh' ? '全文搜索' : 'Search'}</span>
+          <Input
+            type="search"
+            value={searchDraft}
+            placeholder={language === 'zh' ? '查询、错误或请求/响应内容' : 'Query, error or body text'}
+            aria-label={language === 'zh' ? '全文搜索请求日志' : 'Search request logs'}
+            onChange={(event) => setSearchDraft(event.target.value)}
+          />
+        </form>
+      ) : null}
     </div>
   )
   const headerFiltersPortal = headerFiltersTarget
@@ -1238,6 +1279,7 @@ export default function AdminRecentRequestsPanel({
                           strings={strings}
                           language={language}
                           formatTime={formatTime}
+                          renderLogReplay={renderLogReplay}
                         />
                       </TableCell>
                     </TableRow>
@@ -1387,6 +1429,7 @@ export default function AdminRecentRequestsPanel({
                       strings={strings}
                       language={language}
                       formatTime={formatTime}
+                      renderLogReplay={renderLogReplay}
                     />
                   </div>
                 ) : null}
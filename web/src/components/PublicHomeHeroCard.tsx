@@ -26,6 +26,8 @@ export interface PublicHomeHeroCardProps {
   topControls?: ReactNode
   linuxDoHref?: string
   onLinuxDoLogin?: () => void
+  oidcLoginLabel?: string | null
+  onOidcLogin?: () => void
   onTokenAccessClick?: () => void
   onAdminActionClick?: () => void
 }
@@ -83,12 +85,15 @@ function PublicHomeHeroCard({
   topControls,
   linuxDoHref = '/auth/linuxdo',
   onLinuxDoLogin,
+  oidcLoginLabel = null,
+  onOidcLogin,
   onTokenAccessClick,
   onAdminActionClick,
 }: PublicHomeHeroCardProps): JSX.Element {
   const { resolvedTheme } = useTheme()
   const showAuthStatus = showAuthStatusLoading || showAuthStatusUnavailable
-  const shouldShowActions = showAuthStatus || showLinuxDoLogin || showTokenAccessButton || showAdminAction
+  const oidcLoginText = oidcLoginLabel ? publicStrings.oidcLogin.button.replace('{provider}', oidcLoginLabel) : null
+  const shouldShowActions = showAuthStatus || showLinuxDoLogin || oidcLoginText != null || showTokenAccessButton || showAdminAction
   const authStatusText = showAuthStatusUnavailable
     ? publicStrings.authStatus.unavailable
     : publicStrings.authStatus.checking
@@ -331,6 +336,17 @@ function PublicHomeHeroCard({
                   </Button>
                 )
           )}
+          {oidcLoginText && (
+            <Button
+              type="button"
+              className={`oidc-login-button ${heroPrimaryButtonClassName}`}
+              aria-label={oidcLoginText}
+              onClick={onOidcLogin}
+            >
+              <Icon icon="mdi:account-arrow-right-outline" width={20} height={20} aria-hidden="true" />
+              <span>{oidcLoginText}</span>
+            </Button>
+          )}
           {showTokenAccessButton && (
             <Button
               type="button"
@@ -0,0 +1,132 @@
+import { useEffect, useState } from 'react'
+
+import type { TeamQuota, TeamUsageSeries, TeamUsageSeriesKey } from '../api'
+import { Button } from './ui/button'
+import type { Language } from '../i18n'
+
+interface TeamUsageSummaryProps {
+  language: Language
+  quota: TeamQuota
+  /** Loads one team usage series; the admin and user console call different endpoints. */
+  loadSeries: (series: TeamUsageSeriesKey, signal: AbortSignal) => Promise<TeamUsageSeries>
+}
+
+function copy(language: Language) {
+  return language === 'zh'
+    ? {
+        hourly: '本小时',
+        daily: '今日',
+        monthly: '本月',
+        exhausted: '团队额度已用尽',
+        series: { dailyCredits: '近 7 日', monthlyCredits: '近 12 个月' } as Record<TeamUsageSeriesKey, string>,
+        bucket: '时间',
+        used: '已用额度',
+        limit: '上限',
+        loading: '正在加载用量…',
+      }
+    : {
+        hourly: 'This hour',
+        daily: 'Today',
+        monthly: 'This month',
+        exhausted: 'Team quota exhausted',
+        series: { dailyCredits: 'Last 7 days', monthlyCredits: 'Last 12 months' } as Record<
+          TeamUsageSeriesKey,
+          string
+        >,
+        bucket: 'Period',
+        used: 'Credits used',
+        limit: 'Limit',
+        loading: 'Loading usage…',
+      }
+}
+
+function formatBucket(ts: number, series: TeamUsageSeriesKey, language: Language): string {
+  const locale = language === 'zh' ? 'zh-CN' : 'en-US'
+  return new Date(ts * 1000).toLocaleDateString(
+    locale,
+    series === 'monthlyCredits' ? { year: 'numeric', month: 'short' } : { month: 'short', day: 'numeric' },
+  )
+}
+
+export default function TeamUsageSummary({ language, quota, loadSeries }: TeamUsageSummaryProps): JSX.Element {
+  const strings = copy(language)
+  const [seriesKey, setSeriesKey] = useState<TeamUsageSeriesKey>('dailyCredits')
+  const [series, setSeries] = useState<TeamUsageSeries | null>(null)
+  const [error, setError] = useState<string | null>(null)
+
+  useEffect(() => {
+    const controller = new AbortController()
+    setSeries(null)
+    loadSeries(seriesKey, controller.signal)
+      .then((next) => {
+        setSeries(next)
+        setError(null)
+      })
+      .catch((err) => {
+        if (!controller.signal.aborted) setError(err instanceof Error ? err.message : String(err))
+      })
+    return () => controller.abort()
+  }, [loadSeries, seriesKey])
+
+  const windows = [
+    [strings.hourly, quota.hourlyUsed, quota.hourlyLimit],
+    [strings.daily, quota.dailyUsed, quota.dailyLimit],
+    [strings.monthly, quota.monthlyUsed, quota.monthlyLimit],
+  ] as const
+
+  return (
+    <div className="team-usage-summary">
+      {quota.window ? <div className="alert alert-warning">{strings.exhausted}</div> : null}
+      <div className="system-settings-field-grid">
+        {windows.map(([label, used, limit]) => (
+          <div className="system-settings-field" key={label}>
+            <span className="text-sm font-medium">{label}</span>
+            <span>
+              {used} / {limit}
+            </span>
+          </div>
+        ))}
+      </div>
+
+      <div className="table-actions">
+        {(['dailyCredits', 'monthlyCredits'] as const).map((key) => (
+          <Button
+            key={key}
+            type="button"
+            size="sm"
+            variant={seriesKey === key ? 'default' : 'outline'}
+            aria-pressed={seriesKey === key}
+            onClick={() => setSeriesKey(key)}
+          >
+            {strings.series[key]}
+          </Button>
+        ))}
+      </div>
+      {error ? <div className="alert alert-error">{error}</div> : null}
+      {series ? (
+        <div className="table-wrapper">
+          <table className="jobs-table">
+            <thead>
+              <tr>
+                <th>{strings.bucket}</th>
+                <th>{strings.used}</th>
+                <th>{strings.limit}</th>
+              </tr>
+            </thead>
+            <tbody>
+              {series.points.map((point) => (
+                <tr key={point.bucketStart}>
+                  <td>{formatBucket(point.displayBucketStart ?? point.bucketStart, seriesKey, language)}</td>
+                  <td>{point.value ?? '—'}</td>
+                  <td>{point.limitValue ?? '—'}</td>
+                </tr>
+              ))}
+            </tbody>
+          </table>
+        </div>
+      ) : error ? null : (
+        <p className="panel-description">{strings.loading}</p>
+      )}
+    </div>
+  )
+}
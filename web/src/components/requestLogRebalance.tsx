@@ -95,6 +95,8 @@ export function apiRebalanceSelectionEffectTone(code: string | null | undefined)
       return 'error'
     case 'api_rebalance_pressure_avoided':
       return 'success'
+    case 'cross_key_retry':
+      return 'warning'
     default:
       return null
   }
@@ -108,6 +110,8 @@ export function apiRebalanceSelectionEffectLabel(code: string | null | undefined
       return strings.logs.selectionEffects.apiRebalanceRateLimitAvoided
     case 'api_rebalance_pressure_avoided':
       return strings.logs.selectionEffects.apiRebalancePressureAvoided
+    case 'cross_key_retry':
+      return strings.logs.selectionEffects.crossKeyRetry
     default:
       return null
   }
@@ -121,6 +125,8 @@ export function apiRebalanceSelectionEffectBadgeLabel(code: string | null | unde
       return language === 'zh' ? 'API避429' : 'API 429'
     case 'api_rebalance_pressure_avoided':
       return language === 'zh' ? 'API避高压' : 'API pressure'
+    case 'cross_key_retry':
+      return language === 'zh' ? '换Key重试' : 'Key retry'
     default:
       return null
   }
@@ -134,6 +140,8 @@ export function apiRebalanceSelectionEffectSummary(code: string | null | undefin
       return language === 'zh' ? 'API Rebalance 避开了最近更容易触发限流的 Key' : 'API rebalance avoided a key that was recently more rate-limited'
     case 'api_rebalance_pressure_avoided':
       return language === 'zh' ? 'API Rebalance 避开了近期压力更高的 Key' : 'API rebalance avoided a key under higher recent pressure'
+    case 'cross_key_retry':
+      return language === 'zh' ? '上一次尝试失败后，请求在另一把 Key 上重试' : 'The request was retried on a different key after the previous attempt failed'
     default:
       return null
   }
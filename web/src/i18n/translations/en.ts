@@ -35,6 +35,9 @@ export const EN: TranslationShape = {
         button: 'Sign in with Linux DO',
         logoAlt: 'Linux DO logo',
       },
+      oidcLogin: {
+        button: 'Sign in with {provider}',
+      },
       authStatus: {
         checking: 'Checking sign-in and registration status…',
         checkingAction: 'Checking sign-in…',
@@ -48,6 +51,10 @@ export const EN: TranslationShape = {
           'This service is currently accepting sign-ins from already registered users only. New Linux DO accounts cannot be created right now.',
         returnHome: 'Return to home',
         continueHint: 'If you already have an account, go back to the home page and continue signing in there.',
+        inviteLabel: 'Have an invite code?',
+        invitePlaceholder: 'e.g. TEAM-ALPHA',
+        inviteHint: 'Invite codes issued by an administrator still let new accounts register while registration is paused.',
+        inviteSubmit: 'Register with {provider}',
       },
       registrationPausedNotice: {
         title: 'New registration is paused',
@@ -56,6 +63,11 @@ export const EN: TranslationShape = {
       adminLogin: {
         title: 'Admin Login', description: 'Sign in to manage Tavily keys and access tokens.',
         credentialsTitle: 'Login credentials',
+        username: {
+          label: 'Username',
+          placeholder: 'Named admin account',
+          hint: 'Leave empty to sign in with the built-in admin password.',
+        },
         password: {
           label: 'Admin Password',
           placeholder: 'Enter admin password',
@@ -354,6 +366,8 @@ export const EN: TranslationShape = {
           user_quota_exhausted: 'User quota exhausted',
           api_key_exhausted: 'API key exhausted',
           job_failed: 'Job failed',
+          token_expiring: 'Token expiring',
+          threshold_rule: 'Threshold rule breached',
         },
       },
       rankings: {
@@ -665,6 +679,14 @@ export const EN: TranslationShape = {
           currentPercentValue: 'Current ratio: {percent}%',
           apiRebalanceLabel: 'Enable API Rebalance',
           apiRebalanceHint: 'When enabled, every new Tavily HTTP JSON request goes through rebalance. When disabled, all new requests stay on the legacy path. Research result polling always stays pinned to the key used at create time.',
+          quotaWeightedSelectionLabel: 'Quota-weighted key selection',
+          quotaWeightedSelectionHint: 'When enabled, the global key pool, API Rebalance, and Rebalance MCP prefer keys with the most remaining monthly credits (minus recent billable requests) so the pool drains evenly and keys stop hitting 432 mid-month. When disabled, keys rotate least-recently-used first.',
+          crossKeyRetryLabel: 'Cross-key retry',
+          crossKeyRetryHint: 'When enabled, search, extract and map calls that hit 429, 432 or an upstream 5xx are retried on a different key within a short time budget. Every attempt is logged, the token is billed once, and research is never retried.',
+          responseCacheLabel: 'Response cache',
+          responseCacheHint: 'When enabled, identical search, extract and map requests are answered from a local cache for 10 minutes without using an upstream key. Hits are logged as cache hits and are free by default.',
+          requestCoalescingLabel: 'Coalesce identical in-flight requests',
+          requestCoalescingHint: 'When enabled, identical search, extract, crawl and map calls that arrive while one of them is still upstream wait for it and share its response. Every caller keeps its own log entry and is billed in full by default.',
           apiRebalancePercentLabel: 'API request rollout ratio',
           apiRebalancePercentHint: 'Randomly samples each new Tavily HTTP JSON request. Research result polling always stays pinned to the key used at create time.',
           apiRebalancePercentDisabledHint: 'Disabled while API Rebalance is off. Keep this at 0% until the rollout is ready.',
@@ -751,6 +773,8 @@ export const EN: TranslationShape = {
         status: {
           active: 'Active',
           inactive: 'Inactive',
+          pendingApproval: 'Pending approval',
+          approve: 'Approve',
           enabled: 'Enabled',
           disabled: 'Disabled',
           unknown: 'Unknown',
@@ -999,6 +1023,8 @@ export const EN: TranslationShape = {
         title: 'Access Tokens',
         description: 'Auth for /mcp. Format th-xxxx-xxxxxxxxxxxx',
         notePlaceholder: 'Note (optional)',
+        notBeforeLabel: 'Valid from (optional)',
+        expiresAtLabel: 'Expires at (optional)',
         newToken: 'New Token',
         creating: 'Creating…',
         batchCreate: 'Batch Create',
@@ -1409,6 +1435,8 @@ export const EN: TranslationShape = {
           mcpSessionInitBackoffSet: 'MCP Backoff Set',
           mcpSessionRetryWaited: 'MCP Retry Waited',
           mcpSessionRetryScheduled: 'MCP Retry Scheduled',
+          responseCacheHit: 'Cache Hit',
+          requestCoalesced: 'Shared In-flight',
           unknown: 'Updated',
         },
         bindingEffects: {
@@ -1432,6 +1460,7 @@ export const EN: TranslationShape = {
           apiRebalanceCooldownAvoided: 'API Rebalance Cooldown Avoided',
           apiRebalanceRateLimitAvoided: 'API Rebalance Rate Limit Avoided',
           apiRebalancePressureAvoided: 'API Rebalance Pressure Avoided',
+          crossKeyRetry: 'Cross-Key Retry',
           unknown: 'Routing Updated',
         },
       },
@@ -1492,6 +1521,7 @@ export const EN: TranslationShape = {
           forward_proxy_geo_refresh: 'Node IP/GEO refresh',
           linuxdo_user_status_sync: 'LinuxDo user sync',
           linuxdo_user_tag_binding_refresh: 'LinuxDo tag refresh',
+          token_expiry_notice: 'Token expiry notices',
         },
       },
       statuses: {
@@ -1528,6 +1558,7 @@ export const EN: TranslationShape = {
         routingSubjectHash: 'Routing Subject Hash',
         upstreamOperation: 'Upstream Operation',
         fallbackReason: 'Fallback Reason',
+        traceId: 'Trace ID',
         requestTypeDetail: 'Request Type Detail',
         solution: 'Suggested Handling',
         requestBody: 'Request Body',
@@ -35,6 +35,9 @@ export const ZH: TranslationShape = {
         button: '使用 Linux DO 登录',
         logoAlt: 'Linux DO 标志',
       },
+      oidcLogin: {
+        button: '使用 {provider} 登录',
+      },
       authStatus: {
         checking: '正在检查登录与注册状态…',
         checkingAction: '正在检查登录状态…',
@@ -47,6 +50,10 @@ export const ZH: TranslationShape = {
         description: '当前服务仅允许已注册用户继续登录，暂不接受新的 Linux DO 账户创建本地身份。',
         returnHome: '返回首页',
         continueHint: '如果你已经有账号，请返回首页继续登录。',
+        inviteLabel: '有邀请码？',
+        invitePlaceholder: '例如 TEAM-ALPHA',
+        inviteHint: '管理员发放的邀请码在注册暂停期间仍可用于创建新账户。',
+        inviteSubmit: '使用 {provider} 注册',
       },
       registrationPausedNotice: {
         title: '新注册已暂停',
@@ -55,6 +62,11 @@ export const ZH: TranslationShape = {
       adminLogin: {
         title: '管理员登录', description: '登录后可管理 Tavily key 与访问令牌。',
         credentialsTitle: '登录凭据',
+        username: {
+          label: '用户名',
+          placeholder: '具名管理员账户',
+          hint: '留空则使用内置管理员密码登录。',
+        },
         password: {
           label: '管理员口令',
           placeholder: '请输入管理员口令',
@@ -353,6 +365,8 @@ export const ZH: TranslationShape = {
           user_quota_exhausted: '用户额度耗尽',
           api_key_exhausted: 'API Key 耗尽',
           job_failed: '任务失败',
+          token_expiring: '令牌即将过期',
+          threshold_rule: '阈值规则触发',
         },
       },
       rankings: {
@@ -664,6 +678,14 @@ export const ZH: TranslationShape = {
           currentPercentValue: '当前比例：{percent}%',
           apiRebalanceLabel: '启用 API Rebalance',
           apiRebalanceHint: '开启后，所有新的 Tavily HTTP JSON 请求都走 rebalance；关闭后全部走旧路径。research result 查询始终沿用创建时的 key。',
+          quotaWeightedSelectionLabel: '按剩余额度加权选 key',
+          quotaWeightedSelectionHint: '开启后，全局 key 池、API Rebalance 与 Rebalance MCP 优先选择剩余月度额度最多的 key（扣除最近的计费请求），让额度均匀消耗，减少月中 432。关闭后按最久未使用轮换。',
+          crossKeyRetryLabel: '跨 key 重试',
+          crossKeyRetryHint: '开启后，search、extract、map 遇到 429、432 或上游 5xx 时会在限定时间内换一把 key 重试。每次尝试都会记录日志，令牌只计费一次，research 永不重试。',
+          responseCacheLabel: '响应缓存',
+          responseCacheHint: '开启后，10 分钟内完全相同的 search、extract、map 请求直接由本地缓存应答，不占用上游 key。命中会单独记录为缓存命中，默认不计费。',
+          requestCoalescingLabel: '合并相同的进行中请求',
+          requestCoalescingHint: '开启后，当相同的 search、extract、crawl、map 调用仍在上游处理时，后到的相同请求会等待并共享同一份响应。每个调用方仍各自记录日志，默认按全额计费。',
           apiRebalancePercentLabel: 'API 请求放量比例',
           apiRebalancePercentHint: '每个新 Tavily HTTP JSON 请求独立随机分桶；research result 查询始终沿用创建时的 key。',
           apiRebalancePercentDisabledHint: 'API Rebalance 关闭时不可调整；放量前保持 0%。',
@@ -750,6 +772,8 @@ export const ZH: TranslationShape = {
         status: {
           active: '活跃',
           inactive: '未激活',
+          pendingApproval: '待审批',
+          approve: '批准',
           enabled: '启用',
           disabled: '禁用',
           unknown: '未知',
@@ -998,6 +1022,8 @@ export const ZH: TranslationShape = {
         title: '访问令牌',
         description: '用于 /mcp 的认证，格式 th-xxxx-xxxxxxxxxxxx',
         notePlaceholder: '备注（可选）',
+        notBeforeLabel: '生效时间（可选）',
+        expiresAtLabel: '过期时间（可选）',
         newToken: '新建令牌',
         creating: '创建中…',
         batchCreate: '批量创建',
@@ -1408,6 +1434,8 @@ export const ZH: TranslationShape = {
           mcpSessionInitBackoffSet: '已设置 MCP 回退',
           mcpSessionRetryWaited: 'MCP 已等待重试',
           mcpSessionRetryScheduled: 'MCP 已安排重试',
+          responseCacheHit: '缓存命中',
+          requestCoalesced: '共享进行中请求',
           unknown: '已更新',
         },
         bindingEffects: {
@@ -1431,6 +1459,7 @@ export const ZH: TranslationShape = {
           apiRebalanceCooldownAvoided: 'API Rebalance 避开冷却 Key',
           apiRebalanceRateLimitAvoided: 'API Rebalance 避开限流 Key',
           apiRebalancePressureAvoided: 'API Rebalance 避开高压 Key',
+          crossKeyRetry: '跨 Key 重试',
           unknown: '选路已更新',
         },
       },
@@ -1491,6 +1520,7 @@ export const ZH: TranslationShape = {
           forward_proxy_geo_refresh: '节点 IP/GEO 刷新',
           linuxdo_user_status_sync: 'LinuxDo 用户同步',
           linuxdo_user_tag_binding_refresh: 'LinuxDo 标签刷新',
+          token_expiry_notice: '令牌到期提醒',
         },
       },
       statuses: {
@@ -1527,6 +1557,7 @@ export const ZH: TranslationShape = {
         routingSubjectHash: '路由主题哈希',
         upstreamOperation: '上游操作',
         fallbackReason: '回退原因',
+        traceId: '追踪 ID',
         requestTypeDetail: '请求类型详情',
         solution: '建议处理',
         requestBody: '请求体',
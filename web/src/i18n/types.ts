@@ -29,6 +29,9 @@ export interface PublicTranslations {
     button: string
     logoAlt: string
   }
+  oidcLogin: {
+    button: string
+  }
   authStatus: {
     checking: string
     checkingAction: string
@@ -41,6 +44,10 @@ export interface PublicTranslations {
     description: string
     returnHome: string
     continueHint: string
+    inviteLabel: string
+    invitePlaceholder: string
+    inviteHint: string
+    inviteSubmit: string
   }
   registrationPausedNotice: {
     title: string
@@ -50,6 +57,11 @@ export interface PublicTranslations {
     title: string
     description: string
     credentialsTitle: string
+    username: {
+      label: string
+      placeholder: string
+      hint: string
+    }
     password: {
       label: string
       placeholder: string
@@ -340,6 +352,8 @@ export interface AdminTranslationsShape {
       user_quota_exhausted: string
       api_key_exhausted: string
       job_failed: string
+      token_expiring: string
+      threshold_rule: string
     }
   }
   rankings: {
@@ -994,6 +1008,14 @@ export interface AdminTranslationsShape {
       currentPercentValue: string
       apiRebalanceLabel: string
       apiRebalanceHint: string
+      quotaWeightedSelectionLabel: string
+      quotaWeightedSelectionHint: string
+      crossKeyRetryLabel: string
+      crossKeyRetryHint: string
+      responseCacheLabel: string
+      responseCacheHint: string
+      requestCoalescingLabel: string
+      requestCoalescingHint: string
       apiRebalancePercentLabel: string
       apiRebalancePercentHint: string
       apiRebalancePercentDisabledHint: string
@@ -1088,6 +1110,8 @@ export interface AdminTranslationsShape {
     status: {
       active: string
       inactive: string
+      pendingApproval: string
+      approve: string
       enabled: string
       disabled: string
       unknown: string
@@ -1337,6 +1361,8 @@ export interface AdminTranslationsShape {
     title: string
     description: string
     notePlaceholder: string
+    notBeforeLabel: string
+    expiresAtLabel: string
     newToken: string
     creating: string
     batchCreate: string
@@ -1785,6 +1811,8 @@ export interface AdminTranslationsShape {
       mcpSessionInitBackoffSet: string
       mcpSessionRetryWaited: string
       mcpSessionRetryScheduled: string
+      responseCacheHit: string
+      requestCoalesced: string
       unknown: string
     }
     bindingEffects: {
@@ -1808,6 +1836,7 @@ export interface AdminTranslationsShape {
       apiRebalanceCooldownAvoided: string
       apiRebalanceRateLimitAvoided: string
       apiRebalancePressureAvoided: string
+      crossKeyRetry: string
       unknown: string
     }
   }
@@ -1826,6 +1855,7 @@ export interface AdminTranslationsShape {
     routingSubjectHash: string
     upstreamOperation: string
     fallbackReason: string
+    traceId: string
     requestTypeDetail: string
     solution: string
     requestBody: string
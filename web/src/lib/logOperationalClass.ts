@@ -114,6 +114,10 @@ export function failureKindGuidance(
       return language === 'zh'
         ? 'query 超出上游长度限制，请缩短输入后重试。'
         : 'The query exceeds the upstream length limit. Shorten the input before retrying.'
+    case 'parameter_policy_violation':
+      return language === 'zh'
+        ? '请求参数超出了该令牌的参数策略上限，请降低 search_depth、结果数或爬取范围后重试。'
+        : "The request exceeds this token's parameter policy. Lower search_depth, result counts, or crawl scope before retrying."
     case 'mcp_method_405':
       return language === 'zh'
         ? '这是 MCP transport 层返回的 405，请结合请求类型与上游响应判断是否属于控制面行为。'
@@ -27,12 +27,14 @@ function AdminLogin({ updateBanner }: { updateBanner?: ReactNode } = {}): JSX.El
   const ui = strings.public.adminLogin
   const offline = useOfflineState()
 
+  const [username, setUsername] = useState('')
   const [password, setPassword] = useState('')
   const [error, setError] = useState<string | null>(null)
   const [state, setState] = useState<LoginState>('checking')
   const [submittingAction, setSubmittingAction] = useState<SubmitAction | null>(null)
   const [builtinEnabled, setBuiltinEnabled] = useState<boolean | null>(null)
   const [passkeyEnabled, setPasskeyEnabled] = useState(false)
+  const [accountsEnabled, setAccountsEnabled] = useState(false)
   const [totpRequired, setTotpRequired] = useState(false)
   const [totpCode, setTotpCode] = useState('')
   const [profileUnavailable, setProfileUnavailable] = useState(false)
@@ -53,6 +55,7 @@ function AdminLogin({ updateBanner }: { updateBanner?: ReactNode } = {}): JSX.El
         if (!alive) return
         setBuiltinEnabled(profile.builtinAuthEnabled ?? false)
         setPasskeyEnabled(profile.passkeyAuthEnabled ?? false)
+        setAccountsEnabled(profile.adminAccountsEnabled ?? false)
         setTotpRequired(profile.adminLoginTotpRequired ?? false)
         setProfileUnavailable(false)
         if (profile.isAdmin && !resetMode && !isDemoMode()) {
@@ -64,6 +67,7 @@ function AdminLogin({ updateBanner }: { updateBanner?: ReactNode } = {}): JSX.El
         if (!alive) return
         setBuiltinEnabled(null)
         setPasskeyEnabled(false)
+        setAccountsEnabled(false)
         setTotpRequired(false)
         setProfileUnavailable(true)
       })
@@ -76,14 +80,20 @@ function AdminLogin({ updateBanner }: { updateBanner?: ReactNode } = {}): JSX.El
     }
   }, [resetMode])
 
-  const showPasswordForm = !resetMode && builtinEnabled !== false
+  const showPasswordForm = !resetMode && (builtinEnabled !== false || accountsEnabled)
+  const showUsernameInput = showPasswordForm && accountsEnabled
+  const accountLogin = showUsernameInput && (username.trim().length > 0 || builtinEnabled === false)
   const showPasskeyLogin = !resetMode && (passkeyEnabled || profileUnavailable)
-  const showTotpInput = totpRequired && (showPasswordForm || showPasskeyLogin)
-  const noLoginMethods = !resetMode && builtinEnabled === false && !passkeyEnabled
+  // The login TOTP belongs to the built-in admin; named accounts sign in with their own password.
+  const showTotpInput = totpRequired && ((showPasswordForm && !accountLogin) || showPasskeyLogin)
+  const noLoginMethods = !resetMode && builtinEnabled === false && !passkeyEnabled && !accountsEnabled
 
   const canSubmit = useMemo(
-    () => showPasswordForm && state === 'ready' && password.trim().length > 0 && (!totpRequired || totpCode.length === 6),
-    [password, showPasswordForm, state, totpCode.length, totpRequired],
+    () => showPasswordForm
+      && state === 'ready'
+      && password.trim().length > 0
+      && (accountLogin ? username.trim().length > 0 : !totpRequired || totpCode.length === 6),
+    [accountLogin, password, showPasswordForm, state, totpCode.length, totpRequired, username],
   )
   const canUsePasskey = showPasskeyLogin && state === 'ready' && !offline.isOffline && (!totpRequired || totpCode.length === 6)
   const canRegisterResetPasskey = resetMode && state === 'ready' && !offline.isOffline
@@ -126,10 +136,9 @@ function AdminLogin({ updateBanner }: { updateBanner?: ReactNode } = {}): JSX.El
     await finishWithErrorHandling('password', () => requestJson('/api/admin/login', {
       method: 'POST',
       headers: { 'Content-Type': 'application/json' },
-      body: JSON.stringify({
-        password: password.trim(),
-        ...(totpRequired ? { totpCode } : {}),
-      }),
+      body: JSON.stringify(accountLogin
+        ? { username: username.trim(), password: password.trim() }
+        : { password: password.trim(), ...(totpRequired ? { totpCode } : {}) }),
     }))
   }
 
@@ -274,6 +283,25 @@ function AdminLogin({ updateBanner }: { updateBanner?: ReactNode } = {}): JSX.El
 
               {showPasswordForm ? (
                 <>
+                  {showUsernameInput ? (
+                    <label className="auth-password-label grid w-full gap-2 text-base font-medium" htmlFor="admin-username-input">
+                      <span>{ui.username.label}</span>
+                      <Input
+                        id="admin-username-input"
+                        className="auth-password-input"
+                        type="text"
+                        value={username}
+                        onChange={(e) => setUsername(e.target.value)}
+                        placeholder={ui.username.placeholder}
+                        aria-label={ui.username.label}
+                        autoComplete="username"
+                        disabled={state !== 'ready'}
+                      />
+                      {builtinEnabled !== false ? (
+                        <span className="text-sm font-medium text-muted-foreground">{ui.username.hint}</span>
+                      ) : null}
+                    </label>
+                  ) : null}
                   <label className="auth-password-label grid w-full gap-2 text-base font-medium" htmlFor="admin-password-input">
                     <span>{ui.password.label}</span>
                     <Input
@@ -1,9 +1,13 @@
+import { useEffect, useState } from 'react'
+
+import { fetchProfile, resolveUserLoginProviders, type UserLoginProvider } from '../api'
 import BrandLockup from '../components/BrandLockup'
 import ThemeToggle from '../components/ThemeToggle'
 import LanguageSwitcher from '../components/LanguageSwitcher'
 import { ConnectedUpdateAvailableBanner } from '../components/UpdateAvailableBanner'
 import { Button } from '../components/ui/button'
 import { Card, CardContent, CardDescription, CardHeader, CardTitle } from '../components/ui/card'
+import { Input } from '../components/ui/input'
 import { useTranslate } from '../i18n'
 import { useTheme } from '../theme'
 
@@ -12,6 +16,16 @@ function RegistrationPaused(): JSX.Element {
   const strings = translations.public.registrationPaused
   const { resolvedTheme } = useTheme()
   const isDark = resolvedTheme === 'dark'
+  const [providers, setProviders] = useState<UserLoginProvider[]>(() => resolveUserLoginProviders(null))
+  const [inviteCode, setInviteCode] = useState('')
+
+  useEffect(() => {
+    const controller = new AbortController()
+    fetchProfile(controller.signal)
+      .then((profile) => setProviders(resolveUserLoginProviders(profile)))
+      .catch(() => undefined)
+    return () => controller.abort()
+  }, [])
 
   return (
     <div
@@ -77,6 +91,33 @@ function RegistrationPaused(): JSX.Element {
             >
               {strings.continueHint}
             </div>
+            <form method="POST" action={providers[0]?.startPath} className="space-y-3">
+              <label className="block space-y-1 text-sm font-medium" htmlFor="registration-invite-code">
+                <span>{strings.inviteLabel}</span>
+                <Input
+                  id="registration-invite-code"
+                  name="invite_code"
+                  autoComplete="off"
+                  placeholder={strings.invitePlaceholder}
+                  value={inviteCode}
+                  onChange={(event) => setInviteCode(event.target.value)}
+                />
+              </label>
+              <p className={`text-xs ${isDark ? 'text-muted-foreground' : 'text-amber-900/70'}`}>{strings.inviteHint}</p>
+              <div className="flex flex-wrap items-center gap-2">
+                {providers.map((provider) => (
+                  <Button
+                    key={provider.id}
+                    type="submit"
+                    variant="outline"
+                    formAction={provider.startPath}
+                    disabled={inviteCode.trim().length === 0}
+                  >
+                    {strings.inviteSubmit.replace('{provider}', provider.label)}
+                  </Button>
+                ))}
+              </div>
+            </form>
             <div className="flex flex-wrap items-center justify-end gap-3">
               <Button asChild>
                 <a href="/">{strings.returnHome}</a>
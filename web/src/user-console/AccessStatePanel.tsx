@@ -1,21 +1,33 @@
+import { resolveUserLoginProviders, type UserLoginProvider } from '../api'
 import { Icon } from '../lib/icons'
-import { USER_CONSOLE_LOGIN_START_PATH } from './oauthCallback'
 import type { EN } from './text'
 
-type AccessText = Pick<typeof EN, 'unavailable' | 'loggedOut' | 'loginRequired'>
+type AccessText = Pick<typeof EN, 'unavailable' | 'loggedOut' | 'loginRequired' | 'pendingApproval'>
 
 interface AccessStatePanelProps {
-  state: 'unavailable' | 'logged_out' | 'login_required'
+  state: 'unavailable' | 'logged_out' | 'login_required' | 'pending_approval'
   text: AccessText
   onHome: () => void
+  loginProvider?: UserLoginProvider
 }
 
-export default function AccessStatePanel({ state, text, onHome }: AccessStatePanelProps): JSX.Element {
+const DEFAULT_LOGIN_PROVIDER = resolveUserLoginProviders(null)[0]
+
+export default function AccessStatePanel({
+  state,
+  text,
+  onHome,
+  loginProvider = DEFAULT_LOGIN_PROVIDER,
+}: AccessStatePanelProps): JSX.Element {
+  const startLogin = () => { window.location.href = loginProvider.startPath }
   const model = state === 'unavailable'
     ? { icon: 'mdi:account-off-outline', copy: text.unavailable, action: onHome }
-    : state === 'logged_out'
-      ? { icon: 'mdi:logout-variant', copy: text.loggedOut, action: () => { window.location.href = USER_CONSOLE_LOGIN_START_PATH } }
-      : { icon: 'mdi:account-arrow-right-outline', copy: text.loginRequired, action: () => { window.location.href = USER_CONSOLE_LOGIN_START_PATH } }
+    : state === 'pending_approval'
+      ? { icon: 'mdi:progress-clock', copy: text.pendingApproval, action: onHome }
+      : state === 'logged_out'
+        ? { icon: 'mdi:logout-variant', copy: text.loggedOut, action: startLogin }
+        : { icon: 'mdi:account-arrow-right-outline', copy: text.loginRequired, action: startLogin }
+  const fill = (template: string) => template.replace(/\{provider\}/g, loginProvider.label)
 
   return (
     <section className="surface panel access-panel">
@@ -25,11 +37,11 @@ export default function AccessStatePanel({ state, text, onHome }: AccessStatePan
         </div>
         <div className="console-unavailable-copy">
           <h2>{model.copy.title}</h2>
-          <p>{model.copy.description}</p>
+          <p>{fill(model.copy.description)}</p>
         </div>
         <div className="table-actions console-unavailable-actions">
           <button type="button" className="btn btn-primary" onClick={model.action}>
-            {'home' in model.copy ? model.copy.home : model.copy.action}
+            {'home' in model.copy ? model.copy.home : fill(model.copy.action)}
           </button>
         </div>
       </div>
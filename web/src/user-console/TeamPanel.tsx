@@ -0,0 +1,202 @@
+import { useCallback, useEffect, useState } from 'react'
+
+import {
+  createUserTeamToken,
+  deleteUserTeamToken,
+  fetchUserTeam,
+  fetchUserTeamTokens,
+  fetchUserTeamUsageSeries,
+  type TeamRole,
+  type TeamToken,
+  type UserTeam,
+} from '../api'
+import TeamUsageSummary from '../components/TeamUsageSummary'
+import { Button } from '../components/ui/button'
+import { Input } from '../components/ui/input'
+import { copyText } from '../lib/clipboard'
+import { Icon } from '../lib/icons'
+import type { Language } from '../i18n'
+
+interface TeamPanelProps {
+  language: Language
+}
+
+function copy(language: Language) {
+  return language === 'zh'
+    ? {
+        title: (name: string) => `团队 · ${name}`,
+        description: '你的调用会计入团队共享额度池。',
+        roles: { owner: '所有者', admin: '管理员', member: '成员' } as Record<TeamRole, string>,
+        membersTitle: '成员',
+        tokensTitle: '团队令牌',
+        tokensEmpty: '还没有团队令牌。',
+        tokenNote: '令牌备注（可选）',
+        createToken: '创建令牌',
+        createdTitle: '新令牌只显示这一次，请立即复制保存：',
+        copyToken: '复制令牌',
+        dismiss: '关闭',
+        delete: '删除',
+      }
+    : {
+        title: (name: string) => `Team · ${name}`,
+        description: 'Your calls draw from the team shared credit pool.',
+        roles: { owner: 'Owner', admin: 'Admin', member: 'Member' } as Record<TeamRole, string>,
+        membersTitle: 'Members',
+        tokensTitle: 'Team tokens',
+        tokensEmpty: 'No team tokens yet.',
+        tokenNote: 'Token note (optional)',
+        createToken: 'Create token',
+        createdTitle: 'This token is shown only once. Copy it now:',
+        copyToken: 'Copy token',
+        dismiss: 'Dismiss',
+        delete: 'Delete',
+      }
+}
+
+/** Team pool usage and team tokens; renders nothing for users outside a team. */
+export default function TeamPanel({ language }: TeamPanelProps): JSX.Element | null {
+  const strings = copy(language)
+  const [team, setTeam] = useState<UserTeam | null>(null)
+  const [tokens, setTokens] = useState<TeamToken[]>([])
+  const [tokenNote, setTokenNote] = useState('')
+  const [createdToken, setCreatedToken] = useState<string | null>(null)
+  const [busy, setBusy] = useState(false)
+  const [error, setError] = useState<string | null>(null)
+
+  const load = useCallback(async (signal?: AbortSignal) => {
+    try {
+      const nextTeam = await fetchUserTeam(signal)
+      setTeam(nextTeam)
+      const managesTokens = nextTeam.role === 'owner' || nextTeam.role === 'admin'
+      setTokens(managesTokens ? await fetchUserTeamTokens(signal) : [])
+      setError(null)
+    } catch (err) {
+      if (signal?.aborted) return
+      setError(err instanceof Error ? err.message : String(err))
+    }
+  }, [])
+
+  useEffect(() => {
+    const controller = new AbortController()
+    void load(controller.signal)
+    return () => controller.abort()
+  }, [load])
+
+  const run = async (action: () => Promise<unknown>) => {
+    setBusy(true)
+    try {
+      await action()
+      await load()
+    } catch (err) {
+      setError(err instanceof Error ? err.message : String(err))
+    } finally {
+      setBusy(false)
+    }
+  }
+
+  if (!team?.team || !team.quota) return null
+  const managesTokens = team.role === 'owner' || team.role === 'admin'
+
+  return (
+    <section className="surface panel user-console-section user-console-team-section" data-console-section="team">
+      <header className="panel-header user-console-section-header">
+        <div>
+          <h2>{strings.title(team.team.name)}</h2>
+          <p className="panel-description">
+            {strings.description}
+            {team.role ? ` · ${strings.roles[team.role]}` : ''}
+          </p>
+        </div>
+      </header>
+      {error ? <div className="alert alert-error">{error}</div> : null}
+
+      <TeamUsageSummary language={language} quota={team.quota} loadSeries={fetchUserTeamUsageSeries} />
+
+      <h3>{strings.membersTitle}</h3>
+      <div className="table-wrapper">
+        <table className="jobs-table">
+          <tbody>
+            {team.members.map((member) => (
+              <tr key={member.userId}>
+                <td>{member.displayName ?? member.username ?? member.userId}</td>
+                <td>{strings.roles[member.role]}</td>
+              </tr>
+            ))}
+          </tbody>
+        </table>
+      </div>
+
+      {managesTokens ? (
+        <>
+          <h3>{strings.tokensTitle}</h3>
+          {createdToken ? (
+            <div className="alert alert-warning">
+              <span>{strings.createdTitle}</span>
+              <code>{createdToken}</code>
+              <div className="table-actions">
+                <Button type="button" size="sm" variant="outline" onClick={() => void copyText(createdToken)}>
+                  <Icon icon="mdi:content-copy" width={16} height={16} aria-hidden="true" />
+                  <span>{strings.copyToken}</span>
+                </Button>
+                <Button type="button" size="sm" variant="ghost" onClick={() => setCreatedToken(null)}>
+                  {strings.dismiss}
+                </Button>
+              </div>
+            </div>
+          ) : null}
+          {tokens.length === 0 ? (
+            <div className="empty-state alert">{strings.tokensEmpty}</div>
+          ) : (
+            <div className="table-wrapper">
+              <table className="jobs-table">
+                <tbody>
+                  {tokens.map((token) => (
+                    <tr key={token.tokenId}>
+                      <td>
+                        <code>{token.tokenId}</code>
+                      </td>
+                      <td>{token.note ?? '—'}</td>
+                      <td className="table-actions">
+                        <Button
+                          type="button"
+                          size="sm"
+                          variant="outline"
+                          disabled={busy}
+                          aria-label={strings.delete}
+                          onClick={() => void run(() => deleteUserTeamToken(token.tokenId))}
+                        >
+                          <Icon icon="mdi:trash-can-outline" width={16} height={16} aria-hidden="true" />
+                        </Button>
+                      </td>
+                    </tr>
+                  ))}
+                </tbody>
+              </table>
+            </div>
+          )}
+          <form
+            className="table-actions"
+            onSubmit={(event) => {
+              event.preventDefault()
+              void run(async () => {
+                const created = await createUserTeamToken(tokenNote.trim() || undefined)
+                setCreatedToken(created.token)
+                setTokenNote('')
+              })
+            }}
+          >
+            <Input
+              aria-label={strings.tokenNote}
+              placeholder={strings.tokenNote}
+              value={tokenNote}
+              onChange={(event) => setTokenNote(event.target.value)}
+            />
+            <Button type="submit" size="sm" disabled={busy}>
+              {strings.createToken}
+            </Button>
+          </form>
+        </>
+      ) : null}
+    </section>
+  )
+}
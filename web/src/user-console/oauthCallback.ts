@@ -1,6 +1,7 @@
 import type { EN } from './text'
 
 export const USER_CONSOLE_LOGIN_START_PATH = '/auth/linuxdo'
+export const USER_LOGIN_CALLBACK_PROVIDERS = ['linuxdo', 'oidc'] as const
 export const OAUTH_CALLBACK_FINALIZE_TIMEOUT_MS = 12_000
 
 export interface OAuthCallbackQueryState {
@@ -17,6 +18,7 @@ export type OAuthCallbackScreenState =
   | 'invalidRequest'
   | 'invalidState'
   | 'inactiveUser'
+  | 'invalidInvite'
   | 'timeout'
   | 'upstreamFailure'
   | 'serverError'
@@ -70,12 +72,17 @@ export function parseOAuthCallbackQuery(search: string): OAuthCallbackQueryState
 
 export function resolveOAuthCallbackProviderLabel(
   provider: string,
-  providers: { linuxdo: string },
+  providers: { linuxdo: string; oidc: string },
 ): string {
   if (provider === 'linuxdo') return providers.linuxdo
+  if (provider === 'oidc') return providers.oidc
   return provider
 }
 
+export function resolveUserLoginStartPath(provider: string | null): string {
+  return provider === 'oidc' ? '/auth/oidc' : USER_CONSOLE_LOGIN_START_PATH
+}
+
 function stepSet(
   text: OAuthCallbackText,
   returned: OAuthCallbackStepState,
@@ -183,6 +190,19 @@ export function resolveOAuthCallbackPanelModel(args: {
         showActions: true,
         steps: stepSet(text, 'complete', 'complete', 'error'),
       }
+    case 'invalidInvite':
+      return {
+        ...base,
+        title: text.status.invalidInvite.title,
+        description: fill(text.status.invalidInvite.description),
+        note: null,
+        liveMessage: text.status.invalidInvite.title,
+        tone: 'warning',
+        icon: 'mdi:key-outline',
+        busy: false,
+        showActions: true,
+        steps: stepSet(text, 'complete', 'complete', 'error'),
+      }
     case 'timeout':
       return {
         ...base,
@@ -20,6 +20,7 @@ import TokenListSummary from './TokenListSummary'
 import BillingPage from './BillingPage'
 import SetupGuidePage from './SetupGuidePage'
 import RechargePanel from './RechargePanel'
+import TeamPanel from './TeamPanel'
 import { DEFAULT_RECHARGE_UNIT_CREDITS, normalizeRechargeSelection } from './rechargeControls'
 import TokenResetDialogs from './TokenResetDialogs'
 import TokenLogsHeader, {
@@ -67,6 +68,7 @@ import {
   probeMcpToolsCall,
   probeMcpToolsList,
   parseUserTokenEventSnapshot,
+  resolveUserLoginProviders,
   rotateUserTokenSecret,
   type Profile,
   type PublicTokenLog,
@@ -311,10 +313,9 @@ function resolveUserConsoleIdentityName(profile: Profile | null): string | null
 
 function resolveUserConsoleProviderLabel(
   provider: Profile['userProvider'] | undefined,
-  providers: { linuxdo: string },
+  providers: { linuxdo: string; oidc: string },
 ): string | null {
-  if (provider === 'linuxdo') return providers.linuxdo
-  return null
+  return provider ? providers[provider] : null
 }
 
 function resolveFallbackLogoutTarget(
@@ -2866,8 +2867,9 @@ export default function UserConsole(): JSX.Element {
 
       <ConnectedUpdateAvailableBanner strings={publicStrings.updateBanner} />
       {consoleUnavailable && <AccessStatePanel state="unavailable" text={text} onHome={goHome} />}
-      {consoleLoggedOut && <AccessStatePanel state="logged_out" text={text} onHome={goHome} />}
-      {consoleNeedsLogin && <AccessStatePanel state="login_required" text={text} onHome={goHome} />}
+      {consoleLoggedOut && <AccessStatePanel state="logged_out" text={text} onHome={goHome} loginProvider={resolveUserLoginProviders(profile)[0]} />}
+      {profile?.userPendingApproval === true && !isOAuthCallbackRoute && <AccessStatePanel state="pending_approval" text={text} onHome={goHome} />}
+      {consoleNeedsLogin && <AccessStatePanel state="login_required" text={text} onHome={goHome} loginProvider={resolveUserLoginProviders(profile)[0]} />}
       {isOAuthCallbackRoute && (
         <div className="oauth-callback-stage">
           <OAuthCallbackPanel
@@ -3148,6 +3150,8 @@ export default function UserConsole(): JSX.Element {
               )}
             </div>
           </section>
+
+          <TeamPanel language={language} />
         </div>
       )}
 
@@ -23,6 +23,7 @@ export const EN = {
     },
     providers: {
       linuxdo: 'LinuxDo',
+      oidc: 'SSO',
     },
   },
   announcements: {
@@ -231,12 +232,17 @@ export const EN = {
   loggedOut: {
     title: 'You have signed out',
     description: 'Sign in again to view your dashboard and token data.',
-    action: 'Sign in with LinuxDo',
+    action: 'Sign in with {provider}',
   },
   loginRequired: {
     title: 'Sign in to open your console',
-    description: 'Dashboard and token data appear here after you sign in with LinuxDo.',
-    action: 'Sign in with LinuxDo',
+    description: 'Dashboard and token data appear here after you sign in with {provider}.',
+    action: 'Sign in with {provider}',
+  },
+  pendingApproval: {
+    title: 'Your account is waiting for approval',
+    description: 'An administrator must approve new accounts before an access token is issued. Check back later.',
+    home: 'Back to Home',
   },
   oauthCallback: {
     badge: 'OAuth Callback',
@@ -263,7 +269,11 @@ export const EN = {
       },
       inactiveUser: {
         title: '{provider} account unavailable',
-        description: 'This LinuxDo account is currently marked inactive and cannot open a console session.',
+        description: 'This {provider} account is currently marked inactive and cannot open a console session.',
+      },
+      invalidInvite: {
+        title: 'This invite code cannot be used',
+        description: 'The invite code is invalid, expired, or already used up. Ask an administrator for a new code, then start a fresh {provider} login.',
       },
       timeout: {
         title: 'The connection took too long',
@@ -279,7 +289,7 @@ export const EN = {
       },
       unsupportedProvider: {
         title: 'This provider is not available here yet',
-        description: 'This callback shell is ready for future providers, but this deployment currently only supports LinuxDo.',
+        description: 'This callback shell is ready for future providers, but this deployment currently only supports LinuxDo and OIDC single sign-on.',
       },
     },
     steps: {
@@ -460,6 +470,7 @@ export const ZH = {
     },
     providers: {
       linuxdo: 'LinuxDo',
+      oidc: 'SSO',
     },
   },
   announcements: {
@@ -668,12 +679,17 @@ export const ZH = {
   loggedOut: {
     title: '你已退出登录',
     description: '重新登录后即可查看账户仪表盘与 Token 数据。',
-    action: '使用 LinuxDo 登录',
+    action: '使用 {provider} 登录',
   },
   loginRequired: {
     title: '登录后即可打开控制台',
-    description: '使用 LinuxDo 登录后，这里就会显示你的仪表盘与 Token 数据。',
-    action: '使用 LinuxDo 登录',
+    description: '使用 {provider} 登录后，这里就会显示你的仪表盘与 Token 数据。',
+    action: '使用 {provider} 登录',
+  },
+  pendingApproval: {
+    title: '你的账户正在等待审核',
+    description: '新账户需经管理员审核通过后才会发放访问令牌，请稍后再来查看。',
+    home: '返回首页',
   },
   oauthCallback: {
     badge: 'OAuth 回调',
@@ -700,7 +716,11 @@ export const ZH = {
       },
       inactiveUser: {
         title: '{provider} 账户当前不可用',
-        description: '这个 LinuxDo 账户当前被标记为 inactive，暂时无法打开控制台会话。',
+        description: '这个 {provider} 账户当前被标记为 inactive，暂时无法打开控制台会话。',
+      },
+      invalidInvite: {
+        title: '这个邀请码无法使用',
+        description: '邀请码无效、已过期或已用完。请向管理员索取新的邀请码，然后重新发起一次 {provider} 登录。',
       },
       timeout: {
         title: '等待时间有点久了',
@@ -716,7 +736,7 @@ export const ZH = {
       },
       unsupportedProvider: {
         title: '当前部署还不支持这个提供方',
-        description: '这套回调壳已预留多提供方入口，但当前部署暂时只接入 LinuxDo。',
+        description: '这套回调壳已预留多提供方入口，但当前部署暂时只接入 LinuxDo 与 OIDC 单点登录。',
       },
     },
     steps: {
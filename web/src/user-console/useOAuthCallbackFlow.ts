@@ -1,13 +1,14 @@
 import { useCallback, useEffect, useMemo, useRef, useState, type Dispatch, type SetStateAction } from 'react'
 
-import { finalizeLinuxDoAuth, type LinuxDoFinalizeResult } from '../api'
+import { finalizeUserLogin, type LinuxDoFinalizeResult, type UserLoginProviderId } from '../api'
 import { userConsoleRouteToPath, type UserConsoleRoute } from '../lib/userConsoleRoutes'
 import {
   OAUTH_CALLBACK_FINALIZE_TIMEOUT_MS,
-  USER_CONSOLE_LOGIN_START_PATH,
+  USER_LOGIN_CALLBACK_PROVIDERS,
   parseOAuthCallbackQuery,
   resolveOAuthCallbackPanelModel,
   resolveOAuthCallbackProviderLabel,
+  resolveUserLoginStartPath,
   type OAuthCallbackScreenState,
 } from './oauthCallback'
 import type { EN } from './text'
@@ -73,13 +74,18 @@ export function useOAuthCallbackFlow({
     }
   }, [oauthCallbackProvider, route])
 
-  const ensureFinalizeAttempt = useCallback((key: string, code: string, state: string) => {
+  const ensureFinalizeAttempt = useCallback((
+    key: string,
+    provider: UserLoginProviderId,
+    code: string,
+    state: string,
+  ) => {
     if (oauthCallbackAttemptRef.current?.key === key) {
       return oauthCallbackAttemptRef.current
     }
 
     const controller = new AbortController()
-    const promise = finalizeLinuxDoAuth(code, state, controller.signal).finally(() => {
+    const promise = finalizeUserLogin(provider, code, state, controller.signal).finally(() => {
       if (oauthCallbackAttemptRef.current?.key === key) {
         oauthCallbackAttemptRef.current = null
       }
@@ -96,7 +102,8 @@ export function useOAuthCallbackFlow({
     setLoading(false)
     setError(null)
 
-    if (oauthCallbackProvider !== 'linuxdo') {
+    const provider = USER_LOGIN_CALLBACK_PROVIDERS.find((candidate) => candidate === oauthCallbackProvider)
+    if (!provider) {
       setOauthCallbackState('unsupportedProvider')
       setOauthCallbackDetail(null)
       return
@@ -116,6 +123,7 @@ export function useOAuthCallbackFlow({
 
     const attempt = ensureFinalizeAttempt(
       `${oauthCallbackProvider}:${query.code}:${query.state}`,
+      provider,
       query.code,
       query.state,
     )
@@ -157,6 +165,11 @@ export function useOAuthCallbackFlow({
           setOauthCallbackDetail(result.detail)
           return
         }
+        if (result.outcome === 'invalid_invite') {
+          setOauthCallbackState('invalidInvite')
+          setOauthCallbackDetail(result.detail)
+          return
+        }
         if (result.outcome === 'upstream_failure') {
           setOauthCallbackState('upstreamFailure')
           setOauthCallbackDetail(result.detail)
@@ -207,8 +220,8 @@ export function useOAuthCallbackFlow({
     [oauthCallbackDetail, oauthCallbackState, providerLabel, text],
   )
   const restartAuth = useCallback(() => {
-    window.location.href = USER_CONSOLE_LOGIN_START_PATH
-  }, [])
+    window.location.href = resolveUserLoginStartPath(oauthCallbackProvider)
+  }, [oauthCallbackProvider])
 
   return {
     isOAuthCallbackRoute,
@@ -1,6 +1,6 @@
 import { afterEach, describe, expect, it, mock } from 'bun:test'
 
-import { finalizeLinuxDoAuth } from './api'
+import { finalizeLinuxDoAuth, finalizeUserLogin, resolveUserLoginProviders } from './api'
 
 const originalFetch = globalThis.fetch
 
@@ -37,3 +37,36 @@ describe('linuxdo auth api helpers', () => {
     })
   })
 })
+
+describe('user login providers', () => {
+  it('posts oidc finalize requests to the oidc endpoint', async () => {
+    const fetchMock = mock((_input: RequestInfo | URL, _init?: RequestInit) =>
+      Promise.resolve(
+        new Response(JSON.stringify({
+          outcome: 'success',
+          provider: 'oidc',
+          redirectTo: '/console',
+          detail: null,
+        }), {
+          status: 200,
+          headers: { 'Content-Type': 'application/json' },
+        }),
+      ),
+    )
+    globalThis.fetch = fetchMock as typeof fetch
+
+    const result = await finalizeUserLogin('oidc', 'oidc-code', 'oidc-state')
+
+    expect(result.provider).toBe('oidc')
+    expect(fetchMock.mock.calls[0]?.[0]).toBe('/auth/oidc/finalize')
+  })
+
+  it('falls back to linux.do when the profile predates the provider list', () => {
+    expect(resolveUserLoginProviders({}).map((provider) => provider.id)).toEqual(['linuxdo'])
+    expect(
+      resolveUserLoginProviders({
+        userLoginProviders: [{ id: 'oidc', label: 'Company SSO', startPath: '/auth/oidc' }],
+      }).map((provider) => provider.id),
+    ).toEqual(['oidc'])
+  })
+})
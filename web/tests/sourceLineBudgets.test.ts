@@ -18,33 +18,33 @@ const EXCEPTIONS = new Map<string, { max: number; reason: string }>([
   [
     'src/admin/AdminDashboardRuntime.tsx',
     {
-      max: 13820,
+      max: 13880,
       reason:
-        'Legacy admin dashboard runtime remains as a compatibility shell while HA source settings, upstream privacy status routing, active-user list filtering, shadow reconciliation comparison wiring, MCP session bindings route state, and the admin rankings live-status wiring finish converging before a larger extraction pass.',
+        'Legacy admin dashboard runtime remains as a compatibility shell while HA source settings, upstream privacy status routing, active-user list filtering, shadow reconciliation comparison wiring, MCP session bindings route state, and the admin rankings live-status wiring finish converging before a larger extraction pass, plus the token expiry alert wiring, the shared response cache settings wiring, the alert webhook delivery panel wiring, the request log search wiring, the request log replay panel wiring, and the team management panel wiring.',
     },
   ],
   [
     'src/admin/storySupport/AdminPagesStoryRuntime.tsx',
     {
-      max: 8000,
+      max: 8010,
       reason:
-        'Storybook proof runtime remains centralized temporarily while active-user admin states, upstream privacy status proof data, rankings shell proof, system-settings proof data, MCP session bindings page proof data, shadow reconciliation comparison proof data, Users Usage 1h sorting proof, and the recharge lifecycle page evidence continue to share the same Admin/Pages proof shell.',
+        'Storybook proof runtime remains centralized temporarily while active-user admin states, upstream privacy status proof data, rankings shell proof, system-settings proof data, MCP session bindings page proof data, shadow reconciliation comparison proof data, Users Usage 1h sorting proof, and the recharge lifecycle page evidence continue to share the same Admin/Pages proof shell, plus the shared response cache settings proof state.',
     },
   ],
   [
     'src/api/runtime.ts',
     {
-      max: 4120,
+      max: 4160,
       reason:
-        'API barrel still carries HA source settings, upstream privacy status contracts, MCP session bindings contracts, planned cutover node-detail contracts, admin settings, passkey/password admin auth contracts, auth-token retention contracts, grouped-alert dashboard summary contracts, alert last-good coverage decoding, expanded alert event/group job metadata, user-list contracts, source dialog failure normalization, and user-console overview APIs until the proxy API surface is split out.',
+        'API barrel still carries HA source settings, upstream privacy status contracts, MCP session bindings contracts, planned cutover node-detail contracts, admin settings, passkey/password admin auth contracts, auth-token retention contracts, grouped-alert dashboard summary contracts, alert last-good coverage decoding, expanded alert event/group job metadata, user-list contracts, source dialog failure normalization, and user-console overview APIs until the proxy API surface is split out, plus shared response cache settings contracts, threshold alert rule contracts, alert lifecycle contracts, and the user login provider profile contract.',
     },
   ],
   [
     'src/api/demo.ts',
     {
-      max: 2610,
+      max: 2630,
       reason:
-        'Demo API fixtures now also cover the upstream privacy status surface, dedicated user billing summary surface, user-console overview snapshots, alerts center mother-child aggregation states, request-record drawers, SSE proof states, auth-token retention settings, recharge availability evidence, admin passkey/password security states, and the Tavily usage-boundary probe on the shared demo shell.',
+        'Demo API fixtures now also cover the upstream privacy status surface, dedicated user billing summary surface, user-console overview snapshots, alerts center mother-child aggregation states, request-record drawers, SSE proof states, auth-token retention settings, recharge availability evidence, admin passkey/password security states, and the Tavily usage-boundary probe on the shared demo shell, plus shared response cache settings fixtures and team fixtures.',
     },
   ],
   [
@@ -66,9 +66,9 @@ const EXCEPTIONS = new Map<string, { max: number; reason: string }>([
   [
     'src/api.test.ts',
     {
-      max: 1800,
+      max: 1870,
       reason:
-        'Shared API contract coverage now includes upstream privacy status, auth-token retention settings, the user-console overview snapshot, events surface, the expanded admin rankings endpoint, and admin user base quota ledger contracts until the largest runtime suites are split out.',
+        'Shared API contract coverage now includes upstream privacy status, auth-token retention settings, key selection mode, the user-console overview snapshot, events surface, the expanded admin rankings endpoint, and admin user base quota ledger contracts until the largest runtime suites are split out, plus shared response cache settings contracts.',
     },
   ],
   [
@@ -98,9 +98,9 @@ const EXCEPTIONS = new Map<string, { max: number; reason: string }>([
   [
     'src/i18n/types.ts',
     {
-      max: 1950,
+      max: 1980,
       reason:
-        'HA source settings mode-specific failure copy, upstream privacy status strings, planned-cutover and node-detail strings, admin jobs maintenance strings, the expanded admin rankings contract, grouped-alert dashboard summary strings, auth-token retention settings copy, and admin passkey/password security copy remain in the shared catalog contract.',
+        'HA source settings mode-specific failure copy, upstream privacy status strings, planned-cutover and node-detail strings, admin jobs maintenance strings, the expanded admin rankings contract, grouped-alert dashboard summary strings, auth-token retention settings copy, quota-weighted key selection copy, and admin passkey/password security copy remain in the shared catalog contract, plus shared response cache settings copy and registration invite and approval queue copy.',
     },
   ],
   [
@@ -114,9 +114,9 @@ const EXCEPTIONS = new Map<string, { max: number; reason: string }>([
   [
     'src/user-console/runtime.tsx',
     {
-      max: 3385,
+      max: 3390,
       reason:
-        'User console runtime owns the dedicated billing route, the restored visible recharge rail, and the pagination/late-payment billing states, while the route-level shell and Tavily API usage-boundary probe with recursive field-name leak detection still await a broader extraction into dedicated hooks and sections.',
+        'User console runtime owns the dedicated billing route, the restored visible recharge rail, and the pagination/late-payment billing states, while the route-level shell and Tavily API usage-boundary probe with recursive field-name leak detection still await a broader extraction into dedicated hooks and sections, plus the team panel route wiring.',
     },
   ],
   [
@@ -142,6 +142,14 @@ const EXCEPTIONS = new Map<string, { max: number; reason: string }>([
       reason: 'Forward proxy settings now carries the node-pool and error-statistics surfaces; extraction remains a follow-up.',
     },
   ],
+  [
+    'src/admin/AlertsCenter.tsx',
+    {
+      max: 1510,
+      reason:
+        'Alerts center still keeps the per-type tone, filter, and label copy inline, including the token expiry alert type, until the alert type catalog moves into a shared module.',
+    },
+  ],
   [
     'src/components/AdminRecentRequestsPanel.tsx',
     {